@@ -2,7 +2,6 @@ use clap::{Args, Parser, Subcommand};
 use mango_v4_client::{
     keypair_from_cli, pubkey_from_cli, Client, MangoClient, TransactionBuilderConfig,
 };
-use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -223,16 +222,7 @@ async fn main() -> Result<(), anyhow::Error> {
         Command::MangoAccountAddress { group, owner, num } => {
             let group = pubkey_from_cli(&group);
             let owner = pubkey_from_cli(&owner);
-            let address = Pubkey::find_program_address(
-                &[
-                    group.as_ref(),
-                    b"MangoAccount".as_ref(),
-                    owner.as_ref(),
-                    &num.to_le_bytes(),
-                ],
-                &mango_v4::ID,
-            )
-            .0;
+            let address = mango_v4_client::pda::mango_account_address(&group, &owner, num);
             println!("{}", address);
         }
         Command::TestOracles { group, rpc } => {
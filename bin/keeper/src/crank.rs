@@ -181,6 +181,7 @@ pub async fn loop_update_index_and_rate(
                         mint_info: token.mint_info_address,
                         oracle,
                         instructions: solana_program::sysvar::instructions::id(),
+                        keeper: None,
                     },
                     None,
                 ),
@@ -321,6 +322,7 @@ pub async fn loop_consume_events(
                         group: perp_market.group,
                         perp_market: pk,
                         event_queue: perp_market.event_queue,
+                        keeper: None,
                     },
                     None,
                 );
@@ -380,6 +382,7 @@ pub async fn loop_update_funding(
                     bids: perp_market.bids,
                     asks: perp_market.asks,
                     oracle: perp_market.oracle,
+                    keeper: None,
                 },
                 None,
             ),
@@ -4,7 +4,7 @@ use std::time::Duration;
 use itertools::Itertools;
 use mango_v4::health::{HealthCache, HealthType};
 use mango_v4::state::{MangoAccountValue, PerpMarketIndex, Side, TokenIndex, QUOTE_TOKEN_INDEX};
-use mango_v4_client::{chain_data, MangoClient, PreparedInstructions};
+use mango_v4_client::{chain_data, MangoClient, PreparedInstructions, TransactionBuilder};
 use solana_sdk::signature::Signature;
 
 use futures::{stream, StreamExt, TryStreamExt};
@@ -39,7 +39,38 @@ struct LiquidateHelper<'a> {
 }
 
 impl<'a> LiquidateHelper<'a> {
-    async fn serum3_close_orders(&self) -> anyhow::Result<Option<Signature>> {
+    /// Appends `new_ixs` to `ixs` if the result still fits into a single transaction.
+    fn try_append_to_tx(
+        &self,
+        tx_builder: &mut TransactionBuilder,
+        ixs: &mut PreparedInstructions,
+        new_ixs: PreparedInstructions,
+    ) -> anyhow::Result<bool> {
+        let mut combined = ixs.clone();
+        combined.append(new_ixs);
+
+        let exceeds_cu_limit = combined.cu > self.config.max_cu_per_transaction;
+        let exceeds_size_limit = {
+            tx_builder.instructions = combined.clone().to_instructions();
+            !tx_builder.transaction_size()?.is_ok()
+        };
+        if exceeds_cu_limit || exceeds_size_limit {
+            return Ok(false);
+        }
+
+        *ixs = combined;
+        Ok(true)
+    }
+
+    /// Force cancels perp and serum3 orders on as many markets as fit into one transaction
+    async fn close_orders(&self) -> anyhow::Result<Option<Signature>> {
+        let mut perp_force_cancels = self
+            .liqee
+            .active_perp_positions()
+            .filter_map(|pp| pp.has_open_orders().then_some(pp.market_index))
+            .collect::<Vec<PerpMarketIndex>>();
+        perp_force_cancels.shuffle(&mut rand::thread_rng());
+
         // look for any open serum orders or settleable balances
         let serum_oos: anyhow::Result<Vec<_>> = self
             .liqee
@@ -63,71 +94,54 @@ impl<'a> LiquidateHelper<'a> {
                 }
             })
             .collect::<Vec<_>>();
-        if serum_force_cancels.is_empty() {
+        serum_force_cancels.shuffle(&mut rand::thread_rng());
+
+        if perp_force_cancels.is_empty() && serum_force_cancels.is_empty() {
             return Ok(None);
         }
-        serum_force_cancels.shuffle(&mut rand::thread_rng());
 
         let mut ixs = PreparedInstructions::new();
-        let mut cancelled_markets = vec![];
+        let mut cancelled_perp_markets = vec![];
+        let mut cancelled_serum3_markets = vec![];
         let mut tx_builder = self.client.transaction_builder().await?;
 
-        for force_cancel in serum_force_cancels {
-            let mut new_ixs = ixs.clone();
-            new_ixs.append(
-                self.client
-                    .serum3_liq_force_cancel_orders_instruction(
-                        (self.pubkey, self.liqee),
-                        force_cancel.market_index,
-                        &force_cancel.open_orders,
-                    )
-                    .await?,
-            );
-
-            let exceeds_cu_limit = new_ixs.cu > self.config.max_cu_per_transaction;
-            let exceeds_size_limit = {
-                tx_builder.instructions = new_ixs.clone().to_instructions();
-                !tx_builder.transaction_size()?.is_ok()
-            };
-            if exceeds_cu_limit || exceeds_size_limit {
+        for perp_market_index in perp_force_cancels {
+            let new_ixs = self
+                .client
+                .perp_liq_force_cancel_orders_instruction(
+                    (self.pubkey, self.liqee),
+                    perp_market_index,
+                )
+                .await?;
+            if !self.try_append_to_tx(&mut tx_builder, &mut ixs, new_ixs)? {
                 break;
             }
+            cancelled_perp_markets.push(perp_market_index);
+        }
 
-            ixs = new_ixs;
-            cancelled_markets.push(force_cancel.market_index);
+        for force_cancel in serum_force_cancels {
+            let new_ixs = self
+                .client
+                .serum3_liq_force_cancel_orders_instruction(
+                    (self.pubkey, self.liqee),
+                    force_cancel.market_index,
+                    &force_cancel.open_orders,
+                )
+                .await?;
+            if !self.try_append_to_tx(&mut tx_builder, &mut ixs, new_ixs)? {
+                break;
+            }
+            cancelled_serum3_markets.push(force_cancel.market_index);
         }
 
         tx_builder.instructions = ixs.to_instructions();
 
         let txsig = tx_builder.send_and_confirm(&self.client.client).await?;
         info!(
-            market_indexes = ?cancelled_markets,
-            %txsig,
-            "Force cancelled serum orders",
-        );
-        Ok(Some(txsig))
-    }
-
-    async fn perp_close_orders(&self) -> anyhow::Result<Option<Signature>> {
-        let perp_force_cancels = self
-            .liqee
-            .active_perp_positions()
-            .filter_map(|pp| pp.has_open_orders().then_some(pp.market_index))
-            .collect::<Vec<PerpMarketIndex>>();
-        if perp_force_cancels.is_empty() {
-            return Ok(None);
-        }
-
-        // Cancel all orders on a random perp market
-        let perp_market_index = *perp_force_cancels.choose(&mut rand::thread_rng()).unwrap();
-        let txsig = self
-            .client
-            .perp_liq_force_cancel_orders((self.pubkey, self.liqee), perp_market_index)
-            .await?;
-        info!(
-            perp_market_index,
+            perp_market_indexes = ?cancelled_perp_markets,
+            serum3_market_indexes = ?cancelled_serum3_markets,
             %txsig,
-            "Force cancelled perp orders",
+            "Force cancelled orders",
         );
         Ok(Some(txsig))
     }
@@ -497,7 +511,7 @@ impl<'a> LiquidateHelper<'a> {
             })?
             .0;
 
-        let quote_token_index = 0;
+        let quote_token_index = self.client.context.insurance_token_index;
         let max_liab_transfer = self
             .max_token_liab_transfer(liab_token_index, quote_token_index)
             .await?;
@@ -539,11 +553,7 @@ impl<'a> LiquidateHelper<'a> {
         //
         // Phase 1: Try to close orders before touching the user's positions
         //
-        // TODO: All these close ix could be in one transaction.
-        if let Some(txsig) = self.perp_close_orders().await? {
-            return Ok(Some(txsig));
-        }
-        if let Some(txsig) = self.serum3_close_orders().await? {
+        if let Some(txsig) = self.close_orders().await? {
             return Ok(Some(txsig));
         }
 
@@ -83,6 +83,7 @@ impl AccountWriteSink for MangoV4PerpCrankSink {
                 group: self.group_pk,
                 perp_market: *mkt_pk,
                 event_queue: *pk,
+                keeper: None,
             },
             None,
         );
@@ -34,3 +34,17 @@ pub fn checked_math_or_panic(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Like checked_math(), but returns a `Result` with the given error on None results
+///
+/// Usage: `checked_math_or_err!(error, expr)`. The error expression is only evaluated
+/// on failure, which allows it to capture the invocation location, like anchor's
+/// `error!()` does. Assignments like `a += b` evaluate to `Result<(), _>`.
+#[proc_macro]
+#[proc_macro_error]
+pub fn checked_math_or_err(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as transform::checked::OrErrInput);
+    let expanded = transform::checked::transform_expr_or_err(input.error, input.expr);
+
+    TokenStream::from(expanded)
+}
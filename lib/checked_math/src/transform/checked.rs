@@ -1,6 +1,7 @@
 use proc_macro_error::abort;
 use quote::quote;
-use syn::{spanned::Spanned, BinOp, Expr, ExprBinary, ExprUnary, Ident, Lit, UnOp};
+use syn::parse::{Parse, ParseStream};
+use syn::{spanned::Spanned, BinOp, Expr, ExprBinary, ExprUnary, Ident, Lit, Token, UnOp};
 
 pub fn transform_expr_or_panic(expr: Expr) -> proc_macro2::TokenStream {
     match expr {
@@ -35,6 +36,57 @@ pub fn transform_expr_or_panic(expr: Expr) -> proc_macro2::TokenStream {
     }
 }
 
+/// Input of checked_math_or_err: `error, expr`
+pub struct OrErrInput {
+    pub error: Expr,
+    pub expr: Expr,
+}
+
+impl Parse for OrErrInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let error = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let expr = input.parse()?;
+        Ok(Self { error, expr })
+    }
+}
+
+pub fn transform_expr_or_err(error: Expr, expr: Expr) -> proc_macro2::TokenStream {
+    match expr {
+        Expr::Group(g) => transform_expr_or_err(error, *g.expr),
+        Expr::AssignOp(assign_op) => {
+            // Rewrite `left += right` into `match checked!(left + right) { .. }`
+            let bin_op = Expr::Binary(ExprBinary {
+                attrs: vec![],
+                left: assign_op.left.clone(),
+                right: assign_op.right.clone(),
+                op: match assign_op.op {
+                    BinOp::AddEq(t) => BinOp::Add(syn::token::Add(t.spans[0])),
+                    BinOp::SubEq(t) => BinOp::Sub(syn::token::Sub(t.spans[0])),
+                    BinOp::MulEq(t) => BinOp::Mul(syn::token::Star(t.spans[0])),
+                    BinOp::DivEq(t) => BinOp::Div(syn::token::Div(t.spans[0])),
+                    _ => panic!("unsupported AssignOp.op: {:#?}", assign_op.op),
+                },
+            });
+            let left = assign_op.left;
+            let bin_op_tokens = transform_expr(bin_op);
+            quote! {
+                match #bin_op_tokens {
+                    Some(v) => {
+                        #left = v;
+                        Ok(())
+                    }
+                    None => Err(#error),
+                }
+            }
+        }
+        _ => {
+            let toks = transform_expr(expr);
+            quote! { (#toks).ok_or_else(|| #error) }
+        }
+    }
+}
+
 pub fn transform_expr(mut expr: Expr) -> proc_macro2::TokenStream {
     match expr {
         Expr::Unary(unary) => transform_unary(unary),
@@ -153,3 +205,30 @@ fn transform_binary(binary: ExprBinary) -> proc_macro2::TokenStream {
             }
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_or_err(input: &str) -> String {
+        let input: OrErrInput = syn::parse_str(input).unwrap();
+        transform_expr_or_err(input.error, input.expr)
+            .to_string()
+            .replace(' ', "")
+    }
+
+    #[test]
+    fn test_or_err_expression() {
+        let expanded = expand_or_err("MyError::Overflow, a + b");
+        assert!(expanded.contains("checked_add"));
+        assert!(expanded.contains("ok_or_else(||MyError::Overflow)"));
+    }
+
+    #[test]
+    fn test_or_err_assign() {
+        let expanded = expand_or_err("err(), a -= b");
+        assert!(expanded.contains("checked_sub"));
+        assert!(expanded.contains("a=v;Ok(())"));
+        assert!(expanded.contains("None=>Err(err())"));
+    }
+}
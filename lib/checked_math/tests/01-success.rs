@@ -1,4 +1,4 @@
-use checked_math::{checked_math, checked_math_or_panic};
+use checked_math::{checked_math, checked_math_or_err, checked_math_or_panic};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -6,6 +6,9 @@ fn f() -> u8 {
     3u8
 }
 
+#[derive(Debug, PartialEq)]
+struct MathError(u32);
+
 struct S {}
 impl S {
     fn m(&self) -> u8 {
@@ -45,6 +48,20 @@ fn main() {
     checked_math_or_panic!(m += 4);
     assert_eq!(m, 6);
 
+    let r = checked_math_or_err!(MathError(0), num + 4u8);
+    assert_eq!(r, Ok(6));
+
+    // the error expression is evaluated at the invocation site
+    let line = line!();
+    let r = checked_math_or_err!(MathError(line!()), num * 200u8);
+    assert_eq!(r, Err(MathError(line + 1)));
+
+    let mut m = 250u8;
+    assert_eq!(checked_math_or_err!(MathError(0), m += 4), Ok(()));
+    assert_eq!(m, 254);
+    assert_eq!(checked_math_or_err!(MathError(1), m += 4), Err(MathError(1)));
+    assert_eq!(m, 254);
+
     let g = Rc::new(RefCell::new(0u8));
     let single_eval_test = || -> Rc<RefCell<u8>> {
         *g.borrow_mut() += 1;
@@ -21,7 +21,7 @@ use mango_v4::accounts_zerocopy::KeyedAccountSharedData;
 use mango_v4::health::HealthCache;
 use mango_v4::state::{
     Bank, Group, MangoAccountValue, OracleAccountInfos, PerpMarket, PerpMarketIndex,
-    PlaceOrderType, SelfTradeBehavior, Serum3MarketIndex, Side, TokenIndex, INSURANCE_TOKEN_INDEX,
+    PlaceOrderType, SelfTradeBehavior, Serum3MarketIndex, Side, TokenIndex,
 };
 
 use crate::account_fetcher::*;
@@ -29,6 +29,7 @@ use crate::confirm_transaction::{wait_for_transaction_confirmation, RpcConfirmTr
 use crate::context::MangoGroupContext;
 use crate::gpa::{fetch_anchor_account, fetch_mango_accounts};
 use crate::health_cache;
+use crate::pda;
 use crate::util::PreparedInstructions;
 use crate::{jupiter, util};
 use solana_address_lookup_table_program::state::AddressLookupTable;
@@ -270,11 +271,7 @@ pub struct MangoClient {
 
 impl MangoClient {
     pub fn group_for_admin(admin: Pubkey, num: u32) -> Pubkey {
-        Pubkey::find_program_address(
-            &["Group".as_ref(), admin.as_ref(), num.to_le_bytes().as_ref()],
-            &mango_v4::ID,
-        )
-        .0
+        pda::group_address(&admin, num)
     }
 
     pub async fn find_accounts(
@@ -339,16 +336,7 @@ impl MangoClient {
         account_num: u32,
         mango_account_name: &str,
     ) -> anyhow::Result<(Pubkey, Signature)> {
-        let account = Pubkey::find_program_address(
-            &[
-                b"MangoAccount".as_ref(),
-                group.as_ref(),
-                owner.pubkey().as_ref(),
-                &account_num.to_le_bytes(),
-            ],
-            &mango_v4::id(),
-        )
-        .0;
+        let account = pda::mango_account_address(&group, &owner.pubkey(), account_num);
         let ix = Instruction {
             program_id: mango_v4::id(),
             accounts: anchor_lang::ToAccountMetas::to_account_metas(
@@ -358,6 +346,7 @@ impl MangoClient {
                     account,
                     payer: payer.pubkey(),
                     system_program: System::id(),
+                    referrer: None,
                 },
                 None,
             ),
@@ -666,15 +655,7 @@ impl MangoClient {
         let account_pubkey = self.mango_account_address;
         let s3 = self.context.serum3(market_index);
 
-        let open_orders = Pubkey::find_program_address(
-            &[
-                b"Serum3OO".as_ref(),
-                account_pubkey.as_ref(),
-                s3.address.as_ref(),
-            ],
-            &mango_v4::ID,
-        )
-        .0;
+        let open_orders = pda::serum3_open_orders_address(&account_pubkey, &s3.address);
 
         Instruction {
             program_id: mango_v4::id(),
@@ -1273,11 +1254,11 @@ impl MangoClient {
             .await
     }
 
-    pub async fn perp_liq_force_cancel_orders(
+    pub async fn perp_liq_force_cancel_orders_instruction(
         &self,
         liqee: (&Pubkey, &MangoAccountValue),
         market_index: PerpMarketIndex,
-    ) -> anyhow::Result<Signature> {
+    ) -> anyhow::Result<PreparedInstructions> {
         let perp = self.context.perp(market_index);
 
         let (health_remaining_ams, health_cu) = self
@@ -1310,6 +1291,17 @@ impl MangoClient {
             self.instruction_cu(health_cu)
                 + self.context.compute_estimates.cu_per_perp_order_cancel * limit as u32,
         );
+        Ok(ixs)
+    }
+
+    pub async fn perp_liq_force_cancel_orders(
+        &self,
+        liqee: (&Pubkey, &MangoAccountValue),
+        market_index: PerpMarketIndex,
+    ) -> anyhow::Result<Signature> {
+        let ixs = self
+            .perp_liq_force_cancel_orders_instruction(liqee, market_index)
+            .await?;
         self.send_and_confirm_permissionless_tx(ixs.to_instructions())
             .await
     }
@@ -1383,13 +1375,14 @@ impl MangoClient {
         let mango_account = &self.mango_account().await?;
         let perp = self.context.perp(market_index);
         let settle_token_info = self.context.token(perp.settle_token_index);
-        let insurance_token_info = self.context.token(INSURANCE_TOKEN_INDEX);
+        let insurance_token_index = self.context.insurance_token_index;
+        let insurance_token_info = self.context.token(insurance_token_index);
 
         let (health_remaining_ams, health_cu) = self
             .derive_health_check_remaining_account_metas_two_accounts(
                 mango_account,
                 liqee.1,
-                &[INSURANCE_TOKEN_INDEX],
+                &[insurance_token_index],
                 &[],
             )
             .await
@@ -1486,7 +1479,7 @@ impl MangoClient {
         max_liab_transfer: I80F48,
     ) -> anyhow::Result<PreparedInstructions> {
         let mango_account = &self.mango_account().await?;
-        let quote_token_index = 0;
+        let quote_token_index = self.context.insurance_token_index;
 
         let quote_info = self.context.token(quote_token_index);
         let liab_info = self.context.token(liab_token_index);
@@ -1501,7 +1494,7 @@ impl MangoClient {
             .derive_health_check_remaining_account_metas_two_accounts(
                 mango_account,
                 liqee.1,
-                &[INSURANCE_TOKEN_INDEX],
+                &[quote_token_index],
                 &[quote_token_index, liab_token_index],
             )
             .await
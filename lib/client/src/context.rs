@@ -41,7 +41,7 @@ pub struct TokenContext {
 
 impl TokenContext {
     pub fn native_to_ui(&self, native: I80F48) -> f64 {
-        (native / I80F48::from(10u64.pow(self.decimals.into()))).to_num()
+        mango_v4::util::native_to_ui(native, self.decimals).to_num()
     }
 
     pub fn first_bank(&self) -> Pubkey {
@@ -185,6 +185,8 @@ pub struct MangoGroupContext {
 
     pub address_lookup_tables: Vec<Pubkey>,
 
+    pub insurance_token_index: TokenIndex,
+
     pub compute_estimates: ComputeEstimates,
 }
 
@@ -390,6 +392,7 @@ impl MangoGroupContext {
             perp_markets,
             perp_market_indexes_by_name,
             address_lookup_tables,
+            insurance_token_index: group_data.insurance_token_index,
             compute_estimates: ComputeEstimates::default(),
         })
     }
@@ -14,6 +14,7 @@ pub mod error_tracking;
 pub mod gpa;
 pub mod health_cache;
 pub mod jupiter;
+pub mod pda;
 pub mod perp_pnl;
 pub mod snapshot_source;
 mod util;
@@ -0,0 +1,109 @@
+//! Addresses of the program derived accounts of the mango program.
+//!
+//! The seeds must match the ones used in the `accounts_ix` account constraints.
+
+use anchor_lang::prelude::Pubkey;
+use mango_v4::state::{PerpMarket, PerpMarketIndex, Serum3MarketIndex, TokenIndex};
+
+pub fn group_address(creator: &Pubkey, group_num: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"Group".as_ref(),
+            creator.as_ref(),
+            &group_num.to_le_bytes(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn insurance_vault_address(group: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"InsuranceVault".as_ref(), group.as_ref()], &mango_v4::ID).0
+}
+
+pub fn mango_account_address(group: &Pubkey, owner: &Pubkey, account_num: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"MangoAccount".as_ref(),
+            group.as_ref(),
+            owner.as_ref(),
+            &account_num.to_le_bytes(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn bank_address(group: &Pubkey, token_index: TokenIndex, bank_num: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"Bank".as_ref(),
+            group.as_ref(),
+            &token_index.to_le_bytes(),
+            &bank_num.to_le_bytes(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn vault_address(group: &Pubkey, token_index: TokenIndex, bank_num: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"Vault".as_ref(),
+            group.as_ref(),
+            &token_index.to_le_bytes(),
+            &bank_num.to_le_bytes(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn mint_info_address(group: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"MintInfo".as_ref(), group.as_ref(), mint.as_ref()],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn perp_market_address(group: &Pubkey, perp_market_index: PerpMarketIndex) -> Pubkey {
+    PerpMarket::address(group, perp_market_index).0
+}
+
+pub fn serum3_market_address(group: &Pubkey, serum_market_external: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"Serum3Market".as_ref(),
+            group.as_ref(),
+            serum_market_external.as_ref(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn serum3_index_address(group: &Pubkey, market_index: Serum3MarketIndex) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"Serum3Index".as_ref(),
+            group.as_ref(),
+            &market_index.to_le_bytes(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
+
+pub fn serum3_open_orders_address(account: &Pubkey, serum3_market: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"Serum3OO".as_ref(),
+            account.as_ref(),
+            serum3_market.as_ref(),
+        ],
+        &mango_v4::ID,
+    )
+    .0
+}
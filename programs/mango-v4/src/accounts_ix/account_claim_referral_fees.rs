@@ -0,0 +1,40 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AccountClaimReferralFees<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::AccountClaimReferralFees) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    /// The referred account
+    #[account(
+        mut,
+        has_one = group,
+        has_one = referrer,
+    )]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = referrer.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub referrer: AccountLoader<'info, MangoAccountFixed>,
+
+    /// The bank the payout is deposited into.
+    ///
+    /// Without perp_market, the payout comes out of this bank's loan origination fees.
+    #[account(mut, has_one = group)]
+    pub bank: AccountLoader<'info, Bank>,
+
+    /// If set, the payout comes out of this market's settled fees instead
+    #[account(
+        mut,
+        has_one = group,
+        constraint = perp_market.load()?.settle_token_index == bank.load()?.token_index,
+    )]
+    pub perp_market: Option<AccountLoader<'info, PerpMarket>>,
+}
@@ -24,6 +24,10 @@ pub struct AccountCreate<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// The MangoAccount that referred the new account, see MangoAccount::referrer
+    #[account(has_one = group)]
+    pub referrer: Option<AccountLoader<'info, MangoAccountFixed>>,
 }
 
 #[derive(Accounts)]
@@ -48,4 +52,8 @@ pub struct AccountCreateV2<'info> {
     pub payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// The MangoAccount that referred the new account, see MangoAccount::referrer
+    #[account(has_one = group)]
+    pub referrer: Option<AccountLoader<'info, MangoAccountFixed>>,
 }
@@ -0,0 +1,21 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Ensures the DAO account has token positions for the banks passed in remaining accounts
+#[derive(Accounts)]
+pub struct AccountCreateDaoTokenPositions<'info> {
+    #[account(
+        has_one = admin,
+    )]
+    pub group: AccountLoader<'info, Group>,
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = dao_account.load()?.is_operational() @ MangoError::AccountIsFrozen,
+        address = group.load()?.buyback_fees_swap_mango_account
+    )]
+    pub dao_account: AccountLoader<'info, MangoAccountFixed>,
+}
@@ -6,7 +6,7 @@ use anchor_lang::prelude::*;
 pub struct AccountToggleFreeze<'info> {
     #[account(
         constraint = group.load()?.is_ix_enabled(IxGate::AccountToggleFreeze) @ MangoError::IxIsDisabled,
-        constraint = group.load()?.admin == admin.key()
+        // group <-> admin relation is checked at #1
     )]
     pub group: AccountLoader<'info, Group>,
 
@@ -0,0 +1,28 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_address_lookup_table_program as solana_alt;
+
+#[derive(Accounts)]
+pub struct AltCreate<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = group.load()?.is_ix_enabled(IxGate::AltCreate) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: ALT address is checked inline
+    #[account(mut)]
+    pub address_lookup_table: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: address is checked
+    #[account(address = solana_alt::ID)]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+}
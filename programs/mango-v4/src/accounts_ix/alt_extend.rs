@@ -11,6 +11,8 @@ pub struct AltExtend<'info> {
     )]
     pub group: AccountLoader<'info, Group>,
     pub admin: Signer<'info>,
+
+    #[account(mut)]
     pub payer: Signer<'info>,
 
     /// CHECK: ALT address is checked inline
@@ -19,4 +21,6 @@ pub struct AltExtend<'info> {
         owner = solana_alt::ID,
     )]
     pub address_lookup_table: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,10 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ComputeHealthBreakdown<'info> {
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(has_one = group)]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+}
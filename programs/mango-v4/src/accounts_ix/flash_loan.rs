@@ -82,7 +82,7 @@ pub struct FlashLoanEnd<'info> {
         mut,
         constraint = account.load()?.is_operational() @ MangoError::AccountIsFrozen
     )]
-    pub account: AccountLoader<'info, MangoAccountFixed>,
+    pub account: Box<AccountLoader<'info, MangoAccountFixed>>,
     // owner is checked at #1
     pub owner: Signer<'info>,
 
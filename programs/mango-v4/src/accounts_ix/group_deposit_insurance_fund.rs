@@ -0,0 +1,35 @@
+use crate::{error::MangoError, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct GroupDepositInsuranceFund<'info> {
+    #[account(
+        has_one = insurance_vault,
+        constraint = group.load()?.is_ix_enabled(IxGate::GroupDepositInsuranceFund) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(mut)]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    // the mint is implicitly the same as the insurance vault's because
+    // spl::token::transfer succeeds
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+    pub token_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> GroupDepositInsuranceFund<'info> {
+    pub fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let program = self.token_program.to_account_info();
+        let accounts = token::Transfer {
+            from: self.token_account.to_account_info(),
+            to: self.insurance_vault.to_account_info(),
+            authority: self.token_authority.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Permissionless, see Group::migrate
+#[derive(Accounts)]
+pub struct GroupMigrate<'info> {
+    #[account(
+        mut,
+        constraint = group.load()?.is_ix_enabled(IxGate::GroupMigrate) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+}
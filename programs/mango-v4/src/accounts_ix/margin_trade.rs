@@ -0,0 +1,42 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+pub mod spl_token_2022 {
+    use solana_program::declare_id;
+    declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+}
+
+/// Swaps tokens directly out of and into the bank vaults, by cpi-ing into an
+/// allowed swap program with the group as signer
+///
+/// In addition to these accounts, there must be remaining_accounts:
+/// 1. health accounts (num_health_accounts), banks whose vault is used must be writable
+/// 2. the accounts for the swap program instruction; the group signs for the vaults
+///
+/// Every token account the group has authority over that is passed to the swap
+/// program must be the vault of a bank in the health accounts. Delegates may only
+/// use the Jupiter swap programs.
+#[derive(Accounts)]
+pub struct MarginTrade<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::MarginTrade) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = account.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+    // owner is checked at #1
+    pub owner: Signer<'info>,
+
+    /// CHECK: checked against the group's allowed swap programs
+    #[account(
+        executable,
+        constraint = group.load()?.is_swap_program_allowed(swap_program.key) @ MangoError::SwapProgramNotAllowed,
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::error::*;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -8,6 +9,7 @@ pub struct OpenbookV2EditMarket<'info> {
     #[account(
         constraint = group.load()?.openbook_v2_supported(),
         constraint = group.load()?.admin == admin.key(),
+        constraint = group.load()?.is_ix_enabled(IxGate::OpenbookV2EditMarket) @ MangoError::IxIsDisabled,
     )]
     pub group: AccountLoader<'info, Group>,
 
@@ -18,4 +18,12 @@ pub struct PerpConsumeEvents<'info> {
 
     #[account(mut)]
     pub event_queue: AccountLoader<'info, EventQueue>,
+
+    /// Account that receives the keeper reward, see PerpMarket::keeper_reward_per_event
+    #[account(
+        mut,
+        has_one = group,
+        constraint = keeper.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub keeper: Option<AccountLoader<'info, MangoAccountFixed>>,
 }
@@ -0,0 +1,58 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+/// Closes a bankrupt account's perp base position against an opposing position
+/// that is in profit, at the liqee's bankruptcy price.
+///
+/// Without a base position, the counterparty takes over the liqee's negative pnl instead.
+///
+/// Only possible when the insurance fund can no longer cover the liqee's losses.
+/// The counterparty must be the market's adl candidate, see perp_update_adl_candidate.
+///
+/// In addition to these accounts, the health accounts of the liqee and the counterparty
+/// must be passed as remaining_accounts, see ScanningAccountRetriever.
+#[derive(Accounts)]
+pub struct PerpLiqAutoDeleverage<'info> {
+    #[account(
+        has_one = insurance_vault,
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpLiqAutoDeleverage) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(mut, has_one = group, has_one = oracle)]
+    pub perp_market: AccountLoader<'info, PerpMarket>,
+
+    /// CHECK: Oracle can have different account types, constrained by address in perp_market
+    pub oracle: UncheckedAccount<'info>,
+
+    // This account MUST be bankrupt
+    #[account(
+        mut,
+        has_one = group,
+        constraint = liqee.load()?.is_operational() @ MangoError::AccountIsFrozen,
+        constraint = liqee.key() != counterparty.key(),
+    )]
+    pub liqee: AccountLoader<'info, MangoAccountFixed>,
+
+    // This account MUST be the adl candidate with positive pnl, opposing the liqee's position
+    #[account(
+        mut,
+        has_one = group,
+        constraint = counterparty.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub counterparty: AccountLoader<'info, MangoAccountFixed>,
+
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        has_one = group,
+        constraint = insurance_bank.load()?.token_index == group.load()?.insurance_token_index
+    )]
+    pub insurance_bank: AccountLoader<'info, Bank>,
+
+    /// CHECK: Oracle can have different account types
+    #[account(address = insurance_bank.load()?.oracle)]
+    pub insurance_oracle: UncheckedAccount<'info>,
+}
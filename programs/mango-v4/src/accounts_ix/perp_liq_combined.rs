@@ -0,0 +1,65 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct PerpLiqCombined<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpLiqCombined) @ MangoError::IxIsDisabled,
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpLiqForceCancelOrders) @ MangoError::IxIsDisabled,
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpLiqBaseOrPositivePnl) @ MangoError::IxIsDisabled,
+        constraint = group.load()?.is_ix_enabled(IxGate::TokenLiqWithToken) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = oracle,
+        has_one = bids,
+        has_one = asks,
+    )]
+    pub perp_market: AccountLoader<'info, PerpMarket>,
+
+    /// CHECK: Oracle can have different account types, constrained by address in perp_market
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = liqor.load()?.is_operational() @ MangoError::AccountIsFrozen
+        // liqor_owner is checked in the liquidation steps
+    )]
+    pub liqor: AccountLoader<'info, MangoAccountFixed>,
+    pub liqor_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = liqee.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub liqee: AccountLoader<'info, MangoAccountFixed>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = settle_bank.load()?.token_index == perp_market.load()?.settle_token_index @ MangoError::InvalidBank
+    )]
+    pub settle_bank: AccountLoader<'info, Bank>,
+
+    #[account(
+        mut,
+        address = settle_bank.load()?.vault
+    )]
+    pub settle_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Oracle can have different account types
+    #[account(address = settle_bank.load()?.oracle)]
+    pub settle_oracle: UncheckedAccount<'info>,
+}
@@ -45,7 +45,7 @@ pub struct PerpLiqNegativePnlOrBankruptcy<'info> {
         mut,
         address = settle_bank.load()?.vault
     )]
-    pub settle_vault: Account<'info, TokenAccount>,
+    pub settle_vault: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: Oracle can have different account types
     #[account(address = settle_bank.load()?.oracle)]
@@ -54,7 +54,7 @@ pub struct PerpLiqNegativePnlOrBankruptcy<'info> {
     // future: this would be an insurance fund vault specific to a
     // trustless token, separate from the shared one on the group
     #[account(mut)]
-    pub insurance_vault: Account<'info, TokenAccount>,
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token>,
 }
@@ -101,7 +101,7 @@ pub struct PerpLiqNegativePnlOrBankruptcyV2<'info> {
         mut,
         address = settle_bank.load()?.vault
     )]
-    pub settle_vault: Account<'info, TokenAccount>,
+    pub settle_vault: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: Oracle can have different account types
     #[account(address = settle_bank.load()?.oracle)]
@@ -110,12 +110,12 @@ pub struct PerpLiqNegativePnlOrBankruptcyV2<'info> {
     // future: this would be an insurance fund vault specific to a
     // trustless token, separate from the shared one on the group
     #[account(mut)]
-    pub insurance_vault: Account<'info, TokenAccount>,
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
         has_one = group,
-        constraint = insurance_bank.load()?.token_index == INSURANCE_TOKEN_INDEX
+        constraint = insurance_bank.load()?.token_index == group.load()?.insurance_token_index
     )]
     pub insurance_bank: AccountLoader<'info, Bank>,
 
@@ -123,7 +123,7 @@ pub struct PerpLiqNegativePnlOrBankruptcyV2<'info> {
         mut,
         address = insurance_bank.load()?.vault
     )]
-    pub insurance_bank_vault: Account<'info, TokenAccount>,
+    pub insurance_bank_vault: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: Oracle can have different account types
     #[account(address = insurance_bank.load()?.oracle)]
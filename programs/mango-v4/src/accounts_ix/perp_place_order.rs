@@ -15,7 +15,7 @@ pub struct PerpPlaceOrder<'info> {
         constraint = account.load()?.is_operational() @ MangoError::AccountIsFrozen
         // owner is checked at #1
     )]
-    pub account: AccountLoader<'info, MangoAccountFixed>,
+    pub account: Box<AccountLoader<'info, MangoAccountFixed>>,
     pub owner: Signer<'info>,
 
     #[account(
@@ -26,13 +26,13 @@ pub struct PerpPlaceOrder<'info> {
         has_one = event_queue,
         has_one = oracle,
     )]
-    pub perp_market: AccountLoader<'info, PerpMarket>,
+    pub perp_market: Box<AccountLoader<'info, PerpMarket>>,
     #[account(mut)]
-    pub bids: AccountLoader<'info, BookSide>,
+    pub bids: Box<AccountLoader<'info, BookSide>>,
     #[account(mut)]
-    pub asks: AccountLoader<'info, BookSide>,
+    pub asks: Box<AccountLoader<'info, BookSide>>,
     #[account(mut)]
-    pub event_queue: AccountLoader<'info, EventQueue>,
+    pub event_queue: Box<AccountLoader<'info, EventQueue>>,
 
     /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
     pub oracle: UncheckedAccount<'info>,
@@ -0,0 +1,53 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// The parameters of one order in perp_place_orders, see perp_place_order_v2
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct PerpPlaceOrderArgs {
+    pub side: Side,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots: i64,
+    pub client_order_id: u64,
+    pub order_type: PlaceOrderType,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub reduce_only: bool,
+    pub expiry_timestamp: u64,
+}
+
+#[derive(Accounts)]
+pub struct PerpPlaceOrders<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpPlaceOrders) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = account.load()?.is_operational() @ MangoError::AccountIsFrozen
+        // owner is checked at #1
+    )]
+    pub account: Box<AccountLoader<'info, MangoAccountFixed>>,
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = bids,
+        has_one = asks,
+        has_one = event_queue,
+        has_one = oracle,
+    )]
+    pub perp_market: Box<AccountLoader<'info, PerpMarket>>,
+    #[account(mut)]
+    pub bids: Box<AccountLoader<'info, BookSide>>,
+    #[account(mut)]
+    pub asks: Box<AccountLoader<'info, BookSide>>,
+    #[account(mut)]
+    pub event_queue: Box<AccountLoader<'info, EventQueue>>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle: UncheckedAccount<'info>,
+}
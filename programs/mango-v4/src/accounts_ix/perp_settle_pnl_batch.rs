@@ -0,0 +1,40 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Settles pnl between many account pairs of the same perp market
+///
+/// In addition to these accounts, there must be remaining_accounts:
+/// 1. 2 * num_pairs mango accounts (writable): for each pair first the profitable
+///    account a, then the unprofitable account b
+/// 2. health accounts covering all of these mango accounts, in any order
+#[derive(Accounts)]
+pub struct PerpSettlePnlBatch<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpSettlePnlBatch) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = settler.load()?.is_operational() @ MangoError::AccountIsFrozen
+        // settler_owner is checked at #1
+    )]
+    pub settler: AccountLoader<'info, MangoAccountFixed>,
+    pub settler_owner: Signer<'info>,
+
+    #[account(has_one = group, has_one = oracle)]
+    pub perp_market: AccountLoader<'info, PerpMarket>,
+
+    /// CHECK: Oracle can have different account types, constrained by address in perp_market
+    pub oracle: UncheckedAccount<'info>,
+
+    // bank correctness is checked at #2
+    #[account(mut, has_one = group)]
+    pub settle_bank: AccountLoader<'info, Bank>,
+
+    /// CHECK: Oracle can have different account types
+    #[account(address = settle_bank.load()?.oracle)]
+    pub settle_oracle: UncheckedAccount<'info>,
+}
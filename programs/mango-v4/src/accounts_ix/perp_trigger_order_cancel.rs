@@ -0,0 +1,29 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PerpTriggerOrderCancel<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpTriggerOrderCancel) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        has_one = group,
+        constraint = account.load()?.is_owner_or_delegate(authority.key()),
+    )]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = account,
+        close = sol_destination
+    )]
+    pub trigger_order: AccountLoader<'info, PerpTriggerOrder>,
+
+    #[account(mut)]
+    /// CHECK: target for account rent needs no checks
+    pub sol_destination: UncheckedAccount<'info>,
+}
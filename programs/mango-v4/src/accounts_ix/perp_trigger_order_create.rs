@@ -0,0 +1,37 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(order_id: u64)]
+pub struct PerpTriggerOrderCreate<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpTriggerOrderCreate) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        has_one = group,
+        constraint = account.load()?.is_operational() @ MangoError::AccountIsFrozen,
+        constraint = account.load()?.is_owner_or_delegate(authority.key()),
+    )]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+    pub authority: Signer<'info>,
+
+    #[account(has_one = group)]
+    pub perp_market: AccountLoader<'info, PerpMarket>,
+
+    #[account(
+        init,
+        seeds = [b"PerpTriggerOrder".as_ref(), account.key().as_ref(), &order_id.to_le_bytes()],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<PerpTriggerOrder>(),
+    )]
+    pub trigger_order: AccountLoader<'info, PerpTriggerOrder>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,65 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Anyone can execute a triggered order. The rent of the trigger order account
+/// goes to the executor and the executor incentive to the executor's account.
+///
+/// The health accounts of the mango account are passed in remaining_accounts.
+#[derive(Accounts)]
+pub struct PerpTriggerOrderExecute<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpTriggerOrderExecute) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = account.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = bids,
+        has_one = asks,
+        has_one = event_queue,
+        has_one = oracle,
+    )]
+    pub perp_market: AccountLoader<'info, PerpMarket>,
+    #[account(mut)]
+    pub bids: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub asks: AccountLoader<'info, BookSide>,
+    #[account(mut)]
+    pub event_queue: AccountLoader<'info, EventQueue>,
+
+    /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = account,
+        constraint = trigger_order.load()?.perp_market_index == perp_market.load()?.perp_market_index,
+        close = executor
+    )]
+    pub trigger_order: AccountLoader<'info, PerpTriggerOrder>,
+
+    #[account(
+        mut,
+        has_one = group,
+        constraint = executor_account.load()?.is_operational() @ MangoError::AccountIsFrozen,
+        constraint = executor_account.load()?.is_owner_or_delegate(executor.key()),
+        constraint = executor_account.key() != account.key(),
+    )]
+    pub executor_account: AccountLoader<'info, MangoAccountFixed>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    // bank correctness is checked at #1
+    #[account(mut, has_one = group)]
+    pub settle_bank: AccountLoader<'info, Bank>,
+}
@@ -0,0 +1,25 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Makes an account the auto-deleveraging candidate for its side of a perp market
+/// if it ranks above the current candidate, or refreshes the current candidate.
+///
+/// In addition to these accounts, the account's health accounts must be passed as
+/// remaining_accounts, see ScanningAccountRetriever.
+#[derive(Accounts)]
+pub struct PerpUpdateAdlCandidate<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::PerpUpdateAdlCandidate) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(mut, has_one = group, has_one = oracle)]
+    pub perp_market: AccountLoader<'info, PerpMarket>,
+
+    /// CHECK: Oracle can have different account types, constrained by address in perp_market
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(has_one = group)]
+    pub account: AccountLoader<'info, MangoAccountFixed>,
+}
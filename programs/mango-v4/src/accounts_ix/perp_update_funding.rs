@@ -24,4 +24,12 @@ pub struct PerpUpdateFunding<'info> {
 
     /// CHECK: The oracle can be one of several different account types and the pubkey is checked above
     pub oracle: UncheckedAccount<'info>,
+
+    /// Account that receives the keeper reward, see PerpMarket::keeper_reward_funding_per_hour
+    #[account(
+        mut,
+        has_one = group,
+        constraint = keeper.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub keeper: Option<AccountLoader<'info, MangoAccountFixed>>,
 }
@@ -31,7 +31,19 @@ pub enum Serum3Side {
     Ask = 1,
 }
 
-// Used for Serum3PlaceOrder v1 and v2
+/// The parameters of one order in serum3_place_orders
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct Serum3PlaceOrderArgs {
+    pub limit_price_lots: u64,
+    pub max_base_qty: u64,
+    pub max_native_quote_qty_including_fees: u64,
+    pub self_trade_behavior: Serum3SelfTradeBehavior,
+    pub order_type: Serum3OrderType,
+    pub client_order_id: u64,
+    pub limit: u16,
+}
+
+// Used for Serum3PlaceOrder v1 and v2 and Serum3PlaceOrders
 #[derive(Accounts)]
 pub struct Serum3PlaceOrder<'info> {
     // ix gate check is done at #4
@@ -25,6 +25,7 @@ pub struct Serum3SettleFunds<'info> {
     pub open_orders: UncheckedAccount<'info>,
 
     #[account(
+        mut,
         has_one = group,
         has_one = serum_program,
         has_one = serum_market_external,
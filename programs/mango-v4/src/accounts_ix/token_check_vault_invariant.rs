@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::error::*;
+use crate::state::*;
+
+/// Permissionless check that a bank's vault holds at least what the bank owes.
+#[derive(Accounts)]
+pub struct TokenCheckVaultInvariant<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::TokenCheckVaultInvariant) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+        has_one = vault,
+    )]
+    pub bank: AccountLoader<'info, Bank>,
+
+    pub vault: Account<'info, TokenAccount>,
+}
@@ -41,12 +41,12 @@ pub struct TokenLiqBankruptcy<'info> {
     #[account(mut)]
     // address is checked at #2 a) and b)
     // better name would be "insurance_bank_vault"
-    pub quote_vault: Account<'info, TokenAccount>,
+    pub quote_vault: Box<Account<'info, TokenAccount>>,
 
     // future: this would be an insurance fund vault specific to a
     // trustless token, separate from the shared one on the group
     #[account(mut)]
-    pub insurance_vault: Account<'info, TokenAccount>,
+    pub insurance_vault: Box<Account<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token>,
 }
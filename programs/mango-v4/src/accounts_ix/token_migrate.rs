@@ -0,0 +1,18 @@
+use crate::error::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+/// Permissionless, see Bank::migrate
+#[derive(Accounts)]
+pub struct TokenMigrate<'info> {
+    #[account(
+        constraint = group.load()?.is_ix_enabled(IxGate::TokenMigrate) @ MangoError::IxIsDisabled,
+    )]
+    pub group: AccountLoader<'info, Group>,
+
+    #[account(
+        mut,
+        has_one = group,
+    )]
+    pub bank: AccountLoader<'info, Bank>,
+}
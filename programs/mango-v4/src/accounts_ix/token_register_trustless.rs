@@ -61,3 +61,17 @@ pub struct TokenRegisterTrustless<'info> {
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
+
+/// Parameter presets that token_register_trustless can list a token with.
+///
+/// The parameters of each tier are hard-coded, so listings that don't go
+/// through governance can't set weights beyond what the Premium tier allows.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum FastListingTier {
+    /// No collateral value, deposit-only
+    CollateralDisabled,
+    /// Small collateral value and low limits
+    Midwit,
+    /// For liquid tokens with reliable oracles
+    Premium,
+}
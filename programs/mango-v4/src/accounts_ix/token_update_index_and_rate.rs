@@ -35,4 +35,12 @@ pub struct TokenUpdateIndexAndRate<'info> {
     /// CHECK: fixed instructions sysvar account
     #[account(address = tx_instructions::ID)]
     pub instructions: UncheckedAccount<'info>,
+
+    /// Account that receives the keeper reward, see Bank::keeper_reward_per_hour
+    #[account(
+        mut,
+        has_one = group,
+        constraint = keeper.load()?.is_operational() @ MangoError::AccountIsFrozen
+    )]
+    pub keeper: Option<AccountLoader<'info, MangoAccountFixed>>,
 }
@@ -171,6 +171,28 @@ impl KeyedAccountReader for KeyedAccountSharedData {
 // Common traits for loading from account data.
 //
 
+/// Casts the data after the discriminator to T.
+///
+/// Errors instead of panicking if the data is too short or misaligned. Data from the
+/// runtime is always aligned, but account data in client code may not be.
+fn from_bytes_checked<T: ZeroCopy>(data: &[u8]) -> Result<&T> {
+    let end = mem::size_of::<T>() + 8;
+    if data.len() < end {
+        return Err(ErrorCode::AccountDidNotDeserialize.into());
+    }
+    bytemuck::try_from_bytes(&data[8..end]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Same as from_bytes_checked(), but mut
+fn from_bytes_mut_checked<T: ZeroCopy>(data: &mut [u8]) -> Result<&mut T> {
+    let end = mem::size_of::<T>() + 8;
+    if data.len() < end {
+        return Err(ErrorCode::AccountDidNotDeserialize.into());
+    }
+    bytemuck::try_from_bytes_mut(&mut data[8..end])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
 pub trait LoadZeroCopy {
     /// Using AccountLoader forces a AccountInfo.clone() and then binds the loaded
     /// lifetime to the AccountLoader's lifetime. This function avoids both.
@@ -179,6 +201,9 @@ pub trait LoadZeroCopy {
 
     /// Same as load(), but doesn't check the discriminator or owner.
     fn load_fully_unchecked<T: ZeroCopy + Owner>(&self) -> Result<&T>;
+
+    /// Same as load(), but returns a copy. Works for data without alignment guarantees.
+    fn load_unaligned<T: ZeroCopy + Owner>(&self) -> Result<T>;
 }
 
 pub trait LoadMutZeroCopy {
@@ -222,13 +247,32 @@ impl<A: AccountReader> LoadZeroCopy for A {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
-        Ok(bytemuck::from_bytes(&data[8..mem::size_of::<T>() + 8]))
+        from_bytes_checked(data)
     }
 
     fn load_fully_unchecked<T: ZeroCopy + Owner>(&self) -> Result<&T> {
-        Ok(bytemuck::from_bytes(
-            &self.data()[8..mem::size_of::<T>() + 8],
-        ))
+        from_bytes_checked(self.data())
+    }
+
+    fn load_unaligned<T: ZeroCopy + Owner>(&self) -> Result<T> {
+        if self.owner() != &T::owner() {
+            return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+        }
+
+        let data = self.data();
+        if data.len() < 8 {
+            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+        }
+        let disc_bytes = array_ref![data, 0, 8];
+        if disc_bytes != &T::discriminator() {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+
+        let end = mem::size_of::<T>() + 8;
+        if data.len() < end {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+        Ok(bytemuck::pod_read_unaligned(&data[8..end]))
     }
 }
 
@@ -246,15 +290,11 @@ impl<'info, 'a> LoadMutZeroCopy for AccountInfoRefMut<'info, 'a> {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
-        Ok(bytemuck::from_bytes_mut(
-            &mut self.data[8..mem::size_of::<T>() + 8],
-        ))
+        from_bytes_mut_checked(&mut self.data)
     }
 
     fn load_mut_fully_unchecked<T: ZeroCopy + Owner>(&mut self) -> Result<&mut T> {
-        Ok(bytemuck::from_bytes_mut(
-            &mut self.data[8..mem::size_of::<T>() + 8],
-        ))
+        from_bytes_mut_checked(&mut self.data)
     }
 }
 
@@ -274,6 +314,7 @@ impl<'info> LoadZeroCopyRef for AccountInfo<'info> {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
+        from_bytes_checked::<T>(&data)?;
         Ok(Ref::map(data, |data| {
             bytemuck::from_bytes(&data[8..mem::size_of::<T>() + 8])
         }))
@@ -281,6 +322,7 @@ impl<'info> LoadZeroCopyRef for AccountInfo<'info> {
 
     fn load_fully_unchecked<T: ZeroCopy + Owner>(&self) -> Result<Ref<T>> {
         let data = self.try_borrow_data()?;
+        from_bytes_checked::<T>(&data)?;
         Ok(Ref::map(data, |data| {
             bytemuck::from_bytes(&data[8..mem::size_of::<T>() + 8])
         }))
@@ -303,6 +345,7 @@ impl<'info> LoadMutZeroCopyRef for AccountInfo<'info> {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
+        from_bytes_checked::<T>(&data)?;
         Ok(RefMut::map(data, |data| {
             bytemuck::from_bytes_mut(&mut data[8..mem::size_of::<T>() + 8])
         }))
@@ -310,8 +353,77 @@ impl<'info> LoadMutZeroCopyRef for AccountInfo<'info> {
 
     fn load_mut_fully_unchecked<T: ZeroCopy + Owner>(&self) -> Result<RefMut<T>> {
         let data = self.try_borrow_mut_data()?;
+        from_bytes_checked::<T>(&data)?;
         Ok(RefMut::map(data, |data| {
             bytemuck::from_bytes_mut(&mut data[8..mem::size_of::<T>() + 8])
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Bank;
+    use anchor_lang::Discriminator;
+    use bytemuck::Zeroable;
+
+    struct TestAccount {
+        owner: Pubkey,
+        // u64 storage guarantees an 8-byte aligned start of the data
+        storage: Vec<u64>,
+        offset: usize,
+        len: usize,
+    }
+
+    impl AccountReader for TestAccount {
+        fn owner(&self) -> &Pubkey {
+            &self.owner
+        }
+
+        fn data(&self) -> &[u8] {
+            &bytemuck::cast_slice(&self.storage)[self.offset..self.offset + self.len]
+        }
+    }
+
+    fn bank_account(offset: usize) -> TestAccount {
+        let mut bank = Bank::zeroed();
+        bank.token_index = 3;
+        let mut data = Bank::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&bank));
+
+        let mut storage = vec![0u64; (offset + data.len()) / 8 + 1];
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut storage);
+        bytes[offset..offset + data.len()].copy_from_slice(&data);
+        TestAccount {
+            owner: crate::id(),
+            storage,
+            offset,
+            len: data.len(),
+        }
+    }
+
+    #[test]
+    fn test_load_checked() {
+        let account = bank_account(0);
+        assert_eq!(account.load::<Bank>().unwrap().token_index, 3);
+        assert_eq!(account.load_unaligned::<Bank>().unwrap().token_index, 3);
+
+        // misaligned data errors instead of panicking, but can be copied out
+        let account = bank_account(1);
+        assert!(account.load::<Bank>().is_err());
+        assert!(account.load_fully_unchecked::<Bank>().is_err());
+        assert_eq!(account.load_unaligned::<Bank>().unwrap().token_index, 3);
+
+        // too short data errors
+        let mut account = bank_account(0);
+        account.len -= 1;
+        assert!(account.load::<Bank>().is_err());
+        assert!(account.load_unaligned::<Bank>().is_err());
+
+        // wrong owner
+        let mut account = bank_account(0);
+        account.owner = Pubkey::new_unique();
+        assert!(account.load::<Bank>().is_err());
+        assert!(account.load_unaligned::<Bank>().is_err());
+    }
+}
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use solana_address_lookup_table_program as solana_alt;
 use solana_program::pubkey::Pubkey;
 
+use crate::state::Group;
+
 pub fn addresses(table: &[u8]) -> &[Pubkey] {
     bytemuck::try_cast_slice(&table[solana_alt::state::LOOKUP_TABLE_META_SIZE..]).unwrap()
 }
@@ -14,6 +16,7 @@ pub fn cpi_extend<'info>(
     lookup_table_ai: AccountInfo<'info>,
     authority_ai: AccountInfo<'info>,
     payer_ai: AccountInfo<'info>,
+    system_program_ai: AccountInfo<'info>,
     signer_seeds: &[&[&[u8]]],
     new_addresses: Vec<Pubkey>,
 ) -> std::result::Result<(), ProgramError> {
@@ -23,6 +26,84 @@ pub fn cpi_extend<'info>(
         Some(payer_ai.key()),
         new_addresses,
     );
-    let account_infos = [lookup_table_ai, authority_ai, payer_ai];
+    let account_infos = [lookup_table_ai, authority_ai, payer_ai, system_program_ai];
     solana_program::program::invoke_signed(&instruction, &account_infos, signer_seeds)
 }
+
+pub fn cpi_create<'info>(
+    lookup_table_ai: AccountInfo<'info>,
+    authority_ai: AccountInfo<'info>,
+    payer_ai: AccountInfo<'info>,
+    system_program_ai: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    recent_slot: u64,
+) -> Result<()> {
+    let (instruction, lookup_table_address) = solana_alt::instruction::create_lookup_table_signed(
+        authority_ai.key(),
+        payer_ai.key(),
+        recent_slot,
+    );
+    require_keys_eq!(lookup_table_address, lookup_table_ai.key());
+    let account_infos = [lookup_table_ai, authority_ai, payer_ai, system_program_ai];
+    solana_program::program::invoke_signed(&instruction, &account_infos, signer_seeds)?;
+    Ok(())
+}
+
+/// Add addresses to one of the group's lookup tables, if one was passed in
+/// `remaining_accounts`.
+///
+/// Used by listing instructions to register the new accounts. The lookup table
+/// must have the group as authority, see alt_create. Addresses that are already
+/// in the table are skipped.
+pub fn maybe_extend_group_alt<'info>(
+    group_loader: &AccountLoader<'info, Group>,
+    payer_ai: AccountInfo<'info>,
+    system_program_ai: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    new_addresses: &[Pubkey],
+) -> Result<()> {
+    // Copy what's needed: the group must not be borrowed during the cpi if it's writable
+    let (creator, group_num, bump, lookup_table_ai) = {
+        let group = group_loader.load()?;
+        let lookup_table_ai = remaining_accounts
+            .iter()
+            .find(|ai| ai.owner == &solana_alt::ID && group.address_lookup_tables.contains(ai.key));
+        (group.creator, group.group_num, group.bump, lookup_table_ai)
+    };
+    let lookup_table_ai = match lookup_table_ai {
+        Some(ai) => ai,
+        None => return Ok(()),
+    };
+
+    let to_add = {
+        let table = lookup_table_ai.try_borrow_data()?;
+        let mut to_add: Vec<Pubkey> = Vec::with_capacity(new_addresses.len());
+        for address in new_addresses.iter() {
+            if !contains(&table, address) && !to_add.contains(address) {
+                to_add.push(*address);
+            }
+        }
+        to_add
+    };
+    if to_add.is_empty() {
+        return Ok(());
+    }
+
+    msg!("adding {} addresses to lookup table", to_add.len());
+    let group_num_bytes = group_num.to_le_bytes();
+    let group_seeds: &[&[u8]] = &[
+        b"Group".as_ref(),
+        creator.as_ref(),
+        &group_num_bytes,
+        &[bump],
+    ];
+    cpi_extend(
+        lookup_table_ai.clone(),
+        group_loader.to_account_info(),
+        payer_ai,
+        system_program_ai,
+        &[group_seeds],
+        to_add,
+    )?;
+    Ok(())
+}
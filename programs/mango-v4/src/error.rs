@@ -143,6 +143,34 @@ pub enum MangoError {
     InvalidFeedForCLMMOracle,
     #[msg("Pyth USDC/USD or SOL/USD feed not found (required by CLMM oracle)")]
     MissingFeedForCLMMOracle,
+    #[msg("the provided pyth price update account is not valid")]
+    InvalidPythPriceUpdate,
+    #[msg("the provided switchboard pull feed account is not valid")]
+    InvalidSwitchboardPullFeed,
+    #[msg("the vault does not belong to the bank")]
+    VaultBankMismatch,
+    #[msg("no FlashLoanEnd instruction found in the transaction")]
+    MissingFlashLoanEnd,
+    #[msg("the instruction may not be invoked via CPI")]
+    CpiForbidden,
+    #[msg("a vault has no matching bank in the health accounts")]
+    HealthAccountBankMissing,
+    #[msg("instruction is not allowed between FlashLoanBegin and FlashLoanEnd")]
+    UnexpectedFlashLoanInstruction,
+    #[msg("the perp trigger order has expired")]
+    PerpTriggerOrderExpired,
+    #[msg("the oracle price has not reached the perp trigger order's trigger price")]
+    PerpTriggerOrderNotTriggered,
+    #[msg("the swap program is not allowed")]
+    SwapProgramNotAllowed,
+    #[msg("the position is not allowed for this isolated account")]
+    IsolatedAccountPositionNotAllowed,
+    #[msg("the perp order price is too far from the oracle price")]
+    PerpPriceBandExceeded,
+    #[msg("group is in withdraw only mode")]
+    GroupInWithdrawOnlyMode,
+    #[msg("the auto-deleveraging counterparty's position does not oppose the liqee's")]
+    PerpAdlCounterpartyNotOpposing,
 }
 
 impl MangoError {
@@ -278,6 +306,20 @@ macro_rules! require_msg_typed {
     };
 }
 
+/// Checked math that returns MathError, with the location of the invocation, on overflow
+///
+/// Example: cm!(a + b * c)? or cm!(fees += amount)?
+#[macro_export]
+macro_rules! cm {
+    ($($expr:tt)+) => {
+        ::checked_math::checked_math_or_err!(
+            ::anchor_lang::error!($crate::error::MangoError::MathError),
+            $($expr)+
+        )
+    };
+}
+
+pub use cm;
 pub use error_msg;
 pub use error_msg_typed;
 pub use require_msg;
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use fixed::types::I80F48;
 
-use crate::state::{PerpMarketIndex, TokenIndex};
+use crate::state::{PerpMarketIndex, Serum3MarketIndex, TokenIndex};
 
 #[event]
 pub struct MangoAccountData {
@@ -10,6 +10,37 @@ pub struct MangoAccountData {
     pub equity: Equity,
 }
 
+#[event]
+pub struct MangoAccountPositions {
+    pub tokens: Vec<TokenPositionData>,
+    pub serum3: Vec<Serum3PositionData>,
+    pub perps: Vec<PerpPositionData>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct TokenPositionData {
+    pub token_index: TokenIndex,
+    pub native: I80F48,
+    pub oracle_price: I80F48,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct Serum3PositionData {
+    pub market_index: Serum3MarketIndex,
+    pub base_token_index: TokenIndex,
+    pub quote_token_index: TokenIndex,
+    pub base_native_total: u64,
+    pub quote_native_total: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct PerpPositionData {
+    pub perp_market_index: PerpMarketIndex,
+    pub base_position_native: I80F48,
+    pub quote_position_native: I80F48,
+    pub oracle_price: I80F48,
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize, Debug)]
 pub struct Equity {
     pub tokens: Vec<TokenEquity>,
@@ -27,3 +58,61 @@ pub struct PerpEquity {
     pub perp_market_index: PerpMarketIndex,
     value: I80F48, // in native quote
 }
+
+/// Return data of compute_account_data_page
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct MangoAccountDataPage {
+    pub init_health: I80F48,
+    pub maint_health: I80F48,
+    pub page: u8,
+    pub page_count: u8,
+    pub entries: Vec<MangoAccountDataEntry>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub enum MangoAccountDataEntry {
+    TokenEquity(TokenEquity),
+    PerpEquity(PerpEquity),
+    TokenPosition(TokenPositionData),
+    Serum3Position(Serum3PositionData),
+    PerpPosition(PerpPositionData),
+}
+
+/// Return data of compute_health_breakdown
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct MangoAccountHealthBreakdown {
+    pub init_health: I80F48,
+    pub maint_health: I80F48,
+    pub page: u8,
+    pub page_count: u8,
+    pub contributions: Vec<HealthContribution>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub enum HealthContribution {
+    Token(TokenHealthContribution),
+    Serum3(Serum3HealthContribution),
+    Perp(PerpHealthContribution),
+}
+
+/// Excludes the perp hupnl that's attributed to perp markets settling in the token
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct TokenHealthContribution {
+    pub token_index: TokenIndex,
+    pub init: I80F48,  // in native quote
+    pub maint: I80F48, // in native quote
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct Serum3HealthContribution {
+    pub market_index: Serum3MarketIndex,
+    pub init: I80F48,  // in native quote
+    pub maint: I80F48, // in native quote
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Debug)]
+pub struct PerpHealthContribution {
+    pub perp_market_index: PerpMarketIndex,
+    pub init: I80F48,  // in native quote
+    pub maint: I80F48, // in native quote
+}
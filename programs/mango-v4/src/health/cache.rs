@@ -767,9 +767,8 @@ impl HealthCache {
         }
 
         for perp_info in self.perp_infos.iter() {
-            let quote_price = self.token_infos[perp_info.settle_token_index as usize]
-                .prices
-                .oracle;
+            let settle_info_index = self.token_info_index(perp_info.settle_token_index).unwrap();
+            let quote_price = self.token_infos[settle_info_index].prices.oracle;
             let quote_position_value = perp_info.quote * quote_price;
             if perp_info.quote.is_negative() {
                 liabs -= quote_position_value;
@@ -1127,6 +1126,42 @@ impl HealthCache {
         }
     }
 
+    /// Splits health into the contributions of each token, serum3 market and perp market.
+    ///
+    /// The results are in the order of token_infos, serum3_infos and perp_infos.
+    ///
+    /// Perp markets are attributed the settle token weighted value of their hupnl. Since
+    /// the hupnl is part of the settle token balance, the settle token's entry is what
+    /// remains of its contribution. All entries sum to health().
+    pub fn health_breakdown(
+        &self,
+        health_type: HealthType,
+    ) -> (Vec<I80F48>, Vec<I80F48>, Vec<I80F48>) {
+        let token_balances = self.effective_token_balances(health_type);
+        let mut token_contribs =
+            Vec::with_capacity(self.token_infos.len() + self.serum3_infos.len());
+        self.health_sum(
+            health_type,
+            |contrib| token_contribs.push(contrib),
+            &token_balances,
+        );
+        let serum3_contribs = token_contribs.split_off(self.token_infos.len());
+
+        let perp_contribs = self
+            .perp_infos
+            .iter()
+            .map(|perp_info| {
+                let settle_index = self.token_info_index(perp_info.settle_token_index).unwrap();
+                let contrib = self.token_infos[settle_index]
+                    .health_contribution(health_type, perp_info.health_unsettled_pnl(health_type));
+                token_contribs[settle_index] -= contrib;
+                contrib
+            })
+            .collect();
+
+        (token_contribs, serum3_contribs, perp_contribs)
+    }
+
     /// Returns how much pnl is settleable for a given settle token.
     ///
     /// The idea of this limit is that settlement is only permissible as long as there are
@@ -1381,6 +1416,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_perp_overall_asset_weight() {
+        let perp_info = |quote: f64| PerpInfo {
+            perp_market_index: 0,
+            settle_token_index: 0,
+            maint_base_asset_weight: I80F48::ONE,
+            init_base_asset_weight: I80F48::ONE,
+            maint_base_liab_weight: I80F48::ONE,
+            init_base_liab_weight: I80F48::ONE,
+            maint_overall_asset_weight: I80F48::from_num(0.8),
+            init_overall_asset_weight: I80F48::from_num(0.5),
+            base_lot_size: 1,
+            base_lots: 0,
+            bids_base_lots: 0,
+            asks_base_lots: 0,
+            quote: I80F48::from_num(quote),
+            base_prices: Prices::new_single_price(I80F48::ONE),
+            has_open_orders: false,
+            has_open_fills: false,
+        };
+
+        // Positive unsettled pnl is discounted until it is settled
+        let positive = perp_info(100.0);
+        assert!(health_eq(
+            positive.unweighted_health_unsettled_pnl(HealthType::Init),
+            100.0
+        ));
+        assert!(health_eq(
+            positive.health_unsettled_pnl(HealthType::Init),
+            50.0
+        ));
+        assert!(health_eq(
+            positive.health_unsettled_pnl(HealthType::LiquidationEnd),
+            50.0
+        ));
+        assert!(health_eq(
+            positive.health_unsettled_pnl(HealthType::Maint),
+            80.0
+        ));
+
+        // Negative unsettled pnl counts fully
+        let negative = perp_info(-100.0);
+        for health_type in [
+            HealthType::Init,
+            HealthType::LiquidationEnd,
+            HealthType::Maint,
+        ] {
+            assert!(health_eq(
+                negative.health_unsettled_pnl(health_type),
+                -100.0
+            ));
+        }
+    }
+
+    #[test]
+    fn test_serum3_reserved_conversion_uses_placed_prices() {
+        let token_info = |price: f64| TokenInfo {
+            token_index: 0,
+            maint_asset_weight: I80F48::ONE,
+            init_asset_weight: I80F48::ONE,
+            init_scaled_asset_weight: I80F48::ONE,
+            maint_liab_weight: I80F48::ONE,
+            init_liab_weight: I80F48::ONE,
+            init_scaled_liab_weight: I80F48::ONE,
+            prices: Prices::new_single_price(I80F48::from_num(price)),
+            balance_spot: I80F48::ZERO,
+        };
+        let quote_info = token_info(1.0);
+        let base_info = token_info(5.0);
+
+        let serum3_info = |bid_inv: f64, ask: f64| Serum3Info {
+            reserved_base: I80F48::from(10),
+            reserved_quote: I80F48::from(100),
+            reserved_base_as_quote_lowest_ask: I80F48::from(10) * I80F48::from_num(ask),
+            reserved_quote_as_base_highest_bid: I80F48::from(100) * I80F48::from_num(bid_inv),
+            base_info_index: 1,
+            quote_info_index: 0,
+            market_index: 0,
+            has_zero_funds: false,
+        };
+
+        // Without tracked order prices, conversion happens at the oracle price
+        let untracked = serum3_info(0.0, 0.0);
+        assert!(health_eq(
+            untracked.all_reserved_as_base(HealthType::Maint, &quote_info, &base_info),
+            10.0 + 100.0 / 5.0
+        ));
+        assert!(health_eq(
+            untracked.all_reserved_as_quote(HealthType::Maint, &quote_info, &base_info),
+            100.0 + 10.0 * 5.0
+        ));
+
+        // Orders placed at worse-than-oracle prices can't do better than oracle
+        let far = serum3_info(1.0 / 4.0, 6.0);
+        assert!(health_eq(
+            far.all_reserved_as_base(HealthType::Maint, &quote_info, &base_info),
+            10.0 + 100.0 / 5.0
+        ));
+        assert!(health_eq(
+            far.all_reserved_as_quote(HealthType::Maint, &quote_info, &base_info),
+            100.0 + 10.0 * 5.0
+        ));
+
+        // Orders crossing the oracle bound the conversion by their placed price
+        let crossing = serum3_info(1.0 / 10.0, 2.0);
+        assert!(health_eq(
+            crossing.all_reserved_as_base(HealthType::Maint, &quote_info, &base_info),
+            10.0 + 100.0 / 10.0
+        ));
+        assert!(health_eq(
+            crossing.all_reserved_as_quote(HealthType::Maint, &quote_info, &base_info),
+            100.0 + 10.0 * 2.0
+        ));
+    }
+
     // Run a health test that includes all the side values (like referrer_rebates_accrued)
     #[test]
     fn test_health0() {
@@ -1456,6 +1606,64 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_serum3_free_funds_in_token_balances() {
+        let buffer = MangoAccount::default_for_tests().try_to_vec().unwrap();
+        let mut account = MangoAccountValue::from_bytes(&buffer).unwrap();
+
+        let group = Pubkey::new_unique();
+
+        let (mut bank1, mut oracle1) = mock_bank_and_oracle(group, 0, 1.0, 0.2, 0.1);
+        let (mut bank2, mut oracle2) = mock_bank_and_oracle(group, 4, 5.0, 0.5, 0.3);
+        bank1
+            .data()
+            .deposit(
+                account.ensure_token_position(0).unwrap().0,
+                I80F48::from(100),
+                DUMMY_NOW_TS,
+            )
+            .unwrap();
+        account.ensure_token_position(4).unwrap();
+
+        let mut oo1 = TestAccount::<OpenOrders>::new_zeroed();
+        let serum3account = account.create_serum3_orders(2).unwrap();
+        serum3account.open_orders = oo1.pubkey;
+        serum3account.base_token_index = 4;
+        serum3account.quote_token_index = 0;
+        oo1.data().native_pc_total = 27;
+        oo1.data().native_coin_total = 8;
+        oo1.data().native_pc_free = 7;
+        oo1.data().native_coin_free = 3;
+
+        let ais = vec![
+            bank1.as_account_info(),
+            bank2.as_account_info(),
+            oracle1.as_account_info(),
+            oracle2.as_account_info(),
+            oo1.as_account_info(),
+        ];
+        let retriever = ScanningAccountRetriever::new_with_staleness(&ais, &group, None).unwrap();
+        let health_cache = new_health_cache(&account.borrow(), &retriever, 0).unwrap();
+
+        // free funds are attributed to the market's base and quote banks
+        assert_eq!(
+            health_cache.token_info(0).unwrap().balance_spot,
+            I80F48::from(107)
+        );
+        assert_eq!(
+            health_cache.token_info(4).unwrap().balance_spot,
+            I80F48::from(3)
+        );
+
+        // only the reserved amounts are left for the serum3 health contribution
+        let serum3_info = &health_cache.serum3_infos[0];
+        assert_eq!(serum3_info.reserved_quote, I80F48::from(20));
+        assert_eq!(serum3_info.reserved_base, I80F48::from(5));
+        assert_eq!(serum3_info.base_info_index, 1);
+        assert_eq!(serum3_info.quote_info_index, 0);
+        assert!(!serum3_info.has_zero_funds);
+    }
+
     #[derive(Default)]
     struct BankSettings {
         deposits: u64,
@@ -1579,6 +1787,16 @@ mod tests {
             compute_health(&account.borrow(), HealthType::Init, &retriever, 0).unwrap(),
             testcase.expected_health
         ));
+
+        // The breakdown adds up to the same health
+        let health_cache = new_health_cache(&account.borrow(), &retriever, 0).unwrap();
+        let (tokens, serum3, perps) = health_cache.health_breakdown(HealthType::Init);
+        let breakdown_health = tokens
+            .iter()
+            .chain(serum3.iter())
+            .chain(perps.iter())
+            .fold(I80F48::ZERO, |sum, contrib| sum + contrib);
+        assert!(health_eq(breakdown_health, testcase.expected_health));
     }
 
     // Check some specific health constellations
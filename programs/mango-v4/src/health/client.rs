@@ -1632,6 +1632,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_assets_and_liabs_non_usd_settle_token() {
+        // the perp settles in token 5, valued at 2
+        let health_cache = HealthCache {
+            token_infos: vec![
+                TokenInfo {
+                    token_index: 0,
+                    balance_spot: I80F48::ONE,
+                    ..default_token_info(0.0, 1.0)
+                },
+                TokenInfo {
+                    token_index: 5,
+                    ..default_token_info(0.2, 2.0)
+                },
+            ],
+            serum3_infos: vec![],
+            perp_infos: vec![PerpInfo {
+                perp_market_index: 0,
+                settle_token_index: 5,
+                base_lots: -3,
+                quote: I80F48::from_num(10),
+                ..default_perp_info(0.1, 1.5)
+            }],
+            being_liquidated: false,
+        };
+
+        let (assets, liabs) = health_cache.assets_and_liabs();
+        assert!((assets.to_num::<f64>() - (1.0 + 10.0 * 2.0)).abs() < 0.01);
+        assert!((liabs.to_num::<f64>() - 3.0 * 1.5 * 2.0).abs() < 0.01);
+    }
+
     #[test]
     fn test_leverage() {
         // only deposits
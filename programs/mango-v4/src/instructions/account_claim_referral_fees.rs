@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::logs::{emit_stack, ReferralFeesClaimLog, TokenBalanceLog};
+use crate::state::*;
+
+/// Pays the referrer its share of the fees the referred account accrued in a bank
+/// or perp market.
+///
+/// The payout comes out of the fees where they were collected: the bank's loan
+/// origination fees, or the settled fees of the perp market if one is passed.
+/// It's booked in referral_fees_paid.
+pub fn account_claim_referral_fees(ctx: Context<AccountClaimReferralFees>) -> Result<()> {
+    let group = ctx.accounts.group.load()?;
+    let mut account = ctx.accounts.account.load_mut()?;
+    let mut referrer = ctx.accounts.referrer.load_full_mut()?;
+    let mut bank = ctx.accounts.bank.load_mut()?;
+    let mut perp_market_opt = ctx
+        .accounts
+        .perp_market
+        .as_ref()
+        .map(|pm| pm.load_mut())
+        .transpose()?;
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    // The fees accrued for the source and what it can pay out
+    let (source, source_index, available_fees) = if let Some(perp_market) = perp_market_opt.as_ref()
+    {
+        (
+            ReferralFeeSource::PerpMarket,
+            perp_market.perp_market_index,
+            perp_market.fees_available(),
+        )
+    } else {
+        (
+            ReferralFeeSource::Bank,
+            bank.token_index,
+            bank.fees_available(),
+        )
+    };
+    let fees_accrued = account.referral_fees_accrued(source, source_index);
+
+    let share = I80F48::from_num(group.referral_fee_share);
+    let mut fees_native = I80F48::from(fees_accrued);
+    if share.is_positive() {
+        fees_native = fees_native.min(available_fees.max(I80F48::ZERO) / share);
+    }
+    let fees_native = fees_native.floor();
+    let amount = (fees_native * share).floor();
+    if amount <= I80F48::ZERO {
+        msg!(
+            "nothing to claim (referral fees accrued {}, share {}, available fees {})",
+            fees_accrued,
+            share,
+            available_fees
+        );
+        return Ok(());
+    }
+
+    // If the source limits the payout, the rest stays claimable
+    let fees_used = fees_native.to_num::<u64>();
+    account.reduce_referral_fees_accrued(source, source_index, fees_used)?;
+
+    let amount_u64 = amount.to_num::<u64>();
+    if let Some(perp_market) = perp_market_opt.as_mut() {
+        cm!(perp_market.referral_fees_unclaimed -= fees_used)?;
+        cm!(perp_market.referral_fees_paid += amount_u64)?;
+    } else {
+        cm!(bank.referral_fees_unclaimed -= fees_used)?;
+        cm!(bank.referral_fees_paid += amount_u64)?;
+    }
+
+    let (position, raw_token_index, _) = referrer.ensure_token_position(bank.token_index)?;
+    let in_use = bank.deposit(position, amount, now_ts)?;
+    emit_stack(TokenBalanceLog {
+        mango_group: ctx.accounts.group.key(),
+        mango_account: ctx.accounts.referrer.key(),
+        token_index: bank.token_index,
+        indexed_position: position.indexed_position.to_bits(),
+        deposit_index: bank.deposit_index.to_bits(),
+        borrow_index: bank.borrow_index.to_bits(),
+    });
+    if !in_use {
+        referrer.deactivate_token_position_and_log(raw_token_index, ctx.accounts.referrer.key());
+    }
+
+    emit_stack(ReferralFeesClaimLog {
+        mango_group: ctx.accounts.group.key(),
+        mango_account: ctx.accounts.account.key(),
+        referrer: ctx.accounts.referrer.key(),
+        token_index: bank.token_index,
+        perp_market_index: perp_market_opt.map(|pm| pm.perp_market_index),
+        fees_used,
+        amount: amount.to_bits(),
+    });
+
+    Ok(())
+}
@@ -15,6 +15,7 @@ pub fn account_create(
     perp_oo_count: u8,
     token_conditional_swap_count: u8,
     name: String,
+    referrer: Pubkey,
 ) -> Result<()> {
     let mut account = account_ai.load_full_init()?;
 
@@ -39,6 +40,9 @@ pub fn account_create(
     account.fixed.bump = account_bump;
     account.fixed.delegate = Pubkey::default();
     account.fixed.set_being_liquidated(false);
+    account.fixed.isolated_perp_market_index = PerpMarketIndex::MAX;
+    account.fixed.isolated_token_indexes = [TokenIndex::MAX; 6];
+    account.fixed.referrer = referrer;
 
     account.resize_dynamic_content(
         token_count,
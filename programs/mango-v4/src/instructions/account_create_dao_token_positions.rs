@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::{accounts_zerocopy::LoadZeroCopyRef, state::*};
+
+/// Activates a token position on the DAO account for each bank in remaining accounts.
+///
+/// Fees and dust get moved into the DAO account, pre-creating positions makes sure
+/// that can't fail because the account is out of token position slots.
+pub fn account_create_dao_token_positions(
+    ctx: Context<AccountCreateDaoTokenPositions>,
+) -> Result<()> {
+    let group_pk = ctx.accounts.group.key();
+    let mut account = ctx.accounts.dao_account.load_full_mut()?;
+
+    for bank_ai in ctx.remaining_accounts.iter() {
+        let bank = bank_ai.load::<Bank>()?;
+        require_keys_eq!(bank.group, group_pk);
+
+        let (_, raw_token_index, _) = account.ensure_token_position(bank.token_index)?;
+        msg!(
+            "token position for token index {} at raw index {}",
+            bank.token_index,
+            raw_token_index
+        );
+    }
+
+    Ok(())
+}
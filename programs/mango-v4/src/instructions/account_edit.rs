@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
+use crate::accounts_zerocopy::*;
 use crate::error::MangoError;
 use crate::state::*;
 use crate::util::fill_from_str;
@@ -14,9 +15,16 @@ pub fn account_edit(
     delegate_opt: Option<Pubkey>,
     temporary_delegate_opt: Option<Pubkey>,
     temporary_delegate_expiry_opt: Option<u64>,
+    isolated_opt: Option<bool>,
+    isolated_perp_market_index_opt: Option<PerpMarketIndex>,
+    isolated_token_indexes_opt: Option<Vec<TokenIndex>>,
 ) -> Result<()> {
     require!(
-        name_opt.is_some() || delegate_opt.is_some(),
+        name_opt.is_some()
+            || delegate_opt.is_some()
+            || isolated_opt.is_some()
+            || isolated_perp_market_index_opt.is_some()
+            || isolated_token_indexes_opt.is_some(),
         MangoError::SomeError
     );
 
@@ -43,5 +51,83 @@ pub fn account_edit(
         }
     }
 
+    // Older accounts may have zeroed isolation fields: the subset must be
+    // set explicitly when isolating an account
+    if isolated_opt == Some(true) && !account.fixed.is_isolated() {
+        require_msg!(
+            isolated_perp_market_index_opt.is_some() && isolated_token_indexes_opt.is_some(),
+            "the isolated perp market and tokens must be set when isolating an account"
+        );
+    }
+
+    if let Some(isolated_perp_market_index) = isolated_perp_market_index_opt {
+        account.fixed.isolated_perp_market_index = isolated_perp_market_index;
+    }
+
+    if let Some(isolated_token_indexes) = isolated_token_indexes_opt {
+        let max_len = account.fixed.isolated_token_indexes.len();
+        require_msg!(
+            isolated_token_indexes.len() <= max_len,
+            "at most {} isolated tokens can be set",
+            max_len
+        );
+        account.fixed.isolated_token_indexes = [TokenIndex::MAX; 6];
+        account.fixed.isolated_token_indexes[..isolated_token_indexes.len()]
+            .copy_from_slice(&isolated_token_indexes);
+    }
+
+    if let Some(isolated) = isolated_opt {
+        account.fixed.set_isolated(isolated);
+    }
+
+    // The settle token of the isolated perp market must be isolated too, otherwise
+    // the perp position couldn't be settled. The perp market is passed as the
+    // first remaining account.
+    let isolated_perp_market_index = account.fixed.isolated_perp_market_index;
+    let isolation_changed = isolated_opt.is_some()
+        || isolated_perp_market_index_opt.is_some()
+        || isolated_token_indexes_opt.is_some();
+    if isolation_changed
+        && account.fixed.is_isolated()
+        && isolated_perp_market_index != PerpMarketIndex::MAX
+    {
+        let perp_market_ai = ctx.remaining_accounts.first().ok_or_else(|| {
+            error_msg!("the isolated perp market must be passed as remaining account")
+        })?;
+        let perp_market = perp_market_ai.load::<PerpMarket>()?;
+        require_keys_eq!(perp_market.group, ctx.accounts.group.key());
+        require_eq!(perp_market.perp_market_index, isolated_perp_market_index);
+        require_msg_typed!(
+            account
+                .fixed
+                .is_token_allowed(perp_market.settle_token_index),
+            MangoError::IsolatedAccountPositionNotAllowed,
+            "the settle token index {} of perp market index {} must be isolated too",
+            perp_market.settle_token_index,
+            isolated_perp_market_index
+        );
+    }
+
+    // The existing positions must fit into the isolated subset, since health
+    // is only supposed to depend on it
+    if account.fixed.is_isolated() {
+        for position in account.active_token_positions() {
+            require_msg_typed!(
+                account.fixed.is_token_allowed(position.token_index),
+                MangoError::IsolatedAccountPositionNotAllowed,
+                "account has a position in token index {}, which is not isolated",
+                position.token_index
+            );
+        }
+        for position in account.active_perp_positions() {
+            require_msg_typed!(
+                account.fixed.is_perp_market_allowed(position.market_index),
+                MangoError::IsolatedAccountPositionNotAllowed,
+                "account has a position in perp market index {}, which is not isolated",
+                position.market_index
+            );
+        }
+    }
+
     Ok(())
 }
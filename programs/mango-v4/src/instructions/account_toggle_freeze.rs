@@ -1,11 +1,28 @@
 use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
+use crate::error::*;
 use crate::state::*;
 
 // Freezing an account, prevents all instructions involving account (also settling and liquidation), except
 // perp consume events and force cancellation of orders
 pub fn account_toggle_freeze(ctx: Context<AccountToggleFreeze>, freeze: bool) -> Result<()> {
+    // account constraint #1
+    // security admin can only freeze, unfreezing needs the group admin
+    let group = ctx.accounts.group.load()?;
+    if freeze {
+        require!(
+            group.admin == ctx.accounts.admin.key()
+                || group.security_admin == ctx.accounts.admin.key(),
+            MangoError::SomeError
+        );
+    } else {
+        require!(
+            group.admin == ctx.accounts.admin.key(),
+            MangoError::SomeError
+        );
+    }
+
     let mut account = ctx.accounts.account.load_full_mut()?;
     if freeze {
         let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
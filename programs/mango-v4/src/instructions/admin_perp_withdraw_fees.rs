@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use fixed::types::I80F48;
 
 use crate::{accounts_ix::*, group_seeds};
 
@@ -8,7 +9,11 @@ pub fn admin_perp_withdraw_fees(ctx: Context<AdminPerpWithdrawFees>) -> Result<(
     let mut perp_market = ctx.accounts.perp_market.load_mut()?;
 
     let group_seeds = group_seeds!(group);
-    let fees = perp_market.fees_settled.floor().to_num::<u64>() - perp_market.fees_withdrawn;
+    let fees = perp_market
+        .fees_available()
+        .max(I80F48::ZERO)
+        .floor()
+        .to_num::<u64>();
     let amount = fees.min(ctx.accounts.vault.amount);
     token::transfer(
         ctx.accounts.transfer_ctx().with_signer(&[group_seeds]),
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token;
+use fixed::types::I80F48;
 
 use crate::{accounts_ix::*, group_seeds};
 
@@ -8,7 +9,11 @@ pub fn admin_token_withdraw_fees(ctx: Context<AdminTokenWithdrawFees>) -> Result
     let mut bank = ctx.accounts.bank.load_mut()?;
 
     let group_seeds = group_seeds!(group);
-    let fees = bank.collected_fees_native.floor().to_num::<u64>() - bank.fees_withdrawn;
+    let fees = bank
+        .fees_available()
+        .max(I80F48::ZERO)
+        .floor()
+        .to_num::<u64>();
     let amount = fees.min(ctx.accounts.vault.amount);
     token::transfer(
         ctx.accounts.transfer_ctx().with_signer(&[group_seeds]),
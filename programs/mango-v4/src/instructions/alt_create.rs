@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::address_lookup_table_program;
+
+/// Create an address lookup table with the group as authority and register it
+///
+/// Unlike ALTs registered with alt_set, these can be extended by the program,
+/// see alt_extend and the listing instructions.
+pub fn alt_create(ctx: Context<AltCreate>, index: u8, recent_slot: u64) -> Result<()> {
+    let (creator, group_num, bump) = {
+        let group = ctx.accounts.group.load()?;
+        require_gt!(group.address_lookup_tables.len(), index as usize);
+        require_msg!(
+            group.address_lookup_tables[index as usize] == Pubkey::default(),
+            "address lookup table index {} is already in use",
+            index
+        );
+        (group.creator, group.group_num, group.bump)
+    };
+    let group_num_bytes = group_num.to_le_bytes();
+    let group_seeds: &[&[u8]] = &[
+        b"Group".as_ref(),
+        creator.as_ref(),
+        &group_num_bytes,
+        &[bump],
+    ];
+
+    // The group account must not be borrowed during the cpi, since it's writable
+    address_lookup_table_program::cpi_create(
+        ctx.accounts.address_lookup_table.to_account_info(),
+        ctx.accounts.group.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        &[group_seeds],
+        recent_slot,
+    )?;
+
+    let mut group = ctx.accounts.group.load_mut()?;
+    group.address_lookup_tables[index as usize] = ctx.accounts.address_lookup_table.key();
+    Ok(())
+}
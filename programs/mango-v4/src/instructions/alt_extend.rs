@@ -6,7 +6,7 @@ use crate::state::*;
 
 /// Add addresses to a registered lookup table
 ///
-/// NOTE: This only works for ALTs that have the group as owner, see alt_set.
+/// NOTE: This only works for ALTs that have the group as owner, see alt_create.
 pub fn alt_extend(ctx: Context<AltExtend>, index: u8, new_addresses: Vec<Pubkey>) -> Result<()> {
     let group = ctx.accounts.group.load()?;
     require_keys_eq!(
@@ -19,6 +19,7 @@ pub fn alt_extend(ctx: Context<AltExtend>, index: u8, new_addresses: Vec<Pubkey>
         ctx.accounts.address_lookup_table.to_account_info(),
         ctx.accounts.group.to_account_info(),
         ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
         &[group_seeds],
         new_addresses,
     )?;
@@ -3,6 +3,7 @@ use fixed::types::{I80F48, U80F48};
 use solana_program::log::sol_log_compute_units;
 
 use crate::accounts_ix::*;
+use crate::health::{HealthCache, HealthType, Prices, TokenInfo};
 use crate::i80f48::LowPrecisionDivision;
 
 #[inline(never)]
@@ -95,5 +96,38 @@ pub fn benchmark(_ctx: Context<Benchmark>) -> Result<()> {
         run_bench("division_u64", || a.checked_div(b).unwrap());
     }
 
+    // health computation cost grows with the number of active positions
+    for (token_count, name) in [
+        (1u16, "health_init_1_tokens"),
+        (4, "health_init_4_tokens"),
+        (8, "health_init_8_tokens"),
+    ] {
+        let health_cache = HealthCache {
+            token_infos: (0..token_count)
+                .map(|i| {
+                    let price = I80F48::from(s + i as i64);
+                    TokenInfo {
+                        token_index: i,
+                        maint_asset_weight: I80F48::from_num(0.9),
+                        init_asset_weight: I80F48::from_num(0.8),
+                        init_scaled_asset_weight: I80F48::from_num(0.8),
+                        maint_liab_weight: I80F48::from_num(1.1),
+                        init_liab_weight: I80F48::from_num(1.2),
+                        init_scaled_liab_weight: I80F48::from_num(1.2),
+                        prices: Prices {
+                            oracle: price,
+                            stable: price,
+                        },
+                        balance_spot: I80F48::from(if i % 2 == 0 { t } else { -t }),
+                    }
+                })
+                .collect(),
+            serum3_infos: vec![],
+            perp_infos: vec![],
+            being_liquidated: false,
+        };
+        run_bench(name, || health_cache.health(HealthType::Init));
+    }
+
     Ok(())
 }
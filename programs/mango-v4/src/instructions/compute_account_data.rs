@@ -1,6 +1,17 @@
 use crate::accounts_ix::*;
-use crate::{error::MangoError, events::MangoAccountData, health::*, state::*};
+use crate::events::{
+    MangoAccountData, MangoAccountDataEntry, MangoAccountDataPage, MangoAccountPositions,
+    PerpPositionData, Serum3PositionData, TokenPositionData,
+};
+use crate::{error::MangoError, health::*, state::*};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{set_return_data, MAX_RETURN_DATA};
+
+/// Number of entries per page of paginated return data
+///
+/// Keeps the serialized page below MAX_RETURN_DATA. Used by compute_account_data_page
+/// and compute_health_breakdown.
+pub const RETURN_DATA_PAGE_ENTRIES: usize = 16;
 
 pub fn compute_account_data(ctx: Context<ComputeAccountData>) -> Result<()> {
     let group_pk = ctx.accounts.group.key();
@@ -19,6 +30,7 @@ pub fn compute_account_data(ctx: Context<ComputeAccountData>) -> Result<()> {
     let maint_health = health_cache.health(HealthType::Maint);
 
     let equity = compute_equity(&account.borrow(), &account_retriever)?;
+    let positions = compute_positions(&account.borrow(), &account_retriever)?;
 
     // Potentially too big for the stack!
     emit!(MangoAccountData {
@@ -26,6 +38,137 @@ pub fn compute_account_data(ctx: Context<ComputeAccountData>) -> Result<()> {
         maint_health,
         equity,
     });
+    emit!(positions);
 
     Ok(())
 }
+
+/// Like compute_account_data, but provides the health, equity and positions as
+/// return data, so simulating clients don't need to parse logs
+///
+/// The equity and position entries are split into pages of RETURN_DATA_PAGE_ENTRIES.
+pub fn compute_account_data_page(ctx: Context<ComputeAccountData>, page: u8) -> Result<()> {
+    let group_pk = ctx.accounts.group.key();
+
+    let account = ctx.accounts.account.load_full()?;
+
+    let account_retriever = ScanningAccountRetriever::new(ctx.remaining_accounts, &group_pk)?;
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let health_cache = new_health_cache(&account.borrow(), &account_retriever, now_ts)?;
+
+    let equity = compute_equity(&account.borrow(), &account_retriever)?;
+    let positions = compute_positions(&account.borrow(), &account_retriever)?;
+
+    let entries = equity
+        .tokens
+        .into_iter()
+        .map(MangoAccountDataEntry::TokenEquity)
+        .chain(
+            equity
+                .perps
+                .into_iter()
+                .map(MangoAccountDataEntry::PerpEquity),
+        )
+        .chain(
+            positions
+                .tokens
+                .into_iter()
+                .map(MangoAccountDataEntry::TokenPosition),
+        )
+        .chain(
+            positions
+                .serum3
+                .into_iter()
+                .map(MangoAccountDataEntry::Serum3Position),
+        )
+        .chain(
+            positions
+                .perps
+                .into_iter()
+                .map(MangoAccountDataEntry::PerpPosition),
+        )
+        .collect::<Vec<_>>();
+    let (page_count, entries) = return_data_page(entries, page)?;
+
+    set_return_data_checked(&MangoAccountDataPage {
+        init_health: health_cache.health(HealthType::Init),
+        maint_health: health_cache.health(HealthType::Maint),
+        page,
+        page_count,
+        entries,
+    })
+}
+
+/// Returns the number of pages and the entries on `page`
+///
+/// There's always at least one page, even if there are no entries.
+pub(crate) fn return_data_page<T>(entries: Vec<T>, page: u8) -> Result<(u8, Vec<T>)> {
+    let page_count =
+        (entries.len().max(1) + RETURN_DATA_PAGE_ENTRIES - 1) / RETURN_DATA_PAGE_ENTRIES;
+    require_gt!(page_count, page as usize);
+    let entries = entries
+        .into_iter()
+        .skip(page as usize * RETURN_DATA_PAGE_ENTRIES)
+        .take(RETURN_DATA_PAGE_ENTRIES)
+        .collect();
+    Ok((page_count.try_into().unwrap(), entries))
+}
+
+pub(crate) fn set_return_data_checked<T: AnchorSerialize>(data: &T) -> Result<()> {
+    let bytes = data.try_to_vec()?;
+    require_gte!(MAX_RETURN_DATA, bytes.len());
+    set_return_data(&bytes);
+    Ok(())
+}
+
+fn compute_positions(
+    account: &MangoAccountRef,
+    retriever: &ScanningAccountRetriever,
+) -> Result<MangoAccountPositions> {
+    let tokens = account
+        .active_token_positions()
+        .map(|position| {
+            let (bank, oracle_price) = retriever.scanned_bank_and_oracle(position.token_index)?;
+            Ok(TokenPositionData {
+                token_index: position.token_index,
+                native: position.native(bank),
+                oracle_price,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let serum3 = account
+        .active_serum3_orders()
+        .map(|serum3| {
+            let oo = retriever.scanned_serum_oo(&serum3.open_orders)?;
+            Ok(Serum3PositionData {
+                market_index: serum3.market_index,
+                base_token_index: serum3.base_token_index,
+                quote_token_index: serum3.quote_token_index,
+                base_native_total: oo.native_coin_total,
+                quote_native_total: oo.native_pc_total,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let perps = account
+        .active_perp_positions()
+        .map(|position| {
+            let (perp_market, oracle_price) =
+                retriever.scanned_perp_market_and_oracle(position.market_index)?;
+            Ok(PerpPositionData {
+                perp_market_index: position.market_index,
+                base_position_native: position.base_position_native(perp_market),
+                quote_position_native: position.quote_position_native(),
+                oracle_price,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(MangoAccountPositions {
+        tokens,
+        serum3,
+        perps,
+    })
+}
@@ -0,0 +1,76 @@
+use crate::accounts_ix::*;
+use crate::events::{
+    HealthContribution, MangoAccountHealthBreakdown, PerpHealthContribution,
+    Serum3HealthContribution, TokenHealthContribution,
+};
+use crate::{health::*, state::*};
+use anchor_lang::prelude::*;
+
+use super::compute_account_data::{return_data_page, set_return_data_checked};
+
+/// Provides each position's contribution to init and maint health as return data
+///
+/// The contributions are split into pages of RETURN_DATA_PAGE_ENTRIES.
+pub fn compute_health_breakdown(ctx: Context<ComputeHealthBreakdown>, page: u8) -> Result<()> {
+    let group_pk = ctx.accounts.group.key();
+
+    let account = ctx.accounts.account.load_full()?;
+
+    let account_retriever = ScanningAccountRetriever::new(ctx.remaining_accounts, &group_pk)?;
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let health_cache = new_health_cache(&account.borrow(), &account_retriever, now_ts)?;
+
+    let (init_tokens, init_serum3, init_perps) = health_cache.health_breakdown(HealthType::Init);
+    let (maint_tokens, maint_serum3, maint_perps) =
+        health_cache.health_breakdown(HealthType::Maint);
+
+    let contributions = health_cache
+        .token_infos
+        .iter()
+        .zip(init_tokens.into_iter().zip(maint_tokens.into_iter()))
+        .map(|(info, (init, maint))| {
+            HealthContribution::Token(TokenHealthContribution {
+                token_index: info.token_index,
+                init,
+                maint,
+            })
+        })
+        .chain(
+            health_cache
+                .serum3_infos
+                .iter()
+                .zip(init_serum3.into_iter().zip(maint_serum3.into_iter()))
+                .map(|(info, (init, maint))| {
+                    HealthContribution::Serum3(Serum3HealthContribution {
+                        market_index: info.market_index,
+                        init,
+                        maint,
+                    })
+                }),
+        )
+        .chain(
+            health_cache
+                .perp_infos
+                .iter()
+                .zip(init_perps.into_iter().zip(maint_perps.into_iter()))
+                .map(|(info, (init, maint))| {
+                    HealthContribution::Perp(PerpHealthContribution {
+                        perp_market_index: info.perp_market_index,
+                        init,
+                        maint,
+                    })
+                }),
+        )
+        .collect::<Vec<_>>();
+    let (page_count, contributions) = return_data_page(contributions, page)?;
+
+    // Clients are expected to simulate this instruction and read the return data
+    set_return_data_checked(&MangoAccountHealthBreakdown {
+        init_health: health_cache.health(HealthType::Init),
+        maint_health: health_cache.health(HealthType::Maint),
+        page,
+        page_count,
+        contributions,
+    })
+}
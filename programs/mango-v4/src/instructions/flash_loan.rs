@@ -2,7 +2,7 @@ use crate::accounts_ix::*;
 use crate::accounts_zerocopy::*;
 use crate::error::*;
 use crate::group_seeds;
-use crate::health::{new_fixed_order_account_retriever, new_health_cache, AccountRetriever};
+use crate::health::{new_fixed_order_account_retriever, new_health_cache};
 use crate::logs::{emit_stack, FlashLoanLogV3, FlashLoanTokenDetailV3, TokenBalanceLog};
 use crate::state::*;
 
@@ -69,7 +69,13 @@ pub fn flash_loan_begin<'key, 'accounts, 'remaining, 'info>(
     {
         let mut bank = bank_ai.load_mut::<Bank>()?;
         require_keys_eq!(bank.group, group_ai.key());
-        require_keys_eq!(bank.vault, *vault_ai.key);
+        require_msg_typed!(
+            bank.vault == *vault_ai.key,
+            MangoError::VaultBankMismatch,
+            "bank vault {} does not match vault {}",
+            bank.vault,
+            vault_ai.key
+        );
 
         require_msg!(
             !seen_token_indexes.contains(&bank.token_index),
@@ -122,8 +128,9 @@ pub fn flash_loan_begin<'key, 'accounts, 'remaining, 'info>(
 
         // Forbid FlashLoanBegin to be called from CPI (it does not have to be the first instruction)
         let current_ix = tx_instructions::load_instruction_at_checked(current_index, ixs)?;
-        require_msg!(
+        require_msg_typed!(
             &current_ix.program_id == program_id,
+            MangoError::CpiForbidden,
             "FlashLoanBegin must be a top-level instruction"
         );
 
@@ -139,13 +146,14 @@ pub fn flash_loan_begin<'key, 'accounts, 'remaining, 'info>(
             };
 
             if account.fixed.is_delegate(*owner_pk) {
-                require_msg!(
+                require_msg_typed!(
                     ix.program_id == AssociatedToken::id()
                         || ix.program_id == jupiter_mainnet_3::ID
                         || ix.program_id == jupiter_mainnet_4::ID
                         || ix.program_id == jupiter_mainnet_6::ID
                         || ix.program_id == compute_budget::ID
                         || ix.program_id == crate::id(),
+                    MangoError::UnexpectedFlashLoanInstruction,
                     "delegate is only allowed to pass in ixs to ATA or Jupiter v3/v4/v6 programs, passed ({})", ix.program_id
                 );
             }
@@ -153,9 +161,10 @@ pub fn flash_loan_begin<'key, 'accounts, 'remaining, 'info>(
             // Check that the mango program key is not used
             if ix.program_id == crate::id() {
                 // must be the FlashLoanEnd instruction
-                require!(
+                require_msg_typed!(
                     ix.data[0..8] == crate::instruction::FlashLoanEndV2::discriminator(),
-                    MangoError::SomeError
+                    MangoError::UnexpectedFlashLoanInstruction,
+                    "the only mango instruction allowed after FlashLoanBegin is FlashLoanEnd"
                 );
                 // the correct number of loans is passed to the End instruction
                 require_eq!(ix.data[8] as usize, num_loans);
@@ -172,6 +181,19 @@ pub fn flash_loan_begin<'key, 'accounts, 'remaining, 'info>(
                     require_msg!(*begin_account.key == end_account.pubkey, "the trailing vault, token and group accounts passed to FlashLoanBegin and End must match, found {} on begin and {} on end", begin_account.key, end_account.pubkey);
                 }
 
+                // check that the banks are passed to End too, among the health accounts
+                let end_health_accounts = &ix.accounts[..ix.accounts.len() - begin_accounts.len()];
+                for bank_ai in banks.iter() {
+                    require_msg_typed!(
+                        end_health_accounts
+                            .iter()
+                            .any(|meta| meta.pubkey == *bank_ai.key),
+                        MangoError::VaultBankMismatch,
+                        "bank {} passed to FlashLoanBegin is missing in FlashLoanEnd",
+                        bank_ai.key
+                    );
+                }
+
                 // No need to check any instructions after the end instruction.
                 // "Duplicate FlashLoanEnd" is guarded against the same way as "End without Begin":
                 // The End instruction requires at least one bank-vault pair and that bank
@@ -181,14 +203,15 @@ pub fn flash_loan_begin<'key, 'accounts, 'remaining, 'info>(
             } else {
                 // ensure no one can cpi into mango either
                 for meta in ix.accounts.iter() {
-                    require_msg!(meta.pubkey != crate::id(), "instructions between FlashLoanBegin and End may not use the Mango program account");
+                    require_msg_typed!(meta.pubkey != crate::id(), MangoError::CpiForbidden, "instructions between FlashLoanBegin and End may not use the Mango program account");
                 }
             }
 
             index += 1;
         }
-        require_msg!(
+        require_msg_typed!(
             found_end,
+            MangoError::MissingFlashLoanEnd,
             "found no FlashLoanEnd instruction in transaction"
         );
     }
@@ -283,6 +306,9 @@ pub fn flash_loan_end<'key, 'accounts, 'remaining, 'info>(
     let remaining_len = ctx.remaining_accounts.len();
     let group_ai = &ctx.remaining_accounts[remaining_len - 1];
     require_keys_eq!(group, group_ai.key());
+    let withdraw_only = AccountLoader::<Group>::try_from(group_ai)?
+        .load()?
+        .is_withdraw_only();
 
     // Find index at which vaults start
     let vaults_len: usize = num_loans.into();
@@ -368,8 +394,9 @@ pub fn flash_loan_end<'key, 'accounts, 'remaining, 'info>(
 
     // all vaults must have had matching banks
     for (i, has_bank) in vaults_with_banks.iter().enumerate() {
-        require_msg!(
+        require_msg_typed!(
             has_bank,
+            MangoError::HealthAccountBankMissing,
             "missing bank for vault index {}, address {}",
             i,
             vaults[i].key
@@ -390,21 +417,16 @@ pub fn flash_loan_end<'key, 'accounts, 'remaining, 'info>(
     let retriever = new_fixed_order_account_retriever(health_ais, &account.borrow())?;
     let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
     let health_cache = new_health_cache(&account.borrow(), &retriever, now_ts)?;
-    let pre_init_health = account.check_health_pre(&health_cache)?;
-
-    // Prices for logging and net borrow checks
-    let mut oracle_prices = vec![];
-    for change in &changes {
-        let (_, oracle_price) = retriever.bank_and_oracle(
-            &account.fixed.group,
-            change.bank_index,
-            change.token_index,
-        )?;
-
-        oracle_prices.push(oracle_price);
-    }
     // Drop retriever as mut bank below uses health_ais
     drop(retriever);
+    let pre_init_health = account.check_health_pre(&health_cache)?;
+
+    // Prices for logging and net borrow checks. The health cache already read the
+    // oracles of all involved banks, no need to load them again.
+    let oracle_prices = changes
+        .iter()
+        .map(|change| Ok(health_cache.token_info(change.token_index)?.prices.oracle))
+        .collect::<Result<Vec<I80F48>>>()?;
 
     // Apply the vault diffs to the bank positions
     let mut deactivated_token_positions = vec![];
@@ -448,6 +470,13 @@ pub fn flash_loan_end<'key, 'accounts, 'remaining, 'info>(
                 MangoError::TokenInReduceOnlyMode
             );
         }
+        if withdraw_only {
+            require!(
+                (native_after_change < 1 || native_after_change <= native)
+                    && (native_after_change >= native || native_after_change >= 0),
+                MangoError::GroupInWithdrawOnlyMode
+            );
+        }
 
         let is_active = bank.change_without_fee(
             position,
@@ -492,6 +521,16 @@ pub fn flash_loan_end<'key, 'accounts, 'remaining, 'info>(
             deposit_index: bank.deposit_index.to_bits(),
             borrow_index: bank.borrow_index.to_bits(),
         });
+
+        // Keep the referrer's part of the fee claimable, see account_claim_referral_fees
+        let referral_fees = loan_origination_fee.floor().to_num::<u64>();
+        if account.fixed.accrue_referral_fees(
+            ReferralFeeSource::Bank,
+            bank.token_index,
+            referral_fees,
+        ) {
+            cm!(bank.referral_fees_unclaimed += referral_fees)?;
+        }
     }
 
     emit_stack(FlashLoanLogV3 {
@@ -504,7 +543,7 @@ pub fn flash_loan_end<'key, 'accounts, 'remaining, 'info>(
     // Check health after account position changes
     let retriever = new_fixed_order_account_retriever(health_ais, &account.borrow())?;
     let health_cache = new_health_cache(&account.borrow(), &retriever, now_ts)?;
-    account.check_health_post(&health_cache, pre_init_health)?;
+    account.check_health_post(&health_cache, pre_init_health, ctx.accounts.account.key())?;
 
     // Deactivate inactive token accounts after health check
     for raw_token_index in deactivated_token_positions {
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
 use crate::error::*;
+use crate::state::*;
 
 pub fn group_create(
     ctx: Context<GroupCreate>,
@@ -14,10 +15,12 @@ pub fn group_create(
     group.group_num = group_num;
     group.admin = ctx.accounts.creator.key();
     group.fast_listing_admin = Pubkey::default();
+    group.security_admin = Pubkey::default();
     group.insurance_vault = ctx.accounts.insurance_vault.key();
     group.insurance_mint = ctx.accounts.insurance_mint.key();
     group.bump = *ctx.bumps.get("group").ok_or(MangoError::SomeError)?;
     group.testing = testing;
     group.version = version;
+    group.layout_version = Group::LAYOUT_VERSION;
     Ok(())
 }
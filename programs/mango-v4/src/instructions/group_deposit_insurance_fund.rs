@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+use crate::accounts_ix::GroupDepositInsuranceFund;
+use crate::logs::{emit_stack, GroupInsuranceFundLog};
+
+/// Anyone can top up the group's insurance fund. Funds can only leave the vault via
+/// bankruptcy handling or group_withdraw_insurance_fund.
+pub fn group_deposit_insurance_fund(
+    ctx: Context<GroupDepositInsuranceFund>,
+    amount: u64,
+) -> Result<()> {
+    token::transfer(ctx.accounts.transfer_ctx(), amount)?;
+    ctx.accounts.insurance_vault.reload()?;
+
+    msg!(
+        "deposited {} into the insurance fund of group {}",
+        amount,
+        ctx.accounts.group.key()
+    );
+
+    emit_stack(GroupInsuranceFundLog {
+        mango_group: ctx.accounts.group.key(),
+        insurance_vault: ctx.accounts.insurance_vault.key(),
+        amount_change: amount.try_into().unwrap(),
+        vault_balance: ctx.accounts.insurance_vault.amount,
+        token_account: ctx.accounts.token_account.key(),
+    });
+
+    Ok(())
+}
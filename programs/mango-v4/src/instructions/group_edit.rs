@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{accounts_ix::*, state::TokenIndex};
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::*;
+use crate::error::*;
+use crate::state::*;
 
 // use case - transfer group ownership to governance, where
 // admin and fast_listing_admin are PDAs
@@ -19,6 +22,10 @@ pub fn group_edit(
     mngo_token_index_opt: Option<TokenIndex>,
     buyback_fees_expiry_interval_opt: Option<u64>,
     allowed_fast_listings_per_interval_opt: Option<u16>,
+    allowed_swap_programs_opt: Option<Vec<Pubkey>>,
+    referral_fee_share_opt: Option<f32>,
+    insurance_token_index_opt: Option<TokenIndex>,
+    allow_cpi_deposit_opt: Option<bool>,
 ) -> Result<()> {
     let mut group = ctx.accounts.group.load_mut()?;
 
@@ -116,5 +123,70 @@ pub fn group_edit(
         group.allowed_fast_listings_per_interval = allowed_fast_listings_per_interval;
     }
 
+    if let Some(allowed_swap_programs) = allowed_swap_programs_opt {
+        require_gte!(
+            group.allowed_swap_programs.len(),
+            allowed_swap_programs.len()
+        );
+        for program in allowed_swap_programs.iter() {
+            require_msg!(
+                *program != crate::id()
+                    && *program != anchor_spl::token::ID
+                    && *program != spl_token_2022::ID
+                    && *program != solana_program::system_program::ID
+                    && *program != solana_address_lookup_table_program::ID,
+                "swap program {} may not be allowed, the group signs for it",
+                program
+            );
+        }
+        msg!(
+            "Allowed swap programs old {:?}, new {:?}",
+            group.allowed_swap_programs,
+            allowed_swap_programs
+        );
+        group.allowed_swap_programs = Default::default();
+        group.allowed_swap_programs[..allowed_swap_programs.len()]
+            .copy_from_slice(&allowed_swap_programs);
+    }
+
+    if let Some(referral_fee_share) = referral_fee_share_opt {
+        require_gte!(referral_fee_share, 0.0);
+        require_gte!(1.0, referral_fee_share);
+        msg!(
+            "Referral fee share old {:?}, new {:?}",
+            group.referral_fee_share,
+            referral_fee_share
+        );
+        group.referral_fee_share = referral_fee_share;
+    }
+
+    if let Some(insurance_token_index) = insurance_token_index_opt {
+        // The bank for the new index must be passed as first remaining account,
+        // to make sure it exists and is for the insurance mint
+        require_neq!(insurance_token_index, TokenIndex::MAX);
+        let bank_ai = ctx.remaining_accounts.first().ok_or_else(|| {
+            error_msg!("the bank for the insurance token index must be passed as remaining account")
+        })?;
+        let bank = bank_ai.load::<Bank>()?;
+        require_keys_eq!(bank.group, ctx.accounts.group.key());
+        require_eq!(bank.token_index, insurance_token_index);
+        require_keys_eq!(bank.mint, group.insurance_mint);
+        msg!(
+            "Insurance token index old {:?}, new {:?}",
+            group.insurance_token_index,
+            insurance_token_index
+        );
+        group.insurance_token_index = insurance_token_index;
+    }
+
+    if let Some(allow_cpi_deposit) = allow_cpi_deposit_opt {
+        msg!(
+            "Allow cpi deposit old {:?}, new {:?}",
+            group.allow_cpi_deposit,
+            allow_cpi_deposit
+        );
+        group.allow_cpi_deposit = u8::from(allow_cpi_deposit);
+    }
+
     Ok(())
 }
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::state::*;
+
+pub fn group_migrate(ctx: Context<GroupMigrate>) -> Result<()> {
+    let mut group = ctx.accounts.group.load_mut()?;
+    let old_version = group.layout_version;
+    if group.migrate()? {
+        msg!(
+            "Group layout version old {}, new {}",
+            old_version,
+            group.layout_version
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+
+// Withdraw-only mode sits between normal operation and disabling instructions
+// with the ix gate: accounts can still reduce their exposure, but not add to it.
+pub fn group_toggle_withdraw_only(
+    ctx: Context<GroupToggleWithdrawOnly>,
+    withdraw_only: bool,
+) -> Result<()> {
+    let mut group = ctx.accounts.group.load_mut()?;
+
+    // account constraint #1
+    // security admin can only enable, disabling needs the group admin
+    if withdraw_only {
+        require!(
+            group.admin == ctx.accounts.admin.key()
+                || group.security_admin == ctx.accounts.admin.key(),
+            MangoError::SomeError
+        );
+    } else {
+        require!(
+            group.admin == ctx.accounts.admin.key(),
+            MangoError::SomeError
+        );
+    }
+
+    msg!(
+        "Withdraw only old {:?}, new {:?}",
+        group.withdraw_only,
+        withdraw_only
+    );
+    group.withdraw_only = u8::from(withdraw_only);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
+use crate::logs::{emit_stack, GroupInsuranceFundLog};
 use crate::{accounts_ix::GroupWithdrawInsuranceFund, group_seeds};
 
 pub fn group_withdraw_insurance_fund(
@@ -10,10 +11,27 @@ pub fn group_withdraw_insurance_fund(
     let group = ctx.accounts.group.load()?;
 
     let group_seeds = group_seeds!(group);
+    let amount = amount.min(ctx.accounts.insurance_vault.amount);
     token::transfer(
         ctx.accounts.transfer_ctx().with_signer(&[group_seeds]),
-        amount.min(ctx.accounts.insurance_vault.amount),
+        amount,
     )?;
+    ctx.accounts.insurance_vault.reload()?;
+
+    msg!(
+        "withdrew {} from the insurance fund of group {} to {}",
+        amount,
+        ctx.accounts.group.key(),
+        ctx.accounts.destination.key()
+    );
+
+    emit_stack(GroupInsuranceFundLog {
+        mango_group: ctx.accounts.group.key(),
+        insurance_vault: ctx.accounts.insurance_vault.key(),
+        amount_change: -i64::try_from(amount).unwrap(),
+        vault_balance: ctx.accounts.insurance_vault.amount,
+        token_account: ctx.accounts.destination.key(),
+    });
 
     Ok(())
 }
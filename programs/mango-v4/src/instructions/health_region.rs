@@ -113,7 +113,7 @@ pub fn health_region_end<'key, 'accounts, 'remaining, 'info>(
     let health_cache = new_health_cache(&account.borrow(), &account_retriever, now_ts)?;
 
     let pre_init_health = I80F48::from(account.fixed.health_region_begin_init_health);
-    account.check_health_post(&health_cache, pre_init_health)?;
+    account.check_health_post(&health_cache, pre_init_health, ctx.accounts.account.key())?;
     account.fixed.health_region_begin_init_health = 0;
 
     Ok(())
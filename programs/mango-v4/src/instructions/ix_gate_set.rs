@@ -95,6 +95,23 @@ pub fn ix_gate_set(ctx: Context<IxGateSet>, ix_gate: u128) -> Result<()> {
         IxGate::TokenConditionalSwapCreateLinearAuction,
     );
     log_if_changed(&group, ix_gate, IxGate::Serum3PlaceOrderV2);
+    log_if_changed(&group, ix_gate, IxGate::PerpLiqAutoDeleverage);
+    log_if_changed(&group, ix_gate, IxGate::GroupDepositInsuranceFund);
+    log_if_changed(&group, ix_gate, IxGate::PerpTriggerOrderCreate);
+    log_if_changed(&group, ix_gate, IxGate::PerpTriggerOrderCancel);
+    log_if_changed(&group, ix_gate, IxGate::PerpTriggerOrderExecute);
+    log_if_changed(&group, ix_gate, IxGate::AltCreate);
+    log_if_changed(&group, ix_gate, IxGate::MarginTrade);
+    log_if_changed(&group, ix_gate, IxGate::PerpSettlePnlBatch);
+    log_if_changed(&group, ix_gate, IxGate::PerpModifyOrder);
+    log_if_changed(&group, ix_gate, IxGate::PerpPlaceOrders);
+    log_if_changed(&group, ix_gate, IxGate::Serum3PlaceOrders);
+    log_if_changed(&group, ix_gate, IxGate::TokenCheckVaultInvariant);
+    log_if_changed(&group, ix_gate, IxGate::AccountClaimReferralFees);
+    log_if_changed(&group, ix_gate, IxGate::PerpUpdateAdlCandidate);
+    log_if_changed(&group, ix_gate, IxGate::PerpLiqCombined);
+    log_if_changed(&group, ix_gate, IxGate::GroupMigrate);
+    log_if_changed(&group, ix_gate, IxGate::TokenMigrate);
 
     group.ix_gate = ix_gate;
 
@@ -0,0 +1,316 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token;
+use anchor_spl::token::{Token, TokenAccount};
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::*;
+use crate::error::*;
+use crate::health::{new_fixed_order_account_retriever, new_health_cache};
+use crate::logs::{emit_stack, MarginTradeLog, MarginTradeTokenDetail, TokenBalanceLog};
+use crate::state::*;
+
+struct VaultChange {
+    token_index: TokenIndex,
+    bank_index: usize,
+    raw_token_index: usize,
+    vault_index: usize,
+    before: u64,
+}
+
+/// Swap tokens directly out of and into bank vaults
+///
+/// The swap program is called with the group as signer, so it can move tokens
+/// out of the vaults that appear in its accounts. Afterwards the vault balance
+/// changes are applied to the account's token positions, like in flash_loan_end.
+/// Compared to a flash loan this avoids the transfers to and from the user's
+/// token accounts.
+pub fn margin_trade<'key, 'accounts, 'remaining, 'info>(
+    ctx: Context<'key, 'accounts, 'remaining, 'info, MarginTrade<'info>>,
+    num_health_accounts: u8,
+    cpi_data: Vec<u8>,
+) -> Result<()> {
+    let group_pk = ctx.accounts.group.key();
+    let withdraw_only = ctx.accounts.group.load()?.is_withdraw_only();
+    let num_health_accounts = num_health_accounts as usize;
+    require_gte!(ctx.remaining_accounts.len(), num_health_accounts);
+    let (health_ais, cpi_ais) = ctx.remaining_accounts.split_at(num_health_accounts);
+
+    let mut account = ctx.accounts.account.load_full_mut()?;
+
+    // account constraint #1
+    require!(
+        account.fixed.is_owner_or_delegate(ctx.accounts.owner.key()),
+        MangoError::SomeError
+    );
+
+    // Delegates get the same program restrictions as for flash loans
+    if account.fixed.is_delegate(ctx.accounts.owner.key()) {
+        let swap_program = ctx.accounts.swap_program.key();
+        require_msg_typed!(
+            swap_program == jupiter_mainnet_3::ID
+                || swap_program == jupiter_mainnet_4::ID
+                || swap_program == jupiter_mainnet_6::ID,
+            MangoError::SwapProgramNotAllowed,
+            "delegate is only allowed to swap via Jupiter v3/v4/v6 programs, passed ({})",
+            swap_program
+        );
+    }
+
+    // The health accounts start with the banks, like for the fixed order account retriever.
+    // A bank after the first non-bank would not be considered below.
+    let num_banks = health_ais
+        .iter()
+        .take_while(|ai| ai.load::<Bank>().is_ok())
+        .count();
+    require_msg_typed!(
+        health_ais[num_banks..]
+            .iter()
+            .all(|ai| ai.load::<Bank>().is_err()),
+        MangoError::InvalidHealthAccountCount,
+        "the health accounts must start with all banks"
+    );
+
+    // Find the vaults of the health account banks among the swap accounts
+    let mut changes = vec![];
+    let mut max_swap_fee_rate = 0.0f32;
+    for (bank_index, bank_ai) in health_ais[..num_banks].iter().enumerate() {
+        let bank = bank_ai.load::<Bank>()?;
+        require_keys_eq!(bank.group, group_pk);
+
+        let vault_index = match cpi_ais.iter().position(|ai| ai.key == &bank.vault) {
+            Some(i) => i,
+            None => continue,
+        };
+        let vault = Account::<TokenAccount>::try_from(&cpi_ais[vault_index])?;
+
+        // Create the token position now, so we can compute the pre-health with fixed order health accounts
+        let (_, raw_token_index, _) = account.ensure_token_position(bank.token_index)?;
+
+        max_swap_fee_rate = max_swap_fee_rate.max(bank.flash_loan_swap_fee_rate);
+
+        changes.push(VaultChange {
+            token_index: bank.token_index,
+            bank_index,
+            raw_token_index,
+            vault_index,
+            before: vault.amount,
+        });
+    }
+    // The group signs for every account it has authority over. Each token account
+    // with the group as owner, delegate or close authority must be a vault of a bank
+    // in the health accounts, so that any change in its balance is accounted for.
+    for (i, ai) in cpi_ais.iter().enumerate() {
+        require_msg_typed!(
+            ai.key != &crate::id(),
+            MangoError::CpiForbidden,
+            "the swap accounts may not include the Mango program account"
+        );
+        // This includes the group's lookup tables, which have the group as authority
+        require_msg_typed!(
+            ai.key != &solana_address_lookup_table_program::ID
+                && ai.owner != &solana_address_lookup_table_program::ID,
+            MangoError::CpiForbidden,
+            "the swap accounts may not include address lookup tables, passed ({})",
+            ai.key
+        );
+        if changes.iter().any(|c| c.vault_index == i) {
+            continue;
+        }
+        if let Some(token_account) = unpack_any_token_account(ai)? {
+            require_msg_typed!(
+                token_account.owner != group_pk
+                    && token_account.delegate != COption::Some(group_pk)
+                    && token_account.close_authority != COption::Some(group_pk),
+                MangoError::HealthAccountBankMissing,
+                "token account {} has the group as authority, but its bank is not in the health accounts",
+                ai.key
+            );
+        }
+    }
+
+    require_msg!(
+        !changes.is_empty(),
+        "no bank vaults were passed to the swap program"
+    );
+
+    // Check health before balance adjustments
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let retriever = new_fixed_order_account_retriever(health_ais, &account.borrow())?;
+    let health_cache = new_health_cache(&account.borrow(), &retriever, now_ts)?;
+    // Drop retriever as mut bank below uses health_ais
+    drop(retriever);
+    let pre_init_health = account.check_health_pre(&health_cache)?;
+
+    let oracle_prices = changes
+        .iter()
+        .map(|change| Ok(health_cache.token_info(change.token_index)?.prices.oracle))
+        .collect::<Result<Vec<I80F48>>>()?;
+
+    // Call the swap program, with the group signing for the vaults
+    {
+        let (creator, group_num, bump) = {
+            let group = ctx.accounts.group.load()?;
+            (group.creator, group.group_num, group.bump)
+        };
+        let group_num_bytes = group_num.to_le_bytes();
+        let group_seeds: &[&[u8]] = &[
+            b"Group".as_ref(),
+            creator.as_ref(),
+            &group_num_bytes,
+            &[bump],
+        ];
+
+        let instruction = Instruction {
+            program_id: ctx.accounts.swap_program.key(),
+            accounts: cpi_ais
+                .iter()
+                .map(|ai| AccountMeta {
+                    pubkey: *ai.key,
+                    is_signer: ai.is_signer || *ai.key == group_pk,
+                    is_writable: ai.is_writable,
+                })
+                .collect(),
+            data: cpi_data,
+        };
+        let mut account_infos = cpi_ais.to_vec();
+        account_infos.push(ctx.accounts.swap_program.to_account_info());
+        solana_program::program::invoke_signed(&instruction, &account_infos, &[group_seeds])?;
+    }
+
+    // Apply the vault diffs to the bank positions
+    let mut deactivated_token_positions = vec![];
+    let mut token_details = Vec::with_capacity(changes.len());
+    for (change, oracle_price) in changes.iter().zip(oracle_prices.iter()) {
+        let vault = Account::<TokenAccount>::try_from(&cpi_ais[change.vault_index])?;
+        let amount = I80F48::from(vault.amount) - I80F48::from(change.before);
+
+        let mut bank = health_ais[change.bank_index].load_mut::<Bank>()?;
+
+        let position = account.token_position_mut_by_raw_index(change.raw_token_index);
+        let native = position.native(&bank);
+
+        let withdrawn = (-amount).max(I80F48::ZERO);
+        let loan = if native.is_positive() {
+            (withdrawn - native).max(I80F48::ZERO)
+        } else {
+            withdrawn
+        };
+
+        let loan_origination_fee = loan * bank.loan_origination_fee_rate;
+        bank.collected_fees_native += loan_origination_fee;
+
+        let swap_fee = withdrawn * I80F48::from_num(max_swap_fee_rate);
+        bank.collected_fees_native += swap_fee;
+
+        let change_amount = amount - loan_origination_fee - swap_fee;
+        let native_after_change = native + change_amount;
+        if bank.are_deposits_reduce_only() {
+            require!(
+                native_after_change < 1 || native_after_change <= native,
+                MangoError::TokenInReduceOnlyMode
+            );
+        }
+        if bank.are_borrows_reduce_only() {
+            require!(
+                native_after_change >= native || native_after_change >= 0,
+                MangoError::TokenInReduceOnlyMode
+            );
+        }
+        if withdraw_only {
+            require!(
+                (native_after_change < 1 || native_after_change <= native)
+                    && (native_after_change >= native || native_after_change >= 0),
+                MangoError::GroupInWithdrawOnlyMode
+            );
+        }
+
+        let is_active = bank.change_without_fee(position, change_amount, now_ts)?;
+        if !is_active {
+            deactivated_token_positions.push(change.raw_token_index);
+        }
+
+        if change_amount < 0 && native_after_change < 0 {
+            bank.enforce_max_utilization_on_borrow()?;
+            bank.check_net_borrows(*oracle_price)?;
+        } else {
+            bank.enforce_borrows_lte_deposits()?;
+        }
+
+        if change_amount > 0 && native_after_change > 0 {
+            bank.check_deposit_and_oo_limit()?;
+        }
+
+        token_details.push(MarginTradeTokenDetail {
+            token_index: position.token_index,
+            change_amount: amount.to_bits(),
+            loan: loan.to_bits(),
+            loan_origination_fee: loan_origination_fee.to_bits(),
+            swap_fee: swap_fee.to_bits(),
+            deposit_index: bank.deposit_index.to_bits(),
+            borrow_index: bank.borrow_index.to_bits(),
+            price: oracle_price.to_bits(),
+        });
+
+        emit_stack(TokenBalanceLog {
+            mango_group: group_pk,
+            mango_account: ctx.accounts.account.key(),
+            token_index: bank.token_index as u16,
+            indexed_position: position.indexed_position.to_bits(),
+            deposit_index: bank.deposit_index.to_bits(),
+            borrow_index: bank.borrow_index.to_bits(),
+        });
+
+        // Keep the referrer's part of the fee claimable, see account_claim_referral_fees
+        let referral_fees = loan_origination_fee.floor().to_num::<u64>();
+        if account.fixed.accrue_referral_fees(
+            ReferralFeeSource::Bank,
+            bank.token_index,
+            referral_fees,
+        ) {
+            cm!(bank.referral_fees_unclaimed += referral_fees)?;
+        }
+    }
+
+    emit_stack(MarginTradeLog {
+        mango_group: group_pk,
+        mango_account: ctx.accounts.account.key(),
+        swap_program: ctx.accounts.swap_program.key(),
+        token_details,
+    });
+
+    // Check health after account position changes
+    let retriever = new_fixed_order_account_retriever(health_ais, &account.borrow())?;
+    let health_cache = new_health_cache(&account.borrow(), &retriever, now_ts)?;
+    account.check_health_post(&health_cache, pre_init_health, ctx.accounts.account.key())?;
+
+    // Deactivate inactive token accounts after health check
+    for raw_token_index in deactivated_token_positions {
+        account.deactivate_token_position_and_log(raw_token_index, ctx.accounts.account.key());
+    }
+
+    Ok(())
+}
+
+/// Unpacks the base account state of spl-token and token-2022 token accounts
+///
+/// Returns None for other accounts, like mints.
+fn unpack_any_token_account(ai: &AccountInfo) -> Result<Option<spl_token::state::Account>> {
+    if ai.owner != &Token::id() && ai.owner != &spl_token_2022::ID {
+        return Ok(None);
+    }
+    let data = ai.try_borrow_data()?;
+    let len = spl_token::state::Account::LEN;
+    // token-2022 accounts with extensions have an account type byte after the base state
+    let is_account = data.len() == len
+        || (ai.owner == &spl_token_2022::ID && data.len() > len && data[len] == 2);
+    if !is_account {
+        return Ok(None);
+    }
+    Ok(Some(spl_token::state::Account::unpack_from_slice(
+        &data[..len],
+    )?))
+}
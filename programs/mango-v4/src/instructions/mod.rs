@@ -1,23 +1,34 @@
 pub use account_buyback_fees_with_mngo::*;
+pub use account_claim_referral_fees::*;
 pub use account_close::*;
 pub use account_create::*;
+pub use account_create_dao_token_positions::*;
 pub use account_edit::*;
 pub use account_expand::*;
 pub use account_size_migration::*;
 pub use account_toggle_freeze::*;
 pub use admin_perp_withdraw_fees::*;
 pub use admin_token_withdraw_fees::*;
+pub use alt_create::*;
 pub use alt_extend::*;
 pub use alt_set::*;
 pub use benchmark::*;
 pub use compute_account_data::*;
+pub use compute_health_breakdown::*;
 pub use flash_loan::*;
 pub use group_close::*;
 pub use group_create::*;
+pub use group_deposit_insurance_fund::*;
 pub use group_edit::*;
+pub use group_migrate::*;
+pub use group_toggle_withdraw_only::*;
 pub use group_withdraw_insurance_fund::*;
 pub use health_region::*;
 pub use ix_gate_set::*;
+pub use margin_trade::*;
+pub use openbook_v2_deregister_market::*;
+pub use openbook_v2_edit_market::*;
+pub use openbook_v2_register_market::*;
 pub use perp_cancel_all_orders::*;
 pub use perp_cancel_all_orders_by_side::*;
 pub use perp_cancel_order::*;
@@ -28,12 +39,21 @@ pub use perp_create_market::*;
 pub use perp_deactivate_position::*;
 pub use perp_edit_market::*;
 pub use perp_force_close_position::*;
+pub use perp_liq_auto_deleverage::*;
 pub use perp_liq_base_or_positive_pnl::*;
+pub use perp_liq_combined::*;
 pub use perp_liq_force_cancel_orders::*;
 pub use perp_liq_negative_pnl_or_bankruptcy::*;
+pub use perp_modify_order::*;
 pub use perp_place_order::*;
+pub use perp_place_orders::*;
 pub use perp_settle_fees::*;
 pub use perp_settle_pnl::*;
+pub use perp_settle_pnl_batch::*;
+pub use perp_trigger_order_cancel::*;
+pub use perp_trigger_order_create::*;
+pub use perp_trigger_order_execute::*;
+pub use perp_update_adl_candidate::*;
 pub use perp_update_funding::*;
 pub use serum3_cancel_all_orders::*;
 pub use serum3_cancel_order::*;
@@ -44,12 +64,14 @@ pub use serum3_deregister_market::*;
 pub use serum3_edit_market::*;
 pub use serum3_liq_force_cancel_orders::*;
 pub use serum3_place_order::*;
+pub use serum3_place_orders::*;
 pub use serum3_register_market::*;
 pub use serum3_settle_funds::*;
 pub use stub_oracle_close::*;
 pub use stub_oracle_create::*;
 pub use stub_oracle_set::*;
 pub use token_add_bank::*;
+pub use token_check_vault_invariant::*;
 pub use token_conditional_swap_cancel::*;
 pub use token_conditional_swap_create::*;
 pub use token_conditional_swap_start::*;
@@ -60,31 +82,43 @@ pub use token_edit::*;
 pub use token_force_close_borrows_with_token::*;
 pub use token_liq_bankruptcy::*;
 pub use token_liq_with_token::*;
+pub use token_migrate::*;
 pub use token_register::*;
 pub use token_register_trustless::*;
 pub use token_update_index_and_rate::*;
 pub use token_withdraw::*;
 
 mod account_buyback_fees_with_mngo;
+mod account_claim_referral_fees;
 mod account_close;
 mod account_create;
+mod account_create_dao_token_positions;
 mod account_edit;
 mod account_expand;
 mod account_size_migration;
 mod account_toggle_freeze;
 mod admin_perp_withdraw_fees;
 mod admin_token_withdraw_fees;
+mod alt_create;
 mod alt_extend;
 mod alt_set;
 mod benchmark;
 mod compute_account_data;
+mod compute_health_breakdown;
 mod flash_loan;
 mod group_close;
 mod group_create;
+mod group_deposit_insurance_fund;
 mod group_edit;
+mod group_migrate;
+mod group_toggle_withdraw_only;
 mod group_withdraw_insurance_fund;
 mod health_region;
 mod ix_gate_set;
+mod margin_trade;
+mod openbook_v2_deregister_market;
+mod openbook_v2_edit_market;
+mod openbook_v2_register_market;
 mod perp_cancel_all_orders;
 mod perp_cancel_all_orders_by_side;
 mod perp_cancel_order;
@@ -95,12 +129,21 @@ mod perp_create_market;
 mod perp_deactivate_position;
 mod perp_edit_market;
 mod perp_force_close_position;
+mod perp_liq_auto_deleverage;
 mod perp_liq_base_or_positive_pnl;
+mod perp_liq_combined;
 mod perp_liq_force_cancel_orders;
 mod perp_liq_negative_pnl_or_bankruptcy;
+mod perp_modify_order;
 mod perp_place_order;
+mod perp_place_orders;
 mod perp_settle_fees;
 mod perp_settle_pnl;
+mod perp_settle_pnl_batch;
+mod perp_trigger_order_cancel;
+mod perp_trigger_order_create;
+mod perp_trigger_order_execute;
+mod perp_update_adl_candidate;
 mod perp_update_funding;
 mod serum3_cancel_all_orders;
 mod serum3_cancel_order;
@@ -111,12 +154,14 @@ mod serum3_deregister_market;
 mod serum3_edit_market;
 mod serum3_liq_force_cancel_orders;
 mod serum3_place_order;
+mod serum3_place_orders;
 mod serum3_register_market;
 mod serum3_settle_funds;
 mod stub_oracle_close;
 mod stub_oracle_create;
 mod stub_oracle_set;
 mod token_add_bank;
+mod token_check_vault_invariant;
 mod token_conditional_swap_cancel;
 mod token_conditional_swap_create;
 mod token_conditional_swap_start;
@@ -127,6 +172,7 @@ mod token_edit;
 mod token_force_close_borrows_with_token;
 mod token_liq_bankruptcy;
 mod token_liq_with_token;
+mod token_migrate;
 mod token_register;
 mod token_register_trustless;
 mod token_update_index_and_rate;
@@ -0,0 +1,7 @@
+use crate::accounts_ix::*;
+use anchor_lang::prelude::*;
+
+/// The market and index reservation accounts are closed by the account constraints.
+pub fn openbook_v2_deregister_market(_ctx: Context<OpenbookV2DeregisterMarket>) -> Result<()> {
+    Ok(())
+}
@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 use bytemuck::cast_ref;
+use fixed::types::I80F48;
 
 use crate::error::MangoError;
 use crate::state::*;
 
 use crate::accounts_ix::*;
-use crate::logs::{emit_perp_balances, emit_stack, FillLogV3};
+use crate::logs::{emit_perp_balances, emit_stack, FillLogV4};
 
 /// Load a mango account by key from the list of account infos.
 ///
@@ -54,6 +55,7 @@ pub fn perp_consume_events(ctx: Context<PerpConsumeEvents>, limit: usize) -> Res
     let perp_market_index = perp_market.perp_market_index;
     let mut event_queue = ctx.accounts.event_queue.load_mut()?;
     let mango_account_ais = &ctx.remaining_accounts;
+    let mut processed_events: u32 = 0;
 
     for _ in 0..limit {
         let event = match event_queue.peek_front() {
@@ -66,7 +68,7 @@ pub fn perp_consume_events(ctx: Context<PerpConsumeEvents>, limit: usize) -> Res
                 let fill: &FillEvent = cast_ref(event);
 
                 // handle self trade separately because of rust borrow checker
-                let (maker_closed_pnl, taker_closed_pnl) = if fill.maker == fill.taker {
+                let (maker_pnl, taker_pnl, maker_base, taker_base) = if fill.maker == fill.taker {
                     load_mango_account!(
                         maker_taker,
                         fill.maker,
@@ -85,14 +87,11 @@ pub fn perp_consume_events(ctx: Context<PerpConsumeEvents>, limit: usize) -> Res
                         &mut perp_market,
                         fill,
                     )?;
-                    emit_perp_balances(
-                        group_key,
-                        fill.maker,
-                        maker_taker.perp_position(perp_market_index).unwrap(),
-                        &perp_market,
-                    );
+                    let perp_position = maker_taker.perp_position(perp_market_index).unwrap();
+                    emit_perp_balances(group_key, fill.maker, perp_position, &perp_market);
                     let closed_pnl = maker_realized_pnl + taker_realized_pnl;
-                    (closed_pnl, closed_pnl)
+                    let base_position = perp_position.base_position_lots();
+                    (closed_pnl, closed_pnl, base_position, base_position)
                 } else {
                     load_mango_account!(maker, fill.maker, mango_account_ais, group, event_queue);
                     load_mango_account!(taker, fill.taker, mango_account_ais, group, event_queue);
@@ -105,22 +104,23 @@ pub fn perp_consume_events(ctx: Context<PerpConsumeEvents>, limit: usize) -> Res
                     )?;
                     let taker_realized_pnl =
                         taker.execute_perp_taker(perp_market_index, &mut perp_market, fill)?;
-                    emit_perp_balances(
-                        group_key,
-                        fill.maker,
-                        maker.perp_position(perp_market_index).unwrap(),
-                        &perp_market,
-                    );
-                    emit_perp_balances(
-                        group_key,
-                        fill.taker,
-                        taker.perp_position(perp_market_index).unwrap(),
-                        &perp_market,
-                    );
+                    let maker_perp_position = maker.perp_position(perp_market_index).unwrap();
+                    let taker_perp_position = taker.perp_position(perp_market_index).unwrap();
+                    emit_perp_balances(group_key, fill.maker, maker_perp_position, &perp_market);
+                    emit_perp_balances(group_key, fill.taker, taker_perp_position, &perp_market);
 
-                    (maker_realized_pnl, taker_realized_pnl)
+                    (
+                        maker_realized_pnl,
+                        taker_realized_pnl,
+                        maker_perp_position.base_position_lots(),
+                        taker_perp_position.base_position_lots(),
+                    )
                 };
-                emit_stack(FillLogV3 {
+
+                let quote_native = I80F48::from(perp_market.quote_lot_size)
+                    * I80F48::from(fill.price)
+                    * I80F48::from(fill.quantity);
+                emit_stack(FillLogV4 {
                     mango_group: group_key,
                     market_index: perp_market_index,
                     taker_side: fill.taker_side as u8,
@@ -137,8 +137,12 @@ pub fn perp_consume_events(ctx: Context<PerpConsumeEvents>, limit: usize) -> Res
                     taker_fee: fill.taker_fee,
                     price: fill.price,
                     quantity: fill.quantity,
-                    maker_closed_pnl: maker_closed_pnl.to_num(),
-                    taker_closed_pnl: taker_closed_pnl.to_num(),
+                    maker_closed_pnl: maker_pnl.to_num(),
+                    taker_closed_pnl: taker_pnl.to_num(),
+                    maker_fee_paid: (quote_native * I80F48::from_num(fill.maker_fee)).to_num(),
+                    taker_fee_paid: (quote_native * I80F48::from_num(fill.taker_fee)).to_num(),
+                    maker_base_position: maker_base,
+                    taker_base_position: taker_base,
                 });
             }
             EventType::Out => {
@@ -159,6 +163,19 @@ pub fn perp_consume_events(ctx: Context<PerpConsumeEvents>, limit: usize) -> Res
 
         // consume this event
         event_queue.pop_front()?;
+        processed_events += 1;
+    }
+
+    // The keeper may also be a maker or taker, so it's only loaded after all events
+    // were processed. Returning early above skips the reward, which is fine since
+    // events are only left unprocessed when accounts are missing.
+    if let Some(keeper_loader) = ctx.accounts.keeper.as_ref() {
+        let reward =
+            I80F48::from_num(perp_market.keeper_reward_per_event) * I80F48::from(processed_events);
+        let mut keeper = keeper_loader.load_full_mut()?;
+        let paid = keeper.pay_perp_keeper_reward(&mut perp_market, reward);
+        msg!("keeper reward {}", paid);
     }
+
     Ok(())
 }
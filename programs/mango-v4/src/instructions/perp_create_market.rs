@@ -7,6 +7,7 @@ use crate::state::*;
 use crate::util::fill_from_str;
 
 use crate::accounts_ix::*;
+use crate::address_lookup_table_program;
 use crate::logs::{emit_stack, PerpMarketMetaDataLog};
 
 #[allow(clippy::too_many_arguments)]
@@ -95,7 +96,19 @@ pub fn perp_create_market(
         fees_withdrawn: 0,
         platform_liquidation_fee: I80F48::from_num(platform_liquidation_fee),
         accrued_liquidation_fees: I80F48::ZERO,
-        reserved: [0; 1848],
+        keeper_reward_per_event: 0.0,
+        keeper_reward_funding_per_hour: 0.0,
+        oracle_price_band: 0.0,
+        funding_period_seconds: 0,
+        dust_threshold: 0.0,
+        padding5: Default::default(),
+        referral_fees_unclaimed: 0,
+        adl_candidate_long: Pubkey::default(),
+        adl_candidate_short: Pubkey::default(),
+        adl_candidate_long_score: 0.0,
+        adl_candidate_short_score: 0.0,
+        referral_fees_paid: 0,
+        reserved: [0; 1728],
     };
 
     let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
@@ -125,5 +138,19 @@ pub fn perp_create_market(
         oracle: ctx.accounts.oracle.key(),
     });
 
+    address_lookup_table_program::maybe_extend_group_alt(
+        &ctx.accounts.group,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.perp_market.key(),
+            ctx.accounts.oracle.key(),
+            ctx.accounts.bids.key(),
+            ctx.accounts.asks.key(),
+            ctx.accounts.event_queue.key(),
+        ],
+    )?;
+
     Ok(())
 }
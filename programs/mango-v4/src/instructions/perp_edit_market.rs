@@ -40,6 +40,11 @@ pub fn perp_edit_market(
     name_opt: Option<String>,
     force_close_opt: Option<bool>,
     platform_liquidation_fee_opt: Option<f32>,
+    keeper_reward_per_event_opt: Option<f32>,
+    keeper_reward_funding_per_hour_opt: Option<f32>,
+    oracle_price_band_opt: Option<f32>,
+    funding_period_seconds_opt: Option<u32>,
+    dust_threshold_opt: Option<f32>,
 ) -> Result<()> {
     let group = ctx.accounts.group.load()?;
 
@@ -171,6 +176,7 @@ pub fn perp_edit_market(
     }
 
     if let Some(min_funding) = min_funding_opt {
+        require_gte!(0.0, min_funding);
         msg!(
             "Min funding: old - {:?}, new - {:?}",
             perp_market.min_funding,
@@ -180,6 +186,7 @@ pub fn perp_edit_market(
         require_group_admin = true;
     }
     if let Some(max_funding) = max_funding_opt {
+        require_gte!(max_funding, 0.0);
         msg!(
             "Max funding: old - {:?}, new - {:?}",
             perp_market.max_funding,
@@ -188,6 +195,30 @@ pub fn perp_edit_market(
         perp_market.max_funding = I80F48::from_num(max_funding);
         require_group_admin = true;
     }
+    if min_funding_opt.is_some() || max_funding_opt.is_some() {
+        require_gte!(perp_market.max_funding, perp_market.min_funding);
+    }
+    if let Some(funding_period_seconds) = funding_period_seconds_opt {
+        // at least an hour: funding is applied for at most an hour per update
+        require_gte!(funding_period_seconds, 3600);
+        msg!(
+            "Funding period seconds: old - {:?}, new - {:?}",
+            perp_market.funding_period_seconds,
+            funding_period_seconds
+        );
+        perp_market.funding_period_seconds = funding_period_seconds;
+        require_group_admin = true;
+    }
+    if let Some(dust_threshold) = dust_threshold_opt {
+        require_gte!(dust_threshold, 0.0);
+        msg!(
+            "Dust threshold: old - {:?}, new - {:?}",
+            perp_market.dust_threshold,
+            dust_threshold
+        );
+        perp_market.dust_threshold = dust_threshold;
+        require_group_admin = true;
+    }
     if let Some(impact_quantity) = impact_quantity_opt {
         msg!(
             "Impact quantity: old - {:?}, new - {:?}",
@@ -213,6 +244,12 @@ pub fn perp_edit_market(
             perp_market.base_decimals,
             base_decimals
         );
+        // changing decimals changes the meaning of existing positions' prices
+        require_msg!(
+            perp_market.open_interest == 0,
+            "base decimals can't change while the market has open interest {}",
+            perp_market.open_interest
+        );
         perp_market.base_decimals = base_decimals;
         require_group_admin = true;
     }
@@ -356,6 +393,38 @@ pub fn perp_edit_market(
         require_group_admin = true;
     };
 
+    if let Some(keeper_reward_per_event) = keeper_reward_per_event_opt {
+        require_gte!(keeper_reward_per_event, 0.0);
+        msg!(
+            "Keeper reward per event: old - {:?}, new - {:?}",
+            perp_market.keeper_reward_per_event,
+            keeper_reward_per_event
+        );
+        perp_market.keeper_reward_per_event = keeper_reward_per_event;
+        require_group_admin = true;
+    };
+
+    if let Some(keeper_reward_funding_per_hour) = keeper_reward_funding_per_hour_opt {
+        require_gte!(keeper_reward_funding_per_hour, 0.0);
+        msg!(
+            "Keeper reward funding per hour: old - {:?}, new - {:?}",
+            perp_market.keeper_reward_funding_per_hour,
+            keeper_reward_funding_per_hour
+        );
+        perp_market.keeper_reward_funding_per_hour = keeper_reward_funding_per_hour;
+        require_group_admin = true;
+    };
+
+    if let Some(oracle_price_band) = oracle_price_band_opt {
+        require_gte!(oracle_price_band, 0.0);
+        msg!(
+            "Oracle price band: old - {:?}, new - {:?}",
+            perp_market.oracle_price_band,
+            oracle_price_band
+        );
+        perp_market.oracle_price_band = oracle_price_band;
+    };
+
     // account constraint #1
     if require_group_admin {
         require!(
@@ -41,6 +41,14 @@ pub fn perp_force_close_position(ctx: Context<PerpForceClosePosition>) -> Result
 
     account_a_perp_position.record_trade(&mut perp_market, -base_transfer, quote_transfer);
     account_b_perp_position.record_trade(&mut perp_market, base_transfer, -quote_transfer);
+    perp_market.clear_stale_adl_candidate(
+        &ctx.accounts.account_a.key(),
+        account_a_perp_position.base_position_lots(),
+    );
+    perp_market.clear_stale_adl_candidate(
+        &ctx.accounts.account_b.key(),
+        account_b_perp_position.base_position_lots(),
+    );
 
     emit_perp_balances(
         ctx.accounts.group.key(),
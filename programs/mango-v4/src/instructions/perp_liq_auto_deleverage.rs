@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::error::*;
+use crate::health::*;
+use crate::logs::{emit_perp_balances, emit_stack, PerpLiqAutoDeleverageLog};
+use crate::state::*;
+
+use super::perp_update_adl_candidate::adl_score;
+
+/// Auto-deleveraging: when the insurance fund can't cover a bankrupt account's loss, its
+/// perp base position is closed against an opposing position that is in profit.
+///
+/// The trade happens at the liqee's bankruptcy price, the price at which the liqee's
+/// equity would be zero. That way the counterparty absorbs the liqee's loss by giving
+/// up part of its unsettled profit, instead of the loss being socialized among all
+/// positions in the market.
+///
+/// If the liqee has no base position left, the counterparty takes over its negative
+/// pnl directly instead. The loss is only socialized once no adl candidates are left,
+/// see perp_liq_negative_pnl_or_bankruptcy.
+///
+/// The counterparty must be the market's adl candidate for its side, the highest ranked
+/// profitable position, see perp_update_adl_candidate. It never loses more than its
+/// unsettled pnl in the market and must stay healthy.
+pub fn perp_liq_auto_deleverage(
+    ctx: Context<PerpLiqAutoDeleverage>,
+    max_base_transfer: u64,
+) -> Result<()> {
+    let mango_group = ctx.accounts.group.key();
+
+    let now_slot = Clock::get()?.slot;
+    let now_ts = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    let insurance_token_oracle_price = {
+        let insurance_bank = ctx.accounts.insurance_bank.load()?;
+        let insurance_oracle_ref = &AccountInfoRef::borrow(ctx.accounts.insurance_oracle.as_ref())?;
+        insurance_bank.oracle_price(
+            &OracleAccountInfos::from_reader(insurance_oracle_ref),
+            Some(now_slot),
+        )?
+    };
+
+    let mut liqee = ctx.accounts.liqee.load_full_mut()?;
+    let mut counterparty = ctx.accounts.counterparty.load_full_mut()?;
+
+    require_msg_typed!(
+        !counterparty.fixed.being_liquidated(),
+        MangoError::BeingLiquidated,
+        "counterparty account"
+    );
+
+    // Build the health caches before the perp market is borrowed mutably: the retriever
+    // loads it from the remaining accounts too
+    let (mut liqee_health_cache, mut counterparty_health_cache) = {
+        let retriever = ScanningAccountRetriever::new(ctx.remaining_accounts, &mango_group)
+            .context("create account retriever")?;
+        (
+            new_health_cache(&liqee.borrow(), &retriever, now_ts)?,
+            new_health_cache(&counterparty.borrow(), &retriever, now_ts)?,
+        )
+    };
+
+    if liqee.check_liquidatable(&liqee_health_cache)? != CheckLiquidatable::Liquidatable {
+        return Ok(());
+    }
+
+    let mut perp_market = ctx.accounts.perp_market.load_mut()?;
+    let perp_market_index = perp_market.perp_market_index;
+    let settle_token_index = perp_market.settle_token_index;
+    let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
+    let oracle_price =
+        perp_market.oracle_price(&OracleAccountInfos::from_reader(oracle_ref), Some(now_slot))?;
+
+    // The liqee must be bankrupt: its equity at oracle prices is negative
+    let (assets, liabs) = liqee_health_cache.assets_and_liabs();
+    let equity = assets - liabs;
+    require_msg_typed!(
+        equity.is_negative(),
+        MangoError::IsNotBankrupt,
+        "liqee equity {} is not negative",
+        equity
+    );
+
+    // Insurance fund coverage must be used up before anyone gets deleveraged: the fund
+    // must not be able to cover the loss that would otherwise be socialized
+    if perp_market.elligible_for_group_insurance_fund() {
+        let insurance_value =
+            I80F48::from(ctx.accounts.insurance_vault.amount) * insurance_token_oracle_price;
+        require_msg!(
+            insurance_value < -equity,
+            "insurance fund value {} can cover the liqee's loss {}",
+            insurance_value,
+            -equity
+        );
+    }
+
+    let settle_token_price = liqee_health_cache
+        .token_info(settle_token_index)?
+        .prices
+        .oracle;
+    let equity_settle_native = equity / settle_token_price;
+
+    let liqee_perp_position = liqee.perp_position_mut(perp_market_index)?;
+    require_msg!(
+        !liqee_perp_position.has_open_orders_or_fills(),
+        "liqee must not have open perp orders or fills"
+    );
+    liqee_perp_position.settle_funding(&perp_market);
+    let liqee_base_lots = liqee_perp_position.base_position_lots();
+
+    let counterparty_perp_position = counterparty.perp_position_mut(perp_market_index)?;
+    counterparty_perp_position.settle_funding(&perp_market);
+    let counterparty_base_lots = counterparty_perp_position.base_position_lots();
+    require_msg!(
+        counterparty_base_lots != 0,
+        "counterparty has no perp base position"
+    );
+    require_msg_typed!(
+        liqee_base_lots == 0 || counterparty_base_lots.signum() == -liqee_base_lots.signum(),
+        MangoError::PerpAdlCounterpartyNotOpposing,
+        "counterparty position {} does not oppose liqee position {}",
+        counterparty_base_lots,
+        liqee_base_lots
+    );
+    let counterparty_pnl = counterparty_perp_position.unsettled_pnl(&perp_market, oracle_price)?;
+    require_msg_typed!(
+        counterparty_pnl.is_positive(),
+        MangoError::ProfitabilityMismatch,
+        "counterparty pnl {} is not positive",
+        counterparty_pnl
+    );
+
+    // Only the highest ranked position on the counterparty's side may be deleveraged
+    let counterparty_is_long = counterparty_base_lots > 0;
+    let (adl_candidate, _) = perp_market.adl_candidate(counterparty_is_long);
+    require_msg!(
+        adl_candidate == ctx.accounts.counterparty.key(),
+        "counterparty is not the adl candidate {}",
+        adl_candidate
+    );
+
+    let (liqee_base_change, liqee_quote_change, bankruptcy_price) = if liqee_base_lots != 0 {
+        // Closing the whole liqee position at this price brings its equity to zero.
+        // For a long liqee the price is above the oracle price, for a short one below.
+        let liqee_base_native = liqee_perp_position.base_position_native(&perp_market);
+        let bankruptcy_price = oracle_price - equity_settle_native / liqee_base_native;
+        require_msg!(
+            bankruptcy_price.is_positive(),
+            "bankruptcy price {} is not positive",
+            bankruptcy_price
+        );
+
+        // The counterparty must not lose more than its unsettled pnl
+        let base_lot_size = I80F48::from(perp_market.base_lot_size);
+        let loss_per_lot = (bankruptcy_price - oracle_price).abs() * base_lot_size;
+        let max_base_for_pnl = if loss_per_lot.is_zero() {
+            i64::MAX
+        } else {
+            (counterparty_pnl / loss_per_lot)
+                .floor()
+                .checked_to_num::<i64>()
+                .unwrap_or(i64::MAX)
+        };
+
+        let base_transfer = liqee_base_lots
+            .abs()
+            .min(counterparty_base_lots.abs())
+            .min(max_base_for_pnl)
+            .min(max_base_transfer.try_into().unwrap_or(i64::MAX));
+        require_msg!(base_transfer > 0, "nothing to deleverage");
+
+        let quote_transfer = I80F48::from(base_transfer) * base_lot_size * bankruptcy_price;
+        let liqee_base_change = -liqee_base_lots.signum() * base_transfer;
+        let liqee_quote_change = I80F48::from(liqee_base_lots.signum()) * quote_transfer;
+
+        liqee_perp_position.record_trade(&mut perp_market, liqee_base_change, liqee_quote_change);
+        counterparty_perp_position.record_trade(
+            &mut perp_market,
+            -liqee_base_change,
+            -liqee_quote_change,
+        );
+        (liqee_base_change, liqee_quote_change, bankruptcy_price)
+    } else {
+        // Without a base position, the liqee's negative pnl is taken over directly, like
+        // a liqor does in perp_liq_negative_pnl_or_bankruptcy. The counterparty gives up
+        // unsettled profit until the liqee's equity is zero. This must happen before the
+        // loss can be socialized.
+        let liqee_pnl = liqee_perp_position.unsettled_pnl(&perp_market, oracle_price)?;
+
+        // Conservatively assume the counterparty's settle token balance is negative
+        let settle_info = counterparty_health_cache.token_info(settle_token_index)?;
+        let settle_liab_price =
+            settle_info.prices.liab(HealthType::Maint) * settle_info.maint_liab_weight;
+        let max_for_health = counterparty_health_cache
+            .health(HealthType::Maint)
+            .max(I80F48::ZERO)
+            / settle_liab_price;
+
+        let quote_transfer = (-liqee_pnl)
+            .min(-equity_settle_native)
+            .min(counterparty_pnl)
+            .min(max_for_health)
+            .floor();
+        require_msg!(quote_transfer.is_positive(), "nothing to deleverage");
+
+        liqee_perp_position.record_settle(-quote_transfer, &perp_market);
+        counterparty_perp_position.record_liquidation_quote_change(-quote_transfer);
+        (0, quote_transfer, oracle_price)
+    };
+
+    msg!(
+        "auto-deleveraged {} base lots and {} quote at price {}",
+        liqee_base_change,
+        liqee_quote_change,
+        bankruptcy_price
+    );
+
+    emit_perp_balances(
+        mango_group,
+        ctx.accounts.liqee.key(),
+        liqee_perp_position,
+        &perp_market,
+    );
+    emit_perp_balances(
+        mango_group,
+        ctx.accounts.counterparty.key(),
+        counterparty_perp_position,
+        &perp_market,
+    );
+
+    // Giving up unsettled profit must not make the counterparty liquidatable
+    let counterparty_perp_position = counterparty.perp_position(perp_market_index)?;
+    counterparty_health_cache.recompute_perp_info(counterparty_perp_position, &perp_market)?;
+    let counterparty_maint_health = counterparty_health_cache.health(HealthType::Maint);
+    require_msg_typed!(
+        !counterparty_maint_health.is_negative(),
+        MangoError::HealthMustBePositive,
+        "counterparty maint health {} after deleveraging",
+        counterparty_maint_health
+    );
+
+    // Refresh the candidate's rank, it may no longer be eligible
+    let counterparty_pnl = counterparty_perp_position.unsettled_pnl(&perp_market, oracle_price)?;
+    match adl_score(
+        &counterparty_health_cache,
+        settle_token_index,
+        counterparty_pnl,
+    )? {
+        Some(score) if counterparty_perp_position.base_position_lots() != 0 => {
+            perp_market.set_adl_candidate(
+                counterparty_is_long,
+                ctx.accounts.counterparty.key(),
+                score,
+            );
+        }
+        _ => perp_market.set_adl_candidate(counterparty_is_long, Pubkey::default(), 0.0),
+    }
+
+    // The liqee leaves being_liquidated only once its liquidation end health is restored
+    let liqee_perp_position = liqee.perp_position(perp_market_index)?;
+    liqee_health_cache.recompute_perp_info(liqee_perp_position, &perp_market)?;
+    let liqee_liq_end_health = liqee_health_cache.health(HealthType::LiquidationEnd);
+    liqee
+        .fixed
+        .maybe_recover_from_being_liquidated(liqee_liq_end_health);
+
+    emit_stack(PerpLiqAutoDeleverageLog {
+        mango_group,
+        perp_market_index,
+        liqee: ctx.accounts.liqee.key(),
+        counterparty: ctx.accounts.counterparty.key(),
+        base_transfer_liqee: liqee_base_change,
+        quote_transfer_liqee: liqee_quote_change.to_bits(),
+        bankruptcy_price: bankruptcy_price.to_bits(),
+        oracle_price: oracle_price.to_bits(),
+    });
+
+    Ok(())
+}
@@ -119,6 +119,14 @@ pub fn perp_liq_base_or_positive_pnl(
 
     let liqee_perp_position = liqee.perp_position_mut(perp_market_index)?;
     let liqor_perp_position = liqor.perp_position_mut(perp_market_index)?;
+    perp_market.clear_stale_adl_candidate(
+        &ctx.accounts.liqee.key(),
+        liqee_perp_position.base_position_lots(),
+    );
+    perp_market.clear_stale_adl_candidate(
+        &ctx.accounts.liqor.key(),
+        liqor_perp_position.base_position_lots(),
+    );
 
     emit_perp_balances(
         ctx.accounts.group.key(),
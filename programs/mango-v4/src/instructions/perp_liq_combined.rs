@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::health::*;
+use crate::instructions::{perp_liq_base_or_positive_pnl, token_liq_with_token};
+use crate::state::*;
+
+/// Runs the liquidation steps a liquidator would otherwise send one by one:
+/// - cancel the liqee's orders on the perp market
+/// - reduce the liqee's base position or take over its positive pnl
+/// - liquidate liab_token_index against asset_token_index
+///
+/// Each step behaves like its standalone instruction and does nothing once the liqee
+/// is no longer liquidatable. The token step is skipped if both token indexes are equal.
+///
+/// The health accounts must cover both the liqor and the liqee, like for
+/// perp_liq_base_or_positive_pnl. The liqee must not have open serum3 orders.
+pub fn perp_liq_combined(
+    ctx: Context<PerpLiqCombined>,
+    limit: u8,
+    max_base_transfer: i64,
+    max_pnl_transfer: u64,
+    asset_token_index: TokenIndex,
+    liab_token_index: TokenIndex,
+    max_liab_transfer: I80F48,
+) -> Result<()> {
+    require_keys_neq!(ctx.accounts.liqor.key(), ctx.accounts.liqee.key());
+
+    cancel_perp_orders(&ctx, limit)?;
+
+    let mut perp_accounts = PerpLiqBaseOrPositivePnl {
+        group: ctx.accounts.group.clone(),
+        perp_market: ctx.accounts.perp_market.clone(),
+        oracle: ctx.accounts.oracle.clone(),
+        liqor: ctx.accounts.liqor.clone(),
+        liqor_owner: ctx.accounts.liqor_owner.clone(),
+        liqee: ctx.accounts.liqee.clone(),
+        settle_bank: ctx.accounts.settle_bank.clone(),
+        settle_vault: ctx.accounts.settle_vault.clone(),
+        settle_oracle: ctx.accounts.settle_oracle.clone(),
+    };
+    perp_liq_base_or_positive_pnl(
+        Context::new(
+            ctx.program_id,
+            &mut perp_accounts,
+            ctx.remaining_accounts,
+            BTreeMap::new(),
+        ),
+        max_base_transfer,
+        max_pnl_transfer,
+    )?;
+
+    if asset_token_index != liab_token_index {
+        let mut token_accounts = TokenLiqWithToken {
+            group: ctx.accounts.group.clone(),
+            liqor: ctx.accounts.liqor.clone(),
+            liqor_owner: ctx.accounts.liqor_owner.clone(),
+            liqee: ctx.accounts.liqee.clone(),
+        };
+        token_liq_with_token(
+            Context::new(
+                ctx.program_id,
+                &mut token_accounts,
+                ctx.remaining_accounts,
+                BTreeMap::new(),
+            ),
+            asset_token_index,
+            liab_token_index,
+            max_liab_transfer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Same as perp_liq_force_cancel_orders, but with health accounts for liqor and liqee
+fn cancel_perp_orders(ctx: &Context<PerpLiqCombined>, limit: u8) -> Result<()> {
+    let mut liqee = ctx.accounts.liqee.load_full_mut()?;
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let mut health_cache = {
+        let retriever =
+            ScanningAccountRetriever::new(ctx.remaining_accounts, &ctx.accounts.group.key())
+                .context("create account retriever")?;
+        new_health_cache(&liqee.borrow(), &retriever, now_ts).context("create health cache")?
+    };
+
+    if liqee.check_liquidatable(&health_cache)? != CheckLiquidatable::Liquidatable {
+        return Ok(());
+    }
+
+    let mut perp_market = ctx.accounts.perp_market.load_mut()?;
+    {
+        let mut book = Orderbook {
+            bids: ctx.accounts.bids.load_mut()?,
+            asks: ctx.accounts.asks.load_mut()?,
+        };
+
+        book.cancel_all_orders(
+            &mut liqee.borrow_mut(),
+            ctx.accounts.liqee.as_ref().key,
+            &mut perp_market,
+            limit,
+            None,
+        )?;
+
+        let perp_position = liqee.perp_position(perp_market.perp_market_index)?;
+        health_cache.recompute_perp_info(perp_position, &perp_market)?;
+    }
+
+    let liq_end_health = health_cache.health(HealthType::LiquidationEnd);
+    liqee
+        .fixed
+        .maybe_recover_from_being_liquidated(liq_end_health);
+
+    Ok(())
+}
@@ -55,10 +55,10 @@ pub fn perp_liq_force_cancel_orders(
     //
     // Health check at the end
     //
-    let init_health = health_cache.health(HealthType::LiquidationEnd);
+    let liq_end_health = health_cache.health(HealthType::LiquidationEnd);
     account
         .fixed
-        .maybe_recover_from_being_liquidated(init_health);
+        .maybe_recover_from_being_liquidated(liq_end_health);
 
     Ok(())
 }
@@ -398,10 +398,20 @@ pub(crate) fn liquidation_action(
         let (starting_long_funding, starting_short_funding) =
             (perp_market.long_funding, perp_market.short_funding);
         if insurance_fund_exhausted && remaining_liab > 0 {
-            perp_market.socialize_loss(-remaining_liab)?;
-            liqee_perp_position.record_settle(-remaining_liab, &perp_market);
-            socialized_loss = remaining_liab;
-            msg!("socialized loss: {}", socialized_loss);
+            if perp_market.has_adl_candidate() {
+                // Profitable positions must be auto-deleveraged first, see
+                // perp_liq_auto_deleverage. Stale candidates can be cleared with
+                // perp_update_adl_candidate.
+                msg!(
+                    "not socializing {} while adl candidates exist",
+                    remaining_liab
+                );
+            } else {
+                perp_market.socialize_loss(-remaining_liab)?;
+                liqee_perp_position.record_settle(-remaining_liab, &perp_market);
+                socialized_loss = remaining_liab;
+                msg!("socialized loss: {}", socialized_loss);
+            }
         }
 
         emit_stack(PerpLiqBankruptcyLog {
@@ -821,4 +831,51 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_liq_negative_pnl_or_bankruptcy_adl_candidate() {
+        // Like "bankruptcy, with insurance 1", but with an adl candidate the
+        // remaining loss stays with the liqee instead of being socialized
+        let mut setup = TestSetup::new();
+        {
+            let t = setup.settle_bank.data();
+            t.init_asset_weight = I80F48::from_num(0.9);
+            t.init_liab_weight = I80F48::from_num(1.1);
+            t.maint_asset_weight = I80F48::from_num(0.9);
+            t.maint_liab_weight = I80F48::from_num(1.1);
+            t.stable_price_model.stable_price = 2.0;
+            setup.settle_oracle.data().price = I80F48::from_num(2.0);
+
+            let t = setup.insurance_bank.data();
+            t.stable_price_model.stable_price = 3.0;
+            setup.insurance_oracle.data().price = I80F48::from_num(3.0);
+
+            let p = setup.perp_market.data();
+            p.init_overall_asset_weight = I80F48::from_num(0.0);
+            p.open_interest = 1;
+            p.set_adl_candidate(false, Pubkey::new_unique(), 1.0);
+
+            setup.insurance_vault.amount = 6;
+
+            perp_p(&mut setup.liqee).quote_position_native = I80F48::from_num(-50.0);
+            let settle_bank = setup.settle_bank.data();
+            settle_bank
+                .change_without_fee(settle_p(&mut setup.liqee), I80F48::from_num(40.0), 0)
+                .unwrap();
+            let other_bank = setup.other_bank.data();
+            other_bank
+                .change_without_fee(other_p(&mut setup.liqee), I80F48::from_num(-36.0), 0)
+                .unwrap();
+        }
+
+        let mut result = setup.run(100).unwrap();
+        let insurance_bank = result.insurance_bank.data();
+        assert_eq_f!(
+            insurance_p(&mut result.liqor).native(insurance_bank),
+            6.0,
+            0.01
+        );
+        assert_eq_f!(perp_p(&mut result.liqee).quote_position_native, -41.0, 0.1);
+        assert_eq_f!(result.perp_market.data().long_funding, 0.0, 0.1);
+    }
 }
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+use super::place_perp_orders;
+
+/// Cancels an order by client order id and places a replacement order
+///
+/// Compared to separate cancel and place instructions, this needs only one
+/// health check.
+pub fn perp_modify_order(
+    ctx: Context<PerpModifyOrder>,
+    cancel_client_order_id: u64,
+    order: Order,
+    limit: u8,
+) -> Result<Option<u128>> {
+    // account constraint #1
+    require!(
+        ctx.accounts
+            .account
+            .load()?
+            .is_owner_or_delegate(ctx.accounts.owner.key()),
+        MangoError::SomeError
+    );
+
+    let order_ids = place_perp_orders(
+        &ctx.accounts.group,
+        &ctx.accounts.account,
+        &ctx.accounts.perp_market,
+        &ctx.accounts.bids,
+        &ctx.accounts.asks,
+        &ctx.accounts.event_queue,
+        &ctx.accounts.oracle,
+        ctx.remaining_accounts,
+        Some(cancel_client_order_id),
+        vec![order],
+        limit,
+    )?;
+    Ok(order_ids[0])
+}
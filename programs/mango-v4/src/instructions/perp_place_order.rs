@@ -10,11 +10,58 @@ use crate::state::*;
 #[allow(clippy::too_many_arguments)]
 pub fn perp_place_order(
     ctx: Context<PerpPlaceOrder>,
-    mut order: Order,
+    order: Order,
     limit: u8,
 ) -> Result<Option<u128>> {
-    require_gte!(order.max_base_lots, 0);
-    require_gte!(order.max_quote_lots, 0);
+    // account constraint #1
+    require!(
+        ctx.accounts
+            .account
+            .load()?
+            .is_owner_or_delegate(ctx.accounts.owner.key()),
+        MangoError::SomeError
+    );
+
+    let order_ids = place_perp_orders(
+        &ctx.accounts.group,
+        &ctx.accounts.account,
+        &ctx.accounts.perp_market,
+        &ctx.accounts.bids,
+        &ctx.accounts.asks,
+        &ctx.accounts.event_queue,
+        &ctx.accounts.oracle,
+        ctx.remaining_accounts,
+        None,
+        vec![order],
+        limit,
+    )?;
+    Ok(order_ids[0])
+}
+
+/// Places perp orders for an account, with a single health check.
+///
+/// If cancel_client_order_id_opt is set, that order is canceled first, sharing
+/// the health check with the new orders.
+///
+/// Callers must check that placing the order was authorized.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn place_perp_orders<'info>(
+    group_ai: &AccountLoader<'info, Group>,
+    account_ai: &AccountLoader<'info, MangoAccountFixed>,
+    perp_market_ai: &AccountLoader<'info, PerpMarket>,
+    bids_ai: &AccountLoader<'info, BookSide>,
+    asks_ai: &AccountLoader<'info, BookSide>,
+    event_queue_ai: &AccountLoader<'info, EventQueue>,
+    oracle_ai: &UncheckedAccount<'info>,
+    health_ais: &[AccountInfo<'info>],
+    cancel_client_order_id_opt: Option<u64>,
+    orders: Vec<Order>,
+    limit: u8,
+) -> Result<Vec<Option<u128>>> {
+    for order in orders.iter() {
+        require_gte!(order.max_base_lots, 0);
+        require_gte!(order.max_quote_lots, 0);
+    }
 
     let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
     let oracle_price;
@@ -24,13 +71,13 @@ pub fn perp_place_order(
     // Doing this automatically here makes it impossible for attackers to add orders to the orderbook
     // before triggering the funding computation.
     {
-        let mut perp_market = ctx.accounts.perp_market.load_mut()?;
+        let mut perp_market = perp_market_ai.load_mut()?;
         let book = Orderbook {
-            bids: ctx.accounts.bids.load_mut()?,
-            asks: ctx.accounts.asks.load_mut()?,
+            bids: bids_ai.load_mut()?,
+            asks: asks_ai.load_mut()?,
         };
 
-        let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
+        let oracle_ref = &AccountInfoRef::borrow(oracle_ai.as_ref())?;
         let oracle_state = perp_market.oracle_state(
             &OracleAccountInfos::from_reader(oracle_ref),
             None, // staleness checked in health
@@ -40,17 +87,11 @@ pub fn perp_place_order(
         perp_market.update_funding_and_stable_price(&book, &oracle_state, now_ts)?;
     }
 
-    let mut account = ctx.accounts.account.load_full_mut()?;
-    // account constraint #1
-    require!(
-        account.fixed.is_owner_or_delegate(ctx.accounts.owner.key()),
-        MangoError::SomeError
-    );
-
-    let account_pk = ctx.accounts.account.key();
+    let mut account = account_ai.load_full_mut()?;
+    let account_pk = account_ai.key();
 
     let (perp_market_index, settle_token_index) = {
-        let perp_market = ctx.accounts.perp_market.load()?;
+        let perp_market = perp_market_ai.load()?;
         (
             perp_market.perp_market_index,
             perp_market.settle_token_index,
@@ -66,8 +107,7 @@ pub fn perp_place_order(
     // Pre-health computation, _after_ perp position is created
     //
     let pre_health_opt = if !account.fixed.is_in_health_region() {
-        let retriever =
-            new_fixed_order_account_retriever(ctx.remaining_accounts, &account.borrow())?;
+        let retriever = new_fixed_order_account_retriever(health_ais, &account.borrow())?;
         let health_cache = new_health_cache(&account.borrow(), &retriever, now_ts)
             .context("pre-withdraw init health")?;
         let pre_init_health = account.check_health_pre(&health_cache)?;
@@ -76,45 +116,73 @@ pub fn perp_place_order(
         None
     };
 
-    let mut perp_market = ctx.accounts.perp_market.load_mut()?;
+    let mut perp_market = perp_market_ai.load_mut()?;
     let mut book = Orderbook {
-        bids: ctx.accounts.bids.load_mut()?,
-        asks: ctx.accounts.asks.load_mut()?,
+        bids: bids_ai.load_mut()?,
+        asks: asks_ai.load_mut()?,
     };
 
-    let mut event_queue = ctx.accounts.event_queue.load_mut()?;
-    let group = ctx.accounts.group.load()?;
+    let mut event_queue = event_queue_ai.load_mut()?;
+    let group = group_ai.load()?;
 
     let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
     account
         .fixed
         .expire_buyback_fees(now_ts, group.buyback_fees_expiry_interval);
 
-    let pp = account.perp_position(perp_market_index)?;
-    let effective_pos = pp.effective_base_position_lots();
-    let max_base_lots = if order.reduce_only || perp_market.is_reduce_only() {
-        reduce_only_max_base_lots(pp, &order, perp_market.is_reduce_only())
-    } else {
-        order.max_base_lots
-    };
-    if perp_market.is_reduce_only() {
-        require!(
-            order.reduce_only || max_base_lots == order.max_base_lots,
-            MangoError::MarketInReduceOnlyMode
-        )
-    };
-    order.max_base_lots = max_base_lots;
-
-    let order_id_opt = book.new_order(
-        order,
-        &mut perp_market,
-        &mut event_queue,
-        oracle_price,
-        &mut account.borrow_mut(),
-        &account_pk,
-        now_ts,
-        limit,
-    )?;
+    if let Some(cancel_client_order_id) = cancel_client_order_id_opt {
+        let (slot, _) = account
+            .perp_find_order_with_client_order_id(perp_market_index, cancel_client_order_id)
+            .ok_or_else(|| {
+                error_msg_typed!(
+                    MangoError::PerpOrderIdNotFound,
+                    "could not find perp order with client order id {cancel_client_order_id} in user account"
+                )
+            })?;
+        book.cancel_order_by_slot(
+            &mut account.borrow_mut(),
+            &account_pk,
+            slot,
+            perp_market_index,
+        )?;
+    }
+
+    let mut order_ids = Vec::with_capacity(orders.len());
+    for mut order in orders {
+        let pp = account.perp_position(perp_market_index)?;
+        let effective_pos = pp.effective_base_position_lots();
+        let market_reduce_only = perp_market.is_reduce_only() || group.is_withdraw_only();
+        let max_base_lots = if order.reduce_only || market_reduce_only {
+            reduce_only_max_base_lots(pp, &order, market_reduce_only)
+        } else {
+            order.max_base_lots
+        };
+        if perp_market.is_reduce_only() {
+            require!(
+                order.reduce_only || max_base_lots == order.max_base_lots,
+                MangoError::MarketInReduceOnlyMode
+            )
+        };
+        if group.is_withdraw_only() {
+            require!(
+                order.reduce_only || max_base_lots == order.max_base_lots,
+                MangoError::GroupInWithdrawOnlyMode
+            )
+        };
+        order.max_base_lots = max_base_lots;
+
+        let order_id_opt = book.new_order(
+            order,
+            &mut perp_market,
+            &mut event_queue,
+            oracle_price,
+            &mut account.borrow_mut(),
+            &account_pk,
+            now_ts,
+            limit,
+        )?;
+        order_ids.push(order_id_opt);
+    }
 
     //
     // Health check
@@ -122,10 +190,10 @@ pub fn perp_place_order(
     if let Some((mut health_cache, pre_init_health)) = pre_health_opt {
         let perp_position = account.perp_position(perp_market_index)?;
         health_cache.recompute_perp_info(perp_position, &perp_market)?;
-        account.check_health_post(&health_cache, pre_init_health)?;
+        account.check_health_post(&health_cache, pre_init_health, account_pk)?;
     }
 
-    Ok(order_id_opt)
+    Ok(order_ids)
 }
 
 fn reduce_only_max_base_lots(pp: &PerpPosition, order: &Order, market_reduce_only: bool) -> i64 {
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+use super::place_perp_orders;
+
+/// Maximum number of orders in a single perp_place_orders instruction
+pub const MAX_PERP_PLACE_ORDERS: usize = 8;
+
+/// Places several perp orders with a single health check
+pub fn perp_place_orders(
+    ctx: Context<PerpPlaceOrders>,
+    orders: Vec<Order>,
+    limit: u8,
+) -> Result<()> {
+    require_gte!(MAX_PERP_PLACE_ORDERS, orders.len());
+
+    // account constraint #1
+    require!(
+        ctx.accounts
+            .account
+            .load()?
+            .is_owner_or_delegate(ctx.accounts.owner.key()),
+        MangoError::SomeError
+    );
+
+    place_perp_orders(
+        &ctx.accounts.group,
+        &ctx.accounts.account,
+        &ctx.accounts.perp_market,
+        &ctx.accounts.bids,
+        &ctx.accounts.asks,
+        &ctx.accounts.event_queue,
+        &ctx.accounts.oracle,
+        ctx.remaining_accounts,
+        None,
+        orders,
+        limit,
+    )?;
+    Ok(())
+}
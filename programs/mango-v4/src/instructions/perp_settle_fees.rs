@@ -127,6 +127,17 @@ pub fn perp_settle_fees(ctx: Context<PerpSettleFees>, max_settle_amount: u64) ->
     let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
     let health = compute_health(&account.borrow(), HealthType::Init, &retriever, now_ts)?;
     require!(health >= 0, MangoError::HealthMustBePositive);
+    drop(retriever);
+
+    // Done after the health check, since it changes the account's health accounts
+    let mut settle_bank = ctx.accounts.settle_bank.load_mut()?;
+    let mut perp_market = ctx.accounts.perp_market.load_mut()?;
+    account.perp_dust_deactivate_if_possible(
+        &mut perp_market,
+        &mut settle_bank,
+        now_ts,
+        ctx.accounts.account.key(),
+    )?;
 
     msg!("settled fees = {}", settlement);
     Ok(())
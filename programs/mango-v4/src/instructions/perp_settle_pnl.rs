@@ -73,13 +73,91 @@ pub fn perp_settle_pnl(ctx: Context<PerpSettlePnl>) -> Result<()> {
         None, // staleness checked in health
     )?;
 
+    let (_, fee) = settle_pnl_pair(
+        ctx.accounts.group.key(),
+        &perp_market,
+        &mut settle_bank,
+        oracle_price,
+        settle_token_oracle_price,
+        ctx.accounts.settler.key(),
+        ctx.accounts.account_a.key(),
+        &mut account_a.borrow_mut(),
+        a_liq_end_health,
+        a_maint_health,
+        ctx.accounts.account_b.key(),
+        &mut account_b.borrow_mut(),
+        b_max_settle,
+        now_ts,
+    )?;
+
+    // settler might be the same as account a or b
+    drop(account_a);
+    drop(account_b);
+
+    let mut settler = ctx.accounts.settler.load_full_mut()?;
+    // account constraint #1
+    require!(
+        settler
+            .fixed
+            .is_owner_or_delegate(ctx.accounts.settler_owner.key()),
+        MangoError::SomeError
+    );
+
+    let (settler_token_position, settler_token_raw_index, _) =
+        settler.ensure_token_position(settle_token_index)?;
+    let settler_token_position_active = settle_bank.deposit(settler_token_position, fee, now_ts)?;
+
+    emit_stack(TokenBalanceLog {
+        mango_group: ctx.accounts.group.key(),
+        mango_account: ctx.accounts.settler.key(),
+        token_index: settler_token_position.token_index,
+        indexed_position: settler_token_position.indexed_position.to_bits(),
+        deposit_index: settle_bank.deposit_index.to_bits(),
+        borrow_index: settle_bank.borrow_index.to_bits(),
+    });
+
+    if !settler_token_position_active {
+        settler
+            .deactivate_token_position_and_log(settler_token_raw_index, ctx.accounts.settler.key());
+    }
+
+    Ok(())
+}
+
+/// Settles pnl between a profitable account a and an unprofitable account b
+///
+/// Returns the settlement amount and the fee, which the caller must credit to the settler.
+///
+/// Errors with ProfitabilityMismatch, HealthMustBePositive or SettlementAmountMustBePositive
+/// if there's nothing to settle. In that case only the funding and settle limit updates
+/// have been applied to the perp positions.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn settle_pnl_pair(
+    group: Pubkey,
+    perp_market: &PerpMarket,
+    settle_bank: &mut Bank,
+    oracle_price: I80F48,
+    settle_token_oracle_price: I80F48,
+    settler_pk: Pubkey,
+    account_a_pk: Pubkey,
+    account_a: &mut MangoAccountRefMut,
+    a_liq_end_health: I80F48,
+    a_maint_health: I80F48,
+    account_b_pk: Pubkey,
+    account_b: &mut MangoAccountRefMut,
+    b_max_settle: I80F48,
+    now_ts: u64,
+) -> Result<(I80F48, I80F48)> {
+    let perp_market_index = perp_market.perp_market_index;
+    let settle_token_index = perp_market.settle_token_index;
+
     // Fetch perp position and pnl
     let a_perp_position = account_a.perp_position_mut(perp_market_index)?;
     let b_perp_position = account_b.perp_position_mut(perp_market_index)?;
-    a_perp_position.settle_funding(&perp_market);
-    b_perp_position.settle_funding(&perp_market);
-    let a_pnl = a_perp_position.unsettled_pnl(&perp_market, oracle_price)?;
-    let b_pnl = b_perp_position.unsettled_pnl(&perp_market, oracle_price)?;
+    a_perp_position.settle_funding(perp_market);
+    b_perp_position.settle_funding(perp_market);
+    let a_pnl = a_perp_position.unsettled_pnl(perp_market, oracle_price)?;
+    let b_pnl = b_perp_position.unsettled_pnl(perp_market, oracle_price)?;
 
     // PnL must have opposite signs for there to be a settlement:
     // Account A must be profitable, and B must be unprofitable.
@@ -97,10 +175,10 @@ pub fn perp_settle_pnl(ctx: Context<PerpSettlePnl>) -> Result<()> {
     );
 
     // Apply pnl settle limits
-    a_perp_position.update_settle_limit(&perp_market, now_ts);
-    let a_settleable_pnl = a_perp_position.apply_pnl_settle_limit(&perp_market, a_pnl);
-    b_perp_position.update_settle_limit(&perp_market, now_ts);
-    let b_settleable_pnl = b_perp_position.apply_pnl_settle_limit(&perp_market, b_pnl);
+    a_perp_position.update_settle_limit(perp_market, now_ts);
+    let a_settleable_pnl = a_perp_position.apply_pnl_settle_limit(perp_market, a_pnl);
+    b_perp_position.update_settle_limit(perp_market, now_ts);
+    let b_settleable_pnl = b_perp_position.apply_pnl_settle_limit(perp_market, b_pnl);
 
     require_msg_typed!(
         a_settleable_pnl.is_positive(),
@@ -143,23 +221,13 @@ pub fn perp_settle_pnl(ctx: Context<PerpSettlePnl>) -> Result<()> {
         b_max_settle,
     );
 
-    a_perp_position.record_settle(settlement, &perp_market);
-    b_perp_position.record_settle(-settlement, &perp_market);
-    emit_perp_balances(
-        ctx.accounts.group.key(),
-        ctx.accounts.account_a.key(),
-        a_perp_position,
-        &perp_market,
-    );
-    emit_perp_balances(
-        ctx.accounts.group.key(),
-        ctx.accounts.account_b.key(),
-        b_perp_position,
-        &perp_market,
-    );
+    a_perp_position.record_settle(settlement, perp_market);
+    b_perp_position.record_settle(-settlement, perp_market);
+    emit_perp_balances(group, account_a_pk, a_perp_position, perp_market);
+    emit_perp_balances(group, account_b_pk, b_perp_position, perp_market);
 
     // Compute fee
-    let a_position_value = a_perp_position.base_position_native(&perp_market).abs() * oracle_price;
+    let a_position_value = a_perp_position.base_position_native(perp_market).abs() * oracle_price;
     let a_pnl_value = a_pnl * settle_token_oracle_price;
     let fee = perp_market.compute_settle_fee(
         settlement,
@@ -190,10 +258,15 @@ pub fn perp_settle_pnl(ctx: Context<PerpSettlePnl>) -> Result<()> {
     // Even small loan origination fees could accumulate if a perp position is
     // settled back and forth repeatedly.
     settle_bank.withdraw_without_fee(b_token_position, settlement, now_ts)?;
+    // Settling may create borrows in reduce-only mode, but never for collateral-only tokens
+    require!(
+        b_token_position.indexed_position >= 0 || !settle_bank.is_collateral_only(),
+        MangoError::TokenInReduceOnlyMode
+    );
 
     emit_stack(TokenBalanceLog {
-        mango_group: ctx.accounts.group.key(),
-        mango_account: ctx.accounts.account_a.key(),
+        mango_group: group,
+        mango_account: account_a_pk,
         token_index: settle_token_index,
         indexed_position: a_token_position.indexed_position.to_bits(),
         deposit_index: settle_bank.deposit_index.to_bits(),
@@ -201,55 +274,25 @@ pub fn perp_settle_pnl(ctx: Context<PerpSettlePnl>) -> Result<()> {
     });
 
     emit_stack(TokenBalanceLog {
-        mango_group: ctx.accounts.group.key(),
-        mango_account: ctx.accounts.account_b.key(),
+        mango_group: group,
+        mango_account: account_b_pk,
         token_index: settle_token_index,
         indexed_position: b_token_position.indexed_position.to_bits(),
         deposit_index: settle_bank.deposit_index.to_bits(),
         borrow_index: settle_bank.borrow_index.to_bits(),
     });
 
-    // settler might be the same as account a or b
-    drop(account_a);
-    drop(account_b);
-
-    let mut settler = ctx.accounts.settler.load_full_mut()?;
-    // account constraint #1
-    require!(
-        settler
-            .fixed
-            .is_owner_or_delegate(ctx.accounts.settler_owner.key()),
-        MangoError::SomeError
-    );
-
-    let (settler_token_position, settler_token_raw_index, _) =
-        settler.ensure_token_position(settle_token_index)?;
-    let settler_token_position_active = settle_bank.deposit(settler_token_position, fee, now_ts)?;
-
-    emit_stack(TokenBalanceLog {
-        mango_group: ctx.accounts.group.key(),
-        mango_account: ctx.accounts.settler.key(),
-        token_index: settler_token_position.token_index,
-        indexed_position: settler_token_position.indexed_position.to_bits(),
-        deposit_index: settle_bank.deposit_index.to_bits(),
-        borrow_index: settle_bank.borrow_index.to_bits(),
-    });
-
-    if !settler_token_position_active {
-        settler
-            .deactivate_token_position_and_log(settler_token_raw_index, ctx.accounts.settler.key());
-    }
-
     emit_stack(PerpSettlePnlLog {
-        mango_group: ctx.accounts.group.key(),
-        mango_account_a: ctx.accounts.account_a.key(),
-        mango_account_b: ctx.accounts.account_b.key(),
+        mango_group: group,
+        mango_account_a: account_a_pk,
+        mango_account_b: account_b_pk,
         perp_market_index,
         settlement: settlement.to_bits(),
-        settler: ctx.accounts.settler.key(),
+        settler: settler_pk,
         fee: fee.to_bits(),
     });
 
     msg!("settled pnl = {}, fee = {}", settlement, fee);
-    Ok(())
+
+    Ok((settlement, fee))
 }
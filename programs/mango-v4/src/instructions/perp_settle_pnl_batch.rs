@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::*;
+use crate::error::*;
+use crate::health::{new_health_cache, HealthType, ScanningAccountRetriever};
+use crate::instructions::settle_pnl_pair;
+use crate::logs::{emit_stack, TokenBalanceLog};
+use crate::state::*;
+
+/// Like perp_settle_pnl, but for many account pairs at once
+///
+/// Pairs that have nothing to settle (anymore) are skipped, so that a batch
+/// doesn't fail because a single pair's pnl changed since it was assembled.
+pub fn perp_settle_pnl_batch<'key, 'accounts, 'remaining, 'info>(
+    ctx: Context<'key, 'accounts, 'remaining, 'info, PerpSettlePnlBatch<'info>>,
+    num_pairs: u8,
+) -> Result<()> {
+    require_gt!(num_pairs, 0);
+    let num_accounts = 2 * num_pairs as usize;
+    require_gte!(ctx.remaining_accounts.len(), num_accounts);
+    let (account_ais, health_ais) = ctx.remaining_accounts.split_at(num_accounts);
+
+    let group_pk = ctx.accounts.group.key();
+
+    // Settling changes the health of an account, which was computed upfront.
+    // Cannot settle with yourself either.
+    for (i, ai) in account_ais.iter().enumerate() {
+        require_msg!(
+            !account_ais[..i].iter().any(|other| other.key == ai.key),
+            "account {} appears more than once",
+            ai.key
+        );
+    }
+
+    let (perp_market_index, settle_token_index) = {
+        let perp_market = ctx.accounts.perp_market.load()?;
+        (
+            perp_market.perp_market_index,
+            perp_market.settle_token_index,
+        )
+    };
+
+    let account_loaders = account_ais
+        .iter()
+        .map(|ai| {
+            let loader = AccountLoader::<MangoAccountFixed>::try_from(ai)?;
+            {
+                let account = loader.load()?;
+                require_keys_eq!(account.group, group_pk);
+                require!(account.is_operational(), MangoError::AccountIsFrozen);
+            }
+            Ok(loader)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut accounts = account_loaders
+        .iter()
+        .map(|loader| loader.load_full_mut())
+        .collect::<Result<Vec<_>>>()?;
+
+    // check positions exist, for nicer error messages
+    for (account, ai) in accounts.iter().zip(account_ais.iter()) {
+        account
+            .perp_position(perp_market_index)
+            .with_context(|| format!("account {}", ai.key))?;
+        account
+            .token_position(settle_token_index)
+            .with_context(|| format!("account {}", ai.key))?;
+    }
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    // (a_liq_end_health, a_maint_health, b_max_settle) for each pair
+    let mut pair_healths = Vec::with_capacity(num_pairs as usize);
+    {
+        let retriever = ScanningAccountRetriever::new(health_ais, &group_pk)
+            .context("create account retriever")?;
+        for pair in accounts.chunks(2) {
+            let a_cache = new_health_cache(&pair[0].borrow(), &retriever, now_ts)?;
+            let b_max_settle = new_health_cache(&pair[1].borrow(), &retriever, now_ts)?
+                .perp_max_settle(settle_token_index)?;
+            pair_healths.push((
+                a_cache.health(HealthType::LiquidationEnd),
+                a_cache.health(HealthType::Maint),
+                b_max_settle,
+            ));
+        }
+    }
+
+    let mut settle_bank = ctx.accounts.settle_bank.load_mut()?;
+    let perp_market = ctx.accounts.perp_market.load()?;
+
+    // Verify that the bank is the quote currency bank (#2)
+    require!(
+        settle_bank.token_index == settle_token_index,
+        MangoError::InvalidBank
+    );
+
+    // Get oracle prices
+    let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
+    let oracle_price = perp_market.oracle_price(
+        &OracleAccountInfos::from_reader(oracle_ref),
+        None, // staleness checked in health
+    )?;
+    let settle_oracle_ref = &AccountInfoRef::borrow(ctx.accounts.settle_oracle.as_ref())?;
+    let settle_token_oracle_price = settle_bank.oracle_price(
+        &OracleAccountInfos::from_reader(settle_oracle_ref),
+        None, // staleness checked in health
+    )?;
+
+    let mut total_fee = I80F48::ZERO;
+    for (i, (pair, (a_liq_end_health, a_maint_health, b_max_settle))) in
+        accounts.chunks_mut(2).zip(pair_healths).enumerate()
+    {
+        let (pair_a, pair_b) = pair.split_at_mut(1);
+        let result = settle_pnl_pair(
+            group_pk,
+            &perp_market,
+            &mut settle_bank,
+            oracle_price,
+            settle_token_oracle_price,
+            ctx.accounts.settler.key(),
+            account_ais[2 * i].key(),
+            &mut pair_a[0].borrow_mut(),
+            a_liq_end_health,
+            a_maint_health,
+            account_ais[2 * i + 1].key(),
+            &mut pair_b[0].borrow_mut(),
+            b_max_settle,
+            now_ts,
+        );
+
+        // These errors happen before any balances are changed
+        let nothing_to_settle = [
+            MangoError::ProfitabilityMismatch,
+            MangoError::HealthMustBePositive,
+            MangoError::SettlementAmountMustBePositive,
+        ]
+        .iter()
+        .any(|e| result.is_anchor_error_with_code(e.error_code()));
+        if nothing_to_settle {
+            msg!("skipping pair {}: nothing to settle", i);
+            continue;
+        }
+
+        let (_, fee) = result?;
+        total_fee += fee;
+    }
+
+    // settler might be one of the settled accounts
+    drop(accounts);
+
+    let mut settler = ctx.accounts.settler.load_full_mut()?;
+    // account constraint #1
+    require!(
+        settler
+            .fixed
+            .is_owner_or_delegate(ctx.accounts.settler_owner.key()),
+        MangoError::SomeError
+    );
+
+    let (settler_token_position, settler_token_raw_index, _) =
+        settler.ensure_token_position(settle_token_index)?;
+    let settler_token_position_active =
+        settle_bank.deposit(settler_token_position, total_fee, now_ts)?;
+
+    emit_stack(TokenBalanceLog {
+        mango_group: group_pk,
+        mango_account: ctx.accounts.settler.key(),
+        token_index: settler_token_position.token_index,
+        indexed_position: settler_token_position.indexed_position.to_bits(),
+        deposit_index: settle_bank.deposit_index.to_bits(),
+        borrow_index: settle_bank.borrow_index.to_bits(),
+    });
+
+    if !settler_token_position_active {
+        settler
+            .deactivate_token_position_and_log(settler_token_raw_index, ctx.accounts.settler.key());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, PerpTriggerOrderCancelLog};
+
+pub fn perp_trigger_order_cancel(ctx: Context<PerpTriggerOrderCancel>) -> Result<()> {
+    // The trigger order account is closed by the account constraints
+    let order_id = ctx.accounts.trigger_order.load()?.order_id;
+
+    emit_stack(PerpTriggerOrderCancelLog {
+        mango_group: ctx.accounts.group.key(),
+        mango_account: ctx.accounts.account.key(),
+        trigger_order: ctx.accounts.trigger_order.key(),
+        order_id,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::logs::{emit_stack, PerpTriggerOrderCreateLog};
+use crate::state::*;
+
+#[allow(clippy::too_many_arguments)]
+pub fn perp_trigger_order_create(
+    ctx: Context<PerpTriggerOrderCreate>,
+    order_id: u64,
+    side: Side,
+    trigger_condition: PerpTriggerCondition,
+    trigger_price_lots: i64,
+    price_lots: i64,
+    max_base_lots: i64,
+    max_quote_lots: i64,
+    reduce_only: bool,
+    expiry_timestamp: u64,
+    executor_incentive: u64,
+) -> Result<()> {
+    require_gt!(trigger_price_lots, 0);
+    require_gt!(price_lots, 0);
+    require_gt!(max_base_lots, 0);
+    require_gt!(max_quote_lots, 0);
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    require_msg!(
+        expiry_timestamp > now_ts,
+        "expiry timestamp {} is not in the future",
+        expiry_timestamp
+    );
+
+    let perp_market_index = ctx.accounts.perp_market.load()?.perp_market_index;
+
+    let mut trigger_order = ctx.accounts.trigger_order.load_init()?;
+    *trigger_order = PerpTriggerOrder {
+        group: ctx.accounts.group.key(),
+        account: ctx.accounts.account.key(),
+        order_id,
+        expiry_timestamp,
+        trigger_price_lots,
+        price_lots,
+        max_base_lots,
+        max_quote_lots,
+        executor_incentive,
+        perp_market_index,
+        side: side.into(),
+        trigger_condition: trigger_condition.into(),
+        reduce_only: u8::from(reduce_only),
+        bump: *ctx
+            .bumps
+            .get("trigger_order")
+            .ok_or(MangoError::SomeError)?,
+        padding: Default::default(),
+        reserved: [0; 56],
+    };
+
+    emit_stack(PerpTriggerOrderCreateLog {
+        mango_group: ctx.accounts.group.key(),
+        mango_account: ctx.accounts.account.key(),
+        trigger_order: ctx.accounts.trigger_order.key(),
+        order_id,
+        perp_market_index,
+        side: side.into(),
+        trigger_condition: trigger_condition.into(),
+        trigger_price_lots,
+        price_lots,
+        max_base_lots,
+        max_quote_lots,
+        reduce_only,
+        expiry_timestamp,
+        executor_incentive,
+    });
+
+    Ok(())
+}
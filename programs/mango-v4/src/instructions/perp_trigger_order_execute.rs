@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::*;
+use crate::error::*;
+use crate::health::{compute_health, new_fixed_order_account_retriever, HealthType};
+use crate::logs::{emit_stack, PerpTriggerOrderExecuteLog, TokenBalanceLog};
+use crate::state::*;
+
+use super::place_perp_orders;
+
+pub fn perp_trigger_order_execute(ctx: Context<PerpTriggerOrderExecute>, limit: u8) -> Result<()> {
+    let trigger_order = *ctx.accounts.trigger_order.load()?;
+
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let now_slot = Clock::get()?.slot;
+
+    // Unlike for regular order placement, the trigger condition needs a fresh oracle
+    let (oracle_price_lots, settle_token_index) = {
+        let perp_market = ctx.accounts.perp_market.load()?;
+        let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
+        let oracle_price = perp_market
+            .oracle_price(&OracleAccountInfos::from_reader(oracle_ref), Some(now_slot))?;
+        (
+            perp_market.native_price_to_lot(oracle_price),
+            perp_market.settle_token_index,
+        )
+    };
+    trigger_order.check_executable(oracle_price_lots, now_ts)?;
+
+    // Health regions skip the health check in place_perp_orders()
+    require!(
+        !ctx.accounts.account.load()?.is_in_health_region(),
+        MangoError::HealthRegionBadInnerInstruction
+    );
+
+    // The incentive may not decrease health below the pre-trade health, unless health
+    // ends up nonnegative: stop-losses must execute on accounts with negative health too.
+    // The perp position is created first, like in place_perp_orders().
+    let (base_position_before, pre_init_health) = {
+        let mut account = ctx.accounts.account.load_full_mut()?;
+        let base_position_before = account
+            .perp_position(trigger_order.perp_market_index)
+            .map(|pp| pp.effective_base_position_lots())
+            .unwrap_or(0);
+        account.ensure_perp_position(trigger_order.perp_market_index, settle_token_index)?;
+        let retriever =
+            new_fixed_order_account_retriever(ctx.remaining_accounts, &account.borrow())?;
+        let pre_init_health =
+            compute_health(&account.borrow(), HealthType::Init, &retriever, now_ts)?;
+        (base_position_before, pre_init_health)
+    };
+
+    let order = Order {
+        side: trigger_order.side(),
+        max_base_lots: trigger_order.max_base_lots,
+        max_quote_lots: trigger_order.max_quote_lots,
+        client_order_id: trigger_order.order_id,
+        reduce_only: trigger_order.is_reduce_only(),
+        time_in_force: 0,
+        self_trade_behavior: SelfTradeBehavior::default(),
+        params: OrderParams::ImmediateOrCancel {
+            price_lots: trigger_order.price_lots,
+        },
+    };
+    place_perp_orders(
+        &ctx.accounts.group,
+        &ctx.accounts.account,
+        &ctx.accounts.perp_market,
+        &ctx.accounts.bids,
+        &ctx.accounts.asks,
+        &ctx.accounts.event_queue,
+        &ctx.accounts.oracle,
+        ctx.remaining_accounts,
+        None,
+        vec![order],
+        limit,
+    )?;
+
+    // Don't let executors consume the trigger order without anything happening,
+    // for example when the book is empty
+    let base_position_after = ctx
+        .accounts
+        .account
+        .load_full()?
+        .perp_position(trigger_order.perp_market_index)?
+        .effective_base_position_lots();
+    require_msg!(
+        base_position_after != base_position_before,
+        "the triggered order did not fill"
+    );
+
+    if trigger_order.executor_incentive > 0 {
+        pay_executor_incentive(
+            &ctx,
+            settle_token_index,
+            trigger_order.executor_incentive,
+            pre_init_health,
+        )?;
+    }
+
+    emit_stack(PerpTriggerOrderExecuteLog {
+        mango_group: ctx.accounts.group.key(),
+        mango_account: ctx.accounts.account.key(),
+        trigger_order: ctx.accounts.trigger_order.key(),
+        order_id: trigger_order.order_id,
+        executor: ctx.accounts.executor.key(),
+        oracle_price_lots,
+        executor_incentive: trigger_order.executor_incentive,
+    });
+
+    Ok(())
+}
+
+/// Moves the incentive from the account's settle token position to the executor's account.
+///
+/// Afterwards the account's init health must be nonnegative or not below pre_init_health,
+/// the health before the trade.
+fn pay_executor_incentive(
+    ctx: &Context<PerpTriggerOrderExecute>,
+    settle_token_index: TokenIndex,
+    incentive: u64,
+    pre_init_health: I80F48,
+) -> Result<()> {
+    let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let group_pk = ctx.accounts.group.key();
+    let mut account = ctx.accounts.account.load_full_mut()?;
+    let mut executor_account = ctx.accounts.executor_account.load_full_mut()?;
+
+    {
+        let mut settle_bank = ctx.accounts.settle_bank.load_mut()?;
+        // Verify that the bank is the settle token bank (#1)
+        require!(
+            settle_bank.token_index == settle_token_index,
+            MangoError::InvalidBank
+        );
+
+        let incentive = I80F48::from(incentive);
+        let (account_token, _) = account.token_position_mut(settle_token_index)?;
+        settle_bank.withdraw_without_fee(account_token, incentive, now_ts)?;
+        require!(
+            account_token.indexed_position >= 0 || !settle_bank.is_collateral_only(),
+            MangoError::TokenInReduceOnlyMode
+        );
+        let (executor_token, _, _) = executor_account.ensure_token_position(settle_token_index)?;
+        settle_bank.deposit(executor_token, incentive, now_ts)?;
+
+        emit_stack(TokenBalanceLog {
+            mango_group: group_pk,
+            mango_account: ctx.accounts.account.key(),
+            token_index: settle_token_index,
+            indexed_position: account_token.indexed_position.to_bits(),
+            deposit_index: settle_bank.deposit_index.to_bits(),
+            borrow_index: settle_bank.borrow_index.to_bits(),
+        });
+        emit_stack(TokenBalanceLog {
+            mango_group: group_pk,
+            mango_account: ctx.accounts.executor_account.key(),
+            token_index: settle_token_index,
+            indexed_position: executor_token.indexed_position.to_bits(),
+            deposit_index: settle_bank.deposit_index.to_bits(),
+            borrow_index: settle_bank.borrow_index.to_bits(),
+        });
+    }
+
+    let retriever = new_fixed_order_account_retriever(ctx.remaining_accounts, &account.borrow())?;
+    let health = compute_health(&account.borrow(), HealthType::Init, &retriever, now_ts)?;
+    msg!("init health after paying the incentive: {}", health);
+    account.check_health_post_checks(pre_init_health, health)?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::accounts_zerocopy::AccountInfoRef;
+use crate::health::*;
+use crate::state::*;
+
+/// Auto-deleveraging rank of a perp position: its unsettled pnl (in USD) divided by the
+/// equity of the account. That's the profit relative to the position size times the
+/// account's leverage.
+///
+/// Returns None if the position isn't profitable, the account has no equity or it
+/// couldn't give up any pnl without becoming liquidatable.
+pub(crate) fn adl_score(
+    health_cache: &HealthCache,
+    settle_token_index: TokenIndex,
+    pnl: I80F48,
+) -> Result<Option<f64>> {
+    if !pnl.is_positive() || !health_cache.health(HealthType::Maint).is_positive() {
+        return Ok(None);
+    }
+    let (assets, liabs) = health_cache.assets_and_liabs();
+    let equity = assets - liabs;
+    if !equity.is_positive() {
+        return Ok(None);
+    }
+    let settle_token_price = health_cache.token_info(settle_token_index)?.prices.oracle;
+    Ok(Some((pnl * settle_token_price / equity).to_num::<f64>()))
+}
+
+pub fn perp_update_adl_candidate(ctx: Context<PerpUpdateAdlCandidate>) -> Result<()> {
+    let account_key = ctx.accounts.account.key();
+    let account = ctx.accounts.account.load_full()?;
+
+    let now_slot = Clock::get()?.slot;
+    let now_ts = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    let health_cache = {
+        let retriever =
+            ScanningAccountRetriever::new(ctx.remaining_accounts, &ctx.accounts.group.key())
+                .context("create account retriever")?;
+        new_health_cache(&account.borrow(), &retriever, now_ts)?
+    };
+
+    let mut perp_market = ctx.accounts.perp_market.load_mut()?;
+    let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
+    let oracle_price =
+        perp_market.oracle_price(&OracleAccountInfos::from_reader(oracle_ref), Some(now_slot))?;
+
+    // The side of the account's position and its score, if it can be deleveraged at all
+    let position_score = match account.perp_position(perp_market.perp_market_index) {
+        Ok(perp_position) if perp_position.base_position_lots() != 0 => {
+            let pnl = perp_position.unsettled_pnl(&perp_market, oracle_price)?
+                - perp_position.unsettled_funding(&perp_market);
+            adl_score(&health_cache, perp_market.settle_token_index, pnl)?
+                .map(|score| (perp_position.base_position_lots() > 0, score))
+        }
+        _ => None,
+    };
+
+    for long in [true, false] {
+        let (candidate, candidate_score) = perp_market.adl_candidate(long);
+        let score = position_score
+            .filter(|(is_long, _)| *is_long == long)
+            .map(|(_, score)| score);
+        if candidate == account_key {
+            // Refresh the current candidate, dropping it if it's no longer eligible
+            match score {
+                Some(score) => perp_market.set_adl_candidate(long, account_key, score),
+                None => perp_market.set_adl_candidate(long, Pubkey::default(), 0.0),
+            }
+        } else if let Some(score) = score {
+            if candidate == Pubkey::default() || score > candidate_score {
+                perp_market.set_adl_candidate(long, account_key, score);
+            }
+        }
+    }
+
+    msg!(
+        "adl candidates: long {} (score {}), short {} (score {})",
+        perp_market.adl_candidate_long,
+        perp_market.adl_candidate_long_score,
+        perp_market.adl_candidate_short,
+        perp_market.adl_candidate_short_score
+    );
+
+    Ok(())
+}
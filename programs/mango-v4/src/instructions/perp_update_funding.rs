@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use fixed::types::I80F48;
 
 use crate::accounts_ix::*;
 use crate::accounts_zerocopy::*;
@@ -18,7 +19,19 @@ pub fn perp_update_funding(ctx: Context<PerpUpdateFunding>) -> Result<()> {
     let oracle_state =
         perp_market.oracle_state(&OracleAccountInfos::from_reader(oracle_ref), Some(now_slot))?;
 
+    let funding_last_updated = perp_market.funding_last_updated;
     perp_market.update_funding_and_stable_price(&book, &oracle_state, now_ts)?;
 
+    // Pay the keeper for the time covered by this update, like funding capped at one hour
+    if let Some(keeper_loader) = ctx.accounts.keeper.as_ref() {
+        let diff_ts = now_ts.saturating_sub(funding_last_updated).min(HOUR as u64);
+        let reward = I80F48::from_num(perp_market.keeper_reward_funding_per_hour)
+            * I80F48::from(diff_ts)
+            / I80F48::from(HOUR);
+        let mut keeper = keeper_loader.load_full_mut()?;
+        let paid = keeper.pay_perp_keeper_reward(&mut perp_market, reward);
+        msg!("keeper reward {}", paid);
+    }
+
     Ok(())
 }
@@ -8,6 +8,7 @@ pub fn serum3_edit_market(
     force_close_opt: Option<bool>,
     name_opt: Option<String>,
     oracle_price_band_opt: Option<f32>,
+    crossing_price_band_opt: Option<f32>,
 ) -> Result<()> {
     let mut serum3_market = ctx.accounts.market.load_mut()?;
 
@@ -57,6 +58,17 @@ pub fn serum3_edit_market(
         require_group_admin = true;
     };
 
+    if let Some(crossing_price_band) = crossing_price_band_opt {
+        require_gte!(crossing_price_band, 0.0);
+        msg!(
+            "Crossing price band: old - {:?}, new - {:?}",
+            serum3_market.crossing_price_band,
+            crossing_price_band
+        );
+        serum3_market.crossing_price_band = crossing_price_band;
+        require_group_admin = true;
+    };
+
     if require_group_admin {
         require!(
             group.admin == ctx.accounts.admin.key(),
@@ -16,7 +16,7 @@ pub fn serum3_liq_force_cancel_orders(
     //
     // Validation
     //
-    let serum_market = ctx.accounts.serum_market.load()?;
+    let mut serum_market = ctx.accounts.serum_market.load_mut()?;
     {
         let account = ctx.accounts.account.load_full()?;
 
@@ -146,7 +146,7 @@ pub fn serum3_liq_force_cancel_orders(
         &mut account.borrow_mut(),
         &mut base_bank,
         &mut quote_bank,
-        &serum_market,
+        &mut serum_market,
         before_base_vault,
         before_quote_vault,
         &before_oo,
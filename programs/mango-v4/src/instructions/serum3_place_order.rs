@@ -27,14 +27,36 @@ pub fn serum3_place_order(
     limit: u16,
     require_v2: bool,
 ) -> Result<()> {
-    // Also required by serum3's place order
-    require_gt!(limit_price_lots, 0);
+    let order = Serum3PlaceOrderArgs {
+        limit_price_lots,
+        max_base_qty,
+        max_native_quote_qty_including_fees,
+        self_trade_behavior,
+        order_type,
+        client_order_id,
+        limit,
+    };
+    place_serum3_orders(ctx, side, &[order], require_v2)
+}
 
+/// Places serum3 orders on one side of the book, with a single health check.
+///
+/// Callers must check the ix gate for batched orders.
+pub(crate) fn place_serum3_orders(
+    ctx: Context<Serum3PlaceOrder>,
+    side: Serum3Side,
+    orders: &[Serum3PlaceOrderArgs],
+    require_v2: bool,
+) -> Result<()> {
     let serum_market = ctx.accounts.serum_market.load()?;
     require!(
         !serum_market.is_reduce_only(),
         MangoError::MarketInReduceOnlyMode
     );
+    require!(
+        !ctx.accounts.group.load()?.is_withdraw_only(),
+        MangoError::GroupInWithdrawOnlyMode
+    );
 
     //
     // Validation
@@ -134,278 +156,339 @@ pub fn serum3_place_order(
         }
     }
 
-    //
-    // Before-order tracking
-    //
+    for order in orders {
+        let Serum3PlaceOrderArgs {
+            limit_price_lots,
+            max_base_qty,
+            max_native_quote_qty_including_fees,
+            self_trade_behavior,
+            order_type,
+            client_order_id,
+            limit,
+        } = *order;
 
-    let before_vault = ctx.accounts.payer_vault.amount;
-
-    let before_oo_free_slots;
-    let before_had_bids;
-    let before_had_asks;
-    let before_oo = {
-        let oo_ai = &ctx.accounts.open_orders.as_ref();
-        let open_orders = load_open_orders_ref(oo_ai)?;
-        before_oo_free_slots = open_orders.free_slot_bits;
-        before_had_bids = (!open_orders.free_slot_bits & open_orders.is_bid_bits) != 0;
-        before_had_asks = (!open_orders.free_slot_bits & !open_orders.is_bid_bits) != 0;
-        OpenOrdersSlim::from_oo(&open_orders)
-    };
+        // Also required by serum3's place order
+        require_gt!(limit_price_lots, 0);
 
-    // Provide a readable error message in case the vault doesn't have enough tokens
-    let base_lot_size;
-    let quote_lot_size;
-    {
-        let market_state = load_market_state(
-            &ctx.accounts.serum_market_external,
-            &ctx.accounts.serum_program.key(),
-        )?;
-        base_lot_size = market_state.coin_lot_size;
-        quote_lot_size = market_state.pc_lot_size;
+        //
+        // Before-order tracking
+        //
 
-        let needed_amount = match side {
-            Serum3Side::Ask => {
-                (max_base_qty * base_lot_size).saturating_sub(before_oo.native_base_free())
-            }
-            Serum3Side::Bid => {
-                max_native_quote_qty_including_fees.saturating_sub(before_oo.native_quote_free())
-            }
+        let before_vault = ctx.accounts.payer_vault.amount;
+
+        let before_oo_free_slots;
+        let before_had_bids;
+        let before_had_asks;
+        let before_oo = {
+            let oo_ai = &ctx.accounts.open_orders.as_ref();
+            let open_orders = load_open_orders_ref(oo_ai)?;
+            before_oo_free_slots = open_orders.free_slot_bits;
+            before_had_bids = (!open_orders.free_slot_bits & open_orders.is_bid_bits) != 0;
+            before_had_asks = (!open_orders.free_slot_bits & !open_orders.is_bid_bits) != 0;
+            OpenOrdersSlim::from_oo(&open_orders)
         };
-        if before_vault < needed_amount {
-            return err!(MangoError::InsufficentBankVaultFunds).with_context(|| {
-                format!(
-                    "bank vault does not have enough tokens, need {} but have {}",
-                    needed_amount, before_vault
-                )
-            });
-        }
-    }
 
-    //
-    // Apply the order to serum
-    //
-    let order = serum_dex::instruction::NewOrderInstructionV3 {
-        side: u8::try_from(side).unwrap().try_into().unwrap(),
-        limit_price: limit_price_lots.try_into().unwrap(),
-        max_coin_qty: max_base_qty.try_into().unwrap(),
-        max_native_pc_qty_including_fees: max_native_quote_qty_including_fees.try_into().unwrap(),
-        self_trade_behavior: u8::try_from(self_trade_behavior)
-            .unwrap()
-            .try_into()
-            .unwrap(),
-        order_type: u8::try_from(order_type).unwrap().try_into().unwrap(),
-        client_order_id,
-        limit,
-        max_ts: i64::MAX,
-    };
-    cpi_place_order(ctx.accounts, order)?;
-
-    //
-    // After-order tracking
-    //
-    let after_oo_free_slots;
-    let after_oo = {
-        let oo_ai = &ctx.accounts.open_orders.as_ref();
-        let open_orders = load_open_orders_ref(oo_ai)?;
-        after_oo_free_slots = open_orders.free_slot_bits;
-        OpenOrdersSlim::from_oo(&open_orders)
-    };
-    let oo_difference = OODifference::new(&before_oo, &after_oo);
+        // Provide a readable error message in case the vault doesn't have enough tokens
+        let base_lot_size;
+        let quote_lot_size;
+        {
+            let market_state = load_market_state(
+                &ctx.accounts.serum_market_external,
+                &ctx.accounts.serum_program.key(),
+            )?;
+            base_lot_size = market_state.coin_lot_size;
+            quote_lot_size = market_state.pc_lot_size;
 
-    //
-    // Track the highest bid and lowest ask, to be able to evaluate worst-case health even
-    // when they cross the oracle
-    //
-    let serum = account.serum3_orders_mut(serum_market.market_index)?;
-    if !before_had_bids {
-        // The 0 state means uninitialized/no value
-        serum.highest_placed_bid_inv = 0.0;
-        serum.lowest_placed_bid_inv = 0.0;
-    }
-    if !before_had_asks {
-        serum.lowest_placed_ask = 0.0;
-        serum.highest_placed_ask = 0.0;
-    }
-    // in the normal quote per base units
-    let limit_price = limit_price_lots as f64 * quote_lot_size as f64 / base_lot_size as f64;
-
-    let new_order_on_book = after_oo_free_slots != before_oo_free_slots;
-    if new_order_on_book {
-        match side {
-            Serum3Side::Ask => {
-                serum.lowest_placed_ask = if serum.lowest_placed_ask == 0.0 {
-                    limit_price
-                } else {
-                    serum.lowest_placed_ask.min(limit_price)
-                };
-                serum.highest_placed_ask = if serum.highest_placed_ask == 0.0 {
-                    limit_price
-                } else {
-                    serum.highest_placed_ask.max(limit_price)
+            let needed_amount = match side {
+                Serum3Side::Ask => {
+                    (max_base_qty * base_lot_size).saturating_sub(before_oo.native_base_free())
                 }
+                Serum3Side::Bid => max_native_quote_qty_including_fees
+                    .saturating_sub(before_oo.native_quote_free()),
+            };
+            if before_vault < needed_amount {
+                return err!(MangoError::InsufficentBankVaultFunds).with_context(|| {
+                    format!(
+                        "bank vault does not have enough tokens, need {} but have {}",
+                        needed_amount, before_vault
+                    )
+                });
             }
-            Serum3Side::Bid => {
-                // in base per quote units, to avoid a division in health
-                let limit_price_inv = 1.0 / limit_price;
-                serum.highest_placed_bid_inv = if serum.highest_placed_bid_inv == 0.0 {
-                    limit_price_inv
-                } else {
-                    // the highest bid has the lowest _inv value
-                    serum.highest_placed_bid_inv.min(limit_price_inv)
-                };
-                serum.lowest_placed_bid_inv = if serum.lowest_placed_bid_inv == 0.0 {
-                    limit_price_inv
-                } else {
-                    // lowest bid has max _inv value
-                    serum.lowest_placed_bid_inv.max(limit_price_inv)
+        }
+
+        // Crossing price bands: Whether or not the order ends up on the book, ensure
+        // - a bid isn't too far above oracle
+        // - an ask isn't too far below oracle
+        // so a fat-fingered price can't take out a thin book at a huge loss.
+        let crossing_band = serum_market.crossing_price_band();
+        if crossing_band != f32::MAX {
+            let payer_token_index = ctx.accounts.payer_bank.load()?.token_index;
+            let payer_oracle = health_cache.token_info(payer_token_index)?.prices.oracle;
+            let (base_oracle, quote_oracle) = match side {
+                Serum3Side::Bid => (receiver_bank_oracle, payer_oracle),
+                Serum3Side::Ask => (payer_oracle, receiver_bank_oracle),
+            };
+            let base_oracle_f64 = base_oracle.to_num::<f64>();
+            let quote_oracle_f64 = quote_oracle.to_num::<f64>();
+            // in the normal quote per base units
+            let limit_price =
+                limit_price_lots as f64 * quote_lot_size as f64 / base_lot_size as f64;
+            let limit_price_in_dollar = limit_price * quote_oracle_f64;
+            let crossing_band = crossing_band as f64;
+            match side {
+                Serum3Side::Bid => {
+                    let max_price = base_oracle_f64 * (1.0 + crossing_band);
+                    require_msg_typed!(
+                        limit_price_in_dollar <= max_price,
+                        MangoError::Serum3PriceBandExceeded,
+                        "bid price {} must be smaller than {} ({}% of oracle)",
+                        limit_price,
+                        max_price / quote_oracle_f64,
+                        (100.0 * (1.0 + crossing_band)) as u64,
+                    );
+                }
+                Serum3Side::Ask => {
+                    let min_price = base_oracle_f64 * (1.0 - crossing_band);
+                    require_msg_typed!(
+                        limit_price_in_dollar >= min_price,
+                        MangoError::Serum3PriceBandExceeded,
+                        "ask price {} must be larger than {} ({}% of oracle)",
+                        limit_price,
+                        min_price / quote_oracle_f64,
+                        (100.0 * (1.0 - crossing_band)).max(0.0) as u64,
+                    );
                 }
             }
         }
-    }
 
-    emit_stack(Serum3OpenOrdersBalanceLogV2 {
-        mango_group: ctx.accounts.group.key(),
-        mango_account: ctx.accounts.account.key(),
-        market_index: serum_market.market_index,
-        base_token_index: serum_market.base_token_index,
-        quote_token_index: serum_market.quote_token_index,
-        base_total: after_oo.native_base_total(),
-        base_free: after_oo.native_base_free(),
-        quote_total: after_oo.native_quote_total(),
-        quote_free: after_oo.native_quote_free(),
-        referrer_rebates_accrued: after_oo.native_rebates(),
-    });
+        //
+        // Apply the order to serum
+        //
+        let order = serum_dex::instruction::NewOrderInstructionV3 {
+            side: u8::try_from(side).unwrap().try_into().unwrap(),
+            limit_price: limit_price_lots.try_into().unwrap(),
+            max_coin_qty: max_base_qty.try_into().unwrap(),
+            max_native_pc_qty_including_fees: max_native_quote_qty_including_fees
+                .try_into()
+                .unwrap(),
+            self_trade_behavior: u8::try_from(self_trade_behavior)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            order_type: u8::try_from(order_type).unwrap().try_into().unwrap(),
+            client_order_id,
+            limit,
+            max_ts: i64::MAX,
+        };
+        cpi_place_order(ctx.accounts, order)?;
 
-    ctx.accounts.payer_vault.reload()?;
-    let after_vault = ctx.accounts.payer_vault.amount;
+        //
+        // After-order tracking
+        //
+        let after_oo_free_slots;
+        let after_oo = {
+            let oo_ai = &ctx.accounts.open_orders.as_ref();
+            let open_orders = load_open_orders_ref(oo_ai)?;
+            after_oo_free_slots = open_orders.free_slot_bits;
+            OpenOrdersSlim::from_oo(&open_orders)
+        };
+        let oo_difference = OODifference::new(&before_oo, &after_oo);
 
-    // Placing an order cannot increase vault balance
-    require_gte!(before_vault, after_vault);
+        //
+        // Track the highest bid and lowest ask, to be able to evaluate worst-case health even
+        // when they cross the oracle
+        //
+        let serum = account.serum3_orders_mut(serum_market.market_index)?;
+        if !before_had_bids {
+            // The 0 state means uninitialized/no value
+            serum.highest_placed_bid_inv = 0.0;
+            serum.lowest_placed_bid_inv = 0.0;
+        }
+        if !before_had_asks {
+            serum.lowest_placed_ask = 0.0;
+            serum.highest_placed_ask = 0.0;
+        }
+        // in the normal quote per base units
+        let limit_price = limit_price_lots as f64 * quote_lot_size as f64 / base_lot_size as f64;
+
+        let new_order_on_book = after_oo_free_slots != before_oo_free_slots;
+        if new_order_on_book {
+            match side {
+                Serum3Side::Ask => {
+                    serum.lowest_placed_ask = if serum.lowest_placed_ask == 0.0 {
+                        limit_price
+                    } else {
+                        serum.lowest_placed_ask.min(limit_price)
+                    };
+                    serum.highest_placed_ask = if serum.highest_placed_ask == 0.0 {
+                        limit_price
+                    } else {
+                        serum.highest_placed_ask.max(limit_price)
+                    }
+                }
+                Serum3Side::Bid => {
+                    // in base per quote units, to avoid a division in health
+                    let limit_price_inv = 1.0 / limit_price;
+                    serum.highest_placed_bid_inv = if serum.highest_placed_bid_inv == 0.0 {
+                        limit_price_inv
+                    } else {
+                        // the highest bid has the lowest _inv value
+                        serum.highest_placed_bid_inv.min(limit_price_inv)
+                    };
+                    serum.lowest_placed_bid_inv = if serum.lowest_placed_bid_inv == 0.0 {
+                        limit_price_inv
+                    } else {
+                        // lowest bid has max _inv value
+                        serum.lowest_placed_bid_inv.max(limit_price_inv)
+                    }
+                }
+            }
+        }
 
-    let mut payer_bank = ctx.accounts.payer_bank.load_mut()?;
+        emit_stack(Serum3OpenOrdersBalanceLogV2 {
+            mango_group: ctx.accounts.group.key(),
+            mango_account: ctx.accounts.account.key(),
+            market_index: serum_market.market_index,
+            base_token_index: serum_market.base_token_index,
+            quote_token_index: serum_market.quote_token_index,
+            base_total: after_oo.native_base_total(),
+            base_free: after_oo.native_base_free(),
+            quote_total: after_oo.native_quote_total(),
+            quote_free: after_oo.native_quote_free(),
+            referrer_rebates_accrued: after_oo.native_rebates(),
+        });
+
+        ctx.accounts.payer_vault.reload()?;
+        let after_vault = ctx.accounts.payer_vault.amount;
+
+        // Placing an order cannot increase vault balance
+        require_gte!(before_vault, after_vault);
+
+        let mut payer_bank = ctx.accounts.payer_bank.load_mut()?;
+
+        // Update the potential token tracking in banks
+        // (for init weight scaling, deposit limit checks)
+        if is_v2_instruction {
+            let mut receiver_bank = receiver_bank_ai.load_mut::<Bank>()?;
+            let (base_bank, quote_bank) = match side {
+                Serum3Side::Bid => (&mut receiver_bank, &mut payer_bank),
+                Serum3Side::Ask => (&mut payer_bank, &mut receiver_bank),
+            };
+            update_bank_potential_tokens(serum, base_bank, quote_bank, &after_oo);
+        } else {
+            update_bank_potential_tokens_payer_only(serum, &mut payer_bank, &after_oo);
+        }
 
-    // Update the potential token tracking in banks
-    // (for init weight scaling, deposit limit checks)
-    if is_v2_instruction {
-        let mut receiver_bank = receiver_bank_ai.load_mut::<Bank>()?;
-        let (base_bank, quote_bank) = match side {
-            Serum3Side::Bid => (&mut receiver_bank, &mut payer_bank),
-            Serum3Side::Ask => (&mut payer_bank, &mut receiver_bank),
+        // Track position before withdraw happens
+        let before_position_native = account
+            .token_position_mut(payer_bank.token_index)?
+            .0
+            .native(&payer_bank);
+
+        // Charge the difference in vault balance to the user's account
+        // (must be done before limit checks like deposit limit)
+        let vault_difference = {
+            apply_vault_difference(
+                ctx.accounts.account.key(),
+                &mut account.borrow_mut(),
+                serum_market.market_index,
+                &mut payer_bank,
+                after_vault,
+                before_vault,
+            )?
         };
-        update_bank_potential_tokens(serum, base_bank, quote_bank, &after_oo);
-    } else {
-        update_bank_potential_tokens_payer_only(serum, &mut payer_bank, &after_oo);
-    }
-
-    // Track position before withdraw happens
-    let before_position_native = account
-        .token_position_mut(payer_bank.token_index)?
-        .0
-        .native(&payer_bank);
-
-    // Charge the difference in vault balance to the user's account
-    // (must be done before limit checks like deposit limit)
-    let vault_difference = {
-        apply_vault_difference(
-            ctx.accounts.account.key(),
-            &mut account.borrow_mut(),
-            serum_market.market_index,
-            &mut payer_bank,
-            after_vault,
-            before_vault,
-        )?
-    };
-
-    // Deposit limit check, receiver side:
-    // Placing an order can always increase the receiver bank deposits on fill.
-    {
-        let receiver_bank = receiver_bank_ai.load::<Bank>()?;
-        receiver_bank
-            .check_deposit_and_oo_limit()
-            .with_context(|| std::format!("on {}", receiver_bank.name()))?;
-    }
 
-    // Payer bank safety checks like reduce-only, net borrows, vault-to-deposits ratio
-    let payer_oracle_ref = &AccountInfoRef::borrow(&ctx.accounts.payer_oracle)?;
-    let payer_bank_oracle =
-        payer_bank.oracle_price(&OracleAccountInfos::from_reader(payer_oracle_ref), None)?;
-    let withdrawn_from_vault = I80F48::from(before_vault - after_vault);
-    if withdrawn_from_vault > before_position_native {
-        require_msg_typed!(
-            !payer_bank.are_borrows_reduce_only(),
-            MangoError::TokenInReduceOnlyMode,
-            "the payer tokens cannot be borrowed"
-        );
-        payer_bank.enforce_max_utilization_on_borrow()?;
-        payer_bank.check_net_borrows(payer_bank_oracle)?;
+        // Deposit limit check, receiver side:
+        // Placing an order can always increase the receiver bank deposits on fill.
+        {
+            let receiver_bank = receiver_bank_ai.load::<Bank>()?;
+            receiver_bank
+                .check_deposit_and_oo_limit()
+                .with_context(|| std::format!("on {}", receiver_bank.name()))?;
+        }
 
-        // Deposit limit check, payer side:
-        // The payer bank deposits could increase when cancelling the order later:
-        // Imagine the account borrowing payer tokens to place the order, repaying the borrows
-        // and then cancelling the order to create a deposit.
-        //
-        // However, if the account only decreases its deposits to place an order it can't
-        // worsen the situation and should always go through, even if payer deposit limits are
-        // already exceeded.
-        payer_bank
-            .check_deposit_and_oo_limit()
-            .with_context(|| std::format!("on {}", payer_bank.name()))?;
-    } else {
-        payer_bank.enforce_borrows_lte_deposits()?;
-    }
+        // Payer bank safety checks like reduce-only, net borrows, vault-to-deposits ratio
+        let payer_oracle_ref = &AccountInfoRef::borrow(&ctx.accounts.payer_oracle)?;
+        let payer_bank_oracle =
+            payer_bank.oracle_price(&OracleAccountInfos::from_reader(payer_oracle_ref), None)?;
+        let withdrawn_from_vault = I80F48::from(before_vault - after_vault);
+        if withdrawn_from_vault > before_position_native {
+            require_msg_typed!(
+                !payer_bank.are_borrows_reduce_only(),
+                MangoError::TokenInReduceOnlyMode,
+                "the payer tokens cannot be borrowed"
+            );
+            payer_bank.enforce_max_utilization_on_borrow()?;
+            payer_bank.check_net_borrows(payer_bank_oracle)?;
+
+            // Deposit limit check, payer side:
+            // The payer bank deposits could increase when cancelling the order later:
+            // Imagine the account borrowing payer tokens to place the order, repaying the borrows
+            // and then cancelling the order to create a deposit.
+            //
+            // However, if the account only decreases its deposits to place an order it can't
+            // worsen the situation and should always go through, even if payer deposit limits are
+            // already exceeded.
+            payer_bank
+                .check_deposit_and_oo_limit()
+                .with_context(|| std::format!("on {}", payer_bank.name()))?;
+        } else {
+            payer_bank.enforce_borrows_lte_deposits()?;
+        }
 
-    // Limit order price bands: If the order ends up on the book, ensure
-    // - a bid isn't too far below oracle
-    // - an ask isn't too far above oracle
-    // because placing orders that are guaranteed to never be hit can be bothersome:
-    // For example placing a very large bid near zero would make the potential_base_tokens
-    // value go through the roof, reducing available init margin for other users.
-    let band_threshold = serum_market.oracle_price_band();
-    if new_order_on_book && band_threshold != f32::MAX {
-        let (base_oracle, quote_oracle) = match side {
-            Serum3Side::Bid => (&receiver_bank_oracle, &payer_bank_oracle),
-            Serum3Side::Ask => (&payer_bank_oracle, &receiver_bank_oracle),
-        };
-        let base_oracle_f64 = base_oracle.to_num::<f64>();
-        let quote_oracle_f64 = quote_oracle.to_num::<f64>();
-        // this has the same units as base_oracle: USD per BASE; limit_price is in QUOTE per BASE
-        let limit_price_in_dollar = limit_price * quote_oracle_f64;
-        let band_factor = 1.0 + band_threshold as f64;
-        match side {
-            Serum3Side::Bid => {
-                require_msg_typed!(
-                    limit_price_in_dollar * band_factor >= base_oracle_f64,
-                    MangoError::Serum3PriceBandExceeded,
-                    "bid price {} must be larger than {} ({}% of oracle)",
-                    limit_price,
-                    base_oracle_f64 / (quote_oracle_f64 * band_factor),
-                    (100.0 / band_factor) as u64,
-                );
-            }
-            Serum3Side::Ask => {
-                require_msg_typed!(
-                    limit_price_in_dollar <= base_oracle_f64 * band_factor,
-                    MangoError::Serum3PriceBandExceeded,
-                    "ask price {} must be smaller than {} ({}% of oracle)",
-                    limit_price,
-                    base_oracle_f64 * band_factor / quote_oracle_f64,
-                    (100.0 * band_factor) as u64,
-                );
+        // Limit order price bands: If the order ends up on the book, ensure
+        // - a bid isn't too far below oracle
+        // - an ask isn't too far above oracle
+        // because placing orders that are guaranteed to never be hit can be bothersome:
+        // For example placing a very large bid near zero would make the potential_base_tokens
+        // value go through the roof, reducing available init margin for other users.
+        let band_threshold = serum_market.oracle_price_band();
+        if new_order_on_book && band_threshold != f32::MAX {
+            let (base_oracle, quote_oracle) = match side {
+                Serum3Side::Bid => (&receiver_bank_oracle, &payer_bank_oracle),
+                Serum3Side::Ask => (&payer_bank_oracle, &receiver_bank_oracle),
+            };
+            let base_oracle_f64 = base_oracle.to_num::<f64>();
+            let quote_oracle_f64 = quote_oracle.to_num::<f64>();
+            // this has the same units as base_oracle: USD per BASE; limit_price is in QUOTE per BASE
+            let limit_price_in_dollar = limit_price * quote_oracle_f64;
+            let band_factor = 1.0 + band_threshold as f64;
+            match side {
+                Serum3Side::Bid => {
+                    require_msg_typed!(
+                        limit_price_in_dollar * band_factor >= base_oracle_f64,
+                        MangoError::Serum3PriceBandExceeded,
+                        "bid price {} must be larger than {} ({}% of oracle)",
+                        limit_price,
+                        base_oracle_f64 / (quote_oracle_f64 * band_factor),
+                        (100.0 / band_factor) as u64,
+                    );
+                }
+                Serum3Side::Ask => {
+                    require_msg_typed!(
+                        limit_price_in_dollar <= base_oracle_f64 * band_factor,
+                        MangoError::Serum3PriceBandExceeded,
+                        "ask price {} must be smaller than {} ({}% of oracle)",
+                        limit_price,
+                        base_oracle_f64 * band_factor / quote_oracle_f64,
+                        (100.0 * band_factor) as u64,
+                    );
+                }
             }
         }
-    }
 
-    // Health cache updates for the changed account state
-    let receiver_bank = receiver_bank_ai.load::<Bank>()?;
-    // update scaled weights for receiver bank
-    health_cache.adjust_token_balance(&receiver_bank, I80F48::ZERO)?;
-    vault_difference.adjust_health_cache_token_balance(&mut health_cache, &payer_bank)?;
-    let serum_account = account.serum3_orders(serum_market.market_index)?;
-    oo_difference.recompute_health_cache_serum3_state(
-        &mut health_cache,
-        &serum_account,
-        &after_oo,
-    )?;
+        // Health cache updates for the changed account state
+        let receiver_bank = receiver_bank_ai.load::<Bank>()?;
+        // update scaled weights for receiver bank
+        health_cache.adjust_token_balance(&receiver_bank, I80F48::ZERO)?;
+        vault_difference.adjust_health_cache_token_balance(&mut health_cache, &payer_bank)?;
+        let serum_account = account.serum3_orders(serum_market.market_index)?;
+        oo_difference.recompute_health_cache_serum3_state(
+            &mut health_cache,
+            &serum_account,
+            &after_oo,
+        )?;
+    }
 
     // Check the receiver's reduce only flag.
     //
@@ -427,7 +510,7 @@ pub fn serum3_place_order(
     // Health check
     //
     if let Some(pre_init_health) = pre_health_opt {
-        account.check_health_post(&health_cache, pre_init_health)?;
+        account.check_health_post(&health_cache, pre_init_health, ctx.accounts.account.key())?;
     }
 
     Ok(())
@@ -554,7 +637,7 @@ pub fn apply_settle_changes(
     account: &mut MangoAccountRefMut,
     base_bank: &mut Bank,
     quote_bank: &mut Bank,
-    serum_market: &Serum3Market,
+    serum_market: &mut Serum3Market,
     before_base_vault: u64,
     before_quote_vault: u64,
     before_oo: &OpenOrdersSlim,
@@ -572,6 +655,7 @@ pub fn apply_settle_changes(
             .native_rebates()
             .saturating_sub(after_oo.native_rebates());
         quote_bank.collected_fees_native += I80F48::from(received_fees);
+        serum_market.collected_referrer_rebates += received_fees;
 
         // Credit the buyback_fees at the current value of the quote token.
         if let Some(quote_oracle_ai) = quote_oracle {
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+use crate::error::*;
+use crate::state::*;
+
+use super::place_serum3_orders;
+
+/// Maximum number of orders in a single serum3_place_orders instruction
+pub const MAX_SERUM3_PLACE_ORDERS: usize = 8;
+
+/// Places several serum3 orders on one side of the book with a single health check
+///
+/// Like serum3_place_order_v2, the receiver bank in the health accounts must be writable.
+pub fn serum3_place_orders(
+    ctx: Context<Serum3PlaceOrder>,
+    side: Serum3Side,
+    orders: Vec<Serum3PlaceOrderArgs>,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .group
+            .load()?
+            .is_ix_enabled(IxGate::Serum3PlaceOrders),
+        MangoError::IxIsDisabled
+    );
+    require_gt!(orders.len(), 0);
+    require_gte!(MAX_SERUM3_PLACE_ORDERS, orders.len());
+
+    place_serum3_orders(ctx, side, &orders, true)
+}
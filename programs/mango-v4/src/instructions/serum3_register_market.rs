@@ -6,6 +6,7 @@ use crate::state::*;
 use crate::util::fill_from_str;
 
 use crate::accounts_ix::*;
+use crate::address_lookup_table_program;
 use crate::logs::{emit_stack, Serum3RegisterMarketLog};
 
 pub fn serum3_register_market(
@@ -74,7 +75,9 @@ pub fn serum3_register_market(
         padding2: Default::default(),
         oracle_price_band,
         registration_time: Clock::get()?.unix_timestamp.try_into().unwrap(),
-        reserved: [0; 128],
+        collected_referrer_rebates: 0,
+        crossing_price_band: 0.0,
+        reserved: [0; 116],
     };
 
     let mut serum_index_reservation = ctx.accounts.index_reservation.load_init()?;
@@ -94,5 +97,16 @@ pub fn serum3_register_market(
         serum_program_external: ctx.accounts.serum_market_external.key(),
     });
 
+    address_lookup_table_program::maybe_extend_group_alt(
+        &ctx.accounts.group,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.serum_market.key(),
+            ctx.accounts.serum_market_external.key(),
+        ],
+    )?;
+
     Ok(())
 }
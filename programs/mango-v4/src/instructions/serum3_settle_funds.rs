@@ -23,7 +23,7 @@ pub fn serum3_settle_funds<'info>(
     v2: Option<&mut Serum3SettleFundsV2Extra<'info>>,
     fees_to_dao: bool,
 ) -> Result<()> {
-    let serum_market = accounts.serum_market.load()?;
+    let mut serum_market = accounts.serum_market.load_mut()?;
 
     //
     // Validation
@@ -121,7 +121,7 @@ pub fn serum3_settle_funds<'info>(
         &mut account.borrow_mut(),
         &mut base_bank,
         &mut quote_bank,
-        &serum_market,
+        &mut serum_market,
         before_base_vault,
         before_quote_vault,
         &before_oo,
@@ -192,6 +192,14 @@ pub fn charge_loan_origination_fees(
                 )
             })
             .transpose()?;
+        let referral_fees = withdraw_result.loan_origination_fee.floor().to_num::<u64>();
+        if account.fixed.accrue_referral_fees(
+            ReferralFeeSource::Bank,
+            base_bank.token_index,
+            referral_fees,
+        ) {
+            cm!(base_bank.referral_fees_unclaimed += referral_fees)?;
+        }
 
         emit_stack(WithdrawLoanLog {
             mango_group: *group_pubkey,
@@ -232,6 +240,14 @@ pub fn charge_loan_origination_fees(
                 )
             })
             .transpose()?;
+        let referral_fees = withdraw_result.loan_origination_fee.floor().to_num::<u64>();
+        if account.fixed.accrue_referral_fees(
+            ReferralFeeSource::Bank,
+            quote_bank.token_index,
+            referral_fees,
+        ) {
+            cm!(quote_bank.referral_fees_unclaimed += referral_fees)?;
+        }
 
         emit_stack(WithdrawLoanLog {
             mango_group: *group_pubkey,
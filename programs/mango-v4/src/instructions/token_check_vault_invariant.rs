@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use fixed::types::I80F48;
+
+use crate::accounts_ix::*;
+use crate::logs::{emit_stack, TokenVaultInvariantViolationLog};
+
+/// Shortfalls up to this many native tokens are attributed to rounding
+const VAULT_INVARIANT_TOLERANCE_NATIVE: I80F48 = I80F48::ONE;
+
+/// Compares the vault balance to the deposits minus borrows plus unwithdrawn fees
+/// of the bank and logs if the vault holds less.
+///
+/// If the bank is configured to halt on violations, it is also put into
+/// reduce-only mode.
+pub fn token_check_vault_invariant(ctx: Context<TokenCheckVaultInvariant>) -> Result<()> {
+    let mut bank = ctx.accounts.bank.load_mut()?;
+
+    // Between FlashLoanBegin and End vault tokens are out without the positions
+    // being updated yet. No mango instructions are allowed there, but be safe.
+    require_msg!(
+        bank.flash_loan_token_account_initial == u64::MAX,
+        "can't check the vault invariant during a flash loan"
+    );
+
+    let vault_balance = ctx.accounts.vault.amount;
+    let expected = bank.expected_vault_balance();
+    let shortfall = expected - I80F48::from(vault_balance);
+    msg!(
+        "vault balance {}, expected at least {}, shortfall {}",
+        vault_balance,
+        expected,
+        shortfall
+    );
+    if shortfall <= VAULT_INVARIANT_TOLERANCE_NATIVE {
+        return Ok(());
+    }
+
+    let halted = bank.halts_on_vault_invariant_violation();
+    if halted {
+        msg!("vault invariant violated, setting bank to reduce only");
+        bank.reduce_only = 1;
+    }
+
+    emit_stack(TokenVaultInvariantViolationLog {
+        mango_group: ctx.accounts.group.key(),
+        bank: ctx.accounts.bank.key(),
+        token_index: bank.token_index,
+        vault_balance,
+        native_deposits: bank.native_deposits().to_bits(),
+        native_borrows: bank.native_borrows().to_bits(),
+        collected_fees: bank.collected_fees_native.to_bits(),
+        fees_withdrawn: bank.fees_withdrawn,
+        shortfall: shortfall.to_bits(),
+        halted,
+    });
+
+    Ok(())
+}
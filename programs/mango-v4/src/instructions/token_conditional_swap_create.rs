@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
+use crate::error::*;
 use crate::logs::{emit_stack, TokenConditionalSwapCreateLogV3};
 use crate::state::*;
 
@@ -36,6 +37,20 @@ pub fn token_conditional_swap_create(
     let buy_bank = ctx.accounts.buy_bank.load()?;
     let sell_bank = ctx.accounts.sell_bank.load()?;
 
+    // Premium auctions pay a start incentive, they must be big enough for it to be negligible
+    if token_conditional_swap.tcs_type() == TokenConditionalSwapType::PremiumAuction {
+        let buy_token_price = buy_bank.stable_price().to_num::<f64>();
+        let sell_token_price = sell_bank.stable_price().to_num::<f64>();
+        let max_volume = (buy_token_price * token_conditional_swap.max_buy as f64)
+            .min(sell_token_price * token_conditional_swap.max_sell as f64)
+            as u64;
+        require_gte!(
+            max_volume,
+            TCS_START_INCENTIVE * 10,
+            MangoError::TokenConditionalSwapTooSmallForStartIncentive
+        );
+    }
+
     let tcs = account.free_token_conditional_swap_mut()?;
     *tcs = token_conditional_swap;
     tcs.id = id;
@@ -133,7 +133,7 @@ pub fn token_conditional_swap_start(
     tcs.sold += incentive_native;
     assert!(tcs.sold <= tcs.max_sell);
 
-    liqee.check_health_post(&health_cache, pre_init_health)?;
+    liqee.check_health_post(&health_cache, pre_init_health, liqee_key)?;
 
     Ok(())
 }
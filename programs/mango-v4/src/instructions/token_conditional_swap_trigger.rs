@@ -28,6 +28,10 @@ pub fn token_conditional_swap_trigger(
     min_taker_price: f64,
 ) -> Result<()> {
     let group_pk = &ctx.accounts.group.key();
+    require!(
+        !ctx.accounts.group.load()?.is_withdraw_only(),
+        MangoError::GroupInWithdrawOnlyMode
+    );
     let liqee_key = ctx.accounts.liqee.key();
     let liqor_key = ctx.accounts.liqor.key();
     require_keys_neq!(liqee_key, liqor_key);
@@ -232,6 +236,14 @@ fn action(
 
         tcs.check_triggerable(price, now_ts)?;
 
+        // Banks with an oracle twap require the twap price to be in range too
+        if buy_bank.oracle_twap().is_some() || sell_bank.oracle_twap().is_some() {
+            let buy_twap = buy_bank.oracle_twap().unwrap_or(buy_token_price);
+            let sell_twap = sell_bank.oracle_twap().unwrap_or(sell_token_price);
+            let twap_price = buy_twap.to_num::<f64>() / sell_twap.to_num::<f64>();
+            tcs.check_triggerable(twap_price, now_ts)?;
+        }
+
         // We need to borrow liqee token positions mutably and can't hold the tcs borrow at the
         // same time. Copying the whole struct is convenience.
         tcs.clone()
@@ -409,7 +421,7 @@ fn action(
     liqee_health_cache.adjust_token_balance(&sell_bank, liqee_sell_change)?;
 
     let liqee_post_init_health =
-        liqee.check_health_post(&liqee_health_cache, liqee_pre_init_health)?;
+        liqee.check_health_post(&liqee_health_cache, liqee_pre_init_health, liqee_key)?;
 
     // update tcs information on the account
     let closed = {
@@ -3,6 +3,7 @@ use anchor_spl::token;
 use anchor_spl::token::Token;
 use anchor_spl::token::TokenAccount;
 use fixed::types::I80F48;
+use solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
 
 use crate::accounts_zerocopy::AccountInfoRef;
 use crate::error::*;
@@ -43,6 +44,15 @@ impl<'a, 'info> DepositCommon<'a, 'info> {
     ) -> Result<()> {
         require_msg!(amount > 0, "deposit amount must be positive");
 
+        let group = self.group.load()?;
+        require_msg_typed!(
+            get_stack_height() <= TRANSACTION_LEVEL_STACK_HEIGHT || group.allows_cpi_deposit(),
+            MangoError::CpiForbidden,
+            "deposits via CPI are not enabled for this group"
+        );
+        let withdraw_only = group.is_withdraw_only();
+        drop(group);
+
         let mut bank = self.bank.load_mut()?;
         let token_index = bank.token_index;
 
@@ -51,7 +61,7 @@ impl<'a, 'info> DepositCommon<'a, 'info> {
             let account = self.account.load_full()?;
             let position = account.token_position(token_index)?;
 
-            let amount_i80f48 = if reduce_only || bank.are_deposits_reduce_only() {
+            let amount_i80f48 = if reduce_only || bank.are_deposits_reduce_only() || withdraw_only {
                 position
                     .native(&bank)
                     .min(I80F48::ZERO)
@@ -67,6 +77,12 @@ impl<'a, 'info> DepositCommon<'a, 'info> {
                     MangoError::TokenInReduceOnlyMode
                 );
             }
+            if withdraw_only {
+                require!(
+                    reduce_only || amount_i80f48 == I80F48::from(amount),
+                    MangoError::GroupInWithdrawOnlyMode
+                );
+            }
             amount_i80f48
         };
 
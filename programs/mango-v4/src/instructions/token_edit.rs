@@ -53,9 +53,21 @@ pub fn token_edit(
     deposit_limit_opt: Option<u64>,
     zero_util_rate: Option<f32>,
     platform_liquidation_fee: Option<f32>,
+    keeper_reward_per_hour_opt: Option<f32>,
+    fallback_oracle_max_stable_price_deviation_opt: Option<f32>,
+    fallback_oracle_min_liquidity_opt: Option<u64>,
+    halt_on_vault_invariant_violation_opt: Option<bool>,
+    collateral_only_opt: Option<bool>,
+    interest_rate_shift_hours_opt: Option<u32>,
+    oracle_twap_window_seconds_opt: Option<u32>,
 ) -> Result<()> {
     let group = ctx.accounts.group.load()?;
 
+    require_msg!(
+        interest_rate_shift_hours_opt.is_none() || interest_rate_params_opt.is_some(),
+        "interest rate shift hours can only be set together with interest rate params"
+    );
+
     let mut mint_info = ctx.accounts.mint_info.load_mut()?;
     mint_info.verify_banks_ais(ctx.remaining_accounts)?;
 
@@ -133,11 +145,28 @@ pub fn token_edit(
             interest_rate_params.max_rate,
         );
             bank.adjustment_factor = I80F48::from_num(interest_rate_params.adjustment_factor);
-            bank.util0 = I80F48::from_num(interest_rate_params.util0);
-            bank.rate0 = I80F48::from_num(interest_rate_params.rate0);
-            bank.util1 = I80F48::from_num(interest_rate_params.util1);
-            bank.rate1 = I80F48::from_num(interest_rate_params.rate1);
-            bank.max_rate = I80F48::from_num(interest_rate_params.max_rate);
+            let shift_hours = interest_rate_shift_hours_opt.unwrap_or(0);
+            if shift_hours > 0 {
+                // The curve parameters move towards the new values in token_update_index_and_rate
+                let now_ts: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+                bank.interest_rate_shift_end = now_ts + shift_hours as u64 * HOUR as u64;
+                bank.interest_rate_shift_util0 = I80F48::from_num(interest_rate_params.util0);
+                bank.interest_rate_shift_rate0 = I80F48::from_num(interest_rate_params.rate0);
+                bank.interest_rate_shift_util1 = I80F48::from_num(interest_rate_params.util1);
+                bank.interest_rate_shift_rate1 = I80F48::from_num(interest_rate_params.rate1);
+                bank.interest_rate_shift_max_rate = I80F48::from_num(interest_rate_params.max_rate);
+                msg!(
+                    "Interest rate params shift until {}",
+                    bank.interest_rate_shift_end
+                );
+            } else {
+                bank.util0 = I80F48::from_num(interest_rate_params.util0);
+                bank.rate0 = I80F48::from_num(interest_rate_params.rate0);
+                bank.util1 = I80F48::from_num(interest_rate_params.util1);
+                bank.rate1 = I80F48::from_num(interest_rate_params.rate1);
+                bank.max_rate = I80F48::from_num(interest_rate_params.max_rate);
+                bank.clear_interest_rate_shift();
+            }
             require_group_admin = true;
         }
 
@@ -484,6 +513,72 @@ pub fn token_edit(
             bank.platform_liquidation_fee = I80F48::from_num(platform_liquidation_fee);
             require_group_admin = true;
         }
+
+        if let Some(keeper_reward_per_hour) = keeper_reward_per_hour_opt {
+            msg!(
+                "Keeper reward per hour old {:?}, new {:?}",
+                bank.keeper_reward_per_hour,
+                keeper_reward_per_hour
+            );
+            bank.keeper_reward_per_hour = keeper_reward_per_hour;
+            require_group_admin = true;
+        }
+
+        if let Some(max_deviation) = fallback_oracle_max_stable_price_deviation_opt {
+            msg!(
+                "Fallback oracle max stable price deviation old {:?}, new {:?}",
+                bank.fallback_oracle_max_stable_price_deviation,
+                max_deviation
+            );
+            bank.fallback_oracle_max_stable_price_deviation = max_deviation;
+            require_group_admin = true;
+        }
+
+        if let Some(min_liquidity) = fallback_oracle_min_liquidity_opt {
+            msg!(
+                "Fallback oracle min liquidity old {:?}, new {:?}",
+                bank.fallback_oracle_min_liquidity,
+                min_liquidity
+            );
+            bank.fallback_oracle_min_liquidity = min_liquidity;
+            require_group_admin = true;
+        }
+
+        if let Some(halt) = halt_on_vault_invariant_violation_opt {
+            msg!(
+                "Halt on vault invariant violation old {:?}, new {:?}",
+                bank.halt_on_vault_invariant_violation,
+                halt
+            );
+            bank.halt_on_vault_invariant_violation = u8::from(halt);
+            require_group_admin = true;
+        }
+
+        if let Some(collateral_only) = collateral_only_opt {
+            msg!(
+                "Collateral only old {:?}, new {:?}",
+                bank.collateral_only,
+                collateral_only
+            );
+            // security admin can only disable borrowing
+            if !collateral_only {
+                require_group_admin = true;
+            }
+            bank.collateral_only = u8::from(collateral_only);
+        }
+
+        if let Some(window_seconds) = oracle_twap_window_seconds_opt {
+            msg!(
+                "Oracle twap window seconds old {:?}, new {:?}",
+                bank.oracle_twap_window_seconds,
+                window_seconds
+            );
+            bank.oracle_twap_window_seconds = window_seconds;
+            // restart averaging at the next update
+            bank.oracle_twap = 0.0;
+            bank.oracle_twap_last_update = 0;
+            require_group_admin = true;
+        }
     }
 
     // account constraint #1
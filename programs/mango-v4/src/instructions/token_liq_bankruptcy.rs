@@ -9,7 +9,7 @@ use crate::state::*;
 
 use crate::accounts_ix::*;
 use crate::logs::{
-    emit_stack, LoanOriginationFeeInstruction, TokenBalanceLog, TokenLiqBankruptcyLog,
+    emit_stack, LoanOriginationFeeInstruction, TokenBalanceLog, TokenLiqBankruptcyLogV2,
     WithdrawLoanLog,
 };
 
@@ -51,9 +51,10 @@ pub fn token_liq_bankruptcy(
     liqee_health_cache.require_after_phase2_liquidation()?;
     liqee.fixed.set_being_liquidated(true);
 
-    let liab_is_insurance_token = liab_token_index == INSURANCE_TOKEN_INDEX;
+    let insurance_token_index = group.insurance_token_index;
+    let liab_is_insurance_token = liab_token_index == insurance_token_index;
     let (liab_bank, liab_oracle_price, opt_quote_bank_and_price) =
-        account_retriever.banks_mut_and_oracles(liab_token_index, INSURANCE_TOKEN_INDEX)?;
+        account_retriever.banks_mut_and_oracles(liab_token_index, insurance_token_index)?;
     assert!(liab_is_insurance_token == opt_quote_bank_and_price.is_none());
 
     let mut liab_deposit_index = liab_bank.deposit_index;
@@ -76,14 +77,18 @@ pub fn token_liq_bankruptcy(
     // guaranteed positive
     let mut remaining_liab_loss = (-initial_liab_native).min(-liqee_liab_health_balance);
 
+    let insurance_price = opt_quote_bank_and_price
+        .as_ref()
+        .map(|(_, quote_price)| *quote_price)
+        .unwrap_or(liab_oracle_price);
+
     // We pay for the liab token in quote. Example: SOL is at $20 and USDC is at $2, then for a liab
     // of 3 SOL, we'd pay 3 * 20 / 2 * (1+fee) = 30 * (1+fee) USDC.
-    let liab_to_quote_with_fee =
-        if let Some((_quote_bank, quote_price)) = opt_quote_bank_and_price.as_ref() {
-            liab_oracle_price * (I80F48::ONE + liab_bank.liquidation_fee) / quote_price
-        } else {
-            I80F48::ONE
-        };
+    let liab_to_quote_with_fee = if opt_quote_bank_and_price.is_some() {
+        liab_oracle_price * (I80F48::ONE + liab_bank.liquidation_fee) / insurance_price
+    } else {
+        I80F48::ONE
+    };
 
     let liab_transfer_unrounded = remaining_liab_loss.min(max_liab_transfer);
 
@@ -106,6 +111,8 @@ pub fn token_liq_bankruptcy(
     // exceed max_liab_transfer due to the ceil() above! Otoh, not doing it would allow
     // liquidators to exploit the insurance fund for 1 native token each call.
     let liab_transfer = insurance_transfer_i80f48 / liab_to_quote_with_fee;
+    let liquidation_fee =
+        insurance_transfer_i80f48 - liab_transfer * liab_oracle_price / insurance_price;
 
     let mut liqee_liab_active = true;
     if insurance_transfer > 0 {
@@ -133,7 +140,7 @@ pub fn token_liq_bankruptcy(
 
             // credit the liqor
             let (liqor_quote, liqor_quote_raw_token_index, _) =
-                liqor.ensure_token_position(INSURANCE_TOKEN_INDEX)?;
+                liqor.ensure_token_position(insurance_token_index)?;
             let liqor_quote_active =
                 quote_bank.deposit(liqor_quote, insurance_transfer_i80f48, now_ts)?;
 
@@ -141,7 +148,7 @@ pub fn token_liq_bankruptcy(
             emit_stack(TokenBalanceLog {
                 mango_group: ctx.accounts.group.key(),
                 mango_account: ctx.accounts.liqor.key(),
-                token_index: INSURANCE_TOKEN_INDEX,
+                token_index: insurance_token_index,
                 indexed_position: liqor_quote.indexed_position.to_bits(),
                 deposit_index: quote_deposit_index.to_bits(),
                 borrow_index: quote_borrow_index.to_bits(),
@@ -202,11 +209,11 @@ pub fn token_liq_bankruptcy(
                 );
             }
         } else {
-            // For liab_token_index == INSURANCE_TOKEN_INDEX: the insurance fund deposits directly into liqee,
+            // For liab_token_index == insurance_token_index: the insurance fund deposits directly into liqee,
             // without a fee or the liqor being involved
             // account constraint #2 b)
             require_keys_eq!(liab_bank.vault, ctx.accounts.quote_vault.key());
-            require_eq!(liab_token_index, INSURANCE_TOKEN_INDEX);
+            require_eq!(liab_token_index, insurance_token_index);
             require_eq!(liab_to_quote_with_fee, I80F48::ONE);
             require_eq!(insurance_transfer_i80f48, liab_transfer);
         }
@@ -280,15 +287,19 @@ pub fn token_liq_bankruptcy(
         liqee.deactivate_token_position_and_log(liqee_raw_token_index, ctx.accounts.liqee.key());
     }
 
-    emit_stack(TokenLiqBankruptcyLog {
+    emit_stack(TokenLiqBankruptcyLogV2 {
         mango_group: ctx.accounts.group.key(),
         liqee: ctx.accounts.liqee.key(),
         liqor: ctx.accounts.liqor.key(),
         liab_token_index,
         initial_liab_native: initial_liab_native.to_bits(),
         liab_price: liab_oracle_price.to_bits(),
-        insurance_token_index: INSURANCE_TOKEN_INDEX,
+        liab_transfer: liab_transfer.to_bits(),
+        insurance_token_index,
+        insurance_price: insurance_price.to_bits(),
         insurance_transfer: insurance_transfer_i80f48.to_bits(),
+        liquidation_fee: liquidation_fee.to_bits(),
+        insurance_fund_exhausted,
         socialized_loss: socialized_loss.to_bits(),
         starting_liab_deposit_index: starting_deposit_index.to_bits(),
         ending_liab_deposit_index: liab_deposit_index.to_bits(),
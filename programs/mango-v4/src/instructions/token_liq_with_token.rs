@@ -137,7 +137,12 @@ pub(crate) fn liquidation_action(
     let fee_factor_total =
         (I80F48::ONE + liab_bank.liquidation_fee + liab_bank.platform_liquidation_fee)
             * (I80F48::ONE + asset_bank.liquidation_fee + asset_bank.platform_liquidation_fee);
-    let liab_oracle_price_adjusted = liab_oracle_price * fee_factor_total;
+    // With an oracle twap configured, the exchange values the liqee's asset at the max and
+    // its liab at the min of oracle and twap price. That's folded into the liab price to
+    // keep asset_oracle_price the price that health uses.
+    let liab_exchange_price = liab_bank.twap_liab_price(liab_oracle_price) * asset_oracle_price
+        / asset_bank.twap_asset_price(asset_oracle_price);
+    let liab_oracle_price_adjusted = liab_exchange_price * fee_factor_total;
 
     let init_asset_weight = asset_bank.init_asset_weight;
     let init_liab_weight = liab_bank.init_liab_weight;
@@ -221,7 +226,7 @@ pub(crate) fn liquidation_action(
         .max(I80F48::ZERO);
 
     // The amount of asset native tokens we will give up for them
-    let asset_transfer_base = liab_transfer * liab_oracle_price / asset_oracle_price;
+    let asset_transfer_base = liab_transfer * liab_exchange_price / asset_oracle_price;
     let asset_transfer_to_liqor = asset_transfer_base * fee_factor_liqor;
     let asset_transfer_from_liqee = asset_transfer_base * fee_factor_total;
 
@@ -244,6 +249,11 @@ pub(crate) fn liquidation_action(
     let liqor_liab_withdraw_result =
         liab_bank.withdraw_with_fee(liqor_liab_position, liab_transfer, now_ts)?;
     let liqor_liab_indexed_position = liqor_liab_position.indexed_position;
+    // The liqor may not borrow collateral-only tokens to pay for the liab
+    require!(
+        liqor_liab_indexed_position >= 0 || !liab_bank.is_collateral_only(),
+        MangoError::TokenInReduceOnlyMode
+    );
     let liqee_liab_native_after = liqee_liab_position.native(liab_bank);
 
     let (liqor_asset_position, liqor_asset_raw_index, _) =
@@ -596,6 +606,151 @@ mod tests {
         assert_eq_f!(hc.health(HealthType::LiquidationEnd), 0.0, 0.01);
     }
 
+    // Check that the liqor and platform liquidation fees are paid by the liqee
+    #[test]
+    fn test_liq_with_token_fees() {
+        let mut setup = TestSetup::new();
+        {
+            let ab = setup.asset_bank.data();
+            ab.init_asset_weight = I80F48::from_num(0.6);
+            ab.platform_liquidation_fee = I80F48::from_num(0.02);
+            let lb = setup.liab_bank.data();
+            lb.init_liab_weight = I80F48::from_num(1.4);
+            lb.liquidation_fee = I80F48::from_num(0.05);
+        }
+        {
+            let asset_bank = setup.asset_bank.data();
+            asset_bank
+                .change_without_fee(asset_p(&mut setup.liqee), I80F48::from_num(10.0), 0)
+                .unwrap();
+
+            let liab_bank = setup.liab_bank.data();
+            liab_bank
+                .change_without_fee(liab_p(&mut setup.liqor), I80F48::from_num(1000.0), 0)
+                .unwrap();
+            liab_bank
+                .change_without_fee(liab_p(&mut setup.liqee), I80F48::from_num(-11.0), 0)
+                .unwrap();
+        }
+
+        // limited by max_liab_transfer
+        let mut result = setup.run(I80F48::from(2)).unwrap();
+
+        let liqor_fee_factor = 1.05;
+        let total_fee_factor = 1.05 * 1.02;
+
+        let asset_bank = result.asset_bank.data();
+        assert_eq_f!(
+            asset_p(&mut result.liqee).native(asset_bank),
+            10.0 - 2.0 * total_fee_factor,
+            0.01
+        );
+        assert_eq_f!(
+            asset_p(&mut result.liqor).native(asset_bank),
+            2.0 * liqor_fee_factor,
+            0.01
+        );
+        assert_eq_f!(
+            asset_bank.collected_liquidation_fees,
+            2.0 * (total_fee_factor - liqor_fee_factor),
+            0.01
+        );
+
+        let liab_bank = result.liab_bank.data();
+        assert_eq_f!(liab_p(&mut result.liqee).native(liab_bank), -9.0, 0.01);
+        assert_eq_f!(liab_p(&mut result.liqor).native(liab_bank), 998.0, 0.01);
+    }
+
+    // Check that the liqor can't borrow a collateral-only liab token
+    #[test]
+    fn test_liq_with_token_collateral_only_liab() {
+        let mut setup = TestSetup::new();
+        {
+            let ab = setup.asset_bank.data();
+            ab.init_asset_weight = I80F48::from_num(0.6);
+            let lb = setup.liab_bank.data();
+            lb.init_liab_weight = I80F48::from_num(1.4);
+        }
+        {
+            let asset_bank = setup.asset_bank.data();
+            asset_bank
+                .change_without_fee(asset_p(&mut setup.liqee), I80F48::from_num(10.0), 0)
+                .unwrap();
+            asset_bank
+                .change_without_fee(asset_p(&mut setup.liqor), I80F48::from_num(1000.0), 0)
+                .unwrap();
+
+            let liab_bank = setup.liab_bank.data();
+            liab_bank
+                .change_without_fee(liab_p(&mut setup.liqor), I80F48::from_num(1.0), 0)
+                .unwrap();
+            liab_bank
+                .change_without_fee(liab_p(&mut setup.liqee), I80F48::from_num(-11.0), 0)
+                .unwrap();
+            liab_bank.collateral_only = 1;
+        }
+
+        assert!(setup.run(I80F48::from(2)).is_err());
+
+        let mut result = setup.run(I80F48::from(1)).unwrap();
+        let liab_bank = result.liab_bank.data();
+        assert_eq_f!(liab_p(&mut result.liqee).native(liab_bank), -10.0, 0.01);
+        assert_eq_f!(liab_p(&mut result.liqor).native(liab_bank), 0.0, 0.01);
+    }
+
+    // Check that an unbounded max_liab_transfer stops once liquidation end health is restored
+    #[test]
+    fn test_liq_with_token_unbounded_transfer() {
+        let mut setup = TestSetup::new();
+        {
+            let ab = setup.asset_bank.data();
+            ab.init_asset_weight = I80F48::from_num(0.6);
+            let lb = setup.liab_bank.data();
+            lb.init_liab_weight = I80F48::from_num(1.4);
+        }
+        {
+            let asset_bank = setup.asset_bank.data();
+            asset_bank
+                .change_without_fee(asset_p(&mut setup.liqee), I80F48::from_num(10.0), 0)
+                .unwrap();
+
+            let liab_bank = setup.liab_bank.data();
+            liab_bank
+                .change_without_fee(liab_p(&mut setup.liqor), I80F48::from_num(1000.0), 0)
+                .unwrap();
+            liab_bank
+                .change_without_fee(liab_p(&mut setup.liqee), I80F48::from_num(-5.0), 0)
+                .unwrap();
+        }
+
+        let hc = setup.liqee_health_cache();
+        assert_eq_f!(hc.health(HealthType::LiquidationEnd), 6.0 - 7.0, 0.01);
+
+        // liab needed: 1.0 / (1.4 - 0.6)
+        let mut result = setup.run(I80F48::MAX).unwrap();
+        assert_eq_f!(
+            asset_p(&mut result.liqee).native(result.asset_bank.data()),
+            10.0 - 1.25,
+            0.01
+        );
+        assert_eq_f!(
+            liab_p(&mut result.liqee).native(result.liab_bank.data()),
+            -5.0 + 1.25,
+            0.01
+        );
+
+        let hc = result.liqee_health_cache();
+        assert_eq_f!(hc.health(HealthType::LiquidationEnd), 0.0, 0.01);
+
+        // liquidating again does not take more than dust
+        let mut again = result.run(I80F48::MAX).unwrap();
+        assert_eq_f!(
+            asset_p(&mut again.liqee).native(again.asset_bank.data()),
+            10.0 - 1.25,
+            0.01
+        );
+    }
+
     #[test]
     fn test_liq_with_token_while_perp() {
         let test_cases = vec![
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::accounts_ix::*;
+
+pub fn token_migrate(ctx: Context<TokenMigrate>) -> Result<()> {
+    let mut bank = ctx.accounts.bank.load_mut()?;
+    let old_version = bank.layout_version;
+    if bank.migrate()? {
+        msg!(
+            "Bank {} layout version old {}, new {}",
+            bank.name(),
+            old_version,
+            bank.layout_version
+        );
+    }
+    Ok(())
+}
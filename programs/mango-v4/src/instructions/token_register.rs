@@ -11,6 +11,7 @@ use crate::logs::{emit_stack, TokenMetaDataLogV2};
 pub const INDEX_START: I80F48 = I80F48::from_bits(1_000_000 * I80F48::ONE.to_bits());
 
 use crate::accounts_ix::*;
+use crate::address_lookup_table_program;
 
 #[allow(clippy::too_many_arguments)]
 pub fn token_register(
@@ -45,8 +46,8 @@ pub fn token_register(
     zero_util_rate: f32,
     platform_liquidation_fee: f32,
 ) -> Result<()> {
-    // Require token 0 to be in the insurance token
-    if token_index == INSURANCE_TOKEN_INDEX {
+    // Require the insurance token index to be used for the insurance mint
+    if token_index == ctx.accounts.group.load()?.insurance_token_index {
         require_keys_eq!(
             ctx.accounts.group.load()?.insurance_mint,
             ctx.accounts.mint.key()
@@ -127,7 +128,29 @@ pub fn token_register(
         zero_util_rate: I80F48::from_num(zero_util_rate),
         platform_liquidation_fee: I80F48::from_num(platform_liquidation_fee),
         collected_liquidation_fees: I80F48::ZERO,
-        reserved: [0; 1920],
+        keeper_reward_per_hour: 0.0,
+        fallback_oracle_max_stable_price_deviation: 0.0,
+        fallback_oracle_min_liquidity: 0,
+        halt_on_vault_invariant_violation: 0,
+        collateral_only: 0,
+        padding2: Default::default(),
+        interest_rate_shift_end: 0,
+        padding3: Default::default(),
+        interest_rate_shift_util0: I80F48::ZERO,
+        interest_rate_shift_rate0: I80F48::ZERO,
+        interest_rate_shift_util1: I80F48::ZERO,
+        interest_rate_shift_rate1: I80F48::ZERO,
+        interest_rate_shift_max_rate: I80F48::ZERO,
+        oracle_twap: 0.0,
+        oracle_twap_last_update: 0,
+        oracle_twap_window_seconds: 0,
+        padding4: Default::default(),
+        referral_fees_unclaimed: 0,
+        keeper_rewards_paid: 0,
+        referral_fees_paid: 0,
+        layout_version: Bank::LAYOUT_VERSION,
+        padding5: [0; 7],
+        reserved: [0; 1744],
     };
 
     let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
@@ -172,5 +195,18 @@ pub fn token_register(
         mint_info: ctx.accounts.mint_info.key(),
     });
 
+    address_lookup_table_program::maybe_extend_group_alt(
+        &ctx.accounts.group,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.bank.key(),
+            ctx.accounts.vault.key(),
+            ctx.accounts.mint_info.key(),
+            ctx.accounts.oracle.key(),
+        ],
+    )?;
+
     Ok(())
 }
@@ -10,12 +10,68 @@ use crate::util::fill_from_str;
 use crate::logs::{emit_stack, TokenMetaDataLogV2};
 
 use crate::accounts_ix::*;
+use crate::address_lookup_table_program;
+
+struct TierParams {
+    conf_filter: f64,
+    max_staleness_slots: i64,
+    maint_asset_weight: f64,
+    init_asset_weight: f64,
+    maint_liab_weight: f64,
+    init_liab_weight: f64,
+    liquidation_fee: f64,
+    net_borrow_limit_per_window_quote: i64,
+    scale_start_quote: f64,
+    reduce_only: u8,
+}
+
+fn tier_params(tier: FastListingTier) -> TierParams {
+    match tier {
+        FastListingTier::CollateralDisabled => TierParams {
+            conf_filter: 1000.0,     // effectively disabled
+            max_staleness_slots: -1, // no collateral value to protect
+            maint_asset_weight: 0.0,
+            init_asset_weight: 0.0,
+            maint_liab_weight: 1.4, // 2.5x
+            init_liab_weight: 1.8,  // 1.25x
+            liquidation_fee: 0.05,
+            net_borrow_limit_per_window_quote: 5_000_000_000, // $5k
+            scale_start_quote: 5_000_000_000.0,               // $5k
+            reduce_only: 2,                                   // deposit-only
+        },
+        FastListingTier::Midwit => TierParams {
+            conf_filter: 0.2,
+            max_staleness_slots: 120,
+            maint_asset_weight: 0.5,
+            init_asset_weight: 0.25,
+            maint_liab_weight: 1.4, // 2.5x
+            init_liab_weight: 1.8,  // 1.25x
+            liquidation_fee: 0.05,
+            net_borrow_limit_per_window_quote: 5_000_000_000, // $5k
+            scale_start_quote: 20_000_000_000.0,              // $20k
+            reduce_only: 0,
+        },
+        FastListingTier::Premium => TierParams {
+            conf_filter: 0.1,
+            max_staleness_slots: 120,
+            maint_asset_weight: 0.8,
+            init_asset_weight: 0.6,
+            maint_liab_weight: 1.2, // 5x
+            init_liab_weight: 1.4,  // 2.5x
+            liquidation_fee: 0.03,
+            net_borrow_limit_per_window_quote: 50_000_000_000, // $50k
+            scale_start_quote: 100_000_000_000.0,              // $100k
+            reduce_only: 0,
+        },
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 pub fn token_register_trustless(
     ctx: Context<TokenRegisterTrustless>,
     token_index: TokenIndex,
     name: String,
+    tier: FastListingTier,
 ) -> Result<()> {
     require_neq!(token_index, QUOTE_TOKEN_INDEX);
     require_neq!(token_index, TokenIndex::MAX);
@@ -36,6 +92,8 @@ pub fn token_register_trustless(
     }
 
     let net_borrow_limit_window_size_ts = 24 * 60 * 60u64;
+    let params = tier_params(tier);
+    msg!("Fast listing tier {:?}", tier);
 
     let mut bank = ctx.accounts.bank.load_init()?;
     *bank = Bank {
@@ -45,8 +103,8 @@ pub fn token_register_trustless(
         vault: ctx.accounts.vault.key(),
         oracle: ctx.accounts.oracle.key(),
         oracle_config: OracleConfig {
-            conf_filter: I80F48::from_num(1000.0), // effectively disabled
-            max_staleness_slots: -1,
+            conf_filter: I80F48::from_num(params.conf_filter),
+            max_staleness_slots: params.max_staleness_slots,
             reserved: [0; 72],
         },
         stable_price_model: StablePriceModel::default(),
@@ -67,11 +125,11 @@ pub fn token_register_trustless(
         collected_fees_native: I80F48::ZERO,
         loan_origination_fee_rate: I80F48::from_num(0.0020),
         loan_fee_rate: I80F48::from_num(0.005),
-        maint_asset_weight: I80F48::from_num(0),
-        init_asset_weight: I80F48::from_num(0),
-        maint_liab_weight: I80F48::from_num(1.4), // 2.5x
-        init_liab_weight: I80F48::from_num(1.8),  // 1.25x
-        liquidation_fee: I80F48::from_num(0.05),
+        maint_asset_weight: I80F48::from_num(params.maint_asset_weight),
+        init_asset_weight: I80F48::from_num(params.init_asset_weight),
+        maint_liab_weight: I80F48::from_num(params.maint_liab_weight),
+        init_liab_weight: I80F48::from_num(params.init_liab_weight),
+        liquidation_fee: I80F48::from_num(params.liquidation_fee),
         platform_liquidation_fee: I80F48::from_num(0.05),
         dust: I80F48::ZERO,
         flash_loan_token_account_initial: u64::MAX,
@@ -84,11 +142,11 @@ pub fn token_register_trustless(
         net_borrow_limit_window_size_ts,
         last_net_borrows_window_start_ts: now_ts / net_borrow_limit_window_size_ts
             * net_borrow_limit_window_size_ts,
-        net_borrow_limit_per_window_quote: 5_000_000_000, // $5k
+        net_borrow_limit_per_window_quote: params.net_borrow_limit_per_window_quote,
         net_borrows_in_window: 0,
-        borrow_weight_scale_start_quote: 5_000_000_000.0, // $5k
-        deposit_weight_scale_start_quote: 5_000_000_000.0, // $5k
-        reduce_only: 2,                                   // deposit-only
+        borrow_weight_scale_start_quote: params.scale_start_quote,
+        deposit_weight_scale_start_quote: params.scale_start_quote,
+        reduce_only: params.reduce_only,
         force_close: 0,
         padding: Default::default(),
         fees_withdrawn: 0,
@@ -107,7 +165,29 @@ pub fn token_register_trustless(
         deposit_limit: 0,
         zero_util_rate: I80F48::ZERO,
         collected_liquidation_fees: I80F48::ZERO,
-        reserved: [0; 1920],
+        keeper_reward_per_hour: 0.0,
+        fallback_oracle_max_stable_price_deviation: 0.0,
+        fallback_oracle_min_liquidity: 0,
+        halt_on_vault_invariant_violation: 0,
+        collateral_only: 0,
+        padding2: Default::default(),
+        interest_rate_shift_end: 0,
+        padding3: Default::default(),
+        interest_rate_shift_util0: I80F48::ZERO,
+        interest_rate_shift_rate0: I80F48::ZERO,
+        interest_rate_shift_util1: I80F48::ZERO,
+        interest_rate_shift_rate1: I80F48::ZERO,
+        interest_rate_shift_max_rate: I80F48::ZERO,
+        oracle_twap: 0.0,
+        oracle_twap_last_update: 0,
+        oracle_twap_window_seconds: 0,
+        padding4: Default::default(),
+        referral_fees_unclaimed: 0,
+        keeper_rewards_paid: 0,
+        referral_fees_paid: 0,
+        layout_version: Bank::LAYOUT_VERSION,
+        padding5: [0; 7],
+        reserved: [0; 1744],
     };
     let oracle_ref = &AccountInfoRef::borrow(ctx.accounts.oracle.as_ref())?;
     if let Ok(oracle_price) = bank.oracle_price(&OracleAccountInfos::from_reader(oracle_ref), None)
@@ -151,5 +231,18 @@ pub fn token_register_trustless(
         mint_info: ctx.accounts.mint_info.key(),
     });
 
+    address_lookup_table_program::maybe_extend_group_alt(
+        &ctx.accounts.group,
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        &[
+            ctx.accounts.bank.key(),
+            ctx.accounts.vault.key(),
+            ctx.accounts.mint_info.key(),
+            ctx.accounts.oracle.key(),
+        ],
+    )?;
+
     Ok(())
 }
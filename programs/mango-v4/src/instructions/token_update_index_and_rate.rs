@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 
 use crate::accounts_ix::*;
 use crate::error::MangoError;
-use crate::logs::{emit_stack, UpdateIndexLog, UpdateRateLogV2};
-use crate::state::{OracleAccountInfos, HOUR};
+use crate::logs::{emit_stack, TokenBalanceLog, UpdateIndexLog, UpdateRateLogV2};
+use crate::state::{MangoAccountLoader, OracleAccountInfos, HOUR};
 use crate::{
     accounts_zerocopy::{AccountInfoRef, LoadMutZeroCopyRef, LoadZeroCopyRef},
     state::Bank,
@@ -78,6 +78,18 @@ pub fn token_update_index_and_rate(ctx: Context<TokenUpdateIndexAndRate>) -> Res
         let diff_ts =
             I80F48::from_num((now_ts - some_bank.index_last_updated).min(max_interest_timestep));
 
+        // Apply a configured interest rate curve shift before computing the new indexes
+        let last_updated = some_bank.index_last_updated;
+        some_bank.update_interest_rate_shift(last_updated, now_ts);
+        let (util0, rate0, util1, rate1, max_rate) = (
+            some_bank.util0,
+            some_bank.rate0,
+            some_bank.util1,
+            some_bank.rate1,
+            some_bank.max_rate,
+        );
+        let interest_rate_shift_done = some_bank.interest_rate_shift_end == 0;
+
         let (deposit_index, borrow_index, borrow_fees, borrow_rate, deposit_rate) =
             some_bank.compute_index(indexed_total_deposits, indexed_total_borrows, diff_ts)?;
 
@@ -100,6 +112,10 @@ pub fn token_update_index_and_rate(ctx: Context<TokenUpdateIndexAndRate>) -> Res
             .update(now_ts as u64, price.to_num());
         let stable_price_model = some_bank.stable_price_model;
 
+        some_bank.update_oracle_twap(now_ts, price.to_num());
+        let (oracle_twap, oracle_twap_last_update) =
+            (some_bank.oracle_twap, some_bank.oracle_twap_last_update);
+
         // If a maint weight shift is done, copy the target into the normal values
         // and clear the transition parameters.
         let maint_shift_done = some_bank.maint_weight_shift_duration_inv.is_positive()
@@ -141,6 +157,17 @@ pub fn token_update_index_and_rate(ctx: Context<TokenUpdateIndexAndRate>) -> Res
             bank.avg_utilization = new_avg_utilization;
 
             bank.stable_price_model = stable_price_model;
+            bank.oracle_twap = oracle_twap;
+            bank.oracle_twap_last_update = oracle_twap_last_update;
+
+            bank.util0 = util0;
+            bank.rate0 = rate0;
+            bank.util1 = util1;
+            bank.rate1 = rate1;
+            bank.max_rate = max_rate;
+            if interest_rate_shift_done {
+                bank.clear_interest_rate_shift();
+            }
 
             if maint_shift_done {
                 bank.maint_asset_weight = bank.maint_weight_shift_asset_target;
@@ -152,6 +179,36 @@ pub fn token_update_index_and_rate(ctx: Context<TokenUpdateIndexAndRate>) -> Res
                 bank.maint_weight_shift_end = 0;
             }
         }
+
+        // Pay the keeper out of the fees collected by the mint's first bank, pro-rated
+        // by the interest timestep. Only accounts that already have a position for the
+        // token are paid.
+        if let Some(keeper_loader) = ctx.accounts.keeper.as_ref() {
+            let bank_ai = ctx
+                .remaining_accounts
+                .iter()
+                .find(|ai| ai.key() == mint_info.first_bank())
+                .ok_or_else(|| error_msg!("first bank of the mint not passed"))?;
+            let mut bank = bank_ai.load_mut::<Bank>()?;
+            let mut keeper = keeper_loader.load_full_mut()?;
+            if let Ok((position, _)) = keeper.token_position_mut(mint_info.token_index) {
+                let reward_target =
+                    I80F48::from_num(bank.keeper_reward_per_hour) * diff_ts / I80F48::from(HOUR);
+                let reward = bank.take_keeper_reward(reward_target);
+                if reward > 0 {
+                    bank.deposit(position, I80F48::from(reward), now_ts)?;
+                    emit_stack(TokenBalanceLog {
+                        mango_group: mint_info.group.key(),
+                        mango_account: keeper_loader.key(),
+                        token_index: mint_info.token_index,
+                        indexed_position: position.indexed_position.to_bits(),
+                        deposit_index: bank.deposit_index.to_bits(),
+                        borrow_index: bank.borrow_index.to_bits(),
+                    });
+                }
+                msg!("keeper reward {}", reward);
+            }
+        }
     }
 
     // compute optimal rates, and max rate and set them on the bank
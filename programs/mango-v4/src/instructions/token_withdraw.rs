@@ -50,8 +50,9 @@ pub fn token_withdraw(ctx: Context<TokenWithdraw>, amount: u64, allow_borrow: bo
     let position = account.token_position_mut_by_raw_index(raw_token_index);
     let native_position = position.native(&bank);
 
-    // Handle amount special case for withdrawing everything
-    let amount = if amount == u64::MAX && !allow_borrow {
+    // Handle amount special case for withdrawing everything: this never borrows,
+    // even with allow_borrow, and closes the position (dusting the remainder)
+    let amount = if amount == u64::MAX {
         if !native_position.is_negative() {
             // TODO: This rounding may mean that if we deposit and immediately withdraw
             //       we can't withdraw the full amount!
@@ -68,6 +69,9 @@ pub fn token_withdraw(ctx: Context<TokenWithdraw>, amount: u64, allow_borrow: bo
     if bank.are_borrows_reduce_only() {
         require!(!is_borrow, MangoError::TokenInReduceOnlyMode);
     }
+    if group.is_withdraw_only() {
+        require!(!is_borrow, MangoError::GroupInWithdrawOnlyMode);
+    }
 
     let amount_i80f48 = I80F48::from(amount);
 
@@ -160,7 +164,11 @@ pub fn token_withdraw(ctx: Context<TokenWithdraw>, amount: u64, allow_borrow: bo
         if let Some((mut health_cache, pre_init_health)) = pre_health_opt {
             // This is the normal case
             health_cache.adjust_token_balance(&bank, native_position_after - native_position)?;
-            account.check_health_post(&health_cache, pre_init_health)?;
+            account.check_health_post(
+                &health_cache,
+                pre_init_health,
+                ctx.accounts.account.key(),
+            )?;
         } else {
             // Some oracle was stale/not confident enough above.
             //
@@ -176,6 +184,7 @@ pub fn token_withdraw(ctx: Context<TokenWithdraw>, amount: u64, allow_borrow: bo
             let post_init_health = health_cache.health(HealthType::Init);
             account.check_health_pre_checks(&health_cache, post_init_health)?;
             account.check_health_post_checks(I80F48::MAX, post_init_health)?;
+            account.emit_health_log(&health_cache, post_init_health, ctx.accounts.account.key());
         }
     }
 
@@ -231,5 +240,16 @@ pub fn token_withdraw(ctx: Context<TokenWithdraw>, amount: u64, allow_borrow: bo
         bank.enforce_borrows_lte_deposits()?;
     }
 
+    // Keep the referrer's part of the fee claimable, see account_claim_referral_fees
+    let referral_fees = withdraw_result.loan_origination_fee.floor().to_num::<u64>();
+    if account
+        .fixed
+        .accrue_referral_fees(ReferralFeeSource::Bank, token_index, referral_fees)
+    {
+        drop(bank);
+        let mut bank = ctx.accounts.bank.load_mut()?;
+        cm!(bank.referral_fees_unclaimed += referral_fees)?;
+    }
+
     Ok(())
 }
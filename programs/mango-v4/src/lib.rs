@@ -25,6 +25,9 @@ pub mod serum3_cpi;
 pub mod state;
 pub mod types;
 
+#[cfg(feature = "test-fixtures")]
+pub mod test_fixtures;
+
 #[cfg(feature = "enable-gpl")]
 pub mod instructions;
 
@@ -33,10 +36,10 @@ pub mod instructions;
 compile_error!("compiling the program entrypoint without 'enable-gpl' makes no sense, enable it or use the 'cpi' or 'client' features");
 
 use state::{
-    IxGate, OpenbookV2MarketIndex, OracleConfigParams, PerpMarketIndex, PlaceOrderType,
-    SelfTradeBehavior, Serum3MarketIndex, Side, TokenConditionalSwap,
+    IxGate, OpenbookV2MarketIndex, OracleConfigParams, PerpMarketIndex, PerpTriggerCondition,
+    PlaceOrderType, SelfTradeBehavior, Serum3MarketIndex, Side, TokenConditionalSwap,
     TokenConditionalSwapDisplayPriceStyle, TokenConditionalSwapIntention, TokenConditionalSwapType,
-    TokenIndex, TCS_START_INCENTIVE,
+    TokenIndex,
 };
 
 declare_id!("4MangoMjqJ2firMokCjjGgoK8d4MXcrgL7XJaL3w6fVg");
@@ -84,6 +87,10 @@ pub mod mango_v4 {
         mngo_token_index_opt: Option<TokenIndex>,
         buyback_fees_expiry_interval_opt: Option<u64>,
         allowed_fast_listings_per_interval_opt: Option<u16>,
+        allowed_swap_programs_opt: Option<Vec<Pubkey>>,
+        referral_fee_share_opt: Option<f32>,
+        insurance_token_index_opt: Option<TokenIndex>,
+        allow_cpi_deposit_opt: Option<bool>,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::group_edit(
@@ -100,10 +107,23 @@ pub mod mango_v4 {
             mngo_token_index_opt,
             buyback_fees_expiry_interval_opt,
             allowed_fast_listings_per_interval_opt,
+            allowed_swap_programs_opt,
+            referral_fee_share_opt,
+            insurance_token_index_opt,
+            allow_cpi_deposit_opt,
         )?;
         Ok(())
     }
 
+    pub fn group_deposit_insurance_fund(
+        ctx: Context<GroupDepositInsuranceFund>,
+        amount: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::group_deposit_insurance_fund(ctx, amount)?;
+        Ok(())
+    }
+
     pub fn group_withdraw_insurance_fund(
         ctx: Context<GroupWithdrawInsuranceFund>,
         amount: u64,
@@ -119,6 +139,12 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn group_migrate(ctx: Context<GroupMigrate>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::group_migrate(ctx)?;
+        Ok(())
+    }
+
     pub fn group_close(ctx: Context<GroupClose>) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::group_close(ctx)?;
@@ -198,9 +224,10 @@ pub mod mango_v4 {
         ctx: Context<TokenRegisterTrustless>,
         token_index: TokenIndex,
         name: String,
+        tier: FastListingTier,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
-        instructions::token_register_trustless(ctx, token_index, name)?;
+        instructions::token_register_trustless(ctx, token_index, name, tier)?;
         Ok(())
     }
 
@@ -245,6 +272,13 @@ pub mod mango_v4 {
         deposit_limit_opt: Option<u64>,
         zero_util_rate_opt: Option<f32>,
         platform_liquidation_fee_opt: Option<f32>,
+        keeper_reward_per_hour_opt: Option<f32>,
+        fallback_oracle_max_stable_price_deviation_opt: Option<f32>,
+        fallback_oracle_min_liquidity_opt: Option<u64>,
+        halt_on_vault_invariant_violation_opt: Option<bool>,
+        collateral_only_opt: Option<bool>,
+        interest_rate_shift_hours_opt: Option<u32>,
+        oracle_twap_window_seconds_opt: Option<u32>,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::token_edit(
@@ -287,6 +321,13 @@ pub mod mango_v4 {
             deposit_limit_opt,
             zero_util_rate_opt,
             platform_liquidation_fee_opt,
+            keeper_reward_per_hour_opt,
+            fallback_oracle_max_stable_price_deviation_opt,
+            fallback_oracle_min_liquidity_opt,
+            halt_on_vault_invariant_violation_opt,
+            collateral_only_opt,
+            interest_rate_shift_hours_opt,
+            oracle_twap_window_seconds_opt,
         )?;
         Ok(())
     }
@@ -316,6 +357,18 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn token_migrate(ctx: Context<TokenMigrate>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::token_migrate(ctx)?;
+        Ok(())
+    }
+
+    pub fn token_check_vault_invariant(ctx: Context<TokenCheckVaultInvariant>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::token_check_vault_invariant(ctx)?;
+        Ok(())
+    }
+
     pub fn account_create(
         ctx: Context<AccountCreate>,
         account_num: u32,
@@ -338,6 +391,10 @@ pub mod mango_v4 {
             perp_oo_count,
             0,
             name,
+            ctx.accounts
+                .referrer
+                .as_ref()
+                .map_or(Pubkey::default(), |referrer| referrer.key()),
         )?;
         Ok(())
     }
@@ -365,6 +422,10 @@ pub mod mango_v4 {
             perp_oo_count,
             token_conditional_swap_count,
             name,
+            ctx.accounts
+                .referrer
+                .as_ref()
+                .map_or(Pubkey::default(), |referrer| referrer.key()),
         )?;
         Ok(())
     }
@@ -413,6 +474,9 @@ pub mod mango_v4 {
         delegate_opt: Option<Pubkey>,
         temporary_delegate_opt: Option<Pubkey>,
         temporary_delegate_expiry_opt: Option<u64>,
+        isolated_opt: Option<bool>,
+        isolated_perp_market_index_opt: Option<PerpMarketIndex>,
+        isolated_token_indexes_opt: Option<Vec<TokenIndex>>,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::account_edit(
@@ -421,10 +485,30 @@ pub mod mango_v4 {
             delegate_opt,
             temporary_delegate_opt,
             temporary_delegate_expiry_opt,
+            isolated_opt,
+            isolated_perp_market_index_opt,
+            isolated_token_indexes_opt,
         )?;
         Ok(())
     }
 
+    pub fn group_toggle_withdraw_only(
+        ctx: Context<GroupToggleWithdrawOnly>,
+        withdraw_only: bool,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::group_toggle_withdraw_only(ctx, withdraw_only)?;
+        Ok(())
+    }
+
+    pub fn account_create_dao_token_positions(
+        ctx: Context<AccountCreateDaoTokenPositions>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::account_create_dao_token_positions(ctx)?;
+        Ok(())
+    }
+
     pub fn account_toggle_freeze(ctx: Context<AccountToggleFreeze>, freeze: bool) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::account_toggle_freeze(ctx, freeze)?;
@@ -446,6 +530,12 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn account_claim_referral_fees(ctx: Context<AccountClaimReferralFees>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::account_claim_referral_fees(ctx)?;
+        Ok(())
+    }
+
     // todo:
     // ckamm: generally, using an I80F48 arg will make it harder to call
     // because generic anchor clients won't know how to deal with it
@@ -551,6 +641,16 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn margin_trade<'key, 'accounts, 'remaining, 'info>(
+        ctx: Context<'key, 'accounts, 'remaining, 'info, MarginTrade<'info>>,
+        num_health_accounts: u8,
+        cpi_data: Vec<u8>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::margin_trade(ctx, num_health_accounts, cpi_data)?;
+        Ok(())
+    }
+
     pub fn health_region_begin<'key, 'accounts, 'remaining, 'info>(
         ctx: Context<'key, 'accounts, 'remaining, 'info, HealthRegionBegin<'info>>,
     ) -> Result<()> {
@@ -588,6 +688,7 @@ pub mod mango_v4 {
         force_close_opt: Option<bool>,
         name_opt: Option<String>,
         oracle_price_band_opt: Option<f32>,
+        crossing_price_band_opt: Option<f32>,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::serum3_edit_market(
@@ -596,6 +697,7 @@ pub mod mango_v4 {
             force_close_opt,
             name_opt,
             oracle_price_band_opt,
+            crossing_price_band_opt,
         )?;
         Ok(())
     }
@@ -680,6 +782,18 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    /// Place several orders on one side of the book with a single health check,
+    /// requires the receiver_bank in the health account list to be writable
+    pub fn serum3_place_orders(
+        ctx: Context<Serum3PlaceOrder>,
+        side: Serum3Side,
+        orders: Vec<Serum3PlaceOrderArgs>,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::serum3_place_orders(ctx, side, orders)?;
+        Ok(())
+    }
+
     pub fn serum3_cancel_order(
         ctx: Context<Serum3CancelOrder>,
         side: Serum3Side,
@@ -912,6 +1026,11 @@ pub mod mango_v4 {
         name_opt: Option<String>,
         force_close_opt: Option<bool>,
         platform_liquidation_fee_opt: Option<f32>,
+        keeper_reward_per_event_opt: Option<f32>,
+        keeper_reward_funding_per_hour_opt: Option<f32>,
+        oracle_price_band_opt: Option<f32>,
+        funding_period_seconds_opt: Option<u32>,
+        dust_threshold_opt: Option<f32>,
     ) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::perp_edit_market(
@@ -947,6 +1066,11 @@ pub mod mango_v4 {
             name_opt,
             force_close_opt,
             platform_liquidation_fee_opt,
+            keeper_reward_per_event_opt,
+            keeper_reward_funding_per_hour_opt,
+            oracle_price_band_opt,
+            funding_period_seconds_opt,
+            dust_threshold_opt,
         )?;
         Ok(())
     }
@@ -1237,6 +1361,98 @@ pub mod mango_v4 {
         Ok(None)
     }
 
+    /// Cancel the order with cancel_client_order_id and place a new order, like
+    /// perp_place_order_v2, with a single health check.
+    #[allow(clippy::too_many_arguments)]
+    pub fn perp_modify_order(
+        ctx: Context<PerpModifyOrder>,
+        cancel_client_order_id: u64,
+        side: Side,
+        price_lots: i64,
+        max_base_lots: i64,
+        max_quote_lots: i64,
+        client_order_id: u64,
+        order_type: PlaceOrderType,
+        self_trade_behavior: SelfTradeBehavior,
+        reduce_only: bool,
+        expiry_timestamp: u64,
+        limit: u8,
+    ) -> Result<Option<u128>> {
+        require_gte!(price_lots, 0);
+
+        use crate::state::{Order, OrderParams};
+        // Unlike when placing an order, skipping would leave the old order on the book
+        let time_in_force = Order::tif_from_expiry(expiry_timestamp)
+            .ok_or_else(|| error_msg!("order is already expired"))?;
+        let order = Order {
+            side,
+            max_base_lots,
+            max_quote_lots,
+            client_order_id,
+            reduce_only,
+            time_in_force,
+            self_trade_behavior,
+            params: match order_type {
+                PlaceOrderType::Market => OrderParams::Market {},
+                PlaceOrderType::ImmediateOrCancel => OrderParams::ImmediateOrCancel { price_lots },
+                _ => OrderParams::Fixed {
+                    price_lots,
+                    order_type: order_type.to_post_order_type()?,
+                },
+            },
+        };
+        #[cfg(feature = "enable-gpl")]
+        return instructions::perp_modify_order(ctx, cancel_client_order_id, order, limit);
+
+        #[cfg(not(feature = "enable-gpl"))]
+        Ok(None)
+    }
+
+    /// Place several orders, like perp_place_order_v2, with a single health check.
+    ///
+    /// Already expired orders are skipped.
+    pub fn perp_place_orders(
+        ctx: Context<PerpPlaceOrders>,
+        orders: Vec<PerpPlaceOrderArgs>,
+        limit: u8,
+    ) -> Result<()> {
+        use crate::state::{Order, OrderParams};
+        let mut perp_orders = Vec::with_capacity(orders.len());
+        for args in orders {
+            require_gte!(args.price_lots, 0);
+            let time_in_force = match Order::tif_from_expiry(args.expiry_timestamp) {
+                Some(t) => t,
+                None => {
+                    msg!("Order {} is already expired", args.client_order_id);
+                    continue;
+                }
+            };
+            let price_lots = args.price_lots;
+            perp_orders.push(Order {
+                side: args.side,
+                max_base_lots: args.max_base_lots,
+                max_quote_lots: args.max_quote_lots,
+                client_order_id: args.client_order_id,
+                reduce_only: args.reduce_only,
+                time_in_force,
+                self_trade_behavior: args.self_trade_behavior,
+                params: match args.order_type {
+                    PlaceOrderType::Market => OrderParams::Market {},
+                    PlaceOrderType::ImmediateOrCancel => {
+                        OrderParams::ImmediateOrCancel { price_lots }
+                    }
+                    _ => OrderParams::Fixed {
+                        price_lots,
+                        order_type: args.order_type.to_post_order_type()?,
+                    },
+                },
+            });
+        }
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_place_orders(ctx, perp_orders, limit)?;
+        Ok(())
+    }
+
     pub fn perp_cancel_order(ctx: Context<PerpCancelOrder>, order_id: u128) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::perp_cancel_order(ctx, order_id)?;
@@ -1286,6 +1502,15 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn perp_settle_pnl_batch<'key, 'accounts, 'remaining, 'info>(
+        ctx: Context<'key, 'accounts, 'remaining, 'info, PerpSettlePnlBatch<'info>>,
+        num_pairs: u8,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_settle_pnl_batch(ctx, num_pairs)?;
+        Ok(())
+    }
+
     pub fn perp_force_close_position(ctx: Context<PerpForceClosePosition>) -> Result<()> {
         #[cfg(feature = "enable-gpl")]
         instructions::perp_force_close_position(ctx)?;
@@ -1308,6 +1533,28 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn perp_liq_combined(
+        ctx: Context<PerpLiqCombined>,
+        limit: u8,
+        max_base_transfer: i64,
+        max_pnl_transfer: u64,
+        asset_token_index: TokenIndex,
+        liab_token_index: TokenIndex,
+        max_liab_transfer: I80F48,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_liq_combined(
+            ctx,
+            limit,
+            max_base_transfer,
+            max_pnl_transfer,
+            asset_token_index,
+            liab_token_index,
+            max_liab_transfer,
+        )?;
+        Ok(())
+    }
+
     pub fn perp_liq_force_cancel_orders(
         ctx: Context<PerpLiqForceCancelOrders>,
         limit: u8,
@@ -1335,6 +1582,79 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn perp_liq_auto_deleverage(
+        ctx: Context<PerpLiqAutoDeleverage>,
+        max_base_transfer: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_liq_auto_deleverage(ctx, max_base_transfer)?;
+        Ok(())
+    }
+
+    pub fn perp_update_adl_candidate(ctx: Context<PerpUpdateAdlCandidate>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_update_adl_candidate(ctx)?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn perp_trigger_order_create(
+        ctx: Context<PerpTriggerOrderCreate>,
+        order_id: u64,
+        side: Side,
+        trigger_condition: PerpTriggerCondition,
+
+        // Oracle price at which the order triggers, in quote lots per base lot
+        trigger_price_lots: i64,
+
+        // The triggered order is ImmediateOrCancel with this worst price, in lots
+        price_lots: i64,
+
+        max_base_lots: i64,
+        max_quote_lots: i64,
+        reduce_only: bool,
+
+        // The order can't be executed after this time, u64::MAX for never
+        expiry_timestamp: u64,
+
+        // Native settle tokens paid to the executor
+        executor_incentive: u64,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_trigger_order_create(
+            ctx,
+            order_id,
+            side,
+            trigger_condition,
+            trigger_price_lots,
+            price_lots,
+            max_base_lots,
+            max_quote_lots,
+            reduce_only,
+            expiry_timestamp,
+            executor_incentive,
+        )?;
+        Ok(())
+    }
+
+    pub fn perp_trigger_order_cancel(ctx: Context<PerpTriggerOrderCancel>) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_trigger_order_cancel(ctx)?;
+        Ok(())
+    }
+
+    /// Permissionless: places the stored order once the oracle price reached the trigger price
+    pub fn perp_trigger_order_execute(
+        ctx: Context<PerpTriggerOrderExecute>,
+
+        // Maximum number of orders from the book to fill
+        limit: u8,
+    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::perp_trigger_order_execute(ctx, limit)?;
+        Ok(())
+    }
+
     pub fn token_conditional_swap_create(
         ctx: Context<TokenConditionalSwapCreate>,
         max_buy: u64,
@@ -1487,21 +1807,6 @@ pub mod mango_v4 {
         );
         require_gte!(duration_seconds, 1);
 
-        let buy_token_price = ctx.accounts.buy_bank.load()?.stable_price().to_num::<f64>();
-        let sell_token_price = ctx
-            .accounts
-            .sell_bank
-            .load()?
-            .stable_price()
-            .to_num::<f64>();
-        let max_volume =
-            (buy_token_price * max_buy as f64).min(sell_token_price * max_sell as f64) as u64;
-        require_gte!(
-            max_volume,
-            TCS_START_INCENTIVE * 10,
-            MangoError::TokenConditionalSwapTooSmallForStartIncentive
-        );
-
         let tcs = TokenConditionalSwap {
             id: u64::MAX, // set inside
             max_buy,
@@ -1611,6 +1916,12 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    pub fn alt_create(ctx: Context<AltCreate>, index: u8, recent_slot: u64) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::alt_create(ctx, index, recent_slot)?;
+        Ok(())
+    }
+
     pub fn alt_extend(
         ctx: Context<AltExtend>,
         index: u8,
@@ -1628,6 +1939,20 @@ pub mod mango_v4 {
         Ok(())
     }
 
+    /// Returns the account's health, equity and positions as paginated return data
+    pub fn compute_account_data_page(ctx: Context<ComputeAccountData>, page: u8) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::compute_account_data_page(ctx, page)?;
+        Ok(())
+    }
+
+    /// Returns each position's contribution to init and maint health as paginated return data
+    pub fn compute_health_breakdown(ctx: Context<ComputeHealthBreakdown>, page: u8) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::compute_health_breakdown(ctx, page)?;
+        Ok(())
+    }
+
     ///
     /// OpenbookV2
     ///
@@ -1637,6 +1962,8 @@ pub mod mango_v4 {
         market_index: OpenbookV2MarketIndex,
         name: String,
     ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::openbook_v2_register_market(ctx, market_index, name)?;
         Ok(())
     }
 
@@ -1645,79 +1972,14 @@ pub mod mango_v4 {
         reduce_only_opt: Option<bool>,
         force_close_opt: Option<bool>,
     ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::openbook_v2_edit_market(ctx, reduce_only_opt, force_close_opt)?;
         Ok(())
     }
 
     pub fn openbook_v2_deregister_market(ctx: Context<OpenbookV2DeregisterMarket>) -> Result<()> {
-        Ok(())
-    }
-
-    pub fn openbook_v2_create_open_orders(
-        ctx: Context<OpenbookV2CreateOpenOrders>,
-        account_num: u32,
-    ) -> Result<()> {
-        Ok(())
-    }
-
-    pub fn openbook_v2_close_open_orders(ctx: Context<OpenbookV2CloseOpenOrders>) -> Result<()> {
-        Ok(())
-    }
-
-    #[allow(clippy::too_many_arguments)]
-    pub fn openbook_v2_place_order(
-        ctx: Context<OpenbookV2PlaceOrder>,
-        side: u8, // openbook_v2::state::Side
-        limit_price: u64,
-        max_base_qty: u64,
-        max_native_quote_qty_including_fees: u64,
-        self_trade_behavior: u8, // openbook_v2::state::SelfTradeBehavior
-        order_type: u8,          // openbook_v2::state::PlaceOrderType
-        client_order_id: u64,
-        limit: u16,
-    ) -> Result<()> {
-        Ok(())
-    }
-
-    #[allow(clippy::too_many_arguments)]
-    pub fn openbook_v2_place_taker_order(
-        ctx: Context<OpenbookV2PlaceTakeOrder>,
-        side: u8, // openbook_v2::state::Side
-        limit_price: u64,
-        max_base_qty: u64,
-        max_native_quote_qty_including_fees: u64,
-        self_trade_behavior: u8, // openbook_v2::state::SelfTradeBehavior
-        client_order_id: u64,
-        limit: u16,
-    ) -> Result<()> {
-        Ok(())
-    }
-
-    pub fn openbook_v2_cancel_order(
-        ctx: Context<OpenbookV2CancelOrder>,
-        side: u8, // openbook_v2::state::Side
-        order_id: u128,
-    ) -> Result<()> {
-        Ok(())
-    }
-
-    pub fn openbook_v2_settle_funds(
-        ctx: Context<OpenbookV2SettleFunds>,
-        fees_to_dao: bool,
-    ) -> Result<()> {
-        Ok(())
-    }
-
-    pub fn openbook_v2_liq_force_cancel_orders(
-        ctx: Context<OpenbookV2LiqForceCancelOrders>,
-        limit: u8,
-    ) -> Result<()> {
-        Ok(())
-    }
-
-    pub fn openbook_v2_cancel_all_orders(
-        ctx: Context<OpenbookV2CancelOrder>,
-        limit: u8,
-    ) -> Result<()> {
+        #[cfg(feature = "enable-gpl")]
+        instructions::openbook_v2_deregister_market(ctx)?;
         Ok(())
     }
 
@@ -40,6 +40,14 @@ pub fn emit_perp_balances(
     });
 }
 
+#[event]
+pub struct HealthLog {
+    pub mango_group: Pubkey,
+    pub mango_account: Pubkey,
+    pub init_health: i128,  // I80F48
+    pub maint_health: i128, // I80F48
+}
+
 #[event]
 pub struct PerpBalanceLog {
     pub mango_group: Pubkey,
@@ -258,6 +266,38 @@ pub struct FillLogV3 {
     pub taker_closed_pnl: f64, // settle-token-native units
 }
 
+#[event]
+pub struct FillLogV4 {
+    pub mango_group: Pubkey,
+    pub market_index: u16,
+    pub taker_side: u8, // side from the taker's POV
+    pub maker_slot: u8,
+    pub maker_out: bool, // true if maker order quantity == 0
+    pub timestamp: u64,
+    pub seq_num: u64, // note: usize same as u64
+
+    pub maker: Pubkey,
+    pub maker_client_order_id: u64,
+    pub maker_fee: f32,
+
+    // Timestamp of when the maker order was placed; copied over from the LeafNode
+    pub maker_timestamp: u64,
+
+    pub taker: Pubkey,
+    pub taker_client_order_id: u64,
+    pub taker_fee: f32,
+
+    pub price: i64,
+    pub quantity: i64,         // number of base lots
+    pub maker_closed_pnl: f64, // settle-token-native units
+    pub taker_closed_pnl: f64, // settle-token-native units
+
+    pub maker_fee_paid: f64, // settle-token-native units, negative for rebates
+    pub taker_fee_paid: f64, // settle-token-native units, charged when the taker order matched
+    pub maker_base_position: i64, // base lots after the fill
+    pub taker_base_position: i64, // base lots after the fill
+}
+
 #[event]
 pub struct PerpUpdateFundingLog {
     pub mango_group: Pubkey,
@@ -435,6 +475,27 @@ pub struct TokenLiqBankruptcyLog {
     pub ending_liab_deposit_index: i128,
 }
 
+#[event]
+pub struct TokenLiqBankruptcyLogV2 {
+    pub mango_group: Pubkey,
+    pub liqee: Pubkey,
+    pub liqor: Pubkey,
+    pub liab_token_index: u16,
+    pub initial_liab_native: i128, // I80F48
+    pub liab_price: i128,          // I80F48
+    /// liab native tokens taken over by the liqor in exchange for insurance tokens
+    pub liab_transfer: i128, // I80F48
+    pub insurance_token_index: u16,
+    pub insurance_price: i128,    // I80F48
+    pub insurance_transfer: i128, // I80F48
+    /// part of insurance_transfer that the liqor received on top of the liab value
+    pub liquidation_fee: i128, // I80F48
+    pub insurance_fund_exhausted: bool,
+    pub socialized_loss: i128,             // I80F48
+    pub starting_liab_deposit_index: i128, // I80F48
+    pub ending_liab_deposit_index: i128,   // I80F48
+}
+
 #[event]
 pub struct DeactivateTokenPositionLog {
     pub mango_group: Pubkey,
@@ -499,6 +560,17 @@ pub struct Serum3RegisterMarketLog {
     pub serum_program_external: Pubkey,
 }
 
+#[event]
+pub struct OpenbookV2RegisterMarketLog {
+    pub mango_group: Pubkey,
+    pub openbook_market: Pubkey,
+    pub market_index: u16,
+    pub base_token_index: u16,
+    pub quote_token_index: u16,
+    pub openbook_program: Pubkey,
+    pub openbook_market_external: Pubkey,
+}
+
 #[event]
 pub struct PerpLiqBaseOrPositivePnlLog {
     pub mango_group: Pubkey,
@@ -611,6 +683,18 @@ pub struct PerpForceClosePositionLog {
     pub price: i128,
 }
 
+#[event]
+pub struct PerpLiqAutoDeleverageLog {
+    pub mango_group: Pubkey,
+    pub perp_market_index: u16,
+    pub liqee: Pubkey,
+    pub counterparty: Pubkey,
+    pub base_transfer_liqee: i64,
+    pub quote_transfer_liqee: i128, // I80F48
+    pub bankruptcy_price: i128,     // I80F48
+    pub oracle_price: i128,         // I80F48
+}
+
 #[event]
 pub struct TokenForceCloseBorrowsWithTokenLog {
     pub mango_group: Pubkey,
@@ -779,3 +863,123 @@ pub struct TokenConditionalSwapStartLog {
     pub incentive_token_index: u16,
     pub incentive_amount: u64,
 }
+
+#[event]
+pub struct GroupInsuranceFundLog {
+    pub mango_group: Pubkey,
+    pub insurance_vault: Pubkey,
+    pub amount_change: i64, // positive for deposits, negative for withdrawals
+    pub vault_balance: u64, // vault balance after the change
+    /// Source of deposits, destination of withdrawals
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct TokenVaultInvariantViolationLog {
+    pub mango_group: Pubkey,
+    pub bank: Pubkey,
+    pub token_index: u16,
+    pub vault_balance: u64,
+    pub native_deposits: i128, // I80F48
+    pub native_borrows: i128,  // I80F48
+    pub collected_fees: i128,  // I80F48
+    pub fees_withdrawn: u64,
+    pub shortfall: i128, // I80F48
+    pub halted: bool,
+}
+
+#[event]
+pub struct PerpTriggerOrderCreateLog {
+    pub mango_group: Pubkey,
+    pub mango_account: Pubkey,
+    pub trigger_order: Pubkey,
+    pub order_id: u64,
+    pub perp_market_index: u16,
+    pub side: u8,
+    pub trigger_condition: u8,
+    pub trigger_price_lots: i64,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots: i64,
+    pub reduce_only: bool,
+    pub expiry_timestamp: u64,
+    pub executor_incentive: u64,
+}
+
+#[event]
+pub struct PerpTriggerOrderCancelLog {
+    pub mango_group: Pubkey,
+    pub mango_account: Pubkey,
+    pub trigger_order: Pubkey,
+    pub order_id: u64,
+}
+
+#[event]
+pub struct PerpTriggerOrderExecuteLog {
+    pub mango_group: Pubkey,
+    pub mango_account: Pubkey,
+    pub trigger_order: Pubkey,
+    pub order_id: u64,
+    pub executor: Pubkey,
+    pub oracle_price_lots: i64,
+    pub executor_incentive: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MarginTradeTokenDetail {
+    pub token_index: u16,
+
+    /// The change of the vault balance caused by the swap
+    ///
+    /// Does NOT include the loan_origination_fee or swap_fee, so the true
+    /// change of the token position is `change_amount - loan_origination_fee - swap_fee`.
+    pub change_amount: i128,
+
+    /// The amount that was a loan, depends on the user's deposits
+    pub loan: i128,
+    pub loan_origination_fee: i128,
+
+    /// Swap fee paid on tokens that left the vault
+    pub swap_fee: i128,
+
+    pub deposit_index: i128,
+    pub borrow_index: i128,
+    pub price: i128,
+}
+
+#[event]
+pub struct MarginTradeLog {
+    pub mango_group: Pubkey,
+    pub mango_account: Pubkey,
+    pub swap_program: Pubkey,
+    pub token_details: Vec<MarginTradeTokenDetail>,
+}
+
+#[event]
+pub struct ReferralFeesClaimLog {
+    pub mango_group: Pubkey,
+    pub mango_account: Pubkey,
+    pub referrer: Pubkey,
+    pub token_index: u16,
+    /// Set if the payout came out of a perp market's fees
+    pub perp_market_index: Option<u16>,
+    /// Native fees of the account's referral_fees_accrued for the bank or perp market
+    /// that were used up
+    pub fees_used: u64,
+    pub amount: i128, // I80F48
+}
+
+#[event]
+pub struct OracleRejectedLog {
+    pub oracle: Pubkey,
+    pub oracle_type: OracleType,
+    pub price: i128,     // I80F48
+    pub deviation: i128, // I80F48
+    pub last_update_slot: u64,
+    /// Slots since the last update, zero if staleness wasn't checked
+    pub age_slots: u64,
+    /// Rejected for being stale, otherwise for its confidence
+    pub stale: bool,
+    /// Whether a fallback oracle is tried instead
+    pub fallback_available: bool,
+}
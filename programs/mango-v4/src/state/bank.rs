@@ -2,7 +2,7 @@ use super::{OracleAccountInfos, OracleConfig, TokenIndex, TokenPosition};
 use crate::accounts_zerocopy::KeyedAccountReader;
 use crate::error::*;
 use crate::i80f48::ClampToInt;
-use crate::state::{oracle, StablePriceModel};
+use crate::state::{load_whirlpool_state, oracle, OracleState, OracleType, StablePriceModel};
 use crate::util;
 
 use anchor_lang::prelude::*;
@@ -217,8 +217,80 @@ pub struct Bank {
     /// See also collected_fees_native and fees_withdrawn.
     pub collected_liquidation_fees: I80F48,
 
+    /// Reward (in native tokens) paid out of collected fees to the keeper of
+    /// token_update_index_and_rate, pro-rated by the time since the last update
+    /// and capped at one hour.
+    pub keeper_reward_per_hour: f32,
+
+    /// Max relative deviation of the fallback oracle price from the stable price
+    /// for the fallback to be used. Zero means no check.
+    pub fallback_oracle_max_stable_price_deviation: f32,
+
+    /// Min in-range liquidity of a CLMM pool fallback oracle for it to be used.
+    /// Zero means no check.
+    pub fallback_oracle_min_liquidity: u64,
+
+    /// If set, a vault invariant violation found by token_check_vault_invariant
+    /// puts the bank into reduce-only mode.
+    pub halt_on_vault_invariant_violation: u8,
+
+    /// Deposits count as collateral, but the token can never be borrowed.
+    ///
+    /// Unlike reduce_only this is a listing property and not a temporary state.
+    pub collateral_only: u8,
+
+    #[derivative(Debug = "ignore")]
+    pub padding2: [u8; 6],
+
+    /// End timestamp in seconds of a shift of the interest rate curve parameters
+    /// util0, rate0, util1, rate1, max_rate towards the interest_rate_shift_ targets.
+    ///
+    /// The parameters move linearly towards the targets in each token_update_index_and_rate.
+    /// Zero means no shift is configured.
+    pub interest_rate_shift_end: u64,
+    #[derivative(Debug = "ignore")]
+    pub padding3: [u8; 8],
+    pub interest_rate_shift_util0: I80F48,
+    pub interest_rate_shift_rate0: I80F48,
+    pub interest_rate_shift_util1: I80F48,
+    pub interest_rate_shift_rate1: I80F48,
+    pub interest_rate_shift_max_rate: I80F48,
+
+    /// Time weighted (exponential) moving average of the oracle price, updated
+    /// in token_update_index_and_rate.
+    ///
+    /// Liquidations and token conditional swap triggers use it to make prices
+    /// harder to manipulate, see oracle_twap_window_seconds.
+    pub oracle_twap: f64,
+    pub oracle_twap_last_update: u64,
+    /// Averaging window of oracle_twap. Zero disables the twap.
+    pub oracle_twap_window_seconds: u32,
+
+    #[derivative(Debug = "ignore")]
+    pub padding4: [u8; 4],
+
+    /// Loan origination fees (native) in collected_fees_native that were paid by accounts
+    /// with a referrer and that the referrers haven't claimed their share of yet.
+    pub referral_fees_unclaimed: u64,
+
+    /// Keeper rewards (native) paid out of collected fees, see keeper_reward_per_hour.
+    ///
+    /// Kept apart from fees_withdrawn, which only tracks fees withdrawn from the vault.
+    pub keeper_rewards_paid: u64,
+
+    /// Referral fees (native) paid out of collected fees, see account_claim_referral_fees.
+    ///
+    /// Kept apart from fees_withdrawn, the payouts stay in the vault as referrer deposits.
+    pub referral_fees_paid: u64,
+
+    /// Layout version of this account, see Bank::LAYOUT_VERSION and token_migrate.
+    pub layout_version: u8,
+
+    #[derivative(Debug = "ignore")]
+    pub padding5: [u8; 7],
+
     #[derivative(Debug = "ignore")]
-    pub reserved: [u8; 1920],
+    pub reserved: [u8; 1744],
 }
 const_assert_eq!(
     size_of::<Bank>(),
@@ -256,7 +328,25 @@ const_assert_eq!(
         + 32
         + 8
         + 16 * 3
-        + 1920
+        + 4
+        + 4
+        + 8
+        + 1
+        + 1
+        + 6
+        + 8
+        + 8
+        + 16 * 5
+        + 8
+        + 8
+        + 4
+        + 4
+        + 8
+        + 8
+        + 8
+        + 1
+        + 7
+        + 1744
 );
 const_assert_eq!(size_of::<Bank>(), 3064);
 const_assert_eq!(size_of::<Bank>() % 8, 0);
@@ -287,6 +377,35 @@ impl TransferResult {
 }
 
 impl Bank {
+    /// Latest layout version, see layout_version.
+    ///
+    /// Bump it when a change needs existing banks to be converted, and add the
+    /// conversion to migrate().
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    /// Upgrades a bank with an older layout_version in place.
+    ///
+    /// Returns whether anything changed.
+    pub fn migrate(&mut self) -> Result<bool> {
+        require_msg!(
+            self.layout_version <= Self::LAYOUT_VERSION,
+            "bank layout version {} is newer than the supported {}",
+            self.layout_version,
+            Self::LAYOUT_VERSION
+        );
+        let old_version = self.layout_version;
+        while self.layout_version < Self::LAYOUT_VERSION {
+            match self.layout_version {
+                // v1 only introduced layout_version, the fields carved from
+                // reserved up to then default to zero
+                0 => {}
+                _ => unreachable!(),
+            }
+            self.layout_version += 1;
+        }
+        Ok(self.layout_version != old_version)
+    }
+
     pub fn from_existing_bank(
         existing_bank: &Bank,
         vault: Pubkey,
@@ -363,10 +482,73 @@ impl Bank {
             deposit_limit: existing_bank.deposit_limit,
             zero_util_rate: existing_bank.zero_util_rate,
             platform_liquidation_fee: existing_bank.platform_liquidation_fee,
-            reserved: [0; 1920],
+            keeper_reward_per_hour: existing_bank.keeper_reward_per_hour,
+            fallback_oracle_max_stable_price_deviation: existing_bank
+                .fallback_oracle_max_stable_price_deviation,
+            fallback_oracle_min_liquidity: existing_bank.fallback_oracle_min_liquidity,
+            halt_on_vault_invariant_violation: existing_bank.halt_on_vault_invariant_violation,
+            collateral_only: existing_bank.collateral_only,
+            padding2: [0; 6],
+            interest_rate_shift_end: existing_bank.interest_rate_shift_end,
+            padding3: [0; 8],
+            interest_rate_shift_util0: existing_bank.interest_rate_shift_util0,
+            interest_rate_shift_rate0: existing_bank.interest_rate_shift_rate0,
+            interest_rate_shift_util1: existing_bank.interest_rate_shift_util1,
+            interest_rate_shift_rate1: existing_bank.interest_rate_shift_rate1,
+            interest_rate_shift_max_rate: existing_bank.interest_rate_shift_max_rate,
+            oracle_twap: existing_bank.oracle_twap,
+            oracle_twap_last_update: existing_bank.oracle_twap_last_update,
+            oracle_twap_window_seconds: existing_bank.oracle_twap_window_seconds,
+            padding4: [0; 4],
+            referral_fees_unclaimed: 0,
+            keeper_rewards_paid: 0,
+            referral_fees_paid: 0,
+            layout_version: Self::LAYOUT_VERSION,
+            padding5: [0; 7],
+            reserved: [0; 1744],
         }
     }
 
+    /// Collected fees that weren't withdrawn or paid out as keeper rewards or
+    /// referral fees.
+    pub fn fees_available(&self) -> I80F48 {
+        self.collected_fees_native
+            - I80F48::from(self.fees_withdrawn)
+            - I80F48::from(self.keeper_rewards_paid)
+            - I80F48::from(self.referral_fees_paid)
+    }
+
+    /// Takes a keeper reward out of the fees that are still available.
+    ///
+    /// Returns the native amount that must be credited to the keeper.
+    pub fn take_keeper_reward(&mut self, reward: I80F48) -> u64 {
+        let available = self
+            .fees_available()
+            .max(I80F48::ZERO)
+            .floor()
+            .to_num::<u64>();
+        let reward = reward
+            .max(I80F48::ZERO)
+            .floor()
+            .to_num::<u64>()
+            .min(available);
+        self.keeper_rewards_paid += reward;
+        reward
+    }
+
+    /// Native tokens that should be in the vault: deposits minus borrows plus
+    /// the fees that are still available.
+    ///
+    /// The vault may hold more than this (for example perp fees are taken out of
+    /// deposits), but never less.
+    pub fn expected_vault_balance(&self) -> I80F48 {
+        self.native_deposits() - self.native_borrows() + self.fees_available()
+    }
+
+    pub fn halts_on_vault_invariant_violation(&self) -> bool {
+        self.halt_on_vault_invariant_violation == 1
+    }
+
     pub fn verify(&self) -> Result<()> {
         require_gte!(self.oracle_config.conf_filter, 0.0);
         require_gte!(self.util0, I80F48::ZERO);
@@ -386,6 +568,12 @@ impl Bank {
         require_gt!(self.borrow_weight_scale_start_quote, 0.0);
         require_gt!(self.deposit_weight_scale_start_quote, 0.0);
         require_gte!(2, self.reduce_only);
+        require_gte!(1, self.collateral_only);
+        require_gte!(self.interest_rate_shift_util0, I80F48::ZERO);
+        require_gte!(self.interest_rate_shift_rate0, I80F48::ZERO);
+        require_gte!(self.interest_rate_shift_util1, I80F48::ZERO);
+        require_gte!(self.interest_rate_shift_rate1, I80F48::ZERO);
+        require_gte!(self.interest_rate_shift_max_rate, I80F48::ZERO);
         require_gte!(self.token_conditional_swap_taker_fee_rate, 0.0);
         require_gte!(self.token_conditional_swap_maker_fee_rate, 0.0);
         require_gte!(self.flash_loan_swap_fee_rate, 0.0);
@@ -396,6 +584,8 @@ impl Bank {
         require_gte!(self.maint_weight_shift_liab_target, 0.0);
         require_gte!(self.zero_util_rate, I80F48::ZERO);
         require_gte!(self.platform_liquidation_fee, 0.0);
+        require_gte!(self.keeper_reward_per_hour, 0.0);
+        require_gte!(self.fallback_oracle_max_stable_price_deviation, 0.0);
         Ok(())
     }
 
@@ -410,7 +600,11 @@ impl Bank {
     }
 
     pub fn are_borrows_reduce_only(&self) -> bool {
-        self.reduce_only == 1 || self.reduce_only == 2
+        self.reduce_only == 1 || self.reduce_only == 2 || self.is_collateral_only()
+    }
+
+    pub fn is_collateral_only(&self) -> bool {
+        self.collateral_only == 1
     }
 
     pub fn is_force_close(&self) -> bool {
@@ -1116,6 +1310,41 @@ impl Bank {
             / new_avg_time
     }
 
+    /// Moves the interest rate curve parameters towards the targets of a configured
+    /// shift, linearly in time, such that they reach the targets at interest_rate_shift_end.
+    ///
+    /// last_ts is the time of the previous update.
+    pub fn update_interest_rate_shift(&mut self, last_ts: u64, now_ts: u64) {
+        let end = self.interest_rate_shift_end;
+        if end == 0 {
+            return;
+        }
+
+        let fraction = if now_ts >= end || last_ts >= end {
+            I80F48::ONE
+        } else {
+            I80F48::from(now_ts.saturating_sub(last_ts)) / I80F48::from(end - last_ts)
+        };
+        self.util0 += fraction * (self.interest_rate_shift_util0 - self.util0);
+        self.rate0 += fraction * (self.interest_rate_shift_rate0 - self.rate0);
+        self.util1 += fraction * (self.interest_rate_shift_util1 - self.util1);
+        self.rate1 += fraction * (self.interest_rate_shift_rate1 - self.rate1);
+        self.max_rate += fraction * (self.interest_rate_shift_max_rate - self.max_rate);
+
+        if now_ts >= end {
+            self.clear_interest_rate_shift();
+        }
+    }
+
+    pub fn clear_interest_rate_shift(&mut self) {
+        self.interest_rate_shift_end = 0;
+        self.interest_rate_shift_util0 = I80F48::ZERO;
+        self.interest_rate_shift_rate0 = I80F48::ZERO;
+        self.interest_rate_shift_util1 = I80F48::ZERO;
+        self.interest_rate_shift_rate1 = I80F48::ZERO;
+        self.interest_rate_shift_max_rate = I80F48::ZERO;
+    }
+
     // computes new optimal rates and max rate
     pub fn update_interest_rate_scaling(&mut self) {
         // Interest increases above target_util, decreases below
@@ -1147,6 +1376,12 @@ impl Bank {
         let primary_state = oracle::oracle_state_unchecked(oracle_acc_infos, self.mint_decimals)?;
         let primary_ok =
             primary_state.check_confidence_and_maybe_staleness(&self.oracle_config, staleness_slot);
+        primary_state.log_if_rejected(
+            self.oracle,
+            &primary_ok,
+            staleness_slot,
+            oracle_acc_infos.fallback_opt.is_some(),
+        );
         if primary_ok.is_oracle_error() && oracle_acc_infos.fallback_opt.is_some() {
             let fallback_oracle_acc = oracle_acc_infos.fallback_opt.unwrap();
             require_keys_eq!(self.fallback_oracle, *fallback_oracle_acc.key());
@@ -1154,6 +1389,12 @@ impl Bank {
                 oracle::fallback_oracle_state_unchecked(&oracle_acc_infos, self.mint_decimals)?;
             let fallback_ok = fallback_state
                 .check_confidence_and_maybe_staleness(&self.oracle_config, staleness_slot);
+            fallback_state.log_if_rejected(
+                self.fallback_oracle,
+                &fallback_ok,
+                staleness_slot,
+                false,
+            );
             fallback_ok.with_context(|| {
                 format!(
                     "{} {}",
@@ -1171,6 +1412,7 @@ impl Bank {
                     )
                 )
             })?;
+            self.check_fallback_oracle_guards(fallback_oracle_acc, &fallback_state)?;
             Ok(fallback_state.price)
         } else {
             primary_ok.with_context(|| {
@@ -1185,10 +1427,82 @@ impl Bank {
         }
     }
 
+    /// Guards against using a manipulated fallback price, in particular one derived
+    /// from a thin CLMM pool.
+    ///
+    /// Failures are reported as oracle confidence errors, like an unusable primary oracle.
+    fn check_fallback_oracle_guards(
+        &self,
+        fallback_oracle_acc: &impl KeyedAccountReader,
+        fallback_state: &OracleState,
+    ) -> Result<()> {
+        if fallback_state.oracle_type == OracleType::OrcaCLMM
+            && self.fallback_oracle_min_liquidity > 0
+        {
+            let whirlpool = load_whirlpool_state(fallback_oracle_acc)?;
+            require_msg_typed!(
+                whirlpool.liquidity >= self.fallback_oracle_min_liquidity as u128,
+                MangoError::OracleConfidence,
+                "fallback oracle for {}: pool liquidity {} is below the minimum {}",
+                self.name(),
+                whirlpool.liquidity,
+                self.fallback_oracle_min_liquidity
+            );
+        }
+
+        let stable_price = self.stable_price();
+        if self.fallback_oracle_max_stable_price_deviation > 0.0 && stable_price.is_positive() {
+            let deviation = (fallback_state.price - stable_price).abs() / stable_price;
+            require_msg_typed!(
+                deviation <= I80F48::from_num(self.fallback_oracle_max_stable_price_deviation),
+                MangoError::OracleConfidence,
+                "fallback oracle for {}: price {} deviates {} from stable price {}",
+                self.name(),
+                fallback_state.price,
+                deviation,
+                stable_price
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn stable_price(&self) -> I80F48 {
         I80F48::from_num(self.stable_price_model.stable_price)
     }
 
+    pub fn update_oracle_twap(&mut self, now_ts: u64, oracle_price: f64) {
+        if self.oracle_twap_window_seconds == 0 {
+            return;
+        }
+        if self.oracle_twap <= 0.0 {
+            self.oracle_twap = oracle_price;
+        } else {
+            let dt = now_ts.saturating_sub(self.oracle_twap_last_update) as f64;
+            let weight = (dt / self.oracle_twap_window_seconds as f64).min(1.0);
+            self.oracle_twap += weight * (oracle_price - self.oracle_twap);
+        }
+        self.oracle_twap_last_update = now_ts;
+    }
+
+    /// The oracle twap, if it is enabled and initialized
+    pub fn oracle_twap(&self) -> Option<I80F48> {
+        (self.oracle_twap_window_seconds > 0 && self.oracle_twap > 0.0)
+            .then(|| I80F48::from_num(self.oracle_twap))
+    }
+
+    /// Price for valuing an asset that's given up: the max of oracle and twap
+    pub fn twap_asset_price(&self, oracle_price: I80F48) -> I80F48 {
+        self.oracle_twap()
+            .map_or(oracle_price, |twap| oracle_price.max(twap))
+    }
+
+    /// Price for valuing a liability that's paid off: the min of oracle and twap
+    pub fn twap_liab_price(&self, oracle_price: I80F48) -> I80F48 {
+        self.oracle_twap()
+            .map_or(oracle_price, |twap| oracle_price.min(twap))
+    }
+
     /// Returns the init asset weight, adjusted for the number of deposits on the bank.
     ///
     /// If max_collateral is 0, then the scaled init weight will be 0.
@@ -1727,6 +2041,81 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_bank_interest_rate_shift() {
+        let mut bank = Bank::zeroed();
+        bank.util0 = I80F48::from_num(0.5);
+        bank.rate0 = I80F48::from(2);
+        bank.util1 = I80F48::from_num(0.75);
+        bank.rate1 = I80F48::from(4);
+        bank.max_rate = I80F48::from(10);
+
+        bank.interest_rate_shift_end = 1000;
+        bank.interest_rate_shift_util0 = I80F48::from_num(0.5);
+        bank.interest_rate_shift_rate0 = I80F48::from(6);
+        bank.interest_rate_shift_util1 = I80F48::from_num(0.75);
+        bank.interest_rate_shift_rate1 = I80F48::from(8);
+        bank.interest_rate_shift_max_rate = I80F48::from(20);
+
+        let d = |a: I80F48, b: f64| (a.to_num::<f64>() - b).abs() < 0.0001;
+
+        // a quarter of the way, starting at 0
+        bank.update_interest_rate_shift(0, 250);
+        assert!(d(bank.rate0, 3.0));
+        assert!(d(bank.rate1, 5.0));
+        assert!(d(bank.max_rate, 12.5));
+        assert_eq!(bank.interest_rate_shift_end, 1000);
+
+        // half way: the steps are linear
+        bank.update_interest_rate_shift(250, 500);
+        assert!(d(bank.rate0, 4.0));
+        assert!(d(bank.rate1, 6.0));
+        assert!(d(bank.max_rate, 15.0));
+
+        // overshooting the end reaches the targets and clears the shift
+        bank.update_interest_rate_shift(500, 2000);
+        assert!(d(bank.rate0, 6.0));
+        assert!(d(bank.rate1, 8.0));
+        assert!(d(bank.max_rate, 20.0));
+        assert!(d(bank.util0, 0.5));
+        assert_eq!(bank.interest_rate_shift_end, 0);
+        assert_eq!(bank.interest_rate_shift_rate0, I80F48::ZERO);
+
+        // without a shift nothing changes
+        bank.update_interest_rate_shift(2000, 3000);
+        assert!(d(bank.rate0, 6.0));
+    }
+
+    #[test]
+    fn test_bank_oracle_twap() {
+        let mut bank = Bank::zeroed();
+        let oracle = I80F48::from(10);
+
+        // disabled: no updates, oracle price is used as-is
+        bank.update_oracle_twap(100, 10.0);
+        assert_eq!(bank.oracle_twap, 0.0);
+        assert_eq!(bank.oracle_twap(), None);
+        assert_eq!(bank.twap_asset_price(oracle), oracle);
+
+        // first update initializes
+        bank.oracle_twap_window_seconds = 100;
+        bank.update_oracle_twap(100, 10.0);
+        assert_eq!(bank.oracle_twap, 10.0);
+        assert_eq!(bank.oracle_twap_last_update, 100);
+
+        // a quarter of the window moves a quarter of the way
+        bank.update_oracle_twap(125, 20.0);
+        assert!((bank.oracle_twap - 12.5).abs() < 1e-9);
+        assert_eq!(bank.twap_asset_price(oracle), I80F48::from_num(12.5));
+        assert_eq!(bank.twap_liab_price(oracle), oracle);
+
+        // more than the window jumps to the new price
+        bank.update_oracle_twap(500, 5.0);
+        assert!((bank.oracle_twap - 5.0).abs() < 1e-9);
+        assert_eq!(bank.twap_asset_price(oracle), oracle);
+        assert_eq!(bank.twap_liab_price(oracle), I80F48::from(5));
+    }
+
     #[test]
     fn test_bank_interest_rate_curve() {
         let mut bank = Bank::zeroed();
@@ -1767,4 +2156,20 @@ mod tests {
         assert!(d(interest(0.75 + delta), 7.0) <= eps);
         assert!(d(interest(1.0 - delta), 13.0) <= eps);
     }
+
+    #[test]
+    fn test_bank_migrate() {
+        // banks created before layout_version existed have it zeroed
+        let mut bank = Bank::zeroed();
+        assert!(bank.migrate().unwrap());
+        assert_eq!(bank.layout_version, Bank::LAYOUT_VERSION);
+
+        // migrating again is a no-op
+        assert!(!bank.migrate().unwrap());
+        assert_eq!(bank.layout_version, Bank::LAYOUT_VERSION);
+
+        // layouts from a newer program are rejected
+        bank.layout_version = Bank::LAYOUT_VERSION + 1;
+        assert!(bank.migrate().is_err());
+    }
 }
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use static_assertions::const_assert_eq;
 use std::mem::size_of;
 
+use crate::error::*;
+
 // TODO: Assuming we allow up to 65536 different tokens
 pub type TokenIndex = u16;
 
@@ -12,11 +14,6 @@ pub type TokenIndex = u16;
 /// incorrect assumption.
 pub const QUOTE_TOKEN_INDEX: TokenIndex = 0;
 
-/// The token index used for the insurance fund.
-///
-/// We should eventually generalize insurance funds.
-pub const INSURANCE_TOKEN_INDEX: TokenIndex = 0;
-
 /// The token index used for settling perp markets.
 ///
 /// We should eventually generalize to make the whole perp quote (and settle) token
@@ -98,16 +95,96 @@ pub struct Group {
     /// Number of fast listings that are allowed per interval
     pub allowed_fast_listings_per_interval: u16,
 
-    pub reserved: [u8; 1812],
+    /// Programs that margin_trade may cpi into, unused entries are Pubkey::default()
+    pub allowed_swap_programs: [Pubkey; 4],
+
+    /// In withdraw-only mode instructions that increase positions are rejected,
+    /// while withdrawals, cancels, settles and liquidations keep working.
+    ///
+    /// See group_toggle_withdraw_only.
+    pub withdraw_only: u8,
+
+    /// Whether token deposits may be invoked via CPI, for integrator programs
+    /// that manage MangoAccounts. Other instructions don't check this flag.
+    pub allow_cpi_deposit: u8,
+
+    pub padding2: [u8; 2],
+
+    /// Share of the fees that a referred MangoAccount generates that is paid out to
+    /// its referrer, see MangoAccount::referral_fees_accrued.
+    pub referral_fee_share: f32,
+
+    /// Token index of the bank for insurance_mint.
+    ///
+    /// Bankruptcy pays out insurance fund tokens through this bank, converting
+    /// at oracle prices to the liability that is being covered.
+    pub insurance_token_index: TokenIndex,
+
+    /// Layout version of this account, see Group::LAYOUT_VERSION and group_migrate.
+    ///
+    /// Unrelated to `version`, which gates features.
+    pub layout_version: u8,
+
+    pub reserved: [u8; 1673],
 }
 const_assert_eq!(
     size_of::<Group>(),
-    32 + 4 + 32 * 2 + 4 + 32 * 2 + 4 + 4 + 20 * 32 + 32 + 8 + 16 + 32 + 8 + 8 + 2 * 2 + 1812
+    32 + 4
+        + 32 * 2
+        + 4
+        + 32 * 2
+        + 4
+        + 4
+        + 20 * 32
+        + 32
+        + 8
+        + 16
+        + 32
+        + 8
+        + 8
+        + 2 * 2
+        + 4 * 32
+        + 1
+        + 1
+        + 2
+        + 4
+        + 2
+        + 1
+        + 1673
 );
 const_assert_eq!(size_of::<Group>(), 2736);
 const_assert_eq!(size_of::<Group>() % 8, 0);
 
 impl Group {
+    /// Latest layout version, see layout_version.
+    ///
+    /// Bump it when a change needs existing groups to be converted, and add the
+    /// conversion to migrate().
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    /// Upgrades a group with an older layout_version in place.
+    ///
+    /// Returns whether anything changed.
+    pub fn migrate(&mut self) -> Result<bool> {
+        require_msg!(
+            self.layout_version <= Self::LAYOUT_VERSION,
+            "group layout version {} is newer than the supported {}",
+            self.layout_version,
+            Self::LAYOUT_VERSION
+        );
+        let old_version = self.layout_version;
+        while self.layout_version < Self::LAYOUT_VERSION {
+            match self.layout_version {
+                // v1 only introduced layout_version, the fields carved from
+                // reserved up to then default to zero
+                0 => {}
+                _ => unreachable!(),
+            }
+            self.layout_version += 1;
+        }
+        Ok(self.layout_version != old_version)
+    }
+
     pub fn buyback_fees(&self) -> bool {
         self.buyback_fees == 1
     }
@@ -135,6 +212,18 @@ impl Group {
     pub fn openbook_v2_supported(&self) -> bool {
         self.is_testing()
     }
+
+    pub fn is_withdraw_only(&self) -> bool {
+        self.withdraw_only == 1
+    }
+
+    pub fn allows_cpi_deposit(&self) -> bool {
+        self.allow_cpi_deposit == 1
+    }
+
+    pub fn is_swap_program_allowed(&self, program: &Pubkey) -> bool {
+        *program != Pubkey::default() && self.allowed_swap_programs.contains(program)
+    }
 }
 
 /// Enum for lookup into ix gate
@@ -224,6 +313,23 @@ pub enum IxGate {
     TokenConditionalSwapCreatePremiumAuction = 69,
     TokenConditionalSwapCreateLinearAuction = 70,
     Serum3PlaceOrderV2 = 71,
+    PerpLiqAutoDeleverage = 72,
+    GroupDepositInsuranceFund = 73,
+    PerpTriggerOrderCreate = 74,
+    PerpTriggerOrderCancel = 75,
+    PerpTriggerOrderExecute = 76,
+    AltCreate = 77,
+    MarginTrade = 78,
+    PerpSettlePnlBatch = 79,
+    PerpModifyOrder = 80,
+    PerpPlaceOrders = 81,
+    Serum3PlaceOrders = 82,
+    TokenCheckVaultInvariant = 83,
+    AccountClaimReferralFees = 84,
+    PerpUpdateAdlCandidate = 85,
+    PerpLiqCombined = 86,
+    GroupMigrate = 87,
+    TokenMigrate = 88,
     // NOTE: Adding new variants requires matching changes in ts and the ix_gate_set instruction.
 }
 
@@ -241,3 +347,50 @@ macro_rules! group_seeds {
 }
 
 pub use group_seeds;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_gating() {
+        let mut group: Group = bytemuck::Zeroable::zeroed();
+        let supported = |group: &Group| {
+            (
+                group.serum3_supported(),
+                group.perps_supported(),
+                group.multiple_banks_supported(),
+                group.openbook_v2_supported(),
+            )
+        };
+
+        // features are enabled progressively with the group version
+        group.version = 0;
+        assert_eq!(supported(&group), (false, false, false, false));
+        group.version = 1;
+        assert_eq!(supported(&group), (true, false, false, false));
+        group.version = 2;
+        assert_eq!(supported(&group), (true, true, true, false));
+
+        // testing groups have everything enabled, independent of the version
+        group.testing = 1;
+        group.version = 0;
+        assert_eq!(supported(&group), (true, true, true, true));
+    }
+
+    #[test]
+    fn test_group_migrate() {
+        // groups created before layout_version existed have it zeroed
+        let mut group: Group = bytemuck::Zeroable::zeroed();
+        assert!(group.migrate().unwrap());
+        assert_eq!(group.layout_version, Group::LAYOUT_VERSION);
+
+        // migrating again is a no-op
+        assert!(!group.migrate().unwrap());
+        assert_eq!(group.layout_version, Group::LAYOUT_VERSION);
+
+        // layouts from a newer program are rejected
+        group.layout_version = Group::LAYOUT_VERSION + 1;
+        assert!(group.migrate().is_err());
+    }
+}
@@ -13,7 +13,9 @@ use static_assertions::const_assert_eq;
 
 use crate::error::*;
 use crate::health::{HealthCache, HealthType};
-use crate::logs::{emit_stack, DeactivatePerpPositionLog, DeactivateTokenPositionLog};
+use crate::logs::{
+    emit_stack, DeactivatePerpPositionLog, DeactivateTokenPositionLog, HealthLog, TokenBalanceLog,
+};
 use crate::util;
 
 use super::BookSideOrderTree;
@@ -28,6 +30,7 @@ use super::TokenIndex;
 use super::FREE_ORDER_SLOT;
 use super::{dynamic_account::*, Group};
 use super::{PerpPosition, Serum3Orders, TokenPosition};
+use super::{ReferralFeeSource, ReferralFeesAccrued};
 use super::{Side, SideAndOrderTree};
 
 type BorshVecLength = u32;
@@ -327,11 +330,29 @@ pub struct MangoAccountFixed {
     pub next_token_conditional_swap_id: u64,
     pub temporary_delegate: Pubkey,
     pub temporary_delegate_expiry: u64,
-    pub reserved: [u8; 160],
+
+    /// Isolated accounts may only have positions in the isolated perp market and tokens,
+    /// which also means their health only depends on those.
+    isolated: u8,
+    pub padding2: [u8; 1],
+    /// The perp market an isolated account may use, PerpMarketIndex::MAX for none
+    pub isolated_perp_market_index: PerpMarketIndex,
+    /// The tokens an isolated account may use, TokenIndex::MAX for unused entries
+    pub isolated_token_indexes: [TokenIndex; 6],
+
+    /// The MangoAccount that referred this account, Pubkey::default() for none.
+    ///
+    /// Can only be set when the account is created.
+    pub referrer: Pubkey,
+    /// Fees this account generated since the referrer last claimed, per bank or perp
+    /// market that collected them. The referrer receives Group::referral_fee_share of them.
+    pub referral_fees_accrued: [ReferralFeesAccrued; 6],
+
+    pub reserved: [u8; 16],
 }
 const_assert_eq!(
     size_of::<MangoAccountFixed>(),
-    32 * 4 + 8 + 8 * 8 + 32 + 8 + 160
+    32 * 4 + 8 + 8 * 8 + 32 + 8 + 1 + 1 + 2 + 2 * 6 + 32 + 16 * 6 + 16
 );
 const_assert_eq!(size_of::<MangoAccountFixed>(), 400);
 const_assert_eq!(size_of::<MangoAccountFixed>() % 8, 0);
@@ -381,6 +402,24 @@ impl MangoAccountFixed {
         self.in_health_region = u8::from(b);
     }
 
+    pub fn is_isolated(&self) -> bool {
+        self.isolated == 1
+    }
+
+    pub fn set_isolated(&mut self, b: bool) {
+        self.isolated = u8::from(b);
+    }
+
+    pub fn is_token_allowed(&self, token_index: TokenIndex) -> bool {
+        !self.is_isolated()
+            || (token_index != TokenIndex::MAX
+                && self.isolated_token_indexes.contains(&token_index))
+    }
+
+    pub fn is_perp_market_allowed(&self, perp_market_index: PerpMarketIndex) -> bool {
+        !self.is_isolated() || self.isolated_perp_market_index == perp_market_index
+    }
+
     pub fn maybe_recover_from_being_liquidated(&mut self, liq_end_health: I80F48) -> bool {
         // This is used as threshold to flip flag instead of 0 because of dust issues
         let one_native_usdc = I80F48::ONE;
@@ -434,6 +473,73 @@ impl MangoAccountFixed {
         }
     }
 
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
+
+    /// Add fees that the referrer earns a share of. Does nothing without a referrer.
+    ///
+    /// The fees are tracked per bank or perp market, in native units of the token they
+    /// were paid in. If all slots are used by other sources, the fees aren't accrued.
+    ///
+    /// Returns whether the fees were accrued. If so, the caller must also add them to
+    /// referral_fees_unclaimed of the bank or perp market that collected them.
+    pub fn accrue_referral_fees(
+        &mut self,
+        source: ReferralFeeSource,
+        index: u16,
+        amount: u64,
+    ) -> bool {
+        if !self.has_referrer() || amount == 0 {
+            return false;
+        }
+        let slot_index = self
+            .referral_fees_accrued
+            .iter()
+            .position(|slot| slot.is_for(source, index))
+            .or_else(|| {
+                self.referral_fees_accrued
+                    .iter()
+                    .position(|slot| slot.source() == ReferralFeeSource::Unused)
+            });
+        let slot = match slot_index {
+            Some(i) => &mut self.referral_fees_accrued[i],
+            None => return false,
+        };
+        slot.source = source.into();
+        slot.index = index;
+        slot.amount = slot.amount.saturating_add(amount);
+        true
+    }
+
+    /// Referral fees accrued for a bank or perp market, zero if there are none
+    pub fn referral_fees_accrued(&self, source: ReferralFeeSource, index: u16) -> u64 {
+        self.referral_fees_accrued
+            .iter()
+            .find(|slot| slot.is_for(source, index))
+            .map_or(0, |slot| slot.amount)
+    }
+
+    /// Removes claimed referral fees, freeing the slot once nothing is left
+    pub fn reduce_referral_fees_accrued(
+        &mut self,
+        source: ReferralFeeSource,
+        index: u16,
+        amount: u64,
+    ) -> Result<()> {
+        let slot = self
+            .referral_fees_accrued
+            .iter_mut()
+            .find(|slot| slot.is_for(source, index))
+            .ok_or_else(|| error_msg!("no referral fees accrued for {:?} {}", source, index))?;
+        require_gte!(slot.amount, amount);
+        slot.amount -= amount;
+        if slot.amount == 0 {
+            *slot = ReferralFeesAccrued::default();
+        }
+        Ok(())
+    }
+
     pub fn pda_seeds(&self) -> MangoAccountPdaSeeds {
         MangoAccountPdaSeeds {
             group: self.group,
@@ -990,8 +1096,15 @@ impl<
             }
         }
         if let Some((raw_index, bank_index)) = match_or_free {
+            let allowed = self.fixed().is_token_allowed(token_index);
             let v = self.token_position_mut_by_raw_index(raw_index);
             if !v.is_active_for_token(token_index) {
+                require_msg_typed!(
+                    allowed,
+                    MangoError::IsolatedAccountPositionNotAllowed,
+                    "token index {} is not allowed for this isolated account",
+                    token_index
+                );
                 *v = TokenPosition {
                     indexed_position: I80F48::ZERO,
                     token_index,
@@ -1138,6 +1251,12 @@ impl<
             .all_perp_positions()
             .position(|p| p.is_active_for_market(perp_market_index));
         if raw_index_opt.is_none() {
+            require_msg_typed!(
+                self.fixed().is_perp_market_allowed(perp_market_index),
+                MangoError::IsolatedAccountPositionNotAllowed,
+                "perp market index {} is not allowed for this isolated account",
+                perp_market_index
+            );
             raw_index_opt = self.all_perp_positions().position(|p| !p.is_active());
             if let Some(raw_index) = raw_index_opt {
                 let perp_position = self.perp_position_mut_by_raw_index(raw_index);
@@ -1196,6 +1315,74 @@ impl<
         Ok(())
     }
 
+    /// Settles a dust perp position into the settle token position and deactivates it.
+    ///
+    /// Only positions without base lots, open orders or unprocessed fills whose quote
+    /// position is below the market's dust_threshold qualify. The residual is exchanged
+    /// with the market's fees, like in perp_settle_fees.
+    ///
+    /// Returns true if the position was deactivated.
+    pub fn perp_dust_deactivate_if_possible(
+        &mut self,
+        perp_market: &mut PerpMarket,
+        settle_bank: &mut crate::state::Bank,
+        now_ts: u64,
+        mango_account_pubkey: Pubkey,
+    ) -> Result<bool> {
+        let perp_market_index = perp_market.perp_market_index;
+        let settle_token_index = perp_market.settle_token_index;
+        require_eq!(settle_bank.token_index, settle_token_index);
+        if perp_market.dust_threshold <= 0.0 {
+            return Ok(false);
+        }
+
+        let residual = {
+            let perp_position = match self.perp_position_mut(perp_market_index) {
+                Ok(p) => p,
+                Err(_) => return Ok(false),
+            };
+            perp_position.settle_funding(perp_market);
+            let residual = perp_position.quote_position_native();
+            if perp_position.base_position_lots() != 0
+                || perp_position.bids_base_lots != 0
+                || perp_position.asks_base_lots != 0
+                || perp_position.taker_base_lots != 0
+                || perp_position.taker_quote_lots != 0
+                || residual.abs() >= I80F48::from_num(perp_market.dust_threshold)
+            {
+                return Ok(false);
+            }
+            perp_position.record_settle(residual, perp_market);
+            let residual_i64 = residual.round_to_zero().to_num::<i64>();
+            perp_position.perp_spot_transfers += residual_i64;
+            self.fixed_mut().perp_spot_transfers += residual_i64;
+            residual
+        };
+
+        perp_market.fees_accrued += residual;
+        perp_market.fees_settled -= residual;
+
+        let group = self.fixed().group;
+        let token_position = self.token_position_mut(settle_token_index)?.0;
+        settle_bank.change_without_fee(token_position, residual, now_ts)?;
+        emit_stack(TokenBalanceLog {
+            mango_group: group,
+            mango_account: mango_account_pubkey,
+            token_index: settle_token_index,
+            indexed_position: token_position.indexed_position.to_bits(),
+            deposit_index: settle_bank.deposit_index.to_bits(),
+            borrow_index: settle_bank.borrow_index.to_bits(),
+        });
+
+        self.deactivate_perp_position_and_log(
+            perp_market_index,
+            settle_token_index,
+            mango_account_pubkey,
+        )?;
+
+        Ok(true)
+    }
+
     pub fn add_perp_order(
         &mut self,
         perp_market_index: PerpMarketIndex,
@@ -1259,6 +1446,7 @@ impl<
         pa.settle_funding(perp_market);
         pa.record_trading_fee(fees);
         let realized_pnl = pa.record_trade(perp_market, base_change, quote);
+        perp_market.clear_stale_adl_candidate(&fill.maker, pa.base_position_lots());
 
         pa.maker_volume += quote.abs().to_num::<u64>();
 
@@ -1308,6 +1496,7 @@ impl<
         let quote_change_native =
             I80F48::from(perp_market.quote_lot_size) * I80F48::from(quote_change);
         let realized_pnl = pa.record_trade(perp_market, base_change, quote_change_native);
+        perp_market.clear_stale_adl_candidate(&fill.taker, pa.base_position_lots());
 
         pa.taker_volume += quote_change_native.abs().to_num::<u64>();
 
@@ -1345,6 +1534,33 @@ impl<
         Ok(())
     }
 
+    /// Pays a perp crank reward out of the market's fees_accrued to this account.
+    ///
+    /// Only accounts that already have a position in the market are paid, that way
+    /// passing an account as keeper never occupies one of its perp position slots.
+    ///
+    /// Returns the reward that was paid.
+    pub fn pay_perp_keeper_reward(
+        &mut self,
+        perp_market: &mut PerpMarket,
+        reward: I80F48,
+    ) -> I80F48 {
+        let pa = match self.perp_position_mut(perp_market.perp_market_index) {
+            Ok(pa) => pa,
+            Err(_) => return I80F48::ZERO,
+        };
+        let reward = reward
+            .min(perp_market.fees_accrued)
+            .max(I80F48::ZERO)
+            .floor();
+        if reward.is_zero() {
+            return I80F48::ZERO;
+        }
+        perp_market.fees_accrued -= reward;
+        pa.record_keeper_reward(reward);
+        reward
+    }
+
     pub fn token_conditional_swap_mut_by_index(
         &mut self,
         index: usize,
@@ -1389,17 +1605,34 @@ impl<
         Ok(())
     }
 
+    /// Checks the health after an instruction and emits a HealthLog for monitoring
     pub fn check_health_post(
         &mut self,
         health_cache: &HealthCache,
         pre_init_health: I80F48,
+        mango_account_pk: Pubkey,
     ) -> Result<I80F48> {
         let post_init_health = health_cache.health(HealthType::Init);
         msg!("post_init_health: {}", post_init_health);
         self.check_health_post_checks(pre_init_health, post_init_health)?;
+        self.emit_health_log(health_cache, post_init_health, mango_account_pk);
         Ok(post_init_health)
     }
 
+    pub fn emit_health_log(
+        &self,
+        health_cache: &HealthCache,
+        init_health: I80F48,
+        mango_account_pk: Pubkey,
+    ) {
+        emit_stack(HealthLog {
+            mango_group: self.fixed().group,
+            mango_account: mango_account_pk,
+            init_health: init_health.to_bits(),
+            maint_health: health_cache.health(HealthType::Maint).to_bits(),
+        });
+    }
+
     pub fn check_health_post_checks(
         &mut self,
         pre_init_health: I80F48,
@@ -1968,6 +2201,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_header_version() {
+        let account = MangoAccount::default_for_tests();
+        let mut bytes = AnchorSerialize::try_to_vec(&account).unwrap();
+
+        // the dynamic part starts with the layout version
+        let version_offset = size_of::<MangoAccountFixed>();
+        assert_eq!(bytes[version_offset], DEFAULT_MANGO_ACCOUNT_VERSION);
+        assert!(MangoAccountValue::from_bytes(&bytes).is_ok());
+
+        // unknown layouts must be rejected instead of being misinterpreted
+        bytes[version_offset] = DEFAULT_MANGO_ACCOUNT_VERSION + 1;
+        assert!(MangoAccountValue::from_bytes(&bytes).is_err());
+        bytes[version_offset] = 0;
+        assert!(MangoAccountValue::from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_token_positions() {
         let mut account = make_test_account();
@@ -2199,6 +2449,45 @@ mod tests {
         assert_eq!(fixed.buyback_fees_accrued(), 0);
     }
 
+    #[test]
+    fn test_referral_fees() {
+        use ReferralFeeSource::*;
+
+        let mut account = make_test_account();
+        let fixed = account.fixed_mut();
+
+        // nothing accrues without a referrer
+        assert!(!fixed.accrue_referral_fees(Bank, 0, 10));
+        assert_eq!(fixed.referral_fees_accrued(Bank, 0), 0);
+
+        fixed.referrer = Pubkey::new_unique();
+        assert!(fixed.accrue_referral_fees(Bank, 0, 10));
+        assert!(fixed.accrue_referral_fees(Bank, 0, 5));
+        assert!(fixed.accrue_referral_fees(PerpMarket, 0, 7));
+        assert!(!fixed.accrue_referral_fees(Bank, 1, 0));
+        assert_eq!(fixed.referral_fees_accrued(Bank, 0), 15);
+        assert_eq!(fixed.referral_fees_accrued(PerpMarket, 0), 7);
+        assert_eq!(fixed.referral_fees_accrued(Bank, 1), 0);
+
+        // when all slots are in use, other sources don't accrue
+        for i in 1..5 {
+            assert!(fixed.accrue_referral_fees(Bank, i, 1));
+        }
+        assert!(!fixed.accrue_referral_fees(Bank, 5, 1));
+        assert!(fixed.accrue_referral_fees(Bank, 4, 1));
+        assert_eq!(fixed.referral_fees_accrued(Bank, 4), 2);
+
+        // reducing to zero frees the slot
+        assert!(fixed.reduce_referral_fees_accrued(Bank, 0, 16).is_err());
+        fixed.reduce_referral_fees_accrued(Bank, 0, 5).unwrap();
+        assert_eq!(fixed.referral_fees_accrued(Bank, 0), 10);
+        fixed.reduce_referral_fees_accrued(Bank, 0, 10).unwrap();
+        assert_eq!(fixed.referral_fees_accrued(Bank, 0), 0);
+        assert!(fixed.reduce_referral_fees_accrued(Bank, 0, 1).is_err());
+        assert!(fixed.accrue_referral_fees(Bank, 5, 1));
+        assert_eq!(fixed.referral_fees_accrued(Bank, 5), 1);
+    }
+
     #[test]
     fn test_token_conditional_swap() {
         let mut account = make_test_account();
@@ -2601,6 +2890,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_perp_dust_deactivate() {
+        let mut account = make_test_account();
+        let mut perp_market = PerpMarket::default_for_tests();
+        perp_market.perp_market_index = 1;
+        let mut settle_bank = crate::state::Bank::zeroed();
+        settle_bank.deposit_index = I80F48::ONE;
+        settle_bank.borrow_index = I80F48::ONE;
+        let account_pk = Pubkey::new_unique();
+
+        account.ensure_perp_position(1, 0).unwrap();
+        account.perp_position_mut(1).unwrap().quote_position_native = I80F48::from_num(0.5);
+
+        let mut try_deactivate = |account: &mut MangoAccountValue, perp_market: &mut PerpMarket| {
+            account
+                .perp_dust_deactivate_if_possible(perp_market, &mut settle_bank, 0, account_pk)
+                .unwrap()
+        };
+
+        // disabled by default
+        assert!(!try_deactivate(&mut account, &mut perp_market));
+
+        // quote position too large
+        perp_market.dust_threshold = 0.4;
+        assert!(!try_deactivate(&mut account, &mut perp_market));
+
+        // base position still open
+        perp_market.dust_threshold = 1.0;
+        account.perp_position_mut(1).unwrap().base_position_lots = 1;
+        assert!(!try_deactivate(&mut account, &mut perp_market));
+        account.perp_position_mut(1).unwrap().base_position_lots = 0;
+
+        assert!(try_deactivate(&mut account, &mut perp_market));
+        assert!(account.perp_position(1).is_err());
+        assert_eq!(perp_market.fees_accrued, I80F48::from_num(0.5));
+        assert_eq!(perp_market.fees_settled, I80F48::from_num(-0.5));
+
+        let token_position = account.token_position(0).unwrap();
+        assert_eq!(token_position.in_use_count, 0);
+        assert!((token_position.native(&settle_bank) - I80F48::from_num(0.5)).abs() < 0.0001);
+    }
+
     #[test]
     fn test_perp_order_events() -> Result<()> {
         let group = Group::zeroed();
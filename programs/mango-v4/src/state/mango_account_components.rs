@@ -2,12 +2,14 @@ use anchor_lang::prelude::*;
 
 use derivative::Derivative;
 use fixed::types::I80F48;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert_eq;
 use std::cmp::Ordering;
 use std::mem::size_of;
 
 use crate::i80f48::ClampToInt;
 use crate::state::*;
+use crate::util;
 
 pub const FREE_ORDER_SLOT: PerpMarketIndex = PerpMarketIndex::MAX;
 
@@ -78,22 +80,12 @@ impl TokenPosition {
     }
 
     pub fn native(&self, bank: &Bank) -> I80F48 {
-        if self.indexed_position.is_positive() {
-            self.indexed_position * bank.deposit_index
-        } else {
-            self.indexed_position * bank.borrow_index
-        }
+        util::indexed_to_native(self.indexed_position, bank.deposit_index, bank.borrow_index)
     }
 
     #[cfg(feature = "client")]
     pub fn ui(&self, bank: &Bank) -> I80F48 {
-        if self.indexed_position.is_positive() {
-            (self.indexed_position * bank.deposit_index)
-                / I80F48::from_num(10u64.pow(bank.mint_decimals as u32))
-        } else {
-            (self.indexed_position * bank.borrow_index)
-                / I80F48::from_num(10u64.pow(bank.mint_decimals as u32))
-        }
+        util::native_to_ui(self.native(bank), bank.mint_decimals)
     }
 
     pub fn is_in_use(&self) -> bool {
@@ -323,12 +315,23 @@ pub struct PerpPosition {
     /// price and current price of the base position is the overall pnl.
     pub realized_pnl_for_position_native: I80F48,
 
+    /// Quote paid (negative) or received (positive) for entering the current base position.
+    ///
+    /// Reduced proportionally when the base position is reduced and reset when the position
+    /// changes sign or goes to zero. Unlike avg_entry_price_per_base_lot, this is exact.
+    pub entry_quote_native: I80F48,
+
+    /// Pnl that was settled over the current position's lifetime.
+    ///
+    /// Reset when the position changes sign or goes to zero.
+    pub settled_pnl_for_position_native: I80F48,
+
     #[derivative(Debug = "ignore")]
-    pub reserved: [u8; 88],
+    pub reserved: [u8; 56],
 }
 const_assert_eq!(
     size_of::<PerpPosition>(),
-    2 + 2 + 4 + 8 + 8 + 16 + 8 + 16 * 2 + 8 * 2 + 8 * 2 + 8 * 5 + 8 + 2 * 16 + 8 + 16 + 88
+    2 + 2 + 4 + 8 + 8 + 16 + 8 + 16 * 2 + 8 * 2 + 8 * 2 + 8 * 5 + 8 + 2 * 16 + 8 + 16 * 3 + 56
 );
 const_assert_eq!(size_of::<PerpPosition>(), 304);
 const_assert_eq!(size_of::<PerpPosition>() % 8, 0);
@@ -359,7 +362,9 @@ impl Default for PerpPosition {
             settle_pnl_limit_settled_in_current_window_native: 0,
             recurring_settle_pnl_allowance: 0,
             realized_pnl_for_position_native: I80F48::ZERO,
-            reserved: [0; 88],
+            entry_quote_native: I80F48::ZERO,
+            settled_pnl_for_position_native: I80F48::ZERO,
+            reserved: [0; 56],
         }
     }
 }
@@ -476,6 +481,9 @@ impl PerpPosition {
         let old_position = self.base_position_lots;
         let new_position = old_position + base_change;
 
+        // Positions opened before entry_quote_native existed start from the estimate
+        self.entry_quote_native = self.entry_quote_native_or_estimate();
+
         // abs amount of lots that were reduced:
         // - going from -5 to 10 lots is a reduction of 5
         // - going from 10 to -5 is a reduction of 10
@@ -498,10 +506,18 @@ impl PerpPosition {
             self.avg_entry_price_per_base_lot = 0.0;
             self.quote_running_native = 0;
             self.realized_pnl_for_position_native = I80F48::ZERO;
+            self.entry_quote_native = I80F48::ZERO;
+            self.settled_pnl_for_position_native = I80F48::ZERO;
         } else if old_position.signum() != new_position.signum() {
             // If the base position changes sign, we've crossed base_pos == 0 (or old_position == 0)
             reduced_lots = old_position.abs();
             increased_lots = new_position.abs();
+
+            // New position entered with the part of the trade that went beyond closing
+            // the old position
+            self.entry_quote_native =
+                quote_change_native / I80F48::from(base_change) * I80F48::from(new_position);
+
             let old_position = old_position as f64;
             let new_position = new_position as f64;
             let base_change = base_change as f64;
@@ -517,6 +533,7 @@ impl PerpPosition {
 
             // New position without realized pnl
             self.realized_pnl_for_position_native = I80F48::ZERO;
+            self.settled_pnl_for_position_native = I80F48::ZERO;
         } else {
             // The old and new position have the same sign
 
@@ -534,6 +551,7 @@ impl PerpPosition {
                 let new_position_quote_value =
                     old_position_abs * old_avg_entry + quote_change_native.to_num::<f64>().abs();
                 self.avg_entry_price_per_base_lot = new_position_quote_value / new_position_abs;
+                self.entry_quote_native += quote_change_native;
             } else {
                 // Decreasing position: pnl is realized, avg entry price does not change
                 reduced_lots = base_change.abs();
@@ -541,6 +559,8 @@ impl PerpPosition {
                 let avg_entry = I80F48::from_num(self.avg_entry_price_per_base_lot);
                 newly_realized_pnl = quote_change_native + I80F48::from(base_change) * avg_entry;
                 self.realized_pnl_for_position_native += newly_realized_pnl;
+                self.entry_quote_native = self.entry_quote_native / I80F48::from(old_position)
+                    * I80F48::from(new_position);
             }
         }
 
@@ -638,6 +658,30 @@ impl PerpPosition {
         Ok(pnl)
     }
 
+    /// Calculate the pnl of the current base position relative to its average entry price
+    ///
+    /// The overall pnl of the position is this plus realized_pnl_for_position_native.
+    /// Contrary to unsettled_pnl(), settling does not change this value.
+    pub fn unrealized_pnl(&self, perp_market: &PerpMarket, price: I80F48) -> Result<I80F48> {
+        require_eq!(self.market_index, perp_market.perp_market_index);
+        if self.base_position_lots == 0 {
+            return Ok(I80F48::ZERO);
+        }
+        let base_native = self.base_position_native(perp_market);
+        Ok(base_native * price + self.entry_quote_native_or_estimate())
+    }
+
+    /// The entry_quote_native of the position, estimated from avg_entry_price_per_base_lot
+    /// when it is zero on an open position.
+    ///
+    /// That's the case for positions opened before entry_quote_native was tracked.
+    pub fn entry_quote_native_or_estimate(&self) -> I80F48 {
+        if self.base_position_lots == 0 || !self.entry_quote_native.is_zero() {
+            return self.entry_quote_native;
+        }
+        -I80F48::from(self.base_position_lots) * I80F48::from_num(self.avg_entry_price_per_base_lot)
+    }
+
     /// Updates the perp pnl limit time windowing, resetting the amount
     /// of used settle-pnl budget if necessary
     pub fn update_settle_limit(&mut self, market: &PerpMarket, now_ts: u64) {
@@ -725,6 +769,7 @@ impl PerpPosition {
     /// If `pnl` is positive, then that is settled away, deducting from the quote position.
     pub fn record_settle(&mut self, settled_pnl: I80F48, perp_market: &PerpMarket) {
         self.change_quote_position(-settled_pnl);
+        self.settled_pnl_for_position_native += settled_pnl;
 
         // Settlement reduces oneshot_settle_pnl_allowance if available.
         // Reduction only happens if settled_pnl has the same sign as oneshot_settle_pnl_allowance.
@@ -771,6 +816,12 @@ impl PerpPosition {
         self.realized_pnl_for_position_native -= fee;
     }
 
+    /// Adds immediately-settleable pnl when a keeper is rewarded out of the market's fees
+    pub fn record_keeper_reward(&mut self, reward: I80F48) {
+        self.change_quote_position(reward);
+        self.oneshot_settle_pnl_allowance += reward;
+    }
+
     /// Adds immediately-settleable realized pnl when a liqor takes over pnl during liquidation
     pub fn record_liquidation_quote_change(&mut self, change: I80F48) {
         self.change_quote_position(change);
@@ -784,6 +835,58 @@ impl PerpPosition {
     }
 }
 
+/// The bank or perp market that collected referral fees, see ReferralFeesAccrued
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    TryFromPrimitive,
+    IntoPrimitive,
+    AnchorSerialize,
+    AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum ReferralFeeSource {
+    Unused = 0,
+    /// Loan origination fees, collected by the bank
+    Bank = 1,
+    /// Taker fees, collected by the perp market
+    PerpMarket = 2,
+}
+
+/// Referral fees that a referred account generated in one bank or perp market and that
+/// its referrer hasn't claimed yet, see MangoAccountFixed::referral_fees_accrued
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Derivative, Default, PartialEq)]
+#[derivative(Debug)]
+pub struct ReferralFeesAccrued {
+    /// ReferralFeeSource, zero for unused slots
+    pub source: u8,
+    #[derivative(Debug = "ignore")]
+    pub padding: [u8; 1],
+    /// TokenIndex of the bank or PerpMarketIndex of the perp market
+    pub index: u16,
+    #[derivative(Debug = "ignore")]
+    pub padding2: [u8; 4],
+    /// Native fees, in the bank's token or the perp market's settle token
+    pub amount: u64,
+}
+const_assert_eq!(size_of::<ReferralFeesAccrued>(), 1 + 1 + 2 + 4 + 8);
+const_assert_eq!(size_of::<ReferralFeesAccrued>(), 16);
+const_assert_eq!(size_of::<ReferralFeesAccrued>() % 8, 0);
+
+impl ReferralFeesAccrued {
+    pub fn source(&self) -> ReferralFeeSource {
+        ReferralFeeSource::try_from(self.source).unwrap_or(ReferralFeeSource::Unused)
+    }
+
+    pub fn is_for(&self, source: ReferralFeeSource, index: u16) -> bool {
+        self.source() == source && self.index == index
+    }
+}
+
 #[zero_copy]
 #[derive(AnchorSerialize, AnchorDeserialize, Derivative)]
 #[derivative(Debug)]
@@ -1265,6 +1368,75 @@ mod tests {
         assert_eq!(pnl, I80F48::from(50 * 10 * 1), "short profitable");
     }
 
+    #[test]
+    fn test_perp_position_unrealized_pnl() {
+        let mut market = test_perp_market(10.0);
+        market.base_lot_size = 10;
+
+        // Go long 10 @ 10, then reduce by 5 @ 12
+        let mut pos = create_perp_position(&market, 0, 0);
+        pos.record_trade(&mut market, 10, I80F48::from(-10 * 100));
+        assert_eq!(pos.entry_quote_native, I80F48::from(-10 * 100));
+        let realized = pos.record_trade(&mut market, -5, I80F48::from(5 * 120));
+        assert_eq!(realized, I80F48::from(5 * 10 * 2));
+        assert_eq!(pos.realized_pnl_for_position_native, realized);
+        assert_eq!(pos.entry_quote_native, I80F48::from(-5 * 100));
+
+        let price = I80F48::from(13);
+        let unrealized = pos.unrealized_pnl(&market, price).unwrap();
+        assert_eq!(unrealized, I80F48::from(5 * 10 * 3));
+
+        // Without fees or funding, realized and unrealized pnl add up to the unsettled pnl
+        assert_eq!(
+            pos.realized_pnl_for_position_native + unrealized,
+            pos.unsettled_pnl(&market, price).unwrap()
+        );
+
+        // Settling moves pnl to spot, but doesn't change realized or unrealized pnl
+        pos.record_settle(I80F48::from(50), &market);
+        assert_eq!(pos.realized_pnl_for_position_native, realized);
+        assert_eq!(pos.settled_pnl_for_position_native, I80F48::from(50));
+        assert_eq!(pos.unrealized_pnl(&market, price).unwrap(), unrealized);
+
+        // Closing the position realizes everything
+        let realized = pos.record_trade(&mut market, -5, I80F48::from(5 * 130));
+        assert_eq!(realized, unrealized);
+        assert_eq!(pos.unrealized_pnl(&market, price).unwrap(), I80F48::ZERO);
+        assert_eq!(pos.entry_quote_native, I80F48::ZERO);
+        assert_eq!(pos.settled_pnl_for_position_native, I80F48::ZERO);
+
+        // Flipping from long 10 @ 10 to short 5 @ 12 enters the short with a third of the trade
+        pos.record_trade(&mut market, 10, I80F48::from(-10 * 100));
+        pos.record_trade(&mut market, -15, I80F48::from(15 * 120));
+        assert_eq!(pos.entry_quote_native, I80F48::from(5 * 120));
+        assert_eq!(
+            pos.unrealized_pnl(&market, I80F48::from(11)).unwrap(),
+            I80F48::from(5 * 10 * 1)
+        );
+
+        // Positions from before entry_quote_native was tracked use the average entry price
+        let mut pos = create_perp_position(&market, 10, 100);
+        pos.entry_quote_native = I80F48::ZERO;
+        assert_eq!(
+            pos.entry_quote_native_or_estimate(),
+            I80F48::from(-10 * 100)
+        );
+        assert_eq!(
+            pos.unrealized_pnl(&market, I80F48::from(12)).unwrap(),
+            I80F48::from(10 * 10 * 2)
+        );
+
+        // and the estimate is stored on the next trade
+        pos.record_trade(&mut market, 10, I80F48::from(-10 * 110));
+        assert_eq!(pos.entry_quote_native, I80F48::from(-10 * 100 - 10 * 110));
+        let mut short_pos = create_perp_position(&market, -5, 120);
+        short_pos.entry_quote_native = I80F48::ZERO;
+        assert_eq!(
+            short_pos.entry_quote_native_or_estimate(),
+            I80F48::from(5 * 120)
+        );
+    }
+
     #[test]
     fn test_perp_settle_limit_allowance_consumption() {
         let market = test_perp_market(10.0);
@@ -10,8 +10,11 @@ pub use oracle::*;
 pub use orca_cpi::*;
 pub use orderbook::*;
 pub use perp_market::*;
+pub use perp_trigger_order::*;
+pub use pyth_receiver::*;
 pub use serum3_market::*;
 pub use stable_price::*;
+pub use switchboard_on_demand::*;
 pub use token_conditional_swap::*;
 
 mod bank;
@@ -26,6 +29,9 @@ mod oracle;
 mod orca_cpi;
 mod orderbook;
 mod perp_market;
+mod perp_trigger_order;
+mod pyth_receiver;
 mod serum3_market;
 mod stable_price;
+mod switchboard_on_demand;
 mod token_conditional_swap;
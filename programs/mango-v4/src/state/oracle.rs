@@ -12,9 +12,13 @@ use switchboard_v2::AggregatorAccountData;
 use crate::accounts_zerocopy::*;
 
 use crate::error::*;
-use crate::state::load_whirlpool_state;
+use crate::logs::{emit_stack, OracleRejectedLog};
+use crate::state::{load_pyth_price_update_v2, load_switchboard_pull_feed, load_whirlpool_state};
 
-use super::orca_mainnet_whirlpool;
+use super::{
+    orca_mainnet_whirlpool, pyth_receiver_program, switchboard_on_demand_program,
+    PYTH_PRICE_UPDATE_V2_DISCRIMINATOR, SWITCHBOARD_PULL_FEED_DISCRIMINATOR,
+};
 
 const DECIMAL_CONSTANT_ZERO_INDEX: i8 = 12;
 const DECIMAL_CONSTANTS: [I80F48; 25] = [
@@ -117,6 +121,10 @@ pub enum OracleType {
     SwitchboardV1,
     SwitchboardV2,
     OrcaCLMM,
+    /// Pull-based pyth price update account, owned by the pyth-solana-receiver program
+    PythV2,
+    /// Switchboard on-demand pull feed
+    SwitchboardOnDemand,
 }
 
 pub struct OracleState {
@@ -157,6 +165,32 @@ impl OracleState {
         }
         Ok(())
     }
+
+    /// Emits an OracleRejectedLog if `check` failed because of the oracle.
+    ///
+    /// The log stays visible in the transaction logs even if the instruction fails.
+    pub fn log_if_rejected<T>(
+        &self,
+        oracle: Pubkey,
+        check: &Result<T>,
+        staleness_slot: Option<u64>,
+        fallback_available: bool,
+    ) {
+        if !check.is_oracle_error() {
+            return;
+        }
+        emit_stack(OracleRejectedLog {
+            oracle,
+            oracle_type: self.oracle_type,
+            price: self.price.to_bits(),
+            deviation: self.deviation.to_bits(),
+            last_update_slot: self.last_update_slot,
+            age_slots: staleness_slot
+                .map_or(0, |now_slot| now_slot.saturating_sub(self.last_update_slot)),
+            stale: check.is_anchor_error_with_code(MangoError::OracleStale.error_code()),
+            fallback_available,
+        });
+    }
 }
 
 #[account(zero_copy)]
@@ -182,6 +216,14 @@ pub fn determine_oracle_type(acc_info: &impl KeyedAccountReader) -> Result<Oracl
         return Ok(OracleType::Pyth);
     } else if data[0..8] == StubOracle::discriminator() {
         return Ok(OracleType::Stub);
+    } else if data[0..8] == PYTH_PRICE_UPDATE_V2_DISCRIMINATOR
+        && acc_info.owner() == &pyth_receiver_program::ID
+    {
+        return Ok(OracleType::PythV2);
+    } else if data[0..8] == SWITCHBOARD_PULL_FEED_DISCRIMINATOR
+        && acc_info.owner() == &switchboard_on_demand_program::ID
+    {
+        return Ok(OracleType::SwitchboardOnDemand);
     }
     // https://github.com/switchboard-xyz/switchboard-v2/blob/main/libraries/rs/src/aggregator.rs#L114
     // note: disc is not public, hence the copy pasta
@@ -274,6 +316,23 @@ fn get_pyth_state(
     })
 }
 
+fn get_pyth_v2_state(acc_info: &impl KeyedAccountReader, base_decimals: u8) -> Result<OracleState> {
+    let update = load_pyth_price_update_v2(acc_info)?;
+
+    let decimals = (update.exponent as i8) + QUOTE_DECIMALS - (base_decimals as i8);
+    let decimal_adj = power_of_ten(decimals);
+    let price = I80F48::from_num(update.price) * decimal_adj;
+    let deviation = I80F48::from_num(update.conf) * decimal_adj;
+    require_gte!(price, 0);
+    Ok(OracleState {
+        price,
+        // the slot the update was posted at, the publish slot is not part of the message
+        last_update_slot: update.posted_slot,
+        deviation,
+        oracle_type: OracleType::PythV2,
+    })
+}
+
 /// Contains all oracle account infos that could be used to read price
 pub struct OracleAccountInfos<'a, T: KeyedAccountReader> {
     pub oracle: &'a T,
@@ -353,6 +412,7 @@ fn oracle_state_unchecked_inner<T: KeyedAccountReader>(
             }
         }
         OracleType::Pyth => get_pyth_state(oracle_info, base_decimals)?,
+        OracleType::PythV2 => get_pyth_v2_state(oracle_info, base_decimals)?,
         OracleType::SwitchboardV2 => {
             fn from_foreign_error(e: impl std::fmt::Display) -> Error {
                 error_msg!("{}", e)
@@ -383,6 +443,25 @@ fn oracle_state_unchecked_inner<T: KeyedAccountReader>(
                 oracle_type: OracleType::SwitchboardV2,
             }
         }
+        OracleType::SwitchboardOnDemand => {
+            let feed = load_switchboard_pull_feed(oracle_info)?;
+
+            // The result slot is the latest submission slot; the result's deviation is
+            // the standard deviation across the oracle submissions.
+            let last_update_slot = feed.slot;
+
+            let decimals = QUOTE_DECIMALS - (base_decimals as i8);
+            let decimal_adj = power_of_ten(decimals);
+            let price = I80F48::from_num(feed.value) * decimal_adj;
+            let deviation = I80F48::from_num(feed.std_dev) * decimal_adj;
+            require_gte!(price, 0);
+            OracleState {
+                price,
+                last_update_slot,
+                deviation,
+                oracle_type: OracleType::SwitchboardOnDemand,
+            }
+        }
         OracleType::SwitchboardV1 => {
             let result = FastRoundResultAccountData::deserialize(data).unwrap();
             let ui_price = I80F48::from_num(result.result.result);
@@ -642,4 +721,212 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_pyth_conf_filter() -> Result<()> {
+        let key = "J83w4HKfqxwcq3BEMMkPFSppX3gqekLyLJBexebFVkix";
+        let filename = format!("resources/test/{}.bin", key);
+        let mut pyth_price_data = read_file(find_file(&filename).unwrap());
+        let data = RefCell::new(&mut pyth_price_data[..]);
+        let ai = &AccountInfoRef {
+            key: &Pubkey::from_str(key).unwrap(),
+            owner: &Pubkey::default(),
+            data: data.borrow(),
+        };
+        let oracle_infos = OracleAccountInfos::from_reader(ai);
+
+        // prices are normalized to native quote per native base token
+        let state6 = oracle_state_unchecked(&oracle_infos, 6)?;
+        let state9 = oracle_state_unchecked(&oracle_infos, 9)?;
+        assert!(state6.price.is_positive());
+        let ratio = state6.price / state9.price;
+        assert!((ratio - I80F48::from(1000)).abs() < I80F48::from_num(0.001));
+
+        // reject prices whose confidence interval is too wide relative to the price
+        let mut state = state6;
+        state.deviation = state.price * I80F48::from_num(0.1);
+        let config = |conf_filter: f64| OracleConfig {
+            conf_filter: I80F48::from_num(conf_filter),
+            max_staleness_slots: -1,
+            reserved: [0; 72],
+        };
+        assert!(state.check_confidence(&config(0.11)).is_ok());
+        assert!(state
+            .check_confidence(&config(0.09))
+            .is_anchor_error_with_code(MangoError::OracleConfidence.error_code()));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_switchboard_v2_state() -> Result<()> {
+        let key = "GvDMxPzN1sCj7L26YDK2HnMRXEQmQ2aemov8YBtPS7vR";
+        let filename = format!("resources/test/{}.bin", key);
+        let mut sb_data = read_file(find_file(&filename).unwrap());
+        let feed = bytemuck::pod_read_unaligned::<AggregatorAccountData>(
+            &sb_data[8..8 + size_of::<AggregatorAccountData>()],
+        );
+        let data = RefCell::new(&mut sb_data[..]);
+        let ai = &AccountInfoRef {
+            key: &Pubkey::from_str(key).unwrap(),
+            owner: &Pubkey::default(),
+            data: data.borrow(),
+        };
+        assert!(determine_oracle_type(ai).unwrap() == OracleType::SwitchboardV2);
+
+        let ui_price: f64 = feed.get_result().unwrap().try_into().unwrap();
+        let ui_deviation: f64 = feed
+            .latest_confirmed_round
+            .std_deviation
+            .try_into()
+            .unwrap();
+
+        // the latest confirmed round is used, with its stdev as deviation
+        let base_decimals = 6;
+        let state = oracle_state_unchecked(&OracleAccountInfos::from_reader(ai), base_decimals)?;
+        assert!(state.oracle_type == OracleType::SwitchboardV2);
+        assert_eq!(
+            state.last_update_slot,
+            feed.latest_confirmed_round.round_open_slot
+        );
+        assert_eq!(state.price, I80F48::from_num(ui_price));
+        assert_eq!(state.deviation, I80F48::from_num(ui_deviation));
+
+        Ok(())
+    }
+
+    fn pyth_price_update_v2_data(
+        verification_level: &[u8],
+        price: i64,
+        conf: u64,
+        exponent: i32,
+        posted_slot: u64,
+    ) -> Vec<u8> {
+        let mut data = PYTH_PRICE_UPDATE_V2_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0; 32]); // write_authority
+        data.extend_from_slice(verification_level);
+        data.extend_from_slice(&[7; 32]); // feed_id
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&conf.to_le_bytes());
+        data.extend_from_slice(&exponent.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes()); // publish_time
+        data.extend_from_slice(&1_699_999_999i64.to_le_bytes()); // prev_publish_time
+        data.extend_from_slice(&price.to_le_bytes()); // ema_price
+        data.extend_from_slice(&conf.to_le_bytes()); // ema_conf
+        data.extend_from_slice(&posted_slot.to_le_bytes());
+        data
+    }
+
+    #[test]
+    pub fn test_pyth_v2_state() -> Result<()> {
+        let key = Pubkey::new_unique();
+
+        // SOL at 150.12345678 +- 0.05, fully verified
+        let mut full_data = pyth_price_update_v2_data(&[1], 15_012_345_678, 5_000_000, -8, 1234);
+        let data = RefCell::new(&mut full_data[..]);
+        let ai = &AccountInfoRef {
+            key: &key,
+            owner: &pyth_receiver_program::ID,
+            data: data.borrow(),
+        };
+        assert!(determine_oracle_type(ai).unwrap() == OracleType::PythV2);
+
+        let base_decimals = 9;
+        let state = oracle_state_unchecked(&OracleAccountInfos::from_reader(ai), base_decimals)?;
+        assert!(state.oracle_type == OracleType::PythV2);
+        assert_eq!(state.last_update_slot, 1234);
+        // native quote per native base: 150.12345678 * 1e6 / 1e9
+        assert!((state.price - I80F48::from_num(0.15012345678)).abs() < I80F48::from_num(1e-12));
+        assert!((state.deviation - I80F48::from_num(0.00005)).abs() < I80F48::from_num(1e-12));
+
+        // the account must be owned by the receiver program
+        let wrong_owner_ai = &AccountInfoRef {
+            key: &key,
+            owner: &Pubkey::default(),
+            data: data.borrow(),
+        };
+        assert!(determine_oracle_type(wrong_owner_ai).is_err());
+
+        // partially verified updates are rejected
+        let mut partial_data =
+            pyth_price_update_v2_data(&[0, 5], 15_012_345_678, 5_000_000, -8, 1234);
+        let data = RefCell::new(&mut partial_data[..]);
+        let ai = &AccountInfoRef {
+            key: &key,
+            owner: &pyth_receiver_program::ID,
+            data: data.borrow(),
+        };
+        assert!(determine_oracle_type(ai).unwrap() == OracleType::PythV2);
+        assert!(
+            oracle_state_unchecked(&OracleAccountInfos::from_reader(ai), base_decimals)
+                .is_anchor_error_with_code(MangoError::InvalidPythPriceUpdate.error_code())
+        );
+
+        Ok(())
+    }
+
+    fn switchboard_pull_feed_data(
+        value: i128,
+        std_dev: i128,
+        num_samples: u8,
+        slot: u64,
+    ) -> Vec<u8> {
+        let result_offset = 8 + 2256;
+        let mut data = vec![0u8; 8 + 3712];
+        data[0..8].copy_from_slice(&SWITCHBOARD_PULL_FEED_DISCRIMINATOR);
+        data[result_offset..result_offset + 16].copy_from_slice(&value.to_le_bytes());
+        data[result_offset + 16..result_offset + 32].copy_from_slice(&std_dev.to_le_bytes());
+        data[result_offset + 96] = num_samples;
+        data[result_offset + 104..result_offset + 112].copy_from_slice(&slot.to_le_bytes());
+        data
+    }
+
+    #[test]
+    pub fn test_switchboard_on_demand_state() -> Result<()> {
+        let key = Pubkey::new_unique();
+        let e18 = 10i128.pow(18);
+
+        // 2.5 +- 0.01, results are fixed point with 18 decimals
+        let mut feed_data = switchboard_pull_feed_data(25 * e18 / 10, e18 / 100, 3, 4321);
+        let data = RefCell::new(&mut feed_data[..]);
+        let ai = &AccountInfoRef {
+            key: &key,
+            owner: &switchboard_on_demand_program::ID,
+            data: data.borrow(),
+        };
+        assert!(determine_oracle_type(ai).unwrap() == OracleType::SwitchboardOnDemand);
+
+        let base_decimals = 6;
+        let state = oracle_state_unchecked(&OracleAccountInfos::from_reader(ai), base_decimals)?;
+        assert!(state.oracle_type == OracleType::SwitchboardOnDemand);
+        assert_eq!(state.last_update_slot, 4321);
+        assert!((state.price - I80F48::from_num(2.5)).abs() < I80F48::from_num(1e-9));
+        assert!((state.deviation - I80F48::from_num(0.01)).abs() < I80F48::from_num(1e-9));
+
+        // staleness is judged by the slot of the current result
+        let config = OracleConfig {
+            conf_filter: I80F48::from_num(0.1),
+            max_staleness_slots: 100,
+            reserved: [0; 72],
+        };
+        assert!(state.check_staleness(&config, 4421).is_ok());
+        assert!(state
+            .check_staleness(&config, 4422)
+            .is_anchor_error_with_code(MangoError::OracleStale.error_code()));
+
+        // a feed without samples can't be used
+        let mut empty_data = switchboard_pull_feed_data(0, 0, 0, 0);
+        let data = RefCell::new(&mut empty_data[..]);
+        let ai = &AccountInfoRef {
+            key: &key,
+            owner: &switchboard_on_demand_program::ID,
+            data: data.borrow(),
+        };
+        assert!(
+            oracle_state_unchecked(&OracleAccountInfos::from_reader(ai), base_decimals)
+                .is_anchor_error_with_code(MangoError::InvalidSwitchboardPullFeed.error_code())
+        );
+
+        Ok(())
+    }
 }
@@ -16,6 +16,8 @@ pub const ORCA_WHIRLPOOL_LEN: usize = 653;
 pub const ORCA_WHIRLPOOL_DISCRIMINATOR: [u8; 8] = [63, 149, 209, 12, 225, 128, 99, 9];
 
 pub struct WhirlpoolState {
+    // in-range liquidity
+    pub liquidity: u128, // 16
     // Q64.64
     pub sqrt_price: u128,     // 16
     pub token_mint_a: Pubkey, // 32
@@ -61,6 +63,8 @@ pub fn load_whirlpool_state(acc_info: &impl KeyedAccountReader) -> Result<Whirlp
         MangoError::InvalidCLMMOracle
     );
 
+    let liquidity_bytes: &[u8; 16] = &data[49..65].try_into().unwrap();
+    let liquidity = u128::from_le_bytes(*liquidity_bytes);
     let price_bytes: &[u8; 16] = &data[65..81].try_into().unwrap();
     let sqrt_price = u128::from_le_bytes(*price_bytes);
     let a: &[u8; 32] = &(&data[101..133]).try_into().unwrap();
@@ -69,6 +73,7 @@ pub fn load_whirlpool_state(acc_info: &impl KeyedAccountReader) -> Result<Whirlp
     let mint_b = Pubkey::from(*b);
 
     Ok(WhirlpoolState {
+        liquidity,
         sqrt_price,
         token_mint_a: mint_a,
         token_mint_b: mint_b,
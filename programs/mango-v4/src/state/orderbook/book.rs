@@ -2,6 +2,7 @@ use crate::error::*;
 use crate::logs::{emit_stack, FilledPerpOrderLog, PerpTakerTradeLog};
 use crate::state::{
     orderbook::bookside::*, EventQueue, MangoAccountRefMut, PerpMarket, PerpMarketIndex,
+    ReferralFeeSource,
 };
 use anchor_lang::prelude::*;
 use bytemuck::cast;
@@ -59,6 +60,21 @@ impl<'a> Orderbook<'a> {
         let mut post_target = order.post_target();
         let (price_lots, price_data) = order.price(now_ts, oracle_price_lots, self)?;
 
+        // Orders that may end up on the book must be within the oracle price band. This is
+        // checked before matching so fills are never reverted. Bids are only limited from
+        // below and asks from above, so inside_price_limit() can't drop such an order later.
+        if post_target.is_some() {
+            let native_price = market.lot_to_native_price(price_lots);
+            require_msg_typed!(
+                market.inside_oracle_price_band(side, native_price, oracle_price),
+                MangoError::PerpPriceBandExceeded,
+                "order price {} is too far from the oracle price {} (band {})",
+                native_price,
+                oracle_price,
+                market.oracle_price_band
+            );
+        }
+
         // generate new order id
         let order_id = market.gen_order_id(side, price_data);
 
@@ -484,6 +500,14 @@ fn apply_fees(
     account
         .fixed
         .accrue_buyback_fees(taker_dao_fees.floor().to_num::<u64>());
+    let referral_fees = taker_fees.floor().to_num::<u64>();
+    if account.fixed.accrue_referral_fees(
+        ReferralFeeSource::PerpMarket,
+        market.perp_market_index,
+        referral_fees,
+    ) {
+        cm!(market.referral_fees_unclaimed += referral_fees)?;
+    }
 
     let perp_position = account.perp_position_mut(market.perp_market_index)?;
     perp_position.record_trading_fee(taker_fees);
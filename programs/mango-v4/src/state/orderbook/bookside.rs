@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use fixed::types::I80F48;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use static_assertions::const_assert_eq;
 
@@ -181,6 +182,18 @@ impl BookSide {
         None
     }
 
+    /// Walk up the book `quantity` units and return the average price in lots an order of
+    /// that size would be filled at. If `quantity` units not on book, return None
+    pub fn impact_price_average(
+        &self,
+        quantity: i64,
+        now_ts: u64,
+        oracle_price_lots: i64,
+    ) -> Option<I80F48> {
+        let amount = self.matched_amount(quantity, now_ts, oracle_price_lots)?;
+        Some(I80F48::from_num(amount) / I80F48::from_num(quantity))
+    }
+
     /// Walk up the book given base units and return the amount in quote lots an order would
     /// be filled at. If not enough liquidity is on book, return None
     pub fn matched_amount(
@@ -21,7 +21,10 @@ mod queue;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::{Group, MangoAccount, MangoAccountValue, PerpMarket, FREE_ORDER_SLOT};
+    use crate::state::{
+        Group, MangoAccount, MangoAccountValue, OracleState, OracleType, PerpMarket,
+        FREE_ORDER_SLOT,
+    };
     use anchor_lang::prelude::*;
     use bytemuck::Zeroable;
     use fixed::types::I80F48;
@@ -700,6 +703,85 @@ mod tests {
         assert_eq!(event_queue.len(), 1);
     }
 
+    // Check that an order sized only in quote terms matches across several price
+    // levels and stops once the quote limit is exhausted
+    #[test]
+    fn book_max_quote_lots_across_levels() {
+        let (mut perp_market, oracle_price, mut event_queue, book_accs) = test_setup(5000.0);
+        let mut book = book_accs.orderbook();
+        let settle_token_index = 0;
+
+        let maker_buffer = MangoAccount::default_for_tests().try_to_vec().unwrap();
+        let mut maker = MangoAccountValue::from_bytes(&maker_buffer).unwrap();
+        maker
+            .ensure_perp_position(perp_market.perp_market_index, settle_token_index)
+            .unwrap();
+        let maker_pk = Pubkey::new_unique();
+
+        let taker_buffer = MangoAccount::default_for_tests().try_to_vec().unwrap();
+        let mut taker = MangoAccountValue::from_bytes(&taker_buffer).unwrap();
+        taker
+            .ensure_perp_position(perp_market.perp_market_index, settle_token_index)
+            .unwrap();
+        let taker_pk = Pubkey::new_unique();
+
+        for price_lots in [5000, 5001, 5002] {
+            book.new_order(
+                Order {
+                    side: Side::Ask,
+                    max_base_lots: 5,
+                    max_quote_lots: i64::MAX,
+                    client_order_id: 0,
+                    time_in_force: 0,
+                    reduce_only: false,
+                    self_trade_behavior: SelfTradeBehavior::DecrementTake,
+                    params: OrderParams::Fixed {
+                        price_lots,
+                        order_type: PostOrderType::Limit,
+                    },
+                },
+                &mut perp_market,
+                &mut event_queue,
+                oracle_price,
+                &mut maker.borrow_mut(),
+                &maker_pk,
+                0, // now_ts
+                u8::MAX,
+            )
+            .unwrap();
+        }
+
+        // Buy all 5 lots at 5000 and 2 lots at 5001; the base limit is irrelevant
+        let max_quote_lots = 5 * 5000 + 2 * 5001 + 100;
+        book.new_order(
+            Order {
+                side: Side::Bid,
+                max_base_lots: i64::MAX,
+                max_quote_lots,
+                client_order_id: 0,
+                time_in_force: 0,
+                reduce_only: false,
+                self_trade_behavior: SelfTradeBehavior::DecrementTake,
+                params: OrderParams::ImmediateOrCancel { price_lots: 5010 },
+            },
+            &mut perp_market,
+            &mut event_queue,
+            oracle_price,
+            &mut taker.borrow_mut(),
+            &taker_pk,
+            0, // now_ts
+            u8::MAX,
+        )
+        .unwrap();
+
+        assert_eq!(event_queue.len(), 2);
+        let taker_pp = taker.perp_position(perp_market.perp_market_index).unwrap();
+        assert_eq!(taker_pp.taker_base_lots, 7);
+        assert_eq!(taker_pp.taker_quote_lots, -(5 * 5000 + 2 * 5001));
+        assert_eq!(taker.perp_next_order_slot().unwrap(), 0);
+        assert_eq!(book.asks.roots[0].leaf_count, 2);
+    }
+
     #[test]
     fn test_self_trade_decrement_take() -> Result<()> {
         // setup market
@@ -1063,4 +1145,105 @@ mod tests {
 
         Ok(())
     }
+
+    fn place_order(
+        book: &mut Orderbook,
+        perp_market: &mut PerpMarket,
+        event_queue: &mut EventQueue,
+        oracle_price: I80F48,
+        side: Side,
+        price_lots: i64,
+        max_base_lots: i64,
+    ) {
+        let buffer = MangoAccount::default_for_tests().try_to_vec().unwrap();
+        let mut account = MangoAccountValue::from_bytes(&buffer).unwrap();
+        account
+            .ensure_perp_position(perp_market.perp_market_index, 0)
+            .unwrap();
+        book.new_order(
+            Order {
+                side,
+                max_base_lots,
+                max_quote_lots: i64::MAX,
+                client_order_id: 0,
+                time_in_force: 0,
+                reduce_only: false,
+                self_trade_behavior: SelfTradeBehavior::DecrementTake,
+                params: OrderParams::Fixed {
+                    price_lots,
+                    order_type: PostOrderType::Limit,
+                },
+            },
+            perp_market,
+            event_queue,
+            oracle_price,
+            &mut account.borrow_mut(),
+            &Pubkey::new_unique(),
+            0,
+            u8::MAX,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_funding_impact_price_average() {
+        let (mut perp_market, oracle_price, mut event_queue, book_accs) = test_setup(100.0);
+        let mut book = book_accs.orderbook();
+        perp_market.impact_quantity = 10;
+        perp_market.min_funding = I80F48::from_num(-0.1);
+        perp_market.max_funding = I80F48::from_num(0.1);
+        perp_market.stable_price_model.reset_to_price(100.0, 0);
+
+        // a thin bid close to the oracle and a deeper one further away
+        for (price_lots, quantity) in [(99, 1), (91, 9)] {
+            place_order(
+                &mut book,
+                &mut perp_market,
+                &mut event_queue,
+                oracle_price,
+                Side::Bid,
+                price_lots,
+                quantity,
+            );
+        }
+
+        let bid = book.bids.impact_price_average(10, 0, 100).unwrap();
+        assert!((bid.to_num::<f64>() - 91.8).abs() < 1e-6);
+        assert!(book.bids.impact_price_average(11, 0, 100).is_none());
+        assert!(book.asks.impact_price_average(10, 0, 100).is_none());
+
+        let oracle_state = OracleState {
+            price: oracle_price,
+            deviation: I80F48::ZERO,
+            last_update_slot: 0,
+            oracle_type: OracleType::Stub,
+        };
+        let one_hour = 3600;
+        let expected_funding = |rate: f64| 100.0 * rate / 24.0;
+
+        // Without a price band, missing asks mean max funding
+        perp_market
+            .update_funding_and_stable_price(&book, &oracle_state, one_hour)
+            .unwrap();
+        assert!((perp_market.long_funding.to_num::<f64>() - expected_funding(0.1)).abs() < 1e-6);
+
+        // With a price band, missing asks count as being at oracle * (1+band):
+        // the book price is (91.8 + 110) / 2 = 100.9
+        perp_market.long_funding = I80F48::ZERO;
+        perp_market.short_funding = I80F48::ZERO;
+        perp_market.oracle_price_band = 0.1;
+        perp_market
+            .update_funding_and_stable_price(&book, &oracle_state, 2 * one_hour)
+            .unwrap();
+        assert!((perp_market.long_funding.to_num::<f64>() - expected_funding(0.009)).abs() < 1e-6);
+
+        // With a two hour funding period, an hour applies half the rate
+        perp_market.long_funding = I80F48::ZERO;
+        perp_market.short_funding = I80F48::ZERO;
+        perp_market.funding_period_seconds = 2 * one_hour as u32;
+        perp_market
+            .update_funding_and_stable_price(&book, &oracle_state, 3 * one_hour)
+            .unwrap();
+        assert!((perp_market.long_funding.to_num::<f64>() - 100.0 * 0.009 / 2.0).abs() < 1e-6);
+    }
 }
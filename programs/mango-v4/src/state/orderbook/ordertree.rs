@@ -631,6 +631,53 @@ mod tests {
         assert!(bids.find_earliest_expiry(&root).is_none());
     }
 
+    #[test]
+    fn order_tree_free_list_reuse() {
+        let mut bids = new_order_tree(OrderTreeType::Bids);
+        let mut root = OrderTreeRoot::zeroed();
+        let new_leaf = |key: u128| {
+            LeafNode::new(
+                0,
+                key,
+                Pubkey::default(),
+                0,
+                0,
+                PostOrderType::Limit,
+                0,
+                -1,
+                0,
+            )
+        };
+
+        // n leaves need 2n-1 nodes: the fixed node pool fits half of it in leaves
+        let mut key = 0;
+        while !bids.is_full() {
+            bids.insert_leaf(&mut root, &new_leaf(key)).unwrap();
+            key += 1;
+        }
+        assert_eq!(key as usize, MAX_ORDERTREE_NODES / 2);
+        assert_eq!(root.leaf_count as usize, MAX_ORDERTREE_NODES / 2);
+        assert_eq!(bids.free_list_len, 0);
+        verify_order_tree(&bids, &root);
+
+        // Removing puts all nodes on the free list
+        for k in 0..key {
+            bids.remove_by_key(&mut root, k).unwrap();
+        }
+        assert_eq!(root.leaf_count, 0);
+        assert_eq!(bids.free_list_len, bids.bump_index);
+        let bump_index = bids.bump_index;
+
+        // Inserting again only uses free list nodes
+        for k in 0..key {
+            bids.insert_leaf(&mut root, &new_leaf(k)).unwrap();
+        }
+        assert_eq!(bids.bump_index, bump_index);
+        assert_eq!(bids.free_list_len, 0);
+        assert!(bids.is_full());
+        verify_order_tree(&bids, &root);
+    }
+
     #[test]
     fn order_tree_expiry_random() {
         use rand::Rng;
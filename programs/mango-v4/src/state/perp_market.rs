@@ -104,9 +104,9 @@ pub struct PerpMarket {
     pub registration_time: u64,
 
     // Funding
-    /// Minimal funding rate per day, must be <= 0.
+    /// Minimal funding rate per funding period, must be <= 0.
     pub min_funding: I80F48,
-    /// Maximal funding rate per day, must be >= 0.
+    /// Maximal funding rate per funding period, must be >= 0.
     pub max_funding: I80F48,
     /// For funding, get the impact price this many base lots deep into the book.
     pub impact_quantity: i64,
@@ -135,7 +135,8 @@ pub struct PerpMarket {
     /// these are increased when new fees are paid and decreased when perp_settle_fees is called
     pub fees_accrued: I80F48,
     /// Fees settled in native quote currency
-    /// these are increased when perp_settle_fees is called, and never decreased
+    /// these are increased when perp_settle_fees is called, and only decreased
+    /// when positive dust perp positions are paid out
     pub fees_settled: I80F48,
 
     /// Fee (in quote native) to charge for ioc orders
@@ -198,8 +199,56 @@ pub struct PerpMarket {
     /// liquidation fees that happened. So never decreases (different to fees_accrued).
     pub accrued_liquidation_fees: I80F48,
 
+    // Crank incentives
+    /// In native units of settlement token, paid out of fees_accrued to the keeper
+    /// for each event processed by perp_consume_events.
+    pub keeper_reward_per_event: f32,
+    /// In native units of settlement token, paid out of fees_accrued to the keeper
+    /// of perp_update_funding. Pro-rated by the time since the last funding update,
+    /// capped at one hour.
+    pub keeper_reward_funding_per_hour: f32,
+
+    /// Orders posted to the book must be bids >= price / (1+band) and asks <= price * (1+band),
+    /// where price is the lower (bids) or higher (asks) of the oracle and stable price.
+    ///
+    /// Protects against orders that sit absurdly far from the market. Zero disables the limit.
+    ///
+    /// Funding also clamps the book prices to oracle / (1+band) and oracle * (1+band).
+    pub oracle_price_band: f32,
+
+    /// The funding rate is the rate paid per this many seconds. Zero means one day.
+    ///
+    /// Lower values make funding more responsive to the book/oracle price difference.
+    pub funding_period_seconds: u32,
+
+    /// Perp positions without base lots and open orders whose quote position is below this
+    /// many native settle token units get settled into the settle token and deactivated
+    /// when fees are settled. Zero disables.
+    pub dust_threshold: f32,
+
     #[derivative(Debug = "ignore")]
-    pub reserved: [u8; 1848],
+    pub padding5: [u8; 4],
+
+    /// Taker fees (native) in fees_accrued that were paid by accounts with a referrer and
+    /// that the referrers haven't claimed their share of yet.
+    pub referral_fees_unclaimed: u64,
+
+    /// The highest ranked profitable long and short positions, the only possible
+    /// counterparties for auto-deleveraging. See perp_update_adl_candidate.
+    pub adl_candidate_long: Pubkey,
+    pub adl_candidate_short: Pubkey,
+    /// Auto-deleveraging rank of the candidates when they were last updated: their
+    /// unsettled pnl divided by their equity
+    pub adl_candidate_long_score: f64,
+    pub adl_candidate_short_score: f64,
+
+    /// Referral fees (native) paid out of settled fees, see account_claim_referral_fees.
+    ///
+    /// Kept apart from fees_withdrawn, the payouts stay in the vault as referrer deposits.
+    pub referral_fees_paid: u64,
+
+    #[derivative(Debug = "ignore")]
+    pub reserved: [u8; 1728],
 }
 
 const_assert_eq!(
@@ -237,12 +286,36 @@ const_assert_eq!(
         + 3 * 16
         + 8
         + 2 * 16
-        + 1848
+        + 4 * 2
+        + 4
+        + 4
+        + 4
+        + 4
+        + 8
+        + 32 * 2
+        + 8 * 2
+        + 8
+        + 1728
 );
 const_assert_eq!(size_of::<PerpMarket>(), 2808);
 const_assert_eq!(size_of::<PerpMarket>() % 8, 0);
 
 impl PerpMarket {
+    /// The PerpMarket PDA for a perp market index in a group.
+    ///
+    /// Perp market indexes are unique per group, so the address can be derived
+    /// without fetching the market, like MintInfo addresses can be for tokens.
+    pub fn address(group: &Pubkey, perp_market_index: PerpMarketIndex) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"PerpMarket".as_ref(),
+                group.as_ref(),
+                perp_market_index.to_le_bytes().as_ref(),
+            ],
+            &crate::id(),
+        )
+    }
+
     pub fn name(&self) -> &str {
         std::str::from_utf8(&self.name)
             .unwrap()
@@ -257,6 +330,49 @@ impl PerpMarket {
         self.force_close == 1
     }
 
+    /// The auto-deleveraging candidate for long (or short) positions and its score
+    pub fn adl_candidate(&self, long: bool) -> (Pubkey, f64) {
+        if long {
+            (self.adl_candidate_long, self.adl_candidate_long_score)
+        } else {
+            (self.adl_candidate_short, self.adl_candidate_short_score)
+        }
+    }
+
+    pub fn set_adl_candidate(&mut self, long: bool, account: Pubkey, score: f64) {
+        if long {
+            self.adl_candidate_long = account;
+            self.adl_candidate_long_score = score;
+        } else {
+            self.adl_candidate_short = account;
+            self.adl_candidate_short_score = score;
+        }
+    }
+
+    /// Settled fees that weren't withdrawn or paid out as referral fees
+    pub fn fees_available(&self) -> I80F48 {
+        self.fees_settled
+            - I80F48::from(self.fees_withdrawn)
+            - I80F48::from(self.referral_fees_paid)
+    }
+
+    /// Clears the adl candidate slots holding `account` if its base position is no longer
+    /// on that slot's side, for example because the position was closed
+    pub fn clear_stale_adl_candidate(&mut self, account: &Pubkey, base_position_lots: i64) {
+        if self.adl_candidate_long == *account && base_position_lots <= 0 {
+            self.set_adl_candidate(true, Pubkey::default(), 0.0);
+        }
+        if self.adl_candidate_short == *account && base_position_lots >= 0 {
+            self.set_adl_candidate(false, Pubkey::default(), 0.0);
+        }
+    }
+
+    /// Whether any position is registered as auto-deleveraging candidate
+    pub fn has_adl_candidate(&self) -> bool {
+        self.adl_candidate_long != Pubkey::default()
+            || self.adl_candidate_short != Pubkey::default()
+    }
+
     pub fn elligible_for_group_insurance_fund(&self) -> bool {
         self.group_insurance_fund == 1
     }
@@ -289,11 +405,11 @@ impl PerpMarket {
     ) -> Result<OracleState> {
         require_keys_eq!(self.oracle, *oracle_acc_infos.oracle.key());
         let state = oracle::oracle_state_unchecked(oracle_acc_infos, self.base_decimals)?;
-        state
-            .check_confidence_and_maybe_staleness(&self.oracle_config, staleness_slot)
-            .with_context(|| {
-                oracle_log_context(self.name(), &state, &self.oracle_config, staleness_slot)
-            })?;
+        let check = state.check_confidence_and_maybe_staleness(&self.oracle_config, staleness_slot);
+        state.log_if_rejected(self.oracle, &check, staleness_slot, false);
+        check.with_context(|| {
+            oracle_log_context(self.name(), &state, &self.oracle_config, staleness_slot)
+        })?;
         Ok(state)
     }
 
@@ -301,6 +417,15 @@ impl PerpMarket {
         I80F48::from_num(self.stable_price_model.stable_price)
     }
 
+    /// Duration in seconds that min_funding, max_funding and the funding rate refer to
+    pub fn funding_period(&self) -> I80F48 {
+        if self.funding_period_seconds == 0 {
+            DAY_I80F48
+        } else {
+            I80F48::from(self.funding_period_seconds)
+        }
+    }
+
     /// Use current order book price and index price to update the instantaneous funding
     pub fn update_funding_and_stable_price(
         &mut self,
@@ -316,24 +441,48 @@ impl PerpMarket {
         let oracle_price_lots = self.native_price_to_lot(oracle_price);
 
         // Get current book price & compare it to index price
-        let bid =
-            book.bookside(Side::Bid)
-                .impact_price(self.impact_quantity, now_ts, oracle_price_lots);
-        let ask =
-            book.bookside(Side::Ask)
-                .impact_price(self.impact_quantity, now_ts, oracle_price_lots);
-
+        //
+        // The book price on each side is the average fill price of an order of impact_quantity,
+        // so that a few lots at the top of the book can't move funding.
+        let impact_quantity = self.impact_quantity.max(1);
+        let bid = book.bookside(Side::Bid).impact_price_average(
+            impact_quantity,
+            now_ts,
+            oracle_price_lots,
+        );
+        let ask = book.bookside(Side::Ask).impact_price_average(
+            impact_quantity,
+            now_ts,
+            oracle_price_lots,
+        );
+
+        let band = self.oracle_price_band();
         let funding_rate = match (bid, ask) {
-            (Some(bid), Some(ask)) => {
+            (None, None) => I80F48::ZERO,
+            (Some(_bid), None) if band == f32::MAX => self.max_funding,
+            (None, Some(_ask)) if band == f32::MAX => self.min_funding,
+            _ => {
+                // Book prices are clamped to the oracle price band and a side without
+                // enough liquidity counts as being at the far edge of the band.
+                let (min_price, max_price) = if band == f32::MAX {
+                    (I80F48::ZERO, I80F48::MAX)
+                } else {
+                    let band_factor = I80F48::from_num(1.0 + band);
+                    (oracle_price / band_factor, oracle_price * band_factor)
+                };
+                let to_native = |price_lots: I80F48| {
+                    (price_lots * I80F48::from_num(self.quote_lot_size)
+                        / I80F48::from_num(self.base_lot_size))
+                    .clamp(min_price, max_price)
+                };
+                let bid_price = bid.map_or(min_price, to_native);
+                let ask_price = ask.map_or(max_price, to_native);
+
                 // calculate mid-market rate
-                let mid_price = (bid + ask) / 2;
-                let book_price = self.lot_to_native_price(mid_price);
+                let book_price = (bid_price + ask_price) / 2;
                 let diff = book_price / oracle_price - I80F48::ONE;
                 diff.clamp(self.min_funding, self.max_funding)
             }
-            (Some(_bid), None) => self.max_funding,
-            (None, Some(_ask)) => self.min_funding,
-            (None, None) => I80F48::ZERO,
         };
 
         // Limit the maximal time interval that funding is applied for. This means we won't use
@@ -344,7 +493,7 @@ impl PerpMarket {
         let diff_ts =
             I80F48::from_num((now_ts - self.funding_last_updated as u64).min(max_funding_timestep));
 
-        let time_factor = diff_ts / DAY_I80F48;
+        let time_factor = diff_ts / self.funding_period();
         let base_lot_size = I80F48::from_num(self.base_lot_size);
 
         // The number of native quote that one base lot should pay in funding
@@ -400,6 +549,35 @@ impl PerpMarket {
         }
     }
 
+    pub fn oracle_price_band(&self) -> f32 {
+        if self.oracle_price_band == 0.0 {
+            f32::MAX // default disabled
+        } else {
+            self.oracle_price_band
+        }
+    }
+
+    /// Is `native_price` within the oracle price band for orders on the `side` of this market?
+    pub fn inside_oracle_price_band(
+        &self,
+        side: Side,
+        native_price: I80F48,
+        oracle_price: I80F48,
+    ) -> bool {
+        let band = self.oracle_price_band();
+        if band == f32::MAX {
+            return true;
+        }
+        let band_factor = 1.0 + band as f64;
+        let price = native_price.to_num::<f64>();
+        let oracle_price = oracle_price.to_num::<f64>();
+        let stable_price = self.stable_price().to_num::<f64>();
+        match side {
+            Side::Bid => price * band_factor >= oracle_price.min(stable_price),
+            Side::Ask => price <= oracle_price.max(stable_price) * band_factor,
+        }
+    }
+
     /// Socialize the loss in this account across all longs and shorts
     ///
     /// `loss` is in settle token native units
@@ -537,7 +715,19 @@ impl PerpMarket {
             fees_withdrawn: 0,
             platform_liquidation_fee: I80F48::ZERO,
             accrued_liquidation_fees: I80F48::ZERO,
-            reserved: [0; 1848],
+            keeper_reward_per_event: 0.0,
+            keeper_reward_funding_per_hour: 0.0,
+            oracle_price_band: 0.0,
+            funding_period_seconds: 0,
+            dust_threshold: 0.0,
+            padding5: Default::default(),
+            referral_fees_unclaimed: 0,
+            adl_candidate_long: Pubkey::default(),
+            adl_candidate_short: Pubkey::default(),
+            adl_candidate_long_score: 0.0,
+            adl_candidate_short_score: 0.0,
+            referral_fees_paid: 0,
+            reserved: [0; 1728],
         }
     }
 }
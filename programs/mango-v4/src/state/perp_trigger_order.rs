@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use static_assertions::const_assert_eq;
+use std::mem::size_of;
+
+use crate::error::*;
+use crate::state::*;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    AnchorDeserialize,
+    AnchorSerialize,
+)]
+#[repr(u8)]
+pub enum PerpTriggerCondition {
+    /// Trigger when the oracle price is at or above the trigger price
+    OraclePriceAbove,
+    /// Trigger when the oracle price is at or below the trigger price
+    OraclePriceBelow,
+}
+
+/// A perp order that anyone can place on behalf of the account once the
+/// oracle price crosses the trigger price, like a stop-loss or take-profit.
+///
+/// The order is placed as ImmediateOrCancel. Whoever executes it receives
+/// the lamports of this account and the executor_incentive as incentives.
+#[account(zero_copy)]
+#[derive(Debug)]
+pub struct PerpTriggerOrder {
+    pub group: Pubkey,
+    pub account: Pubkey,
+
+    /// Chosen by the creator, part of the address seeds
+    pub order_id: u64,
+
+    /// The order can't be executed at or after this time, u64::MAX for never
+    pub expiry_timestamp: u64,
+
+    /// Oracle price at which the order triggers, in quote lots per base lot
+    pub trigger_price_lots: i64,
+
+    /// Worst price the order may fill at, in quote lots per base lot
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots: i64,
+
+    /// Paid from the account's settle token position to the executor, in native tokens
+    pub executor_incentive: u64,
+
+    pub perp_market_index: PerpMarketIndex,
+    pub side: u8,
+    pub trigger_condition: u8,
+    pub reduce_only: u8,
+    pub bump: u8,
+    pub padding: [u8; 2],
+
+    pub reserved: [u8; 56],
+}
+const_assert_eq!(
+    size_of::<PerpTriggerOrder>(),
+    2 * 32 + 8 * 7 + 2 + 1 + 1 + 1 + 1 + 2 + 56
+);
+const_assert_eq!(size_of::<PerpTriggerOrder>(), 184);
+const_assert_eq!(size_of::<PerpTriggerOrder>() % 8, 0);
+
+impl PerpTriggerOrder {
+    pub fn side(&self) -> Side {
+        Side::try_from(self.side).unwrap()
+    }
+
+    pub fn trigger_condition(&self) -> PerpTriggerCondition {
+        PerpTriggerCondition::try_from(self.trigger_condition).unwrap()
+    }
+
+    pub fn is_reduce_only(&self) -> bool {
+        self.reduce_only == 1
+    }
+
+    pub fn is_expired(&self, now_ts: u64) -> bool {
+        now_ts >= self.expiry_timestamp
+    }
+
+    pub fn is_triggered(&self, oracle_price_lots: i64) -> bool {
+        match self.trigger_condition() {
+            PerpTriggerCondition::OraclePriceAbove => oracle_price_lots >= self.trigger_price_lots,
+            PerpTriggerCondition::OraclePriceBelow => oracle_price_lots <= self.trigger_price_lots,
+        }
+    }
+
+    pub fn check_executable(&self, oracle_price_lots: i64, now_ts: u64) -> Result<()> {
+        require!(
+            !self.is_expired(now_ts),
+            MangoError::PerpTriggerOrderExpired
+        );
+        require_msg_typed!(
+            self.is_triggered(oracle_price_lots),
+            MangoError::PerpTriggerOrderNotTriggered,
+            "oracle price {} lots, trigger price {} lots",
+            oracle_price_lots,
+            self.trigger_price_lots
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    #[test]
+    fn test_check_executable() {
+        let mut order = PerpTriggerOrder::zeroed();
+        order.expiry_timestamp = 100;
+        order.trigger_price_lots = 50;
+
+        order.trigger_condition = PerpTriggerCondition::OraclePriceBelow.into();
+        assert!(order.check_executable(49, 0).is_ok());
+        assert!(order.check_executable(50, 0).is_ok());
+        assert!(order.check_executable(51, 0).is_err());
+        assert!(order.check_executable(49, 100).is_err());
+
+        order.trigger_condition = PerpTriggerCondition::OraclePriceAbove.into();
+        assert!(order.check_executable(49, 0).is_err());
+        assert!(order.check_executable(50, 0).is_ok());
+        assert!(order.check_executable(51, 99).is_ok());
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+use crate::{accounts_zerocopy::KeyedAccountReader, error::MangoError};
+
+pub mod pyth_receiver_program {
+    use solana_program::declare_id;
+    declare_id!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+}
+
+pub const PYTH_PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// The parts of a pyth-solana-receiver PriceUpdateV2 account that are needed for pricing.
+///
+/// Layout: discriminator, write_authority, verification_level (borsh enum, one byte
+/// for Full and two for Partial), price_message, posted_slot.
+pub struct PriceUpdateV2State {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub posted_slot: u64,
+}
+
+pub fn load_pyth_price_update_v2(acc_info: &impl KeyedAccountReader) -> Result<PriceUpdateV2State> {
+    let data = &acc_info.data();
+    require!(
+        data.len() >= 8 && data[0..8] == PYTH_PRICE_UPDATE_V2_DISCRIMINATOR[..],
+        MangoError::InvalidPythPriceUpdate
+    );
+    require!(
+        acc_info.owner() == &pyth_receiver_program::ID,
+        MangoError::InvalidPythPriceUpdate
+    );
+
+    // Only accept updates where all guardian signatures were verified
+    let message_start = match data.get(40) {
+        Some(0) => {
+            return Err(error_msg_typed!(
+                MangoError::InvalidPythPriceUpdate,
+                "pyth price update is only partially verified"
+            ))
+        }
+        Some(1) => 41,
+        _ => return Err(MangoError::InvalidPythPriceUpdate.into()),
+    };
+    // feed_id, price, conf, exponent, publish_time, prev_publish_time, ema_price, ema_conf
+    let message_len = 32 + 8 + 8 + 4 + 8 + 8 + 8 + 8;
+    require!(
+        data.len() >= message_start + message_len + 8,
+        MangoError::InvalidPythPriceUpdate
+    );
+    let message = &data[message_start..message_start + message_len];
+    let posted_slot_start = message_start + message_len;
+
+    Ok(PriceUpdateV2State {
+        feed_id: message[0..32].try_into().unwrap(),
+        price: i64::from_le_bytes(message[32..40].try_into().unwrap()),
+        conf: u64::from_le_bytes(message[40..48].try_into().unwrap()),
+        exponent: i32::from_le_bytes(message[48..52].try_into().unwrap()),
+        publish_time: i64::from_le_bytes(message[52..60].try_into().unwrap()),
+        posted_slot: u64::from_le_bytes(
+            data[posted_slot_start..posted_slot_start + 8]
+                .try_into()
+                .unwrap(),
+        ),
+    })
+}
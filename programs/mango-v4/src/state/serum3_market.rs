@@ -36,11 +36,21 @@ pub struct Serum3Market {
 
     pub registration_time: u64,
 
-    pub reserved: [u8; 128],
+    /// Referrer rebates claimed into the quote bank's collected fees during settle, in
+    /// native quote tokens. Only counts settles that send the rebates to the DAO.
+    pub collected_referrer_rebates: u64,
+
+    /// Orders must be bids <= oracle * (1+band) and asks >= oracle * (1-band)
+    ///
+    /// Protects against orders that cross the oracle price by a lot, like fat-fingered
+    /// prices or self-trades against a thin book. Zero disables the limit.
+    pub crossing_price_band: f32,
+
+    pub reserved: [u8; 116],
 }
 const_assert_eq!(
     size_of::<Serum3Market>(),
-    32 + 2 + 2 + 1 + 3 + 16 + 2 * 32 + 2 + 1 + 1 + 4 + 8 + 128
+    32 + 2 + 2 + 1 + 3 + 16 + 2 * 32 + 2 + 1 + 1 + 4 + 8 + 8 + 4 + 116
 );
 const_assert_eq!(size_of::<Serum3Market>(), 264);
 const_assert_eq!(size_of::<Serum3Market>() % 8, 0);
@@ -67,6 +77,14 @@ impl Serum3Market {
             self.oracle_price_band
         }
     }
+
+    pub fn crossing_price_band(&self) -> f32 {
+        if self.crossing_price_band == 0.0 {
+            f32::MAX // default disabled
+        } else {
+            self.crossing_price_band
+        }
+    }
 }
 
 #[account(zero_copy)]
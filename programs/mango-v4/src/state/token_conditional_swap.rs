@@ -378,3 +378,92 @@ impl TokenConditionalSwap {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tcs(tcs_type: TokenConditionalSwapType) -> TokenConditionalSwap {
+        TokenConditionalSwap {
+            is_configured: 1,
+            max_buy: 100,
+            max_sell: 100,
+            expiry_timestamp: 1000,
+            price_lower_limit: 1.0,
+            price_upper_limit: 2.0,
+            price_premium_rate: 0.1,
+            tcs_type: tcs_type.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fixed_premium_triggerable() {
+        let tcs = tcs(TokenConditionalSwapType::FixedPremium);
+
+        assert!(tcs.is_triggerable(1.0, 0));
+        assert!(tcs.is_triggerable(1.5, 0));
+        assert!(tcs.is_triggerable(2.0, 0));
+        assert!(!tcs.is_triggerable(0.99, 0));
+        assert!(!tcs.is_triggerable(2.01, 0));
+        assert!(!tcs.is_triggerable(1.5, 1000));
+
+        // never needs starting
+        assert!(!tcs.is_startable(1.5, 0));
+
+        assert!((tcs.premium_price(1.5, 0) - 1.65).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_premium_auction_start() {
+        let mut tcs = tcs(TokenConditionalSwapType::PremiumAuction);
+        tcs.duration_seconds = 100;
+
+        // not triggerable until started, even when the price is in range
+        assert!(!tcs.is_triggerable(1.5, 10));
+        assert!(tcs.is_startable(1.5, 10));
+        assert!(!tcs.is_startable(2.5, 10));
+        assert!(!tcs.is_startable(1.5, 1000));
+
+        tcs.start_timestamp = 10;
+        assert!(!tcs.is_startable(1.5, 20));
+        // once started, the oracle price no longer matters
+        assert!(tcs.is_triggerable(2.5, 20));
+
+        // premium grows linearly over the duration and then stays
+        assert!((tcs.premium_price(1.0, 10) - 1.0).abs() < 1e-9);
+        assert!((tcs.premium_price(1.0, 60) - 1.05).abs() < 1e-9);
+        assert!((tcs.premium_price(1.0, 110) - 1.1).abs() < 1e-9);
+        assert!((tcs.premium_price(1.0, 500) - 1.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_linear_auction_price() {
+        let mut tcs = tcs(TokenConditionalSwapType::LinearAuction);
+        tcs.start_timestamp = 100;
+        tcs.duration_seconds = 100;
+
+        assert!(!tcs.is_triggerable(1.5, 99));
+        assert!(tcs.is_triggerable(5.0, 100));
+        // not startable via instruction, the start time is fixed
+        assert!(!tcs.is_startable(1.5, 50));
+
+        assert_eq!(tcs.premium_price(0.0, 100), 1.0);
+        assert!((tcs.premium_price(0.0, 150) - 1.5).abs() < 1e-9);
+        assert_eq!(tcs.premium_price(0.0, 200), 2.0);
+        assert_eq!(tcs.premium_price(0.0, 300), 2.0);
+    }
+
+    #[test]
+    fn test_fees() {
+        let mut tcs = tcs(TokenConditionalSwapType::FixedPremium);
+        tcs.maker_fee_rate = 0.015625;
+        tcs.taker_fee_rate = 0.03125;
+
+        assert_eq!(tcs.maker_price(1.0), 1.015625);
+        assert_eq!(tcs.taker_price(1.0), 0.96875);
+        // rounded down
+        assert_eq!(tcs.maker_fee(I80F48::from(1000)), 15);
+        assert_eq!(tcs.taker_fee(I80F48::from(1000)), 31);
+    }
+}
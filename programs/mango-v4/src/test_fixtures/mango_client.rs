@@ -1,15 +1,16 @@
 #![allow(dead_code)]
 
+use crate::accounts_ix::{
+    InterestRateParams, PerpPlaceOrderArgs, Serum3OrderType, Serum3PlaceOrderArgs,
+    Serum3SelfTradeBehavior, Serum3Side,
+};
+use crate::state::{MangoAccount, MangoAccountValue};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::{self, SysvarId};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Token, TokenAccount};
 use fixed::types::I80F48;
 use itertools::Itertools;
-use mango_v4::accounts_ix::{
-    InterestRateParams, Serum3OrderType, Serum3SelfTradeBehavior, Serum3Side,
-};
-use mango_v4::state::{MangoAccount, MangoAccountValue};
 use solana_program::instruction::Instruction;
 use solana_program_test::{BanksClientError, BanksTransactionResultWithMetadata};
 use solana_sdk::instruction;
@@ -18,7 +19,7 @@ use std::sync::Arc;
 
 use super::solana::SolanaCookie;
 use super::utils::TestKeypair;
-use mango_v4::state::*;
+use crate::state::*;
 
 #[async_trait::async_trait(?Send)]
 pub trait ClientAccountLoader {
@@ -149,7 +150,7 @@ impl<'a> ClientTransaction {
 
     pub async fn send_expect_error(
         &self,
-        error: mango_v4::error::MangoError,
+        error: crate::error::MangoError,
     ) -> std::result::Result<(), BanksClientError> {
         let tx_result = self
             .solana
@@ -214,7 +215,7 @@ async fn get_mint_info_by_mint(
             account.fixed.group.as_ref(),
             mint.as_ref(),
         ],
-        &mango_v4::id(),
+        &crate::id(),
     )
     .0;
     account_loader.load(&mint_info_pk).await.unwrap()
@@ -232,7 +233,7 @@ async fn get_mint_info_by_token_index(
             &token_index.to_le_bytes(),
             &0u32.to_le_bytes(),
         ],
-        &mango_v4::id(),
+        &crate::id(),
     )
     .0;
     let bank: Bank = account_loader.load(&bank_pk).await.unwrap();
@@ -240,15 +241,7 @@ async fn get_mint_info_by_token_index(
 }
 
 fn get_perp_market_address_by_index(group: Pubkey, perp_market_index: PerpMarketIndex) -> Pubkey {
-    Pubkey::find_program_address(
-        &[
-            b"PerpMarket".as_ref(),
-            group.as_ref(),
-            &perp_market_index.to_le_bytes(),
-        ],
-        &mango_v4::id(),
-    )
-    .0
+    PerpMarket::address(&group, perp_market_index).0
 }
 
 async fn get_oracle_address_from_perp_market_address(
@@ -438,7 +431,7 @@ pub async fn account_init_health(solana: &SolanaCookie, account: Pubkey) -> f64
         .await
         .unwrap();
     let health_data = solana
-        .program_log_events::<mango_v4::events::MangoAccountData>()
+        .program_log_events::<crate::events::MangoAccountData>()
         .pop()
         .unwrap();
     health_data.init_health.to_num::<f64>()
@@ -449,7 +442,7 @@ pub async fn account_maint_health(solana: &SolanaCookie, account: Pubkey) -> f64
         .await
         .unwrap();
     let health_data = solana
-        .program_log_events::<mango_v4::events::MangoAccountData>()
+        .program_log_events::<crate::events::MangoAccountData>()
         .pop()
         .unwrap();
     health_data.maint_health.to_num::<f64>()
@@ -465,15 +458,29 @@ pub async fn check_prev_instruction_post_health(solana: &SolanaCookie, account:
         .unwrap();
     let post_health = post_health_str.parse::<f64>().unwrap();
 
+    let health_log = solana
+        .program_log_events::<crate::logs::HealthLog>()
+        .pop()
+        .unwrap();
+    assert_eq!(health_log.mango_account, account);
+    assert_eq!(
+        I80F48::from_bits(health_log.init_health).to_num::<f64>(),
+        post_health
+    );
+
     send_tx(solana, ComputeAccountDataInstruction { account })
         .await
         .unwrap();
 
     let health_data = solana
-        .program_log_events::<mango_v4::events::MangoAccountData>()
+        .program_log_events::<crate::events::MangoAccountData>()
         .pop()
         .unwrap();
     assert_eq!(health_data.init_health.to_num::<f64>(), post_health);
+    assert_eq!(
+        health_data.maint_health,
+        I80F48::from_bits(health_log.maint_health)
+    );
 }
 
 pub async fn set_bank_stub_oracle_price(
@@ -548,13 +555,13 @@ pub struct FlashLoanBeginInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for FlashLoanBeginInstruction {
-    type Accounts = mango_v4::accounts::FlashLoanBegin;
-    type Instruction = mango_v4::instruction::FlashLoanBegin;
+    type Accounts = crate::accounts::FlashLoanBegin;
+    type Instruction = crate::instruction::FlashLoanBegin;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let account = account_loader
             .load_mango_account(&self.account)
@@ -622,13 +629,13 @@ pub struct FlashLoanSwapBeginInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for FlashLoanSwapBeginInstruction {
-    type Accounts = mango_v4::accounts::FlashLoanSwapBegin;
-    type Instruction = mango_v4::instruction::FlashLoanSwapBegin;
+    type Accounts = crate::accounts::FlashLoanSwapBegin;
+    type Instruction = crate::instruction::FlashLoanSwapBegin;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let account = account_loader
             .load_mango_account(&self.account)
@@ -706,21 +713,93 @@ impl ClientInstruction for FlashLoanSwapBeginInstruction {
     }
 }
 
+pub struct MarginTradeInstruction {
+    pub account: Pubkey,
+    pub owner: TestKeypair,
+    /// Banks whose vaults are used by the swap instruction
+    pub banks: Vec<Pubkey>,
+    pub swap_instruction: instruction::Instruction,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for MarginTradeInstruction {
+    type Accounts = crate::accounts::MarginTrade;
+    type Instruction = crate::instruction::MarginTrade;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+
+        let mut account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        for bank in self.banks.iter() {
+            let bank: Bank = account_loader.load(bank).await.unwrap();
+            account.ensure_token_position(bank.token_index).unwrap();
+        }
+
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            true,
+            None,
+        )
+        .await;
+
+        let instruction = Self::Instruction {
+            num_health_accounts: health_check_metas.len() as u8,
+            cpi_data: self.swap_instruction.data.clone(),
+        };
+
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+            owner: self.owner.pubkey(),
+            swap_program: self.swap_instruction.program_id,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas.into_iter());
+        // the group signs via cpi, the owner signs the transaction
+        let owner = self.owner.pubkey();
+        instruction
+            .accounts
+            .extend(
+                self.swap_instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| AccountMeta {
+                        pubkey: meta.pubkey,
+                        is_writable: meta.is_writable,
+                        is_signer: meta.is_signer && meta.pubkey == owner,
+                    }),
+            );
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
 pub struct FlashLoanEndInstruction {
     pub account: Pubkey,
     pub owner: TestKeypair,
     pub loans: Vec<FlashLoanPart>,
-    pub flash_loan_type: mango_v4::accounts_ix::FlashLoanType,
+    pub flash_loan_type: crate::accounts_ix::FlashLoanType,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for FlashLoanEndInstruction {
-    type Accounts = mango_v4::accounts::FlashLoanEnd;
-    type Instruction = mango_v4::instruction::FlashLoanEndV2;
+    type Accounts = crate::accounts::FlashLoanEnd;
+    type Instruction = crate::instruction::FlashLoanEndV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             num_loans: self.loans.len() as u8,
             flash_loan_type: self.flash_loan_type,
@@ -793,13 +872,13 @@ pub struct TokenWithdrawInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenWithdrawInstruction {
-    type Accounts = mango_v4::accounts::TokenWithdraw;
-    type Instruction = mango_v4::instruction::TokenWithdraw;
+    type Accounts = crate::accounts::TokenWithdraw;
+    type Instruction = crate::instruction::TokenWithdraw;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             amount: self.amount,
             allow_borrow: self.allow_borrow,
@@ -865,13 +944,13 @@ pub struct TokenDepositInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenDepositInstruction {
-    type Accounts = mango_v4::accounts::TokenDeposit;
-    type Instruction = mango_v4::instruction::TokenDeposit;
+    type Accounts = crate::accounts::TokenDeposit;
+    type Instruction = crate::instruction::TokenDeposit;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             amount: self.amount,
             reduce_only: self.reduce_only,
@@ -926,6 +1005,42 @@ impl ClientInstruction for TokenDepositInstruction {
     }
 }
 
+/// Invokes token_deposit via CPI from the cpi_forward program
+pub struct TokenDepositViaCpiInstruction {
+    pub cpi_program: Pubkey,
+    pub deposit: TokenDepositInstruction,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for TokenDepositViaCpiInstruction {
+    type Accounts = crate::accounts::TokenDeposit;
+    type Instruction = crate::instruction::TokenDeposit;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let (accounts, deposit_instruction) = self.deposit.to_instruction(account_loader).await;
+
+        let mut instruction = instruction::Instruction {
+            program_id: self.cpi_program,
+            accounts: vec![AccountMeta {
+                pubkey: deposit_instruction.program_id,
+                is_writable: false,
+                is_signer: false,
+            }],
+            data: deposit_instruction.data,
+        };
+        instruction
+            .accounts
+            .extend(deposit_instruction.accounts.into_iter());
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        self.deposit.signers()
+    }
+}
+
 pub struct TokenDepositIntoExistingInstruction {
     pub amount: u64,
     pub reduce_only: bool,
@@ -936,13 +1051,13 @@ pub struct TokenDepositIntoExistingInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenDepositIntoExistingInstruction {
-    type Accounts = mango_v4::accounts::TokenDepositIntoExisting;
-    type Instruction = mango_v4::instruction::TokenDepositIntoExisting;
+    type Accounts = crate::accounts::TokenDepositIntoExisting;
+    type Instruction = crate::instruction::TokenDepositIntoExisting;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             amount: self.amount,
             reduce_only: self.reduce_only,
@@ -1026,13 +1141,13 @@ pub struct TokenRegisterInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenRegisterInstruction {
-    type Accounts = mango_v4::accounts::TokenRegister;
-    type Instruction = mango_v4::instruction::TokenRegister;
+    type Accounts = crate::accounts::TokenRegister;
+    type Instruction = crate::instruction::TokenRegister;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             name: format!(
                 "{}{}",
@@ -1134,6 +1249,95 @@ impl ClientInstruction for TokenRegisterInstruction {
     }
 }
 
+pub struct TokenRegisterTrustlessInstruction {
+    pub token_index: TokenIndex,
+    pub tier: crate::accounts_ix::FastListingTier,
+
+    pub group: Pubkey,
+    pub admin: TestKeypair,
+    pub mint: Pubkey,
+    pub oracle: Pubkey,
+    pub payer: TestKeypair,
+
+    /// Group-owned lookup table that should be extended with the new accounts
+    pub address_lookup_table: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for TokenRegisterTrustlessInstruction {
+    type Accounts = crate::accounts::TokenRegisterTrustless;
+    type Instruction = crate::instruction::TokenRegisterTrustless;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            token_index: self.token_index,
+            name: "some_ticker".to_string(),
+            tier: self.tier,
+        };
+
+        let bank = Pubkey::find_program_address(
+            &[
+                b"Bank".as_ref(),
+                self.group.as_ref(),
+                &self.token_index.to_le_bytes(),
+                &0u32.to_le_bytes(),
+            ],
+            &program_id,
+        )
+        .0;
+        let vault = Pubkey::find_program_address(
+            &[
+                b"Vault".as_ref(),
+                self.group.as_ref(),
+                &self.token_index.to_le_bytes(),
+                &0u32.to_le_bytes(),
+            ],
+            &program_id,
+        )
+        .0;
+        let mint_info = Pubkey::find_program_address(
+            &[
+                b"MintInfo".as_ref(),
+                self.group.as_ref(),
+                self.mint.as_ref(),
+            ],
+            &program_id,
+        )
+        .0;
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            admin: self.admin.pubkey(),
+            mint: self.mint,
+            bank,
+            vault,
+            mint_info,
+            oracle: self.oracle,
+            fallback_oracle: Pubkey::default(),
+            payer: self.payer.pubkey(),
+            token_program: Token::id(),
+            system_program: System::id(),
+            rent: sysvar::rent::Rent::id(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        if let Some(address_lookup_table) = self.address_lookup_table {
+            instruction.accounts.push(AccountMeta {
+                pubkey: address_lookup_table,
+                is_writable: true,
+                is_signer: false,
+            });
+        }
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin, self.payer]
+    }
+}
+
 pub struct TokenAddBankInstruction {
     pub token_index: TokenIndex,
     pub bank_num: u32,
@@ -1144,13 +1348,13 @@ pub struct TokenAddBankInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenAddBankInstruction {
-    type Accounts = mango_v4::accounts::TokenAddBank;
-    type Instruction = mango_v4::instruction::TokenAddBank;
+    type Accounts = crate::accounts::TokenAddBank;
+    type Instruction = crate::instruction::TokenAddBank;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             token_index: self.token_index,
             bank_num: self.bank_num,
@@ -1232,14 +1436,14 @@ pub struct TokenDeregisterInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenDeregisterInstruction {
-    type Accounts = mango_v4::accounts::TokenDeregister;
-    type Instruction = mango_v4::instruction::TokenDeregister;
+    type Accounts = crate::accounts::TokenDeregister;
+    type Instruction = crate::instruction::TokenDeregister;
 
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let accounts = Self::Accounts {
@@ -1284,8 +1488,8 @@ impl ClientInstruction for TokenDeregisterInstruction {
     }
 }
 
-pub fn token_edit_instruction_default() -> mango_v4::instruction::TokenEdit {
-    mango_v4::instruction::TokenEdit {
+pub fn token_edit_instruction_default() -> crate::instruction::TokenEdit {
+    crate::instruction::TokenEdit {
         oracle_opt: None,
         oracle_config_opt: None,
         group_insurance_fund_opt: None,
@@ -1324,6 +1528,13 @@ pub fn token_edit_instruction_default() -> mango_v4::instruction::TokenEdit {
         deposit_limit_opt: None,
         zero_util_rate_opt: None,
         platform_liquidation_fee_opt: None,
+        keeper_reward_per_hour_opt: None,
+        fallback_oracle_max_stable_price_deviation_opt: None,
+        fallback_oracle_min_liquidity_opt: None,
+        halt_on_vault_invariant_violation_opt: None,
+        collateral_only_opt: None,
+        interest_rate_shift_hours_opt: None,
+        oracle_twap_window_seconds_opt: None,
     }
 }
 
@@ -1332,17 +1543,17 @@ pub struct TokenEdit {
     pub admin: TestKeypair,
     pub mint: Pubkey,
     pub fallback_oracle: Pubkey,
-    pub options: mango_v4::instruction::TokenEdit,
+    pub options: crate::instruction::TokenEdit,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenEdit {
-    type Accounts = mango_v4::accounts::TokenEdit;
-    type Instruction = mango_v4::instruction::TokenEdit;
+    type Accounts = crate::accounts::TokenEdit;
+    type Instruction = crate::instruction::TokenEdit;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let mint_info_key = Pubkey::find_program_address(
             &[
@@ -1392,13 +1603,13 @@ pub struct TokenEditWeights {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenEditWeights {
-    type Accounts = mango_v4::accounts::TokenEdit;
-    type Instruction = mango_v4::instruction::TokenEdit;
+    type Accounts = crate::accounts::TokenEdit;
+    type Instruction = crate::instruction::TokenEdit;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let mint_info_key = Pubkey::find_program_address(
             &[
@@ -1451,13 +1662,13 @@ pub struct TokenResetStablePriceModel {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenResetStablePriceModel {
-    type Accounts = mango_v4::accounts::TokenEdit;
-    type Instruction = mango_v4::instruction::TokenEdit;
+    type Accounts = crate::accounts::TokenEdit;
+    type Instruction = crate::instruction::TokenEdit;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let mint_info_key = Pubkey::find_program_address(
             &[
@@ -1511,13 +1722,13 @@ pub struct TokenResetNetBorrows {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenResetNetBorrows {
-    type Accounts = mango_v4::accounts::TokenEdit;
-    type Instruction = mango_v4::instruction::TokenEdit;
+    type Accounts = crate::accounts::TokenEdit;
+    type Instruction = crate::instruction::TokenEdit;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let mint_info_key = Pubkey::find_program_address(
             &[
@@ -1572,13 +1783,13 @@ pub struct TokenMakeReduceOnly {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenMakeReduceOnly {
-    type Accounts = mango_v4::accounts::TokenEdit;
-    type Instruction = mango_v4::instruction::TokenEdit;
+    type Accounts = crate::accounts::TokenEdit;
+    type Instruction = crate::instruction::TokenEdit;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let mint_info_key = Pubkey::find_program_address(
             &[
@@ -1630,14 +1841,14 @@ pub struct StubOracleSetInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for StubOracleSetInstruction {
-    type Accounts = mango_v4::accounts::StubOracleSet;
-    type Instruction = mango_v4::instruction::StubOracleSet;
+    type Accounts = crate::accounts::StubOracleSet;
+    type Instruction = crate::instruction::StubOracleSet;
 
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             price: I80F48::from_num(self.price),
         };
@@ -1668,14 +1879,14 @@ pub struct StubOracleSetTestInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for StubOracleSetTestInstruction {
-    type Accounts = mango_v4::accounts::StubOracleSet;
-    type Instruction = mango_v4::instruction::StubOracleSetTest;
+    type Accounts = crate::accounts::StubOracleSet;
+    type Instruction = crate::instruction::StubOracleSetTest;
 
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             price: I80F48::from_num(self.price),
             last_update_slot: self.last_update_slot,
@@ -1706,14 +1917,14 @@ pub struct StubOracleCreate {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for StubOracleCreate {
-    type Accounts = mango_v4::accounts::StubOracleCreate;
-    type Instruction = mango_v4::instruction::StubOracleCreate;
+    type Accounts = crate::accounts::StubOracleCreate;
+    type Instruction = crate::instruction::StubOracleCreate;
 
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             price: I80F48::from_num(1.0),
         };
@@ -1745,14 +1956,14 @@ pub struct StubOracleCloseInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for StubOracleCloseInstruction {
-    type Accounts = mango_v4::accounts::StubOracleClose;
-    type Instruction = mango_v4::instruction::StubOracleClose;
+    type Accounts = crate::accounts::StubOracleClose;
+    type Instruction = crate::instruction::StubOracleClose;
 
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let accounts = Self::Accounts {
@@ -1779,13 +1990,13 @@ pub struct GroupCreateInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for GroupCreateInstruction {
-    type Accounts = mango_v4::accounts::GroupCreate;
-    type Instruction = mango_v4::instruction::GroupCreate;
+    type Accounts = crate::accounts::GroupCreate;
+    type Instruction = crate::instruction::GroupCreate;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             group_num: 0,
             testing: 1,
@@ -1828,8 +2039,8 @@ impl ClientInstruction for GroupCreateInstruction {
     }
 }
 
-pub fn group_edit_instruction_default() -> mango_v4::instruction::GroupEdit {
-    mango_v4::instruction::GroupEdit {
+pub fn group_edit_instruction_default() -> crate::instruction::GroupEdit {
+    crate::instruction::GroupEdit {
         admin_opt: None,
         fast_listing_admin_opt: None,
         security_admin_opt: None,
@@ -1842,6 +2053,10 @@ pub fn group_edit_instruction_default() -> mango_v4::instruction::GroupEdit {
         mngo_token_index_opt: None,
         buyback_fees_expiry_interval_opt: None,
         allowed_fast_listings_per_interval_opt: None,
+        allowed_swap_programs_opt: None,
+        referral_fee_share_opt: None,
+        insurance_token_index_opt: None,
+        allow_cpi_deposit_opt: None,
     }
 }
 
@@ -1854,13 +2069,13 @@ pub struct GroupEditFeeParameters {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for GroupEditFeeParameters {
-    type Accounts = mango_v4::accounts::GroupEdit;
-    type Instruction = mango_v4::instruction::GroupEdit;
+    type Accounts = crate::accounts::GroupEdit;
+    type Instruction = crate::instruction::GroupEdit;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             buyback_fees_opt: Some(true),
             buyback_fees_bonus_factor_opt: Some(self.fees_mngo_bonus_factor),
@@ -1886,17 +2101,17 @@ impl ClientInstruction for GroupEditFeeParameters {
 pub struct GroupEdit {
     pub group: Pubkey,
     pub admin: TestKeypair,
-    pub options: mango_v4::instruction::GroupEdit,
+    pub options: crate::instruction::GroupEdit,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for GroupEdit {
-    type Accounts = mango_v4::accounts::GroupEdit;
-    type Instruction = mango_v4::instruction::GroupEdit;
+    type Accounts = crate::accounts::GroupEdit;
+    type Instruction = crate::instruction::GroupEdit;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = &self.options;
 
         let accounts = Self::Accounts {
@@ -1904,7 +2119,24 @@ impl ClientInstruction for GroupEdit {
             admin: self.admin.pubkey(),
         };
 
-        let instruction = make_instruction(program_id, &accounts, instruction);
+        let mut instruction = make_instruction(program_id, &accounts, instruction);
+        if let Some(insurance_token_index) = self.options.insurance_token_index_opt {
+            let bank = Pubkey::find_program_address(
+                &[
+                    b"Bank".as_ref(),
+                    self.group.as_ref(),
+                    &insurance_token_index.to_le_bytes(),
+                    &0u32.to_le_bytes(),
+                ],
+                &program_id,
+            )
+            .0;
+            instruction.accounts.push(AccountMeta {
+                pubkey: bank,
+                is_writable: false,
+                is_signer: false,
+            });
+        }
         (accounts, instruction)
     }
 
@@ -1913,27 +2145,33 @@ impl ClientInstruction for GroupEdit {
     }
 }
 
-pub struct IxGateSetInstruction {
+pub struct GroupDepositInsuranceFundInstruction {
     pub group: Pubkey,
-    pub admin: TestKeypair,
-    pub ix_gate: u128,
+    pub token_account: Pubkey,
+    pub token_authority: TestKeypair,
+    pub amount: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for IxGateSetInstruction {
-    type Accounts = mango_v4::accounts::IxGateSet;
-    type Instruction = mango_v4::instruction::IxGateSet;
+impl ClientInstruction for GroupDepositInsuranceFundInstruction {
+    type Accounts = crate::accounts::GroupDepositInsuranceFund;
+    type Instruction = crate::instruction::GroupDepositInsuranceFund;
     async fn to_instruction(
         &self,
-        _account_loader: impl ClientAccountLoader + 'async_trait,
+        account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
-            ix_gate: self.ix_gate,
+            amount: self.amount,
         };
 
+        let group: Group = account_loader.load(&self.group).await.unwrap();
+
         let accounts = Self::Accounts {
             group: self.group,
-            admin: self.admin.pubkey(),
+            insurance_vault: group.insurance_vault,
+            token_account: self.token_account,
+            token_authority: self.token_authority.pubkey(),
+            token_program: Token::id(),
         };
 
         let instruction = make_instruction(program_id, &accounts, &instruction);
@@ -1941,37 +2179,36 @@ impl ClientInstruction for IxGateSetInstruction {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.admin]
+        vec![self.token_authority]
     }
 }
 
-pub struct GroupCloseInstruction {
-    pub admin: TestKeypair,
+pub struct GroupWithdrawInsuranceFundInstruction {
     pub group: Pubkey,
-    pub sol_destination: Pubkey,
+    pub admin: TestKeypair,
+    pub destination: Pubkey,
+    pub amount: u64,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for GroupCloseInstruction {
-    type Accounts = mango_v4::accounts::GroupClose;
-    type Instruction = mango_v4::instruction::GroupClose;
+impl ClientInstruction for GroupWithdrawInsuranceFundInstruction {
+    type Accounts = crate::accounts::GroupWithdrawInsuranceFund;
+    type Instruction = crate::instruction::GroupWithdrawInsuranceFund;
     async fn to_instruction(
         &self,
-        _account_loader: impl ClientAccountLoader + 'async_trait,
+        account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
-        let instruction = Self::Instruction {};
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            amount: self.amount,
+        };
 
-        let insurance_vault = Pubkey::find_program_address(
-            &[b"InsuranceVault".as_ref(), self.group.as_ref()],
-            &program_id,
-        )
-        .0;
+        let group: Group = account_loader.load(&self.group).await.unwrap();
 
         let accounts = Self::Accounts {
             group: self.group,
             admin: self.admin.pubkey(),
-            insurance_vault,
-            sol_destination: self.sol_destination,
+            insurance_vault: group.insurance_vault,
+            destination: self.destination,
             token_program: Token::id(),
         };
 
@@ -1984,68 +2221,27 @@ impl ClientInstruction for GroupCloseInstruction {
     }
 }
 
-pub struct AccountCreateInstruction {
-    pub account_num: u32,
-    pub token_count: u8,
-    pub serum3_count: u8,
-    pub perp_count: u8,
-    pub perp_oo_count: u8,
-    pub token_conditional_swap_count: u8,
+pub struct IxGateSetInstruction {
     pub group: Pubkey,
-    pub owner: TestKeypair,
-    pub payer: TestKeypair,
-}
-impl Default for AccountCreateInstruction {
-    fn default() -> Self {
-        AccountCreateInstruction {
-            account_num: 0,
-            token_count: 8,
-            serum3_count: 4,
-            perp_count: 4,
-            perp_oo_count: 16,
-            token_conditional_swap_count: 1,
-            group: Default::default(),
-            owner: Default::default(),
-            payer: Default::default(),
-        }
-    }
+    pub admin: TestKeypair,
+    pub ix_gate: u128,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for AccountCreateInstruction {
-    type Accounts = mango_v4::accounts::AccountCreate;
-    type Instruction = mango_v4::instruction::AccountCreateV2;
+impl ClientInstruction for IxGateSetInstruction {
+    type Accounts = crate::accounts::IxGateSet;
+    type Instruction = crate::instruction::IxGateSet;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
-            account_num: self.account_num,
-            token_count: self.token_count,
-            serum3_count: self.serum3_count,
-            perp_count: self.perp_count,
-            perp_oo_count: self.perp_oo_count,
-            token_conditional_swap_count: self.token_conditional_swap_count,
-            name: "my_mango_account".to_string(),
+            ix_gate: self.ix_gate,
         };
 
-        let account = Pubkey::find_program_address(
-            &[
-                b"MangoAccount".as_ref(),
-                self.group.as_ref(),
-                self.owner.pubkey().as_ref(),
-                &self.account_num.to_le_bytes(),
-            ],
-            &program_id,
-        )
-        .0;
-
         let accounts = Self::Accounts {
             group: self.group,
-            owner: self.owner.pubkey(),
-            account,
-            payer: self.payer.pubkey(),
-            system_program: System::id(),
+            admin: self.admin.pubkey(),
         };
 
         let instruction = make_instruction(program_id, &accounts, &instruction);
@@ -2053,16 +2249,261 @@ impl ClientInstruction for AccountCreateInstruction {
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![self.owner, self.payer]
+        vec![self.admin]
     }
 }
 
-#[derive(Default)]
-pub struct AccountExpandInstruction {
-    pub account_num: u32,
+pub struct AccountToggleFreezeInstruction {
     pub group: Pubkey,
-    pub owner: TestKeypair,
-    pub payer: TestKeypair,
+    pub admin: TestKeypair,
+    pub account: Pubkey,
+    pub freeze: bool,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AccountToggleFreezeInstruction {
+    type Accounts = crate::accounts::AccountToggleFreeze;
+    type Instruction = crate::instruction::AccountToggleFreeze;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            freeze: self.freeze,
+        };
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            account: self.account,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct AccountCreateDaoTokenPositionsInstruction {
+    pub group: Pubkey,
+    pub admin: TestKeypair,
+    pub dao_account: Pubkey,
+    pub banks: Vec<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AccountCreateDaoTokenPositionsInstruction {
+    type Accounts = crate::accounts::AccountCreateDaoTokenPositions;
+    type Instruction = crate::instruction::AccountCreateDaoTokenPositions;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            admin: self.admin.pubkey(),
+            dao_account: self.dao_account,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        for bank in self.banks.iter() {
+            instruction.accounts.push(AccountMeta {
+                pubkey: *bank,
+                is_writable: false,
+                is_signer: false,
+            });
+        }
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct GroupToggleWithdrawOnlyInstruction {
+    pub group: Pubkey,
+    pub admin: TestKeypair,
+    pub withdraw_only: bool,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for GroupToggleWithdrawOnlyInstruction {
+    type Accounts = crate::accounts::GroupToggleWithdrawOnly;
+    type Instruction = crate::instruction::GroupToggleWithdrawOnly;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            withdraw_only: self.withdraw_only,
+        };
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            admin: self.admin.pubkey(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct GroupMigrateInstruction {
+    pub group: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for GroupMigrateInstruction {
+    type Accounts = crate::accounts::GroupMigrate;
+    type Instruction = crate::instruction::GroupMigrate;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let accounts = Self::Accounts { group: self.group };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct GroupCloseInstruction {
+    pub admin: TestKeypair,
+    pub group: Pubkey,
+    pub sol_destination: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for GroupCloseInstruction {
+    type Accounts = crate::accounts::GroupClose;
+    type Instruction = crate::instruction::GroupClose;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let insurance_vault = Pubkey::find_program_address(
+            &[b"InsuranceVault".as_ref(), self.group.as_ref()],
+            &program_id,
+        )
+        .0;
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            admin: self.admin.pubkey(),
+            insurance_vault,
+            sol_destination: self.sol_destination,
+            token_program: Token::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin]
+    }
+}
+
+pub struct AccountCreateInstruction {
+    pub account_num: u32,
+    pub token_count: u8,
+    pub serum3_count: u8,
+    pub perp_count: u8,
+    pub perp_oo_count: u8,
+    pub token_conditional_swap_count: u8,
+    pub group: Pubkey,
+    pub owner: TestKeypair,
+    pub payer: TestKeypair,
+    pub referrer: Option<Pubkey>,
+}
+impl Default for AccountCreateInstruction {
+    fn default() -> Self {
+        AccountCreateInstruction {
+            account_num: 0,
+            token_count: 8,
+            serum3_count: 4,
+            perp_count: 4,
+            perp_oo_count: 16,
+            token_conditional_swap_count: 1,
+            group: Default::default(),
+            owner: Default::default(),
+            payer: Default::default(),
+            referrer: None,
+        }
+    }
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AccountCreateInstruction {
+    type Accounts = crate::accounts::AccountCreate;
+    type Instruction = crate::instruction::AccountCreateV2;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            account_num: self.account_num,
+            token_count: self.token_count,
+            serum3_count: self.serum3_count,
+            perp_count: self.perp_count,
+            perp_oo_count: self.perp_oo_count,
+            token_conditional_swap_count: self.token_conditional_swap_count,
+            name: "my_mango_account".to_string(),
+        };
+
+        let account = Pubkey::find_program_address(
+            &[
+                b"MangoAccount".as_ref(),
+                self.group.as_ref(),
+                self.owner.pubkey().as_ref(),
+                &self.account_num.to_le_bytes(),
+            ],
+            &program_id,
+        )
+        .0;
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            owner: self.owner.pubkey(),
+            account,
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+            referrer: self.referrer,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner, self.payer]
+    }
+}
+
+#[derive(Default)]
+pub struct AccountExpandInstruction {
+    pub account_num: u32,
+    pub group: Pubkey,
+    pub owner: TestKeypair,
+    pub payer: TestKeypair,
     pub token_count: u8,
     pub serum3_count: u8,
     pub perp_count: u8,
@@ -2071,13 +2512,13 @@ pub struct AccountExpandInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AccountExpandInstruction {
-    type Accounts = mango_v4::accounts::AccountExpand;
-    type Instruction = mango_v4::instruction::AccountExpandV2;
+    type Accounts = crate::accounts::AccountExpand;
+    type Instruction = crate::instruction::AccountExpandV2;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             token_count: self.token_count,
             serum3_count: self.serum3_count,
@@ -2097,7 +2538,7 @@ impl ClientInstruction for AccountExpandInstruction {
         )
         .0;
 
-        let accounts = mango_v4::accounts::AccountExpand {
+        let accounts = crate::accounts::AccountExpand {
             group: self.group,
             account,
             owner: self.owner.pubkey(),
@@ -2121,13 +2562,13 @@ pub struct AccountSizeMigrationInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AccountSizeMigrationInstruction {
-    type Accounts = mango_v4::accounts::AccountSizeMigration;
-    type Instruction = mango_v4::instruction::AccountSizeMigration;
+    type Accounts = crate::accounts::AccountSizeMigration;
+    type Instruction = crate::instruction::AccountSizeMigration;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let account = account_loader
@@ -2160,18 +2601,21 @@ pub struct AccountEditInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AccountEditInstruction {
-    type Accounts = mango_v4::accounts::AccountEdit;
-    type Instruction = mango_v4::instruction::AccountEdit;
+    type Accounts = crate::accounts::AccountEdit;
+    type Instruction = crate::instruction::AccountEdit;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
-        let instruction = mango_v4::instruction::AccountEdit {
+        let program_id = crate::id();
+        let instruction = crate::instruction::AccountEdit {
             name_opt: Some(self.name.to_string()),
             delegate_opt: Some(self.delegate),
             temporary_delegate_opt: None,
             temporary_delegate_expiry_opt: None,
+            isolated_opt: None,
+            isolated_perp_market_index_opt: None,
+            isolated_token_indexes_opt: None,
         };
 
         let account = Pubkey::find_program_address(
@@ -2185,7 +2629,7 @@ impl ClientInstruction for AccountEditInstruction {
         )
         .0;
 
-        let accounts = mango_v4::accounts::AccountEdit {
+        let accounts = crate::accounts::AccountEdit {
             group: self.group,
             account,
             owner: self.owner.pubkey(),
@@ -2200,6 +2644,61 @@ impl ClientInstruction for AccountEditInstruction {
     }
 }
 
+pub struct AccountIsolateInstruction {
+    pub account: Pubkey,
+    pub owner: TestKeypair,
+    pub isolated: bool,
+    pub perp_market_index: PerpMarketIndex,
+    pub token_indexes: Vec<TokenIndex>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AccountIsolateInstruction {
+    type Accounts = crate::accounts::AccountEdit;
+    type Instruction = crate::instruction::AccountEdit;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = crate::instruction::AccountEdit {
+            name_opt: None,
+            delegate_opt: None,
+            temporary_delegate_opt: None,
+            temporary_delegate_expiry_opt: None,
+            isolated_opt: Some(self.isolated),
+            isolated_perp_market_index_opt: Some(self.perp_market_index),
+            isolated_token_indexes_opt: Some(self.token_indexes.clone()),
+        };
+
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+
+        let accounts = crate::accounts::AccountEdit {
+            group: account.fixed.group,
+            account: self.account,
+            owner: self.owner.pubkey(),
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        if self.perp_market_index != PerpMarketIndex::MAX {
+            let (perp_market, _) =
+                PerpMarket::address(&account.fixed.group, self.perp_market_index);
+            instruction.accounts.push(AccountMeta {
+                pubkey: perp_market,
+                is_writable: false,
+                is_signer: false,
+            });
+        }
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
 pub struct AccountCloseInstruction {
     pub group: Pubkey,
     pub account: Pubkey,
@@ -2208,13 +2707,13 @@ pub struct AccountCloseInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AccountCloseInstruction {
-    type Accounts = mango_v4::accounts::AccountClose;
-    type Instruction = mango_v4::instruction::AccountClose;
+    type Accounts = crate::accounts::AccountClose;
+    type Instruction = crate::instruction::AccountClose;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { force_close: false };
 
         let accounts = Self::Accounts {
@@ -2242,13 +2741,13 @@ pub struct AccountBuybackFeesWithMngo {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AccountBuybackFeesWithMngo {
-    type Accounts = mango_v4::accounts::AccountBuybackFeesWithMngo;
-    type Instruction = mango_v4::instruction::AccountBuybackFeesWithMngo;
+    type Accounts = crate::accounts::AccountBuybackFeesWithMngo;
+    type Instruction = crate::instruction::AccountBuybackFeesWithMngo;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_buyback_usd: u64::MAX,
         };
@@ -2283,14 +2782,51 @@ impl ClientInstruction for AccountBuybackFeesWithMngo {
     }
 }
 
-pub struct Serum3RegisterMarketInstruction {
-    pub group: Pubkey,
-    pub admin: TestKeypair,
-    pub payer: TestKeypair,
-
-    pub serum_program: Pubkey,
-    pub serum_market_external: Pubkey,
-
+pub struct AccountClaimReferralFeesInstruction {
+    pub account: Pubkey,
+    pub bank: Pubkey,
+    pub perp_market: Option<Pubkey>,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AccountClaimReferralFeesInstruction {
+    type Accounts = crate::accounts::AccountClaimReferralFees;
+    type Instruction = crate::instruction::AccountClaimReferralFees;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+            referrer: account.fixed.referrer,
+            bank: self.bank,
+            perp_market: self.perp_market,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct Serum3RegisterMarketInstruction {
+    pub group: Pubkey,
+    pub admin: TestKeypair,
+    pub payer: TestKeypair,
+
+    pub serum_program: Pubkey,
+    pub serum_market_external: Pubkey,
+
     pub base_bank: Pubkey,
     pub quote_bank: Pubkey,
 
@@ -2298,13 +2834,13 @@ pub struct Serum3RegisterMarketInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3RegisterMarketInstruction {
-    type Accounts = mango_v4::accounts::Serum3RegisterMarket;
-    type Instruction = mango_v4::instruction::Serum3RegisterMarket;
+    type Accounts = crate::accounts::Serum3RegisterMarket;
+    type Instruction = crate::instruction::Serum3RegisterMarket;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             market_index: self.market_index,
             name: "UUU/usdc".to_string(),
@@ -2353,12 +2889,13 @@ impl ClientInstruction for Serum3RegisterMarketInstruction {
     }
 }
 
-pub fn serum3_edit_market_instruction_default() -> mango_v4::instruction::Serum3EditMarket {
-    mango_v4::instruction::Serum3EditMarket {
+pub fn serum3_edit_market_instruction_default() -> crate::instruction::Serum3EditMarket {
+    crate::instruction::Serum3EditMarket {
         reduce_only_opt: None,
         force_close_opt: None,
         name_opt: None,
         oracle_price_band_opt: None,
+        crossing_price_band_opt: None,
     }
 }
 
@@ -2366,17 +2903,17 @@ pub struct Serum3EditMarketInstruction {
     pub group: Pubkey,
     pub admin: TestKeypair,
     pub market: Pubkey,
-    pub options: mango_v4::instruction::Serum3EditMarket,
+    pub options: crate::instruction::Serum3EditMarket,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3EditMarketInstruction {
-    type Accounts = mango_v4::accounts::Serum3EditMarket;
-    type Instruction = mango_v4::instruction::Serum3EditMarket;
+    type Accounts = crate::accounts::Serum3EditMarket;
+    type Instruction = crate::instruction::Serum3EditMarket;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let accounts = Self::Accounts {
             group: self.group,
@@ -2401,13 +2938,13 @@ pub struct Serum3DeregisterMarketInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3DeregisterMarketInstruction {
-    type Accounts = mango_v4::accounts::Serum3DeregisterMarket;
-    type Instruction = mango_v4::instruction::Serum3DeregisterMarket;
+    type Accounts = crate::accounts::Serum3DeregisterMarket;
+    type Instruction = crate::instruction::Serum3DeregisterMarket;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let serum_market = Pubkey::find_program_address(
@@ -2457,13 +2994,13 @@ pub struct Serum3CreateOpenOrdersInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3CreateOpenOrdersInstruction {
-    type Accounts = mango_v4::accounts::Serum3CreateOpenOrders;
-    type Instruction = mango_v4::instruction::Serum3CreateOpenOrders;
+    type Accounts = crate::accounts::Serum3CreateOpenOrders;
+    type Instruction = crate::instruction::Serum3CreateOpenOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let account: MangoAccount = account_loader.load(&self.account).await.unwrap();
@@ -2508,13 +3045,13 @@ pub struct Serum3CloseOpenOrdersInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3CloseOpenOrdersInstruction {
-    type Accounts = mango_v4::accounts::Serum3CloseOpenOrders;
-    type Instruction = mango_v4::instruction::Serum3CloseOpenOrders;
+    type Accounts = crate::accounts::Serum3CloseOpenOrders;
+    type Instruction = crate::instruction::Serum3CloseOpenOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let account: MangoAccount = account_loader.load(&self.account).await.unwrap();
@@ -2566,13 +3103,13 @@ pub struct Serum3PlaceOrderInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3PlaceOrderInstruction {
-    type Accounts = mango_v4::accounts::Serum3PlaceOrder;
-    type Instruction = mango_v4::instruction::Serum3PlaceOrderV2;
+    type Accounts = crate::accounts::Serum3PlaceOrder;
+    type Instruction = crate::instruction::Serum3PlaceOrderV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             side: self.side,
             limit_price: self.limit_price,
@@ -2673,6 +3210,53 @@ impl ClientInstruction for Serum3PlaceOrderInstruction {
     }
 }
 
+pub struct Serum3PlaceOrdersInstruction {
+    pub side: Serum3Side,
+    pub orders: Vec<Serum3PlaceOrderArgs>,
+
+    pub account: Pubkey,
+    pub owner: TestKeypair,
+
+    pub serum_market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for Serum3PlaceOrdersInstruction {
+    type Accounts = crate::accounts::Serum3PlaceOrder;
+    type Instruction = crate::instruction::Serum3PlaceOrders;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        // Same accounts as for a single order
+        let (accounts, mut instruction) = Serum3PlaceOrderInstruction {
+            side: self.side,
+            limit_price: 0,
+            max_base_qty: 0,
+            max_native_quote_qty_including_fees: 0,
+            self_trade_behavior: Serum3SelfTradeBehavior::DecrementTake,
+            order_type: Serum3OrderType::Limit,
+            client_order_id: 0,
+            limit: 0,
+            account: self.account,
+            owner: self.owner,
+            serum_market: self.serum_market,
+        }
+        .to_instruction(account_loader)
+        .await;
+
+        instruction.data = anchor_lang::InstructionData::data(&Self::Instruction {
+            side: self.side,
+            orders: self.orders.clone(),
+        });
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
 pub struct Serum3CancelOrderInstruction {
     pub side: Serum3Side,
     pub order_id: u128,
@@ -2684,13 +3268,13 @@ pub struct Serum3CancelOrderInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3CancelOrderInstruction {
-    type Accounts = mango_v4::accounts::Serum3CancelOrder;
-    type Instruction = mango_v4::instruction::Serum3CancelOrder;
+    type Accounts = crate::accounts::Serum3CancelOrder;
+    type Instruction = crate::instruction::Serum3CancelOrder;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             side: self.side,
             order_id: self.order_id,
@@ -2750,13 +3334,13 @@ pub struct Serum3CancelOrderByClientOrderIdInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3CancelOrderByClientOrderIdInstruction {
-    type Accounts = mango_v4::accounts::Serum3CancelOrder;
-    type Instruction = mango_v4::instruction::Serum3CancelOrderByClientOrderId;
+    type Accounts = crate::accounts::Serum3CancelOrder;
+    type Instruction = crate::instruction::Serum3CancelOrderByClientOrderId;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             client_order_id: self.client_order_id,
         };
@@ -2813,13 +3397,13 @@ pub struct Serum3CancelAllOrdersInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3CancelAllOrdersInstruction {
-    type Accounts = mango_v4::accounts::Serum3CancelAllOrders;
-    type Instruction = mango_v4::instruction::Serum3CancelAllOrders;
+    type Accounts = crate::accounts::Serum3CancelAllOrders;
+    type Instruction = crate::instruction::Serum3CancelAllOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { limit: self.limit };
 
         let account = account_loader
@@ -2875,13 +3459,13 @@ pub struct Serum3SettleFundsV2Instruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3SettleFundsV2Instruction {
-    type Accounts = mango_v4::accounts::Serum3SettleFundsV2;
-    type Instruction = mango_v4::instruction::Serum3SettleFundsV2;
+    type Accounts = crate::accounts::Serum3SettleFundsV2;
+    type Instruction = crate::instruction::Serum3SettleFundsV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             fees_to_dao: self.fees_to_dao,
         };
@@ -2920,7 +3504,7 @@ impl ClientInstruction for Serum3SettleFundsV2Instruction {
         .unwrap();
 
         let accounts = Self::Accounts {
-            v1: mango_v4::accounts::Serum3SettleFunds {
+            v1: crate::accounts::Serum3SettleFunds {
                 group: account.fixed.group,
                 account: self.account,
                 open_orders,
@@ -2937,7 +3521,7 @@ impl ClientInstruction for Serum3SettleFundsV2Instruction {
                 owner: self.owner.pubkey(),
                 token_program: Token::id(),
             },
-            v2: mango_v4::accounts::Serum3SettleFundsV2Extra {
+            v2: crate::accounts::Serum3SettleFundsV2Extra {
                 quote_oracle: quote_info.oracle,
                 base_oracle: base_info.oracle,
             },
@@ -2959,13 +3543,13 @@ pub struct Serum3LiqForceCancelOrdersInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for Serum3LiqForceCancelOrdersInstruction {
-    type Accounts = mango_v4::accounts::Serum3LiqForceCancelOrders;
-    type Instruction = mango_v4::instruction::Serum3LiqForceCancelOrders;
+    type Accounts = crate::accounts::Serum3LiqForceCancelOrders;
+    type Instruction = crate::instruction::Serum3LiqForceCancelOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { limit: self.limit };
 
         let account = account_loader
@@ -3057,13 +3641,13 @@ pub struct TokenForceCloseBorrowsWithTokenInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenForceCloseBorrowsWithTokenInstruction {
-    type Accounts = mango_v4::accounts::TokenForceCloseBorrowsWithToken;
-    type Instruction = mango_v4::instruction::TokenForceCloseBorrowsWithToken;
+    type Accounts = crate::accounts::TokenForceCloseBorrowsWithToken;
+    type Instruction = crate::instruction::TokenForceCloseBorrowsWithToken;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             asset_token_index: self.asset_token_index,
             liab_token_index: self.liab_token_index,
@@ -3120,13 +3704,13 @@ pub struct TokenLiqWithTokenInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenLiqWithTokenInstruction {
-    type Accounts = mango_v4::accounts::TokenLiqWithToken;
-    type Instruction = mango_v4::instruction::TokenLiqWithToken;
+    type Accounts = crate::accounts::TokenLiqWithToken;
+    type Instruction = crate::instruction::TokenLiqWithToken;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             asset_token_index: self.asset_token_index,
             liab_token_index: self.liab_token_index,
@@ -3180,13 +3764,13 @@ pub struct TokenLiqBankruptcyInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenLiqBankruptcyInstruction {
-    type Accounts = mango_v4::accounts::TokenLiqBankruptcy;
-    type Instruction = mango_v4::instruction::TokenLiqBankruptcy;
+    type Accounts = crate::accounts::TokenLiqBankruptcy;
+    type Instruction = crate::instruction::TokenLiqBankruptcy;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_liab_transfer: self.max_liab_transfer,
         };
@@ -3200,20 +3784,20 @@ impl ClientInstruction for TokenLiqBankruptcyInstruction {
             .load_mango_account(&self.liqor)
             .await
             .unwrap();
+        let group_key = liqee.fixed.group;
+        let group: Group = account_loader.load(&group_key).await.unwrap();
+
         let health_check_metas = derive_liquidation_remaining_account_metas(
             &account_loader,
             &liqee,
             &liqor,
-            QUOTE_TOKEN_INDEX,
+            group.insurance_token_index,
             0,
             liab_mint_info.token_index,
             0,
         )
         .await;
 
-        let group_key = liqee.fixed.group;
-        let group: Group = account_loader.load(&group_key).await.unwrap();
-
         let quote_mint_info = Pubkey::find_program_address(
             &[
                 b"MintInfo".as_ref(),
@@ -3303,13 +3887,13 @@ impl PerpCreateMarketInstruction {
     ) -> Self {
         PerpCreateMarketInstruction {
             bids: solana
-                .create_account_for_type::<BookSide>(&mango_v4::id())
+                .create_account_for_type::<BookSide>(&crate::id())
                 .await,
             asks: solana
-                .create_account_for_type::<BookSide>(&mango_v4::id())
+                .create_account_for_type::<BookSide>(&crate::id())
                 .await,
             event_queue: solana
-                .create_account_for_type::<EventQueue>(&mango_v4::id())
+                .create_account_for_type::<EventQueue>(&crate::id())
                 .await,
             oracle: base.oracle,
             base_decimals: base.mint.decimals,
@@ -3319,13 +3903,13 @@ impl PerpCreateMarketInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpCreateMarketInstruction {
-    type Accounts = mango_v4::accounts::PerpCreateMarket;
-    type Instruction = mango_v4::instruction::PerpCreateMarket;
+    type Accounts = crate::accounts::PerpCreateMarket;
+    type Instruction = crate::instruction::PerpCreateMarket;
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             name: "UUU-PERP".to_string(),
             oracle_config: OracleConfigParams {
@@ -3360,15 +3944,7 @@ impl ClientInstruction for PerpCreateMarketInstruction {
             platform_liquidation_fee: self.platform_liquidation_fee,
         };
 
-        let perp_market = Pubkey::find_program_address(
-            &[
-                b"PerpMarket".as_ref(),
-                self.group.as_ref(),
-                self.perp_market_index.to_le_bytes().as_ref(),
-            ],
-            &program_id,
-        )
-        .0;
+        let perp_market = PerpMarket::address(&self.group, self.perp_market_index).0;
 
         let accounts = Self::Accounts {
             group: self.group,
@@ -3391,8 +3967,8 @@ impl ClientInstruction for PerpCreateMarketInstruction {
     }
 }
 
-fn perp_edit_instruction_default() -> mango_v4::instruction::PerpEditMarket {
-    mango_v4::instruction::PerpEditMarket {
+pub fn perp_edit_instruction_default() -> crate::instruction::PerpEditMarket {
+    crate::instruction::PerpEditMarket {
         oracle_opt: None,
         oracle_config_opt: None,
         base_decimals_opt: None,
@@ -3424,6 +4000,46 @@ fn perp_edit_instruction_default() -> mango_v4::instruction::PerpEditMarket {
         name_opt: None,
         force_close_opt: None,
         platform_liquidation_fee_opt: None,
+        keeper_reward_per_event_opt: None,
+        keeper_reward_funding_per_hour_opt: None,
+        oracle_price_band_opt: None,
+        funding_period_seconds_opt: None,
+        dust_threshold_opt: None,
+    }
+}
+
+pub struct PerpEdit {
+    pub group: Pubkey,
+    pub admin: TestKeypair,
+    pub perp_market: Pubkey,
+    pub options: crate::instruction::PerpEditMarket,
+}
+
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpEdit {
+    type Accounts = crate::accounts::PerpEditMarket;
+    type Instruction = crate::instruction::PerpEditMarket;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            admin: self.admin.pubkey(),
+            perp_market: self.perp_market,
+            oracle: perp_market.oracle,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &self.options);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin]
     }
 }
 
@@ -3435,13 +4051,13 @@ pub struct PerpResetStablePriceModel {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpResetStablePriceModel {
-    type Accounts = mango_v4::accounts::PerpEditMarket;
-    type Instruction = mango_v4::instruction::PerpEditMarket;
+    type Accounts = crate::accounts::PerpEditMarket;
+    type Instruction = crate::instruction::PerpEditMarket;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
 
@@ -3475,13 +4091,13 @@ pub struct PerpSetSettleLimitWindow {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpSetSettleLimitWindow {
-    type Accounts = mango_v4::accounts::PerpEditMarket;
-    type Instruction = mango_v4::instruction::PerpEditMarket;
+    type Accounts = crate::accounts::PerpEditMarket;
+    type Instruction = crate::instruction::PerpEditMarket;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
 
@@ -3516,13 +4132,13 @@ pub struct PerpMakeReduceOnly {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpMakeReduceOnly {
-    type Accounts = mango_v4::accounts::PerpEditMarket;
-    type Instruction = mango_v4::instruction::PerpEditMarket;
+    type Accounts = crate::accounts::PerpEditMarket;
+    type Instruction = crate::instruction::PerpEditMarket;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
 
@@ -3558,13 +4174,13 @@ pub struct PerpChangeWeights {
 
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpChangeWeights {
-    type Accounts = mango_v4::accounts::PerpEditMarket;
-    type Instruction = mango_v4::instruction::PerpEditMarket;
+    type Accounts = crate::accounts::PerpEditMarket;
+    type Instruction = crate::instruction::PerpEditMarket;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
 
@@ -3597,13 +4213,13 @@ pub struct PerpCloseMarketInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpCloseMarketInstruction {
-    type Accounts = mango_v4::accounts::PerpCloseMarket;
-    type Instruction = mango_v4::instruction::PerpCloseMarket;
+    type Accounts = crate::accounts::PerpCloseMarket;
+    type Instruction = crate::instruction::PerpCloseMarket;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
 
@@ -3634,13 +4250,13 @@ pub struct PerpDeactivatePositionInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpDeactivatePositionInstruction {
-    type Accounts = mango_v4::accounts::PerpDeactivatePosition;
-    type Instruction = mango_v4::instruction::PerpDeactivatePosition;
+    type Accounts = crate::accounts::PerpDeactivatePosition;
+    type Instruction = crate::instruction::PerpDeactivatePosition;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
 
         let instruction = Self::Instruction {};
@@ -3692,13 +4308,13 @@ impl Default for PerpPlaceOrderInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpPlaceOrderInstruction {
-    type Accounts = mango_v4::accounts::PerpPlaceOrder;
-    type Instruction = mango_v4::instruction::PerpPlaceOrderV2;
+    type Accounts = crate::accounts::PerpPlaceOrder;
+    type Instruction = crate::instruction::PerpPlaceOrderV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             side: self.side,
             price_lots: self.price_lots,
@@ -3747,6 +4363,152 @@ impl ClientInstruction for PerpPlaceOrderInstruction {
     }
 }
 
+pub struct PerpPlaceOrdersInstruction {
+    pub account: Pubkey,
+    pub perp_market: Pubkey,
+    pub owner: TestKeypair,
+    pub orders: Vec<PerpPlaceOrderArgs>,
+    pub limit: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpPlaceOrdersInstruction {
+    type Accounts = crate::accounts::PerpPlaceOrders;
+    type Instruction = crate::instruction::PerpPlaceOrders;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            orders: self.orders.clone(),
+            limit: self.limit,
+        };
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            false,
+            Some(perp_market.perp_market_index),
+        )
+        .await;
+
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+            perp_market: self.perp_market,
+            bids: perp_market.bids,
+            asks: perp_market.asks,
+            event_queue: perp_market.event_queue,
+            oracle: perp_market.oracle,
+            owner: self.owner.pubkey(),
+        };
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas);
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+pub struct PerpModifyOrderInstruction {
+    pub account: Pubkey,
+    pub perp_market: Pubkey,
+    pub owner: TestKeypair,
+    pub cancel_client_order_id: u64,
+    pub side: Side,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots: i64,
+    pub reduce_only: bool,
+    pub client_order_id: u64,
+    pub self_trade_behavior: SelfTradeBehavior,
+    pub limit: u8,
+}
+impl Default for PerpModifyOrderInstruction {
+    fn default() -> Self {
+        Self {
+            account: Pubkey::default(),
+            perp_market: Pubkey::default(),
+            owner: TestKeypair::default(),
+            cancel_client_order_id: 0,
+            side: Side::Bid,
+            price_lots: 0,
+            max_base_lots: i64::MAX,
+            max_quote_lots: i64::MAX,
+            reduce_only: false,
+            client_order_id: 0,
+            self_trade_behavior: SelfTradeBehavior::DecrementTake,
+            limit: 10,
+        }
+    }
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpModifyOrderInstruction {
+    type Accounts = crate::accounts::PerpModifyOrder;
+    type Instruction = crate::instruction::PerpModifyOrder;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            cancel_client_order_id: self.cancel_client_order_id,
+            side: self.side,
+            price_lots: self.price_lots,
+            max_base_lots: self.max_base_lots,
+            max_quote_lots: self.max_quote_lots,
+            client_order_id: self.client_order_id,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: self.self_trade_behavior,
+            reduce_only: self.reduce_only,
+            expiry_timestamp: 0,
+            limit: self.limit,
+        };
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            false,
+            Some(perp_market.perp_market_index),
+        )
+        .await;
+
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+            perp_market: self.perp_market,
+            bids: perp_market.bids,
+            asks: perp_market.asks,
+            event_queue: perp_market.event_queue,
+            oracle: perp_market.oracle,
+            owner: self.owner.pubkey(),
+        };
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas);
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
 pub struct PerpPlaceOrderPeggedInstruction {
     pub account: Pubkey,
     pub perp_market: Pubkey,
@@ -3760,13 +4522,13 @@ pub struct PerpPlaceOrderPeggedInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpPlaceOrderPeggedInstruction {
-    type Accounts = mango_v4::accounts::PerpPlaceOrder;
-    type Instruction = mango_v4::instruction::PerpPlaceOrderPeggedV2;
+    type Accounts = crate::accounts::PerpPlaceOrder;
+    type Instruction = crate::instruction::PerpPlaceOrderPeggedV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             side: self.side,
             price_offset_lots: self.price_offset,
@@ -3825,13 +4587,13 @@ pub struct PerpCancelOrderInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpCancelOrderInstruction {
-    type Accounts = mango_v4::accounts::PerpCancelOrder;
-    type Instruction = mango_v4::instruction::PerpCancelOrder;
+    type Accounts = crate::accounts::PerpCancelOrder;
+    type Instruction = crate::instruction::PerpCancelOrder;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             order_id: self.order_id,
         };
@@ -3862,13 +4624,13 @@ pub struct PerpCancelOrderByClientOrderIdInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpCancelOrderByClientOrderIdInstruction {
-    type Accounts = mango_v4::accounts::PerpCancelOrderByClientOrderId;
-    type Instruction = mango_v4::instruction::PerpCancelOrderByClientOrderId;
+    type Accounts = crate::accounts::PerpCancelOrderByClientOrderId;
+    type Instruction = crate::instruction::PerpCancelOrderByClientOrderId;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             client_order_id: self.client_order_id,
         };
@@ -3899,13 +4661,13 @@ pub struct PerpCancelAllOrdersInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpCancelAllOrdersInstruction {
-    type Accounts = mango_v4::accounts::PerpCancelAllOrders;
-    type Instruction = mango_v4::instruction::PerpCancelAllOrders;
+    type Accounts = crate::accounts::PerpCancelAllOrders;
+    type Instruction = crate::instruction::PerpCancelAllOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { limit: self.limit };
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
         let accounts = Self::Accounts {
@@ -3929,16 +4691,17 @@ impl ClientInstruction for PerpCancelAllOrdersInstruction {
 pub struct PerpConsumeEventsInstruction {
     pub perp_market: Pubkey,
     pub mango_accounts: Vec<Pubkey>,
+    pub keeper: Option<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpConsumeEventsInstruction {
-    type Accounts = mango_v4::accounts::PerpConsumeEvents;
-    type Instruction = mango_v4::instruction::PerpConsumeEvents;
+    type Accounts = crate::accounts::PerpConsumeEvents;
+    type Instruction = crate::instruction::PerpConsumeEvents;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { limit: 10 };
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
@@ -3946,6 +4709,7 @@ impl ClientInstruction for PerpConsumeEventsInstruction {
             group: perp_market.group,
             perp_market: self.perp_market,
             event_queue: perp_market.event_queue,
+            keeper: self.keeper,
         };
 
         let mut instruction = make_instruction(program_id, &accounts, &instruction);
@@ -3964,20 +4728,22 @@ impl ClientInstruction for PerpConsumeEventsInstruction {
     }
 }
 
+#[derive(Clone)]
 pub struct PerpUpdateFundingInstruction {
     pub perp_market: Pubkey,
     pub bank: Pubkey,
     pub oracle: Pubkey,
+    pub keeper: Option<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpUpdateFundingInstruction {
-    type Accounts = mango_v4::accounts::PerpUpdateFunding;
-    type Instruction = mango_v4::instruction::PerpUpdateFunding;
+    type Accounts = crate::accounts::PerpUpdateFunding;
+    type Instruction = crate::instruction::PerpUpdateFunding;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
         let accounts = Self::Accounts {
@@ -3986,6 +4752,7 @@ impl ClientInstruction for PerpUpdateFundingInstruction {
             bids: perp_market.bids,
             asks: perp_market.asks,
             oracle: self.oracle,
+            keeper: self.keeper,
         };
 
         let instruction = make_instruction(program_id, &accounts, &instruction);
@@ -4006,13 +4773,13 @@ pub struct PerpSettlePnlInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpSettlePnlInstruction {
-    type Accounts = mango_v4::accounts::PerpSettlePnl;
-    type Instruction = mango_v4::instruction::PerpSettlePnl;
+    type Accounts = crate::accounts::PerpSettlePnl;
+    type Instruction = crate::instruction::PerpSettlePnl;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
@@ -4064,39 +4831,434 @@ impl ClientInstruction for PerpSettlePnlInstruction {
     }
 }
 
+pub struct PerpSettlePnlBatchInstruction {
+    pub settler: Pubkey,
+    pub settler_owner: TestKeypair,
+    /// (profitable account, unprofitable account)
+    pub pairs: Vec<(Pubkey, Pubkey)>,
+    pub perp_market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpSettlePnlBatchInstruction {
+    type Accounts = crate::accounts::PerpSettlePnlBatch;
+    type Instruction = crate::instruction::PerpSettlePnlBatch;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            num_pairs: self.pairs.len() as u8,
+        };
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let account_keys: Vec<Pubkey> = self.pairs.iter().flat_map(|(a, b)| [*a, *b]).collect();
+        let mut accounts = vec![];
+        for key in account_keys.iter() {
+            accounts.push(account_loader.load_mango_account(key).await.unwrap());
+        }
+
+        // health accounts covering all settled accounts
+        let mut banks = vec![];
+        let mut oracles = vec![];
+        let token_indexes = accounts
+            .iter()
+            .flat_map(|a| a.active_token_positions().map(|ta| ta.token_index))
+            .unique()
+            .collect_vec();
+        for token_index in token_indexes {
+            let mint_info =
+                get_mint_info_by_token_index(&account_loader, &accounts[0], token_index).await;
+            banks.push(mint_info.first_bank());
+            oracles.push(mint_info.oracle);
+        }
+        let perp_markets = accounts
+            .iter()
+            .flat_map(|a| {
+                a.active_perp_positions().map(|perp| {
+                    get_perp_market_address_by_index(perp_market.group, perp.market_index)
+                })
+            })
+            .unique()
+            .collect_vec();
+        let mut perp_oracles = vec![];
+        for perp in perp_markets.iter() {
+            perp_oracles
+                .push(get_oracle_address_from_perp_market_address(&account_loader, perp).await)
+        }
+        let serum_oos = accounts
+            .iter()
+            .flat_map(|a| a.active_serum3_orders().map(|s| s.open_orders))
+            .collect_vec();
+
+        let settle_mint_info = get_mint_info_by_token_index(
+            &account_loader,
+            &accounts[0],
+            perp_market.settle_token_index,
+        )
+        .await;
+
+        let accounts = Self::Accounts {
+            group: perp_market.group,
+            settler: self.settler,
+            settler_owner: self.settler_owner.pubkey(),
+            perp_market: self.perp_market,
+            oracle: perp_market.oracle,
+            settle_bank: settle_mint_info.first_bank(),
+            settle_oracle: settle_mint_info.oracle,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction
+            .accounts
+            .extend(account_keys.into_iter().map(|pubkey| AccountMeta {
+                pubkey,
+                is_writable: true,
+                is_signer: false,
+            }));
+        instruction.accounts.extend(
+            banks
+                .into_iter()
+                .chain(oracles)
+                .chain(perp_markets)
+                .chain(perp_oracles)
+                .chain(serum_oos)
+                .map(|pubkey| AccountMeta {
+                    pubkey,
+                    is_writable: false,
+                    is_signer: false,
+                }),
+        );
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.settler_owner]
+    }
+}
+
 pub struct PerpForceClosePositionInstruction {
     pub account_a: Pubkey,
     pub account_b: Pubkey,
     pub perp_market: Pubkey,
 }
 #[async_trait::async_trait(?Send)]
-impl ClientInstruction for PerpForceClosePositionInstruction {
-    type Accounts = mango_v4::accounts::PerpForceClosePosition;
-    type Instruction = mango_v4::instruction::PerpForceClosePosition;
+impl ClientInstruction for PerpForceClosePositionInstruction {
+    type Accounts = crate::accounts::PerpForceClosePosition;
+    type Instruction = crate::instruction::PerpForceClosePosition;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+
+        let accounts = Self::Accounts {
+            group: perp_market.group,
+            perp_market: self.perp_market,
+            account_a: self.account_a,
+            account_b: self.account_b,
+            oracle: perp_market.oracle,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+#[derive(Clone)]
+pub struct PerpLiqAutoDeleverageInstruction {
+    pub liqee: Pubkey,
+    pub counterparty: Pubkey,
+    pub perp_market: Pubkey,
+    pub max_base_transfer: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpLiqAutoDeleverageInstruction {
+    type Accounts = crate::accounts::PerpLiqAutoDeleverage;
+    type Instruction = crate::instruction::PerpLiqAutoDeleverage;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            max_base_transfer: self.max_base_transfer,
+        };
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let group = account_loader
+            .load::<Group>(&perp_market.group)
+            .await
+            .unwrap();
+        let liqee = account_loader
+            .load_mango_account(&self.liqee)
+            .await
+            .unwrap();
+        let counterparty = account_loader
+            .load_mango_account(&self.counterparty)
+            .await
+            .unwrap();
+        let health_check_metas = derive_liquidation_remaining_account_metas(
+            &account_loader,
+            &liqee,
+            &counterparty,
+            TokenIndex::MAX,
+            0,
+            TokenIndex::MAX,
+            0,
+        )
+        .await;
+        let insurance_mint_info =
+            get_mint_info_by_token_index(&account_loader, &liqee, group.insurance_token_index)
+                .await;
+
+        let accounts = Self::Accounts {
+            group: perp_market.group,
+            perp_market: self.perp_market,
+            oracle: perp_market.oracle,
+            liqee: self.liqee,
+            counterparty: self.counterparty,
+            insurance_vault: group.insurance_vault,
+            insurance_bank: insurance_mint_info.first_bank(),
+            insurance_oracle: insurance_mint_info.oracle,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas.into_iter());
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+#[derive(Clone)]
+pub struct PerpUpdateAdlCandidateInstruction {
+    pub account: Pubkey,
+    pub perp_market: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpUpdateAdlCandidateInstruction {
+    type Accounts = crate::accounts::PerpUpdateAdlCandidate;
+    type Instruction = crate::instruction::PerpUpdateAdlCandidate;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            false,
+            None,
+        )
+        .await;
+
+        let accounts = Self::Accounts {
+            group: perp_market.group,
+            perp_market: self.perp_market,
+            oracle: perp_market.oracle,
+            account: self.account,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas.into_iter());
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub fn perp_trigger_order_address(account: &Pubkey, order_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"PerpTriggerOrder".as_ref(),
+            account.as_ref(),
+            &order_id.to_le_bytes(),
+        ],
+        &crate::id(),
+    )
+    .0
+}
+
+#[derive(Clone)]
+pub struct PerpTriggerOrderCreateInstruction {
+    pub account: Pubkey,
+    pub perp_market: Pubkey,
+    pub owner: TestKeypair,
+    pub payer: TestKeypair,
+    pub order_id: u64,
+    pub side: Side,
+    pub trigger_condition: PerpTriggerCondition,
+    pub trigger_price_lots: i64,
+    pub price_lots: i64,
+    pub max_base_lots: i64,
+    pub max_quote_lots: i64,
+    pub reduce_only: bool,
+    pub expiry_timestamp: u64,
+    pub executor_incentive: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpTriggerOrderCreateInstruction {
+    type Accounts = crate::accounts::PerpTriggerOrderCreate;
+    type Instruction = crate::instruction::PerpTriggerOrderCreate;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            order_id: self.order_id,
+            side: self.side,
+            trigger_condition: self.trigger_condition,
+            trigger_price_lots: self.trigger_price_lots,
+            price_lots: self.price_lots,
+            max_base_lots: self.max_base_lots,
+            max_quote_lots: self.max_quote_lots,
+            reduce_only: self.reduce_only,
+            expiry_timestamp: self.expiry_timestamp,
+            executor_incentive: self.executor_incentive,
+        };
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let accounts = Self::Accounts {
+            group: perp_market.group,
+            account: self.account,
+            authority: self.owner.pubkey(),
+            perp_market: self.perp_market,
+            trigger_order: perp_trigger_order_address(&self.account, self.order_id),
+            payer: self.payer.pubkey(),
+            system_program: System::id(),
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner, self.payer]
+    }
+}
+
+#[derive(Clone)]
+pub struct PerpTriggerOrderCancelInstruction {
+    pub account: Pubkey,
+    pub owner: TestKeypair,
+    pub order_id: u64,
+    pub sol_destination: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpTriggerOrderCancelInstruction {
+    type Accounts = crate::accounts::PerpTriggerOrderCancel;
+    type Instruction = crate::instruction::PerpTriggerOrderCancel;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+            authority: self.owner.pubkey(),
+            trigger_order: perp_trigger_order_address(&self.account, self.order_id),
+            sol_destination: self.sol_destination,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.owner]
+    }
+}
+
+#[derive(Clone)]
+pub struct PerpTriggerOrderExecuteInstruction {
+    pub account: Pubkey,
+    pub perp_market: Pubkey,
+    pub order_id: u64,
+    pub executor: TestKeypair,
+    pub executor_account: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpTriggerOrderExecuteInstruction {
+    type Accounts = crate::accounts::PerpTriggerOrderExecute;
+    type Instruction = crate::instruction::PerpTriggerOrderExecute;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
-        let instruction = Self::Instruction {};
+        let program_id = crate::id();
+        let instruction = Self::Instruction { limit: 10 };
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            false,
+            Some(perp_market.perp_market_index),
+        )
+        .await;
+        let settle_mint_info =
+            get_mint_info_by_token_index(&account_loader, &account, perp_market.settle_token_index)
+                .await;
 
         let accounts = Self::Accounts {
             group: perp_market.group,
+            account: self.account,
             perp_market: self.perp_market,
-            account_a: self.account_a,
-            account_b: self.account_b,
+            bids: perp_market.bids,
+            asks: perp_market.asks,
+            event_queue: perp_market.event_queue,
             oracle: perp_market.oracle,
+            trigger_order: perp_trigger_order_address(&self.account, self.order_id),
+            executor_account: self.executor_account,
+            executor: self.executor.pubkey(),
+            settle_bank: settle_mint_info.first_bank(),
         };
 
-        let instruction = make_instruction(program_id, &accounts, &instruction);
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas);
 
         (accounts, instruction)
     }
 
     fn signers(&self) -> Vec<TestKeypair> {
-        vec![]
+        vec![self.executor]
     }
 }
 
@@ -4107,13 +5269,13 @@ pub struct PerpSettleFeesInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpSettleFeesInstruction {
-    type Accounts = mango_v4::accounts::PerpSettleFees;
-    type Instruction = mango_v4::instruction::PerpSettleFees;
+    type Accounts = crate::accounts::PerpSettleFees;
+    type Instruction = crate::instruction::PerpSettleFees;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_settle_amount: self.max_settle_amount,
         };
@@ -4160,13 +5322,13 @@ pub struct PerpLiqForceCancelOrdersInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpLiqForceCancelOrdersInstruction {
-    type Accounts = mango_v4::accounts::PerpLiqForceCancelOrders;
-    type Instruction = mango_v4::instruction::PerpLiqForceCancelOrders;
+    type Accounts = crate::accounts::PerpLiqForceCancelOrders;
+    type Instruction = crate::instruction::PerpLiqForceCancelOrders;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { limit: 10 };
 
         let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
@@ -4211,13 +5373,13 @@ pub struct PerpLiqBaseOrPositivePnlInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpLiqBaseOrPositivePnlInstruction {
-    type Accounts = mango_v4::accounts::PerpLiqBaseOrPositivePnl;
-    type Instruction = mango_v4::instruction::PerpLiqBaseOrPositivePnl;
+    type Accounts = crate::accounts::PerpLiqBaseOrPositivePnl;
+    type Instruction = crate::instruction::PerpLiqBaseOrPositivePnl;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_base_transfer: self.max_base_transfer,
             max_pnl_transfer: self.max_pnl_transfer,
@@ -4270,6 +5432,85 @@ impl ClientInstruction for PerpLiqBaseOrPositivePnlInstruction {
     }
 }
 
+pub struct PerpLiqCombinedInstruction {
+    pub liqor: Pubkey,
+    pub liqor_owner: TestKeypair,
+    pub liqee: Pubkey,
+    pub perp_market: Pubkey,
+    pub limit: u8,
+    pub max_base_transfer: i64,
+    pub max_pnl_transfer: u64,
+    pub asset_token_index: TokenIndex,
+    pub liab_token_index: TokenIndex,
+    pub max_liab_transfer: I80F48,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for PerpLiqCombinedInstruction {
+    type Accounts = crate::accounts::PerpLiqCombined;
+    type Instruction = crate::instruction::PerpLiqCombined;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            limit: self.limit,
+            max_base_transfer: self.max_base_transfer,
+            max_pnl_transfer: self.max_pnl_transfer,
+            asset_token_index: self.asset_token_index,
+            liab_token_index: self.liab_token_index,
+            max_liab_transfer: self.max_liab_transfer,
+        };
+
+        let perp_market: PerpMarket = account_loader.load(&self.perp_market).await.unwrap();
+        let group_key = perp_market.group;
+        let liqor = account_loader
+            .load_mango_account(&self.liqor)
+            .await
+            .unwrap();
+        let liqee = account_loader
+            .load_mango_account(&self.liqee)
+            .await
+            .unwrap();
+        let health_check_metas = derive_liquidation_remaining_account_metas(
+            &account_loader,
+            &liqee,
+            &liqor,
+            self.asset_token_index,
+            0,
+            self.liab_token_index,
+            0,
+        )
+        .await;
+
+        let settle_mint_info =
+            get_mint_info_by_token_index(&account_loader, &liqee, perp_market.settle_token_index)
+                .await;
+
+        let accounts = Self::Accounts {
+            group: group_key,
+            perp_market: self.perp_market,
+            oracle: perp_market.oracle,
+            bids: perp_market.bids,
+            asks: perp_market.asks,
+            liqor: self.liqor,
+            liqor_owner: self.liqor_owner.pubkey(),
+            liqee: self.liqee,
+            settle_bank: settle_mint_info.first_bank(),
+            settle_vault: settle_mint_info.first_vault(),
+            settle_oracle: settle_mint_info.oracle,
+        };
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas);
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.liqor_owner]
+    }
+}
+
 pub struct PerpLiqNegativePnlOrBankruptcyInstruction {
     pub liqor: Pubkey,
     pub liqor_owner: TestKeypair,
@@ -4279,13 +5520,13 @@ pub struct PerpLiqNegativePnlOrBankruptcyInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for PerpLiqNegativePnlOrBankruptcyInstruction {
-    type Accounts = mango_v4::accounts::PerpLiqNegativePnlOrBankruptcyV2;
-    type Instruction = mango_v4::instruction::PerpLiqNegativePnlOrBankruptcyV2;
+    type Accounts = crate::accounts::PerpLiqNegativePnlOrBankruptcyV2;
+    type Instruction = crate::instruction::PerpLiqNegativePnlOrBankruptcyV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_liab_transfer: self.max_liab_transfer,
         };
@@ -4316,7 +5557,8 @@ impl ClientInstruction for PerpLiqNegativePnlOrBankruptcyInstruction {
             get_mint_info_by_token_index(&account_loader, &liqee, perp_market.settle_token_index)
                 .await;
         let insurance_mint_info =
-            get_mint_info_by_token_index(&account_loader, &liqee, QUOTE_TOKEN_INDEX).await;
+            get_mint_info_by_token_index(&account_loader, &liqee, group.insurance_token_index)
+                .await;
 
         let accounts = Self::Accounts {
             group: group_key,
@@ -4348,13 +5590,13 @@ impl ClientInstruction for PerpLiqNegativePnlOrBankruptcyInstruction {
 pub struct BenchmarkInstruction {}
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for BenchmarkInstruction {
-    type Accounts = mango_v4::accounts::Benchmark;
-    type Instruction = mango_v4::instruction::Benchmark;
+    type Accounts = crate::accounts::Benchmark;
+    type Instruction = crate::instruction::Benchmark;
     async fn to_instruction(
         &self,
         _loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
         let accounts = Self::Accounts {
             dummy: Pubkey::new_unique(),
@@ -4370,16 +5612,17 @@ impl ClientInstruction for BenchmarkInstruction {
 }
 pub struct TokenUpdateIndexAndRateInstruction {
     pub mint_info: Pubkey,
+    pub keeper: Option<Pubkey>,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenUpdateIndexAndRateInstruction {
-    type Accounts = mango_v4::accounts::TokenUpdateIndexAndRate;
-    type Instruction = mango_v4::instruction::TokenUpdateIndexAndRate;
+    type Accounts = crate::accounts::TokenUpdateIndexAndRate;
+    type Instruction = crate::instruction::TokenUpdateIndexAndRate;
     async fn to_instruction(
         &self,
         loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let mint_info: MintInfo = loader.load(&self.mint_info).await.unwrap();
@@ -4389,6 +5632,7 @@ impl ClientInstruction for TokenUpdateIndexAndRateInstruction {
             mint_info: self.mint_info,
             oracle: mint_info.oracle,
             instructions: solana_program::sysvar::instructions::id(),
+            keeper: self.keeper,
         };
 
         let mut instruction = make_instruction(program_id, &accounts, &instruction);
@@ -4411,18 +5655,79 @@ impl ClientInstruction for TokenUpdateIndexAndRateInstruction {
     }
 }
 
+pub struct TokenCheckVaultInvariantInstruction {
+    pub bank: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for TokenCheckVaultInvariantInstruction {
+    type Accounts = crate::accounts::TokenCheckVaultInvariant;
+    type Instruction = crate::instruction::TokenCheckVaultInvariant;
+    async fn to_instruction(
+        &self,
+        loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let bank: Bank = loader.load(&self.bank).await.unwrap();
+
+        let accounts = Self::Accounts {
+            group: bank.group,
+            bank: self.bank,
+            vault: bank.vault,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct TokenMigrateInstruction {
+    pub bank: Pubkey,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for TokenMigrateInstruction {
+    type Accounts = crate::accounts::TokenMigrate;
+    type Instruction = crate::instruction::TokenMigrate;
+    async fn to_instruction(
+        &self,
+        loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {};
+
+        let bank: Bank = loader.load(&self.bank).await.unwrap();
+
+        let accounts = Self::Accounts {
+            group: bank.group,
+            bank: self.bank,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
 pub struct ComputeAccountDataInstruction {
     pub account: Pubkey,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for ComputeAccountDataInstruction {
-    type Accounts = mango_v4::accounts::ComputeAccountData;
-    type Instruction = mango_v4::instruction::ComputeAccountData;
+    type Accounts = crate::accounts::ComputeAccountData;
+    type Instruction = crate::instruction::ComputeAccountData;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let account = account_loader
@@ -4455,18 +5760,108 @@ impl ClientInstruction for ComputeAccountDataInstruction {
     }
 }
 
+pub struct ComputeAccountDataPageInstruction {
+    pub account: Pubkey,
+    pub page: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ComputeAccountDataPageInstruction {
+    type Accounts = crate::accounts::ComputeAccountData;
+    type Instruction = crate::instruction::ComputeAccountDataPage;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction { page: self.page };
+
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            false,
+            None,
+        )
+        .await;
+
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas.into_iter());
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
+pub struct ComputeHealthBreakdownInstruction {
+    pub account: Pubkey,
+    pub page: u8,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for ComputeHealthBreakdownInstruction {
+    type Accounts = crate::accounts::ComputeHealthBreakdown;
+    type Instruction = crate::instruction::ComputeHealthBreakdown;
+    async fn to_instruction(
+        &self,
+        account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction { page: self.page };
+
+        let account = account_loader
+            .load_mango_account(&self.account)
+            .await
+            .unwrap();
+
+        let health_check_metas = derive_health_check_remaining_account_metas(
+            &account_loader,
+            &account,
+            None,
+            false,
+            None,
+        )
+        .await;
+
+        let accounts = Self::Accounts {
+            group: account.fixed.group,
+            account: self.account,
+        };
+
+        let mut instruction = make_instruction(program_id, &accounts, &instruction);
+        instruction.accounts.extend(health_check_metas.into_iter());
+
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![]
+    }
+}
+
 pub struct HealthRegionBeginInstruction {
     pub account: Pubkey,
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for HealthRegionBeginInstruction {
-    type Accounts = mango_v4::accounts::HealthRegionBegin;
-    type Instruction = mango_v4::instruction::HealthRegionBegin;
+    type Accounts = crate::accounts::HealthRegionBegin;
+    type Instruction = crate::instruction::HealthRegionBegin;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let account = account_loader
@@ -4506,13 +5901,13 @@ pub struct HealthRegionEndInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for HealthRegionEndInstruction {
-    type Accounts = mango_v4::accounts::HealthRegionEnd;
-    type Instruction = mango_v4::instruction::HealthRegionEnd;
+    type Accounts = crate::accounts::HealthRegionEnd;
+    type Instruction = crate::instruction::HealthRegionEnd;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {};
 
         let account = account_loader
@@ -4552,13 +5947,13 @@ pub struct AltSetInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AltSetInstruction {
-    type Accounts = mango_v4::accounts::AltSet;
-    type Instruction = mango_v4::instruction::AltSet;
+    type Accounts = crate::accounts::AltSet;
+    type Instruction = crate::instruction::AltSet;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction { index: self.index };
 
         let accounts = Self::Accounts {
@@ -4576,6 +5971,52 @@ impl ClientInstruction for AltSetInstruction {
     }
 }
 
+pub struct AltCreateInstruction {
+    pub group: Pubkey,
+    pub admin: TestKeypair,
+    pub payer: TestKeypair,
+    pub index: u8,
+    pub recent_slot: u64,
+}
+#[async_trait::async_trait(?Send)]
+impl ClientInstruction for AltCreateInstruction {
+    type Accounts = crate::accounts::AltCreate;
+    type Instruction = crate::instruction::AltCreate;
+    async fn to_instruction(
+        &self,
+        _account_loader: impl ClientAccountLoader + 'async_trait,
+    ) -> (Self::Accounts, instruction::Instruction) {
+        let program_id = crate::id();
+        let instruction = Self::Instruction {
+            index: self.index,
+            recent_slot: self.recent_slot,
+        };
+
+        let address_lookup_table =
+            solana_address_lookup_table_program::instruction::derive_lookup_table_address(
+                &self.group,
+                self.recent_slot,
+            )
+            .0;
+
+        let accounts = Self::Accounts {
+            group: self.group,
+            admin: self.admin.pubkey(),
+            payer: self.payer.pubkey(),
+            address_lookup_table,
+            system_program: System::id(),
+            address_lookup_table_program: solana_address_lookup_table_program::ID,
+        };
+
+        let instruction = make_instruction(program_id, &accounts, &instruction);
+        (accounts, instruction)
+    }
+
+    fn signers(&self) -> Vec<TestKeypair> {
+        vec![self.admin, self.payer]
+    }
+}
+
 pub struct AltExtendInstruction {
     pub group: Pubkey,
     pub admin: TestKeypair,
@@ -4586,13 +6027,13 @@ pub struct AltExtendInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for AltExtendInstruction {
-    type Accounts = mango_v4::accounts::AltExtend;
-    type Instruction = mango_v4::instruction::AltExtend;
+    type Accounts = crate::accounts::AltExtend;
+    type Instruction = crate::instruction::AltExtend;
     async fn to_instruction(
         &self,
         _account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             index: self.index,
             new_addresses: self.new_addresses.clone(),
@@ -4603,6 +6044,7 @@ impl ClientInstruction for AltExtendInstruction {
             admin: self.admin.pubkey(),
             payer: self.payer.pubkey(),
             address_lookup_table: self.address_lookup_table,
+            system_program: System::id(),
         };
 
         let instruction = make_instruction(program_id, &accounts, &instruction);
@@ -4630,13 +6072,13 @@ pub struct TokenConditionalSwapCreateInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenConditionalSwapCreateInstruction {
-    type Accounts = mango_v4::accounts::TokenConditionalSwapCreate;
-    type Instruction = mango_v4::instruction::TokenConditionalSwapCreateV2;
+    type Accounts = crate::accounts::TokenConditionalSwapCreate;
+    type Instruction = crate::instruction::TokenConditionalSwapCreateV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_buy: self.max_buy,
             max_sell: self.max_sell,
@@ -4711,13 +6153,13 @@ pub struct TokenConditionalSwapCreateLinearAuctionInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenConditionalSwapCreateLinearAuctionInstruction {
-    type Accounts = mango_v4::accounts::TokenConditionalSwapCreate;
-    type Instruction = mango_v4::instruction::TokenConditionalSwapCreateLinearAuction;
+    type Accounts = crate::accounts::TokenConditionalSwapCreate;
+    type Instruction = crate::instruction::TokenConditionalSwapCreateLinearAuction;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_buy: self.max_buy,
             max_sell: self.max_sell,
@@ -4791,13 +6233,13 @@ pub struct TokenConditionalSwapCreatePremiumAuctionInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenConditionalSwapCreatePremiumAuctionInstruction {
-    type Accounts = mango_v4::accounts::TokenConditionalSwapCreate;
-    type Instruction = mango_v4::instruction::TokenConditionalSwapCreatePremiumAuction;
+    type Accounts = crate::accounts::TokenConditionalSwapCreate;
+    type Instruction = crate::instruction::TokenConditionalSwapCreatePremiumAuction;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             max_buy: self.max_buy,
             max_sell: self.max_sell,
@@ -4864,13 +6306,13 @@ pub struct TokenConditionalSwapCancelInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenConditionalSwapCancelInstruction {
-    type Accounts = mango_v4::accounts::TokenConditionalSwapCancel;
-    type Instruction = mango_v4::instruction::TokenConditionalSwapCancel;
+    type Accounts = crate::accounts::TokenConditionalSwapCancel;
+    type Instruction = crate::instruction::TokenConditionalSwapCancel;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
         let instruction = Self::Instruction {
             token_conditional_swap_index: self.index,
             token_conditional_swap_id: self.id,
@@ -4917,13 +6359,13 @@ pub struct TokenConditionalSwapTriggerInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenConditionalSwapTriggerInstruction {
-    type Accounts = mango_v4::accounts::TokenConditionalSwapTrigger;
-    type Instruction = mango_v4::instruction::TokenConditionalSwapTriggerV2;
+    type Accounts = crate::accounts::TokenConditionalSwapTrigger;
+    type Instruction = crate::instruction::TokenConditionalSwapTriggerV2;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let liqee = account_loader
             .load_mango_account(&self.liqee)
@@ -4985,13 +6427,13 @@ pub struct TokenConditionalSwapStartInstruction {
 }
 #[async_trait::async_trait(?Send)]
 impl ClientInstruction for TokenConditionalSwapStartInstruction {
-    type Accounts = mango_v4::accounts::TokenConditionalSwapStart;
-    type Instruction = mango_v4::instruction::TokenConditionalSwapStart;
+    type Accounts = crate::accounts::TokenConditionalSwapStart;
+    type Instruction = crate::instruction::TokenConditionalSwapStart;
     async fn to_instruction(
         &self,
         account_loader: impl ClientAccountLoader + 'async_trait,
     ) -> (Self::Accounts, instruction::Instruction) {
-        let program_id = mango_v4::id();
+        let program_id = crate::id();
 
         let liqee = account_loader
             .load_mango_account(&self.liqee)
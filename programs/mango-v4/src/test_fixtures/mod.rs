@@ -1,3 +1,15 @@
+//! solana-program-test scaffolding for integration tests against mango, behind
+//! the `test-fixtures` feature. mango's own tests in tests/cases use it too.
+//!
+//! TestContextBuilder starts a ProgramTest with the mango program, mints and
+//! funded users; mango_setup::GroupWithTokensConfig creates a group with tokens
+//! and stub oracles, and mango_setup::create_funded_account an account with
+//! deposits. mango_client has a ClientInstruction for every mango instruction.
+//!
+//! The serum and margin trade programs are loaded from serum_dex.so and
+//! margin_trade.so, which ProgramTest looks for in tests/fixtures or BPF_OUT_DIR.
+//! Enable `enable-gpl` as well, otherwise the mango instructions do nothing.
+
 #![allow(dead_code)]
 
 use std::cell::RefCell;
@@ -53,6 +65,29 @@ pub struct MarginTradeCookie {
     pub token_account_bump: u8,
 }
 
+/// A program that forwards its instruction data and accounts to the program passed
+/// as first account. Used for testing instructions that are invoked via CPI.
+fn cpi_forward_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[solana_program::account_info::AccountInfo],
+    data: &[u8],
+) -> solana_program::entrypoint::ProgramResult {
+    let target_program = &accounts[0];
+    let instruction = solana_program::instruction::Instruction {
+        program_id: *target_program.key,
+        accounts: accounts[1..]
+            .iter()
+            .map(|ai| solana_program::instruction::AccountMeta {
+                pubkey: *ai.key,
+                is_signer: ai.is_signer,
+                is_writable: ai.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    solana_program::program::invoke(&instruction, accounts)
+}
+
 pub struct TestContextBuilder {
     test: ProgramTest,
     mint0: Pubkey,
@@ -71,7 +106,7 @@ impl TestContextBuilder {
                 .build();
         let _ = log::set_boxed_logger(Box::new(env_logger));
 
-        let mut test = ProgramTest::new("mango_v4", mango_v4::id(), processor!(mango_v4::entry));
+        let mut test = ProgramTest::new("mango_v4", crate::id(), processor!(crate::entry));
 
         // intentionally set to as tight as possible, to catch potential problems early
         test.set_compute_max_units(80000);
@@ -218,6 +253,16 @@ impl TestContextBuilder {
         }
     }
 
+    pub fn add_cpi_forward_program(&mut self) -> Pubkey {
+        let program = Pubkey::new_unique();
+        self.test.add_program(
+            "cpi_forward",
+            program,
+            processor!(cpi_forward_process_instruction),
+        );
+        program
+    }
+
     pub async fn start_default(mut self) -> TestContext {
         let mints = self.create_mints();
         let users = self.create_users(&mints);
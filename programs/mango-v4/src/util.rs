@@ -1,5 +1,6 @@
 use crate::error::MangoError;
 use anchor_lang::prelude::*;
+use fixed::types::I80F48;
 
 #[macro_export]
 macro_rules! zip {
@@ -31,6 +32,44 @@ pub fn format_zero_terminated_utf8_bytes(
     )
 }
 
+/// Native amount of an indexed token position.
+///
+/// Positive positions grow with the deposit index, negative ones with the borrow index.
+pub fn indexed_to_native(
+    indexed_position: I80F48,
+    deposit_index: I80F48,
+    borrow_index: I80F48,
+) -> I80F48 {
+    if indexed_position.is_positive() {
+        indexed_position * deposit_index
+    } else {
+        indexed_position * borrow_index
+    }
+}
+
+/// Indexed position that corresponds to a native amount, inverse of indexed_to_native().
+pub fn native_to_indexed(native: I80F48, deposit_index: I80F48, borrow_index: I80F48) -> I80F48 {
+    if native.is_positive() {
+        native / deposit_index
+    } else {
+        native / borrow_index
+    }
+}
+
+fn decimals_factor(decimals: u8) -> I80F48 {
+    I80F48::from(10u64.pow(decimals.into()))
+}
+
+/// Converts a native token amount to ui units, given the mint decimals
+pub fn native_to_ui(native: I80F48, decimals: u8) -> I80F48 {
+    native / decimals_factor(decimals)
+}
+
+/// Converts a ui token amount to native units, given the mint decimals
+pub fn ui_to_native(ui: I80F48, decimals: u8) -> I80F48 {
+    ui * decimals_factor(decimals)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +87,34 @@ mod tests {
         );
         assert!(fill_from_str::<4>("abcde").is_err());
     }
+
+    #[test]
+    fn test_amount_conversions() {
+        let deposit_index = I80F48::from_num(1.5);
+        let borrow_index = I80F48::from_num(2.0);
+
+        let native = indexed_to_native(I80F48::from(10), deposit_index, borrow_index);
+        assert_eq!(native, I80F48::from(15));
+        assert_eq!(
+            native_to_indexed(native, deposit_index, borrow_index),
+            I80F48::from(10)
+        );
+
+        let native = indexed_to_native(I80F48::from(-10), deposit_index, borrow_index);
+        assert_eq!(native, I80F48::from(-20));
+        assert_eq!(
+            native_to_indexed(native, deposit_index, borrow_index),
+            I80F48::from(-10)
+        );
+
+        assert_eq!(
+            native_to_ui(I80F48::from(1_500_000), 6),
+            I80F48::from_num(1.5)
+        );
+        assert_eq!(
+            ui_to_native(I80F48::from_num(1.5), 6),
+            I80F48::from(1_500_000)
+        );
+        assert_eq!(native_to_ui(I80F48::from(7), 0), I80F48::from(7));
+    }
 }
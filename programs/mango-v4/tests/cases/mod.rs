@@ -18,13 +18,16 @@ mod test_basic;
 mod test_benchmark;
 mod test_borrow_limits;
 mod test_delegate;
+mod test_fast_listing;
 mod test_fees_buyback_with_mngo;
 mod test_force_close;
 mod test_health_compute;
 mod test_health_region;
+mod test_isolated_account;
 mod test_ix_gate_set;
 mod test_liq_perps_bankruptcy;
 mod test_liq_perps_base_and_bankruptcy;
+mod test_liq_perps_combined;
 mod test_liq_perps_force_cancel;
 mod test_liq_perps_positive_pnl;
 mod test_liq_tokens;
@@ -34,6 +37,7 @@ mod test_perp_settle;
 mod test_perp_settle_fees;
 mod test_position_lifetime;
 mod test_reduce_only;
+mod test_referral;
 mod test_serum;
 mod test_stale_oracles;
 mod test_token_conditional_swap;
@@ -1,4 +1,5 @@
 use super::*;
+use mango_v4::address_lookup_table_program::addresses;
 
 #[tokio::test]
 async fn test_alt() -> Result<(), TransportError> {
@@ -47,23 +48,135 @@ async fn test_alt() -> Result<(), TransportError> {
     assert!(group_data.address_lookup_tables[1] == Pubkey::default());
 
     //
-    // TEST: Extend the lookup table
+    // TEST: Create a lookup table owned by the group
     //
-    /* FUTURE: See alt_set
-    assert_eq!(address_lookup_table_program::addresses(&solana.get_account_data(address_lookup_table).await.unwrap()).len(), 0);
+    send_tx(
+        solana,
+        AltCreateInstruction {
+            group,
+            admin,
+            payer,
+            index: 1,
+            recent_slot: solana.get_newest_slot_from_history().await,
+        },
+    )
+    .await
+    .unwrap();
+
+    let group_data = solana.get_account::<Group>(group).await;
+    let address_lookup_table = group_data.address_lookup_tables[1];
+    assert!(address_lookup_table != Pubkey::default());
+    let alt_addresses = |data: Vec<u8>| addresses(&data).to_vec();
+    assert_eq!(
+        alt_addresses(solana.get_account_data(address_lookup_table).await.unwrap()).len(),
+        0
+    );
+
+    // Occupied and out of range indexes are rejected
+    for index in [0, 1, 20] {
+        assert!(send_tx(
+            solana,
+            AltCreateInstruction {
+                group,
+                admin,
+                payer,
+                index,
+                recent_slot: solana.get_newest_slot_from_history().await,
+            },
+        )
+        .await
+        .is_err());
+    }
 
+    //
+    // TEST: Extend the lookup table
+    //
     let new_addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
-    send_tx(solana, AltExtendInstruction {
-        group,
-        admin,
-        payer,
-        index: 0,
-        address_lookup_table,
-        new_addresses: new_addresses.clone(),
-    }).await.unwrap();
+    send_tx(
+        solana,
+        AltExtendInstruction {
+            group,
+            admin,
+            payer,
+            index: 1,
+            address_lookup_table,
+            new_addresses: new_addresses.clone(),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        alt_addresses(solana.get_account_data(address_lookup_table).await.unwrap()),
+        new_addresses
+    );
+
+    //
+    // TEST: Listing a token registers its accounts in the lookup table
+    //
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                fast_listing_admin_opt: Some(admin.pubkey()),
+                allowed_fast_listings_per_interval_opt: Some(1),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let oracle = send_tx(
+        solana,
+        StubOracleCreate {
+            oracle: TestKeypair::new(),
+            group,
+            mint: context.mints[1].pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .oracle;
+    send_tx(
+        solana,
+        StubOracleSetInstruction {
+            oracle,
+            group,
+            admin,
+            mint: context.mints[1].pubkey,
+            price: 1.0,
+        },
+    )
+    .await
+    .unwrap();
+
+    let listing = send_tx(
+        solana,
+        TokenRegisterTrustlessInstruction {
+            token_index: 1,
+            tier: mango_v4::accounts_ix::FastListingTier::Premium,
+            group,
+            admin,
+            mint: context.mints[1].pubkey,
+            oracle,
+            payer,
+            address_lookup_table: Some(address_lookup_table),
+        },
+    )
+    .await
+    .unwrap();
 
-    assert_eq!(address_lookup_table_program::addresses(&solana.get_account_data(address_lookup_table).await.unwrap()), &new_addresses);
-    */
+    let mut expected_addresses = new_addresses;
+    expected_addresses.extend([listing.bank, listing.vault, listing.mint_info, oracle]);
+    assert_eq!(
+        alt_addresses(solana.get_account_data(address_lookup_table).await.unwrap()),
+        expected_addresses
+    );
 
     Ok(())
 }
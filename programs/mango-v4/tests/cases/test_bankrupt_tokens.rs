@@ -489,6 +489,29 @@ async fn test_bankrupt_tokens_insurance_fund() -> Result<(), TransportError> {
     let liqee = get_mango_account(solana, account).await;
     assert!(liqee.being_liquidated());
 
+    // bankruptcy isn't possible while there's still collateral to liquidate
+    let insurance_vault_before = solana.token_account_balance(insurance_vault).await;
+    let result = send_tx(
+        solana,
+        TokenLiqBankruptcyInstruction {
+            liqee: account,
+            liqor: vault_account,
+            liqor_owner: owner,
+            liab_mint_info: borrow_token1.mint_info,
+            max_liab_transfer: I80F48::from_num(100000.0),
+        },
+    )
+    .await;
+    assert_mango_error(
+        &result,
+        MangoError::HasLiquidatableTokenPosition.into(),
+        "".into(),
+    );
+    assert_eq!(
+        solana.token_account_balance(insurance_vault).await,
+        insurance_vault_before
+    );
+
     // eat collateral2, leaving the account bankrupt
     send_tx(
         solana,
@@ -581,6 +604,28 @@ async fn test_bankrupt_tokens_insurance_fund() -> Result<(), TransportError> {
         account_position(solana, vault_account, borrow_token1.bank).await,
         liqor_before + usdc_amount as i64
     );
+    let log = solana
+        .program_log_events::<mango_v4::logs::TokenLiqBankruptcyLogV2>()
+        .pop()
+        .unwrap();
+    assert!(assert_equal(
+        I80F48::from_bits(log.liab_transfer),
+        liab_transfer,
+        0.01
+    ));
+    assert!(assert_equal(
+        I80F48::from_bits(log.insurance_transfer),
+        usdc_amount as f64,
+        0.01
+    ));
+    // the 2% fee on top of the liab value, in USDC
+    assert!(assert_equal(
+        I80F48::from_bits(log.liquidation_fee),
+        liab_transfer / usdc_to_liab * 0.02,
+        0.01
+    ));
+    assert!(!log.insurance_fund_exhausted);
+    assert_eq!(log.socialized_loss, 0);
 
     // bankruptcy of a non-USDC liability: USDC to liqor, liability to liqee
     // liquidating fully and then doing socialized loss because the insurance fund is exhausted
@@ -607,6 +652,12 @@ async fn test_bankrupt_tokens_insurance_fund() -> Result<(), TransportError> {
         account_position(solana, vault_account, borrow_token1.bank).await,
         liqor_before + insurance_vault_before as i64
     );
+    let log = solana
+        .program_log_events::<mango_v4::logs::TokenLiqBankruptcyLogV2>()
+        .pop()
+        .unwrap();
+    assert!(log.insurance_fund_exhausted);
+    assert!(I80F48::from_bits(log.socialized_loss) > 0);
 
     Ok(())
 }
@@ -41,6 +41,7 @@ async fn test_basic() -> Result<(), TransportError> {
             group,
             owner,
             payer,
+            referrer: None,
         },
     )
     .await
@@ -146,6 +147,7 @@ async fn test_basic() -> Result<(), TransportError> {
         )
         .await
         .unwrap();
+        let balance_logs = solana.program_log_events::<mango_v4::logs::TokenBalanceLog>();
 
         assert_eq!(solana.token_account_balance(vault).await, deposit_amount);
         assert_eq!(
@@ -162,6 +164,23 @@ async fn test_basic() -> Result<(), TransportError> {
         let account_data: MangoAccount = solana.get_account(account).await;
         // Assumes oracle price of 1
         assert_eq!(account_data.net_deposits, deposit_amount as i64);
+
+        // The balance log snapshots the position and the indexes it is relative to
+        assert_eq!(balance_logs.len(), 1);
+        assert_eq!(balance_logs[0].mango_account, account);
+        assert_eq!(balance_logs[0].token_index, tokens[0].index);
+        assert_eq!(
+            balance_logs[0].indexed_position,
+            account_data.tokens[0].indexed_position.to_bits()
+        );
+        assert_eq!(
+            balance_logs[0].deposit_index,
+            bank_data.deposit_index.to_bits()
+        );
+        assert_eq!(
+            balance_logs[0].borrow_index,
+            bank_data.borrow_index.to_bits()
+        );
     }
 
     //
@@ -169,6 +188,96 @@ async fn test_basic() -> Result<(), TransportError> {
     //
     assert_eq!(account_init_health(solana, account).await.round(), 60.0);
 
+    // The positions are logged as a separate event
+    {
+        let positions = solana
+            .program_log_events::<mango_v4::events::MangoAccountPositions>()
+            .pop()
+            .unwrap();
+        assert_eq!(positions.tokens.len(), 1);
+        assert_eq!(positions.tokens[0].token_index, tokens[0].index);
+        assert!(positions.serum3.is_empty());
+        assert!(positions.perps.is_empty());
+    }
+
+    // The same data is available as paginated return data, for clients that simulate
+    {
+        use anchor_lang::AnchorDeserialize;
+        use mango_v4::events::MangoAccountDataEntry;
+        let result = send_tx_get_metadata(
+            solana,
+            ComputeAccountDataPageInstruction { account, page: 0 },
+        )
+        .await
+        .unwrap();
+        let return_data = result.metadata.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, mango_v4::id());
+        let account_data =
+            mango_v4::events::MangoAccountDataPage::try_from_slice(&return_data.data).unwrap();
+        assert_eq!(account_data.init_health.round().to_num::<f64>(), 60.0);
+        assert_eq!(account_data.page, 0);
+        assert_eq!(account_data.page_count, 1);
+        assert_eq!(account_data.entries.len(), 2);
+        match &account_data.entries[0] {
+            MangoAccountDataEntry::TokenEquity(equity) => {
+                assert_eq!(equity.token_index, tokens[0].index);
+                assert!(assert_equal(equity.value, 100.0, 0.01));
+            }
+            entry => panic!("unexpected entry {:?}", entry),
+        }
+        match &account_data.entries[1] {
+            MangoAccountDataEntry::TokenPosition(position) => {
+                assert_eq!(position.token_index, tokens[0].index);
+                assert!(assert_equal(position.native, 100.0, 0.01));
+                assert_eq!(position.oracle_price, I80F48::ONE);
+            }
+            entry => panic!("unexpected entry {:?}", entry),
+        }
+
+        // there's no second page
+        assert!(send_tx(
+            solana,
+            ComputeAccountDataPageInstruction { account, page: 1 }
+        )
+        .await
+        .is_err());
+    }
+
+    // The per-position health breakdown
+    {
+        use anchor_lang::AnchorDeserialize;
+        use mango_v4::events::HealthContribution;
+        let result = send_tx_get_metadata(
+            solana,
+            ComputeHealthBreakdownInstruction { account, page: 0 },
+        )
+        .await
+        .unwrap();
+        let return_data = result.metadata.unwrap().return_data.unwrap();
+        let breakdown =
+            mango_v4::events::MangoAccountHealthBreakdown::try_from_slice(&return_data.data)
+                .unwrap();
+        assert_eq!(breakdown.init_health.round().to_num::<f64>(), 60.0);
+        assert_eq!(breakdown.page_count, 1);
+        assert_eq!(breakdown.contributions.len(), 1);
+        match &breakdown.contributions[0] {
+            HealthContribution::Token(token) => {
+                assert_eq!(token.token_index, tokens[0].index);
+                assert_eq!(token.init, breakdown.init_health);
+                assert_eq!(token.maint, breakdown.maint_health);
+            }
+            contribution => panic!("unexpected contribution {:?}", contribution),
+        }
+
+        // there's no second page
+        assert!(send_tx(
+            solana,
+            ComputeHealthBreakdownInstruction { account, page: 1 }
+        )
+        .await
+        .is_err());
+    }
+
     //
     // TEST: Withdraw funds
     //
@@ -190,6 +299,7 @@ async fn test_basic() -> Result<(), TransportError> {
         )
         .await
         .unwrap();
+        let balance_logs = solana.program_log_events::<mango_v4::logs::TokenBalanceLog>();
 
         check_prev_instruction_post_health(&solana, account).await;
 
@@ -214,6 +324,12 @@ async fn test_basic() -> Result<(), TransportError> {
             account_data.net_deposits,
             (start_amount - withdraw_amount) as i64
         );
+
+        assert_eq!(balance_logs.len(), 1);
+        assert_eq!(
+            balance_logs[0].indexed_position,
+            account_data.tokens[0].indexed_position.to_bits()
+        );
     }
 
     //
@@ -225,6 +341,7 @@ async fn test_basic() -> Result<(), TransportError> {
         solana,
         TokenUpdateIndexAndRateInstruction {
             mint_info: tokens[0].mint_info,
+            keeper: None,
         },
     )
     .await
@@ -342,6 +459,7 @@ async fn test_account_size_migration() -> Result<(), TransportError> {
             group,
             owner,
             payer,
+            referrer: None,
         },
     )
     .await
@@ -416,6 +534,87 @@ async fn test_account_size_migration() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_layout_migration() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let mango_setup::GroupWithTokens { group, tokens, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let bank = tokens[0].bank;
+
+    //
+    // TEST: New groups and banks start out at the latest layout
+    //
+
+    let group_data: Group = solana.get_account(group).await;
+    assert_eq!(group_data.layout_version, Group::LAYOUT_VERSION);
+    let bank_data: Bank = solana.get_account(bank).await;
+    assert_eq!(bank_data.layout_version, Bank::LAYOUT_VERSION);
+
+    //
+    // TEST: Accounts from before layout_version existed are upgraded in place
+    //
+
+    let mut old_group = group_data;
+    old_group.layout_version = 0;
+    let mut old_bank = bank_data;
+    old_bank.layout_version = 0;
+    for (address, data) in [
+        (group, bytemuck::bytes_of(&old_group)),
+        (bank, bytemuck::bytes_of(&old_bank)),
+    ] {
+        let mut account_raw = solana
+            .context
+            .borrow_mut()
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap();
+        account_raw.data[8..].copy_from_slice(data);
+        solana
+            .context
+            .borrow_mut()
+            .set_account(&address, &account_raw.into());
+    }
+
+    send_tx(solana, GroupMigrateInstruction { group })
+        .await
+        .unwrap();
+    send_tx(solana, TokenMigrateInstruction { bank })
+        .await
+        .unwrap();
+
+    let group_data: Group = solana.get_account(group).await;
+    assert_eq!(group_data.layout_version, Group::LAYOUT_VERSION);
+    let bank_data: Bank = solana.get_account(bank).await;
+    assert_eq!(bank_data.layout_version, Bank::LAYOUT_VERSION);
+
+    //
+    // TEST: Migrating again has no effect
+    //
+
+    let before_bytes = solana.get_account_data(bank).await;
+    send_tx(solana, TokenMigrateInstruction { bank })
+        .await
+        .unwrap();
+    let after_bytes = solana.get_account_data(bank).await;
+    assert_eq!(before_bytes, after_bytes);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_bank_maint_weight_shift() -> Result<(), TransportError> {
     let context = TestContext::new().await;
@@ -685,3 +884,680 @@ async fn test_bank_deposit_limit() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_token_deposit_via_cpi() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    let cpi_program = test_builder.add_cpi_forward_program();
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let payer_token_account = context.users[1].token_accounts[0];
+    let mints = &context.mints[0..1];
+
+    let mango_setup::GroupWithTokens { group, tokens, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], &[], 0, 0).await;
+
+    let deposit_via_cpi = |amount: u64| TokenDepositViaCpiInstruction {
+        cpi_program,
+        deposit: TokenDepositInstruction {
+            amount,
+            reduce_only: false,
+            account,
+            owner,
+            token_account: payer_token_account,
+            token_authority: payer,
+            bank_index: 0,
+        },
+    };
+
+    let group_edit = |allow_cpi_deposit| GroupEdit {
+        group,
+        admin,
+        options: mango_v4::instruction::GroupEdit {
+            allow_cpi_deposit_opt: Some(allow_cpi_deposit),
+            ..group_edit_instruction_default()
+        },
+    };
+    let direct_deposit = TokenDepositInstruction {
+        amount: 100,
+        reduce_only: false,
+        account,
+        owner,
+        token_account: payer_token_account,
+        token_authority: payer,
+        bank_index: 0,
+    };
+
+    //
+    // TEST: Deposits via CPI are rejected by default
+    //
+    send_tx_expect_error!(solana, deposit_via_cpi(100), MangoError::CpiForbidden);
+
+    // direct deposits work
+    send_tx(solana, direct_deposit.clone()).await.unwrap();
+    assert_eq!(account_position(solana, account, tokens[0].bank).await, 100);
+
+    //
+    // TEST: Deposits via CPI work once the group allows them
+    //
+    send_tx(solana, group_edit(true)).await.unwrap();
+    send_tx(solana, deposit_via_cpi(100)).await.unwrap();
+    assert_eq!(account_position(solana, account, tokens[0].bank).await, 200);
+
+    //
+    // TEST: Disallowing them again rejects deposits via CPI
+    //
+    send_tx(solana, group_edit(false)).await.unwrap();
+    send_tx_expect_error!(solana, deposit_via_cpi(100), MangoError::CpiForbidden);
+    send_tx(solana, direct_deposit).await.unwrap();
+    assert_eq!(account_position(solana, account, tokens[0].bank).await, 300);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insurance_fund_deposit_withdraw() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+    let payer_mint0_account = context.users[1].token_accounts[0];
+
+    let mango_setup::GroupWithTokens {
+        group,
+        insurance_vault,
+        ..
+    } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    //
+    // TEST: Anyone can deposit into the insurance fund
+    //
+    let payer_before = solana.token_account_balance(payer_mint0_account).await;
+    send_tx(
+        solana,
+        GroupDepositInsuranceFundInstruction {
+            group,
+            token_account: payer_mint0_account,
+            token_authority: payer,
+            amount: 1000,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.token_account_balance(insurance_vault).await, 1000);
+    assert_eq!(
+        solana.token_account_balance(payer_mint0_account).await,
+        payer_before - 1000
+    );
+
+    //
+    // TEST: Only the admin can withdraw
+    //
+    let res = send_tx(
+        solana,
+        GroupWithdrawInsuranceFundInstruction {
+            group,
+            admin: payer,
+            destination: payer_mint0_account,
+            amount: 100,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        GroupWithdrawInsuranceFundInstruction {
+            group,
+            admin,
+            destination: payer_mint0_account,
+            amount: 100,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.token_account_balance(insurance_vault).await, 900);
+
+    // withdrawals are capped at the vault balance
+    send_tx(
+        solana,
+        GroupWithdrawInsuranceFundInstruction {
+            group,
+            admin,
+            destination: payer_mint0_account,
+            amount: u64::MAX,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.token_account_balance(insurance_vault).await, 0);
+    assert_eq!(
+        solana.token_account_balance(payer_mint0_account).await,
+        payer_before
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stub_oracle_admin() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let mango_setup::GroupWithTokens { group, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    //
+    // TEST: Only the group admin can create stub oracles
+    //
+    let oracle = TestKeypair::new();
+    let res = send_tx(
+        solana,
+        StubOracleCreate {
+            oracle,
+            group,
+            mint: mints[0].pubkey,
+            admin: payer,
+            payer,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        StubOracleCreate {
+            oracle,
+            group,
+            mint: mints[0].pubkey,
+            admin,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+    let stub = solana.get_account::<StubOracle>(oracle.pubkey()).await;
+    assert_eq!(stub.group, group);
+    assert_eq!(stub.mint, mints[0].pubkey);
+    assert_eq!(stub.price, I80F48::ONE);
+
+    //
+    // TEST: Only the group admin can set the price
+    //
+    let res = send_tx(
+        solana,
+        StubOracleSetInstruction {
+            oracle: oracle.pubkey(),
+            group,
+            mint: mints[0].pubkey,
+            admin: payer,
+            price: 2.5,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        StubOracleSetInstruction {
+            oracle: oracle.pubkey(),
+            group,
+            mint: mints[0].pubkey,
+            admin,
+            price: 2.5,
+        },
+    )
+    .await
+    .unwrap();
+    let stub = solana.get_account::<StubOracle>(oracle.pubkey()).await;
+    assert_eq!(stub.price, I80F48::from_num(2.5));
+
+    //
+    // TEST: Close the stub oracle
+    //
+    send_tx(
+        solana,
+        StubOracleCloseInstruction {
+            oracle: oracle.pubkey(),
+            group,
+            mint: mints[0].pubkey,
+            admin,
+            sol_destination: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(oracle.pubkey()).await.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_create() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let insurance_mint = context.mints[0].pubkey;
+
+    let accounts = send_tx(
+        solana,
+        GroupCreateInstruction {
+            creator: admin,
+            payer,
+            insurance_mint,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: The creator becomes the admin, the other admin roles start out unset
+    //
+    let group = solana.get_account::<Group>(accounts.group).await;
+    assert_eq!(group.creator, admin.pubkey());
+    assert_eq!(group.group_num, 0);
+    assert_eq!(group.admin, admin.pubkey());
+    assert_eq!(group.fast_listing_admin, Pubkey::default());
+    assert_eq!(group.security_admin, Pubkey::default());
+    assert_eq!(group.testing, 1);
+    assert_eq!(group.version, 0);
+
+    //
+    // TEST: The insurance vault is a group-owned token account for the insurance mint
+    //
+    assert_eq!(group.insurance_mint, insurance_mint);
+    assert_eq!(group.insurance_vault, accounts.insurance_vault);
+    let vault = solana
+        .get_account::<anchor_spl::token::TokenAccount>(accounts.insurance_vault)
+        .await;
+    assert_eq!(vault.mint, insurance_mint);
+    assert_eq!(vault.owner, accounts.group);
+    assert_eq!(vault.amount, 0);
+
+    // all instructions start out enabled
+    assert!(group.is_ix_enabled(IxGate::TokenDeposit));
+    assert!(group.is_ix_enabled(IxGate::PerpPlaceOrder));
+
+    //
+    // TEST: The group address is derived from creator and group_num, so it can't be created twice
+    //
+    let res = send_tx(
+        solana,
+        GroupCreateInstruction {
+            creator: admin,
+            payer,
+            insurance_mint,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_edit() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let new_admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    let mango_setup::GroupWithTokens { group, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let group_before = solana.get_account::<Group>(group).await;
+
+    //
+    // TEST: Only the admin can edit the group
+    //
+    let res = send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin: payer,
+            options: mango_v4::instruction::GroupEdit {
+                testing_opt: Some(0),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    //
+    // TEST: Only the passed options change
+    //
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                fast_listing_admin_opt: Some(payer.pubkey()),
+                testing_opt: Some(0),
+                deposit_limit_quote_opt: Some(1_000_000),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let group_data = solana.get_account::<Group>(group).await;
+    assert_eq!(group_data.fast_listing_admin, payer.pubkey());
+    assert_eq!(group_data.testing, 0);
+    assert_eq!(group_data.deposit_limit_quote, 1_000_000);
+    assert_eq!(group_data.admin, group_before.admin);
+    assert_eq!(group_data.security_admin, group_before.security_admin);
+    assert_eq!(group_data.version, group_before.version);
+    assert_eq!(group_data.buyback_fees, group_before.buyback_fees);
+    assert_eq!(group_data.mngo_token_index, group_before.mngo_token_index);
+
+    //
+    // TEST: The admin can be rotated, after which the old admin loses access
+    //
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                admin_opt: Some(new_admin.pubkey()),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.get_account::<Group>(group).await.admin,
+        new_admin.pubkey()
+    );
+
+    let res = send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                testing_opt: Some(1),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin: new_admin,
+            options: mango_v4::instruction::GroupEdit {
+                testing_opt: Some(1),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(solana.get_account::<Group>(group).await.testing, 1);
+
+    //
+    // TEST: The admin can't be set to the default pubkey
+    //
+    let res = send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin: new_admin,
+            options: mango_v4::instruction::GroupEdit {
+                admin_opt: Some(Pubkey::default()),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    //
+    // TEST: The insurance token index must be for an existing bank of the insurance mint
+    //
+    let set_insurance_token_index = |insurance_token_index| GroupEdit {
+        group,
+        admin: new_admin,
+        options: mango_v4::instruction::GroupEdit {
+            insurance_token_index_opt: Some(insurance_token_index),
+            ..group_edit_instruction_default()
+        },
+    };
+    assert!(send_tx(solana, set_insurance_token_index(1)).await.is_err());
+    assert!(send_tx(solana, set_insurance_token_index(5)).await.is_err());
+    send_tx(solana, set_insurance_token_index(0)).await.unwrap();
+    assert_eq!(
+        solana
+            .get_account::<Group>(group)
+            .await
+            .insurance_token_index,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_close() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let payer_mint0_account = context.users[1].token_accounts[0];
+    let sol_destination = Pubkey::new_unique();
+
+    let accounts = send_tx(
+        solana,
+        GroupCreateInstruction {
+            creator: admin,
+            payer,
+            insurance_mint: context.mints[0].pubkey,
+        },
+    )
+    .await
+    .unwrap();
+    let group = accounts.group;
+    let insurance_vault = accounts.insurance_vault;
+
+    send_tx(
+        solana,
+        GroupDepositInsuranceFundInstruction {
+            group,
+            token_account: payer_mint0_account,
+            token_authority: payer,
+            amount: 100,
+        },
+    )
+    .await
+    .unwrap();
+
+    let close_ix = |admin| GroupCloseInstruction {
+        group,
+        admin,
+        sol_destination,
+    };
+
+    //
+    // TEST: Only the admin can close the group
+    //
+    assert!(send_tx(solana, close_ix(payer)).await.is_err());
+
+    //
+    // TEST: The insurance vault must be empty
+    //
+    assert!(send_tx(solana, close_ix(admin)).await.is_err());
+
+    send_tx(
+        solana,
+        GroupWithdrawInsuranceFundInstruction {
+            group,
+            admin,
+            destination: payer_mint0_account,
+            amount: 100,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Only testing groups can be closed
+    //
+    let set_testing = |testing| GroupEdit {
+        group,
+        admin,
+        options: mango_v4::instruction::GroupEdit {
+            testing_opt: Some(testing),
+            ..group_edit_instruction_default()
+        },
+    };
+    send_tx(solana, set_testing(0)).await.unwrap();
+    assert!(send_tx(solana, close_ix(admin)).await.is_err());
+    send_tx(solana, set_testing(1)).await.unwrap();
+
+    //
+    // TEST: Closing reclaims the rent of the group and insurance vault
+    //
+    let rent = {
+        let mut ctx = solana.context.borrow_mut();
+        ctx.banks_client.get_balance(group).await.unwrap()
+            + ctx.banks_client.get_balance(insurance_vault).await.unwrap()
+    };
+    send_tx(solana, close_ix(admin)).await.unwrap();
+
+    assert!(solana.get_account_data(group).await.is_none());
+    assert!(solana.get_account_data(insurance_vault).await.is_none());
+    let reclaimed = solana
+        .context
+        .borrow_mut()
+        .banks_client
+        .get_balance(sol_destination)
+        .await
+        .unwrap();
+    assert_eq!(reclaimed, rent);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_group_testing_flag() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let mango_setup::GroupWithTokens { group, tokens, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 100, 0).await;
+
+    let set_testing = |testing| GroupEdit {
+        group,
+        admin,
+        options: mango_v4::instruction::GroupEdit {
+            testing_opt: Some(testing),
+            ..group_edit_instruction_default()
+        },
+    };
+    let add_bank = |bank_num| TokenAddBankInstruction {
+        token_index: tokens[0].index,
+        bank_num,
+        group,
+        admin,
+        payer,
+    };
+
+    //
+    // TEST: Without the testing flag, testing-only instructions are rejected
+    //
+    send_tx(solana, set_testing(0)).await.unwrap();
+
+    assert!(send_tx(solana, ComputeAccountDataInstruction { account })
+        .await
+        .is_err());
+    assert!(send_tx(solana, add_bank(2)).await.is_err());
+    assert!(send_tx(
+        solana,
+        StubOracleCloseInstruction {
+            oracle: tokens[0].oracle,
+            group,
+            mint: tokens[0].mint.pubkey,
+            admin,
+            sol_destination: payer.pubkey(),
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: With the testing flag, they are allowed
+    //
+    send_tx(solana, set_testing(1)).await.unwrap();
+
+    send_tx(solana, ComputeAccountDataInstruction { account })
+        .await
+        .unwrap();
+    send_tx(solana, add_bank(2)).await.unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use super::*;
+use mango_v4::accounts_ix::FastListingTier;
+
+#[tokio::test]
+async fn test_fast_listing_tiers() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let fast_listing_admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..5];
+
+    let mango_setup::GroupWithTokens { group, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints[0..1].to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                fast_listing_admin_opt: Some(fast_listing_admin.pubkey()),
+                allowed_fast_listings_per_interval_opt: Some(3),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut oracles = vec![];
+    for mint in &mints[1..] {
+        let oracle = send_tx(
+            solana,
+            StubOracleCreate {
+                oracle: TestKeypair::new(),
+                group,
+                mint: mint.pubkey,
+                admin,
+                payer,
+            },
+        )
+        .await
+        .unwrap()
+        .oracle;
+        send_tx(
+            solana,
+            StubOracleSetInstruction {
+                oracle,
+                group,
+                admin,
+                mint: mint.pubkey,
+                price: 1.0,
+            },
+        )
+        .await
+        .unwrap();
+        oracles.push(oracle);
+    }
+
+    let register =
+        |i: usize, admin: TestKeypair, tier: FastListingTier| TokenRegisterTrustlessInstruction {
+            token_index: i as TokenIndex,
+            tier,
+            group,
+            admin,
+            mint: mints[i].pubkey,
+            oracle: oracles[i - 1],
+            payer,
+            address_lookup_table: None,
+        };
+
+    //
+    // TEST: Only the admin or the fast-listing admin can list
+    //
+    let res = send_tx(solana, register(1, payer, FastListingTier::Premium)).await;
+    assert!(res.is_err());
+
+    //
+    // TEST: Each tier uses its preset parameters
+    //
+    let tiers = [
+        (1, FastListingTier::CollateralDisabled, 0.0, 0.0, 2, -1),
+        (2, FastListingTier::Midwit, 0.5, 0.25, 0, 120),
+        (3, FastListingTier::Premium, 0.8, 0.6, 0, 120),
+    ];
+    for (i, tier, maint_asset_weight, init_asset_weight, reduce_only, max_staleness_slots) in tiers
+    {
+        let accounts = send_tx(solana, register(i, fast_listing_admin, tier))
+            .await
+            .unwrap();
+        let bank = solana.get_account::<Bank>(accounts.bank).await;
+        assert_eq!(
+            bank.maint_asset_weight,
+            I80F48::from_num(maint_asset_weight)
+        );
+        assert_eq!(bank.init_asset_weight, I80F48::from_num(init_asset_weight));
+        assert!(bank.maint_liab_weight >= I80F48::from_num(1.2));
+        assert!(bank.init_liab_weight >= bank.maint_liab_weight);
+        assert_eq!(bank.reduce_only, reduce_only);
+        assert_eq!(bank.oracle_config.max_staleness_slots, max_staleness_slots);
+    }
+
+    //
+    // TEST: The number of fast listings per interval is limited
+    //
+    let res = send_tx(
+        solana,
+        register(4, fast_listing_admin, FastListingTier::CollateralDisabled),
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
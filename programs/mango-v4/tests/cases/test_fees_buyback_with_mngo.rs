@@ -130,6 +130,7 @@ async fn test_fees_buyback_with_mngo() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -196,5 +197,155 @@ async fn test_fees_buyback_with_mngo() -> Result<(), TransportError> {
         0.1
     ));
 
+    //
+    // Test: Buyback is only possible while enabled in the group configuration
+    //
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                buyback_fees_opt: Some(false),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+    let group_data = solana.get_account::<Group>(group).await;
+    assert!(!group_data.buyback_fees());
+    assert_eq!(group_data.mngo_token_index, 1);
+    assert_eq!(group_data.buyback_fees_swap_mango_account, account_2);
+    assert_eq!(group_data.buyback_fees_mngo_bonus_factor, 1.2);
+
+    let res = send_tx(
+        solana,
+        AccountBuybackFeesWithMngo {
+            owner,
+            account: account_1,
+            mngo_bank: tokens[1].bank,
+            fees_bank: tokens[0].bank,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dao_account_token_positions() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..3];
+
+    //
+    // SETUP: Create a group and an empty dao account
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let banks = tokens.iter().map(|t| t.bank).collect::<Vec<_>>();
+
+    let dao_account = send_tx(
+        solana,
+        AccountCreateInstruction {
+            account_num: 0,
+            token_count: 8,
+            serum3_count: 0,
+            perp_count: 0,
+            perp_oo_count: 0,
+            token_conditional_swap_count: 0,
+            group,
+            owner,
+            payer,
+            referrer: None,
+        },
+    )
+    .await
+    .unwrap()
+    .account;
+
+    //
+    // TEST: Only the designated dao account can be used
+    //
+    let res = send_tx(
+        solana,
+        AccountCreateDaoTokenPositionsInstruction {
+            group,
+            admin,
+            dao_account,
+            banks: banks.clone(),
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        GroupEditFeeParameters {
+            group,
+            admin,
+            fees_mngo_token_index: 1 as TokenIndex,
+            fees_swap_mango_account: dao_account,
+            fees_mngo_bonus_factor: 1.2,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Only the admin may create the positions
+    //
+    let res = send_tx(
+        solana,
+        AccountCreateDaoTokenPositionsInstruction {
+            group,
+            admin: TestKeypair::new(),
+            dao_account,
+            banks: banks.clone(),
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    //
+    // TEST: Positions are created for all passed banks, repeating is a no-op
+    //
+    for _ in 0..2 {
+        send_tx(
+            solana,
+            AccountCreateDaoTokenPositionsInstruction {
+                group,
+                admin,
+                dao_account,
+                banks: banks.clone(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let account_data = get_mango_account(solana, dao_account).await;
+        let token_indexes = account_data
+            .active_token_positions()
+            .map(|p| p.token_index)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            token_indexes,
+            tokens.iter().map(|t| t.index).collect::<Vec<_>>()
+        );
+    }
+
     Ok(())
 }
@@ -229,6 +229,177 @@ async fn test_force_close_token() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_force_close_token_platform_fee() -> Result<(), TransportError> {
+    let test_builder = TestContextBuilder::new();
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let payer_mint_accounts = &context.users[1].token_accounts[0..2];
+
+    //
+    // SETUP: Create a group where part of the liquidation fee goes to the protocol
+    //
+
+    let mango_setup::GroupWithTokens { group, tokens, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let collateral_token = &tokens[0];
+    let borrow_token = &tokens[1];
+
+    // part of the liquidation fee goes to the protocol
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[0].pubkey,
+            fallback_oracle: Pubkey::default(),
+            options: mango_v4::instruction::TokenEdit {
+                platform_liquidation_fee_opt: Some(0.01),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    // deposit some funds, to the vaults aren't empty
+    create_funded_account(
+        &solana,
+        group,
+        owner,
+        99,
+        &context.users[1],
+        mints,
+        100000,
+        0,
+    )
+    .await;
+
+    let liqor = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[0],
+        &[mints[0]],
+        100,
+        0,
+    )
+    .await;
+    let liqee = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[0],
+        &[mints[0]],
+        100,
+        0,
+    )
+    .await;
+
+    send_tx(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 10,
+            allow_borrow: true,
+            account: liqee,
+            owner,
+            token_account: payer_mint_accounts[1],
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        TokenMakeReduceOnly {
+            admin,
+            group,
+            mint: mints[1].pubkey,
+            reduce_only: 2,
+            force_close: true,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        TokenDepositInstruction {
+            amount: 11,
+            reduce_only: false,
+            account: liqor,
+            owner,
+            token_account: payer_mint_accounts[1],
+            token_authority: payer.clone(),
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Force closing pays the platform fee into the collateral bank
+    //
+    send_tx(
+        solana,
+        TokenForceCloseBorrowsWithTokenInstruction {
+            liqee,
+            liqor,
+            liqor_owner: owner,
+            asset_token_index: collateral_token.index,
+            liab_token_index: borrow_token.index,
+            max_liab_transfer: 10000,
+            asset_bank_index: 0,
+            liab_bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert!(account_position_closed(solana, liqee, borrow_token.bank).await);
+
+    // liab includes the loan origination fee, asset fee factors are 1.02 * (1.02 + 0.01)
+    let liab_transfer = 10.005;
+    let liqee_collateral = account_position_f64(solana, liqee, collateral_token.bank).await;
+    assert!(assert_equal_f64_f64(
+        liqee_collateral,
+        100.0 - liab_transfer * 1.02 * 1.03,
+        0.01
+    ));
+    let liqor_collateral = account_position_f64(solana, liqor, collateral_token.bank).await;
+    assert!(assert_equal_f64_f64(
+        liqor_collateral,
+        100.0 + liab_transfer * 1.02 * 1.02,
+        0.01
+    ));
+
+    // the platform share was accrued to the collateral bank
+    let platform_fee = liab_transfer * 1.02 * 0.01;
+    let collateral_bank = solana.get_account::<Bank>(collateral_token.bank).await;
+    assert!(assert_equal_fixed_f64(
+        collateral_bank.collected_liquidation_fees,
+        platform_fee,
+        0.001
+    ));
+    assert!(collateral_bank.collected_fees_native.to_num::<f64>() >= platform_fee - 0.001);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_force_close_perp() -> Result<(), TransportError> {
     let context = TestContext::new().await;
@@ -350,6 +521,7 @@ async fn test_force_close_perp() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
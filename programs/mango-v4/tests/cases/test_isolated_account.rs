@@ -0,0 +1,146 @@
+use super::*;
+
+#[tokio::test]
+async fn test_isolated_account() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..3];
+
+    //
+    // SETUP: Create a group with three tokens and an account with a token0 deposit
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    // perp market 0 settles in token1
+    send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            settle_token_index: tokens[1].index,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.8,
+            init_base_asset_weight: 0.6,
+            maint_base_liab_weight: 1.2,
+            init_base_liab_weight: 1.4,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[2]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[0..1],
+        100,
+        0,
+    )
+    .await;
+
+    let deposit = |bank_index: usize| TokenDepositInstruction {
+        amount: 10,
+        reduce_only: false,
+        account,
+        owner,
+        token_account: context.users[0].token_accounts[bank_index],
+        token_authority: owner,
+        bank_index,
+    };
+
+    //
+    // TEST: Can't isolate an account that has positions outside of the subset
+    //
+    send_tx_expect_error!(
+        solana,
+        AccountIsolateInstruction {
+            account,
+            owner,
+            isolated: true,
+            perp_market_index: 0,
+            token_indexes: vec![tokens[1].index],
+        },
+        MangoError::IsolatedAccountPositionNotAllowed
+    );
+
+    //
+    // TEST: Can't isolate to a perp market without its settle token
+    //
+    send_tx_expect_error!(
+        solana,
+        AccountIsolateInstruction {
+            account,
+            owner,
+            isolated: true,
+            perp_market_index: 0,
+            token_indexes: vec![tokens[0].index],
+        },
+        MangoError::IsolatedAccountPositionNotAllowed
+    );
+
+    //
+    // TEST: Isolate to token0 and token1
+    //
+    send_tx(
+        solana,
+        AccountIsolateInstruction {
+            account,
+            owner,
+            isolated: true,
+            perp_market_index: 0,
+            token_indexes: vec![tokens[0].index, tokens[1].index],
+        },
+    )
+    .await
+    .unwrap();
+    let mango_account = get_mango_account(solana, account).await;
+    assert!(mango_account.fixed.is_isolated());
+
+    //
+    // TEST: Deposits into isolated tokens work, others fail
+    //
+    send_tx(solana, deposit(1)).await.unwrap();
+    send_tx_expect_error!(
+        solana,
+        deposit(2),
+        MangoError::IsolatedAccountPositionNotAllowed
+    );
+
+    //
+    // TEST: Without isolation, any token can be used again
+    //
+    send_tx(
+        solana,
+        AccountIsolateInstruction {
+            account,
+            owner,
+            isolated: false,
+            perp_market_index: PerpMarketIndex::MAX,
+            token_indexes: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(solana, deposit(2)).await.unwrap();
+    assert_eq!(account_position(solana, account, tokens[2].bank).await, 10);
+
+    Ok(())
+}
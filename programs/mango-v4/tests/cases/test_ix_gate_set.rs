@@ -186,3 +186,183 @@ async fn test_ix_gate_set() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_security_admin_freeze() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let security_admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let mango_setup::GroupWithTokens { group, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                security_admin_opt: Some(security_admin.pubkey()),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 10, 0).await;
+
+    //
+    // TEST: Others can't freeze
+    //
+    let res = send_tx(
+        solana,
+        AccountToggleFreezeInstruction {
+            group,
+            admin: payer,
+            account,
+            freeze: true,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    //
+    // TEST: Security admin can freeze
+    //
+    send_tx(
+        solana,
+        AccountToggleFreezeInstruction {
+            group,
+            admin: security_admin,
+            account,
+            freeze: true,
+        },
+    )
+    .await
+    .unwrap();
+    let account_data = get_mango_account(solana, account).await;
+    assert!(account_data.fixed.frozen_until > 0);
+
+    //
+    // TEST: Security admin can't unfreeze, the admin can
+    //
+    let res = send_tx(
+        solana,
+        AccountToggleFreezeInstruction {
+            group,
+            admin: security_admin,
+            account,
+            freeze: false,
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        AccountToggleFreezeInstruction {
+            group,
+            admin,
+            account,
+            freeze: false,
+        },
+    )
+    .await
+    .unwrap();
+    let account_data = get_mango_account(solana, account).await;
+    assert_eq!(account_data.fixed.frozen_until, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ix_gate_disabled_error() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let mango_setup::GroupWithTokens { group, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        IxGateSetInstruction {
+            group,
+            admin,
+            ix_gate: 1 << IxGate::AccountCreate as u128,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: A disabled instruction fails with IxIsDisabled
+    //
+    let res = send_tx(
+        solana,
+        AccountCreateInstruction {
+            account_num: 0,
+            group,
+            owner,
+            payer,
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_mango_error(
+        &res,
+        MangoError::IxIsDisabled.into(),
+        "account create is disabled".into(),
+    );
+
+    //
+    // TEST: Re-enabling the instruction makes it usable again
+    //
+    send_tx(
+        solana,
+        IxGateSetInstruction {
+            group,
+            admin,
+            ix_gate: 0,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        AccountCreateInstruction {
+            account_num: 0,
+            group,
+            owner,
+            payer,
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    Ok(())
+}
@@ -177,6 +177,7 @@ async fn test_liq_perps_bankruptcy() -> Result<(), TransportError> {
         tx.add_instruction(PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account, helper_account],
+            keeper: None,
         })
         .await;
         tx.send().await.unwrap();
@@ -206,6 +207,7 @@ async fn test_liq_perps_bankruptcy() -> Result<(), TransportError> {
         tx.add_instruction(PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account, helper_account],
+            keeper: None,
         })
         .await;
         tx.send().await.unwrap();
@@ -450,3 +452,239 @@ async fn test_liq_perps_bankruptcy() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_liq_perps_auto_deleverage() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(200_000);
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..3];
+
+    //
+    // SETUP: Create a group with an empty insurance fund
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        zero_token_is_quote: true,
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        TokenEditWeights {
+            group,
+            admin,
+            mint: mints[2].pubkey,
+            maint_liab_weight: 1.0,
+            maint_asset_weight: 1.0,
+            init_liab_weight: 1.0,
+            init_asset_weight: 1.0,
+        },
+    )
+    .await
+    .unwrap();
+
+    let base_token = &tokens[1]; // used for perp market
+    let collateral_token = &tokens[2]; // used for adjusting account health
+
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 1,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.8,
+            init_base_asset_weight: 0.6,
+            maint_base_liab_weight: 1.2,
+            init_base_liab_weight: 1.4,
+            base_liquidation_fee: 0.05,
+            maker_fee: 0.0,
+            taker_fee: 0.0,
+            group_insurance_fund: true,
+            settle_pnl_limit_factor: -1.0,
+            settle_pnl_limit_window_size_ts: 24 * 60 * 60,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, base_token).await
+        },
+    )
+    .await
+    .unwrap();
+    set_perp_stub_oracle_price(solana, group, perp_market, &base_token, admin, 1.0).await;
+    set_bank_stub_oracle_price(solana, group, &collateral_token, admin, 1.0).await;
+
+    let deposit_amount = 1000;
+    let counterparty = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[2..3],
+        deposit_amount,
+        0,
+    )
+    .await;
+    let account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[1],
+        &mints[2..3],
+        deposit_amount,
+        0,
+    )
+    .await;
+
+    //
+    // SETUP: The account goes long 10 lots at price 1.0
+    //
+    let price_lots = 100;
+    let mut tx = ClientTransaction::new(solana);
+    tx.add_instruction(PerpPlaceOrderInstruction {
+        account: counterparty,
+        perp_market,
+        owner,
+        side: Side::Ask,
+        price_lots,
+        max_base_lots: 10,
+        ..PerpPlaceOrderInstruction::default()
+    })
+    .await;
+    tx.add_instruction(PerpPlaceOrderInstruction {
+        account,
+        perp_market,
+        owner,
+        side: Side::Bid,
+        price_lots,
+        max_base_lots: 10,
+        ..PerpPlaceOrderInstruction::default()
+    })
+    .await;
+    tx.add_instruction(PerpConsumeEventsInstruction {
+        perp_market,
+        mango_accounts: vec![account, counterparty],
+        keeper: None,
+    })
+    .await;
+    tx.send().await.unwrap();
+
+    let adl = PerpLiqAutoDeleverageInstruction {
+        liqee: account,
+        counterparty,
+        perp_market,
+        max_base_transfer: u64::MAX,
+    };
+
+    //
+    // TEST: Can't deleverage an account that isn't bankrupt
+    //
+    // pnl: -500, collateral: 1000 * 0.55 => equity 50, while maint health is -50
+    set_perp_stub_oracle_price(solana, group, perp_market, &base_token, admin, 0.5).await;
+    set_bank_stub_oracle_price(solana, group, &collateral_token, admin, 0.55).await;
+    assert!(send_tx(solana, adl.clone()).await.is_err());
+
+    //
+    // TEST: Can't deleverage while the insurance fund can cover the loss
+    //
+    send_tx(
+        solana,
+        GroupDepositInsuranceFundInstruction {
+            group,
+            token_account: context.users[1].token_accounts[0],
+            token_authority: payer,
+            amount: 60,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PerpUpdateAdlCandidateInstruction {
+            account: counterparty,
+            perp_market,
+        },
+    )
+    .await
+    .unwrap();
+
+    // pnl: -500, collateral: 1000 * 0.45 => equity -50
+    set_bank_stub_oracle_price(solana, group, &collateral_token, admin, 0.45).await;
+    assert!(send_tx(solana, adl.clone()).await.is_err());
+
+    //
+    // TEST: Only the adl candidate can be the counterparty
+    //
+    // pnl: -500, collateral: 1000 * 0.4 => equity -100, more than the insurance fund
+    set_bank_stub_oracle_price(solana, group, &collateral_token, admin, 0.4).await;
+    let mut perp_market_data = solana.get_account::<PerpMarket>(perp_market).await;
+    assert_eq!(perp_market_data.adl_candidate_short, counterparty);
+    perp_market_data.adl_candidate_short = Pubkey::default();
+    solana.set_account(perp_market, &perp_market_data).await;
+    assert!(send_tx(solana, adl.clone()).await.is_err());
+
+    send_tx(
+        solana,
+        PerpUpdateAdlCandidateInstruction {
+            account: counterparty,
+            perp_market,
+        },
+    )
+    .await
+    .unwrap();
+    let perp_market_data = solana.get_account::<PerpMarket>(perp_market).await;
+    assert_eq!(perp_market_data.adl_candidate_short, counterparty);
+    assert!(perp_market_data.adl_candidate_short_score > 0.0);
+
+    //
+    // TEST: Deleverage at the bankruptcy price of 0.6
+    //
+    send_tx(solana, adl).await.unwrap();
+
+    let account_data = solana.get_account::<MangoAccount>(account).await;
+    assert_eq!(account_data.perps[0].base_position_lots(), 0);
+    assert!(assert_equal(
+        account_data.perps[0].quote_position_native(),
+        -400.0,
+        0.01
+    ));
+    // equity is zero now, but liquidation end health is still negative
+    assert_eq!(account_data.being_liquidated, 1);
+
+    let counterparty_data = solana.get_account::<MangoAccount>(counterparty).await;
+    assert_eq!(counterparty_data.perps[0].base_position_lots(), 0);
+    assert!(assert_equal(
+        counterparty_data.perps[0].quote_position_native(),
+        400.0,
+        0.01
+    ));
+
+    // the counterparty has no position left, so it's no longer the candidate
+    let perp_market_data = solana.get_account::<PerpMarket>(perp_market).await;
+    assert_eq!(perp_market_data.adl_candidate_short, Pubkey::default());
+
+    let log = solana
+        .program_log_events::<mango_v4::logs::PerpLiqAutoDeleverageLog>()
+        .pop()
+        .unwrap();
+    assert_eq!(log.base_transfer_liqee, -10);
+    assert!(assert_equal(
+        I80F48::from_bits(log.bankruptcy_price),
+        0.6,
+        0.0001
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use super::*;
+
+#[tokio::test]
+async fn test_liq_perps_combined() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(400_000); // runs three liquidation steps
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..3];
+    let payer_mint_accounts = &context.users[1].token_accounts[0..3];
+
+    //
+    // SETUP: Create a group and an account to fill the vaults
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        zero_token_is_quote: true,
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let quote_token = &tokens[0];
+    let base_token = &tokens[1];
+    let borrow_token = &tokens[2];
+
+    // deposit some funds, to the vaults aren't empty
+    let liqor = create_funded_account(
+        &solana,
+        group,
+        owner,
+        250,
+        &context.users[1],
+        mints,
+        10000,
+        0,
+    )
+    .await;
+
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.8,
+            init_base_asset_weight: 0.6,
+            maint_base_liab_weight: 1.2,
+            init_base_liab_weight: 1.4,
+            base_liquidation_fee: 0.05,
+            maker_fee: 0.0,
+            taker_fee: 0.0,
+            settle_pnl_limit_factor: 0.2,
+            settle_pnl_limit_window_size_ts: 24 * 60 * 60,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, base_token).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let perp_market = solana.get_account::<PerpMarket>(perp_market).await;
+        perp_market.native_price_to_lot(I80F48::ONE)
+    };
+
+    //
+    // SETUP: Make the liqee go long, keep a bid open and borrow some tokens
+    //
+    let liqee = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[0..1],
+        1000,
+        0,
+    )
+    .await;
+    let counterparty = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[1],
+        &mints[0..1],
+        1000,
+        0,
+    )
+    .await;
+
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: liqee,
+            perp_market,
+            owner,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 10,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: counterparty,
+            perp_market,
+            owner,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 10,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PerpConsumeEventsInstruction {
+            perp_market,
+            mango_accounts: vec![liqee, counterparty],
+            keeper: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: liqee,
+            perp_market,
+            owner,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 100,
+            allow_borrow: true,
+            account: liqee,
+            owner,
+            token_account: payer_mint_accounts[2],
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // SETUP: Change the oracle to make health go negative
+    // maint health is 1000 - 100.05 * 1.2 + 1000 * 0.1 * 0.8 - 1000 = -40
+    //
+    set_bank_stub_oracle_price(solana, group, base_token, admin, 0.1).await;
+    assert!(account_maint_health(solana, liqee).await < 0.0);
+
+    //
+    // TEST: Cancel orders, take over some base and liquidate some of the borrow at once
+    //
+    send_tx(
+        solana,
+        PerpLiqCombinedInstruction {
+            liqor,
+            liqor_owner: owner,
+            liqee,
+            perp_market,
+            limit: 10,
+            max_base_transfer: 2,
+            max_pnl_transfer: 0,
+            asset_token_index: quote_token.index,
+            liab_token_index: borrow_token.index,
+            max_liab_transfer: I80F48::from_num(10),
+        },
+    )
+    .await
+    .unwrap();
+
+    let liqee_data = solana.get_account::<MangoAccount>(liqee).await;
+    assert!(!liqee_data.perps[0].has_open_orders());
+    assert_eq!(liqee_data.perps[0].base_position_lots(), 8);
+    assert!(get_mango_account(solana, liqee).await.being_liquidated());
+
+    let liqor_data = solana.get_account::<MangoAccount>(liqor).await;
+    assert_eq!(liqor_data.perps[0].base_position_lots(), 2);
+
+    assert!(assert_equal_f64_f64(
+        account_position_f64(solana, liqee, borrow_token.bank).await,
+        -100.05 + 10.0,
+        0.01
+    ));
+
+    Ok(())
+}
@@ -184,6 +184,7 @@ async fn test_liq_perps_positive_pnl() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
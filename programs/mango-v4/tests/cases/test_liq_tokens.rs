@@ -110,6 +110,23 @@ async fn test_liq_tokens_force_cancel() -> Result<(), TransportError> {
     .await
     .unwrap();
 
+    //
+    // TEST: force cancel does nothing while the account is healthy
+    //
+    send_tx(
+        solana,
+        Serum3LiqForceCancelOrdersInstruction {
+            account,
+            serum_market,
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    let account_data = get_mango_account(solana, account).await;
+    let serum_orders = account_data.serum3_orders_by_raw_index(0).unwrap();
+    assert_eq!(serum_orders.potential_base_tokens, 500);
+
     //
     // TEST: Change the oracle to make health go negative
     //
@@ -144,6 +161,10 @@ async fn test_liq_tokens_force_cancel() -> Result<(), TransportError> {
     .await
     .unwrap();
 
+    let account_data = get_mango_account(solana, account).await;
+    let serum_orders = account_data.serum3_orders_by_raw_index(0).unwrap();
+    assert_eq!(serum_orders.potential_base_tokens, 0);
+
     // can withdraw again
     send_tx(
         solana,
@@ -325,7 +346,40 @@ async fn test_liq_tokens_with_token() -> Result<(), TransportError> {
     set_bank_stub_oracle_price(solana, group, borrow_token1, admin, 2.0).await;
 
     //
-    // TEST: liquidate borrow2 against too little collateral2
+    // TEST: only the liqor's owner or delegate can liquidate with it
+    //
+    let liqor_delegate = TestKeypair::new();
+    send_tx(
+        solana,
+        AccountEditInstruction {
+            delegate: liqor_delegate.pubkey(),
+            account_num: 2,
+            group,
+            owner,
+            name: "liqor".to_owned(),
+        },
+    )
+    .await
+    .unwrap();
+
+    let res = send_tx(
+        solana,
+        TokenLiqWithTokenInstruction {
+            liqee: account,
+            liqor: vault_account,
+            liqor_owner: TestKeypair::new(),
+            asset_token_index: collateral_token2.index,
+            liab_token_index: borrow_token2.index,
+            asset_bank_index: 0,
+            liab_bank_index: 0,
+            max_liab_transfer: I80F48::from_num(10000.0),
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    //
+    // TEST: liquidate borrow2 against too little collateral2, signed by the liqor's delegate
     //
 
     send_tx(
@@ -333,7 +387,7 @@ async fn test_liq_tokens_with_token() -> Result<(), TransportError> {
         TokenLiqWithTokenInstruction {
             liqee: account,
             liqor: vault_account,
-            liqor_owner: owner,
+            liqor_owner: liqor_delegate,
             asset_token_index: collateral_token2.index,
             liab_token_index: borrow_token2.index,
             asset_bank_index: 0,
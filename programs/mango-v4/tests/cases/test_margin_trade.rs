@@ -1,4 +1,5 @@
 use super::*;
+use anchor_lang::prelude::AccountMeta;
 
 // This is an unspecific happy-case test that just runs a few instructions to check
 // that they work in principle. It should be split up / renamed.
@@ -388,6 +389,44 @@ async fn test_flash_loan_swap_fee() -> Result<(), BanksClientError> {
         tx.send().await.unwrap();
     }
 
+    //
+    // TEST: the flash loan log breaks down the cost per token
+    //
+    let logs = solana.program_log_events::<mango_v4::logs::FlashLoanLogV3>();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].mango_account, account);
+    let detail = |token_index: TokenIndex| {
+        logs[0]
+            .token_loan_details
+            .iter()
+            .find(|d| d.token_index == token_index)
+            .unwrap()
+    };
+    let fixed_f64 = |bits: i128| I80F48::from_bits(bits).to_num::<f64>();
+
+    let out_detail = detail(tokens[0].index);
+    assert_eq!(out_detail.approved_amount, withdraw_amount);
+    assert!(balance_f64eq(
+        fixed_f64(out_detail.change_amount),
+        -(withdraw_amount as f64)
+    ));
+    // the account had enough deposits, so nothing was borrowed
+    assert_eq!(out_detail.loan, 0);
+    assert_eq!(out_detail.loan_origination_fee, 0);
+    assert!(balance_f64eq(
+        fixed_f64(out_detail.swap_fee),
+        withdraw_amount as f64 * swap_fee_rate
+    ));
+    assert!(balance_f64eq(fixed_f64(out_detail.price), 1.0));
+
+    let in_detail = detail(tokens[1].index);
+    assert_eq!(in_detail.approved_amount, 0);
+    assert!(balance_f64eq(
+        fixed_f64(in_detail.change_amount),
+        deposit_amount as f64
+    ));
+    assert_eq!(in_detail.swap_fee, 0);
+
     let after_owner_balance0 = solana.token_account_balance(owner_accounts[0]).await;
     let after_owner_balance1 = solana.token_account_balance(owner_accounts[1]).await;
     let after_payer_balance0 = solana.token_account_balance(payer_accounts[0]).await;
@@ -732,3 +771,306 @@ async fn test_margin_trade_deposit_limit() -> Result<(), BanksClientError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_flash_loan_errors() -> Result<(), BanksClientError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(100_000);
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    //
+    // SETUP: Create a group and a funded account
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    let loans = vec![FlashLoanPart {
+        bank: tokens[0].bank,
+        token_account: context.users[0].token_accounts[0],
+        withdraw_amount: 100,
+    }];
+
+    //
+    // TEST: FlashLoanBegin requires a FlashLoanEnd in the same transaction
+    //
+    {
+        let mut tx = ClientTransaction::new(solana);
+        tx.add_instruction(FlashLoanBeginInstruction {
+            account,
+            owner,
+            loans: loans.clone(),
+        })
+        .await;
+        tx.send_expect_error(MangoError::MissingFlashLoanEnd)
+            .await
+            .unwrap();
+    }
+
+    //
+    // TEST: No other mango instructions are allowed between Begin and End
+    //
+    {
+        let mut tx = ClientTransaction::new(solana);
+        tx.add_instruction(FlashLoanBeginInstruction {
+            account,
+            owner,
+            loans: loans.clone(),
+        })
+        .await;
+        tx.add_instruction(TokenDepositInstruction {
+            amount: 1,
+            reduce_only: false,
+            account,
+            owner,
+            token_account: context.users[1].token_accounts[0],
+            token_authority: payer,
+            bank_index: 0,
+        })
+        .await;
+        tx.add_instruction(FlashLoanEndInstruction {
+            account,
+            owner,
+            loans,
+            flash_loan_type: mango_v4::accounts_ix::FlashLoanType::Unknown,
+        })
+        .await;
+        tx.send_expect_error(MangoError::UnexpectedFlashLoanInstruction)
+            .await
+            .unwrap();
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_margin_trade_swap_program_cpi() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(100_000);
+    let swap_program = test_builder.add_cpi_forward_program();
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let delegate = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let owner_token0 = context.users[0].token_accounts[0];
+    let owner_token1 = context.users[0].token_accounts[1];
+
+    //
+    // SETUP: Create a group, a funded account for liquidity and a user account with a deposit
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    create_funded_account(&solana, group, owner, 1, &context.users[1], mints, 1000, 0).await;
+    let account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[0..1],
+        100,
+        0,
+    )
+    .await;
+
+    // The swap program forwards token program instructions that move tokens
+    // directly into or out of the vaults.
+    let via_swap_program = |ix: solana_program::instruction::Instruction| {
+        let mut accounts = vec![AccountMeta::new_readonly(spl_token::ID, false)];
+        accounts.extend(ix.accounts);
+        solana_program::instruction::Instruction {
+            program_id: swap_program,
+            accounts,
+            data: ix.data,
+        }
+    };
+    let withdraw_from_vault = |token: &mango_setup::Token, to: Pubkey, amount: u64| {
+        via_swap_program(
+            spl_token::instruction::transfer(
+                &spl_token::ID,
+                &token.vault,
+                &to,
+                &group,
+                &[],
+                amount,
+            )
+            .unwrap(),
+        )
+    };
+    let margin_trade = |banks: Vec<Pubkey>, swap_instruction| MarginTradeInstruction {
+        account,
+        owner,
+        banks,
+        swap_instruction,
+    };
+
+    //
+    // TEST: The swap program must be allowed
+    //
+    send_tx_expect_error!(
+        solana,
+        margin_trade(
+            vec![tokens[0].bank],
+            withdraw_from_vault(&tokens[0], owner_token0, 30)
+        ),
+        MangoError::SwapProgramNotAllowed
+    );
+
+    //
+    // TEST: Programs the group could sign for directly can't be allowed
+    //
+    for program in [
+        mango_v4::id(),
+        spl_token::ID,
+        solana_program::system_program::ID,
+    ] {
+        send_tx_expect_error!(
+            solana,
+            GroupEdit {
+                group,
+                admin,
+                options: mango_v4::instruction::GroupEdit {
+                    allowed_swap_programs_opt: Some(vec![program]),
+                    ..group_edit_instruction_default()
+                },
+            },
+            MangoError::SomeError
+        );
+    }
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                allowed_swap_programs_opt: Some(vec![swap_program]),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Delegates may only use the Jupiter swap programs
+    //
+    send_tx(
+        solana,
+        AccountEditInstruction {
+            delegate: delegate.pubkey(),
+            account_num: 0,
+            group,
+            owner,
+            name: "delegated".to_owned(),
+        },
+    )
+    .await
+    .unwrap();
+    send_tx_expect_error!(
+        solana,
+        MarginTradeInstruction {
+            account,
+            owner: delegate,
+            banks: vec![tokens[0].bank],
+            swap_instruction: withdraw_from_vault(&tokens[0], owner_token0, 30),
+        },
+        MangoError::SwapProgramNotAllowed
+    );
+
+    //
+    // TEST: Vaults of banks that are not in the health accounts can't be used
+    //
+    send_tx_expect_error!(
+        solana,
+        margin_trade(
+            vec![tokens[0].bank],
+            withdraw_from_vault(&tokens[1], owner_token1, 30)
+        ),
+        MangoError::HealthAccountBankMissing
+    );
+
+    //
+    // TEST: Tokens leaving a vault are withdrawn from the account
+    //
+    let owner_token0_before = solana.token_account_balance(owner_token0).await;
+    send_tx(
+        solana,
+        margin_trade(
+            vec![tokens[0].bank],
+            withdraw_from_vault(&tokens[0], owner_token0, 30),
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        solana.token_account_balance(owner_token0).await,
+        owner_token0_before + 30
+    );
+    assert_eq!(account_position(solana, account, tokens[0].bank).await, 70);
+
+    //
+    // TEST: Tokens entering a vault are deposited to the account
+    //
+    send_tx(
+        solana,
+        margin_trade(
+            vec![tokens[1].bank],
+            via_swap_program(
+                spl_token::instruction::transfer(
+                    &spl_token::ID,
+                    &owner_token1,
+                    &tokens[1].vault,
+                    &owner.pubkey(),
+                    &[],
+                    20,
+                )
+                .unwrap(),
+            ),
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(account_position(solana, account, tokens[1].bank).await, 20);
+
+    //
+    // TEST: The account must stay healthy
+    //
+    send_tx_expect_error!(
+        solana,
+        margin_trade(
+            vec![tokens[0].bank],
+            withdraw_from_vault(&tokens[0], owner_token0, 200)
+        ),
+        MangoError::HealthMustBePositiveOrIncrease
+    );
+
+    Ok(())
+}
@@ -280,6 +280,7 @@ async fn test_perp_fixed() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -357,11 +358,26 @@ async fn test_perp_fixed() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
     .unwrap();
 
+    // The fill log identifies both orders and breaks down the fees
+    let fills = solana.program_log_events::<mango_v4::logs::FillLogV4>();
+    assert_eq!(fills.len(), 1);
+    let fill = &fills[0];
+    assert_eq!(fill.maker, account_0);
+    assert_eq!(fill.maker_client_order_id, 7);
+    assert_eq!(fill.taker, account_1);
+    assert_eq!(fill.taker_client_order_id, 8);
+    assert_eq!(fill.quantity, 1);
+    assert!(assert_equal_f64_f64(fill.maker_fee_paid, -0.01, 0.0001));
+    assert!(assert_equal_f64_f64(fill.taker_fee_paid, 0.02, 0.0001));
+    assert_eq!(fill.maker_base_position, 0);
+    assert_eq!(fill.taker_base_position, 0);
+
     let mango_account_0 = solana.get_account::<MangoAccount>(account_0).await;
     assert_eq!(mango_account_0.perps[0].base_position_lots(), 0);
     assert!(assert_equal(
@@ -637,6 +653,7 @@ async fn test_perp_oracle_peg() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -728,6 +745,7 @@ async fn test_perp_oracle_peg() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -819,6 +837,7 @@ async fn test_perp_oracle_peg() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -946,6 +965,7 @@ async fn test_perp_realize_partially() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -1000,6 +1020,7 @@ async fn test_perp_realize_partially() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -1154,6 +1175,7 @@ async fn test_perp_reducing_when_liquidatable() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -1211,6 +1233,66 @@ async fn test_perp_reducing_when_liquidatable() -> Result<(), TransportError> {
     .await;
     assert!(err.is_err());
 
+    //
+    // TEST: A reduce-only trigger order executes and pays its incentive despite negative health
+    //
+    assert!(account_init_health(solana, account_1).await < 0.0);
+    let executor_account =
+        create_funded_account(&solana, group, owner, 2, &context.users[1], &[], 0, 0).await;
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: account_0,
+            perp_market,
+            owner,
+            side: Side::Ask,
+            price_lots: perp_market_data.native_price_to_lot(I80F48::from_num(4000)),
+            // one lot fills account_1's bid from above, the other stays on the book
+            max_base_lots: 2,
+            client_order_id: 7,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PerpTriggerOrderCreateInstruction {
+            account: account_1,
+            perp_market,
+            owner,
+            payer,
+            order_id: 1,
+            side: Side::Bid,
+            trigger_condition: PerpTriggerCondition::OraclePriceAbove,
+            trigger_price_lots: perp_market_data.native_price_to_lot(I80F48::from_num(3000)),
+            price_lots: perp_market_data.native_price_to_lot(I80F48::from_num(4000)),
+            max_base_lots: 1,
+            max_quote_lots: i64::MAX,
+            reduce_only: true,
+            expiry_timestamp: u64::MAX,
+            executor_incentive: 10,
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PerpTriggerOrderExecuteInstruction {
+            account: account_1,
+            perp_market,
+            order_id: 1,
+            executor: owner,
+            executor_account,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        account_position(solana, executor_account, tokens[0].bank).await,
+        10
+    );
+
     Ok(())
 }
 
@@ -1363,6 +1445,7 @@ async fn test_perp_compute() -> Result<(), TransportError> {
             PerpConsumeEventsInstruction {
                 perp_market,
                 mango_accounts: vec![account_0, account_1],
+                keeper: None,
             },
         )
         .await
@@ -1372,6 +1455,7 @@ async fn test_perp_compute() -> Result<(), TransportError> {
             PerpConsumeEventsInstruction {
                 perp_market,
                 mango_accounts: vec![account_0, account_1],
+                keeper: None,
             },
         )
         .await
@@ -1381,6 +1465,7 @@ async fn test_perp_compute() -> Result<(), TransportError> {
             PerpConsumeEventsInstruction {
                 perp_market,
                 mango_accounts: vec![account_0, account_1],
+                keeper: None,
             },
         )
         .await
@@ -1430,6 +1515,7 @@ async fn test_perp_compute() -> Result<(), TransportError> {
             PerpConsumeEventsInstruction {
                 perp_market,
                 mango_accounts: vec![account_0],
+                keeper: None,
             },
         )
         .await
@@ -1581,6 +1667,7 @@ async fn test_perp_cancel_with_in_flight_events() -> Result<(), TransportError>
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -1593,13 +1680,1153 @@ async fn test_perp_cancel_with_in_flight_events() -> Result<(), TransportError>
     Ok(())
 }
 
-async fn assert_no_perp_orders(solana: &SolanaCookie, account_0: Pubkey) {
-    let mango_account_0 = solana.get_account::<MangoAccount>(account_0).await;
+#[tokio::test]
+async fn test_perp_update_funding() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
 
-    for oo in mango_account_0.perp_open_orders.iter() {
-        assert!(oo.id == 0);
-        assert!(oo.side_and_tree() == SideAndOrderTree::BidFixed);
-        assert!(oo.client_id == 0);
-        assert!(oo.market == FREE_ORDER_SLOT);
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group and an account
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account_0 =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    //
+    // SETUP: Create a perp market
+    //
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: 0.0000,
+            taker_fee: 0.0000,
+            settle_pnl_limit_factor: -1.0,
+            settle_pnl_limit_window_size_ts: 24 * 60 * 60,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[1]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let perp_market_data = solana.get_account::<PerpMarket>(perp_market).await;
+    let price_lots = perp_market_data.native_price_to_lot(I80F48::from(1));
+
+    let update_funding = PerpUpdateFundingInstruction {
+        perp_market,
+        bank: tokens[1].bank,
+        oracle: tokens[1].oracle,
+        keeper: None,
+    };
+
+    //
+    // TEST: Without any orders on the book, the crank accrues no funding
+    //
+    solana.advance_clock().await;
+    send_tx(solana, update_funding.clone()).await.unwrap();
+
+    let after_empty = solana.get_account::<PerpMarket>(perp_market).await;
+    assert_eq!(after_empty.long_funding, I80F48::ZERO);
+    assert_eq!(after_empty.short_funding, I80F48::ZERO);
+    assert!(after_empty.funding_last_updated > perp_market_data.funding_last_updated);
+
+    //
+    // TEST: With only a bid on the book, anyone can crank funding without trades happening
+    //
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: account_0,
+            perp_market,
+            owner,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 1,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let before = solana.get_account::<PerpMarket>(perp_market).await;
+    solana.advance_clock().await;
+    send_tx(solana, update_funding).await.unwrap();
+
+    let after = solana.get_account::<PerpMarket>(perp_market).await;
+    assert!(after.long_funding > before.long_funding);
+    assert_eq!(after.long_funding, after.short_funding);
+    assert!(after.funding_last_updated > before.funding_last_updated);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_perp_keeper_reward() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group and accounts
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
     }
+    .create(solana)
+    .await;
+
+    let deposit_amount = 10000;
+    let account_0 = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        mints,
+        deposit_amount,
+        0,
+    )
+    .await;
+    let account_1 = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[1],
+        mints,
+        deposit_amount,
+        0,
+    )
+    .await;
+
+    //
+    // SETUP: Create a perp market with keeper rewards
+    //
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: 0.0,
+            taker_fee: 0.01,
+            settle_pnl_limit_factor: -1.0,
+            settle_pnl_limit_window_size_ts: 24 * 60 * 60,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[1]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PerpEdit {
+            group,
+            admin,
+            perp_market,
+            options: mango_v4::instruction::PerpEditMarket {
+                keeper_reward_per_event_opt: Some(2.0),
+                keeper_reward_funding_per_hour_opt: Some(3600.0),
+                ..perp_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let perp_market = solana.get_account::<PerpMarket>(perp_market).await;
+        perp_market.native_price_to_lot(I80F48::ONE)
+    };
+
+    //
+    // TEST: A fill pays the keeper per consumed event, out of the fees
+    //
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: account_0,
+            perp_market,
+            owner,
+            side: Side::Bid,
+            price_lots,
+            max_base_lots: 10,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: account_1,
+            perp_market,
+            owner,
+            side: Side::Ask,
+            price_lots,
+            max_base_lots: 10,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let fees_before = solana
+        .get_account::<PerpMarket>(perp_market)
+        .await
+        .fees_accrued;
+    assert!(assert_equal(fees_before, 10.0, 0.001));
+
+    send_tx(
+        solana,
+        PerpConsumeEventsInstruction {
+            perp_market,
+            mango_accounts: vec![account_0, account_1],
+            keeper: Some(account_0),
+        },
+    )
+    .await
+    .unwrap();
+
+    let fees_after = solana
+        .get_account::<PerpMarket>(perp_market)
+        .await
+        .fees_accrued;
+    assert!(assert_equal(fees_after, 8.0, 0.001));
+
+    let mango_account_0 = solana.get_account::<MangoAccount>(account_0).await;
+    assert_eq!(mango_account_0.perps[0].base_position_lots(), 10);
+    assert!(assert_equal(
+        mango_account_0.perps[0].quote_position_native(),
+        -1000.0 + 2.0,
+        0.001
+    ));
+
+    //
+    // TEST: Funding updates pay the keeper by elapsed time
+    //
+    let before_funding = solana.get_account::<PerpMarket>(perp_market).await;
+    solana.advance_clock().await;
+    send_tx(
+        solana,
+        PerpUpdateFundingInstruction {
+            perp_market,
+            bank: tokens[1].bank,
+            oracle: tokens[1].oracle,
+            keeper: Some(account_0),
+        },
+    )
+    .await
+    .unwrap();
+
+    let after_funding = solana.get_account::<PerpMarket>(perp_market).await;
+    let elapsed = after_funding.funding_last_updated - before_funding.funding_last_updated;
+    assert!(elapsed > 0);
+    let paid = before_funding.fees_accrued - after_funding.fees_accrued;
+    assert!(paid.is_positive());
+    assert!(paid <= I80F48::from(elapsed));
+
+    let mango_account_0_after = solana.get_account::<MangoAccount>(account_0).await;
+    assert_eq!(
+        mango_account_0_after.perps[0].quote_position_native()
+            - mango_account_0.perps[0].quote_position_native(),
+        paid
+    );
+
+    //
+    // TEST: Accounts without a position in the market are not paid
+    //
+    let account_2 = create_funded_account(
+        &solana,
+        group,
+        owner,
+        2,
+        &context.users[1],
+        mints,
+        deposit_amount,
+        0,
+    )
+    .await;
+    let before_funding = solana.get_account::<PerpMarket>(perp_market).await;
+    solana.advance_clock().await;
+    send_tx(
+        solana,
+        PerpUpdateFundingInstruction {
+            perp_market,
+            bank: tokens[1].bank,
+            oracle: tokens[1].oracle,
+            keeper: Some(account_2),
+        },
+    )
+    .await
+    .unwrap();
+    let after_funding = solana.get_account::<PerpMarket>(perp_market).await;
+    assert_eq!(before_funding.fees_accrued, after_funding.fees_accrued);
+    let mango_account_2 = solana.get_account::<MangoAccount>(account_2).await;
+    assert_eq!(mango_account_2.perps[0].market_index, PerpMarketIndex::MAX);
+
+    Ok(())
+}
+
+async fn assert_no_perp_orders(solana: &SolanaCookie, account_0: Pubkey) {
+    let mango_account_0 = solana.get_account::<MangoAccount>(account_0).await;
+
+    for oo in mango_account_0.perp_open_orders.iter() {
+        assert!(oo.id == 0);
+        assert!(oo.side_and_tree() == SideAndOrderTree::BidFixed);
+        assert!(oo.client_id == 0);
+        assert!(oo.market == FREE_ORDER_SLOT);
+    }
+}
+
+#[tokio::test]
+async fn test_perp_edit_market() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let security_admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group, accounts and a perp market
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                security_admin_opt: Some(security_admin.pubkey()),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let account_0 =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 10000, 0).await;
+    let account_1 =
+        create_funded_account(&solana, group, owner, 1, &context.users[1], mints, 10000, 0).await;
+
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: 0.0,
+            taker_fee: 0.0,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[1]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let edit = |admin, options| PerpEdit {
+        group,
+        admin,
+        perp_market,
+        options,
+    };
+
+    //
+    // TEST: The admin can change parameters, unset options stay unchanged
+    //
+    let before = solana.get_account::<PerpMarket>(perp_market).await;
+    send_tx(
+        solana,
+        edit(
+            admin,
+            mango_v4::instruction::PerpEditMarket {
+                init_base_asset_weight_opt: Some(0.9),
+                taker_fee_opt: Some(0.001),
+                max_funding_opt: Some(0.5),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await
+    .unwrap();
+    let after = solana.get_account::<PerpMarket>(perp_market).await;
+    assert_eq!(after.init_base_asset_weight, I80F48::from_num(0.9f32));
+    assert_eq!(after.taker_fee, I80F48::from_num(0.001f32));
+    assert_eq!(after.max_funding, I80F48::from_num(0.5f32));
+    assert_eq!(
+        after.maint_base_asset_weight,
+        before.maint_base_asset_weight
+    );
+    assert_eq!(after.maker_fee, before.maker_fee);
+    assert_eq!(after.base_lot_size, before.base_lot_size);
+    assert_eq!(after.quote_lot_size, before.quote_lot_size);
+
+    //
+    // TEST: The security admin can only make the market reduce-only
+    //
+    let res = send_tx(
+        solana,
+        edit(
+            security_admin,
+            mango_v4::instruction::PerpEditMarket {
+                taker_fee_opt: Some(0.0),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        edit(
+            security_admin,
+            mango_v4::instruction::PerpEditMarket {
+                reduce_only_opt: Some(true),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(solana
+        .get_account::<PerpMarket>(perp_market)
+        .await
+        .is_reduce_only());
+
+    let res = send_tx(
+        solana,
+        edit(
+            security_admin,
+            mango_v4::instruction::PerpEditMarket {
+                reduce_only_opt: Some(false),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await;
+    assert!(res.is_err());
+
+    send_tx(
+        solana,
+        edit(
+            admin,
+            mango_v4::instruction::PerpEditMarket {
+                reduce_only_opt: Some(false),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Base decimals can change only while there is no open interest
+    //
+    let base_decimals = before.base_decimals;
+    send_tx(
+        solana,
+        edit(
+            admin,
+            mango_v4::instruction::PerpEditMarket {
+                base_decimals_opt: Some(base_decimals),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let perp_market = solana.get_account::<PerpMarket>(perp_market).await;
+        perp_market.native_price_to_lot(I80F48::ONE)
+    };
+    for (account, side) in [(account_0, Side::Bid), (account_1, Side::Ask)] {
+        send_tx(
+            solana,
+            PerpPlaceOrderInstruction {
+                account,
+                perp_market,
+                owner,
+                side,
+                price_lots,
+                max_base_lots: 1,
+                ..PerpPlaceOrderInstruction::default()
+            },
+        )
+        .await
+        .unwrap();
+    }
+    send_tx(
+        solana,
+        PerpConsumeEventsInstruction {
+            perp_market,
+            mango_accounts: vec![account_0, account_1],
+            keeper: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(
+        solana
+            .get_account::<PerpMarket>(perp_market)
+            .await
+            .open_interest
+            > 0
+    );
+
+    let res = send_tx(
+        solana,
+        edit(
+            admin,
+            mango_v4::instruction::PerpEditMarket {
+                base_decimals_opt: Some(base_decimals),
+                ..perp_edit_instruction_default()
+            },
+        ),
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_perp_trigger_order() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let executor = owner;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group, accounts and a perp market
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let deposit_amount = 1000;
+    let account_0 = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        mints,
+        deposit_amount,
+        0,
+    )
+    .await;
+    let account_1 = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[1],
+        mints,
+        deposit_amount,
+        0,
+    )
+    .await;
+    let executor_account =
+        create_funded_account(&solana, group, owner, 2, &context.users[1], &[], 0, 0).await;
+
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: 0.0,
+            taker_fee: 0.0,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[0]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Create a stop-loss style order that sells when the price drops below 0.9
+    //
+    let create_ix = PerpTriggerOrderCreateInstruction {
+        account: account_1,
+        perp_market,
+        owner,
+        payer,
+        order_id: 7,
+        side: Side::Ask,
+        trigger_condition: PerpTriggerCondition::OraclePriceBelow,
+        trigger_price_lots: 9,
+        price_lots: 1,
+        max_base_lots: 1,
+        max_quote_lots: i64::MAX,
+        reduce_only: false,
+        expiry_timestamp: u64::MAX,
+        executor_incentive: 10,
+    };
+    send_tx(solana, create_ix.clone()).await.unwrap();
+
+    let trigger_order_address = perp_trigger_order_address(&account_1, 7);
+    let trigger_order = solana
+        .get_account::<PerpTriggerOrder>(trigger_order_address)
+        .await;
+    assert_eq!(trigger_order.account, account_1);
+    assert_eq!(trigger_order.trigger_price_lots, 9);
+    assert_eq!(trigger_order.side(), Side::Ask);
+
+    let execute_ix = PerpTriggerOrderExecuteInstruction {
+        account: account_1,
+        perp_market,
+        order_id: 7,
+        executor,
+        executor_account,
+    };
+
+    //
+    // TEST: Can't execute before the oracle reached the trigger price
+    //
+    send_tx_expect_error!(
+        solana,
+        execute_ix.clone(),
+        MangoError::PerpTriggerOrderNotTriggered
+    );
+
+    //
+    // TEST: Can't execute when nothing would fill
+    //
+    set_perp_stub_oracle_price(solana, group, perp_market, &tokens[0], admin, 0.85).await;
+    assert!(send_tx(solana, execute_ix.clone()).await.is_err());
+
+    //
+    // TEST: Execute against a bid on the book
+    //
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account: account_0,
+            perp_market,
+            owner,
+            side: Side::Bid,
+            price_lots: 8,
+            max_base_lots: 1,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(solana, execute_ix.clone()).await.unwrap();
+    check_prev_instruction_post_health(&solana, account_1).await;
+    assert!(solana
+        .get_account_data(trigger_order_address)
+        .await
+        .is_none());
+
+    send_tx(
+        solana,
+        PerpConsumeEventsInstruction {
+            perp_market,
+            mango_accounts: vec![account_0, account_1],
+            keeper: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    let mango_account_1 = solana.get_account::<MangoAccount>(account_1).await;
+    assert_eq!(mango_account_1.perps[0].base_position_lots(), -1);
+
+    // the executor incentive was paid in the settle token
+    assert_eq!(
+        account_position(solana, account_1, tokens[0].bank).await,
+        deposit_amount as i64 - 10
+    );
+    assert_eq!(
+        account_position(solana, executor_account, tokens[0].bank).await,
+        10
+    );
+
+    //
+    // TEST: Cancel returns the rent
+    //
+    send_tx(
+        solana,
+        PerpTriggerOrderCreateInstruction {
+            order_id: 8,
+            ..create_ix
+        },
+    )
+    .await
+    .unwrap();
+    let trigger_order_address = perp_trigger_order_address(&account_1, 8);
+    assert!(solana
+        .get_account_data(trigger_order_address)
+        .await
+        .is_some());
+
+    send_tx(
+        solana,
+        PerpTriggerOrderCancelInstruction {
+            account: account_1,
+            owner,
+            order_id: 8,
+            sol_destination: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana
+        .get_account_data(trigger_order_address)
+        .await
+        .is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_perp_modify_order() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group, an account and a perp market
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    let mango_v4::accounts::PerpCreateMarket {
+        perp_market, bids, ..
+    } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: -0.0001,
+            taker_fee: 0.0002,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[0]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let perp_market = solana.get_account::<PerpMarket>(perp_market).await;
+        perp_market.native_price_to_lot(I80F48::ONE)
+    };
+
+    send_tx(
+        solana,
+        PerpPlaceOrderInstruction {
+            account,
+            perp_market,
+            owner,
+            side: Side::Bid,
+            price_lots: price_lots - 10,
+            max_base_lots: 1,
+            client_order_id: 1,
+            ..PerpPlaceOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Modifying an unknown order fails
+    //
+    send_tx_expect_error!(
+        solana,
+        PerpModifyOrderInstruction {
+            account,
+            perp_market,
+            owner,
+            cancel_client_order_id: 5,
+            side: Side::Bid,
+            price_lots: price_lots - 5,
+            max_base_lots: 2,
+            client_order_id: 2,
+            ..PerpModifyOrderInstruction::default()
+        },
+        MangoError::PerpOrderIdNotFound
+    );
+
+    //
+    // TEST: The old order is replaced by the new one
+    //
+    send_tx(
+        solana,
+        PerpModifyOrderInstruction {
+            account,
+            perp_market,
+            owner,
+            cancel_client_order_id: 1,
+            side: Side::Bid,
+            price_lots: price_lots - 5,
+            max_base_lots: 2,
+            client_order_id: 2,
+            ..PerpModifyOrderInstruction::default()
+        },
+    )
+    .await
+    .unwrap();
+    check_prev_instruction_post_health(&solana, account).await;
+
+    let bids_data = solana.get_account_boxed::<BookSide>(bids).await;
+    assert_eq!(bids_data.roots[0].leaf_count, 1);
+    let mango_account = solana.get_account::<MangoAccount>(account).await;
+    assert_eq!(mango_account.perps[0].bids_base_lots, 2);
+    assert_eq!(mango_account.perp_open_orders[0].client_id, 2);
+
+    send_tx(
+        solana,
+        PerpCancelOrderByClientOrderIdInstruction {
+            account,
+            perp_market,
+            owner,
+            client_order_id: 2,
+        },
+    )
+    .await
+    .unwrap();
+    assert_no_perp_orders(solana, account).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_perp_place_orders() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group, an account and a perp market
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    let mango_v4::accounts::PerpCreateMarket {
+        perp_market,
+        bids,
+        asks,
+        ..
+    } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: -0.0001,
+            taker_fee: 0.0002,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[0]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let perp_market = solana.get_account::<PerpMarket>(perp_market).await;
+        perp_market.native_price_to_lot(I80F48::ONE)
+    };
+
+    let order = |side: Side, price_lots: i64, client_order_id: u64| {
+        mango_v4::accounts_ix::PerpPlaceOrderArgs {
+            side,
+            price_lots,
+            max_base_lots: 1,
+            max_quote_lots: i64::MAX,
+            client_order_id,
+            order_type: PlaceOrderType::Limit,
+            self_trade_behavior: SelfTradeBehavior::DecrementTake,
+            reduce_only: false,
+            expiry_timestamp: 0,
+        }
+    };
+
+    //
+    // TEST: Place a ladder of orders on both sides in one instruction
+    //
+    send_tx(
+        solana,
+        PerpPlaceOrdersInstruction {
+            account,
+            perp_market,
+            owner,
+            orders: vec![
+                order(Side::Bid, price_lots - 1, 1),
+                order(Side::Bid, price_lots - 2, 2),
+                order(Side::Ask, price_lots + 1, 3),
+                order(Side::Ask, price_lots + 2, 4),
+            ],
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    check_prev_instruction_post_health(&solana, account).await;
+
+    let bids_data = solana.get_account_boxed::<BookSide>(bids).await;
+    assert_eq!(bids_data.roots[0].leaf_count, 2);
+    let asks_data = solana.get_account_boxed::<BookSide>(asks).await;
+    assert_eq!(asks_data.roots[0].leaf_count, 2);
+    let mango_account = solana.get_account::<MangoAccount>(account).await;
+    assert_eq!(mango_account.perps[0].bids_base_lots, 2);
+    assert_eq!(mango_account.perps[0].asks_base_lots, 2);
+
+    //
+    // TEST: Too many orders fail
+    //
+    assert!(send_tx(
+        solana,
+        PerpPlaceOrdersInstruction {
+            account,
+            perp_market,
+            owner,
+            orders: (0..10)
+                .map(|i| order(Side::Bid, price_lots - 10, 10 + i))
+                .collect(),
+            limit: 10,
+        },
+    )
+    .await
+    .is_err());
+
+    send_tx(
+        solana,
+        PerpCancelAllOrdersInstruction {
+            account,
+            perp_market,
+            owner,
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    assert_no_perp_orders(solana, account).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_perp_oracle_price_band() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group, an account and a perp market with a price band
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 0.975,
+            init_base_asset_weight: 0.95,
+            maint_base_liab_weight: 1.025,
+            init_base_liab_weight: 1.05,
+            base_liquidation_fee: 0.012,
+            maker_fee: 0.0,
+            taker_fee: 0.0,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[0]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        PerpEdit {
+            group,
+            admin,
+            perp_market,
+            options: mango_v4::instruction::PerpEditMarket {
+                oracle_price_band_opt: Some(0.5),
+                ..perp_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    // the oracle price is 1.0, which is 10 lots
+    let place = |side: Side, price_lots: i64| PerpPlaceOrderInstruction {
+        account,
+        perp_market,
+        owner,
+        side,
+        price_lots,
+        max_base_lots: 1,
+        ..PerpPlaceOrderInstruction::default()
+    };
+
+    //
+    // TEST: Orders far away from the oracle are rejected
+    //
+    send_tx_expect_error!(
+        solana,
+        place(Side::Bid, 6),
+        MangoError::PerpPriceBandExceeded
+    );
+    send_tx_expect_error!(
+        solana,
+        place(Side::Ask, 16),
+        MangoError::PerpPriceBandExceeded
+    );
+
+    //
+    // TEST: Orders within the band can be placed
+    //
+    send_tx(solana, place(Side::Bid, 7)).await.unwrap();
+    send_tx(solana, place(Side::Ask, 15)).await.unwrap();
+
+    let mango_account = solana.get_account::<MangoAccount>(account).await;
+    assert_eq!(mango_account.perps[0].bids_base_lots, 1);
+    assert_eq!(mango_account.perps[0].asks_base_lots, 1);
+
+    Ok(())
 }
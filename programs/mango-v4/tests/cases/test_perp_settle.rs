@@ -157,6 +157,7 @@ async fn test_perp_settle_pnl_basic() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -656,6 +657,7 @@ async fn test_perp_settle_pnl_fees() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -965,6 +967,7 @@ async fn test_perp_pnl_settle_limit() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -1093,6 +1096,7 @@ async fn test_perp_pnl_settle_limit() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -1253,3 +1257,207 @@ async fn test_perp_pnl_settle_limit() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_perp_settle_pnl_batch() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(200_000);
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..=2];
+
+    let initial_token_deposit = 10_000;
+
+    //
+    // SETUP: Create a group and accounts
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        zero_token_is_quote: true,
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let settle_bank = tokens[0].bank;
+
+    // ensure vaults are not empty
+    create_funded_account(
+        &solana,
+        group,
+        owner,
+        250,
+        &context.users[1],
+        mints,
+        100_000,
+        0,
+    )
+    .await;
+
+    let settler =
+        create_funded_account(&solana, group, owner, 251, &context.users[1], &[], 0, 0).await;
+    let settler_owner = owner.clone();
+
+    let mut accounts = vec![];
+    for account_num in 0..4 {
+        accounts.push(
+            create_funded_account(
+                &solana,
+                group,
+                owner,
+                account_num,
+                &context.users[1],
+                &mints[0..1],
+                initial_token_deposit,
+                0,
+            )
+            .await,
+        );
+    }
+
+    //
+    // SETUP: Create a perp market without settle fees
+    //
+    let mango_v4::accounts::PerpCreateMarket { perp_market, .. } = send_tx(
+        solana,
+        PerpCreateMarketInstruction {
+            group,
+            admin,
+            payer,
+            perp_market_index: 0,
+            quote_lot_size: 10,
+            base_lot_size: 100,
+            maint_base_asset_weight: 1.0,
+            init_base_asset_weight: 1.0,
+            maint_base_liab_weight: 1.0,
+            init_base_liab_weight: 1.0,
+            base_liquidation_fee: 0.0,
+            maker_fee: 0.0,
+            taker_fee: 0.0,
+            settle_fee_flat: 0.0,
+            settle_fee_amount_threshold: 0.0,
+            settle_fee_fraction_low_health: 0.0,
+            settle_pnl_limit_factor: 0.2,
+            settle_pnl_limit_window_size_ts: 24 * 60 * 60,
+            ..PerpCreateMarketInstruction::with_new_book_and_queue(&solana, &tokens[1]).await
+        },
+    )
+    .await
+    .unwrap();
+
+    let price_lots = {
+        let perp_market = solana.get_account::<PerpMarket>(perp_market).await;
+        perp_market.native_price_to_lot(I80F48::from(1000))
+    };
+    set_perp_stub_oracle_price(solana, group, perp_market, &tokens[1], admin, 1000.0).await;
+
+    //
+    // SETUP: Accounts 0 and 2 go long, accounts 1 and 3 go short
+    //
+    for pair in accounts.chunks(2) {
+        for (account, side) in [(pair[0], Side::Bid), (pair[1], Side::Ask)] {
+            send_tx(
+                solana,
+                PerpPlaceOrderInstruction {
+                    account,
+                    perp_market,
+                    owner,
+                    side,
+                    price_lots,
+                    max_base_lots: 1,
+                    ..PerpPlaceOrderInstruction::default()
+                },
+            )
+            .await
+            .unwrap();
+        }
+        send_tx(
+            solana,
+            PerpConsumeEventsInstruction {
+                perp_market,
+                mango_accounts: pair.to_vec(),
+                keeper: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    set_perp_stub_oracle_price(solana, group, perp_market, &tokens[1], admin, 1050.0).await;
+    let pairs = vec![(accounts[0], accounts[1]), (accounts[2], accounts[3])];
+
+    //
+    // TEST: An account can't appear twice
+    //
+    assert!(send_tx(
+        solana,
+        PerpSettlePnlBatchInstruction {
+            settler,
+            settler_owner,
+            pairs: vec![(accounts[0], accounts[1]), (accounts[2], accounts[1])],
+            perp_market,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Settle both pairs at once
+    //
+    send_tx(
+        solana,
+        PerpSettlePnlBatchInstruction {
+            settler,
+            settler_owner,
+            pairs: pairs.clone(),
+            perp_market,
+        },
+    )
+    .await
+    .unwrap();
+
+    let expected_pnl = 5000;
+    for (a, b) in pairs.iter() {
+        assert_eq!(
+            account_position(solana, *a, settle_bank).await,
+            initial_token_deposit as i64 + expected_pnl
+        );
+        assert_eq!(
+            account_position(solana, *b, settle_bank).await,
+            initial_token_deposit as i64 - expected_pnl
+        );
+        let mango_account_a = solana.get_account::<MangoAccount>(*a).await;
+        let mango_account_b = solana.get_account::<MangoAccount>(*b).await;
+        assert_eq!(mango_account_a.perps[0].perp_spot_transfers, expected_pnl);
+        assert_eq!(mango_account_b.perps[0].perp_spot_transfers, -expected_pnl);
+    }
+
+    //
+    // TEST: Pairs with nothing to settle are skipped
+    //
+    send_tx(
+        solana,
+        PerpSettlePnlBatchInstruction {
+            settler,
+            settler_owner,
+            pairs: pairs.clone(),
+            perp_market,
+        },
+    )
+    .await
+    .unwrap();
+    for (a, _) in pairs.iter() {
+        assert_eq!(
+            account_position(solana, *a, settle_bank).await,
+            initial_token_deposit as i64 + expected_pnl
+        );
+    }
+
+    Ok(())
+}
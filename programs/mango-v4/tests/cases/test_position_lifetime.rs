@@ -182,6 +182,25 @@ async fn test_position_lifetime() -> Result<(), TransportError> {
             -(borrow_amount as i64)
         );
 
+        // withdrawing everything never borrows, even if borrows are allowed
+        send_tx(
+            solana,
+            TokenWithdrawInstruction {
+                amount: u64::MAX,
+                allow_borrow: true,
+                account,
+                owner,
+                token_account: payer_mint_accounts[1],
+                bank_index: 0,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            account_position(solana, account, tokens[1].bank).await,
+            -(borrow_amount as i64)
+        );
+
         // give it back, closing the position
         {
             send_tx(
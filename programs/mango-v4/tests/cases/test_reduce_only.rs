@@ -307,6 +307,7 @@ async fn test_perp_reduce_only() -> Result<(), TransportError> {
         PerpConsumeEventsInstruction {
             perp_market,
             mango_accounts: vec![account_0, account_1],
+            keeper: None,
         },
     )
     .await
@@ -578,3 +579,238 @@ async fn test_perp_reduce_only() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_group_withdraw_only() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let security_admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let payer_mint_accounts = &context.users[1].token_accounts[0..2];
+
+    //
+    // SETUP: Create a group with a security admin and a funded account
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                security_admin_opt: Some(security_admin.pubkey()),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    let deposit = |reduce_only: bool| TokenDepositInstruction {
+        amount: 10,
+        reduce_only,
+        account,
+        owner,
+        token_account: payer_mint_accounts[0],
+        token_authority: payer,
+        bank_index: 0,
+    };
+    let withdraw = |amount: u64, allow_borrow: bool| TokenWithdrawInstruction {
+        amount,
+        allow_borrow,
+        account,
+        owner,
+        token_account: payer_mint_accounts[0],
+        bank_index: 0,
+    };
+    let toggle = |admin: TestKeypair, withdraw_only: bool| GroupToggleWithdrawOnlyInstruction {
+        group,
+        admin,
+        withdraw_only,
+    };
+
+    //
+    // TEST: Others can't enable withdraw-only mode, the security admin can
+    //
+    assert!(send_tx(solana, toggle(payer, true)).await.is_err());
+    send_tx(solana, toggle(security_admin, true)).await.unwrap();
+    assert!(solana.get_account::<Group>(group).await.is_withdraw_only());
+
+    //
+    // TEST: Deposits that don't reduce borrows are rejected
+    //
+    send_tx_expect_error!(solana, deposit(false), MangoError::GroupInWithdrawOnlyMode);
+    send_tx(solana, deposit(true)).await.unwrap();
+    assert_eq!(
+        account_position(solana, account, tokens[0].bank).await,
+        1000
+    );
+
+    //
+    // TEST: Withdrawing deposits works, borrowing doesn't
+    //
+    send_tx(solana, withdraw(500, false)).await.unwrap();
+    send_tx_expect_error!(
+        solana,
+        withdraw(1000, true),
+        MangoError::GroupInWithdrawOnlyMode
+    );
+    assert_eq!(account_position(solana, account, tokens[0].bank).await, 500);
+
+    //
+    // TEST: The security admin can't disable withdraw-only mode, the admin can
+    //
+    assert!(send_tx(solana, toggle(security_admin, false))
+        .await
+        .is_err());
+    send_tx(solana, toggle(admin, false)).await.unwrap();
+    assert!(!solana.get_account::<Group>(group).await.is_withdraw_only());
+
+    send_tx(solana, deposit(false)).await.unwrap();
+    assert_eq!(account_position(solana, account, tokens[0].bank).await, 510);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collateral_only_token() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let owner_token_accounts = &context.users[0].token_accounts[0..2];
+
+    //
+    // SETUP: Create a group where token1 is collateral only
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[1].pubkey,
+            fallback_oracle: Pubkey::default(),
+            options: mango_v4::instruction::TokenEdit {
+                collateral_only_opt: Some(true),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+    let bank = solana.get_account::<Bank>(tokens[1].bank).await;
+    assert!(bank.is_collateral_only());
+    assert!(!bank.are_deposits_reduce_only());
+
+    // vault liquidity for both tokens
+    create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        mints,
+        10_000,
+        0,
+    )
+    .await;
+
+    //
+    // TEST: Deposits of the collateral only token are allowed
+    //
+    let account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[1],
+        &mints[1..2],
+        10_000,
+        0,
+    )
+    .await;
+
+    //
+    // TEST: The deposits count as collateral for borrowing other tokens
+    //
+    send_tx(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 1_000,
+            allow_borrow: true,
+            account,
+            owner,
+            token_account: owner_token_accounts[0],
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(account_position(solana, account, tokens[0].bank).await < 0);
+
+    //
+    // TEST: The collateral only token itself can't be borrowed
+    //
+    send_tx_expect_error!(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 10_001,
+            allow_borrow: true,
+            account,
+            owner,
+            token_account: owner_token_accounts[1],
+            bank_index: 0,
+        },
+        MangoError::TokenInReduceOnlyMode
+    );
+
+    // withdrawing deposits still works
+    send_tx(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 5_000,
+            allow_borrow: false,
+            account,
+            owner,
+            token_account: owner_token_accounts[1],
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        account_position(solana, account, tokens[1].bank).await,
+        5_000
+    );
+
+    Ok(())
+}
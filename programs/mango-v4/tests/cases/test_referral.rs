@@ -0,0 +1,205 @@
+use super::*;
+
+#[tokio::test]
+async fn test_referral_fees() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let payer_mint_accounts = &context.users[1].token_accounts[0..2];
+
+    //
+    // SETUP: Create a group, a referrer account and a referred account
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let quote_bank = tokens[0].bank;
+
+    let referrer = create_funded_account(
+        solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[0..1],
+        1_000_000,
+        0,
+    )
+    .await;
+
+    let account = send_tx(
+        solana,
+        AccountCreateInstruction {
+            account_num: 1,
+            group,
+            owner,
+            payer,
+            referrer: Some(referrer),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap()
+    .account;
+    assert_eq!(
+        get_mango_account(solana, account).await.fixed.referrer,
+        referrer
+    );
+
+    send_tx(
+        solana,
+        TokenDepositInstruction {
+            amount: 1_000_000,
+            reduce_only: false,
+            account,
+            owner,
+            token_account: payer_mint_accounts[1],
+            token_authority: payer,
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        GroupEdit {
+            group,
+            admin,
+            options: mango_v4::instruction::GroupEdit {
+                referral_fee_share_opt: Some(0.5),
+                ..group_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Borrowing accrues the loan origination fee for the referrer
+    //
+    send_tx(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 100_000,
+            allow_borrow: true,
+            account,
+            owner,
+            token_account: payer_mint_accounts[0],
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    // 0.0005 loan origination fee on a 100_000 loan, accrued for the bank that collected it
+    let fixed = get_mango_account(solana, account).await.fixed;
+    assert_eq!(
+        fixed.referral_fees_accrued(ReferralFeeSource::Bank, tokens[0].index),
+        50
+    );
+    assert_eq!(
+        fixed.referral_fees_accrued(ReferralFeeSource::Bank, tokens[1].index),
+        0
+    );
+
+    let bank = solana.get_account::<Bank>(quote_bank).await;
+    assert_eq!(bank.referral_fees_unclaimed, 50);
+
+    //
+    // TEST: Claiming from a bank that didn't collect the fees pays nothing
+    //
+    let other_bank = tokens[1].bank;
+    send_tx(
+        solana,
+        AccountClaimReferralFeesInstruction {
+            account,
+            bank: other_bank,
+            perp_market: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(account_position_closed(solana, referrer, other_bank).await);
+    let fixed = get_mango_account(solana, account).await.fixed;
+    assert_eq!(
+        fixed.referral_fees_accrued(ReferralFeeSource::Bank, tokens[0].index),
+        50
+    );
+
+    //
+    // TEST: Claiming pays the referrer share out of the collected fees
+    //
+    let referrer_before = account_position(solana, referrer, quote_bank).await;
+    let bank_before = solana.get_account::<Bank>(quote_bank).await;
+
+    send_tx(
+        solana,
+        AccountClaimReferralFeesInstruction {
+            account,
+            bank: quote_bank,
+            perp_market: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        account_position(solana, referrer, quote_bank).await,
+        referrer_before + 25
+    );
+    let bank_after = solana.get_account::<Bank>(quote_bank).await;
+    assert_eq!(
+        bank_after.collected_fees_native,
+        bank_before.collected_fees_native
+    );
+    assert_eq!(bank_after.fees_withdrawn, bank_before.fees_withdrawn);
+    assert_eq!(
+        bank_after.referral_fees_paid,
+        bank_before.referral_fees_paid + 25
+    );
+    assert_eq!(
+        bank_after.fees_available(),
+        bank_before.fees_available() - I80F48::from(25)
+    );
+    assert_eq!(bank_after.referral_fees_unclaimed, 0);
+    let fixed = get_mango_account(solana, account).await.fixed;
+    assert_eq!(
+        fixed.referral_fees_accrued(ReferralFeeSource::Bank, tokens[0].index),
+        0
+    );
+    assert!(fixed
+        .referral_fees_accrued
+        .iter()
+        .all(|slot| slot.source() == ReferralFeeSource::Unused));
+
+    //
+    // TEST: Claiming again does nothing
+    //
+    send_tx(
+        solana,
+        AccountClaimReferralFeesInstruction {
+            account,
+            bank: quote_bank,
+            perp_market: None,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        account_position(solana, referrer, quote_bank).await,
+        referrer_before + 25
+    );
+
+    Ok(())
+}
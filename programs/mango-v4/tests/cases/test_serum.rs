@@ -2,7 +2,9 @@
 use super::*;
 
 use anchor_lang::prelude::AccountMeta;
-use mango_v4::accounts_ix::{Serum3OrderType, Serum3SelfTradeBehavior, Serum3Side};
+use mango_v4::accounts_ix::{
+    Serum3OrderType, Serum3PlaceOrderArgs, Serum3SelfTradeBehavior, Serum3Side,
+};
 use mango_v4::serum3_cpi::{load_open_orders_bytes, OpenOrdersSlim};
 use std::sync::Arc;
 
@@ -205,6 +207,12 @@ impl SerumOrderPlacer {
         orders.clone()
     }
 
+    async fn open_order_count(&self) -> u32 {
+        let open_orders = self.serum.load_open_orders(self.open_orders).await;
+        let free_slot_bits = open_orders.free_slot_bits;
+        (!free_slot_bits).count_ones()
+    }
+
     async fn _open_orders(&self) -> OpenOrdersSlim {
         let data = self
             .solana
@@ -453,6 +461,365 @@ async fn test_serum_basics() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_serum_register_market_checks() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..3];
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    let market_a = context
+        .serum
+        .list_spot_market(&tokens[0].mint, &tokens[1].mint)
+        .await;
+    let market_b = context
+        .serum
+        .list_spot_market(&tokens[2].mint, &tokens[1].mint)
+        .await;
+
+    let register =
+        |market: Pubkey, market_index, base: usize, quote: usize| Serum3RegisterMarketInstruction {
+            group,
+            admin,
+            serum_program: context.serum.program_id,
+            serum_market_external: market,
+            market_index,
+            base_bank: tokens[base].bank,
+            quote_bank: tokens[quote].bank,
+            payer,
+        };
+
+    //
+    // TEST: Banks must match the market's coin and pc mints
+    //
+    assert!(send_tx(solana, register(market_a.market, 0, 1, 0))
+        .await
+        .is_err());
+    assert!(send_tx(solana, register(market_a.market, 0, 2, 1))
+        .await
+        .is_err());
+
+    send_tx(solana, register(market_a.market, 0, 0, 1))
+        .await
+        .unwrap();
+
+    //
+    // TEST: A market index can't be reused
+    //
+    assert!(send_tx(solana, register(market_b.market, 0, 2, 1))
+        .await
+        .is_err());
+
+    //
+    // TEST: An external market can't be registered twice
+    //
+    assert!(send_tx(solana, register(market_a.market, 1, 0, 1))
+        .await
+        .is_err());
+
+    let serum_market = send_tx(solana, register(market_b.market, 1, 2, 1))
+        .await
+        .unwrap()
+        .serum_market;
+    let serum_market_data = solana.get_account::<Serum3Market>(serum_market).await;
+    assert_eq!(serum_market_data.market_index, 1);
+    assert_eq!(serum_market_data.base_token_index, tokens[2].index);
+    assert_eq!(serum_market_data.quote_token_index, tokens[1].index);
+    assert_eq!(serum_market_data.serum_market_external, market_b.market);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_serum_cancel_all_with_limit() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(150_000); // Serum3PlaceOrder needs lots
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    //
+    // SETUP: Create a group, accounts, market etc
+    //
+    let deposit_amount = 1000;
+    let CommonSetup {
+        serum_market_cookie,
+        mut order_placer,
+        ..
+    } = common_setup(&context, deposit_amount).await;
+
+    order_placer.bid_maker(0.7, 100).await.unwrap();
+    order_placer.bid_maker(0.8, 100).await.unwrap();
+    order_placer.ask(1.2, 100).await.unwrap();
+    assert_eq!(order_placer.open_order_count().await, 3);
+
+    let cancel_all = |limit| Serum3CancelAllOrdersInstruction {
+        limit,
+        account: order_placer.account,
+        owner: order_placer.owner,
+        serum_market: order_placer.serum_market,
+    };
+
+    // Canceled orders only free their open orders slot once the OutEvent is processed
+    let open_orders = [order_placer.open_orders];
+    let consume_events = || {
+        context
+            .serum
+            .consume_spot_events(&serum_market_cookie, &open_orders)
+    };
+
+    //
+    // TEST: cancel_all stops after limit orders
+    //
+    send_tx(solana, cancel_all(2)).await.unwrap();
+    consume_events().await;
+    assert_eq!(order_placer.open_order_count().await, 1);
+
+    //
+    // TEST: cancel_all removes the remaining orders
+    //
+    send_tx(solana, cancel_all(10)).await.unwrap();
+    consume_events().await;
+    assert_eq!(order_placer.open_order_count().await, 0);
+
+    //
+    // TEST: Settling after cancels restores the original balances
+    //
+    order_placer.settle().await;
+    let o = order_placer.mango_serum_orders().await;
+    assert_eq!(o.potential_base_tokens, 0);
+    assert_eq!(o.potential_quote_tokens, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_serum_open_orders_lifecycle() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(95_000); // Serum3PlaceOrder needs 92.8k
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    //
+    // SETUP: Create a group, accounts, market etc
+    //
+    let deposit_amount = 1000;
+    let CommonSetup {
+        serum_market_cookie,
+        mut order_placer,
+        ..
+    } = common_setup(&context, deposit_amount).await;
+    let account = order_placer.account;
+    let owner = order_placer.owner;
+    let serum_market = order_placer.serum_market;
+    let payer = context.users[1].key;
+
+    //
+    // TEST: The open orders account is a PDA of the mango account and market
+    //
+    let expected_open_orders = Pubkey::find_program_address(
+        &[
+            b"Serum3OO".as_ref(),
+            account.as_ref(),
+            serum_market.as_ref(),
+        ],
+        &mango_v4::id(),
+    )
+    .0;
+    assert_eq!(order_placer.open_orders, expected_open_orders);
+
+    // a second open orders account for the same market can't be created
+    assert!(send_tx(
+        solana,
+        Serum3CreateOpenOrdersInstruction {
+            account,
+            serum_market,
+            owner,
+            payer,
+        },
+    )
+    .await
+    .is_err());
+
+    //
+    // TEST: Closing fails while orders are open
+    //
+    let (order_id, _) = order_placer.bid_maker(0.9, 100).await.unwrap();
+    let close = || Serum3CloseOpenOrdersInstruction {
+        account,
+        serum_market,
+        owner,
+        sol_destination: payer.pubkey(),
+    };
+    assert!(send_tx(solana, close()).await.is_err());
+
+    //
+    // TEST: After cancel, settle and event processing the rent can be reclaimed
+    //
+    order_placer.cancel(order_id).await;
+    order_placer.settle().await;
+    context
+        .serum
+        .consume_spot_events(&serum_market_cookie, &[order_placer.open_orders])
+        .await;
+
+    send_tx(solana, close()).await.unwrap();
+
+    assert!(solana
+        .get_account_data(order_placer.open_orders)
+        .await
+        .is_none());
+    let account_data = get_mango_account(solana, account).await;
+    assert_eq!(account_data.active_serum3_orders().count(), 0);
+
+    //
+    // TEST: The open orders account can be created again afterwards
+    //
+    let open_orders = send_tx(
+        solana,
+        Serum3CreateOpenOrdersInstruction {
+            account,
+            serum_market,
+            owner,
+            payer,
+        },
+    )
+    .await
+    .unwrap()
+    .open_orders;
+    assert_eq!(open_orders, expected_open_orders);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_serum_force_close_and_deregister() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(150_000); // force cancel needs >100k
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    //
+    // SETUP: Create a group, accounts, market etc
+    //
+    let deposit_amount = 1000;
+    let CommonSetup {
+        group_with_tokens,
+        serum_market_cookie,
+        mut order_placer,
+        ..
+    } = common_setup(&context, deposit_amount).await;
+    let group = group_with_tokens.group;
+    let admin = group_with_tokens.admin;
+    let payer = context.users[1].key;
+    let account = order_placer.account;
+    let serum_market = order_placer.serum_market;
+
+    order_placer.bid_maker(0.9, 100).await.unwrap();
+
+    //
+    // TEST: Put the market in force close mode, anyone can cancel the orders
+    //
+    send_tx(
+        solana,
+        Serum3EditMarketInstruction {
+            group,
+            admin,
+            market: serum_market,
+            options: mango_v4::instruction::Serum3EditMarket {
+                reduce_only_opt: Some(true),
+                force_close_opt: Some(true),
+                ..serum3_edit_market_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(
+        solana,
+        Serum3LiqForceCancelOrdersInstruction {
+            account,
+            serum_market,
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        order_placer
+            .mango_serum_orders()
+            .await
+            .potential_quote_tokens,
+        0
+    );
+
+    context
+        .serum
+        .consume_spot_events(&serum_market_cookie, &[order_placer.open_orders])
+        .await;
+    send_tx(
+        solana,
+        Serum3CloseOpenOrdersInstruction {
+            account,
+            serum_market,
+            owner: order_placer.owner,
+            sol_destination: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: Deregistering closes the market and frees its index
+    //
+    send_tx(
+        solana,
+        Serum3DeregisterMarketInstruction {
+            group,
+            admin,
+            serum_market_external: serum_market_cookie.market,
+            sol_destination: payer.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(solana.get_account_data(serum_market).await.is_none());
+
+    let tokens = &group_with_tokens.tokens;
+    let other_market_cookie = context
+        .serum
+        .list_spot_market(&tokens[2].mint, &tokens[0].mint)
+        .await;
+    send_tx(
+        solana,
+        Serum3RegisterMarketInstruction {
+            group,
+            admin,
+            serum_program: context.serum.program_id,
+            serum_market_external: other_market_cookie.market,
+            market_index: 0,
+            base_bank: tokens[2].bank,
+            quote_bank: tokens[0].bank,
+            payer,
+        },
+    )
+    .await
+    .unwrap();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_serum_loan_origination_fees() -> Result<(), TransportError> {
     let mut test_builder = TestContextBuilder::new();
@@ -831,6 +1198,14 @@ async fn test_serum_settle_v2_to_dao() -> Result<(), TransportError> {
     let account2_data = solana.get_account::<MangoAccount>(account2).await;
     assert_eq!(account2_data.buyback_fees_accrued_current, 0);
 
+    let serum_market_data = solana
+        .get_account::<Serum3Market>(order_placer.serum_market)
+        .await;
+    assert_eq!(
+        serum_market_data.collected_referrer_rebates,
+        serum_referrer_fee(amount) as u64
+    );
+
     Ok(())
 }
 
@@ -924,6 +1299,75 @@ async fn test_serum_settle_v2_to_account() -> Result<(), TransportError> {
     let account2_data = solana.get_account::<MangoAccount>(account2).await;
     assert_eq!(account2_data.buyback_fees_accrued_current, 0);
 
+    // rebates stayed with the account, none were collected for the market
+    let serum_market_data = solana
+        .get_account::<Serum3Market>(order_placer.serum_market)
+        .await;
+    assert_eq!(serum_market_data.collected_referrer_rebates, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_serum_settle_without_fills() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(95_000); // Serum3PlaceOrder needs 92.8k
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    //
+    // SETUP: Create a group, accounts, market etc
+    //
+    let deposit_amount = 1000;
+    let CommonSetup {
+        quote_token,
+        base_token,
+        mut order_placer,
+        ..
+    } = common_setup(&context, deposit_amount).await;
+    let quote_bank = quote_token.bank;
+    let base_bank = base_token.bank;
+    let account = order_placer.account;
+
+    let quote_fees_start = solana
+        .get_account::<Bank>(quote_bank)
+        .await
+        .collected_fees_native;
+
+    //
+    // TEST: Settling while an unfilled, borrowing order rests on the book changes nothing
+    //
+    order_placer.bid_maker(1.0, 1500).await.unwrap();
+    let orders_before = order_placer.mango_serum_orders().await;
+    let quote_before = account_position(solana, account, quote_bank).await;
+
+    order_placer.settle().await;
+
+    let orders_after = order_placer.mango_serum_orders().await;
+    assert_eq!(
+        orders_after.quote_borrows_without_fee,
+        orders_before.quote_borrows_without_fee
+    );
+    assert_eq!(
+        orders_after.potential_quote_tokens,
+        orders_before.potential_quote_tokens
+    );
+    assert_eq!(
+        account_position(solana, account, quote_bank).await,
+        quote_before
+    );
+    assert_eq!(
+        account_position(solana, account, base_bank).await,
+        deposit_amount as i64
+    );
+
+    // no loan origination fee is charged for funds that were never used
+    let quote_fees_end = solana
+        .get_account::<Bank>(quote_bank)
+        .await
+        .collected_fees_native;
+    assert_eq!(quote_fees_end, quote_fees_start);
+
     Ok(())
 }
 
@@ -1153,6 +1597,57 @@ async fn test_serum_place_reducing_when_liquidatable() -> Result<(), TransportEr
     Ok(())
 }
 
+#[tokio::test]
+async fn test_serum_place_order_borrow_and_health() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(150_000); // Serum3PlaceOrder needs lots
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    //
+    // SETUP: Create a group, accounts, market etc
+    //
+    let deposit_amount = 1000;
+    let CommonSetup {
+        quote_token,
+        mut order_placer,
+        ..
+    } = common_setup(&context, deposit_amount).await;
+    let account = order_placer.account;
+
+    //
+    // TEST: A bid locking more quote than deposited borrows the difference
+    //
+    order_placer.try_bid(1.0, 2000, false).await.unwrap();
+    check_prev_instruction_post_health(&solana, account).await;
+
+    let quote_position = account_position(solana, account, quote_token.bank).await;
+    assert!((quote_position + 1000).abs() <= 1); // rounded
+    let account_data = get_mango_account(solana, account).await;
+    let serum_orders = account_data.serum3_orders_by_raw_index(0).unwrap();
+    let borrows = serum_orders.quote_borrows_without_fee;
+    assert!(borrows == 999 || borrows == 1000); // rounded
+    assert_eq!(serum_orders.potential_quote_tokens, 2000);
+    assert!(account_init_health(solana, account).await > 0.0);
+
+    //
+    // TEST: A bid that would make init health negative is rejected
+    //
+    let err = order_placer.try_bid(1.0, 20000, false).await;
+    assert_mango_error(
+        &err,
+        MangoError::HealthMustBePositiveOrIncrease.into(),
+        "".into(),
+    );
+
+    // the failed order did not change the borrow
+    let account_data = get_mango_account(solana, account).await;
+    let serum_orders = account_data.serum3_orders_by_raw_index(0).unwrap();
+    assert_eq!(serum_orders.quote_borrows_without_fee, borrows);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_serum_track_bid_ask() -> Result<(), TransportError> {
     let mut test_builder = TestContextBuilder::new();
@@ -1282,6 +1777,72 @@ async fn test_serum_track_bid_ask() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_serum_place_orders_batch() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(400_000); // several Serum3PlaceOrder cpis
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    //
+    // SETUP: Create a group, accounts, market etc
+    //
+    let deposit_amount = 10000;
+    let CommonSetup {
+        quote_token,
+        order_placer,
+        ..
+    } = common_setup(&context, deposit_amount).await;
+
+    //
+    // TEST: Place a ladder of bids in one instruction
+    //
+    let bid = |limit_price: f64, client_order_id: u64| Serum3PlaceOrderArgs {
+        limit_price_lots: (limit_price * 100.0 / 10.0) as u64, // in quote_lot (10) per base lot (100)
+        max_base_qty: 1,                                       // in base lot (100)
+        max_native_quote_qty_including_fees: (limit_price * 100.0) as u64,
+        self_trade_behavior: Serum3SelfTradeBehavior::AbortTransaction,
+        order_type: Serum3OrderType::Limit,
+        client_order_id,
+        limit: 10,
+    };
+    send_tx(
+        solana,
+        Serum3PlaceOrdersInstruction {
+            side: Serum3Side::Bid,
+            orders: vec![bid(9.0, 101), bid(10.0, 102), bid(11.0, 103)],
+            account: order_placer.account,
+            owner: order_placer.owner,
+            serum_market: order_placer.serum_market,
+        },
+    )
+    .await
+    .unwrap();
+    check_prev_instruction_post_health(&solana, order_placer.account).await;
+
+    assert_eq!(order_placer.open_order_count().await, 3);
+    for client_order_id in 101..=103 {
+        assert!(order_placer
+            .find_order_id_for_client_order_id(client_order_id)
+            .await
+            .is_some());
+    }
+
+    let srm = order_placer.mango_serum_orders().await;
+    assert_eq!(srm.highest_placed_bid_inv, 1.0 / 11.0);
+    assert_eq!(srm.lowest_placed_bid_inv, 1.0 / 9.0);
+
+    // the reserved quote was withdrawn from the account
+    assert_eq!(
+        account_position(solana, order_placer.account, quote_token.bank).await,
+        deposit_amount as i64 - 3000
+    );
+
+    order_placer.cancel_all().await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_serum_track_reserved_deposits() -> Result<(), TransportError> {
     let mut test_builder = TestContextBuilder::new();
@@ -1747,6 +2308,32 @@ async fn test_serum_bands() -> Result<(), TransportError> {
     order_placer.try_bid(67.0, 100, false).await.unwrap();
     order_placer.try_ask(149.0, 100).await.unwrap();
 
+    //
+    // TEST: Crossing bands limit how far orders may cross the oracle
+    //
+    send_tx(
+        solana,
+        Serum3EditMarketInstruction {
+            group: group_with_tokens.group,
+            admin: group_with_tokens.admin,
+            market: order_placer.serum_market,
+            options: mango_v4::instruction::Serum3EditMarket {
+                crossing_price_band_opt: Some(0.1),
+                ..serum3_edit_market_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let r = order_placer.try_bid(111.0, 100, false).await;
+    assert_mango_error(&r, MangoError::Serum3PriceBandExceeded.into(), "".into());
+    let r = order_placer.try_ask(89.0, 100).await;
+    assert_mango_error(&r, MangoError::Serum3PriceBandExceeded.into(), "".into());
+
+    order_placer.try_bid(109.0, 100, false).await.unwrap();
+    order_placer.try_ask(110.0, 100).await.unwrap();
+
     Ok(())
 }
 
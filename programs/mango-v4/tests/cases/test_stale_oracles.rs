@@ -180,6 +180,248 @@ async fn test_stale_oracle_deposit_withdraw() -> Result<(), TransportError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_oracle_max_staleness_slots() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(150_000); // bad oracles log a lot
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let payer_token_accounts = &context.users[1].token_accounts[0..2];
+
+    //
+    // SETUP: Create a group, account, register tokens
+    //
+
+    let mango_setup::GroupWithTokens { group, tokens, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    // fill vaults, so we can borrow
+    let _vault_account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        2,
+        &context.users[1],
+        mints,
+        100000,
+        0,
+    )
+    .await;
+
+    // Create account with token0 deposits
+    let account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[0..1],
+        100,
+        0,
+    )
+    .await;
+
+    // token1 prices may be at most 10 slots old
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[1].pubkey,
+            fallback_oracle: Pubkey::default(),
+            options: mango_v4::instruction::TokenEdit {
+                oracle_config_opt: Some(OracleConfigParams {
+                    conf_filter: 0.1,
+                    max_staleness_slots: Some(10),
+                }),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let set_token1_price_now = || async {
+        send_tx(
+            solana,
+            StubOracleSetTestInstruction {
+                oracle: tokens[1].oracle,
+                group,
+                mint: mints[1].pubkey,
+                admin,
+                price: 1.0,
+                last_update_slot: solana.clock().await.slot,
+                deviation: 0.0,
+            },
+        )
+        .await
+        .unwrap();
+    };
+    let borrow_token1 = || TokenWithdrawInstruction {
+        amount: 1,
+        allow_borrow: true,
+        account,
+        owner,
+        token_account: payer_token_accounts[1],
+        bank_index: 0,
+    };
+
+    //
+    // TEST: Borrowing works with a fresh price
+    //
+    set_token1_price_now().await;
+    send_tx(solana, borrow_token1()).await.unwrap();
+
+    //
+    // TEST: Once the price is older than max_staleness_slots, health checks fail
+    //
+    solana.advance_by_slots(20).await;
+    let res = send_tx(solana, borrow_token1()).await;
+    assert_mango_error(
+        &res,
+        MangoError::OracleStale.into(),
+        "token1 oracle is stale".into(),
+    );
+
+    //
+    // TEST: Refreshing the price allows borrowing again
+    //
+    set_token1_price_now().await;
+    send_tx(solana, borrow_token1()).await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_oracle_conf_filter_per_token() -> Result<(), TransportError> {
+    let mut test_builder = TestContextBuilder::new();
+    test_builder.test().set_compute_max_units(150_000); // bad oracles log a lot
+    let context = test_builder.start_default().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+    let payer_token_accounts = &context.users[1].token_accounts[0..2];
+
+    //
+    // SETUP: Create a group, account, register tokens
+    //
+
+    let mango_setup::GroupWithTokens { group, tokens, .. } = mango_setup::GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..mango_setup::GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+
+    // fill vaults, so we can borrow
+    let _vault_account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        2,
+        &context.users[1],
+        mints,
+        100000,
+        0,
+    )
+    .await;
+
+    // Create account with token0 deposits
+    let account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        0,
+        &context.users[1],
+        &mints[0..1],
+        100,
+        0,
+    )
+    .await;
+
+    // token1's price has a confidence interval of 20%, more than the default filter of 10%
+    send_tx(
+        solana,
+        StubOracleSetTestInstruction {
+            oracle: tokens[1].oracle,
+            group,
+            mint: mints[1].pubkey,
+            admin,
+            price: 1.0,
+            last_update_slot: 0,
+            deviation: 0.2,
+        },
+    )
+    .await
+    .unwrap();
+
+    let borrow_token1 = || TokenWithdrawInstruction {
+        amount: 1,
+        allow_borrow: true,
+        account,
+        owner,
+        token_account: payer_token_accounts[1],
+        bank_index: 0,
+    };
+
+    //
+    // TEST: The default confidence filter rejects the price
+    //
+    let res = send_tx(solana, borrow_token1()).await;
+    assert_mango_error(
+        &res,
+        MangoError::OracleConfidence.into(),
+        "token1 confidence too wide".into(),
+    );
+
+    //
+    // TEST: Loosening token1's confidence filter accepts it, without affecting token0
+    //
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[1].pubkey,
+            fallback_oracle: Pubkey::default(),
+            options: mango_v4::instruction::TokenEdit {
+                oracle_config_opt: Some(OracleConfigParams {
+                    conf_filter: 0.3,
+                    max_staleness_slots: None,
+                }),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    let bank1 = solana.get_account::<Bank>(tokens[1].bank).await;
+    assert!(assert_equal(bank1.oracle_config.conf_filter, 0.3, 0.0001));
+    let bank0 = solana.get_account::<Bank>(tokens[0].bank).await;
+    assert!(assert_equal(bank0.oracle_config.conf_filter, 0.1, 0.0001));
+
+    send_tx(solana, borrow_token1()).await.unwrap();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_fallback_oracle_withdraw() -> Result<(), TransportError> {
     let mut test_builder = TestContextBuilder::new();
@@ -317,6 +559,14 @@ async fn test_fallback_oracle_withdraw() -> Result<(), TransportError> {
         .await
         .unwrap();
 
+    // The rejection of the primary oracle was logged
+    let rejected_logs = solana.program_log_events::<mango_v4::logs::OracleRejectedLog>();
+    assert!(!rejected_logs.is_empty());
+    let log = &rejected_logs[0];
+    assert_eq!(log.oracle, tokens[2].oracle);
+    assert!(!log.stale);
+    assert!(log.fallback_available);
+
     Ok(())
 }
 
@@ -494,21 +744,85 @@ async fn test_clmm_fallback_oracle() -> Result<(), TransportError> {
     .result
     .is_err());
 
-    // Finally send txn with a fallback oracle and pyth USD feed
     let pyth_usd_oracle_meta = AccountMeta {
         pubkey: pyth_usd_oracle,
         is_writable: false,
         is_signer: false,
     };
-    send_tx_with_extra_accounts(
+    let fallback_metas = vec![fallback_oracle_meta, pyth_usd_oracle_meta];
+
+    // The pool is rejected if its liquidity is below the configured minimum
+    send_tx(
         solana,
-        token_withdraw_ix,
-        vec![fallback_oracle_meta, pyth_usd_oracle_meta],
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[2].pubkey,
+            fallback_oracle,
+            options: mango_v4::instruction::TokenEdit {
+                fallback_oracle_min_liquidity_opt: Some(u64::MAX),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+    assert!(
+        send_tx_with_extra_accounts(solana, token_withdraw_ix.clone(), fallback_metas.clone())
+            .await
+            .unwrap()
+            .result
+            .is_err()
+    );
+
+    // The pool price is rejected if it deviates too much from the stable price,
+    // which is still at the registration price of 1.0
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[2].pubkey,
+            fallback_oracle,
+            options: mango_v4::instruction::TokenEdit {
+                fallback_oracle_min_liquidity_opt: Some(1),
+                fallback_oracle_max_stable_price_deviation_opt: Some(0.5),
+                ..token_edit_instruction_default()
+            },
+        },
     )
     .await
-    .unwrap()
-    .result
     .unwrap();
+    assert!(
+        send_tx_with_extra_accounts(solana, token_withdraw_ix.clone(), fallback_metas.clone())
+            .await
+            .unwrap()
+            .result
+            .is_err()
+    );
+
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[2].pubkey,
+            fallback_oracle,
+            options: mango_v4::instruction::TokenEdit {
+                fallback_oracle_max_stable_price_deviation_opt: Some(0.99),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    // Finally send txn with a fallback oracle and pyth USD feed, within the guards
+    send_tx_with_extra_accounts(solana, token_withdraw_ix, fallback_metas)
+        .await
+        .unwrap()
+        .result
+        .unwrap();
 
     Ok(())
 }
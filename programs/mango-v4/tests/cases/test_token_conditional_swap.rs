@@ -684,6 +684,28 @@ async fn test_token_conditional_swap_premium_auction() -> Result<(), TransportEr
     let account_data = get_mango_account(solana, account).await;
     assert_eq!(account_data.header.token_conditional_swap_count, 2);
 
+    //
+    // TEST: Can't create a premium auction that's too small for the start incentive
+    //
+    send_tx_expect_error!(
+        solana,
+        TokenConditionalSwapCreatePremiumAuctionInstruction {
+            account,
+            owner,
+            buy_mint: quote_token.mint.pubkey,
+            sell_mint: base_token.mint.pubkey,
+            max_buy: 100,
+            max_sell: 100,
+            price_lower_limit: 0.5,
+            price_upper_limit: 2.0,
+            max_price_premium_rate: 0.01,
+            allow_creating_deposits: true,
+            allow_creating_borrows: true,
+            duration_seconds: 10,
+        },
+        MangoError::TokenConditionalSwapTooSmallForStartIncentive
+    );
+
     //
     // TEST: Can create premium auction
     //
@@ -61,15 +61,33 @@ async fn test_token_update_index_and_rate() -> Result<(), TransportError> {
         solana,
         TokenUpdateIndexAndRateInstruction {
             mint_info: tokens[0].mint_info,
+            keeper: None,
         },
     )
     .await
     .unwrap();
 
+    let index_logs = solana.program_log_events::<mango_v4::logs::UpdateIndexLog>();
+    // rates are only recomputed once an hour
+    let rate_logs = solana.program_log_events::<mango_v4::logs::UpdateRateLogV2>();
+
     let bank_after = solana.get_account::<Bank>(tokens[0].bank).await;
     dbg!(bank_after);
     dbg!(bank_after);
 
+    assert_eq!(index_logs.len(), 1);
+    assert!(rate_logs.is_empty());
+    let log = &index_logs[0];
+    assert_eq!(log.mango_group, group);
+    assert_eq!(log.token_index, tokens[0].index);
+    assert_eq!(log.deposit_index, bank_after.deposit_index.to_bits());
+    assert_eq!(log.borrow_index, bank_after.borrow_index.to_bits());
+    assert_eq!(log.avg_utilization, bank_after.avg_utilization.to_bits());
+    assert_eq!(
+        log.collected_fees,
+        bank_after.collected_fees_native.to_bits()
+    );
+
     let utilization = 0.5; // 10000 deposits / 5000 borrows
     let diff_ts = (time_after - time_before) as f64;
     let year = 31536000.0;
@@ -133,13 +151,24 @@ async fn test_token_rates_migrate() -> Result<(), TransportError> {
         solana,
         TokenUpdateIndexAndRateInstruction {
             mint_info: tokens[0].mint_info,
+            keeper: None,
         },
     )
     .await
     .unwrap();
 
+    let rate_logs = solana.program_log_events::<mango_v4::logs::UpdateRateLogV2>();
+
     let bank_after = solana.get_account::<Bank>(tokens[0].bank).await;
 
+    assert_eq!(rate_logs.len(), 1);
+    let log = &rate_logs[0];
+    assert_eq!(log.token_index, tokens[0].index);
+    assert_eq!(log.rate0, bank_after.rate0.to_bits());
+    assert_eq!(log.rate1, bank_after.rate1.to_bits());
+    assert_eq!(log.max_rate, bank_after.max_rate.to_bits());
+    assert_eq!(log.curve_scaling, bank_after.interest_curve_scaling);
+
     assert!(assert_equal_fixed_f64(bank_after.rate0, 0.07 / 3.0, 0.0001));
     assert!(assert_equal_fixed_f64(bank_after.rate1, 0.9 / 3.0, 0.0001));
     assert!(assert_equal_fixed_f64(bank_after.max_rate, 0.5, 0.0001));
@@ -156,3 +185,367 @@ async fn test_token_rates_migrate() -> Result<(), TransportError> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_token_stable_price_update() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let token = &tokens[0];
+
+    let account =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+    assert!(assert_equal_f64_f64(
+        account_init_health(solana, account).await,
+        1000.0 * 0.6,
+        0.1
+    ));
+
+    //
+    // TEST: A jump in the oracle price is not reflected in the stable price
+    //
+    send_tx(
+        solana,
+        StubOracleSetInstruction {
+            oracle: token.oracle,
+            group,
+            admin,
+            mint: token.mint.pubkey,
+            price: 2.0,
+        },
+    )
+    .await
+    .unwrap();
+
+    let bank_before = solana.get_account::<Bank>(token.bank).await;
+    assert_eq!(bank_before.stable_price_model.stable_price, 1.0);
+
+    // init health values assets at min(oracle, stable), maint health at the oracle price
+    assert!(assert_equal_f64_f64(
+        account_init_health(solana, account).await,
+        1000.0 * 0.6,
+        0.1
+    ));
+    assert!(assert_equal_f64_f64(
+        account_maint_health(solana, account).await,
+        1000.0 * 0.8 * 2.0,
+        0.1
+    ));
+
+    //
+    // TEST: The keeper crank moves the stable price towards the oracle price, growth-clamped
+    //
+    let now = solana.clock().await.unix_timestamp;
+    solana.advance_clock_to(now + 30).await;
+
+    send_tx(
+        solana,
+        TokenUpdateIndexAndRateInstruction {
+            mint_info: token.mint_info,
+            keeper: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    let bank_after = solana.get_account::<Bank>(token.bank).await;
+    let model = &bank_after.stable_price_model;
+    let dt = (model.last_update_timestamp - bank_before.stable_price_model.last_update_timestamp)
+        .min(600) as f64;
+    let max_stable_price = 1.0 * (1.0 + model.stable_growth_limit as f64 * dt);
+    assert!(model.stable_price > 1.0);
+    assert!(model.stable_price <= max_stable_price + 1e-9);
+
+    assert!(assert_equal_f64_f64(
+        account_init_health(solana, account).await,
+        1000.0 * 0.6 * model.stable_price,
+        0.1
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_check_vault_invariant() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..2];
+
+    //
+    // SETUP: Create a group with deposits and a borrow
+    //
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let bank = tokens[0].bank;
+
+    create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 10000, 0).await;
+    let withdraw_account = create_funded_account(
+        &solana,
+        group,
+        owner,
+        1,
+        &context.users[1],
+        &mints[1..2],
+        100000,
+        0,
+    )
+    .await;
+    send_tx(
+        solana,
+        TokenWithdrawInstruction {
+            amount: 5000,
+            allow_borrow: true,
+            account: withdraw_account,
+            owner,
+            token_account: context.users[0].token_accounts[0],
+            bank_index: 0,
+        },
+    )
+    .await
+    .unwrap();
+
+    //
+    // TEST: A healthy bank passes the check
+    //
+    send_tx(solana, TokenCheckVaultInvariantInstruction { bank })
+        .await
+        .unwrap();
+    assert_eq!(solana.get_account::<Bank>(bank).await.reduce_only, 0);
+
+    //
+    // TEST: Without the halt flag, a violation doesn't change the bank
+    //
+    let mut bank_data = solana.get_account::<Bank>(bank).await;
+    bank_data.indexed_deposits += I80F48::from(1000);
+    solana.set_account(bank, &bank_data).await;
+
+    send_tx(solana, TokenCheckVaultInvariantInstruction { bank })
+        .await
+        .unwrap();
+    assert_eq!(solana.get_account::<Bank>(bank).await.reduce_only, 0);
+
+    //
+    // TEST: With the halt flag, a violation makes the bank reduce only
+    //
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[0].pubkey,
+            fallback_oracle: Pubkey::default(),
+            options: mango_v4::instruction::TokenEdit {
+                halt_on_vault_invariant_violation_opt: Some(true),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    send_tx(solana, TokenCheckVaultInvariantInstruction { bank })
+        .await
+        .unwrap();
+    let bank_data = solana.get_account::<Bank>(bank).await;
+    assert_eq!(bank_data.reduce_only, 1);
+    assert!(bank_data.are_deposits_reduce_only());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_update_index_and_rate_keeper_reward() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let owner = context.users[0].key;
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let bank = tokens[0].bank;
+
+    let keeper =
+        create_funded_account(&solana, group, owner, 0, &context.users[1], mints, 1000, 0).await;
+
+    send_tx(
+        solana,
+        TokenEdit {
+            group,
+            admin,
+            mint: mints[0].pubkey,
+            fallback_oracle: Pubkey::default(),
+            options: mango_v4::instruction::TokenEdit {
+                keeper_reward_per_hour_opt: Some(100.0),
+                ..token_edit_instruction_default()
+            },
+        },
+    )
+    .await
+    .unwrap();
+
+    // Pretend the bank collected fees that can fund the reward
+    let mut bank_data = solana.get_account::<Bank>(bank).await;
+    bank_data.collected_fees_native = I80F48::from(1000);
+    solana.set_account(bank, &bank_data).await;
+
+    let start_time = solana.clock_timestamp().await;
+    solana.set_clock_timestamp(start_time + 3601).await;
+
+    //
+    // TEST: The keeper is paid out of the bank's fees, without counting as withdrawn fees
+    //
+    send_tx(
+        solana,
+        TokenUpdateIndexAndRateInstruction {
+            mint_info: tokens[0].mint_info,
+            keeper: Some(keeper),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(account_position(solana, keeper, bank).await, 1100);
+    let bank_data = solana.get_account::<Bank>(bank).await;
+    assert_eq!(bank_data.keeper_rewards_paid, 100);
+    assert_eq!(bank_data.fees_withdrawn, 0);
+    assert_eq!(bank_data.fees_available(), I80F48::from(900));
+
+    //
+    // TEST: A frozen keeper can't collect the reward
+    //
+    send_tx(
+        solana,
+        AccountToggleFreezeInstruction {
+            group,
+            admin,
+            account: keeper,
+            freeze: true,
+        },
+    )
+    .await
+    .unwrap();
+    solana.set_clock_timestamp(start_time + 2 * 3601).await;
+    send_tx_expect_error!(
+        solana,
+        TokenUpdateIndexAndRateInstruction {
+            mint_info: tokens[0].mint_info,
+            keeper: Some(keeper),
+        },
+        MangoError::AccountIsFrozen,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_interest_rate_shift() -> Result<(), TransportError> {
+    let context = TestContext::new().await;
+    let solana = &context.solana.clone();
+
+    let admin = TestKeypair::new();
+    let payer = context.users[1].key;
+    let mints = &context.mints[0..1];
+
+    let GroupWithTokens { group, tokens, .. } = GroupWithTokensConfig {
+        admin,
+        payer,
+        mints: mints.to_vec(),
+        ..GroupWithTokensConfig::default()
+    }
+    .create(solana)
+    .await;
+    let bank = tokens[0].bank;
+    let bank_before = solana.get_account::<Bank>(bank).await;
+
+    let edit = |interest_rate_params_opt: Option<mango_v4::accounts_ix::InterestRateParams>,
+                interest_rate_shift_hours_opt: Option<u32>| TokenEdit {
+        group,
+        admin,
+        mint: mints[0].pubkey,
+        fallback_oracle: Pubkey::default(),
+        options: mango_v4::instruction::TokenEdit {
+            interest_rate_params_opt,
+            interest_rate_shift_hours_opt,
+            ..token_edit_instruction_default()
+        },
+    };
+    let new_params = || mango_v4::accounts_ix::InterestRateParams {
+        util0: 0.5,
+        rate0: 0.1,
+        util1: 0.8,
+        rate1: 0.2,
+        max_rate: 2.0,
+        adjustment_factor: 0.0,
+    };
+
+    //
+    // TEST: A shift without interest rate params is rejected
+    //
+    send_tx_expect_error!(solana, edit(None, Some(10)), MangoError::SomeError);
+
+    //
+    // TEST: A shift with interest rate params sets the targets, not the curve
+    //
+    send_tx(solana, edit(Some(new_params()), Some(10)))
+        .await
+        .unwrap();
+    let now = solana.clock_timestamp().await;
+    let bank_data = solana.get_account::<Bank>(bank).await;
+    assert_eq!(bank_data.interest_rate_shift_end, now + 10 * 3600);
+    assert_eq!(bank_data.rate0, bank_before.rate0);
+    assert_eq!(bank_data.max_rate, bank_before.max_rate);
+    assert_eq!(
+        bank_data.interest_rate_shift_rate0,
+        I80F48::from_num(0.1f32)
+    );
+    assert_eq!(
+        bank_data.interest_rate_shift_max_rate,
+        I80F48::from_num(2.0f32)
+    );
+
+    //
+    // TEST: Interest rate params without a shift apply at once and cancel the shift
+    //
+    send_tx(solana, edit(Some(new_params()), None))
+        .await
+        .unwrap();
+    let bank_data = solana.get_account::<Bank>(bank).await;
+    assert_eq!(bank_data.interest_rate_shift_end, 0);
+    assert_eq!(bank_data.rate0, I80F48::from_num(0.1f32));
+    assert_eq!(bank_data.max_rate, I80F48::from_num(2.0f32));
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
 #![cfg(feature = "test-bpf")]
 
 mod cases;
-pub mod program_test;
+pub use mango_v4::test_fixtures as program_test;
@@ -84,7 +84,11 @@ import {
 import { Id } from './ids';
 import { IDL, MangoV4 } from './mango_v4';
 import { I80F48 } from './numbers/I80F48';
-import { FlashLoanType, OracleConfigParams } from './types';
+import {
+  FastListingTier,
+  FlashLoanType,
+  OracleConfigParams,
+} from './types';
 import {
   I64_MAX_BN,
   U64_MAX_BN,
@@ -304,6 +308,10 @@ export class MangoClient {
     feesMngoTokenIndex?: TokenIndex,
     feesExpiryInterval?: BN,
     allowedFastListingsPerInterval?: number,
+    allowedSwapPrograms?: PublicKey[],
+    referralFeeShare?: number,
+    insuranceTokenIndex?: TokenIndex,
+    allowCpiDeposit?: boolean,
   ): Promise<MangoSignatureStatus> {
     const ix = await this.program.methods
       .groupEdit(
@@ -319,11 +327,27 @@ export class MangoClient {
         feesMngoTokenIndex ?? null,
         feesExpiryInterval ?? null,
         allowedFastListingsPerInterval ?? null,
+        allowedSwapPrograms ?? null,
+        referralFeeShare ?? null,
+        insuranceTokenIndex ?? null,
+        allowCpiDeposit ?? null,
       )
       .accounts({
         group: group.publicKey,
         admin: (this.program.provider as AnchorProvider).wallet.publicKey,
       })
+      .remainingAccounts(
+        insuranceTokenIndex !== undefined
+          ? [
+              {
+                pubkey:
+                  group.getFirstBankByTokenIndex(insuranceTokenIndex).publicKey,
+                isWritable: false,
+                isSigner: false,
+              } as AccountMeta,
+            ]
+          : [],
+      )
       .instruction();
     return await this.sendAndConfirmTransactionForGroup(group, [ix]);
   }
@@ -481,9 +505,10 @@ export class MangoClient {
     oraclePk: PublicKey,
     tokenIndex: number,
     name: string,
+    tier: FastListingTier = FastListingTier.collateralDisabled,
   ): Promise<MangoSignatureStatus> {
     const ix = await this.program.methods
-      .tokenRegisterTrustless(tokenIndex, name)
+      .tokenRegisterTrustless(tokenIndex, name, tier)
       .accounts({
         group: group.publicKey,
         admin: (this.program.provider as AnchorProvider).wallet.publicKey,
@@ -548,6 +573,13 @@ export class MangoClient {
         params.depositLimit,
         params.zeroUtilRate,
         params.platformLiquidationFee,
+        params.keeperRewardPerHour,
+        params.fallbackOracleMaxStablePriceDeviation,
+        params.fallbackOracleMinLiquidity,
+        params.haltOnVaultInvariantViolation,
+        params.collateralOnly,
+        params.interestRateShiftHours,
+        params.oracleTwapWindowSeconds,
       )
       .accounts({
         group: group.publicKey,
@@ -908,12 +940,54 @@ export class MangoClient {
         delegate ?? null,
         temporaryDelegate ?? null,
         delegateExpiry ? new BN(delegateExpiry) : null,
+        null,
+        null,
+        null,
+      )
+      .accounts({
+        group: group.publicKey,
+        account: mangoAccount.publicKey,
+        owner: (this.program.provider as AnchorProvider).wallet.publicKey,
+      })
+      .instruction();
+
+    return await this.sendAndConfirmTransactionForGroup(group, [ix]);
+  }
+
+  public async isolateMangoAccount(
+    group: Group,
+    mangoAccount: MangoAccount,
+    isolated: boolean,
+    perpMarketIndex: PerpMarketIndex,
+    tokenIndexes: TokenIndex[],
+  ): Promise<MangoSignatureStatus> {
+    const remainingAccounts: AccountMeta[] =
+      perpMarketIndex !== PerpPosition.PerpMarketIndexUnset
+        ? [
+            {
+              pubkey: group.getPerpMarketByMarketIndex(perpMarketIndex)
+                .publicKey,
+              isWritable: false,
+              isSigner: false,
+            } as AccountMeta,
+          ]
+        : [];
+    const ix = await this.program.methods
+      .accountEdit(
+        null,
+        null,
+        null,
+        null,
+        isolated,
+        perpMarketIndex,
+        tokenIndexes,
       )
       .accounts({
         group: group.publicKey,
         account: mangoAccount.publicKey,
         owner: (this.program.provider as AnchorProvider).wallet.publicKey,
       })
+      .remainingAccounts(remainingAccounts)
       .instruction();
 
     return await this.sendAndConfirmTransactionForGroup(group, [ix]);
@@ -1645,11 +1719,18 @@ export class MangoClient {
     forceClose: boolean | null,
     name: string | null,
     oraclePriceBand: number | null,
+    crossingPriceBand: number | null = null,
   ): Promise<TransactionInstruction> {
     const serum3Market =
       group.serum3MarketsMapByMarketIndex.get(serum3MarketIndex);
     const ix = await this.program.methods
-      .serum3EditMarket(reduceOnly, forceClose, name, oraclePriceBand)
+      .serum3EditMarket(
+        reduceOnly,
+        forceClose,
+        name,
+        oraclePriceBand,
+        crossingPriceBand,
+      )
       .accounts({
         group: group.publicKey,
         admin: admin,
@@ -1666,6 +1747,7 @@ export class MangoClient {
     forceClose: boolean | null,
     name: string | null,
     oraclePriceBand: number | null,
+    crossingPriceBand: number | null = null,
   ): Promise<MangoSignatureStatus> {
     const admin = (this.program.provider as AnchorProvider).wallet.publicKey;
     const ix = await this.serum3EditMarketIx(
@@ -1676,6 +1758,7 @@ export class MangoClient {
       forceClose,
       name,
       oraclePriceBand,
+      crossingPriceBand,
     );
     return await this.sendAndConfirmTransactionForGroup(group, [ix]);
   }
@@ -2659,6 +2742,11 @@ export class MangoClient {
         params.name,
         params.forceClose,
         params.platformLiquidationFee,
+        params.keeperRewardPerEvent,
+        params.keeperRewardFundingPerHour,
+        params.oraclePriceBand,
+        params.fundingPeriodSeconds,
+        params.dustThreshold,
       )
       .accounts({
         group: group.publicKey,
@@ -111,6 +111,13 @@ export interface TokenEditParams {
   depositLimit: BN | null;
   zeroUtilRate: number | null;
   platformLiquidationFee: number | null;
+  keeperRewardPerHour: number | null;
+  fallbackOracleMaxStablePriceDeviation: number | null;
+  fallbackOracleMinLiquidity: BN | null;
+  haltOnVaultInvariantViolation: boolean | null;
+  collateralOnly: boolean | null;
+  interestRateShiftHours: number | null;
+  oracleTwapWindowSeconds: number | null;
 }
 
 export const NullTokenEditParams: TokenEditParams = {
@@ -152,6 +159,13 @@ export const NullTokenEditParams: TokenEditParams = {
   depositLimit: null,
   zeroUtilRate: null,
   platformLiquidationFee: null,
+  keeperRewardPerHour: null,
+  fallbackOracleMaxStablePriceDeviation: null,
+  fallbackOracleMinLiquidity: null,
+  haltOnVaultInvariantViolation: null,
+  collateralOnly: null,
+  interestRateShiftHours: null,
+  oracleTwapWindowSeconds: null,
 };
 
 export interface PerpEditParams {
@@ -186,6 +200,11 @@ export interface PerpEditParams {
   name: string | null;
   forceClose: boolean | null;
   platformLiquidationFee: number | null;
+  keeperRewardPerEvent: number | null;
+  keeperRewardFundingPerHour: number | null;
+  oraclePriceBand: number | null;
+  fundingPeriodSeconds: number | null;
+  dustThreshold: number | null;
 }
 
 export const NullPerpEditParams: PerpEditParams = {
@@ -220,6 +239,11 @@ export const NullPerpEditParams: PerpEditParams = {
   name: null,
   forceClose: null,
   platformLiquidationFee: null,
+  keeperRewardPerEvent: null,
+  keeperRewardFundingPerHour: null,
+  oraclePriceBand: null,
+  fundingPeriodSeconds: null,
+  dustThreshold: null,
 };
 
 // Use with TrueIxGateParams and buildIxGate
@@ -299,6 +323,23 @@ export interface IxGateParams {
   TokenConditionalSwapCreatePremiumAuction: boolean;
   TokenConditionalSwapCreateLinearAuction: boolean;
   Serum3PlaceOrderV2: boolean;
+  PerpLiqAutoDeleverage: boolean;
+  GroupDepositInsuranceFund: boolean;
+  PerpTriggerOrderCreate: boolean;
+  PerpTriggerOrderCancel: boolean;
+  PerpTriggerOrderExecute: boolean;
+  AltCreate: boolean;
+  MarginTrade: boolean;
+  PerpSettlePnlBatch: boolean;
+  PerpModifyOrder: boolean;
+  PerpPlaceOrders: boolean;
+  Serum3PlaceOrders: boolean;
+  TokenCheckVaultInvariant: boolean;
+  AccountClaimReferralFees: boolean;
+  PerpUpdateAdlCandidate: boolean;
+  PerpLiqCombined: boolean;
+  GroupMigrate: boolean;
+  TokenMigrate: boolean;
 }
 
 // Default with all ixs enabled, use with buildIxGate
@@ -378,6 +419,23 @@ export const TrueIxGateParams: IxGateParams = {
   TokenConditionalSwapCreatePremiumAuction: true,
   TokenConditionalSwapCreateLinearAuction: true,
   Serum3PlaceOrderV2: true,
+  PerpLiqAutoDeleverage: true,
+  GroupDepositInsuranceFund: true,
+  PerpTriggerOrderCreate: true,
+  PerpTriggerOrderCancel: true,
+  PerpTriggerOrderExecute: true,
+  AltCreate: true,
+  MarginTrade: true,
+  PerpSettlePnlBatch: true,
+  PerpModifyOrder: true,
+  PerpPlaceOrders: true,
+  Serum3PlaceOrders: true,
+  TokenCheckVaultInvariant: true,
+  AccountClaimReferralFees: true,
+  PerpUpdateAdlCandidate: true,
+  PerpLiqCombined: true,
+  GroupMigrate: true,
+  TokenMigrate: true,
 };
 
 // build ix gate e.g. buildIxGate(Builder(TrueIxGateParams).TokenDeposit(false).build()).toNumber(),
@@ -467,6 +525,23 @@ export function buildIxGate(p: IxGateParams): BN {
   toggleIx(ixGate, p, 'TokenConditionalSwapCreatePremiumAuction', 69);
   toggleIx(ixGate, p, 'TokenConditionalSwapCreateLinearAuction', 70);
   toggleIx(ixGate, p, 'Serum3PlaceOrderV2', 71);
+  toggleIx(ixGate, p, 'PerpLiqAutoDeleverage', 72);
+  toggleIx(ixGate, p, 'GroupDepositInsuranceFund', 73);
+  toggleIx(ixGate, p, 'PerpTriggerOrderCreate', 74);
+  toggleIx(ixGate, p, 'PerpTriggerOrderCancel', 75);
+  toggleIx(ixGate, p, 'PerpTriggerOrderExecute', 76);
+  toggleIx(ixGate, p, 'AltCreate', 77);
+  toggleIx(ixGate, p, 'MarginTrade', 78);
+  toggleIx(ixGate, p, 'PerpSettlePnlBatch', 79);
+  toggleIx(ixGate, p, 'PerpModifyOrder', 80);
+  toggleIx(ixGate, p, 'PerpPlaceOrders', 81);
+  toggleIx(ixGate, p, 'Serum3PlaceOrders', 82);
+  toggleIx(ixGate, p, 'TokenCheckVaultInvariant', 83);
+  toggleIx(ixGate, p, 'AccountClaimReferralFees', 84);
+  toggleIx(ixGate, p, 'PerpUpdateAdlCandidate', 85);
+  toggleIx(ixGate, p, 'PerpLiqCombined', 86);
+  toggleIx(ixGate, p, 'GroupMigrate', 87);
+  toggleIx(ixGate, p, 'TokenMigrate', 88);
 
   return ixGate;
 }
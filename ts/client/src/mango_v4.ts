@@ -277,6 +277,71 @@ export type MangoV4 = {
           "type": {
             "option": "u16"
           }
+        },
+        {
+          "name": "allowedSwapProgramsOpt",
+          "type": {
+            "option": {
+              "vec": "publicKey"
+            }
+          }
+        },
+        {
+          "name": "referralFeeShareOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "insuranceTokenIndexOpt",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "allowCpiDepositOpt",
+          "type": {
+            "option": "bool"
+          }
+        }
+      ]
+    },
+    {
+      "name": "groupDepositInsuranceFund",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "insurance_vault"
+          ]
+        },
+        {
+          "name": "insuranceVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
@@ -341,6 +406,17 @@ export type MangoV4 = {
         }
       ]
     },
+    {
+      "name": "groupMigrate",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "groupClose",
       "accounts": [
@@ -774,6 +850,12 @@ export type MangoV4 = {
         {
           "name": "name",
           "type": "string"
+        },
+        {
+          "name": "tier",
+          "type": {
+            "defined": "FastListingTier"
+          }
         }
       ]
     },
@@ -1041,6 +1123,48 @@ export type MangoV4 = {
           "type": {
             "option": "f32"
           }
+        },
+        {
+          "name": "keeperRewardPerHourOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "fallbackOracleMaxStablePriceDeviationOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "fallbackOracleMinLiquidityOpt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "haltOnVaultInvariantViolationOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "collateralOnlyOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "interestRateShiftHoursOpt",
+          "type": {
+            "option": "u32"
+          }
+        },
+        {
+          "name": "oracleTwapWindowSecondsOpt",
+          "type": {
+            "option": "u32"
+          }
         }
       ]
     },
@@ -1241,6 +1365,62 @@ export type MangoV4 = {
           "name": "instructions",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Account that receives the keeper reward, see Bank::keeper_reward_per_hour"
+          ],
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "tokenMigrate",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "tokenCheckVaultInvariant",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "vault"
+          ]
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -1296,6 +1476,18 @@ export type MangoV4 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The MangoAccount that referred the new account, see MangoAccount::referrer"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
@@ -1376,6 +1568,18 @@ export type MangoV4 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The MangoAccount that referred the new account, see MangoAccount::referrer"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
@@ -1593,9 +1797,77 @@ export type MangoV4 = {
           "type": {
             "option": "u64"
           }
+        },
+        {
+          "name": "isolatedOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "isolatedPerpMarketIndexOpt",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "isolatedTokenIndexesOpt",
+          "type": {
+            "option": {
+              "vec": "u16"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "groupToggleWithdrawOnly",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawOnly",
+          "type": "bool"
         }
       ]
     },
+    {
+      "name": "accountCreateDaoTokenPositions",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "daoAccount",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "accountToggleFreeze",
       "accounts": [
@@ -1729,24 +2001,80 @@ export type MangoV4 = {
       ]
     },
     {
-      "name": "stubOracleCreate",
+      "name": "accountClaimReferralFees",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
           "isSigner": false,
+          "docs": [
+            "The referred account"
+          ],
           "relations": [
-            "admin"
+            "group",
+            "referrer"
           ]
         },
         {
-          "name": "oracle",
+          "name": "referrer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "admin",
-          "isMut": false,
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank the payout is deposited into.",
+            "",
+            "Without perp_market, the payout comes out of this bank's loan origination fees."
+          ],
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "If set, the payout comes out of this market's settled fees instead"
+          ],
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "stubOracleCreate",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "oracle",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "admin",
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -2237,6 +2565,44 @@ export type MangoV4 = {
         }
       ]
     },
+    {
+      "name": "marginTrade",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "numHealthAccounts",
+          "type": "u8"
+        },
+        {
+          "name": "cpiData",
+          "type": "bytes"
+        }
+      ]
+    },
     {
       "name": "healthRegionBegin",
       "accounts": [
@@ -2442,6 +2808,12 @@ export type MangoV4 = {
           "type": {
             "option": "f32"
           }
+        },
+        {
+          "name": "crossingPriceBandOpt",
+          "type": {
+            "option": "f32"
+          }
         }
       ]
     },
@@ -2940,6 +3312,141 @@ export type MangoV4 = {
         }
       ]
     },
+    {
+      "name": "serum3PlaceOrders",
+      "docs": [
+        "Place several orders on one side of the book with a single health check,",
+        "requires the receiver_bank in the health account list to be writable"
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "serumMarket",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "serum_program",
+            "serum_market_external"
+          ]
+        },
+        {
+          "name": "serumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "serumMarketExternal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketBids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketAsks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketEventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketRequestQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketBaseVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketQuoteVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketVaultSigner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "needed for the automatic settle_funds call"
+          ]
+        },
+        {
+          "name": "payerBank",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank that pays for the order, if necessary"
+          ],
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "payerVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank vault that pays for the order, if necessary"
+          ]
+        },
+        {
+          "name": "payerOracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": "Serum3Side"
+          }
+        },
+        {
+          "name": "orders",
+          "type": {
+            "vec": {
+              "defined": "Serum3PlaceOrderArgs"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "serum3CancelOrder",
       "accounts": [
@@ -3187,7 +3694,7 @@ export type MangoV4 = {
         },
         {
           "name": "serumMarket",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group",
@@ -3292,7 +3799,7 @@ export type MangoV4 = {
             },
             {
               "name": "serumMarket",
-              "isMut": false,
+              "isMut": true,
               "isSigner": false,
               "relations": [
                 "group",
@@ -3407,7 +3914,7 @@ export type MangoV4 = {
         },
         {
           "name": "serumMarket",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group",
@@ -4177,6 +4684,36 @@ export type MangoV4 = {
           "type": {
             "option": "f32"
           }
+        },
+        {
+          "name": "keeperRewardPerEventOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "keeperRewardFundingPerHourOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "oraclePriceBandOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "fundingPeriodSecondsOpt",
+          "type": {
+            "option": "u32"
+          }
+        },
+        {
+          "name": "dustThresholdOpt",
+          "type": {
+            "option": "f32"
+          }
         }
       ]
     },
@@ -4696,7 +5233,11 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "perpCancelOrder",
+      "name": "perpModifyOrder",
+      "docs": [
+        "Cancel the order with cancel_client_order_id and place a new order, like",
+        "perp_place_order_v2, with a single health check."
+      ],
       "accounts": [
         {
           "name": "group",
@@ -4723,7 +5264,9 @@ export type MangoV4 = {
           "relations": [
             "group",
             "bids",
-            "asks"
+            "asks",
+            "event_queue",
+            "oracle"
           ]
         },
         {
@@ -4735,13 +5278,195 @@ export type MangoV4 = {
           "name": "asks",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "orderId",
-          "type": "u128"
-        }
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "cancelClientOrderId",
+          "type": "u64"
+        },
+        {
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "priceLots",
+          "type": "i64"
+        },
+        {
+          "name": "maxBaseLots",
+          "type": "i64"
+        },
+        {
+          "name": "maxQuoteLots",
+          "type": "i64"
+        },
+        {
+          "name": "clientOrderId",
+          "type": "u64"
+        },
+        {
+          "name": "orderType",
+          "type": {
+            "defined": "PlaceOrderType"
+          }
+        },
+        {
+          "name": "selfTradeBehavior",
+          "type": {
+            "defined": "SelfTradeBehavior"
+          }
+        },
+        {
+          "name": "reduceOnly",
+          "type": "bool"
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "limit",
+          "type": "u8"
+        }
+      ],
+      "returns": {
+        "option": "u128"
+      }
+    },
+    {
+      "name": "perpPlaceOrders",
+      "docs": [
+        "Place several orders, like perp_place_order_v2, with a single health check.",
+        "",
+        "Already expired orders are skipped."
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "bids",
+            "asks",
+            "event_queue",
+            "oracle"
+          ]
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "orders",
+          "type": {
+            "vec": {
+              "defined": "PerpPlaceOrderArgs"
+            }
+          }
+        },
+        {
+          "name": "limit",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "perpCancelOrder",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "bids",
+            "asks"
+          ]
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "orderId",
+          "type": "u128"
+        }
       ]
     },
     {
@@ -4920,6 +5645,18 @@ export type MangoV4 = {
           "name": "eventQueue",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Account that receives the keeper reward, see PerpMarket::keeper_reward_per_event"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
@@ -4962,6 +5699,18 @@ export type MangoV4 = {
           "name": "oracle",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Account that receives the keeper reward, see PerpMarket::keeper_reward_funding_per_hour"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": []
@@ -5033,6 +5782,62 @@ export type MangoV4 = {
       ],
       "args": []
     },
+    {
+      "name": "perpSettlePnlBatch",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settler",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "settlerOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "perpMarket",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "settleOracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "numPairs",
+          "type": "u8"
+        }
+      ]
+    },
     {
       "name": "perpForceClosePosition",
       "accounts": [
@@ -5199,7 +6004,7 @@ export type MangoV4 = {
       ]
     },
     {
-      "name": "perpLiqForceCancelOrders",
+      "name": "perpLiqCombined",
       "accounts": [
         {
           "name": "group",
@@ -5207,7 +6012,110 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle",
+            "bids",
+            "asks"
+          ]
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liqor",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "liqorOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "liqee",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "settleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settleOracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "limit",
+          "type": "u8"
+        },
+        {
+          "name": "maxBaseTransfer",
+          "type": "i64"
+        },
+        {
+          "name": "maxPnlTransfer",
+          "type": "u64"
+        },
+        {
+          "name": "assetTokenIndex",
+          "type": "u16"
+        },
+        {
+          "name": "liabTokenIndex",
+          "type": "u16"
+        },
+        {
+          "name": "maxLiabTransfer",
+          "type": {
+            "defined": "I80F48"
+          }
+        }
+      ]
+    },
+    {
+      "name": "perpLiqForceCancelOrders",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -5425,80 +6333,74 @@ export type MangoV4 = {
       ]
     },
     {
-      "name": "tokenConditionalSwapCreate",
+      "name": "perpLiqAutoDeleverage",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "insurance_vault"
+          ]
         },
         {
-          "name": "account",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "oracle"
           ]
         },
         {
-          "name": "authority",
+          "name": "oracle",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "buyBank",
-          "isMut": false,
+          "name": "liqee",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "sellBank",
-          "isMut": false,
+          "name": "counterparty",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
-        },
-        {
-          "name": "expiryTimestamp",
-          "type": "u64"
-        },
-        {
-          "name": "priceLowerLimit",
-          "type": "f64"
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64"
+          "name": "insuranceVault",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "pricePremiumRate",
-          "type": "f64"
+          "name": "insuranceBank",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
-        },
+          "name": "insuranceOracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
+          "name": "maxBaseTransfer",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapCreateV2",
+      "name": "perpUpdateAdlCandidate",
       "accounts": [
         {
           "name": "group",
@@ -5506,28 +6408,21 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "oracle"
           ]
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "buyBank",
+          "name": "oracle",
           "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
         },
         {
-          "name": "sellBank",
+          "name": "account",
           "isMut": false,
           "isSigner": false,
           "relations": [
@@ -5535,55 +6430,10 @@ export type MangoV4 = {
           ]
         }
       ],
-      "args": [
-        {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
-        },
-        {
-          "name": "expiryTimestamp",
-          "type": "u64"
-        },
-        {
-          "name": "priceLowerLimit",
-          "type": "f64"
-        },
-        {
-          "name": "priceUpperLimit",
-          "type": "f64"
-        },
-        {
-          "name": "pricePremiumRate",
-          "type": "f64"
-        },
-        {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
-        },
-        {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
-        },
-        {
-          "name": "displayPriceStyle",
-          "type": {
-            "defined": "TokenConditionalSwapDisplayPriceStyle"
-          }
-        },
-        {
-          "name": "intention",
-          "type": {
-            "defined": "TokenConditionalSwapIntention"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "tokenConditionalSwapCreatePremiumAuction",
+      "name": "perpTriggerOrderCreate",
       "accounts": [
         {
           "name": "group",
@@ -5592,7 +6442,7 @@ export type MangoV4 = {
         },
         {
           "name": "account",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
@@ -5604,7 +6454,7 @@ export type MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "buyBank",
+          "name": "perpMarket",
           "isMut": false,
           "isSigner": false,
           "relations": [
@@ -5612,67 +6462,89 @@ export type MangoV4 = {
           ]
         },
         {
-          "name": "sellBank",
-          "isMut": false,
+          "name": "triggerOrder",
+          "isMut": true,
           "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "PerpTriggerOrder"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "account"
+              },
+              {
+                "kind": "arg",
+                "type": "u64",
+                "path": "order_id"
+              }
+            ]
+          }
         },
         {
-          "name": "expiryTimestamp",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "orderId",
           "type": "u64"
         },
         {
-          "name": "priceLowerLimit",
-          "type": "f64"
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64"
+          "name": "triggerCondition",
+          "type": {
+            "defined": "PerpTriggerCondition"
+          }
         },
         {
-          "name": "maxPricePremiumRate",
-          "type": "f64"
+          "name": "triggerPriceLots",
+          "type": "i64"
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
+          "name": "priceLots",
+          "type": "i64"
         },
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
+          "name": "maxBaseLots",
+          "type": "i64"
         },
         {
-          "name": "displayPriceStyle",
-          "type": {
-            "defined": "TokenConditionalSwapDisplayPriceStyle"
-          }
+          "name": "maxQuoteLots",
+          "type": "i64"
         },
         {
-          "name": "intention",
-          "type": {
-            "defined": "TokenConditionalSwapIntention"
-          }
+          "name": "reduceOnly",
+          "type": "bool"
         },
         {
-          "name": "durationSeconds",
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "executorIncentive",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapCreateLinearAuction",
+      "name": "perpTriggerOrderCancel",
       "accounts": [
         {
           "name": "group",
@@ -5681,7 +6553,7 @@ export type MangoV4 = {
         },
         {
           "name": "account",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
@@ -5693,101 +6565,95 @@ export type MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "buyBank",
-          "isMut": false,
+          "name": "triggerOrder",
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "account"
           ]
         },
         {
-          "name": "sellBank",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "solDestination",
+          "isMut": true,
+          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
-        },
-        {
-          "name": "expiryTimestamp",
-          "type": "u64"
-        },
+      "args": []
+    },
+    {
+      "name": "perpTriggerOrderExecute",
+      "docs": [
+        "Permissionless: places the stored order once the oracle price reached the trigger price"
+      ],
+      "accounts": [
         {
-          "name": "priceStart",
-          "type": "f64"
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "priceEnd",
-          "type": "f64"
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "bids",
+            "asks",
+            "event_queue",
+            "oracle"
+          ]
         },
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "displayPriceStyle",
-          "type": {
-            "defined": "TokenConditionalSwapDisplayPriceStyle"
-          }
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "startTimestamp",
-          "type": "u64"
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "durationSeconds",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "tokenConditionalSwapCancel",
-      "accounts": [
-        {
-          "name": "group",
+          "name": "oracle",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "triggerOrder",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "account"
           ]
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "buyBank",
+          "name": "executorAccount",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "The bank's token_index is checked at #1"
-          ],
           "relations": [
             "group"
           ]
         },
         {
-          "name": "sellBank",
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "settleBank",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -5797,17 +6663,13 @@ export type MangoV4 = {
       ],
       "args": [
         {
-          "name": "tokenConditionalSwapIndex",
+          "name": "limit",
           "type": "u8"
-        },
-        {
-          "name": "tokenConditionalSwapId",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapTrigger",
+      "name": "tokenConditionalSwapCreate",
       "accounts": [
         {
           "name": "group",
@@ -5815,7 +6677,7 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "liqee",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -5823,40 +6685,64 @@ export type MangoV4 = {
           ]
         },
         {
-          "name": "liqor",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "buyBank",
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "liqorAuthority",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
         {
-          "name": "tokenConditionalSwapIndex",
-          "type": "u8"
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "maxSell",
           "type": "u64"
         },
         {
-          "name": "maxBuyTokenToLiqee",
+          "name": "expiryTimestamp",
           "type": "u64"
         },
         {
-          "name": "maxSellTokenToLiqor",
-          "type": "u64"
+          "name": "priceLowerLimit",
+          "type": "f64"
+        },
+        {
+          "name": "priceUpperLimit",
+          "type": "f64"
+        },
+        {
+          "name": "pricePremiumRate",
+          "type": "f64"
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapTriggerV2",
+      "name": "tokenConditionalSwapCreateV2",
       "accounts": [
         {
           "name": "group",
@@ -5864,7 +6750,7 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "liqee",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -5872,48 +6758,76 @@ export type MangoV4 = {
           ]
         },
         {
-          "name": "liqor",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "buyBank",
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "liqorAuthority",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
         {
-          "name": "tokenConditionalSwapIndex",
-          "type": "u8"
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "maxSell",
           "type": "u64"
         },
         {
-          "name": "maxBuyTokenToLiqee",
+          "name": "expiryTimestamp",
           "type": "u64"
         },
         {
-          "name": "maxSellTokenToLiqor",
-          "type": "u64"
+          "name": "priceLowerLimit",
+          "type": "f64"
         },
         {
-          "name": "minBuyToken",
-          "type": "u64"
+          "name": "priceUpperLimit",
+          "type": "f64"
         },
         {
-          "name": "minTakerPrice",
-          "type": "f32"
+          "name": "pricePremiumRate",
+          "type": "f64"
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": {
+            "defined": "TokenConditionalSwapDisplayPriceStyle"
+          }
+        },
+        {
+          "name": "intention",
+          "type": {
+            "defined": "TokenConditionalSwapIntention"
+          }
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapStart",
+      "name": "tokenConditionalSwapCreatePremiumAuction",
       "accounts": [
         {
           "name": "group",
@@ -5921,7 +6835,7 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "liqee",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -5929,104 +6843,215 @@ export type MangoV4 = {
           ]
         },
         {
-          "name": "liqor",
-          "isMut": true,
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "buyBank",
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "liqorAuthority",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
         {
-          "name": "tokenConditionalSwapIndex",
-          "type": "u8"
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "maxSell",
+          "type": "u64"
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "priceLowerLimit",
+          "type": "f64"
+        },
+        {
+          "name": "priceUpperLimit",
+          "type": "f64"
+        },
+        {
+          "name": "maxPricePremiumRate",
+          "type": "f64"
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": {
+            "defined": "TokenConditionalSwapDisplayPriceStyle"
+          }
+        },
+        {
+          "name": "intention",
+          "type": {
+            "defined": "TokenConditionalSwapIntention"
+          }
+        },
+        {
+          "name": "durationSeconds",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "altSet",
+      "name": "tokenConditionalSwapCreateLinearAuction",
       "accounts": [
         {
           "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "admin"
+            "group"
           ]
         },
         {
-          "name": "admin",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "addressLookupTable",
-          "isMut": true,
-          "isSigner": false
+          "name": "buyBank",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "sellBank",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u8"
+          "name": "maxBuy",
+          "type": "u64"
+        },
+        {
+          "name": "maxSell",
+          "type": "u64"
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "priceStart",
+          "type": "f64"
+        },
+        {
+          "name": "priceEnd",
+          "type": "f64"
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": {
+            "defined": "TokenConditionalSwapDisplayPriceStyle"
+          }
+        },
+        {
+          "name": "startTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "durationSeconds",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "altExtend",
+      "name": "tokenConditionalSwapCancel",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "admin"
+            "group"
           ]
         },
         {
-          "name": "admin",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
+          "name": "buyBank",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank's token_index is checked at #1"
+          ],
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "addressLookupTable",
+          "name": "sellBank",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
         {
-          "name": "index",
+          "name": "tokenConditionalSwapIndex",
           "type": "u8"
         },
         {
-          "name": "newAddresses",
-          "type": {
-            "vec": "publicKey"
-          }
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "computeAccountData",
-      "docs": [
-        "Warning, this instruction is for testing purposes only!"
-      ],
+      "name": "tokenConditionalSwapTrigger",
       "accounts": [
         {
           "name": "group",
@@ -6034,135 +7059,105 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
-          "isMut": false,
+          "name": "liqee",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "openbookV2RegisterMarket",
-      "docs": [
-        "",
-        "OpenbookV2",
-        ""
-      ],
-      "accounts": [
+        },
         {
-          "name": "group",
+          "name": "liqor",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "admin"
+            "group"
           ]
         },
         {
-          "name": "admin",
+          "name": "liqorAuthority",
           "isMut": false,
           "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenConditionalSwapIndex",
+          "type": "u8"
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
         },
         {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false
+          "name": "maxBuyTokenToLiqee",
+          "type": "u64"
         },
         {
-          "name": "openbookV2Market",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "OpenbookV2Market"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "group"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "openbook_v2_market_external"
-              }
-            ]
-          }
-        },
+          "name": "maxSellTokenToLiqor",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "tokenConditionalSwapTriggerV2",
+      "accounts": [
         {
-          "name": "indexReservation",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "OpenbookV2Index"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "group"
-              },
-              {
-                "kind": "arg",
-                "type": "u16",
-                "path": "market_index"
-              }
-            ]
-          }
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "quoteBank",
-          "isMut": false,
+          "name": "liqee",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "baseBank",
-          "isMut": false,
+          "name": "liqor",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "systemProgram",
+          "name": "liqorAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "marketIndex",
-          "type": "u16"
+          "name": "tokenConditionalSwapIndex",
+          "type": "u8"
         },
         {
-          "name": "name",
-          "type": "string"
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
+        },
+        {
+          "name": "maxBuyTokenToLiqee",
+          "type": "u64"
+        },
+        {
+          "name": "maxSellTokenToLiqor",
+          "type": "u64"
+        },
+        {
+          "name": "minBuyToken",
+          "type": "u64"
+        },
+        {
+          "name": "minTakerPrice",
+          "type": "f32"
         }
       ]
     },
     {
-      "name": "openbookV2EditMarket",
+      "name": "tokenConditionalSwapStart",
       "accounts": [
         {
           "name": "group",
@@ -6170,36 +7165,40 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
+          "name": "liqee",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "market",
+          "name": "liqor",
           "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
+        },
+        {
+          "name": "liqorAuthority",
+          "isMut": false,
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "reduceOnlyOpt",
-          "type": {
-            "option": "bool"
-          }
+          "name": "tokenConditionalSwapIndex",
+          "type": "u8"
         },
         {
-          "name": "forceCloseOpt",
-          "type": {
-            "option": "bool"
-          }
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "openbookV2DeregisterMarket",
+      "name": "altSet",
       "accounts": [
         {
           "name": "group",
@@ -6215,113 +7214,43 @@ export type MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "openbookV2Market",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "indexReservation",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "solDestination",
+          "name": "addressLookupTable",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "index",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "openbookV2CreateOpenOrders",
+      "name": "altCreate",
       "accounts": [
         {
           "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "authority",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "openbookV2Market",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
-          ]
-        },
-        {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openOrders",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "OpenOrders"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "openbook_v2_market"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "openbook_v2_market_external"
-              },
-              {
-                "kind": "arg",
-                "type": "u32",
-                "path": "account_num"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "openbook_v2_program"
-            }
-          }
+          "isSigner": true
         },
         {
-          "name": "payer",
+          "name": "addressLookupTable",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "systemProgram",
@@ -6329,74 +7258,72 @@ export type MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "addressLookupTableProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "accountNum",
-          "type": "u32"
+          "name": "index",
+          "type": "u8"
+        },
+        {
+          "name": "recentSlot",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "openbookV2CloseOpenOrders",
+      "name": "altExtend",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "authority",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "openbookV2Market",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
-          ]
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
+          "name": "addressLookupTable",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "openbookV2MarketExternal",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
+          "name": "index",
+          "type": "u8"
         },
         {
-          "name": "solDestination",
-          "isMut": true,
-          "isSigner": false
+          "name": "newAddresses",
+          "type": {
+            "vec": "publicKey"
+          }
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "openbookV2PlaceOrder",
+      "name": "computeAccountData",
+      "docs": [
+        "Warning, this instruction is for testing purposes only!"
+      ],
       "accounts": [
         {
           "name": "group",
@@ -6405,665 +7332,286 @@ export type MangoV4 = {
         },
         {
           "name": "account",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "computeAccountDataPage",
+      "docs": [
+        "Returns the account's health, equity and positions as paginated return data"
+      ],
+      "accounts": [
         {
-          "name": "authority",
+          "name": "group",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "openOrders",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "openbookV2Market",
+          "name": "account",
           "isMut": false,
-          "isSigner": false
-        },
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "openbookV2Program",
+          "name": "page",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "computeHealthBreakdown",
+      "docs": [
+        "Returns each position's contribution to init and maint health as paginated return data"
+      ],
+      "accounts": [
+        {
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "openbookV2MarketExternal",
+          "name": "account",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "page",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "openbookV2RegisterMarket",
+      "docs": [
+        "",
+        "OpenbookV2",
+        ""
+      ],
+      "accounts": [
+        {
+          "name": "group",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "bids",
-            "asks",
-            "event_heap"
+            "admin"
           ]
         },
         {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "asks",
-          "isMut": true,
+          "name": "openbookV2Program",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "eventHeap",
-          "isMut": true,
+          "name": "openbookV2MarketExternal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "marketBaseVault",
+          "name": "openbookV2Market",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "OpenbookV2Market"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "group"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "openbook_v2_market_external"
+              }
+            ]
+          }
         },
         {
-          "name": "marketQuoteVault",
+          "name": "indexReservation",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "OpenbookV2Index"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "group"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "market_index"
+              }
+            ]
+          }
         },
         {
-          "name": "marketVaultSigner",
+          "name": "quoteBank",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payerBank",
-          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "The bank that pays for the order, if necessary"
-          ],
           "relations": [
             "group"
           ]
         },
         {
-          "name": "payerVault",
-          "isMut": true,
+          "name": "baseBank",
+          "isMut": false,
           "isSigner": false,
-          "docs": [
-            "The bank vault that pays for the order, if necessary"
+          "relations": [
+            "group"
           ]
         },
         {
-          "name": "payerOracle",
-          "isMut": false,
-          "isSigner": false
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "side",
-          "type": "u8"
-        },
-        {
-          "name": "limitPrice",
-          "type": "u64"
+          "name": "marketIndex",
+          "type": "u16"
         },
         {
-          "name": "maxBaseQty",
-          "type": "u64"
-        },
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "openbookV2EditMarket",
+      "accounts": [
         {
-          "name": "maxNativeQuoteQtyIncludingFees",
-          "type": "u64"
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "selfTradeBehavior",
-          "type": "u8"
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "orderType",
-          "type": "u8"
-        },
+          "name": "market",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "clientOrderId",
-          "type": "u64"
+          "name": "reduceOnlyOpt",
+          "type": {
+            "option": "bool"
+          }
         },
         {
-          "name": "limit",
-          "type": "u16"
+          "name": "forceCloseOpt",
+          "type": {
+            "option": "bool"
+          }
         }
       ]
     },
     {
-      "name": "openbookV2PlaceTakerOrder",
+      "name": "openbookV2DeregisterMarket",
       "accounts": [
         {
           "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "authority",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
           "name": "openbookV2Market",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
+          "name": "indexReservation",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "bids",
-            "asks",
-            "event_heap"
+            "group"
           ]
         },
         {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "eventHeap",
+          "name": "solDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marketRequestQueue",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketBaseVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketQuoteVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketVaultSigner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payerBank",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The bank that pays for the order, if necessary"
-          ],
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "payerVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The bank vault that pays for the order, if necessary"
-          ]
-        },
-        {
-          "name": "payerOracle",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "side",
-          "type": "u8"
-        },
-        {
-          "name": "limitPrice",
-          "type": "u64"
-        },
-        {
-          "name": "maxBaseQty",
-          "type": "u64"
-        },
-        {
-          "name": "maxNativeQuoteQtyIncludingFees",
-          "type": "u64"
-        },
-        {
-          "name": "selfTradeBehavior",
-          "type": "u8"
-        },
-        {
-          "name": "clientOrderId",
-          "type": "u64"
-        },
-        {
-          "name": "limit",
-          "type": "u16"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "openbookV2CancelOrder",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
-          ]
-        },
-        {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "bids",
-            "asks"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        }
+      "name": "benchmark",
+      "docs": [
+        "",
+        "benchmark",
+        ""
       ],
-      "args": [
-        {
-          "name": "side",
-          "type": "u8"
-        },
-        {
-          "name": "orderId",
-          "type": "u128"
-        }
-      ]
-    },
-    {
-      "name": "openbookV2SettleFunds",
       "accounts": [
         {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
-          ]
-        },
-        {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketBaseVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketQuoteVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketVaultSigner",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "needed for the automatic settle_funds call"
-          ]
-        },
-        {
-          "name": "quoteBank",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "quoteVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "baseBank",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "baseVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "quoteOracle",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "baseOracle",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "dummy",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "feesToDao",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "openbookV2LiqForceCancelOrders",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
-          ]
-        },
-        {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "bids",
-            "asks",
-            "event_heap"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "eventHeap",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketBaseVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketQuoteVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "marketVaultSigner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "quoteBank",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "quoteVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "baseBank",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "baseVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "limit",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "openbookV2CancelAllOrders",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
-          ]
-        },
-        {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "bids",
-            "asks"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "limit",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "benchmark",
-      "docs": [
-        "",
-        "benchmark",
-        ""
-      ],
-      "accounts": [
-        {
-          "name": "dummy",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "args": []
+    }
+  ],
+  "accounts": [
     {
       "name": "bank",
       "type": {
@@ -7514,62 +8062,236 @@ export type MangoV4 = {
             }
           },
           {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                1920
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "group",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "creator",
-            "type": "publicKey"
+            "name": "keeperRewardPerHour",
+            "docs": [
+              "Reward (in native tokens) paid out of collected fees to the keeper of",
+              "token_update_index_and_rate, pro-rated by the time since the last update",
+              "and capped at one hour."
+            ],
+            "type": "f32"
           },
           {
-            "name": "groupNum",
-            "type": "u32"
+            "name": "fallbackOracleMaxStablePriceDeviation",
+            "docs": [
+              "Max relative deviation of the fallback oracle price from the stable price",
+              "for the fallback to be used. Zero means no check."
+            ],
+            "type": "f32"
           },
           {
-            "name": "admin",
-            "type": "publicKey"
+            "name": "fallbackOracleMinLiquidity",
+            "docs": [
+              "Min in-range liquidity of a CLMM pool fallback oracle for it to be used.",
+              "Zero means no check."
+            ],
+            "type": "u64"
           },
           {
-            "name": "fastListingAdmin",
-            "type": "publicKey"
+            "name": "haltOnVaultInvariantViolation",
+            "docs": [
+              "If set, a vault invariant violation found by token_check_vault_invariant",
+              "puts the bank into reduce-only mode."
+            ],
+            "type": "u8"
           },
           {
-            "name": "mngoTokenIndex",
-            "type": "u16"
+            "name": "collateralOnly",
+            "docs": [
+              "Deposits count as collateral, but the token can never be borrowed.",
+              "",
+              "Unlike reduce_only this is a listing property and not a temporary state."
+            ],
+            "type": "u8"
           },
           {
-            "name": "padding",
+            "name": "padding2",
             "type": {
               "array": [
                 "u8",
-                2
+                6
               ]
             }
           },
           {
-            "name": "insuranceVault",
-            "type": "publicKey"
-          },
-          {
-            "name": "insuranceMint",
-            "type": "publicKey"
+            "name": "interestRateShiftEnd",
+            "docs": [
+              "End timestamp in seconds of a shift of the interest rate curve parameters",
+              "util0, rate0, util1, rate1, max_rate towards the interest_rate_shift_ targets.",
+              "",
+              "The parameters move linearly towards the targets in each token_update_index_and_rate.",
+              "Zero means no shift is configured."
+            ],
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "padding3",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "interestRateShiftUtil0",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftRate0",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftUtil1",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftRate1",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftMaxRate",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "oracleTwap",
+            "docs": [
+              "Time weighted (exponential) moving average of the oracle price, updated",
+              "in token_update_index_and_rate.",
+              "",
+              "Liquidations and token conditional swap triggers use it to make prices",
+              "harder to manipulate, see oracle_twap_window_seconds."
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "oracleTwapLastUpdate",
+            "type": "u64"
+          },
+          {
+            "name": "oracleTwapWindowSeconds",
+            "docs": [
+              "Averaging window of oracle_twap. Zero disables the twap."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "padding4",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "referralFeesUnclaimed",
+            "docs": [
+              "Loan origination fees (native) in collected_fees_native that were paid by accounts",
+              "with a referrer and that the referrers haven't claimed their share of yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "keeperRewardsPaid",
+            "docs": [
+              "Keeper rewards (native) paid out of collected fees, see keeper_reward_per_hour.",
+              "",
+              "Kept apart from fees_withdrawn, which only tracks fees withdrawn from the vault."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referralFeesPaid",
+            "docs": [
+              "Referral fees (native) paid out of collected fees, see account_claim_referral_fees.",
+              "",
+              "Kept apart from fees_withdrawn, the payouts stay in the vault as referrer deposits."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "Layout version of this account, see Bank::LAYOUT_VERSION and token_migrate."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding5",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                1744
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "group",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "groupNum",
+            "type": "u32"
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "fastListingAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "mngoTokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "insuranceVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "insuranceMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
           },
           {
             "name": "testing",
@@ -7646,12 +8368,78 @@ export type MangoV4 = {
             ],
             "type": "u16"
           },
+          {
+            "name": "allowedSwapPrograms",
+            "docs": [
+              "Programs that margin_trade may cpi into, unused entries are Pubkey::default()"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "withdrawOnly",
+            "docs": [
+              "In withdraw-only mode instructions that increase positions are rejected,",
+              "while withdrawals, cancels, settles and liquidations keep working.",
+              "",
+              "See group_toggle_withdraw_only."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "allowCpiDeposit",
+            "docs": [
+              "Whether token deposits may be invoked via CPI, for integrator programs",
+              "that manage MangoAccounts. Other instructions don't check this flag."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding2",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "referralFeeShare",
+            "docs": [
+              "Share of the fees that a referred MangoAccount generates that is paid out to",
+              "its referrer, see MangoAccount::referral_fees_accrued."
+            ],
+            "type": "f32"
+          },
+          {
+            "name": "insuranceTokenIndex",
+            "docs": [
+              "Token index of the bank for insurance_mint.",
+              "",
+              "Bankruptcy pays out insurance fund tokens through this bank, converting",
+              "at oracle prices to the liability that is being covered."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "Layout version of this account, see Group::LAYOUT_VERSION and group_migrate.",
+              "",
+              "Unrelated to `version`, which gates features."
+            ],
+            "type": "u8"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                1812
+                1673
               ]
             }
           }
@@ -8364,7 +9152,7 @@ export type MangoV4 = {
           {
             "name": "minFunding",
             "docs": [
-              "Minimal funding rate per day, must be <= 0."
+              "Minimal funding rate per funding period, must be <= 0."
             ],
             "type": {
               "defined": "I80F48"
@@ -8373,7 +9161,7 @@ export type MangoV4 = {
           {
             "name": "maxFunding",
             "docs": [
-              "Maximal funding rate per day, must be >= 0."
+              "Maximal funding rate per funding period, must be >= 0."
             ],
             "type": {
               "defined": "I80F48"
@@ -8457,7 +9245,8 @@ export type MangoV4 = {
             "name": "feesSettled",
             "docs": [
               "Fees settled in native quote currency",
-              "these are increased when perp_settle_fees is called, and never decreased"
+              "these are increased when perp_settle_fees is called, and only decreased",
+              "when positive dust perp positions are paid out"
             ],
             "type": {
               "defined": "I80F48"
@@ -8594,79 +9383,280 @@ export type MangoV4 = {
             }
           },
           {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                1848
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "serum3Market",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "group",
-            "type": "publicKey"
+            "name": "keeperRewardPerEvent",
+            "docs": [
+              "In native units of settlement token, paid out of fees_accrued to the keeper",
+              "for each event processed by perp_consume_events."
+            ],
+            "type": "f32"
           },
           {
-            "name": "baseTokenIndex",
-            "type": "u16"
+            "name": "keeperRewardFundingPerHour",
+            "docs": [
+              "In native units of settlement token, paid out of fees_accrued to the keeper",
+              "of perp_update_funding. Pro-rated by the time since the last funding update,",
+              "capped at one hour."
+            ],
+            "type": "f32"
           },
           {
-            "name": "quoteTokenIndex",
-            "type": "u16"
+            "name": "oraclePriceBand",
+            "docs": [
+              "Orders posted to the book must be bids >= price / (1+band) and asks <= price * (1+band),",
+              "where price is the lower (bids) or higher (asks) of the oracle and stable price.",
+              "",
+              "Protects against orders that sit absurdly far from the market. Zero disables the limit.",
+              "",
+              "Funding also clamps the book prices to oracle / (1+band) and oracle * (1+band)."
+            ],
+            "type": "f32"
           },
           {
-            "name": "reduceOnly",
-            "type": "u8"
+            "name": "fundingPeriodSeconds",
+            "docs": [
+              "The funding rate is the rate paid per this many seconds. Zero means one day.",
+              "",
+              "Lower values make funding more responsive to the book/oracle price difference."
+            ],
+            "type": "u32"
           },
           {
-            "name": "forceClose",
-            "type": "u8"
+            "name": "dustThreshold",
+            "docs": [
+              "Perp positions without base lots and open orders whose quote position is below this",
+              "many native settle token units get settled into the settle token and deactivated",
+              "when fees are settled. Zero disables."
+            ],
+            "type": "f32"
           },
           {
-            "name": "padding1",
+            "name": "padding5",
             "type": {
               "array": [
                 "u8",
-                2
+                4
               ]
             }
           },
           {
-            "name": "name",
-            "type": {
-              "array": [
-                "u8",
-                16
-              ]
-            }
+            "name": "referralFeesUnclaimed",
+            "docs": [
+              "Taker fees (native) in fees_accrued that were paid by accounts with a referrer and",
+              "that the referrers haven't claimed their share of yet."
+            ],
+            "type": "u64"
           },
           {
-            "name": "serumProgram",
+            "name": "adlCandidateLong",
+            "docs": [
+              "The highest ranked profitable long and short positions, the only possible",
+              "counterparties for auto-deleveraging. See perp_update_adl_candidate."
+            ],
             "type": "publicKey"
           },
           {
-            "name": "serumMarketExternal",
+            "name": "adlCandidateShort",
             "type": "publicKey"
           },
           {
-            "name": "marketIndex",
-            "type": "u16"
+            "name": "adlCandidateLongScore",
+            "docs": [
+              "Auto-deleveraging rank of the candidates when they were last updated: their",
+              "unsettled pnl divided by their equity"
+            ],
+            "type": "f64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "adlCandidateShortScore",
+            "type": "f64"
           },
           {
-            "name": "padding2",
-            "type": {
+            "name": "referralFeesPaid",
+            "docs": [
+              "Referral fees (native) paid out of settled fees, see account_claim_referral_fees.",
+              "",
+              "Kept apart from fees_withdrawn, the payouts stay in the vault as referrer deposits."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                1728
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "perpTriggerOrder",
+      "docs": [
+        "A perp order that anyone can place on behalf of the account once the",
+        "oracle price crosses the trigger price, like a stop-loss or take-profit.",
+        "",
+        "The order is placed as ImmediateOrCancel. Whoever executes it receives",
+        "the lamports of this account and the executor_incentive as incentives."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "account",
+            "type": "publicKey"
+          },
+          {
+            "name": "orderId",
+            "docs": [
+              "Chosen by the creator, part of the address seeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The order can't be executed at or after this time, u64::MAX for never"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "triggerPriceLots",
+            "docs": [
+              "Oracle price at which the order triggers, in quote lots per base lot"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "priceLots",
+            "docs": [
+              "Worst price the order may fill at, in quote lots per base lot"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxBaseLots",
+            "type": "i64"
+          },
+          {
+            "name": "maxQuoteLots",
+            "type": "i64"
+          },
+          {
+            "name": "executorIncentive",
+            "docs": [
+              "Paid from the account's settle token position to the executor, in native tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "perpMarketIndex",
+            "type": "u16"
+          },
+          {
+            "name": "side",
+            "type": "u8"
+          },
+          {
+            "name": "triggerCondition",
+            "type": "u8"
+          },
+          {
+            "name": "reduceOnly",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                56
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "serum3Market",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "baseTokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "quoteTokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "reduceOnly",
+            "type": "u8"
+          },
+          {
+            "name": "forceClose",
+            "type": "u8"
+          },
+          {
+            "name": "padding1",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "serumProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "serumMarketExternal",
+            "type": "publicKey"
+          },
+          {
+            "name": "marketIndex",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding2",
+            "type": {
               "array": [
                 "u8",
                 1
@@ -8687,12 +9677,30 @@ export type MangoV4 = {
             "name": "registrationTime",
             "type": "u64"
           },
+          {
+            "name": "collectedReferrerRebates",
+            "docs": [
+              "Referrer rebates claimed into the quote bank's collected fees during settle, in",
+              "native quote tokens. Only counts settles that send the rebates to the DAO."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "crossingPriceBand",
+            "docs": [
+              "Orders must be bids <= oracle * (1+band) and asks >= oracle * (1-band)",
+              "",
+              "Protects against orders that cross the oracle price by a lot, like fat-fingered",
+              "prices or self-trades against a thin book. Zero disables the limit."
+            ],
+            "type": "f32"
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                128
+                116
               ]
             }
           }
@@ -8727,99 +9735,135 @@ export type MangoV4 = {
   ],
   "types": [
     {
-      "name": "InterestRateParams",
+      "name": "PerpPlaceOrderArgs",
+      "docs": [
+        "The parameters of one order in perp_place_orders, see perp_place_order_v2"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "util0",
-            "type": "f32"
+            "name": "side",
+            "type": {
+              "defined": "Side"
+            }
           },
           {
-            "name": "rate0",
-            "type": "f32"
+            "name": "priceLots",
+            "type": "i64"
           },
           {
-            "name": "util1",
-            "type": "f32"
+            "name": "maxBaseLots",
+            "type": "i64"
           },
           {
-            "name": "rate1",
-            "type": "f32"
+            "name": "maxQuoteLots",
+            "type": "i64"
           },
           {
-            "name": "maxRate",
-            "type": "f32"
+            "name": "clientOrderId",
+            "type": "u64"
           },
           {
-            "name": "adjustmentFactor",
-            "type": "f32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Equity",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tokens",
+            "name": "orderType",
             "type": {
-              "vec": {
-                "defined": "TokenEquity"
-              }
+              "defined": "PlaceOrderType"
             }
           },
           {
-            "name": "perps",
+            "name": "selfTradeBehavior",
             "type": {
-              "vec": {
-                "defined": "PerpEquity"
-              }
+              "defined": "SelfTradeBehavior"
             }
+          },
+          {
+            "name": "reduceOnly",
+            "type": "bool"
+          },
+          {
+            "name": "expiryTimestamp",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "TokenEquity",
+      "name": "Serum3PlaceOrderArgs",
+      "docs": [
+        "The parameters of one order in serum3_place_orders"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenIndex",
-            "type": "u16"
+            "name": "limitPriceLots",
+            "type": "u64"
           },
           {
-            "name": "value",
+            "name": "maxBaseQty",
+            "type": "u64"
+          },
+          {
+            "name": "maxNativeQuoteQtyIncludingFees",
+            "type": "u64"
+          },
+          {
+            "name": "selfTradeBehavior",
             "type": {
-              "defined": "I80F48"
+              "defined": "Serum3SelfTradeBehavior"
+            }
+          },
+          {
+            "name": "orderType",
+            "type": {
+              "defined": "Serum3OrderType"
             }
+          },
+          {
+            "name": "clientOrderId",
+            "type": "u64"
+          },
+          {
+            "name": "limit",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "PerpEquity",
+      "name": "InterestRateParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "perpMarketIndex",
-            "type": "u16"
+            "name": "util0",
+            "type": "f32"
           },
           {
-            "name": "value",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "rate0",
+            "type": "f32"
+          },
+          {
+            "name": "util1",
+            "type": "f32"
+          },
+          {
+            "name": "rate1",
+            "type": "f32"
+          },
+          {
+            "name": "maxRate",
+            "type": "f32"
+          },
+          {
+            "name": "adjustmentFactor",
+            "type": "f32"
           }
         ]
       }
     },
     {
-      "name": "FlashLoanTokenDetail",
+      "name": "TokenPositionData",
       "type": {
         "kind": "struct",
         "fields": [
@@ -8828,604 +9872,541 @@ export type MangoV4 = {
             "type": "u16"
           },
           {
-            "name": "changeAmount",
-            "type": "i128"
-          },
-          {
-            "name": "loan",
-            "type": "i128"
-          },
-          {
-            "name": "loanOriginationFee",
-            "type": "i128"
-          },
-          {
-            "name": "depositIndex",
-            "type": "i128"
-          },
-          {
-            "name": "borrowIndex",
-            "type": "i128"
+            "name": "native",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "price",
-            "type": "i128"
+            "name": "oraclePrice",
+            "type": {
+              "defined": "I80F48"
+            }
           }
         ]
       }
     },
     {
-      "name": "FlashLoanTokenDetailV2",
+      "name": "Serum3PositionData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenIndex",
+            "name": "marketIndex",
             "type": "u16"
           },
           {
-            "name": "changeAmount",
-            "docs": [
-              "The amount by which the user's token position changed at the end",
-              "",
-              "So if the user repaid the approved_amount in full, it'd be 0.",
-              "",
-              "Does NOT include the loan_origination_fee or deposit_fee, so the true",
-              "change is `change_amount - loan_origination_fee - deposit_fee`."
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "loan",
-            "docs": [
-              "The amount that was a loan (<= approved_amount, depends on user's deposits)"
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "loanOriginationFee",
-            "docs": [
-              "The fee paid on the loan, not included in `loan` or `change_amount`"
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "depositIndex",
-            "type": "i128"
-          },
-          {
-            "name": "borrowIndex",
-            "type": "i128"
+            "name": "baseTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "price",
-            "type": "i128"
+            "name": "quoteTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "depositFee",
-            "docs": [
-              "Deposit fee paid for positive change_amount.",
-              "",
-              "Not factored into change_amount."
-            ],
-            "type": "i128"
+            "name": "baseNativeTotal",
+            "type": "u64"
           },
           {
-            "name": "approvedAmount",
-            "docs": [
-              "The amount that was transfered out to the user"
-            ],
+            "name": "quoteNativeTotal",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "FlashLoanTokenDetailV3",
+      "name": "PerpPositionData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenIndex",
+            "name": "perpMarketIndex",
             "type": "u16"
           },
           {
-            "name": "changeAmount",
-            "docs": [
-              "The amount by which the user's token position changed at the end",
-              "",
-              "So if the user repaid the approved_amount in full, it'd be 0.",
-              "",
-              "Does NOT include the loan_origination_fee or deposit_fee, so the true",
-              "change is `change_amount - loan_origination_fee - deposit_fee`."
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "loan",
-            "docs": [
-              "The amount that was a loan (<= approved_amount, depends on user's deposits)"
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "loanOriginationFee",
-            "docs": [
-              "The fee paid on the loan, not included in `loan` or `change_amount`"
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "depositIndex",
-            "type": "i128"
-          },
-          {
-            "name": "borrowIndex",
-            "type": "i128"
-          },
-          {
-            "name": "price",
-            "type": "i128"
+            "name": "basePositionNative",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "swapFee",
-            "docs": [
-              "Swap fee paid on the in token of a swap.",
-              "",
-              "Not factored into change_amount."
-            ],
-            "type": "i128"
+            "name": "quotePositionNative",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "approvedAmount",
-            "docs": [
-              "The amount that was transfered out to the user"
-            ],
-            "type": "u64"
+            "name": "oraclePrice",
+            "type": {
+              "defined": "I80F48"
+            }
           }
         ]
       }
     },
     {
-      "name": "TokenPosition",
+      "name": "Equity",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "indexedPosition",
-            "docs": [
-              "The deposit_index (if positive) or borrow_index (if negative) scaled position"
-            ],
+            "name": "tokens",
             "type": {
-              "defined": "I80F48"
+              "vec": {
+                "defined": "TokenEquity"
+              }
             }
           },
+          {
+            "name": "perps",
+            "type": {
+              "vec": {
+                "defined": "PerpEquity"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenEquity",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "tokenIndex",
-            "docs": [
-              "index into Group.tokens"
-            ],
             "type": "u16"
           },
           {
-            "name": "inUseCount",
-            "docs": [
-              "incremented when a market requires this position to stay alive"
-            ],
+            "name": "value",
+            "type": {
+              "defined": "I80F48"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PerpEquity",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "perpMarketIndex",
             "type": "u16"
           },
           {
-            "name": "padding",
+            "name": "value",
             "type": {
-              "array": [
-                "u8",
-                4
-              ]
+              "defined": "I80F48"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MangoAccountDataPage",
+      "docs": [
+        "Return data of compute_account_data_page"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initHealth",
+            "type": {
+              "defined": "I80F48"
             }
           },
           {
-            "name": "previousIndex",
+            "name": "maintHealth",
             "type": {
               "defined": "I80F48"
             }
           },
           {
-            "name": "cumulativeDepositInterest",
-            "type": "f64"
+            "name": "page",
+            "type": "u8"
           },
           {
-            "name": "cumulativeBorrowInterest",
-            "type": "f64"
+            "name": "pageCount",
+            "type": "u8"
           },
           {
-            "name": "reserved",
+            "name": "entries",
             "type": {
-              "array": [
-                "u8",
-                128
-              ]
+              "vec": {
+                "defined": "MangoAccountDataEntry"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "Serum3Orders",
+      "name": "MangoAccountHealthBreakdown",
+      "docs": [
+        "Return data of compute_health_breakdown"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "openOrders",
-            "type": "publicKey"
-          },
-          {
-            "name": "baseBorrowsWithoutFee",
-            "docs": [
-              "Tracks the amount of borrows that have flowed into the serum open orders account.",
-              "These borrows did not have the loan origination fee applied, and that may happen",
-              "later (in serum3_settle_funds) if we can guarantee that the funds were used.",
-              "In particular a place-on-book, cancel, settle should not cost fees."
-            ],
-            "type": "u64"
+            "name": "initHealth",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "quoteBorrowsWithoutFee",
-            "type": "u64"
+            "name": "maintHealth",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "marketIndex",
-            "type": "u16"
+            "name": "page",
+            "type": "u8"
           },
           {
-            "name": "baseTokenIndex",
-            "docs": [
-              "Store the base/quote token index, so health computations don't need",
-              "to get passed the static SerumMarket to find which tokens a market",
-              "uses and look up the correct oracles."
-            ],
-            "type": "u16"
+            "name": "pageCount",
+            "type": "u8"
           },
           {
-            "name": "quoteTokenIndex",
+            "name": "contributions",
+            "type": {
+              "vec": {
+                "defined": "HealthContribution"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenHealthContribution",
+      "docs": [
+        "Excludes the perp hupnl that's attributed to perp markets settling in the token"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenIndex",
             "type": "u16"
           },
           {
-            "name": "padding",
+            "name": "init",
             "type": {
-              "array": [
-                "u8",
-                2
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "highestPlacedBidInv",
-            "docs": [
-              "Track something like the highest open bid / lowest open ask, in native/native units.",
-              "",
-              "Tracking it exactly isn't possible since we don't see fills. So instead track",
-              "the min/max of the _placed_ bids and asks.",
-              "",
-              "The value is reset in serum3_place_order when a new order is placed without an",
-              "existing one on the book.",
-              "",
-              "0 is a special \"unset\" state."
-            ],
-            "type": "f64"
-          },
+            "name": "maint",
+            "type": {
+              "defined": "I80F48"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Serum3HealthContribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "lowestPlacedAsk",
-            "type": "f64"
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
-            "name": "potentialBaseTokens",
-            "docs": [
-              "An overestimate of the amount of tokens that might flow out of the open orders account.",
-              "",
-              "The bank still considers these amounts user deposits (see Bank::potential_serum_tokens)",
-              "and that value needs to be updated in conjunction with these numbers.",
-              "",
-              "This estimation is based on the amount of tokens in the open orders account",
-              "(see update_bank_potential_tokens() in serum3_place_order and settle)"
-            ],
-            "type": "u64"
+            "name": "init",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "potentialQuoteTokens",
-            "type": "u64"
-          },
+            "name": "maint",
+            "type": {
+              "defined": "I80F48"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PerpHealthContribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "lowestPlacedBidInv",
-            "docs": [
-              "Track lowest bid/highest ask, same way as for highest bid/lowest ask.",
-              "",
-              "0 is a special \"unset\" state."
-            ],
-            "type": "f64"
+            "name": "perpMarketIndex",
+            "type": "u16"
           },
           {
-            "name": "highestPlacedAsk",
-            "type": "f64"
+            "name": "init",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "reserved",
+            "name": "maint",
             "type": {
-              "array": [
-                "u8",
-                16
-              ]
+              "defined": "I80F48"
             }
           }
         ]
       }
     },
     {
-      "name": "PerpPosition",
+      "name": "FlashLoanTokenDetail",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "marketIndex",
+            "name": "tokenIndex",
             "type": "u16"
           },
           {
-            "name": "padding",
-            "type": {
-              "array": [
-                "u8",
-                2
-              ]
-            }
+            "name": "changeAmount",
+            "type": "i128"
           },
           {
-            "name": "settlePnlLimitWindow",
-            "docs": [
-              "Index of the current settle pnl limit window"
-            ],
-            "type": "u32"
+            "name": "loan",
+            "type": "i128"
           },
           {
-            "name": "settlePnlLimitSettledInCurrentWindowNative",
-            "docs": [
-              "Amount of realized trade pnl and unrealized pnl that was already settled this window.",
-              "",
-              "Will be negative when negative pnl was settled.",
-              "",
-              "Note that this will be adjusted for bookkeeping reasons when the realized_trade settle",
-              "limitchanges and is not useable for actually tracking how much pnl was settled",
-              "on balance."
-            ],
-            "type": "i64"
+            "name": "loanOriginationFee",
+            "type": "i128"
           },
           {
-            "name": "basePositionLots",
-            "docs": [
-              "Active position size, measured in base lots"
-            ],
-            "type": "i64"
+            "name": "depositIndex",
+            "type": "i128"
           },
           {
-            "name": "quotePositionNative",
+            "name": "borrowIndex",
+            "type": "i128"
+          },
+          {
+            "name": "price",
+            "type": "i128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FlashLoanTokenDetailV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "changeAmount",
             "docs": [
-              "Active position in oracle quote native. At the same time this is 1:1 a settle_token native amount.",
+              "The amount by which the user's token position changed at the end",
               "",
-              "Example: Say there's a perp market on the BTC/USD price using SOL for settlement. The user buys",
-              "one long contract for $20k, then base = 1, quote = -20k. The price goes to $21k. Now their",
-              "unsettled pnl is (1 * 21k - 20k) __SOL__ = 1000 SOL. This is because the perp contract arbitrarily",
-              "decides that each unit of price difference creates 1 SOL worth of settlement.",
-              "(yes, causing 1 SOL of settlement for each $1 price change implies a lot of extra leverage; likely",
-              "there should be an extra configurable scaling factor before we use this for cases like that)"
+              "So if the user repaid the approved_amount in full, it'd be 0.",
+              "",
+              "Does NOT include the loan_origination_fee or deposit_fee, so the true",
+              "change is `change_amount - loan_origination_fee - deposit_fee`."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "i128"
           },
           {
-            "name": "quoteRunningNative",
+            "name": "loan",
             "docs": [
-              "Tracks what the position is to calculate average entry & break even price"
+              "The amount that was a loan (<= approved_amount, depends on user's deposits)"
             ],
-            "type": "i64"
+            "type": "i128"
           },
           {
-            "name": "longSettledFunding",
+            "name": "loanOriginationFee",
             "docs": [
-              "Already settled long funding"
+              "The fee paid on the loan, not included in `loan` or `change_amount`"
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "i128"
           },
           {
-            "name": "shortSettledFunding",
-            "docs": [
-              "Already settled short funding"
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "depositIndex",
+            "type": "i128"
           },
           {
-            "name": "bidsBaseLots",
-            "docs": [
-              "Base lots in open bids"
-            ],
-            "type": "i64"
+            "name": "borrowIndex",
+            "type": "i128"
           },
           {
-            "name": "asksBaseLots",
-            "docs": [
-              "Base lots in open asks"
-            ],
-            "type": "i64"
+            "name": "price",
+            "type": "i128"
           },
           {
-            "name": "takerBaseLots",
+            "name": "depositFee",
             "docs": [
-              "Amount of base lots on the EventQueue waiting to be processed"
+              "Deposit fee paid for positive change_amount.",
+              "",
+              "Not factored into change_amount."
             ],
-            "type": "i64"
+            "type": "i128"
           },
           {
-            "name": "takerQuoteLots",
+            "name": "approvedAmount",
             "docs": [
-              "Amount of quote lots on the EventQueue waiting to be processed"
+              "The amount that was transfered out to the user"
             ],
-            "type": "i64"
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FlashLoanTokenDetailV3",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenIndex",
+            "type": "u16"
           },
           {
-            "name": "cumulativeLongFunding",
+            "name": "changeAmount",
             "docs": [
-              "Cumulative long funding in quote native units.",
-              "If the user paid $1 in funding for a long position, this would be 1e6.",
-              "Beware of the sign!",
+              "The amount by which the user's token position changed at the end",
               "",
-              "(Display only)"
+              "So if the user repaid the approved_amount in full, it'd be 0.",
+              "",
+              "Does NOT include the loan_origination_fee or deposit_fee, so the true",
+              "change is `change_amount - loan_origination_fee - deposit_fee`."
             ],
-            "type": "f64"
+            "type": "i128"
           },
           {
-            "name": "cumulativeShortFunding",
+            "name": "loan",
             "docs": [
-              "Cumulative short funding in quote native units",
-              "If the user paid $1 in funding for a short position, this would be -1e6.",
-              "",
-              "(Display only)"
+              "The amount that was a loan (<= approved_amount, depends on user's deposits)"
             ],
-            "type": "f64"
+            "type": "i128"
           },
           {
-            "name": "makerVolume",
+            "name": "loanOriginationFee",
             "docs": [
-              "Cumulative maker volume in quote native units",
-              "",
-              "(Display only)"
+              "The fee paid on the loan, not included in `loan` or `change_amount`"
             ],
-            "type": "u64"
+            "type": "i128"
           },
           {
-            "name": "takerVolume",
-            "docs": [
-              "Cumulative taker volume in quote native units",
-              "",
-              "(Display only)"
-            ],
-            "type": "u64"
+            "name": "depositIndex",
+            "type": "i128"
           },
           {
-            "name": "perpSpotTransfers",
-            "docs": [
-              "Cumulative number of quote native units transfered from the perp position",
-              "to the settle token spot position.",
-              "",
-              "For example, if the user settled $1 of positive pnl into their USDC spot",
-              "position, this would be 1e6.",
-              "",
-              "(Display only)"
-            ],
-            "type": "i64"
+            "name": "borrowIndex",
+            "type": "i128"
           },
           {
-            "name": "avgEntryPricePerBaseLot",
+            "name": "price",
+            "type": "i128"
+          },
+          {
+            "name": "swapFee",
             "docs": [
-              "The native average entry price for the base lots of the current position.",
-              "Reset to 0 when the base position reaches or crosses 0."
+              "Swap fee paid on the in token of a swap.",
+              "",
+              "Not factored into change_amount."
             ],
-            "type": "f64"
+            "type": "i128"
           },
           {
-            "name": "deprecatedRealizedTradePnlNative",
+            "name": "approvedAmount",
             "docs": [
-              "Deprecated field: Amount of pnl that was realized by bringing the base position closer to 0."
+              "The amount that was transfered out to the user"
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MarginTradeTokenDetail",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenIndex",
+            "type": "u16"
           },
           {
-            "name": "oneshotSettlePnlAllowance",
+            "name": "changeAmount",
             "docs": [
-              "Amount of pnl that can be settled once.",
+              "The change of the vault balance caused by the swap",
               "",
-              "- The value is signed: a negative number means negative pnl can be settled.",
-              "- A settlement in the right direction will decrease this amount.",
-              "",
-              "Typically added for fees, funding and liquidation."
+              "Does NOT include the loan_origination_fee or swap_fee, so the true",
+              "change of the token position is `change_amount - loan_origination_fee - swap_fee`."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "i128"
           },
           {
-            "name": "recurringSettlePnlAllowance",
+            "name": "loan",
             "docs": [
-              "Amount of pnl that can be settled in each settle window.",
-              "",
-              "- Unsigned, the settlement can happen in both directions. Value is >= 0.",
-              "- Previously stored a similar value that was signed, so in migration cases",
-              "this value can be negative and should be .abs()ed.",
-              "- If this value exceeds the current stable-upnl, it should be decreased,",
-              "see apply_recurring_settle_pnl_allowance_constraint()",
-              "",
-              "When the base position is reduced, the settle limit contribution from the reduced",
-              "base position is materialized into this value. When the base position increases,",
-              "some of the allowance is taken away.",
-              "",
-              "This also gets increased when a liquidator takes over pnl."
+              "The amount that was a loan, depends on the user's deposits"
             ],
-            "type": "i64"
+            "type": "i128"
           },
           {
-            "name": "realizedPnlForPositionNative",
+            "name": "loanOriginationFee",
+            "type": "i128"
+          },
+          {
+            "name": "swapFee",
             "docs": [
-              "Trade pnl, fees, funding that were added over the current position's lifetime.",
-              "",
-              "Reset when the position changes sign or goes to zero.",
-              "Not decreased by settling.",
-              "",
-              "This is tracked for display purposes: this value plus the difference between entry",
-              "price and current price of the base position is the overall pnl."
+              "Swap fee paid on tokens that left the vault"
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "i128"
           },
           {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                88
-              ]
-            }
+            "name": "depositIndex",
+            "type": "i128"
+          },
+          {
+            "name": "borrowIndex",
+            "type": "i128"
+          },
+          {
+            "name": "price",
+            "type": "i128"
           }
         ]
       }
     },
     {
-      "name": "PerpOpenOrder",
+      "name": "TokenPosition",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sideAndTree",
-            "type": "u8"
-          },
-          {
-            "name": "padding1",
+            "name": "indexedPosition",
+            "docs": [
+              "The deposit_index (if positive) or borrow_index (if negative) scaled position"
+            ],
             "type": {
-              "array": [
-                "u8",
-                1
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "market",
+            "name": "tokenIndex",
+            "docs": [
+              "index into Group.tokens"
+            ],
             "type": "u16"
           },
           {
-            "name": "padding2",
+            "name": "inUseCount",
+            "docs": [
+              "incremented when a market requires this position to stay alive"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "padding",
             "type": {
               "array": [
                 "u8",
@@ -9434,23 +10415,25 @@ export type MangoV4 = {
             }
           },
           {
-            "name": "clientId",
-            "type": "u64"
+            "name": "previousIndex",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "id",
-            "type": "u128"
+            "name": "cumulativeDepositInterest",
+            "type": "f64"
           },
           {
-            "name": "quantity",
-            "type": "i64"
+            "name": "cumulativeBorrowInterest",
+            "type": "f64"
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                56
+                128
               ]
             }
           }
@@ -9458,129 +10441,109 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "MangoAccountFixed",
+      "name": "Serum3Orders",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "group",
-            "type": "publicKey"
-          },
-          {
-            "name": "owner",
+            "name": "openOrders",
             "type": "publicKey"
           },
           {
-            "name": "name",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "delegate",
-            "type": "publicKey"
+            "name": "baseBorrowsWithoutFee",
+            "docs": [
+              "Tracks the amount of borrows that have flowed into the serum open orders account.",
+              "These borrows did not have the loan origination fee applied, and that may happen",
+              "later (in serum3_settle_funds) if we can guarantee that the funds were used.",
+              "In particular a place-on-book, cancel, settle should not cost fees."
+            ],
+            "type": "u64"
           },
           {
-            "name": "accountNum",
-            "type": "u32"
+            "name": "quoteBorrowsWithoutFee",
+            "type": "u64"
           },
           {
-            "name": "beingLiquidated",
-            "type": "u8"
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
-            "name": "inHealthRegion",
-            "type": "u8"
+            "name": "baseTokenIndex",
+            "docs": [
+              "Store the base/quote token index, so health computations don't need",
+              "to get passed the static SerumMarket to find which tokens a market",
+              "uses and look up the correct oracles."
+            ],
+            "type": "u16"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "quoteTokenIndex",
+            "type": "u16"
           },
           {
             "name": "padding",
             "type": {
               "array": [
                 "u8",
-                1
+                2
               ]
             }
           },
           {
-            "name": "netDeposits",
-            "type": "i64"
-          },
-          {
-            "name": "perpSpotTransfers",
-            "type": "i64"
-          },
-          {
-            "name": "healthRegionBeginInitHealth",
-            "type": "i64"
-          },
-          {
-            "name": "frozenUntil",
-            "type": "u64"
-          },
-          {
-            "name": "buybackFeesAccruedCurrent",
-            "type": "u64"
-          },
-          {
-            "name": "buybackFeesAccruedPrevious",
-            "type": "u64"
+            "name": "highestPlacedBidInv",
+            "docs": [
+              "Track something like the highest open bid / lowest open ask, in native/native units.",
+              "",
+              "Tracking it exactly isn't possible since we don't see fills. So instead track",
+              "the min/max of the _placed_ bids and asks.",
+              "",
+              "The value is reset in serum3_place_order when a new order is placed without an",
+              "existing one on the book.",
+              "",
+              "0 is a special \"unset\" state."
+            ],
+            "type": "f64"
           },
           {
-            "name": "buybackFeesExpiryTimestamp",
-            "type": "u64"
+            "name": "lowestPlacedAsk",
+            "type": "f64"
           },
           {
-            "name": "nextTokenConditionalSwapId",
+            "name": "potentialBaseTokens",
+            "docs": [
+              "An overestimate of the amount of tokens that might flow out of the open orders account.",
+              "",
+              "The bank still considers these amounts user deposits (see Bank::potential_serum_tokens)",
+              "and that value needs to be updated in conjunction with these numbers.",
+              "",
+              "This estimation is based on the amount of tokens in the open orders account",
+              "(see update_bank_potential_tokens() in serum3_place_order and settle)"
+            ],
             "type": "u64"
           },
           {
-            "name": "temporaryDelegate",
-            "type": "publicKey"
-          },
-          {
-            "name": "temporaryDelegateExpiry",
+            "name": "potentialQuoteTokens",
             "type": "u64"
           },
           {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                160
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "OracleConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "confFilter",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "lowestPlacedBidInv",
+            "docs": [
+              "Track lowest bid/highest ask, same way as for highest bid/lowest ask.",
+              "",
+              "0 is a special \"unset\" state."
+            ],
+            "type": "f64"
           },
           {
-            "name": "maxStalenessSlots",
-            "type": "i64"
+            "name": "highestPlacedAsk",
+            "type": "f64"
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                72
+                16
               ]
             }
           }
@@ -9588,263 +10551,285 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "OracleConfigParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "confFilter",
-            "type": "f32"
-          },
-          {
-            "name": "maxStalenessSlots",
-            "type": {
-              "option": "u32"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "InnerNode",
-      "docs": [
-        "InnerNodes and LeafNodes compose the binary tree of orders.",
-        "",
-        "Each InnerNode has exactly two children, which are either InnerNodes themselves,",
-        "or LeafNodes. The children share the top `prefix_len` bits of `key`. The left",
-        "child has a 0 in the next bit, and the right a 1."
-      ],
+      "name": "PerpPosition",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tag",
-            "type": "u8"
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
             "name": "padding",
             "type": {
               "array": [
                 "u8",
-                3
+                2
               ]
             }
           },
           {
-            "name": "prefixLen",
+            "name": "settlePnlLimitWindow",
             "docs": [
-              "number of highest `key` bits that all children share",
-              "e.g. if it's 2, the two highest bits of `key` will be the same on all children"
+              "Index of the current settle pnl limit window"
             ],
             "type": "u32"
           },
           {
-            "name": "key",
+            "name": "settlePnlLimitSettledInCurrentWindowNative",
             "docs": [
-              "only the top `prefix_len` bits of `key` are relevant"
+              "Amount of realized trade pnl and unrealized pnl that was already settled this window.",
+              "",
+              "Will be negative when negative pnl was settled.",
+              "",
+              "Note that this will be adjusted for bookkeeping reasons when the realized_trade settle",
+              "limitchanges and is not useable for actually tracking how much pnl was settled",
+              "on balance."
             ],
-            "type": "u128"
+            "type": "i64"
           },
           {
-            "name": "children",
+            "name": "basePositionLots",
             "docs": [
-              "indexes into `BookSide::nodes`"
+              "Active position size, measured in base lots"
             ],
-            "type": {
-              "array": [
-                "u32",
-                2
-              ]
-            }
+            "type": "i64"
           },
           {
-            "name": "childEarliestExpiry",
+            "name": "quotePositionNative",
             "docs": [
-              "The earliest expiry timestamp for the left and right subtrees.",
+              "Active position in oracle quote native. At the same time this is 1:1 a settle_token native amount.",
               "",
-              "Needed to be able to find and remove expired orders without having to",
-              "iterate through the whole bookside."
+              "Example: Say there's a perp market on the BTC/USD price using SOL for settlement. The user buys",
+              "one long contract for $20k, then base = 1, quote = -20k. The price goes to $21k. Now their",
+              "unsettled pnl is (1 * 21k - 20k) __SOL__ = 1000 SOL. This is because the perp contract arbitrarily",
+              "decides that each unit of price difference creates 1 SOL worth of settlement.",
+              "(yes, causing 1 SOL of settlement for each $1 price change implies a lot of extra leverage; likely",
+              "there should be an extra configurable scaling factor before we use this for cases like that)"
             ],
             "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
-          },
-          {
-            "name": "reserved",
-            "type": {
-              "array": [
-                "u8",
-                72
-              ]
+              "defined": "I80F48"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "LeafNode",
-      "docs": [
-        "LeafNodes represent an order in the binary tree"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "tag",
-            "docs": [
-              "NodeTag"
-            ],
-            "type": "u8"
           },
           {
-            "name": "ownerSlot",
+            "name": "quoteRunningNative",
             "docs": [
-              "Index into the owning MangoAccount's PerpOpenOrders"
+              "Tracks what the position is to calculate average entry & break even price"
             ],
-            "type": "u8"
+            "type": "i64"
           },
           {
-            "name": "orderType",
+            "name": "longSettledFunding",
             "docs": [
-              "PostOrderType, this was added for TradingView move order"
+              "Already settled long funding"
             ],
-            "type": "u8"
-          },
-          {
-            "name": "padding",
             "type": {
-              "array": [
-                "u8",
-                1
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "timeInForce",
+            "name": "shortSettledFunding",
             "docs": [
-              "Time in seconds after `timestamp` at which the order expires.",
-              "A value of 0 means no expiry."
+              "Already settled short funding"
             ],
-            "type": "u16"
-          },
-          {
-            "name": "padding2",
             "type": {
-              "array": [
-                "u8",
-                2
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "key",
+            "name": "bidsBaseLots",
             "docs": [
-              "The binary tree key, see new_node_key()"
+              "Base lots in open bids"
             ],
-            "type": "u128"
+            "type": "i64"
           },
           {
-            "name": "owner",
+            "name": "asksBaseLots",
             "docs": [
-              "Address of the owning MangoAccount"
+              "Base lots in open asks"
             ],
-            "type": "publicKey"
+            "type": "i64"
           },
           {
-            "name": "quantity",
+            "name": "takerBaseLots",
             "docs": [
-              "Number of base lots to buy or sell, always >=1"
+              "Amount of base lots on the EventQueue waiting to be processed"
             ],
             "type": "i64"
           },
           {
-            "name": "timestamp",
+            "name": "takerQuoteLots",
             "docs": [
-              "The time the order was placed"
+              "Amount of quote lots on the EventQueue waiting to be processed"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "pegLimit",
+            "name": "cumulativeLongFunding",
             "docs": [
-              "If the effective price of an oracle pegged order exceeds this limit,",
-              "it will be considered invalid and may be removed.",
+              "Cumulative long funding in quote native units.",
+              "If the user paid $1 in funding for a long position, this would be 1e6.",
+              "Beware of the sign!",
               "",
-              "Only applicable in the oracle_pegged OrderTree"
+              "(Display only)"
             ],
-            "type": "i64"
+            "type": "f64"
           },
           {
-            "name": "clientOrderId",
+            "name": "cumulativeShortFunding",
             "docs": [
-              "User defined id for this order, used in FillEvents"
+              "Cumulative short funding in quote native units",
+              "If the user paid $1 in funding for a short position, this would be -1e6.",
+              "",
+              "(Display only)"
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "makerVolume",
+            "docs": [
+              "Cumulative maker volume in quote native units",
+              "",
+              "(Display only)"
             ],
             "type": "u64"
           },
           {
-            "name": "reserved",
+            "name": "takerVolume",
+            "docs": [
+              "Cumulative taker volume in quote native units",
+              "",
+              "(Display only)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "perpSpotTransfers",
+            "docs": [
+              "Cumulative number of quote native units transfered from the perp position",
+              "to the settle token spot position.",
+              "",
+              "For example, if the user settled $1 of positive pnl into their USDC spot",
+              "position, this would be 1e6.",
+              "",
+              "(Display only)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "avgEntryPricePerBaseLot",
+            "docs": [
+              "The native average entry price for the base lots of the current position.",
+              "Reset to 0 when the base position reaches or crosses 0."
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "deprecatedRealizedTradePnlNative",
+            "docs": [
+              "Deprecated field: Amount of pnl that was realized by bringing the base position closer to 0."
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": "I80F48"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "AnyNode",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "tag",
-            "type": "u8"
+            "name": "oneshotSettlePnlAllowance",
+            "docs": [
+              "Amount of pnl that can be settled once.",
+              "",
+              "- The value is signed: a negative number means negative pnl can be settled.",
+              "- A settlement in the right direction will decrease this amount.",
+              "",
+              "Typically added for fees, funding and liquidation."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "data",
+            "name": "recurringSettlePnlAllowance",
+            "docs": [
+              "Amount of pnl that can be settled in each settle window.",
+              "",
+              "- Unsigned, the settlement can happen in both directions. Value is >= 0.",
+              "- Previously stored a similar value that was signed, so in migration cases",
+              "this value can be negative and should be .abs()ed.",
+              "- If this value exceeds the current stable-upnl, it should be decreased,",
+              "see apply_recurring_settle_pnl_allowance_constraint()",
+              "",
+              "When the base position is reduced, the settle limit contribution from the reduced",
+              "base position is materialized into this value. When the base position increases,",
+              "some of the allowance is taken away.",
+              "",
+              "This also gets increased when a liquidator takes over pnl."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "realizedPnlForPositionNative",
+            "docs": [
+              "Trade pnl, fees, funding that were added over the current position's lifetime.",
+              "",
+              "Reset when the position changes sign or goes to zero.",
+              "Not decreased by settling.",
+              "",
+              "This is tracked for display purposes: this value plus the difference between entry",
+              "price and current price of the base position is the overall pnl."
+            ],
             "type": {
-              "array": [
-                "u8",
-                119
-              ]
+              "defined": "I80F48"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "OrderTreeRoot",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "maybeNode",
-            "type": "u32"
+            "name": "entryQuoteNative",
+            "docs": [
+              "Quote paid (negative) or received (positive) for entering the current base position.",
+              "",
+              "Reduced proportionally when the base position is reduced and reset when the position",
+              "changes sign or goes to zero. Unlike avg_entry_price_per_base_lot, this is exact."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "leafCount",
-            "type": "u32"
+            "name": "settledPnlForPositionNative",
+            "docs": [
+              "Pnl that was settled over the current position's lifetime.",
+              "",
+              "Reset when the position changes sign or goes to zero."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                56
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "OrderTreeNodes",
+      "name": "ReferralFeesAccrued",
       "docs": [
-        "A binary tree on AnyNode::key()",
-        "",
-        "The key encodes the price in the top 64 bits."
+        "Referral fees that a referred account generated in one bank or perp market and that",
+        "its referrer hasn't claimed yet, see MangoAccountFixed::referral_fees_accrued"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "orderTreeType",
+            "name": "source",
+            "docs": [
+              "ReferralFeeSource, zero for unused slots"
+            ],
             "type": "u8"
           },
           {
@@ -9852,109 +10837,60 @@ export type MangoV4 = {
             "type": {
               "array": [
                 "u8",
-                3
+                1
               ]
             }
           },
           {
-            "name": "bumpIndex",
-            "type": "u32"
-          },
-          {
-            "name": "freeListLen",
-            "type": "u32"
-          },
-          {
-            "name": "freeListHead",
-            "type": "u32"
+            "name": "index",
+            "docs": [
+              "TokenIndex of the bank or PerpMarketIndex of the perp market"
+            ],
+            "type": "u16"
           },
           {
-            "name": "reserved",
+            "name": "padding2",
             "type": {
               "array": [
                 "u8",
-                512
-              ]
-            }
-          },
-          {
-            "name": "nodes",
-            "type": {
-              "array": [
-                {
-                  "defined": "AnyNode"
-                },
-                1024
+                4
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "EventQueueHeader",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "head",
-            "type": "u32"
-          },
-          {
-            "name": "count",
-            "type": "u32"
           },
           {
-            "name": "seqNum",
+            "name": "amount",
+            "docs": [
+              "Native fees, in the bank's token or the perp market's settle token"
+            ],
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "AnyEvent",
+      "name": "PerpOpenOrder",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "eventType",
+            "name": "sideAndTree",
             "type": "u8"
           },
           {
-            "name": "padding",
+            "name": "padding1",
             "type": {
               "array": [
                 "u8",
-                207
+                1
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "FillEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "eventType",
-            "type": "u8"
-          },
-          {
-            "name": "takerSide",
-            "type": "u8"
-          },
-          {
-            "name": "makerOut",
-            "type": "u8"
           },
           {
-            "name": "makerSlot",
-            "type": "u8"
+            "name": "market",
+            "type": "u16"
           },
           {
-            "name": "padding",
+            "name": "padding2",
             "type": {
               "array": [
                 "u8",
@@ -9963,242 +10899,176 @@ export type MangoV4 = {
             }
           },
           {
-            "name": "timestamp",
+            "name": "clientId",
             "type": "u64"
           },
           {
-            "name": "seqNum",
-            "type": "u64"
+            "name": "id",
+            "type": "u128"
           },
           {
-            "name": "maker",
-            "type": "publicKey"
+            "name": "quantity",
+            "type": "i64"
           },
           {
-            "name": "padding2",
+            "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                32
+                56
               ]
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "MangoAccountFixed",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "makerTimestamp",
-            "type": "u64"
+            "name": "group",
+            "type": "publicKey"
           },
           {
-            "name": "taker",
+            "name": "owner",
             "type": "publicKey"
           },
           {
-            "name": "padding3",
+            "name": "name",
             "type": {
               "array": [
                 "u8",
-                16
+                32
               ]
             }
           },
           {
-            "name": "takerClientOrderId",
-            "type": "u64"
-          },
-          {
-            "name": "makerOrderId",
-            "type": "u128"
-          },
-          {
-            "name": "price",
-            "type": "i64"
+            "name": "delegate",
+            "type": "publicKey"
           },
           {
-            "name": "quantity",
-            "type": "i64"
+            "name": "accountNum",
+            "type": "u32"
           },
           {
-            "name": "makerClientOrderId",
-            "type": "u64"
+            "name": "beingLiquidated",
+            "type": "u8"
           },
           {
-            "name": "makerFee",
-            "type": "f32"
+            "name": "inHealthRegion",
+            "type": "u8"
           },
           {
-            "name": "takerFee",
-            "type": "f32"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "reserved",
+            "name": "padding",
             "type": {
               "array": [
                 "u8",
-                8
+                1
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "OutEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "eventType",
-            "type": "u8"
           },
           {
-            "name": "side",
-            "type": "u8"
+            "name": "netDeposits",
+            "type": "i64"
           },
           {
-            "name": "ownerSlot",
-            "type": "u8"
+            "name": "perpSpotTransfers",
+            "type": "i64"
           },
           {
-            "name": "padding0",
-            "type": {
-              "array": [
-                "u8",
-                5
-              ]
-            }
+            "name": "healthRegionBeginInitHealth",
+            "type": "i64"
           },
           {
-            "name": "timestamp",
+            "name": "frozenUntil",
             "type": "u64"
           },
           {
-            "name": "seqNum",
+            "name": "buybackFeesAccruedCurrent",
             "type": "u64"
           },
           {
-            "name": "owner",
-            "type": "publicKey"
+            "name": "buybackFeesAccruedPrevious",
+            "type": "u64"
           },
           {
-            "name": "quantity",
-            "type": "i64"
+            "name": "buybackFeesExpiryTimestamp",
+            "type": "u64"
           },
           {
-            "name": "orderId",
-            "type": "u128"
+            "name": "nextTokenConditionalSwapId",
+            "type": "u64"
           },
           {
-            "name": "padding1",
-            "type": {
-              "array": [
-                "u8",
-                128
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "StablePriceModel",
-      "docs": [
-        "Maintains a \"stable_price\" based on the oracle price.",
-        "",
-        "The stable price follows the oracle price, but its relative rate of",
-        "change is limited (to `stable_growth_limit`) and futher reduced if",
-        "the oracle price is far from the `delay_price`.",
-        "",
-        "Conceptually the `delay_price` is itself a time delayed",
-        "(`24 * delay_interval_seconds`, assume 24h) and relative rate of change limited",
-        "function of the oracle price. It is implemented as averaging the oracle",
-        "price over every `delay_interval_seconds` (assume 1h) and then applying the",
-        "`delay_growth_limit` between intervals."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "stablePrice",
-            "docs": [
-              "Current stable price to use in health"
-            ],
-            "type": "f64"
+            "name": "temporaryDelegate",
+            "type": "publicKey"
           },
           {
-            "name": "lastUpdateTimestamp",
+            "name": "temporaryDelegateExpiry",
             "type": "u64"
           },
           {
-            "name": "delayPrices",
+            "name": "isolated",
             "docs": [
-              "Stored delay_price for each delay_interval.",
-              "If we want the delay_price to be 24h delayed, we would store one for each hour.",
-              "This is used in a cyclical way: We use the maximally-delayed value at delay_interval_index",
-              "and once enough time passes to move to the next delay interval, that gets overwritten and",
-              "we use the next one."
+              "Isolated accounts may only have positions in the isolated perp market and tokens,",
+              "which also means their health only depends on those."
             ],
+            "type": "u8"
+          },
+          {
+            "name": "padding2",
             "type": {
               "array": [
-                "f64",
-                24
+                "u8",
+                1
               ]
             }
           },
           {
-            "name": "delayAccumulatorPrice",
-            "docs": [
-              "The delay price is based on an average over each delay_interval. The contributions",
-              "to the average are summed up here."
-            ],
-            "type": "f64"
-          },
-          {
-            "name": "delayAccumulatorTime",
-            "docs": [
-              "Accumulating the total time for the above average."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "delayIntervalSeconds",
-            "docs": [
-              "Length of a delay_interval"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "delayGrowthLimit",
+            "name": "isolatedPerpMarketIndex",
             "docs": [
-              "Maximal relative difference between two delay_price in consecutive intervals."
+              "The perp market an isolated account may use, PerpMarketIndex::MAX for none"
             ],
-            "type": "f32"
+            "type": "u16"
           },
           {
-            "name": "stableGrowthLimit",
+            "name": "isolatedTokenIndexes",
             "docs": [
-              "Maximal per-second relative difference of the stable price.",
-              "It gets further reduced if stable and delay price disagree."
+              "The tokens an isolated account may use, TokenIndex::MAX for unused entries"
             ],
-            "type": "f32"
+            "type": {
+              "array": [
+                "u16",
+                6
+              ]
+            }
           },
           {
-            "name": "lastDelayIntervalIndex",
+            "name": "referrer",
             "docs": [
-              "The delay_interval_index that update() was last called on."
+              "The MangoAccount that referred this account, Pubkey::default() for none.",
+              "",
+              "Can only be set when the account is created."
             ],
-            "type": "u8"
+            "type": "publicKey"
           },
           {
-            "name": "resetOnNonzeroPrice",
+            "name": "referralFeesAccrued",
             "docs": [
-              "If set to 1, the stable price will reset on the next non-zero price it sees."
+              "Fees this account generated since the referrer last claimed, per bank or perp",
+              "market that collected them. The referrer receives Group::referral_fee_share of them."
             ],
-            "type": "u8"
-          },
-          {
-            "name": "padding",
             "type": {
               "array": [
-                "u8",
+                {
+                  "defined": "ReferralFeesAccrued"
+                },
                 6
               ]
             }
@@ -10208,7 +11078,7 @@ export type MangoV4 = {
             "type": {
               "array": [
                 "u8",
-                48
+                16
               ]
             }
           }
@@ -10216,187 +11086,226 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "TokenConditionalSwap",
+      "name": "OracleConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "id",
-            "type": "u64"
-          },
-          {
-            "name": "maxBuy",
-            "docs": [
-              "maximum amount of native tokens to buy or sell"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "maxSell",
-            "type": "u64"
+            "name": "confFilter",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "bought",
-            "docs": [
-              "how many native tokens were already bought/sold"
-            ],
-            "type": "u64"
+            "name": "maxStalenessSlots",
+            "type": "i64"
           },
           {
-            "name": "sold",
-            "type": "u64"
-          },
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                72
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleConfigParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "expiryTimestamp",
-            "docs": [
-              "timestamp until which the conditional swap is valid"
-            ],
-            "type": "u64"
+            "name": "confFilter",
+            "type": "f32"
           },
           {
-            "name": "priceLowerLimit",
-            "docs": [
-              "The lower or starting price:",
-              "- For FixedPremium or PremiumAuctions, it's the lower end of the price range:",
-              "the tcs can only be triggered if the oracle price exceeds this value.",
-              "- For LinearAuctions it's the starting price that's offered at start_timestamp.",
-              "",
-              "The price is always in \"sell_token per buy_token\" units, which can be computed",
-              "by dividing the buy token price by the sell token price.",
-              "",
-              "For FixedPremium or PremiumAuctions:",
-              "",
-              "The price must exceed this threshold to allow execution.",
-              "",
-              "This threshold is compared to the \"sell_token per buy_token\" oracle price.",
-              "If that price is >= lower_limit and <= upper_limit the tcs may be executable.",
-              "",
-              "Example: Stop loss to get out of a SOL long: The user bought SOL at 20 USDC/SOL",
-              "and wants to stop loss at 18 USDC/SOL. They'd set buy_token=USDC, sell_token=SOL",
-              "so the reference price is in SOL/USDC units. Set price_lower_limit=toNative(1/18)",
-              "and price_upper_limit=toNative(1/10). Also set allow_borrows=false.",
-              "",
-              "Example: Want to buy SOL with USDC if the price falls below 22 USDC/SOL.",
-              "buy_token=SOL, sell_token=USDC, reference price is in USDC/SOL units. Set",
-              "price_upper_limit=toNative(22), price_lower_limit=0."
-            ],
-            "type": "f64"
+            "name": "maxStalenessSlots",
+            "type": {
+              "option": "u32"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InnerNode",
+      "docs": [
+        "InnerNodes and LeafNodes compose the binary tree of orders.",
+        "",
+        "Each InnerNode has exactly two children, which are either InnerNodes themselves,",
+        "or LeafNodes. The children share the top `prefix_len` bits of `key`. The left",
+        "child has a 0 in the next bit, and the right a 1."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tag",
+            "type": "u8"
           },
           {
-            "name": "priceUpperLimit",
-            "docs": [
-              "Parallel to price_lower_limit, but an upper limit / auction end price."
-            ],
-            "type": "f64"
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
           },
           {
-            "name": "pricePremiumRate",
+            "name": "prefixLen",
             "docs": [
-              "The premium to pay over oracle price to incentivize execution."
+              "number of highest `key` bits that all children share",
+              "e.g. if it's 2, the two highest bits of `key` will be the same on all children"
             ],
-            "type": "f64"
+            "type": "u32"
           },
           {
-            "name": "takerFeeRate",
+            "name": "key",
             "docs": [
-              "The taker receives only premium_price * (1 - taker_fee_rate)"
+              "only the top `prefix_len` bits of `key` are relevant"
             ],
-            "type": "f32"
+            "type": "u128"
           },
           {
-            "name": "makerFeeRate",
+            "name": "children",
             "docs": [
-              "The maker has to pay premium_price * (1 + maker_fee_rate)"
+              "indexes into `BookSide::nodes`"
             ],
-            "type": "f32"
+            "type": {
+              "array": [
+                "u32",
+                2
+              ]
+            }
           },
           {
-            "name": "buyTokenIndex",
+            "name": "childEarliestExpiry",
             "docs": [
-              "indexes of tokens for the swap"
+              "The earliest expiry timestamp for the left and right subtrees.",
+              "",
+              "Needed to be able to find and remove expired orders without having to",
+              "iterate through the whole bookside."
             ],
-            "type": "u16"
+            "type": {
+              "array": [
+                "u64",
+                2
+              ]
+            }
           },
           {
-            "name": "sellTokenIndex",
-            "type": "u16"
-          },
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                72
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeafNode",
+      "docs": [
+        "LeafNodes represent an order in the binary tree"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "isConfigured",
+            "name": "tag",
             "docs": [
-              "If this struct is in use. (tcs are stored in a static-length array)"
+              "NodeTag"
             ],
             "type": "u8"
           },
           {
-            "name": "allowCreatingDeposits",
+            "name": "ownerSlot",
             "docs": [
-              "may token purchases create deposits? (often users just want to get out of a borrow)"
+              "Index into the owning MangoAccount's PerpOpenOrders"
             ],
             "type": "u8"
           },
           {
-            "name": "allowCreatingBorrows",
+            "name": "orderType",
             "docs": [
-              "may token selling create borrows? (often users just want to get out of a long)"
+              "PostOrderType, this was added for TradingView move order"
             ],
             "type": "u8"
           },
           {
-            "name": "displayPriceStyle",
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "timeInForce",
             "docs": [
-              "The stored prices are always \"sell token per buy token\", but if the user",
-              "used \"buy token per sell token\" when creating the tcs order, we should continue",
-              "to show them prices in that way.",
-              "",
-              "Stores a TokenConditionalSwapDisplayPriceStyle enum value"
+              "Time in seconds after `timestamp` at which the order expires.",
+              "A value of 0 means no expiry."
             ],
-            "type": "u8"
+            "type": "u16"
           },
           {
-            "name": "intention",
+            "name": "padding2",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "key",
             "docs": [
-              "The intention the user had when placing this order, display-only",
-              "",
-              "Stores a TokenConditionalSwapIntention enum value"
+              "The binary tree key, see new_node_key()"
             ],
-            "type": "u8"
+            "type": "u128"
           },
           {
-            "name": "tcsType",
+            "name": "owner",
             "docs": [
-              "Stores a TokenConditionalSwapType enum value"
+              "Address of the owning MangoAccount"
             ],
-            "type": "u8"
+            "type": "publicKey"
           },
           {
-            "name": "padding",
-            "type": {
-              "array": [
-                "u8",
-                6
-              ]
-            }
+            "name": "quantity",
+            "docs": [
+              "Number of base lots to buy or sell, always >=1"
+            ],
+            "type": "i64"
           },
           {
-            "name": "startTimestamp",
+            "name": "timestamp",
             "docs": [
-              "In seconds since epoch. 0 means not-started.",
-              "",
-              "FixedPremium: Time of first trigger call. No other effect.",
-              "PremiumAuction: Time of start or first trigger call. Can continue to trigger once started.",
-              "LinearAuction: Set during creation, auction starts with price_lower_limit at this timestamp."
+              "The time the order was placed"
             ],
             "type": "u64"
           },
           {
-            "name": "durationSeconds",
+            "name": "pegLimit",
             "docs": [
-              "Duration of the auction mechanism",
+              "If the effective price of an oracle pegged order exceeds this limit,",
+              "it will be considered invalid and may be removed.",
               "",
-              "FixedPremium: ignored",
-              "PremiumAuction: time after start that the premium needs to scale to price_premium_rate",
-              "LinearAuction: time after start to go from price_lower_limit to price_upper_limit"
+              "Only applicable in the oracle_pegged OrderTree"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "clientOrderId",
+            "docs": [
+              "User defined id for this order, used in FillEvents"
             ],
             "type": "u64"
           },
@@ -10405,7 +11314,7 @@ export type MangoV4 = {
             "type": {
               "array": [
                 "u8",
-                88
+                32
               ]
             }
           }
@@ -10413,545 +11322,709 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "TokenIndex",
-      "docs": [
-        "Nothing in Rust shall use these types. They only exist so that the Anchor IDL",
-        "knows about them and typescript can deserialize it."
-      ],
+      "name": "AnyNode",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "val",
-            "type": "u16"
+            "name": "tag",
+            "type": "u8"
+          },
+          {
+            "name": "data",
+            "type": {
+              "array": [
+                "u8",
+                119
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "Serum3MarketIndex",
+      "name": "OrderTreeRoot",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "val",
-            "type": "u16"
+            "name": "maybeNode",
+            "type": "u32"
+          },
+          {
+            "name": "leafCount",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "PerpMarketIndex",
+      "name": "OrderTreeNodes",
+      "docs": [
+        "A binary tree on AnyNode::key()",
+        "",
+        "The key encodes the price in the top 64 bits."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "val",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "I80F48",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "orderTreeType",
+            "type": "u8"
+          },
           {
-            "name": "val",
-            "type": "i128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FlashLoanType",
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
           {
-            "name": "Unknown"
+            "name": "bumpIndex",
+            "type": "u32"
           },
           {
-            "name": "Swap"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Serum3SelfTradeBehavior",
-      "docs": [
-        "Copy paste a bunch of enums so that we could AnchorSerialize & AnchorDeserialize them"
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "freeListLen",
+            "type": "u32"
+          },
           {
-            "name": "DecrementTake"
+            "name": "freeListHead",
+            "type": "u32"
           },
           {
-            "name": "CancelProvide"
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                512
+              ]
+            }
           },
           {
-            "name": "AbortTransaction"
+            "name": "nodes",
+            "type": {
+              "array": [
+                {
+                  "defined": "AnyNode"
+                },
+                1024
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "Serum3OrderType",
+      "name": "EventQueueHeader",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Limit"
+            "name": "head",
+            "type": "u32"
           },
           {
-            "name": "ImmediateOrCancel"
+            "name": "count",
+            "type": "u32"
           },
           {
-            "name": "PostOnly"
+            "name": "seqNum",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "Serum3Side",
+      "name": "AnyEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Bid"
+            "name": "eventType",
+            "type": "u8"
           },
           {
-            "name": "Ask"
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                207
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "HealthType",
-      "docs": [
-        "There are three types of health:",
-        "- initial health (\"init\"): users can only open new positions if it's >= 0",
-        "- maintenance health (\"maint\"): users get liquidated if it's < 0",
-        "- liquidation end health: once liquidation started (see being_liquidated), it",
-        "only stops once this is >= 0",
-        "",
-        "The ordering is",
-        "init health <= liquidation end health <= maint health",
-        "",
-        "The different health types are realized by using different weights and prices:",
-        "- init health: init weights with scaling, stable-price adjusted prices",
-        "- liq end health: init weights without scaling, oracle prices",
-        "- maint health: maint weights, oracle prices",
-        ""
-      ],
+      "name": "FillEvent",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Init"
+            "name": "eventType",
+            "type": "u8"
           },
           {
-            "name": "Maint"
+            "name": "takerSide",
+            "type": "u8"
           },
           {
-            "name": "LiquidationEnd"
-          }
-        ]
-      }
-    },
-    {
-      "name": "LoanOriginationFeeInstruction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Unknown"
+            "name": "makerOut",
+            "type": "u8"
           },
           {
-            "name": "LiqTokenBankruptcy"
+            "name": "makerSlot",
+            "type": "u8"
           },
           {
-            "name": "LiqTokenWithToken"
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
           },
           {
-            "name": "Serum3LiqForceCancelOrders"
+            "name": "timestamp",
+            "type": "u64"
           },
           {
-            "name": "Serum3PlaceOrder"
+            "name": "seqNum",
+            "type": "u64"
           },
           {
-            "name": "Serum3SettleFunds"
+            "name": "maker",
+            "type": "publicKey"
           },
           {
-            "name": "TokenWithdraw"
+            "name": "padding2",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "TokenConditionalSwapTrigger"
-          }
-        ]
-      }
-    },
-    {
-      "name": "IxGate",
-      "docs": [
-        "Enum for lookup into ix gate",
-        "note:",
-        "total ix files 56,",
-        "ix files included 48,",
-        "ix files not included 8,",
-        "- Benchmark,",
-        "- ComputeAccountData,",
-        "- GroupCreate",
-        "- GroupEdit",
-        "- IxGateSet,",
-        "- PerpZeroOut,",
-        "- PerpEditMarket,",
-        "- TokenEdit,"
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "AccountClose"
+            "name": "makerTimestamp",
+            "type": "u64"
           },
           {
-            "name": "AccountCreate"
+            "name": "taker",
+            "type": "publicKey"
           },
           {
-            "name": "AccountEdit"
+            "name": "padding3",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
           },
           {
-            "name": "AccountExpand"
+            "name": "takerClientOrderId",
+            "type": "u64"
           },
           {
-            "name": "AccountToggleFreeze"
+            "name": "makerOrderId",
+            "type": "u128"
           },
           {
-            "name": "AltExtend"
+            "name": "price",
+            "type": "i64"
           },
           {
-            "name": "AltSet"
+            "name": "quantity",
+            "type": "i64"
           },
           {
-            "name": "FlashLoan"
+            "name": "makerClientOrderId",
+            "type": "u64"
           },
           {
-            "name": "GroupClose"
+            "name": "makerFee",
+            "type": "f32"
           },
           {
-            "name": "GroupCreate"
+            "name": "takerFee",
+            "type": "f32"
           },
           {
-            "name": "HealthRegion"
-          },
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "PerpCancelAllOrders"
+            "name": "eventType",
+            "type": "u8"
           },
           {
-            "name": "PerpCancelAllOrdersBySide"
+            "name": "side",
+            "type": "u8"
           },
           {
-            "name": "PerpCancelOrder"
+            "name": "ownerSlot",
+            "type": "u8"
           },
           {
-            "name": "PerpCancelOrderByClientOrderId"
+            "name": "padding0",
+            "type": {
+              "array": [
+                "u8",
+                5
+              ]
+            }
           },
           {
-            "name": "PerpCloseMarket"
+            "name": "timestamp",
+            "type": "u64"
           },
           {
-            "name": "PerpConsumeEvents"
+            "name": "seqNum",
+            "type": "u64"
           },
           {
-            "name": "PerpCreateMarket"
+            "name": "owner",
+            "type": "publicKey"
           },
           {
-            "name": "PerpDeactivatePosition"
+            "name": "quantity",
+            "type": "i64"
           },
           {
-            "name": "PerpLiqBaseOrPositivePnl"
+            "name": "orderId",
+            "type": "u128"
           },
           {
-            "name": "PerpLiqForceCancelOrders"
-          },
+            "name": "padding1",
+            "type": {
+              "array": [
+                "u8",
+                128
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "StablePriceModel",
+      "docs": [
+        "Maintains a \"stable_price\" based on the oracle price.",
+        "",
+        "The stable price follows the oracle price, but its relative rate of",
+        "change is limited (to `stable_growth_limit`) and futher reduced if",
+        "the oracle price is far from the `delay_price`.",
+        "",
+        "Conceptually the `delay_price` is itself a time delayed",
+        "(`24 * delay_interval_seconds`, assume 24h) and relative rate of change limited",
+        "function of the oracle price. It is implemented as averaging the oracle",
+        "price over every `delay_interval_seconds` (assume 1h) and then applying the",
+        "`delay_growth_limit` between intervals."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "PerpLiqNegativePnlOrBankruptcy"
+            "name": "stablePrice",
+            "docs": [
+              "Current stable price to use in health"
+            ],
+            "type": "f64"
           },
           {
-            "name": "PerpPlaceOrder"
+            "name": "lastUpdateTimestamp",
+            "type": "u64"
           },
           {
-            "name": "PerpSettleFees"
+            "name": "delayPrices",
+            "docs": [
+              "Stored delay_price for each delay_interval.",
+              "If we want the delay_price to be 24h delayed, we would store one for each hour.",
+              "This is used in a cyclical way: We use the maximally-delayed value at delay_interval_index",
+              "and once enough time passes to move to the next delay interval, that gets overwritten and",
+              "we use the next one."
+            ],
+            "type": {
+              "array": [
+                "f64",
+                24
+              ]
+            }
           },
           {
-            "name": "PerpSettlePnl"
+            "name": "delayAccumulatorPrice",
+            "docs": [
+              "The delay price is based on an average over each delay_interval. The contributions",
+              "to the average are summed up here."
+            ],
+            "type": "f64"
           },
           {
-            "name": "PerpUpdateFunding"
+            "name": "delayAccumulatorTime",
+            "docs": [
+              "Accumulating the total time for the above average."
+            ],
+            "type": "u32"
           },
           {
-            "name": "Serum3CancelAllOrders"
+            "name": "delayIntervalSeconds",
+            "docs": [
+              "Length of a delay_interval"
+            ],
+            "type": "u32"
           },
           {
-            "name": "Serum3CancelOrder"
+            "name": "delayGrowthLimit",
+            "docs": [
+              "Maximal relative difference between two delay_price in consecutive intervals."
+            ],
+            "type": "f32"
           },
           {
-            "name": "Serum3CloseOpenOrders"
+            "name": "stableGrowthLimit",
+            "docs": [
+              "Maximal per-second relative difference of the stable price.",
+              "It gets further reduced if stable and delay price disagree."
+            ],
+            "type": "f32"
           },
           {
-            "name": "Serum3CreateOpenOrders"
+            "name": "lastDelayIntervalIndex",
+            "docs": [
+              "The delay_interval_index that update() was last called on."
+            ],
+            "type": "u8"
           },
           {
-            "name": "Serum3DeregisterMarket"
+            "name": "resetOnNonzeroPrice",
+            "docs": [
+              "If set to 1, the stable price will reset on the next non-zero price it sees."
+            ],
+            "type": "u8"
           },
           {
-            "name": "Serum3EditMarket"
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
           },
           {
-            "name": "Serum3LiqForceCancelOrders"
-          },
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                48
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenConditionalSwap",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Serum3PlaceOrder"
+            "name": "id",
+            "type": "u64"
           },
           {
-            "name": "Serum3RegisterMarket"
+            "name": "maxBuy",
+            "docs": [
+              "maximum amount of native tokens to buy or sell"
+            ],
+            "type": "u64"
           },
           {
-            "name": "Serum3SettleFunds"
+            "name": "maxSell",
+            "type": "u64"
           },
           {
-            "name": "StubOracleClose"
+            "name": "bought",
+            "docs": [
+              "how many native tokens were already bought/sold"
+            ],
+            "type": "u64"
           },
           {
-            "name": "StubOracleCreate"
+            "name": "sold",
+            "type": "u64"
           },
           {
-            "name": "StubOracleSet"
+            "name": "expiryTimestamp",
+            "docs": [
+              "timestamp until which the conditional swap is valid"
+            ],
+            "type": "u64"
           },
           {
-            "name": "TokenAddBank"
+            "name": "priceLowerLimit",
+            "docs": [
+              "The lower or starting price:",
+              "- For FixedPremium or PremiumAuctions, it's the lower end of the price range:",
+              "the tcs can only be triggered if the oracle price exceeds this value.",
+              "- For LinearAuctions it's the starting price that's offered at start_timestamp.",
+              "",
+              "The price is always in \"sell_token per buy_token\" units, which can be computed",
+              "by dividing the buy token price by the sell token price.",
+              "",
+              "For FixedPremium or PremiumAuctions:",
+              "",
+              "The price must exceed this threshold to allow execution.",
+              "",
+              "This threshold is compared to the \"sell_token per buy_token\" oracle price.",
+              "If that price is >= lower_limit and <= upper_limit the tcs may be executable.",
+              "",
+              "Example: Stop loss to get out of a SOL long: The user bought SOL at 20 USDC/SOL",
+              "and wants to stop loss at 18 USDC/SOL. They'd set buy_token=USDC, sell_token=SOL",
+              "so the reference price is in SOL/USDC units. Set price_lower_limit=toNative(1/18)",
+              "and price_upper_limit=toNative(1/10). Also set allow_borrows=false.",
+              "",
+              "Example: Want to buy SOL with USDC if the price falls below 22 USDC/SOL.",
+              "buy_token=SOL, sell_token=USDC, reference price is in USDC/SOL units. Set",
+              "price_upper_limit=toNative(22), price_lower_limit=0."
+            ],
+            "type": "f64"
           },
           {
-            "name": "TokenDeposit"
+            "name": "priceUpperLimit",
+            "docs": [
+              "Parallel to price_lower_limit, but an upper limit / auction end price."
+            ],
+            "type": "f64"
           },
           {
-            "name": "TokenDeregister"
+            "name": "pricePremiumRate",
+            "docs": [
+              "The premium to pay over oracle price to incentivize execution."
+            ],
+            "type": "f64"
           },
           {
-            "name": "TokenLiqBankruptcy"
+            "name": "takerFeeRate",
+            "docs": [
+              "The taker receives only premium_price * (1 - taker_fee_rate)"
+            ],
+            "type": "f32"
           },
           {
-            "name": "TokenLiqWithToken"
+            "name": "makerFeeRate",
+            "docs": [
+              "The maker has to pay premium_price * (1 + maker_fee_rate)"
+            ],
+            "type": "f32"
           },
           {
-            "name": "TokenRegister"
+            "name": "buyTokenIndex",
+            "docs": [
+              "indexes of tokens for the swap"
+            ],
+            "type": "u16"
           },
           {
-            "name": "TokenRegisterTrustless"
+            "name": "sellTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "TokenUpdateIndexAndRate"
+            "name": "isConfigured",
+            "docs": [
+              "If this struct is in use. (tcs are stored in a static-length array)"
+            ],
+            "type": "u8"
           },
           {
-            "name": "TokenWithdraw"
+            "name": "allowCreatingDeposits",
+            "docs": [
+              "may token purchases create deposits? (often users just want to get out of a borrow)"
+            ],
+            "type": "u8"
           },
           {
-            "name": "AccountBuybackFeesWithMngo"
+            "name": "allowCreatingBorrows",
+            "docs": [
+              "may token selling create borrows? (often users just want to get out of a long)"
+            ],
+            "type": "u8"
           },
           {
-            "name": "TokenForceCloseBorrowsWithToken"
-          },
-          {
-            "name": "PerpForceClosePosition"
-          },
-          {
-            "name": "GroupWithdrawInsuranceFund"
-          },
-          {
-            "name": "TokenConditionalSwapCreate"
-          },
-          {
-            "name": "TokenConditionalSwapTrigger"
-          },
-          {
-            "name": "TokenConditionalSwapCancel"
-          },
-          {
-            "name": "OpenbookV2CancelOrder"
-          },
-          {
-            "name": "OpenbookV2CloseOpenOrders"
-          },
-          {
-            "name": "OpenbookV2CreateOpenOrders"
-          },
-          {
-            "name": "OpenbookV2DeregisterMarket"
-          },
-          {
-            "name": "OpenbookV2EditMarket"
-          },
-          {
-            "name": "OpenbookV2LiqForceCancelOrders"
-          },
-          {
-            "name": "OpenbookV2PlaceOrder"
-          },
-          {
-            "name": "OpenbookV2PlaceTakeOrder"
-          },
-          {
-            "name": "OpenbookV2RegisterMarket"
-          },
-          {
-            "name": "OpenbookV2SettleFunds"
-          },
-          {
-            "name": "AdminTokenWithdrawFees"
+            "name": "displayPriceStyle",
+            "docs": [
+              "The stored prices are always \"sell token per buy token\", but if the user",
+              "used \"buy token per sell token\" when creating the tcs order, we should continue",
+              "to show them prices in that way.",
+              "",
+              "Stores a TokenConditionalSwapDisplayPriceStyle enum value"
+            ],
+            "type": "u8"
           },
           {
-            "name": "AdminPerpWithdrawFees"
+            "name": "intention",
+            "docs": [
+              "The intention the user had when placing this order, display-only",
+              "",
+              "Stores a TokenConditionalSwapIntention enum value"
+            ],
+            "type": "u8"
           },
           {
-            "name": "AccountSizeMigration"
+            "name": "tcsType",
+            "docs": [
+              "Stores a TokenConditionalSwapType enum value"
+            ],
+            "type": "u8"
           },
           {
-            "name": "TokenConditionalSwapStart"
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
           },
           {
-            "name": "TokenConditionalSwapCreatePremiumAuction"
+            "name": "startTimestamp",
+            "docs": [
+              "In seconds since epoch. 0 means not-started.",
+              "",
+              "FixedPremium: Time of first trigger call. No other effect.",
+              "PremiumAuction: Time of start or first trigger call. Can continue to trigger once started.",
+              "LinearAuction: Set during creation, auction starts with price_lower_limit at this timestamp."
+            ],
+            "type": "u64"
           },
           {
-            "name": "TokenConditionalSwapCreateLinearAuction"
+            "name": "durationSeconds",
+            "docs": [
+              "Duration of the auction mechanism",
+              "",
+              "FixedPremium: ignored",
+              "PremiumAuction: time after start that the premium needs to scale to price_premium_rate",
+              "LinearAuction: time after start to go from price_lower_limit to price_upper_limit"
+            ],
+            "type": "u64"
           },
           {
-            "name": "Serum3PlaceOrderV2"
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                88
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "CheckLiquidatable",
+      "name": "TokenIndex",
+      "docs": [
+        "Nothing in Rust shall use these types. They only exist so that the Anchor IDL",
+        "knows about them and typescript can deserialize it."
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "NotLiquidatable"
-          },
-          {
-            "name": "Liquidatable"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "BecameNotLiquidatable"
+            "name": "val",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "OracleType",
+      "name": "Serum3MarketIndex",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Pyth"
-          },
-          {
-            "name": "Stub"
-          },
-          {
-            "name": "SwitchboardV1"
-          },
-          {
-            "name": "SwitchboardV2"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "OrcaCLMM"
+            "name": "val",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "OrderState",
+      "name": "PerpMarketIndex",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Valid"
-          },
-          {
-            "name": "Invalid"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Skipped"
+            "name": "val",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "BookSideOrderTree",
+      "name": "I80F48",
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Fixed"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "OraclePegged"
+            "name": "val",
+            "type": "i128"
           }
         ]
       }
     },
     {
-      "name": "NodeTag",
+      "name": "FlashLoanType",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Uninitialized"
-          },
-          {
-            "name": "InnerNode"
-          },
-          {
-            "name": "LeafNode"
-          },
-          {
-            "name": "FreeNode"
+            "name": "Unknown"
           },
           {
-            "name": "LastFreeNode"
+            "name": "Swap"
           }
         ]
       }
     },
     {
-      "name": "PlaceOrderType",
+      "name": "Serum3SelfTradeBehavior",
+      "docs": [
+        "Copy paste a bunch of enums so that we could AnchorSerialize & AnchorDeserialize them"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Limit"
-          },
-          {
-            "name": "ImmediateOrCancel"
-          },
-          {
-            "name": "PostOnly"
+            "name": "DecrementTake"
           },
           {
-            "name": "Market"
+            "name": "CancelProvide"
           },
           {
-            "name": "PostOnlySlide"
+            "name": "AbortTransaction"
           }
         ]
       }
     },
     {
-      "name": "PostOrderType",
+      "name": "Serum3OrderType",
       "type": {
         "kind": "enum",
         "variants": [
@@ -10959,38 +12032,16 @@ export type MangoV4 = {
             "name": "Limit"
           },
           {
-            "name": "PostOnly"
-          },
-          {
-            "name": "PostOnlySlide"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SelfTradeBehavior",
-      "docs": [
-        "Self trade behavior controls how taker orders interact with resting limit orders of the same account.",
-        "This setting has no influence on placing a resting or oracle pegged limit order that does not match",
-        "immediately, instead it's the responsibility of the user to correctly configure his taker orders."
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "DecrementTake"
-          },
-          {
-            "name": "CancelProvide"
+            "name": "ImmediateOrCancel"
           },
           {
-            "name": "AbortTransaction"
+            "name": "PostOnly"
           }
         ]
       }
     },
     {
-      "name": "Side",
+      "name": "Serum3Side",
       "type": {
         "kind": "enum",
         "variants": [
@@ -11004,80 +12055,70 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "SideAndOrderTree",
+      "name": "FastListingTier",
       "docs": [
-        "SideAndOrderTree is a storage optimization, so we don't need two bytes for the data"
+        "Parameter presets that token_register_trustless can list a token with.",
+        "",
+        "The parameters of each tier are hard-coded, so listings that don't go",
+        "through governance can't set weights beyond what the Premium tier allows."
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "BidFixed"
-          },
-          {
-            "name": "AskFixed"
+            "name": "CollateralDisabled"
           },
           {
-            "name": "BidOraclePegged"
+            "name": "Midwit"
           },
           {
-            "name": "AskOraclePegged"
+            "name": "Premium"
           }
         ]
       }
     },
     {
-      "name": "OrderParams",
+      "name": "MangoAccountDataEntry",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Market"
+            "name": "TokenEquity",
+            "fields": [
+              {
+                "defined": "TokenEquity"
+              }
+            ]
           },
           {
-            "name": "ImmediateOrCancel",
+            "name": "PerpEquity",
             "fields": [
               {
-                "name": "price_lots",
-                "type": "i64"
+                "defined": "PerpEquity"
               }
             ]
           },
           {
-            "name": "Fixed",
+            "name": "TokenPosition",
             "fields": [
               {
-                "name": "price_lots",
-                "type": "i64"
-              },
-              {
-                "name": "order_type",
-                "type": {
-                  "defined": "PostOrderType"
-                }
+                "defined": "TokenPositionData"
               }
             ]
           },
           {
-            "name": "OraclePegged",
+            "name": "Serum3Position",
             "fields": [
               {
-                "name": "price_offset_lots",
-                "type": "i64"
-              },
-              {
-                "name": "order_type",
-                "type": {
-                  "defined": "PostOrderType"
-                }
-              },
-              {
-                "name": "peg_limit",
-                "type": "i64"
-              },
+                "defined": "Serum3PositionData"
+              }
+            ]
+          },
+          {
+            "name": "PerpPosition",
+            "fields": [
               {
-                "name": "max_oracle_staleness_slots",
-                "type": "i32"
+                "defined": "PerpPositionData"
               }
             ]
           }
@@ -11085,632 +12126,2065 @@ export type MangoV4 = {
       }
     },
     {
-      "name": "OrderTreeType",
+      "name": "HealthContribution",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Bids"
+            "name": "Token",
+            "fields": [
+              {
+                "defined": "TokenHealthContribution"
+              }
+            ]
           },
           {
-            "name": "Asks"
+            "name": "Serum3",
+            "fields": [
+              {
+                "defined": "Serum3HealthContribution"
+              }
+            ]
+          },
+          {
+            "name": "Perp",
+            "fields": [
+              {
+                "defined": "PerpHealthContribution"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "EventType",
+      "name": "HealthType",
+      "docs": [
+        "There are three types of health:",
+        "- initial health (\"init\"): users can only open new positions if it's >= 0",
+        "- maintenance health (\"maint\"): users get liquidated if it's < 0",
+        "- liquidation end health: once liquidation started (see being_liquidated), it",
+        "only stops once this is >= 0",
+        "",
+        "The ordering is",
+        "init health <= liquidation end health <= maint health",
+        "",
+        "The different health types are realized by using different weights and prices:",
+        "- init health: init weights with scaling, stable-price adjusted prices",
+        "- liq end health: init weights without scaling, oracle prices",
+        "- maint health: maint weights, oracle prices",
+        ""
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Fill"
+            "name": "Init"
           },
           {
-            "name": "Out"
+            "name": "Maint"
           },
           {
-            "name": "Liquidate"
+            "name": "LiquidationEnd"
           }
         ]
       }
     },
     {
-      "name": "TokenConditionalSwapDisplayPriceStyle",
+      "name": "LoanOriginationFeeInstruction",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "SellTokenPerBuyToken"
+            "name": "Unknown"
           },
           {
-            "name": "BuyTokenPerSellToken"
-          }
-        ]
-      }
-    },
-    {
-      "name": "TokenConditionalSwapIntention",
-      "type": {
-        "kind": "enum",
-        "variants": [
+            "name": "LiqTokenBankruptcy"
+          },
           {
-            "name": "Unknown"
+            "name": "LiqTokenWithToken"
           },
           {
-            "name": "StopLoss"
+            "name": "Serum3LiqForceCancelOrders"
           },
           {
-            "name": "TakeProfit"
+            "name": "Serum3PlaceOrder"
+          },
+          {
+            "name": "Serum3SettleFunds"
+          },
+          {
+            "name": "TokenWithdraw"
+          },
+          {
+            "name": "TokenConditionalSwapTrigger"
           }
         ]
       }
     },
     {
-      "name": "TokenConditionalSwapType",
+      "name": "IxGate",
+      "docs": [
+        "Enum for lookup into ix gate",
+        "note:",
+        "total ix files 56,",
+        "ix files included 48,",
+        "ix files not included 8,",
+        "- Benchmark,",
+        "- ComputeAccountData,",
+        "- GroupCreate",
+        "- GroupEdit",
+        "- IxGateSet,",
+        "- PerpZeroOut,",
+        "- PerpEditMarket,",
+        "- TokenEdit,"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "FixedPremium"
+            "name": "AccountClose"
           },
           {
-            "name": "PremiumAuction"
+            "name": "AccountCreate"
           },
           {
-            "name": "LinearAuction"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "MangoAccountData",
-      "fields": [
-        {
-          "name": "initHealth",
-          "type": {
-            "defined": "I80F48"
+            "name": "AccountEdit"
           },
-          "index": false
-        },
-        {
-          "name": "maintHealth",
-          "type": {
-            "defined": "I80F48"
+          {
+            "name": "AccountExpand"
           },
-          "index": false
-        },
-        {
-          "name": "equity",
-          "type": {
-            "defined": "Equity"
+          {
+            "name": "AccountToggleFreeze"
           },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "PerpBalanceLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "basePosition",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "quotePosition",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "longSettledFunding",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "shortSettledFunding",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "longFunding",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "shortFunding",
-          "type": "i128",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "TokenBalanceLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "indexedPosition",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "depositIndex",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "borrowIndex",
-          "type": "i128",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "FlashLoanLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenLoanDetails",
-          "type": {
-            "vec": {
-              "defined": "FlashLoanTokenDetail"
-            }
+          {
+            "name": "AltExtend"
           },
-          "index": false
-        },
-        {
-          "name": "flashLoanType",
-          "type": {
-            "defined": "FlashLoanType"
+          {
+            "name": "AltSet"
           },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "FlashLoanLogV2",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenLoanDetails",
-          "type": {
-            "vec": {
-              "defined": "FlashLoanTokenDetailV2"
-            }
+          {
+            "name": "FlashLoan"
           },
-          "index": false
-        },
-        {
-          "name": "flashLoanType",
-          "type": {
-            "defined": "FlashLoanType"
+          {
+            "name": "GroupClose"
           },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "FlashLoanLogV3",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenLoanDetails",
-          "type": {
-            "vec": {
-              "defined": "FlashLoanTokenDetailV3"
-            }
+          {
+            "name": "GroupCreate"
           },
-          "index": false
-        },
-        {
-          "name": "flashLoanType",
-          "type": {
-            "defined": "FlashLoanType"
+          {
+            "name": "HealthRegion"
           },
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "WithdrawLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "signer",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "quantity",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "price",
-          "type": "i128",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "DepositLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "signer",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "quantity",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "price",
-          "type": "i128",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "FillLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "takerSide",
-          "type": "u8",
-          "index": false
-        },
-        {
-          "name": "makerSlot",
-          "type": "u8",
-          "index": false
-        },
-        {
-          "name": "makerOut",
-          "type": "bool",
-          "index": false
-        },
-        {
-          "name": "timestamp",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "seqNum",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maker",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "makerOrderId",
-          "type": "u128",
-          "index": false
-        },
-        {
-          "name": "makerFee",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "makerTimestamp",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "taker",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "takerOrderId",
-          "type": "u128",
-          "index": false
-        },
-        {
-          "name": "takerClientOrderId",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "takerFee",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "price",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "quantity",
-          "type": "i64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "FillLogV2",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketIndex",
-          "type": "u16",
+          {
+            "name": "PerpCancelAllOrders"
+          },
+          {
+            "name": "PerpCancelAllOrdersBySide"
+          },
+          {
+            "name": "PerpCancelOrder"
+          },
+          {
+            "name": "PerpCancelOrderByClientOrderId"
+          },
+          {
+            "name": "PerpCloseMarket"
+          },
+          {
+            "name": "PerpConsumeEvents"
+          },
+          {
+            "name": "PerpCreateMarket"
+          },
+          {
+            "name": "PerpDeactivatePosition"
+          },
+          {
+            "name": "PerpLiqBaseOrPositivePnl"
+          },
+          {
+            "name": "PerpLiqForceCancelOrders"
+          },
+          {
+            "name": "PerpLiqNegativePnlOrBankruptcy"
+          },
+          {
+            "name": "PerpPlaceOrder"
+          },
+          {
+            "name": "PerpSettleFees"
+          },
+          {
+            "name": "PerpSettlePnl"
+          },
+          {
+            "name": "PerpUpdateFunding"
+          },
+          {
+            "name": "Serum3CancelAllOrders"
+          },
+          {
+            "name": "Serum3CancelOrder"
+          },
+          {
+            "name": "Serum3CloseOpenOrders"
+          },
+          {
+            "name": "Serum3CreateOpenOrders"
+          },
+          {
+            "name": "Serum3DeregisterMarket"
+          },
+          {
+            "name": "Serum3EditMarket"
+          },
+          {
+            "name": "Serum3LiqForceCancelOrders"
+          },
+          {
+            "name": "Serum3PlaceOrder"
+          },
+          {
+            "name": "Serum3RegisterMarket"
+          },
+          {
+            "name": "Serum3SettleFunds"
+          },
+          {
+            "name": "StubOracleClose"
+          },
+          {
+            "name": "StubOracleCreate"
+          },
+          {
+            "name": "StubOracleSet"
+          },
+          {
+            "name": "TokenAddBank"
+          },
+          {
+            "name": "TokenDeposit"
+          },
+          {
+            "name": "TokenDeregister"
+          },
+          {
+            "name": "TokenLiqBankruptcy"
+          },
+          {
+            "name": "TokenLiqWithToken"
+          },
+          {
+            "name": "TokenRegister"
+          },
+          {
+            "name": "TokenRegisterTrustless"
+          },
+          {
+            "name": "TokenUpdateIndexAndRate"
+          },
+          {
+            "name": "TokenWithdraw"
+          },
+          {
+            "name": "AccountBuybackFeesWithMngo"
+          },
+          {
+            "name": "TokenForceCloseBorrowsWithToken"
+          },
+          {
+            "name": "PerpForceClosePosition"
+          },
+          {
+            "name": "GroupWithdrawInsuranceFund"
+          },
+          {
+            "name": "TokenConditionalSwapCreate"
+          },
+          {
+            "name": "TokenConditionalSwapTrigger"
+          },
+          {
+            "name": "TokenConditionalSwapCancel"
+          },
+          {
+            "name": "OpenbookV2CancelOrder"
+          },
+          {
+            "name": "OpenbookV2CloseOpenOrders"
+          },
+          {
+            "name": "OpenbookV2CreateOpenOrders"
+          },
+          {
+            "name": "OpenbookV2DeregisterMarket"
+          },
+          {
+            "name": "OpenbookV2EditMarket"
+          },
+          {
+            "name": "OpenbookV2LiqForceCancelOrders"
+          },
+          {
+            "name": "OpenbookV2PlaceOrder"
+          },
+          {
+            "name": "OpenbookV2PlaceTakeOrder"
+          },
+          {
+            "name": "OpenbookV2RegisterMarket"
+          },
+          {
+            "name": "OpenbookV2SettleFunds"
+          },
+          {
+            "name": "AdminTokenWithdrawFees"
+          },
+          {
+            "name": "AdminPerpWithdrawFees"
+          },
+          {
+            "name": "AccountSizeMigration"
+          },
+          {
+            "name": "TokenConditionalSwapStart"
+          },
+          {
+            "name": "TokenConditionalSwapCreatePremiumAuction"
+          },
+          {
+            "name": "TokenConditionalSwapCreateLinearAuction"
+          },
+          {
+            "name": "Serum3PlaceOrderV2"
+          },
+          {
+            "name": "PerpLiqAutoDeleverage"
+          },
+          {
+            "name": "GroupDepositInsuranceFund"
+          },
+          {
+            "name": "PerpTriggerOrderCreate"
+          },
+          {
+            "name": "PerpTriggerOrderCancel"
+          },
+          {
+            "name": "PerpTriggerOrderExecute"
+          },
+          {
+            "name": "AltCreate"
+          },
+          {
+            "name": "MarginTrade"
+          },
+          {
+            "name": "PerpSettlePnlBatch"
+          },
+          {
+            "name": "PerpModifyOrder"
+          },
+          {
+            "name": "PerpPlaceOrders"
+          },
+          {
+            "name": "Serum3PlaceOrders"
+          },
+          {
+            "name": "TokenCheckVaultInvariant"
+          },
+          {
+            "name": "AccountClaimReferralFees"
+          },
+          {
+            "name": "PerpUpdateAdlCandidate"
+          },
+          {
+            "name": "PerpLiqCombined"
+          },
+          {
+            "name": "GroupMigrate"
+          },
+          {
+            "name": "TokenMigrate"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReferralFeeSource",
+      "docs": [
+        "The bank or perp market that collected referral fees, see ReferralFeesAccrued"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unused"
+          },
+          {
+            "name": "Bank"
+          },
+          {
+            "name": "PerpMarket"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CheckLiquidatable",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotLiquidatable"
+          },
+          {
+            "name": "Liquidatable"
+          },
+          {
+            "name": "BecameNotLiquidatable"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "Stub"
+          },
+          {
+            "name": "SwitchboardV1"
+          },
+          {
+            "name": "SwitchboardV2"
+          },
+          {
+            "name": "OrcaCLMM"
+          },
+          {
+            "name": "PythV2"
+          },
+          {
+            "name": "SwitchboardOnDemand"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OrderState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Valid"
+          },
+          {
+            "name": "Invalid"
+          },
+          {
+            "name": "Skipped"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BookSideOrderTree",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fixed"
+          },
+          {
+            "name": "OraclePegged"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NodeTag",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Uninitialized"
+          },
+          {
+            "name": "InnerNode"
+          },
+          {
+            "name": "LeafNode"
+          },
+          {
+            "name": "FreeNode"
+          },
+          {
+            "name": "LastFreeNode"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PlaceOrderType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Limit"
+          },
+          {
+            "name": "ImmediateOrCancel"
+          },
+          {
+            "name": "PostOnly"
+          },
+          {
+            "name": "Market"
+          },
+          {
+            "name": "PostOnlySlide"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostOrderType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Limit"
+          },
+          {
+            "name": "PostOnly"
+          },
+          {
+            "name": "PostOnlySlide"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SelfTradeBehavior",
+      "docs": [
+        "Self trade behavior controls how taker orders interact with resting limit orders of the same account.",
+        "This setting has no influence on placing a resting or oracle pegged limit order that does not match",
+        "immediately, instead it's the responsibility of the user to correctly configure his taker orders."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "DecrementTake"
+          },
+          {
+            "name": "CancelProvide"
+          },
+          {
+            "name": "AbortTransaction"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Side",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Bid"
+          },
+          {
+            "name": "Ask"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SideAndOrderTree",
+      "docs": [
+        "SideAndOrderTree is a storage optimization, so we don't need two bytes for the data"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "BidFixed"
+          },
+          {
+            "name": "AskFixed"
+          },
+          {
+            "name": "BidOraclePegged"
+          },
+          {
+            "name": "AskOraclePegged"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OrderParams",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Market"
+          },
+          {
+            "name": "ImmediateOrCancel",
+            "fields": [
+              {
+                "name": "price_lots",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "Fixed",
+            "fields": [
+              {
+                "name": "price_lots",
+                "type": "i64"
+              },
+              {
+                "name": "order_type",
+                "type": {
+                  "defined": "PostOrderType"
+                }
+              }
+            ]
+          },
+          {
+            "name": "OraclePegged",
+            "fields": [
+              {
+                "name": "price_offset_lots",
+                "type": "i64"
+              },
+              {
+                "name": "order_type",
+                "type": {
+                  "defined": "PostOrderType"
+                }
+              },
+              {
+                "name": "peg_limit",
+                "type": "i64"
+              },
+              {
+                "name": "max_oracle_staleness_slots",
+                "type": "i32"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "OrderTreeType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Bids"
+          },
+          {
+            "name": "Asks"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EventType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Fill"
+          },
+          {
+            "name": "Out"
+          },
+          {
+            "name": "Liquidate"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PerpTriggerCondition",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "OraclePriceAbove"
+          },
+          {
+            "name": "OraclePriceBelow"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenConditionalSwapDisplayPriceStyle",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SellTokenPerBuyToken"
+          },
+          {
+            "name": "BuyTokenPerSellToken"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenConditionalSwapIntention",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unknown"
+          },
+          {
+            "name": "StopLoss"
+          },
+          {
+            "name": "TakeProfit"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenConditionalSwapType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FixedPremium"
+          },
+          {
+            "name": "PremiumAuction"
+          },
+          {
+            "name": "LinearAuction"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MangoAccountData",
+      "fields": [
+        {
+          "name": "initHealth",
+          "type": {
+            "defined": "I80F48"
+          },
+          "index": false
+        },
+        {
+          "name": "maintHealth",
+          "type": {
+            "defined": "I80F48"
+          },
+          "index": false
+        },
+        {
+          "name": "equity",
+          "type": {
+            "defined": "Equity"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MangoAccountPositions",
+      "fields": [
+        {
+          "name": "tokens",
+          "type": {
+            "vec": {
+              "defined": "TokenPositionData"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "serum3",
+          "type": {
+            "vec": {
+              "defined": "Serum3PositionData"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "perps",
+          "type": {
+            "vec": {
+              "defined": "PerpPositionData"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "HealthLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "initHealth",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "maintHealth",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PerpBalanceLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "basePosition",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quotePosition",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "longSettledFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "shortSettledFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "longFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "shortFunding",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenBalanceLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "indexedPosition",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "depositIndex",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "borrowIndex",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoanLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenLoanDetails",
+          "type": {
+            "vec": {
+              "defined": "FlashLoanTokenDetail"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "flashLoanType",
+          "type": {
+            "defined": "FlashLoanType"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoanLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenLoanDetails",
+          "type": {
+            "vec": {
+              "defined": "FlashLoanTokenDetailV2"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "flashLoanType",
+          "type": {
+            "defined": "FlashLoanType"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FlashLoanLogV3",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenLoanDetails",
+          "type": {
+            "vec": {
+              "defined": "FlashLoanTokenDetailV3"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "flashLoanType",
+          "type": {
+            "defined": "FlashLoanType"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DepositLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "signer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FillLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "takerSide",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerSlot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerOut",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seqNum",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "makerOrderId",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "makerFee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "makerTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "takerOrderId",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "takerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerFee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FillLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "takerSide",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerSlot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerOut",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seqNum",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "makerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "makerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "makerTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "takerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FillLogV3",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "takerSide",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerSlot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerOut",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seqNum",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "makerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "makerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "makerTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "takerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "makerClosedPnl",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "takerClosedPnl",
+          "type": "f64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FillLogV4",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "takerSide",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerSlot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerOut",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seqNum",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "makerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "makerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "makerTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "takerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "makerClosedPnl",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "takerClosedPnl",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "makerFeePaid",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "takerFeePaid",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "makerBasePosition",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "takerBasePosition",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PerpUpdateFundingLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "longFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "shortFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "oracleSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stablePrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feesAccrued",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feesSettled",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "openInterest",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "instantaneousFundingRate",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PerpUpdateFundingLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "longFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "shortFunding",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "oracleSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "oracleConfidence",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "oracleType",
+          "type": {
+            "defined": "OracleType"
+          },
+          "index": false
+        },
+        {
+          "name": "stablePrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feesAccrued",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feesSettled",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "openInterest",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "instantaneousFundingRate",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateIndexLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "takerSide",
-          "type": "u8",
+          "name": "tokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "makerSlot",
-          "type": "u8",
+          "name": "depositIndex",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "makerOut",
+          "name": "borrowIndex",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "avgUtilization",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "stablePrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "collectedFees",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "loanFeeRate",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "totalBorrows",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "totalDeposits",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "borrowRate",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "depositRate",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateRateLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "rate0",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "rate1",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "maxRate",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateRateLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "rate0",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "util0",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "rate1",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "util1",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "maxRate",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "curveScaling",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "targetUtilization",
+          "type": "f32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenLiqWithTokenLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "assetTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "liabTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "assetTransfer",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabTransfer",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "bankruptcy",
           "type": "bool",
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenLiqWithTokenLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "timestamp",
-          "type": "u64",
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "assetTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "liabTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "assetTransferFromLiqee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetTransferToLiqor",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetLiquidationFee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabTransfer",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "seqNum",
-          "type": "u64",
+          "name": "bankruptcy",
+          "type": "bool",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "Serum3OpenOrdersBalanceLog",
+      "fields": [
         {
-          "name": "maker",
+          "name": "mangoGroup",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "makerClientOrderId",
-          "type": "u64",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "makerFee",
-          "type": "f32",
+          "name": "baseTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "makerTimestamp",
-          "type": "u64",
+          "name": "quoteTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "taker",
-          "type": "publicKey",
+          "name": "baseTotal",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "takerClientOrderId",
+          "name": "baseFree",
           "type": "u64",
           "index": false
         },
         {
-          "name": "takerFee",
-          "type": "f32",
+          "name": "quoteTotal",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "price",
-          "type": "i64",
+          "name": "quoteFree",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "quantity",
-          "type": "i64",
+          "name": "referrerRebatesAccrued",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "FillLogV3",
+      "name": "Serum3OpenOrdersBalanceLogV2",
       "fields": [
         {
           "name": "mangoGroup",
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "marketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "takerSide",
-          "type": "u8",
+          "name": "baseTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "makerSlot",
-          "type": "u8",
+          "name": "quoteTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "makerOut",
-          "type": "bool",
+          "name": "baseTotal",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "timestamp",
+          "name": "baseFree",
           "type": "u64",
           "index": false
         },
         {
-          "name": "seqNum",
+          "name": "quoteTotal",
           "type": "u64",
           "index": false
         },
         {
-          "name": "maker",
-          "type": "publicKey",
+          "name": "quoteFree",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "makerClientOrderId",
+          "name": "referrerRebatesAccrued",
           "type": "u64",
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawLoanOriginationFeeLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "makerFee",
-          "type": "f32",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "makerTimestamp",
-          "type": "u64",
+          "name": "tokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "taker",
+          "name": "loanOriginationFee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "instruction",
+          "type": {
+            "defined": "LoanOriginationFeeInstruction"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawLoanLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "takerClientOrderId",
-          "type": "u64",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "takerFee",
-          "type": "f32",
+          "name": "tokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "price",
-          "type": "i64",
+          "name": "loanAmount",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "quantity",
-          "type": "i64",
+          "name": "loanOriginationFee",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "makerClosedPnl",
-          "type": "f64",
+          "name": "instruction",
+          "type": {
+            "defined": "LoanOriginationFeeInstruction"
+          },
           "index": false
         },
         {
-          "name": "takerClosedPnl",
-          "type": "f64",
+          "name": "price",
+          "type": {
+            "option": "i128"
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "PerpUpdateFundingLog",
+      "name": "TokenLiqBankruptcyLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -11718,59 +14192,59 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "marketIndex",
-          "type": "u16",
+          "name": "liqee",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "longFunding",
-          "type": "i128",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "shortFunding",
-          "type": "i128",
+          "name": "liabTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "price",
+          "name": "initialLiabNative",
           "type": "i128",
           "index": false
         },
         {
-          "name": "oracleSlot",
-          "type": "u64",
+          "name": "liabPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "stablePrice",
-          "type": "i128",
+          "name": "insuranceTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "feesAccrued",
+          "name": "insuranceTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "feesSettled",
+          "name": "socializedLoss",
           "type": "i128",
           "index": false
         },
         {
-          "name": "openInterest",
-          "type": "i64",
+          "name": "startingLiabDepositIndex",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "instantaneousFundingRate",
+          "name": "endingLiabDepositIndex",
           "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpUpdateFundingLogV2",
+      "name": "TokenLiqBankruptcyLogV2",
       "fields": [
         {
           "name": "mangoGroup",
@@ -11778,71 +14252,79 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "marketIndex",
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liabTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "longFunding",
+          "name": "initialLiabNative",
           "type": "i128",
           "index": false
         },
         {
-          "name": "shortFunding",
+          "name": "liabPrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "price",
+          "name": "liabTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "oracleSlot",
-          "type": "u64",
+          "name": "insuranceTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "oracleConfidence",
+          "name": "insurancePrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "oracleType",
-          "type": {
-            "defined": "OracleType"
-          },
+          "name": "insuranceTransfer",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "stablePrice",
+          "name": "liquidationFee",
           "type": "i128",
           "index": false
         },
         {
-          "name": "feesAccrued",
-          "type": "i128",
+          "name": "insuranceFundExhausted",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "feesSettled",
+          "name": "socializedLoss",
           "type": "i128",
           "index": false
         },
         {
-          "name": "openInterest",
-          "type": "i64",
+          "name": "startingLiabDepositIndex",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "instantaneousFundingRate",
+          "name": "endingLiabDepositIndex",
           "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateIndexLog",
+      "name": "DeactivateTokenPositionLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -11850,99 +14332,109 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "tokenIndex",
-          "type": "u16",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "depositIndex",
-          "type": "i128",
+          "name": "tokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "borrowIndex",
-          "type": "i128",
+          "name": "cumulativeDepositInterest",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "avgUtilization",
-          "type": "i128",
+          "name": "cumulativeBorrowInterest",
+          "type": "f64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "DeactivatePerpPositionLog",
+      "fields": [
         {
-          "name": "price",
-          "type": "i128",
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "stablePrice",
-          "type": "i128",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "collectedFees",
-          "type": "i128",
+          "name": "marketIndex",
+          "type": "u16",
           "index": false
         },
-        {
-          "name": "loanFeeRate",
-          "type": "i128",
+        {
+          "name": "cumulativeLongFunding",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "totalBorrows",
-          "type": "i128",
+          "name": "cumulativeShortFunding",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "totalDeposits",
-          "type": "i128",
+          "name": "makerVolume",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "borrowRate",
-          "type": "i128",
+          "name": "takerVolume",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "depositRate",
-          "type": "i128",
+          "name": "perpSpotTransfers",
+          "type": "i64",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateRateLog",
+      "name": "TokenMetaDataLog",
       "fields": [
         {
           "name": "mangoGroup",
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "tokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "rate0",
-          "type": "i128",
+          "name": "mintDecimals",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "rate1",
-          "type": "i128",
+          "name": "oracle",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "maxRate",
-          "type": "i128",
+          "name": "mintInfo",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "UpdateRateLogV2",
+      "name": "TokenMetaDataLogV2",
       "fields": [
         {
           "name": "mangoGroup",
@@ -11950,49 +14442,39 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "tokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "rate0",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "util0",
-          "type": "i128",
+          "name": "mint",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "rate1",
-          "type": "i128",
+          "name": "tokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "util1",
-          "type": "i128",
+          "name": "mintDecimals",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "maxRate",
-          "type": "i128",
+          "name": "oracle",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "curveScaling",
-          "type": "f64",
+          "name": "fallbackOracle",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "targetUtilization",
-          "type": "f32",
+          "name": "mintInfo",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenLiqWithTokenLog",
+      "name": "PerpMarketMetaDataLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12000,54 +14482,39 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "liqor",
+          "name": "perpMarket",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "assetTokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "liabTokenIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "assetTransfer",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "liabTransfer",
-          "type": "i128",
+          "name": "baseDecimals",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "assetPrice",
-          "type": "i128",
+          "name": "baseLotSize",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "liabPrice",
-          "type": "i128",
+          "name": "quoteLotSize",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "bankruptcy",
-          "type": "bool",
+          "name": "oracle",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenLiqWithTokenLogV2",
+      "name": "Serum3RegisterMarketLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12055,64 +14522,39 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "liqor",
+          "name": "serumMarket",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "assetTokenIndex",
+          "name": "marketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "liabTokenIndex",
+          "name": "baseTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "assetTransferFromLiqee",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "assetTransferToLiqor",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "assetLiquidationFee",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "liabTransfer",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "assetPrice",
-          "type": "i128",
+          "name": "quoteTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "liabPrice",
-          "type": "i128",
+          "name": "serumProgram",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "bankruptcy",
-          "type": "bool",
+          "name": "serumProgramExternal",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "Serum3OpenOrdersBalanceLog",
+      "name": "OpenbookV2RegisterMarketLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12120,49 +14562,39 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "openbookMarket",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "baseTokenIndex",
+          "name": "marketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "quoteTokenIndex",
+          "name": "baseTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "baseTotal",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "baseFree",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "quoteTotal",
-          "type": "u64",
+          "name": "quoteTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "quoteFree",
-          "type": "u64",
+          "name": "openbookProgram",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "referrerRebatesAccrued",
-          "type": "u64",
+          "name": "openbookMarketExternal",
+          "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "Serum3OpenOrdersBalanceLogV2",
+      "name": "PerpLiqBaseOrPositivePnlLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12170,54 +14602,49 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "marketIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "baseTokenIndex",
-          "type": "u16",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "quoteTokenIndex",
-          "type": "u16",
+          "name": "liqee",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "baseTotal",
-          "type": "u64",
+          "name": "baseTransfer",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "baseFree",
-          "type": "u64",
+          "name": "quoteTransfer",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "quoteTotal",
-          "type": "u64",
+          "name": "pnlTransfer",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "quoteFree",
-          "type": "u64",
+          "name": "pnlSettleLimitTransfer",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "referrerRebatesAccrued",
-          "type": "u64",
+          "name": "price",
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "WithdrawLoanOriginationFeeLog",
+      "name": "PerpLiqBaseOrPositivePnlLogV2",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12225,75 +14652,59 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "tokenIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "loanOriginationFee",
-          "type": "i128",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "instruction",
-          "type": {
-            "defined": "LoanOriginationFeeInstruction"
-          },
+          "name": "liqee",
+          "type": "publicKey",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "WithdrawLoanLog",
-      "fields": [
+        },
         {
-          "name": "mangoGroup",
-          "type": "publicKey",
+          "name": "baseTransferLiqee",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "mangoAccount",
-          "type": "publicKey",
+          "name": "quoteTransferLiqee",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "tokenIndex",
-          "type": "u16",
+          "name": "quoteTransferLiqor",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "loanAmount",
+          "name": "quotePlatformFee",
           "type": "i128",
           "index": false
         },
         {
-          "name": "loanOriginationFee",
+          "name": "pnlTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "instruction",
-          "type": {
-            "defined": "LoanOriginationFeeInstruction"
-          },
+          "name": "pnlSettleLimitTransfer",
+          "type": "i128",
           "index": false
         },
         {
           "name": "price",
-          "type": {
-            "option": "i128"
-          },
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenLiqBankruptcyLog",
+      "name": "PerpLiqBankruptcyLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12311,49 +14722,44 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liabTokenIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "initialLiabNative",
+          "name": "insuranceTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "liabPrice",
+          "name": "socializedLoss",
           "type": "i128",
           "index": false
         },
         {
-          "name": "insuranceTokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "insuranceTransfer",
+          "name": "startingLongFunding",
           "type": "i128",
           "index": false
         },
         {
-          "name": "socializedLoss",
+          "name": "startingShortFunding",
           "type": "i128",
           "index": false
         },
         {
-          "name": "startingLiabDepositIndex",
+          "name": "endingLongFunding",
           "type": "i128",
           "index": false
         },
         {
-          "name": "endingLiabDepositIndex",
+          "name": "endingShortFunding",
           "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "DeactivateTokenPositionLog",
+      "name": "PerpLiqNegativePnlOrBankruptcyLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12361,29 +14767,29 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenIndex",
-          "type": "u16",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "cumulativeDepositInterest",
-          "type": "f64",
+          "name": "perpMarketIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "cumulativeBorrowInterest",
-          "type": "f64",
+          "name": "settlement",
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "DeactivatePerpPositionLog",
+      "name": "PerpSettlePnlLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12391,44 +14797,39 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "mangoAccountA",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "marketIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "cumulativeLongFunding",
-          "type": "f64",
+          "name": "mangoAccountB",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "cumulativeShortFunding",
-          "type": "f64",
+          "name": "perpMarketIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "makerVolume",
-          "type": "u64",
+          "name": "settlement",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "takerVolume",
-          "type": "u64",
+          "name": "settler",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "perpSpotTransfers",
-          "type": "i64",
+          "name": "fee",
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenMetaDataLog",
+      "name": "PerpSettleFeesLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12436,34 +14837,24 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mint",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "mintDecimals",
-          "type": "u8",
-          "index": false
-        },
-        {
-          "name": "oracle",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "mintInfo",
-          "type": "publicKey",
+          "name": "settlement",
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenMetaDataLogV2",
+      "name": "AccountBuybackFeesWithMngoLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12471,79 +14862,54 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mint",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "mintDecimals",
-          "type": "u8",
+          "name": "buybackFees",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "oracle",
-          "type": "publicKey",
+          "name": "buybackMngo",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "fallbackOracle",
-          "type": "publicKey",
+          "name": "mngoBuybackPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "mintInfo",
-          "type": "publicKey",
+          "name": "oraclePrice",
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpMarketMetaDataLog",
+      "name": "FilledPerpOrderLog",
       "fields": [
         {
           "name": "mangoGroup",
           "type": "publicKey",
           "index": false
         },
-        {
-          "name": "perpMarket",
-          "type": "publicKey",
-          "index": false
-        },
         {
           "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "baseDecimals",
-          "type": "u8",
-          "index": false
-        },
-        {
-          "name": "baseLotSize",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "quoteLotSize",
-          "type": "i64",
-          "index": false
-        },
-        {
-          "name": "oracle",
-          "type": "publicKey",
+          "name": "seqNum",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "Serum3RegisterMarketLog",
+      "name": "PerpTakerTradeLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12551,39 +14917,54 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "serumMarket",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "marketIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "baseTokenIndex",
-          "type": "u16",
+          "name": "takerSide",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "quoteTokenIndex",
-          "type": "u16",
+          "name": "totalBaseLotsTaken",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "serumProgram",
-          "type": "publicKey",
+          "name": "totalBaseLotsDecremented",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "serumProgramExternal",
-          "type": "publicKey",
+          "name": "totalQuoteLotsTaken",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "totalQuoteLotsDecremented",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "takerFeesPaid",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feePenalty",
+          "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpLiqBaseOrPositivePnlLog",
+      "name": "PerpForceClosePositionLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12596,12 +14977,12 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "accountA",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "accountB",
           "type": "publicKey",
           "index": false
         },
@@ -12615,16 +14996,6 @@ export type MangoV4 = {
           "type": "i128",
           "index": false
         },
-        {
-          "name": "pnlTransfer",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "pnlSettleLimitTransfer",
-          "type": "i128",
-          "index": false
-        },
         {
           "name": "price",
           "type": "i128",
@@ -12633,7 +15004,7 @@ export type MangoV4 = {
       ]
     },
     {
-      "name": "PerpLiqBaseOrPositivePnlLogV2",
+      "name": "PerpLiqAutoDeleverageLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12646,12 +15017,12 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "counterparty",
           "type": "publicKey",
           "index": false
         },
@@ -12666,34 +15037,19 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "quoteTransferLiqor",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "quotePlatformFee",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "pnlTransfer",
-          "type": "i128",
-          "index": false
-        },
-        {
-          "name": "pnlSettleLimitTransfer",
+          "name": "bankruptcyPrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "price",
+          "name": "oraclePrice",
           "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpLiqBankruptcyLog",
+      "name": "TokenForceCloseBorrowsWithTokenLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12701,124 +15057,119 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "liqor",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "perpMarketIndex",
+          "name": "assetTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "insuranceTransfer",
-          "type": "i128",
+          "name": "liabTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "socializedLoss",
+          "name": "assetTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "startingLongFunding",
+          "name": "liabTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "startingShortFunding",
+          "name": "assetPrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "endingLongFunding",
+          "name": "liabPrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "endingShortFunding",
+          "name": "feeFactor",
           "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpLiqNegativePnlOrBankruptcyLog",
+      "name": "TokenForceCloseBorrowsWithTokenLogV2",
       "fields": [
         {
           "name": "mangoGroup",
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
-          "type": "publicKey",
+          "name": "assetTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "perpMarketIndex",
+          "name": "liabTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "settlement",
+          "name": "assetTransferFromLiqee",
           "type": "i128",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "PerpSettlePnlLog",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
-          "index": false
         },
         {
-          "name": "mangoAccountA",
-          "type": "publicKey",
+          "name": "assetTransferToLiqor",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "mangoAccountB",
-          "type": "publicKey",
+          "name": "assetLiquidationFee",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "perpMarketIndex",
-          "type": "u16",
+          "name": "liabTransfer",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "settlement",
+          "name": "assetPrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "settler",
-          "type": "publicKey",
+          "name": "liabPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "fee",
+          "name": "feeFactor",
           "type": "i128",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpSettleFeesLog",
+      "name": "TokenConditionalSwapCreateLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12831,74 +15182,74 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "perpMarketIndex",
-          "type": "u16",
+          "name": "id",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "settlement",
-          "type": "i128",
+          "name": "maxBuy",
+          "type": "u64",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "AccountBuybackFeesWithMngoLog",
-      "fields": [
+        },
         {
-          "name": "mangoGroup",
-          "type": "publicKey",
+          "name": "maxSell",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "mangoAccount",
-          "type": "publicKey",
+          "name": "expiryTimestamp",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "buybackFees",
-          "type": "i128",
+          "name": "priceLowerLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "buybackMngo",
-          "type": "i128",
+          "name": "priceUpperLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "mngoBuybackPrice",
-          "type": "i128",
+          "name": "pricePremiumRate",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "oraclePrice",
-          "type": "i128",
+          "name": "takerFeeRate",
+          "type": "f32",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "FilledPerpOrderLog",
-      "fields": [
+        },
         {
-          "name": "mangoGroup",
-          "type": "publicKey",
+          "name": "makerFeeRate",
+          "type": "f32",
           "index": false
         },
         {
-          "name": "perpMarketIndex",
+          "name": "buyTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "seqNum",
-          "type": "u64",
+          "name": "sellTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool",
           "index": false
         }
       ]
     },
     {
-      "name": "PerpTakerTradeLog",
+      "name": "TokenConditionalSwapCreateLogV2",
       "fields": [
         {
           "name": "mangoGroup",
@@ -12911,89 +15262,84 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "perpMarketIndex",
-          "type": "u16",
+          "name": "id",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "takerSide",
-          "type": "u8",
+          "name": "maxBuy",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "totalBaseLotsTaken",
-          "type": "i64",
+          "name": "maxSell",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "totalBaseLotsDecremented",
-          "type": "i64",
+          "name": "expiryTimestamp",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "totalQuoteLotsTaken",
-          "type": "i64",
+          "name": "priceLowerLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "totalQuoteLotsDecremented",
-          "type": "i64",
+          "name": "priceUpperLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "takerFeesPaid",
-          "type": "i128",
+          "name": "pricePremiumRate",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "feePenalty",
-          "type": "i128",
+          "name": "takerFeeRate",
+          "type": "f32",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "PerpForceClosePositionLog",
-      "fields": [
+        },
         {
-          "name": "mangoGroup",
-          "type": "publicKey",
+          "name": "makerFeeRate",
+          "type": "f32",
           "index": false
         },
         {
-          "name": "perpMarketIndex",
+          "name": "buyTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "accountA",
-          "type": "publicKey",
+          "name": "sellTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "accountB",
-          "type": "publicKey",
+          "name": "allowCreatingDeposits",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "baseTransfer",
-          "type": "i64",
+          "name": "allowCreatingBorrows",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "quoteTransfer",
-          "type": "i128",
+          "name": "displayPriceStyle",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "price",
-          "type": "i128",
+          "name": "intention",
+          "type": "u8",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenForceCloseBorrowsWithTokenLog",
+      "name": "TokenConditionalSwapCreateLogV3",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13001,119 +15347,104 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqor",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "liqee",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "assetTokenIndex",
-          "type": "u16",
+          "name": "id",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "liabTokenIndex",
-          "type": "u16",
+          "name": "maxBuy",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "assetTransfer",
-          "type": "i128",
+          "name": "maxSell",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "liabTransfer",
-          "type": "i128",
+          "name": "expiryTimestamp",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "assetPrice",
-          "type": "i128",
+          "name": "priceLowerLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "liabPrice",
-          "type": "i128",
+          "name": "priceUpperLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "feeFactor",
-          "type": "i128",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "TokenForceCloseBorrowsWithTokenLogV2",
-      "fields": [
-        {
-          "name": "mangoGroup",
-          "type": "publicKey",
+          "name": "pricePremiumRate",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "liqor",
-          "type": "publicKey",
+          "name": "takerFeeRate",
+          "type": "f32",
           "index": false
         },
         {
-          "name": "liqee",
-          "type": "publicKey",
+          "name": "makerFeeRate",
+          "type": "f32",
           "index": false
         },
         {
-          "name": "assetTokenIndex",
+          "name": "buyTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "liabTokenIndex",
+          "name": "sellTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "assetTransferFromLiqee",
-          "type": "i128",
+          "name": "allowCreatingDeposits",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "assetTransferToLiqor",
-          "type": "i128",
+          "name": "allowCreatingBorrows",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "assetLiquidationFee",
-          "type": "i128",
+          "name": "displayPriceStyle",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "liabTransfer",
-          "type": "i128",
+          "name": "intention",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "assetPrice",
-          "type": "i128",
+          "name": "tcsType",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "liabPrice",
-          "type": "i128",
+          "name": "startTimestamp",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "feeFactor",
-          "type": "i128",
+          "name": "durationSeconds",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapCreateLog",
+      "name": "TokenConditionalSwapTriggerLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13121,79 +15452,69 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "id",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maxBuy",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maxSell",
-          "type": "u64",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "expiryTimestamp",
+          "name": "tokenConditionalSwapId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "priceLowerLimit",
-          "type": "f64",
+          "name": "buyTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64",
+          "name": "sellTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "pricePremiumRate",
-          "type": "f64",
+          "name": "buyAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "takerFeeRate",
-          "type": "f32",
+          "name": "sellAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "makerFeeRate",
-          "type": "f32",
+          "name": "makerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
+          "name": "takerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "sellTokenIndex",
-          "type": "u16",
+          "name": "buyTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool",
+          "name": "sellTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "allowCreatingBorrows",
+          "name": "closed",
           "type": "bool",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapCreateLogV2",
+      "name": "TokenConditionalSwapTriggerLogV2",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13201,72 +15522,62 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "id",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maxBuy",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maxSell",
-          "type": "u64",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "expiryTimestamp",
+          "name": "tokenConditionalSwapId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "priceLowerLimit",
-          "type": "f64",
+          "name": "buyTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64",
+          "name": "sellTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "pricePremiumRate",
-          "type": "f64",
+          "name": "buyAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "takerFeeRate",
-          "type": "f32",
+          "name": "sellAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "makerFeeRate",
-          "type": "f32",
+          "name": "makerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
+          "name": "takerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "sellTokenIndex",
-          "type": "u16",
+          "name": "buyTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool",
+          "name": "sellTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "allowCreatingBorrows",
+          "name": "closed",
           "type": "bool",
           "index": false
         },
@@ -13283,7 +15594,7 @@ export type MangoV4 = {
       ]
     },
     {
-      "name": "TokenConditionalSwapCreateLogV3",
+      "name": "TokenConditionalSwapTriggerLogV3",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13291,72 +15602,62 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "id",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maxBuy",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "maxSell",
-          "type": "u64",
+          "name": "liqor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "expiryTimestamp",
+          "name": "tokenConditionalSwapId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "priceLowerLimit",
-          "type": "f64",
+          "name": "buyTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64",
+          "name": "sellTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "pricePremiumRate",
-          "type": "f64",
+          "name": "buyAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "takerFeeRate",
-          "type": "f32",
+          "name": "sellAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "makerFeeRate",
-          "type": "f32",
+          "name": "makerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
+          "name": "takerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "sellTokenIndex",
-          "type": "u16",
+          "name": "buyTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool",
+          "name": "sellTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "allowCreatingBorrows",
+          "name": "closed",
           "type": "bool",
           "index": false
         },
@@ -13379,16 +15680,31 @@ export type MangoV4 = {
           "name": "startTimestamp",
           "type": "u64",
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenConditionalSwapCancelLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "durationSeconds",
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapTriggerLog",
+      "name": "TokenConditionalSwapStartLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13396,12 +15712,12 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "caller",
           "type": "publicKey",
           "index": false
         },
@@ -13411,54 +15727,104 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "buyTokenIndex",
+          "name": "incentiveTokenIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "sellTokenIndex",
-          "type": "u16",
+          "name": "incentiveAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GroupInsuranceFundLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "buyAmount",
-          "type": "u64",
+          "name": "insuranceVault",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sellAmount",
-          "type": "u64",
+          "name": "amountChange",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "makerFee",
+          "name": "vaultBalance",
           "type": "u64",
           "index": false
         },
         {
-          "name": "takerFee",
+          "name": "tokenAccount",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenVaultInvariantViolationLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bank",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "vaultBalance",
           "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenPrice",
+          "name": "nativeDeposits",
           "type": "i128",
           "index": false
         },
         {
-          "name": "sellTokenPrice",
+          "name": "nativeBorrows",
           "type": "i128",
           "index": false
         },
         {
-          "name": "closed",
+          "name": "collectedFees",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feesWithdrawn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "shortfall",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "halted",
           "type": "bool",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapTriggerLogV2",
+      "name": "PerpTriggerOrderCreateLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13466,79 +15832,74 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "triggerOrder",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "orderId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "sellTokenIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "buyAmount",
-          "type": "u64",
+          "name": "side",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "sellAmount",
-          "type": "u64",
+          "name": "triggerCondition",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "makerFee",
-          "type": "u64",
+          "name": "triggerPriceLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "takerFee",
-          "type": "u64",
+          "name": "priceLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "buyTokenPrice",
-          "type": "i128",
+          "name": "maxBaseLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "sellTokenPrice",
-          "type": "i128",
+          "name": "maxQuoteLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "closed",
+          "name": "reduceOnly",
           "type": "bool",
           "index": false
         },
         {
-          "name": "displayPriceStyle",
-          "type": "u8",
+          "name": "expiryTimestamp",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "intention",
-          "type": "u8",
+          "name": "executorIncentive",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapTriggerLogV3",
+      "name": "PerpTriggerOrderCancelLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13546,89 +15907,93 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "triggerOrder",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "orderId",
           "type": "u64",
           "index": false
-        },
-        {
-          "name": "buyTokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "sellTokenIndex",
-          "type": "u16",
-          "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "PerpTriggerOrderExecuteLog",
+      "fields": [
         {
-          "name": "buyAmount",
-          "type": "u64",
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sellAmount",
-          "type": "u64",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "makerFee",
-          "type": "u64",
+          "name": "triggerOrder",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "takerFee",
+          "name": "orderId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenPrice",
-          "type": "i128",
+          "name": "executor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sellTokenPrice",
-          "type": "i128",
+          "name": "oraclePriceLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "closed",
-          "type": "bool",
+          "name": "executorIncentive",
+          "type": "u64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "MarginTradeLog",
+      "fields": [
         {
-          "name": "displayPriceStyle",
-          "type": "u8",
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "intention",
-          "type": "u8",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tcsType",
-          "type": "u8",
+          "name": "swapProgram",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "startTimestamp",
-          "type": "u64",
+          "name": "tokenDetails",
+          "type": {
+            "vec": {
+              "defined": "MarginTradeTokenDetail"
+            }
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapCancelLog",
+      "name": "ReferralFeesClaimLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -13641,44 +16006,78 @@ export type MangoV4 = {
           "index": false
         },
         {
-          "name": "id",
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "perpMarketIndex",
+          "type": {
+            "option": "u16"
+          },
+          "index": false
+        },
+        {
+          "name": "feesUsed",
           "type": "u64",
           "index": false
+        },
+        {
+          "name": "amount",
+          "type": "i128",
+          "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapStartLog",
+      "name": "OracleRejectedLog",
       "fields": [
         {
-          "name": "mangoGroup",
+          "name": "oracle",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "mangoAccount",
-          "type": "publicKey",
+          "name": "oracleType",
+          "type": {
+            "defined": "OracleType"
+          },
           "index": false
         },
         {
-          "name": "caller",
-          "type": "publicKey",
+          "name": "price",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
-          "type": "u64",
+          "name": "deviation",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "incentiveTokenIndex",
-          "type": "u16",
+          "name": "lastUpdateSlot",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "incentiveAmount",
+          "name": "ageSlots",
           "type": "u64",
           "index": false
+        },
+        {
+          "name": "stale",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fallbackAvailable",
+          "type": "bool",
+          "index": false
         }
       ]
     }
@@ -14028,6 +16427,76 @@ export type MangoV4 = {
       "code": 6068,
       "name": "MissingFeedForCLMMOracle",
       "msg": "Pyth USDC/USD or SOL/USD feed not found (required by CLMM oracle)"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPythPriceUpdate",
+      "msg": "the provided pyth price update account is not valid"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidSwitchboardPullFeed",
+      "msg": "the provided switchboard pull feed account is not valid"
+    },
+    {
+      "code": 6071,
+      "name": "VaultBankMismatch",
+      "msg": "the vault does not belong to the bank"
+    },
+    {
+      "code": 6072,
+      "name": "MissingFlashLoanEnd",
+      "msg": "no FlashLoanEnd instruction found in the transaction"
+    },
+    {
+      "code": 6073,
+      "name": "CpiForbidden",
+      "msg": "the instruction may not be invoked via CPI"
+    },
+    {
+      "code": 6074,
+      "name": "HealthAccountBankMissing",
+      "msg": "a vault has no matching bank in the health accounts"
+    },
+    {
+      "code": 6075,
+      "name": "UnexpectedFlashLoanInstruction",
+      "msg": "instruction is not allowed between FlashLoanBegin and FlashLoanEnd"
+    },
+    {
+      "code": 6076,
+      "name": "PerpTriggerOrderExpired",
+      "msg": "the perp trigger order has expired"
+    },
+    {
+      "code": 6077,
+      "name": "PerpTriggerOrderNotTriggered",
+      "msg": "the oracle price has not reached the perp trigger order's trigger price"
+    },
+    {
+      "code": 6078,
+      "name": "SwapProgramNotAllowed",
+      "msg": "the swap program is not allowed"
+    },
+    {
+      "code": 6079,
+      "name": "IsolatedAccountPositionNotAllowed",
+      "msg": "the position is not allowed for this isolated account"
+    },
+    {
+      "code": 6080,
+      "name": "PerpPriceBandExceeded",
+      "msg": "the perp order price is too far from the oracle price"
+    },
+    {
+      "code": 6081,
+      "name": "GroupInWithdrawOnlyMode",
+      "msg": "group is in withdraw only mode"
+    },
+    {
+      "code": 6082,
+      "name": "PerpAdlCounterpartyNotOpposing",
+      "msg": "the auto-deleveraging counterparty's position does not oppose the liqee's"
     }
   ]
 };
@@ -14311,6 +16780,71 @@ export const IDL: MangoV4 = {
           "type": {
             "option": "u16"
           }
+        },
+        {
+          "name": "allowedSwapProgramsOpt",
+          "type": {
+            "option": {
+              "vec": "publicKey"
+            }
+          }
+        },
+        {
+          "name": "referralFeeShareOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "insuranceTokenIndexOpt",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "allowCpiDepositOpt",
+          "type": {
+            "option": "bool"
+          }
+        }
+      ]
+    },
+    {
+      "name": "groupDepositInsuranceFund",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "insurance_vault"
+          ]
+        },
+        {
+          "name": "insuranceVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
@@ -14373,7 +16907,18 @@ export const IDL: MangoV4 = {
           "name": "ixGate",
           "type": "u128"
         }
-      ]
+      ]
+    },
+    {
+      "name": "groupMigrate",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
       "name": "groupClose",
@@ -14808,6 +17353,12 @@ export const IDL: MangoV4 = {
         {
           "name": "name",
           "type": "string"
+        },
+        {
+          "name": "tier",
+          "type": {
+            "defined": "FastListingTier"
+          }
         }
       ]
     },
@@ -15075,6 +17626,48 @@ export const IDL: MangoV4 = {
           "type": {
             "option": "f32"
           }
+        },
+        {
+          "name": "keeperRewardPerHourOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "fallbackOracleMaxStablePriceDeviationOpt",
+          "type": {
+            "option": "f32"
+          }
+        },
+        {
+          "name": "fallbackOracleMinLiquidityOpt",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "haltOnVaultInvariantViolationOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "collateralOnlyOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "interestRateShiftHoursOpt",
+          "type": {
+            "option": "u32"
+          }
+        },
+        {
+          "name": "oracleTwapWindowSecondsOpt",
+          "type": {
+            "option": "u32"
+          }
         }
       ]
     },
@@ -15275,6 +17868,62 @@ export const IDL: MangoV4 = {
           "name": "instructions",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "keeper",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Account that receives the keeper reward, see Bank::keeper_reward_per_hour"
+          ],
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "tokenMigrate",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "tokenCheckVaultInvariant",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "vault"
+          ]
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -15330,6 +17979,18 @@ export const IDL: MangoV4 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The MangoAccount that referred the new account, see MangoAccount::referrer"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
@@ -15410,6 +18071,18 @@ export const IDL: MangoV4 = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "The MangoAccount that referred the new account, see MangoAccount::referrer"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
@@ -15627,9 +18300,77 @@ export const IDL: MangoV4 = {
           "type": {
             "option": "u64"
           }
+        },
+        {
+          "name": "isolatedOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "isolatedPerpMarketIndexOpt",
+          "type": {
+            "option": "u16"
+          }
+        },
+        {
+          "name": "isolatedTokenIndexesOpt",
+          "type": {
+            "option": {
+              "vec": "u16"
+            }
+          }
         }
       ]
     },
+    {
+      "name": "groupToggleWithdrawOnly",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawOnly",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "accountCreateDaoTokenPositions",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "daoAccount",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "accountToggleFreeze",
       "accounts": [
@@ -15762,6 +18503,62 @@ export const IDL: MangoV4 = {
         }
       ]
     },
+    {
+      "name": "accountClaimReferralFees",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The referred account"
+          ],
+          "relations": [
+            "group",
+            "referrer"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank the payout is deposited into.",
+            "",
+            "Without perp_market, the payout comes out of this bank's loan origination fees."
+          ],
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "If set, the payout comes out of this market's settled fees instead"
+          ],
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "stubOracleCreate",
       "accounts": [
@@ -16253,21 +19050,59 @@ export const IDL: MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "numLoans",
+          "type": "u8"
+        },
+        {
+          "name": "flashLoanType",
+          "type": {
+            "defined": "FlashLoanType"
+          }
+        }
+      ]
+    },
+    {
+      "name": "marginTrade",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "swapProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "numLoans",
+          "name": "numHealthAccounts",
           "type": "u8"
         },
         {
-          "name": "flashLoanType",
-          "type": {
-            "defined": "FlashLoanType"
-          }
+          "name": "cpiData",
+          "type": "bytes"
         }
       ]
     },
@@ -16476,6 +19311,12 @@ export const IDL: MangoV4 = {
           "type": {
             "option": "f32"
           }
+        },
+        {
+          "name": "crossingPriceBandOpt",
+          "type": {
+            "option": "f32"
+          }
         }
       ]
     },
@@ -16974,6 +19815,141 @@ export const IDL: MangoV4 = {
         }
       ]
     },
+    {
+      "name": "serum3PlaceOrders",
+      "docs": [
+        "Place several orders on one side of the book with a single health check,",
+        "requires the receiver_bank in the health account list to be writable"
+      ],
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "openOrders",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "serumMarket",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "serum_program",
+            "serum_market_external"
+          ]
+        },
+        {
+          "name": "serumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "serumMarketExternal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketBids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketAsks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketEventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketRequestQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketBaseVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketQuoteVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "marketVaultSigner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "needed for the automatic settle_funds call"
+          ]
+        },
+        {
+          "name": "payerBank",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank that pays for the order, if necessary"
+          ],
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "payerVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The bank vault that pays for the order, if necessary"
+          ]
+        },
+        {
+          "name": "payerOracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "side",
+          "type": {
+            "defined": "Serum3Side"
+          }
+        },
+        {
+          "name": "orders",
+          "type": {
+            "vec": {
+              "defined": "Serum3PlaceOrderArgs"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "serum3CancelOrder",
       "accounts": [
@@ -17221,7 +20197,7 @@ export const IDL: MangoV4 = {
         },
         {
           "name": "serumMarket",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group",
@@ -17326,7 +20302,7 @@ export const IDL: MangoV4 = {
             },
             {
               "name": "serumMarket",
-              "isMut": false,
+              "isMut": true,
               "isSigner": false,
               "relations": [
                 "group",
@@ -17441,7 +20417,7 @@ export const IDL: MangoV4 = {
         },
         {
           "name": "serumMarket",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group",
@@ -18211,114 +21187,52 @@ export const IDL: MangoV4 = {
           "type": {
             "option": "f32"
           }
-        }
-      ]
-    },
-    {
-      "name": "perpCloseMarket",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "admin"
-          ]
-        },
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "perpMarket",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "bids",
-            "asks",
-            "event_queue"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "eventQueue",
-          "isMut": true,
-          "isSigner": false
         },
         {
-          "name": "solDestination",
-          "isMut": true,
-          "isSigner": false
+          "name": "keeperRewardPerEventOpt",
+          "type": {
+            "option": "f32"
+          }
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "perpDeactivatePosition",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
+          "name": "keeperRewardFundingPerHourOpt",
+          "type": {
+            "option": "f32"
+          }
         },
         {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "oraclePriceBandOpt",
+          "type": {
+            "option": "f32"
+          }
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
+          "name": "fundingPeriodSecondsOpt",
+          "type": {
+            "option": "u32"
+          }
         },
         {
-          "name": "perpMarket",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "dustThresholdOpt",
+          "type": {
+            "option": "f32"
+          }
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "perpPlaceOrder",
+      "name": "perpCloseMarket",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "owner",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
@@ -18330,8 +21244,7 @@ export const IDL: MangoV4 = {
             "group",
             "bids",
             "asks",
-            "event_queue",
-            "oracle"
+            "event_queue"
           ]
         },
         {
@@ -18350,59 +21263,52 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "solDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "side",
-          "type": {
-            "defined": "Side"
-          }
-        },
-        {
-          "name": "priceLots",
-          "type": "i64"
-        },
-        {
-          "name": "maxBaseLots",
-          "type": "i64"
-        },
-        {
-          "name": "maxQuoteLots",
-          "type": "i64"
-        },
-        {
-          "name": "clientOrderId",
-          "type": "u64"
-        },
+      "args": []
+    },
+    {
+      "name": "perpDeactivatePosition",
+      "accounts": [
         {
-          "name": "orderType",
-          "type": {
-            "defined": "PlaceOrderType"
-          }
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "reduceOnly",
-          "type": "bool"
+          "name": "account",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "expiryTimestamp",
-          "type": "u64"
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "limit",
-          "type": "u8"
+          "name": "perpMarket",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
-      "returns": {
-        "option": "u128"
-      }
+      "args": []
     },
     {
-      "name": "perpPlaceOrderV2",
+      "name": "perpPlaceOrder",
       "accounts": [
         {
           "name": "group",
@@ -18484,12 +21390,6 @@ export const IDL: MangoV4 = {
             "defined": "PlaceOrderType"
           }
         },
-        {
-          "name": "selfTradeBehavior",
-          "type": {
-            "defined": "SelfTradeBehavior"
-          }
-        },
         {
           "name": "reduceOnly",
           "type": "bool"
@@ -18508,7 +21408,7 @@ export const IDL: MangoV4 = {
       }
     },
     {
-      "name": "perpPlaceOrderPegged",
+      "name": "perpPlaceOrderV2",
       "accounts": [
         {
           "name": "group",
@@ -18569,11 +21469,7 @@ export const IDL: MangoV4 = {
           }
         },
         {
-          "name": "priceOffsetLots",
-          "type": "i64"
-        },
-        {
-          "name": "pegLimit",
+          "name": "priceLots",
           "type": "i64"
         },
         {
@@ -18594,6 +21490,12 @@ export const IDL: MangoV4 = {
             "defined": "PlaceOrderType"
           }
         },
+        {
+          "name": "selfTradeBehavior",
+          "type": {
+            "defined": "SelfTradeBehavior"
+          }
+        },
         {
           "name": "reduceOnly",
           "type": "bool"
@@ -18605,10 +21507,6 @@ export const IDL: MangoV4 = {
         {
           "name": "limit",
           "type": "u8"
-        },
-        {
-          "name": "maxOracleStalenessSlots",
-          "type": "i32"
         }
       ],
       "returns": {
@@ -18616,7 +21514,7 @@ export const IDL: MangoV4 = {
       }
     },
     {
-      "name": "perpPlaceOrderPeggedV2",
+      "name": "perpPlaceOrderPegged",
       "accounts": [
         {
           "name": "group",
@@ -18702,12 +21600,6 @@ export const IDL: MangoV4 = {
             "defined": "PlaceOrderType"
           }
         },
-        {
-          "name": "selfTradeBehavior",
-          "type": {
-            "defined": "SelfTradeBehavior"
-          }
-        },
         {
           "name": "reduceOnly",
           "type": "bool"
@@ -18724,160 +21616,13 @@ export const IDL: MangoV4 = {
           "name": "maxOracleStalenessSlots",
           "type": "i32"
         }
-      ],
-      "returns": {
-        "option": "u128"
-      }
-    },
-    {
-      "name": "perpCancelOrder",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "perpMarket",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "bids",
-            "asks"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "orderId",
-          "type": "u128"
-        }
-      ]
-    },
-    {
-      "name": "perpCancelOrderByClientOrderId",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "perpMarket",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "bids",
-            "asks"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "clientOrderId",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "perpCancelAllOrders",
-      "accounts": [
-        {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "perpMarket",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "bids",
-            "asks"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "limit",
-          "type": "u8"
-        }
-      ]
+      ],
+      "returns": {
+        "option": "u128"
+      }
     },
     {
-      "name": "perpCancelAllOrdersBySide",
+      "name": "perpPlaceOrderPeggedV2",
       "accounts": [
         {
           "name": "group",
@@ -18904,7 +21649,9 @@ export const IDL: MangoV4 = {
           "relations": [
             "group",
             "bids",
-            "asks"
+            "asks",
+            "event_queue",
+            "oracle"
           ]
         },
         {
@@ -18916,25 +21663,84 @@ export const IDL: MangoV4 = {
           "name": "asks",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "sideOption",
+          "name": "side",
           "type": {
-            "option": {
-              "defined": "Side"
-            }
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "priceOffsetLots",
+          "type": "i64"
+        },
+        {
+          "name": "pegLimit",
+          "type": "i64"
+        },
+        {
+          "name": "maxBaseLots",
+          "type": "i64"
+        },
+        {
+          "name": "maxQuoteLots",
+          "type": "i64"
+        },
+        {
+          "name": "clientOrderId",
+          "type": "u64"
+        },
+        {
+          "name": "orderType",
+          "type": {
+            "defined": "PlaceOrderType"
+          }
+        },
+        {
+          "name": "selfTradeBehavior",
+          "type": {
+            "defined": "SelfTradeBehavior"
           }
         },
+        {
+          "name": "reduceOnly",
+          "type": "bool"
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
         {
           "name": "limit",
           "type": "u8"
+        },
+        {
+          "name": "maxOracleStalenessSlots",
+          "type": "i32"
         }
-      ]
+      ],
+      "returns": {
+        "option": "u128"
+      }
     },
     {
-      "name": "perpConsumeEvents",
+      "name": "perpModifyOrder",
+      "docs": [
+        "Cancel the order with cancel_client_order_id and place a new order, like",
+        "perp_place_order_v2, with a single health check."
+      ],
       "accounts": [
         {
           "name": "group",
@@ -18942,34 +21748,17 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "perpMarket",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "event_queue"
+            "group"
           ]
         },
         {
-          "name": "eventQueue",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "limit",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "perpUpdateFunding",
-      "accounts": [
-        {
-          "name": "group",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "perpMarket",
@@ -18979,6 +21768,7 @@ export const IDL: MangoV4 = {
             "group",
             "bids",
             "asks",
+            "event_queue",
             "oracle"
           ]
         },
@@ -18992,83 +21782,80 @@ export const IDL: MangoV4 = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "oracle",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
-    },
-    {
-      "name": "perpSettlePnl",
-      "accounts": [
+      "args": [
         {
-          "name": "group",
-          "isMut": false,
-          "isSigner": false
+          "name": "cancelClientOrderId",
+          "type": "u64"
         },
         {
-          "name": "settler",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
         },
         {
-          "name": "settlerOwner",
-          "isMut": false,
-          "isSigner": true
+          "name": "priceLots",
+          "type": "i64"
         },
         {
-          "name": "perpMarket",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "oracle"
-          ]
+          "name": "maxBaseLots",
+          "type": "i64"
         },
         {
-          "name": "accountA",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "maxQuoteLots",
+          "type": "i64"
         },
         {
-          "name": "accountB",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "clientOrderId",
+          "type": "u64"
         },
         {
-          "name": "oracle",
-          "isMut": false,
-          "isSigner": false
+          "name": "orderType",
+          "type": {
+            "defined": "PlaceOrderType"
+          }
         },
         {
-          "name": "settleBank",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "name": "selfTradeBehavior",
+          "type": {
+            "defined": "SelfTradeBehavior"
+          }
         },
         {
-          "name": "settleOracle",
-          "isMut": false,
-          "isSigner": false
+          "name": "reduceOnly",
+          "type": "bool"
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "limit",
+          "type": "u8"
         }
       ],
-      "args": []
+      "returns": {
+        "option": "u128"
+      }
     },
     {
-      "name": "perpForceClosePosition",
+      "name": "perpPlaceOrders",
+      "docs": [
+        "Place several orders, like perp_place_order_v2, with a single health check.",
+        "",
+        "Already expired orders are skipped."
+      ],
       "accounts": [
         {
           "name": "group",
@@ -19076,29 +21863,44 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "perpMarket",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "oracle"
+            "group"
           ]
         },
         {
-          "name": "accountA",
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "bids",
+            "asks",
+            "event_queue",
+            "oracle"
           ]
         },
         {
-          "name": "accountB",
+          "name": "bids",
           "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "oracle",
@@ -19106,25 +21908,29 @@ export const IDL: MangoV4 = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "orders",
+          "type": {
+            "vec": {
+              "defined": "PerpPlaceOrderArgs"
+            }
+          }
+        },
+        {
+          "name": "limit",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "perpSettleFees",
+      "name": "perpCancelOrder",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "perpMarket",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group",
-            "oracle"
-          ]
-        },
         {
           "name": "account",
           "isMut": true,
@@ -19134,33 +21940,40 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "oracle",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "settleBank",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "bids",
+            "asks"
           ]
         },
         {
-          "name": "settleOracle",
-          "isMut": false,
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "maxSettleAmount",
-          "type": "u64"
+          "name": "orderId",
+          "type": "u128"
         }
       ]
     },
     {
-      "name": "perpLiqBaseOrPositivePnl",
+      "name": "perpCancelOrderByClientOrderId",
       "accounts": [
         {
           "name": "group",
@@ -19168,34 +21981,56 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "perpMarket",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "oracle"
+            "group"
           ]
         },
         {
-          "name": "oracle",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "liqor",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "bids",
+            "asks"
           ]
         },
         {
-          "name": "liqorOwner",
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "clientOrderId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "perpCancelAllOrders",
+      "accounts": [
+        {
+          "name": "group",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "liqee",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19203,37 +22038,40 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "settleBank",
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "bids",
+            "asks"
           ]
         },
         {
-          "name": "settleVault",
+          "name": "bids",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "settleOracle",
-          "isMut": false,
+          "name": "asks",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "maxBaseTransfer",
-          "type": "i64"
-        },
-        {
-          "name": "maxPnlTransfer",
-          "type": "u64"
+          "name": "limit",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "perpLiqForceCancelOrders",
+      "name": "perpCancelAllOrdersBySide",
       "accounts": [
         {
           "name": "group",
@@ -19248,6 +22086,11 @@ export const IDL: MangoV4 = {
             "group"
           ]
         },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
         {
           "name": "perpMarket",
           "isMut": true,
@@ -19270,6 +22113,14 @@ export const IDL: MangoV4 = {
         }
       ],
       "args": [
+        {
+          "name": "sideOption",
+          "type": {
+            "option": {
+              "defined": "Side"
+            }
+          }
+        },
         {
           "name": "limit",
           "type": "u8"
@@ -19277,36 +22128,54 @@ export const IDL: MangoV4 = {
       ]
     },
     {
-      "name": "perpLiqNegativePnlOrBankruptcy",
+      "name": "perpConsumeEvents",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "insurance_vault"
-          ]
+          "isSigner": false
         },
         {
-          "name": "liqor",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "event_queue"
           ]
         },
         {
-          "name": "liqorOwner",
-          "isMut": false,
-          "isSigner": true
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "liqee",
+          "name": "keeper",
           "isMut": true,
           "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Account that receives the keeper reward, see PerpMarket::keeper_reward_per_event"
+          ],
           "relations": [
             "group"
           ]
+        }
+      ],
+      "args": [
+        {
+          "name": "limit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "perpUpdateFunding",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "perpMarket",
@@ -19314,63 +22183,51 @@ export const IDL: MangoV4 = {
           "isSigner": false,
           "relations": [
             "group",
+            "bids",
+            "asks",
             "oracle"
           ]
         },
         {
-          "name": "oracle",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "settleBank",
+          "name": "bids",
           "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
         },
         {
-          "name": "settleVault",
+          "name": "asks",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "settleOracle",
+          "name": "oracle",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "insuranceVault",
+          "name": "keeper",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Account that receives the keeper reward, see PerpMarket::keeper_reward_funding_per_hour"
+          ],
+          "relations": [
+            "group"
+          ]
         }
       ],
-      "args": [
-        {
-          "name": "maxLiabTransfer",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "perpLiqNegativePnlOrBankruptcyV2",
+      "name": "perpSettlePnl",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "insurance_vault"
-          ]
+          "isSigner": false
         },
         {
-          "name": "liqor",
+          "name": "settler",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19378,12 +22235,21 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "liqorOwner",
+          "name": "settlerOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "liqee",
+          "name": "perpMarket",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
+        {
+          "name": "accountA",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19391,12 +22257,11 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "perpMarket",
+          "name": "accountB",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "oracle"
+            "group"
           ]
         },
         {
@@ -19412,23 +22277,24 @@ export const IDL: MangoV4 = {
             "group"
           ]
         },
-        {
-          "name": "settleVault",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "settleOracle",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "perpSettlePnlBatch",
+      "accounts": [
         {
-          "name": "insuranceVault",
-          "isMut": true,
+          "name": "group",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "insuranceBank",
+          "name": "settler",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19436,30 +22302,47 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "insuranceBankVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "settlerOwner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "insuranceOracle",
+          "name": "perpMarket",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
+        {
+          "name": "oracle",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "settleOracle",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "maxLiabTransfer",
-          "type": "u64"
+          "name": "numPairs",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapCreate",
+      "name": "perpForceClosePosition",
       "accounts": [
         {
           "name": "group",
@@ -19467,78 +22350,55 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "oracle"
           ]
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "buyBank",
-          "isMut": false,
+          "name": "accountA",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "sellBank",
-          "isMut": false,
+          "name": "accountB",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
-        },
-        {
-          "name": "expiryTimestamp",
-          "type": "u64"
-        },
-        {
-          "name": "priceLowerLimit",
-          "type": "f64"
-        },
-        {
-          "name": "priceUpperLimit",
-          "type": "f64"
-        },
-        {
-          "name": "pricePremiumRate",
-          "type": "f64"
-        },
-        {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
         },
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "tokenConditionalSwapCreateV2",
+      "name": "perpSettleFees",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
         {
           "name": "account",
           "isMut": true,
@@ -19548,76 +22408,33 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "authority",
+          "name": "oracle",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "buyBank",
-          "isMut": false,
+          "name": "settleBank",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "sellBank",
+          "name": "settleOracle",
           "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
-        },
-        {
-          "name": "expiryTimestamp",
+          "name": "maxSettleAmount",
           "type": "u64"
-        },
-        {
-          "name": "priceLowerLimit",
-          "type": "f64"
-        },
-        {
-          "name": "priceUpperLimit",
-          "type": "f64"
-        },
-        {
-          "name": "pricePremiumRate",
-          "type": "f64"
-        },
-        {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
-        },
-        {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
-        },
-        {
-          "name": "displayPriceStyle",
-          "type": {
-            "defined": "TokenConditionalSwapDisplayPriceStyle"
-          }
-        },
-        {
-          "name": "intention",
-          "type": {
-            "defined": "TokenConditionalSwapIntention"
-          }
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapCreatePremiumAuction",
+      "name": "perpLiqBaseOrPositivePnl",
       "accounts": [
         {
           "name": "group",
@@ -19625,88 +22442,72 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "group",
+            "oracle"
           ]
         },
         {
-          "name": "authority",
+          "name": "oracle",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "buyBank",
-          "isMut": false,
+          "name": "liqor",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "sellBank",
+          "name": "liqorOwner",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "liqee",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "maxBuy",
-          "type": "u64"
-        },
-        {
-          "name": "maxSell",
-          "type": "u64"
-        },
-        {
-          "name": "expiryTimestamp",
-          "type": "u64"
-        },
-        {
-          "name": "priceLowerLimit",
-          "type": "f64"
-        },
-        {
-          "name": "priceUpperLimit",
-          "type": "f64"
-        },
-        {
-          "name": "maxPricePremiumRate",
-          "type": "f64"
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
+          "name": "settleVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "displayPriceStyle",
-          "type": {
-            "defined": "TokenConditionalSwapDisplayPriceStyle"
-          }
-        },
+          "name": "settleOracle",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
-          "name": "intention",
-          "type": {
-            "defined": "TokenConditionalSwapIntention"
-          }
+          "name": "maxBaseTransfer",
+          "type": "i64"
         },
         {
-          "name": "durationSeconds",
+          "name": "maxPnlTransfer",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapCreateLinearAuction",
+      "name": "perpLiqCombined",
       "accounts": [
         {
           "name": "group",
@@ -19714,7 +22515,33 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle",
+            "bids",
+            "asks"
+          ]
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "liqor",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19722,74 +22549,68 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "authority",
+          "name": "liqorOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "buyBank",
-          "isMut": false,
+          "name": "liqee",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "sellBank",
-          "isMut": false,
+          "name": "settleBank",
+          "isMut": true,
           "isSigner": false,
           "relations": [
             "group"
           ]
+        },
+        {
+          "name": "settleVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settleOracle",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "maxBuy",
-          "type": "u64"
+          "name": "limit",
+          "type": "u8"
         },
         {
-          "name": "maxSell",
-          "type": "u64"
+          "name": "maxBaseTransfer",
+          "type": "i64"
         },
         {
-          "name": "expiryTimestamp",
+          "name": "maxPnlTransfer",
           "type": "u64"
         },
         {
-          "name": "priceStart",
-          "type": "f64"
-        },
-        {
-          "name": "priceEnd",
-          "type": "f64"
-        },
-        {
-          "name": "allowCreatingDeposits",
-          "type": "bool"
+          "name": "assetTokenIndex",
+          "type": "u16"
         },
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool"
+          "name": "liabTokenIndex",
+          "type": "u16"
         },
         {
-          "name": "displayPriceStyle",
+          "name": "maxLiabTransfer",
           "type": {
-            "defined": "TokenConditionalSwapDisplayPriceStyle"
+            "defined": "I80F48"
           }
-        },
-        {
-          "name": "startTimestamp",
-          "type": "u64"
-        },
-        {
-          "name": "durationSeconds",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapCancel",
+      "name": "perpLiqForceCancelOrders",
       "accounts": [
         {
           "name": "group",
@@ -19805,51 +22626,46 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "buyBank",
+          "name": "perpMarket",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "The bank's token_index is checked at #1"
-          ],
           "relations": [
-            "group"
+            "group",
+            "bids",
+            "asks"
           ]
         },
         {
-          "name": "sellBank",
+          "name": "bids",
           "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "tokenConditionalSwapIndex",
+          "name": "limit",
           "type": "u8"
-        },
-        {
-          "name": "tokenConditionalSwapId",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapTrigger",
+      "name": "perpLiqNegativePnlOrBankruptcy",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "insurance_vault"
+          ]
         },
         {
-          "name": "liqee",
+          "name": "liqor",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19857,7 +22673,12 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "liqor",
+          "name": "liqorOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "liqee",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19865,44 +22686,64 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "liqorAuthority",
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
+        {
+          "name": "oracle",
           "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": [
+          "isSigner": false
+        },
         {
-          "name": "tokenConditionalSwapIndex",
-          "type": "u8"
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "tokenConditionalSwapId",
-          "type": "u64"
+          "name": "settleVault",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "maxBuyTokenToLiqee",
-          "type": "u64"
+          "name": "settleOracle",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "maxSellTokenToLiqor",
+          "name": "insuranceVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxLiabTransfer",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "tokenConditionalSwapTriggerV2",
+      "name": "perpLiqNegativePnlOrBankruptcyV2",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "liqee",
-          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "insurance_vault"
           ]
         },
         {
@@ -19914,56 +22755,57 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "liqorAuthority",
+          "name": "liqorOwner",
           "isMut": false,
           "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "tokenConditionalSwapIndex",
-          "type": "u8"
         },
         {
-          "name": "tokenConditionalSwapId",
-          "type": "u64"
+          "name": "liqee",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "maxBuyTokenToLiqee",
-          "type": "u64"
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
         },
         {
-          "name": "maxSellTokenToLiqor",
-          "type": "u64"
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "minBuyToken",
-          "type": "u64"
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "minTakerPrice",
-          "type": "f32"
-        }
-      ]
-    },
-    {
-      "name": "tokenConditionalSwapStart",
-      "accounts": [
+          "name": "settleVault",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "group",
+          "name": "settleOracle",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "liqee",
+          "name": "insuranceVault",
           "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
         },
         {
-          "name": "liqor",
+          "name": "insuranceBank",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -19971,102 +22813,117 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "liqorAuthority",
+          "name": "insuranceBankVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "insuranceOracle",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "tokenConditionalSwapIndex",
-          "type": "u8"
-        },
-        {
-          "name": "tokenConditionalSwapId",
+          "name": "maxLiabTransfer",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "altSet",
+      "name": "perpLiqAutoDeleverage",
       "accounts": [
         {
           "name": "group",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
-            "admin"
+            "insurance_vault"
           ]
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "addressLookupTable",
+          "name": "perpMarket",
           "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "index",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "altExtend",
-      "accounts": [
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
         {
-          "name": "group",
+          "name": "oracle",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "liqee",
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "admin"
+            "group"
           ]
         },
         {
-          "name": "admin",
+          "name": "counterparty",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "insuranceVault",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "payer",
+          "name": "insuranceBank",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "addressLookupTable",
-          "isMut": true,
+          "name": "insuranceOracle",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "index",
-          "type": "u8"
-        },
-        {
-          "name": "newAddresses",
-          "type": {
-            "vec": "publicKey"
-          }
+          "name": "maxBaseTransfer",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "computeAccountData",
-      "docs": [
-        "Warning, this instruction is for testing purposes only!"
-      ],
+      "name": "perpUpdateAdlCandidate",
       "accounts": [
         {
           "name": "group",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "oracle"
+          ]
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "account",
           "isMut": false,
@@ -20079,62 +22936,36 @@ export const IDL: MangoV4 = {
       "args": []
     },
     {
-      "name": "openbookV2RegisterMarket",
-      "docs": [
-        "",
-        "OpenbookV2",
-        ""
-      ],
+      "name": "perpTriggerOrderCreate",
       "accounts": [
         {
           "name": "group",
-          "isMut": true,
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
+          "isMut": false,
           "isSigner": false,
           "relations": [
-            "admin"
+            "group"
           ]
         },
         {
-          "name": "admin",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
+          "name": "perpMarket",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
-          "isMut": true,
           "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "OpenbookV2Market"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "group"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "openbook_v2_market_external"
-              }
-            ]
-          }
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "indexReservation",
+          "name": "triggerOrder",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -20142,37 +22973,21 @@ export const IDL: MangoV4 = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "OpenbookV2Index"
+                "value": "PerpTriggerOrder"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "group"
+                "path": "account"
               },
               {
                 "kind": "arg",
-                "type": "u16",
-                "path": "market_index"
+                "type": "u64",
+                "path": "order_id"
               }
             ]
           }
         },
-        {
-          "name": "quoteBank",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
-        {
-          "name": "baseBank",
-          "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
-        },
         {
           "name": "payer",
           "isMut": true,
@@ -20186,17 +23001,53 @@ export const IDL: MangoV4 = {
       ],
       "args": [
         {
-          "name": "marketIndex",
-          "type": "u16"
+          "name": "orderId",
+          "type": "u64"
         },
         {
-          "name": "name",
-          "type": "string"
+          "name": "side",
+          "type": {
+            "defined": "Side"
+          }
+        },
+        {
+          "name": "triggerCondition",
+          "type": {
+            "defined": "PerpTriggerCondition"
+          }
+        },
+        {
+          "name": "triggerPriceLots",
+          "type": "i64"
+        },
+        {
+          "name": "priceLots",
+          "type": "i64"
+        },
+        {
+          "name": "maxBaseLots",
+          "type": "i64"
+        },
+        {
+          "name": "maxQuoteLots",
+          "type": "i64"
+        },
+        {
+          "name": "reduceOnly",
+          "type": "bool"
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64"
+        },
+        {
+          "name": "executorIncentive",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "openbookV2EditMarket",
+      "name": "perpTriggerOrderCancel",
       "accounts": [
         {
           "name": "group",
@@ -20204,60 +23055,95 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "account",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        },
+        {
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "market",
+          "name": "triggerOrder",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "account"
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "reduceOnlyOpt",
-          "type": {
-            "option": "bool"
-          }
         },
         {
-          "name": "forceCloseOpt",
-          "type": {
-            "option": "bool"
-          }
+          "name": "solDestination",
+          "isMut": true,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "openbookV2DeregisterMarket",
+      "name": "perpTriggerOrderExecute",
+      "docs": [
+        "Permissionless: places the stored order once the oracle price reached the trigger price"
+      ],
       "accounts": [
         {
           "name": "group",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "admin"
+            "group"
           ]
         },
         {
-          "name": "admin",
+          "name": "perpMarket",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group",
+            "bids",
+            "asks",
+            "event_queue",
+            "oracle"
+          ]
+        },
+        {
+          "name": "bids",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asks",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "openbookV2Market",
+          "name": "triggerOrder",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "account"
           ]
         },
         {
-          "name": "indexReservation",
+          "name": "executorAccount",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -20265,20 +23151,28 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "solDestination",
+          "name": "executor",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+        {
+          "name": "settleBank",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "limit",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "openbookV2CreateOpenOrders",
+      "name": "tokenConditionalSwapCreate",
       "accounts": [
         {
           "name": "group",
@@ -20299,84 +23193,59 @@ export const IDL: MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "openbookV2Market",
+          "name": "buyBank",
           "isMut": false,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
         },
         {
-          "name": "openbookV2Program",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false
+          "name": "maxSell",
+          "type": "u64"
         },
         {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "OpenOrders"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "openbook_v2_market"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "openbook_v2_market_external"
-              },
-              {
-                "kind": "arg",
-                "type": "u32",
-                "path": "account_num"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "openbook_v2_program"
-            }
-          }
+          "name": "expiryTimestamp",
+          "type": "u64"
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "priceLowerLimit",
+          "type": "f64"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "priceUpperLimit",
+          "type": "f64"
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "pricePremiumRate",
+          "type": "f64"
+        },
         {
-          "name": "accountNum",
-          "type": "u32"
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "openbookV2CloseOpenOrders",
+      "name": "tokenConditionalSwapCreateV2",
       "accounts": [
         {
           "name": "group",
@@ -20397,40 +23266,71 @@ export const IDL: MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "openbookV2Market",
+          "name": "buyBank",
           "isMut": false,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
         },
         {
-          "name": "openbookV2Program",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
-          "isSigner": false
+          "name": "maxSell",
+          "type": "u64"
         },
         {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
+          "name": "expiryTimestamp",
+          "type": "u64"
         },
         {
-          "name": "solDestination",
-          "isMut": true,
-          "isSigner": false
+          "name": "priceLowerLimit",
+          "type": "f64"
+        },
+        {
+          "name": "priceUpperLimit",
+          "type": "f64"
+        },
+        {
+          "name": "pricePremiumRate",
+          "type": "f64"
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": {
+            "defined": "TokenConditionalSwapDisplayPriceStyle"
+          }
+        },
+        {
+          "name": "intention",
+          "type": {
+            "defined": "TokenConditionalSwapIntention"
+          }
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "openbookV2PlaceOrder",
+      "name": "tokenConditionalSwapCreatePremiumAuction",
       "accounts": [
         {
           "name": "group",
@@ -20451,127 +23351,158 @@ export const IDL: MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
+          "name": "buyBank",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "openbookV2Program",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": true,
           "isSigner": false,
           "relations": [
-            "bids",
-            "asks",
-            "event_heap"
+            "group"
           ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
+          "name": "maxSell",
+          "type": "u64"
         },
         {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
+          "name": "expiryTimestamp",
+          "type": "u64"
         },
         {
-          "name": "eventHeap",
-          "isMut": true,
-          "isSigner": false
+          "name": "priceLowerLimit",
+          "type": "f64"
         },
         {
-          "name": "marketBaseVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "priceUpperLimit",
+          "type": "f64"
         },
         {
-          "name": "marketQuoteVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "maxPricePremiumRate",
+          "type": "f64"
         },
         {
-          "name": "marketVaultSigner",
+          "name": "allowCreatingDeposits",
+          "type": "bool"
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool"
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": {
+            "defined": "TokenConditionalSwapDisplayPriceStyle"
+          }
+        },
+        {
+          "name": "intention",
+          "type": {
+            "defined": "TokenConditionalSwapIntention"
+          }
+        },
+        {
+          "name": "durationSeconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "tokenConditionalSwapCreateLinearAuction",
+      "accounts": [
+        {
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerBank",
+          "name": "account",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "The bank that pays for the order, if necessary"
-          ],
           "relations": [
             "group"
           ]
         },
         {
-          "name": "payerVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The bank vault that pays for the order, if necessary"
-          ]
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "payerOracle",
+          "name": "buyBank",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "tokenProgram",
+          "name": "sellBank",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         }
       ],
       "args": [
         {
-          "name": "side",
-          "type": "u8"
+          "name": "maxBuy",
+          "type": "u64"
         },
         {
-          "name": "limitPrice",
+          "name": "maxSell",
           "type": "u64"
         },
         {
-          "name": "maxBaseQty",
+          "name": "expiryTimestamp",
           "type": "u64"
         },
         {
-          "name": "maxNativeQuoteQtyIncludingFees",
-          "type": "u64"
+          "name": "priceStart",
+          "type": "f64"
         },
         {
-          "name": "selfTradeBehavior",
-          "type": "u8"
+          "name": "priceEnd",
+          "type": "f64"
         },
         {
-          "name": "orderType",
-          "type": "u8"
+          "name": "allowCreatingDeposits",
+          "type": "bool"
         },
         {
-          "name": "clientOrderId",
+          "name": "allowCreatingBorrows",
+          "type": "bool"
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": {
+            "defined": "TokenConditionalSwapDisplayPriceStyle"
+          }
+        },
+        {
+          "name": "startTimestamp",
           "type": "u64"
         },
         {
-          "name": "limit",
-          "type": "u16"
+          "name": "durationSeconds",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "openbookV2PlaceTakerOrder",
+      "name": "tokenConditionalSwapCancel",
       "accounts": [
         {
           "name": "group",
@@ -20592,128 +23523,144 @@ export const IDL: MangoV4 = {
           "isSigner": true
         },
         {
-          "name": "openbookV2Market",
-          "isMut": false,
+          "name": "buyBank",
+          "isMut": true,
           "isSigner": false,
+          "docs": [
+            "The bank's token_index is checked at #1"
+          ],
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
+          "name": "sellBank",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "bids",
-            "asks",
-            "event_heap"
+            "group"
           ]
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenConditionalSwapIndex",
+          "type": "u8"
         },
         {
-          "name": "bids",
-          "isMut": true,
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "tokenConditionalSwapTrigger",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "asks",
+          "name": "liqee",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "eventHeap",
+          "name": "liqor",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
         },
         {
-          "name": "marketRequestQueue",
-          "isMut": true,
-          "isSigner": false
+          "name": "liqorAuthority",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenConditionalSwapIndex",
+          "type": "u8"
         },
         {
-          "name": "marketBaseVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
         },
         {
-          "name": "marketQuoteVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "maxBuyTokenToLiqee",
+          "type": "u64"
         },
         {
-          "name": "marketVaultSigner",
+          "name": "maxSellTokenToLiqor",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "tokenConditionalSwapTriggerV2",
+      "accounts": [
+        {
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerBank",
+          "name": "liqee",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "The bank that pays for the order, if necessary"
-          ],
           "relations": [
             "group"
           ]
         },
         {
-          "name": "payerVault",
+          "name": "liqor",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "The bank vault that pays for the order, if necessary"
+          "relations": [
+            "group"
           ]
         },
         {
-          "name": "payerOracle",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "liqorAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "side",
+          "name": "tokenConditionalSwapIndex",
           "type": "u8"
         },
         {
-          "name": "limitPrice",
+          "name": "tokenConditionalSwapId",
           "type": "u64"
         },
         {
-          "name": "maxBaseQty",
+          "name": "maxBuyTokenToLiqee",
           "type": "u64"
         },
         {
-          "name": "maxNativeQuoteQtyIncludingFees",
+          "name": "maxSellTokenToLiqor",
           "type": "u64"
         },
         {
-          "name": "selfTradeBehavior",
-          "type": "u8"
-        },
-        {
-          "name": "clientOrderId",
+          "name": "minBuyToken",
           "type": "u64"
         },
         {
-          "name": "limit",
-          "type": "u16"
+          "name": "minTakerPrice",
+          "type": "f32"
         }
       ]
     },
     {
-      "name": "openbookV2CancelOrder",
+      "name": "tokenConditionalSwapStart",
       "accounts": [
         {
           "name": "group",
@@ -20721,7 +23668,7 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "liqee",
           "isMut": true,
           "isSigner": false,
           "relations": [
@@ -20729,176 +23676,157 @@ export const IDL: MangoV4 = {
           ]
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "openOrders",
+          "name": "liqor",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2Market",
-          "isMut": false,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
+          "name": "liqorAuthority",
           "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "bids",
-            "asks"
-          ]
-        },
-        {
-          "name": "bids",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "side",
+          "name": "tokenConditionalSwapIndex",
           "type": "u8"
         },
         {
-          "name": "orderId",
-          "type": "u128"
+          "name": "tokenConditionalSwapId",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "openbookV2SettleFunds",
+      "name": "altSet",
       "accounts": [
         {
           "name": "group",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "account",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "authority",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "openOrders",
+          "name": "addressLookupTable",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "openbookV2Market",
-          "isMut": false,
+          "name": "index",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "altCreate",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "admin"
           ]
         },
         {
-          "name": "openbookV2Program",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "marketBaseVault",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "marketQuoteVault",
+          "name": "addressLookupTable",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marketVaultSigner",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "needed for the automatic settle_funds call"
-          ]
-        },
-        {
-          "name": "quoteBank",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "group"
-          ]
+          "isSigner": false
         },
         {
-          "name": "quoteVault",
-          "isMut": true,
+          "name": "addressLookupTableProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u8"
         },
         {
-          "name": "baseBank",
-          "isMut": true,
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "altExtend",
+      "accounts": [
+        {
+          "name": "group",
+          "isMut": false,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "baseVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "quoteOracle",
-          "isMut": false,
-          "isSigner": false
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "baseOracle",
-          "isMut": false,
+          "name": "addressLookupTable",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "feesToDao",
-          "type": "bool"
+          "name": "index",
+          "type": "u8"
+        },
+        {
+          "name": "newAddresses",
+          "type": {
+            "vec": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "openbookV2LiqForceCancelOrders",
+      "name": "computeAccountData",
+      "docs": [
+        "Warning, this instruction is for testing purposes only!"
+      ],
       "accounts": [
         {
           "name": "group",
@@ -20907,113 +23835,188 @@ export const IDL: MangoV4 = {
         },
         {
           "name": "account",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "computeAccountDataPage",
+      "docs": [
+        "Returns the account's health, equity and positions as paginated return data"
+      ],
+      "accounts": [
         {
-          "name": "openOrders",
-          "isMut": true,
+          "name": "group",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "openbookV2Market",
+          "name": "account",
           "isMut": false,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "openbookV2Program",
+          "name": "page",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "computeHealthBreakdown",
+      "docs": [
+        "Returns each position's contribution to init and maint health as paginated return data"
+      ],
+      "accounts": [
+        {
+          "name": "group",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "openbookV2MarketExternal",
+          "name": "account",
           "isMut": false,
           "isSigner": false,
           "relations": [
-            "bids",
-            "asks",
-            "event_heap"
+            "group"
           ]
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "bids",
+          "name": "page",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "openbookV2RegisterMarket",
+      "docs": [
+        "",
+        "OpenbookV2",
+        ""
+      ],
+      "accounts": [
+        {
+          "name": "group",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "relations": [
+            "admin"
+          ]
         },
         {
-          "name": "asks",
-          "isMut": true,
-          "isSigner": false
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "eventHeap",
-          "isMut": true,
+          "name": "openbookV2Program",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "marketBaseVault",
-          "isMut": true,
+          "name": "openbookV2MarketExternal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "marketQuoteVault",
+          "name": "openbookV2Market",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "OpenbookV2Market"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "group"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "openbook_v2_market_external"
+              }
+            ]
+          }
         },
         {
-          "name": "marketVaultSigner",
-          "isMut": false,
-          "isSigner": false
+          "name": "indexReservation",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "OpenbookV2Index"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "group"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "market_index"
+              }
+            ]
+          }
         },
         {
           "name": "quoteBank",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
-        {
-          "name": "quoteVault",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "baseBank",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "relations": [
             "group"
           ]
         },
         {
-          "name": "baseVault",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "limit",
-          "type": "u8"
+          "name": "marketIndex",
+          "type": "u16"
+        },
+        {
+          "name": "name",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "openbookV2CancelAllOrders",
+      "name": "openbookV2EditMarket",
       "accounts": [
         {
           "name": "group",
@@ -21021,538 +24024,1290 @@ export const IDL: MangoV4 = {
           "isSigner": false
         },
         {
-          "name": "account",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "market",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "group"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reduceOnlyOpt",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "forceCloseOpt",
+          "type": {
+            "option": "bool"
+          }
+        }
+      ]
+    },
+    {
+      "name": "openbookV2DeregisterMarket",
+      "accounts": [
+        {
+          "name": "group",
           "isMut": true,
           "isSigner": false,
           "relations": [
-            "group"
+            "admin"
           ]
         },
         {
-          "name": "authority",
+          "name": "admin",
           "isMut": false,
           "isSigner": true
         },
-        {
-          "name": "openOrders",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "openbookV2Market",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "group",
-            "openbook_v2_program",
-            "openbook_v2_market_external"
+            "group"
           ]
         },
         {
-          "name": "openbookV2Program",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "openbookV2MarketExternal",
-          "isMut": false,
+          "name": "indexReservation",
+          "isMut": true,
           "isSigner": false,
           "relations": [
-            "bids",
-            "asks"
+            "group"
           ]
         },
         {
-          "name": "bids",
+          "name": "solDestination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "asks",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "benchmark",
+      "docs": [
+        "",
+        "benchmark",
+        ""
+      ],
+      "accounts": [
         {
-          "name": "limit",
-          "type": "u8"
+          "name": "dummy",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bank",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "type": "publicKey"
+          },
+          {
+            "name": "name",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "oracleConfig",
+            "type": {
+              "defined": "OracleConfig"
+            }
+          },
+          {
+            "name": "stablePriceModel",
+            "type": {
+              "defined": "StablePriceModel"
+            }
+          },
+          {
+            "name": "depositIndex",
+            "docs": [
+              "the index used to scale the value of an IndexedPosition",
+              "TODO: should always be >= 0, add checks?"
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "borrowIndex",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "indexedDeposits",
+            "docs": [
+              "deposits/borrows for this bank",
+              "",
+              "Note that these may become negative. It's perfectly fine for users to borrow one one bank",
+              "(increasing indexed_borrows there) and paying back on another (possibly decreasing indexed_borrows",
+              "below zero).",
+              "",
+              "The vault amount is not deducable from these values.",
+              "",
+              "These become meaningful when summed over all banks (like in update_index_and_rate)."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "indexedBorrows",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "indexLastUpdated",
+            "type": "u64"
+          },
+          {
+            "name": "bankRateLastUpdated",
+            "type": "u64"
+          },
+          {
+            "name": "avgUtilization",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "adjustmentFactor",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "util0",
+            "docs": [
+              "The unscaled borrow interest curve is defined as continuous piecewise linear with the points:",
+              "",
+              "- 0% util: zero_util_rate",
+              "- util0% util: rate0",
+              "- util1% util: rate1",
+              "- 100% util: max_rate",
+              "",
+              "The final rate is this unscaled curve multiplied by interest_curve_scaling."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "rate0",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "util1",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "rate1",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maxRate",
+            "docs": [
+              "the 100% utilization rate",
+              "",
+              "This isn't the max_rate, since this still gets scaled by interest_curve_scaling,",
+              "which is >=1."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "collectedFeesNative",
+            "docs": [
+              "Fees collected over the lifetime of the bank",
+              "",
+              "See fees_withdrawn for how much of the fees was withdrawn.",
+              "See collected_liquidation_fees for the (included) subtotal for liquidation related fees."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "loanOriginationFeeRate",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "loanFeeRate",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maintAssetWeight",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "initAssetWeight",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maintLiabWeight",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "initLiabWeight",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "liquidationFee",
+            "docs": [
+              "Liquidation fee that goes to the liqor.",
+              "",
+              "Liquidation always involves two tokens, and the sum of the two configured fees is used.",
+              "",
+              "A fraction of the price, like 0.05 for a 5% fee during liquidation.",
+              "",
+              "See also platform_liquidation_fee."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "dust",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "flashLoanTokenAccountInitial",
+            "type": "u64"
+          },
+          {
+            "name": "flashLoanApprovedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "mintDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "bankNum",
+            "type": "u32"
+          },
+          {
+            "name": "minVaultToDepositsRatio",
+            "docs": [
+              "The maximum utilization allowed when borrowing is 1-this value",
+              "WARNING: Outdated name, kept for IDL compatibility"
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "netBorrowLimitWindowSizeTs",
+            "docs": [
+              "Size in seconds of a net borrows window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastNetBorrowsWindowStartTs",
+            "docs": [
+              "Timestamp at which the last net borrows window started"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "netBorrowLimitPerWindowQuote",
+            "docs": [
+              "Net borrow limit per window in quote native; set to -1 to disable."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "netBorrowsInWindow",
+            "docs": [
+              "Sum of all deposits and borrows in the last window, in native units."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "borrowWeightScaleStartQuote",
+            "docs": [
+              "Soft borrow limit in native quote",
+              "",
+              "Once the borrows on the bank exceed this quote value, init_liab_weight is scaled up.",
+              "Set to f64::MAX to disable.",
+              "",
+              "See scaled_init_liab_weight()."
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "depositWeightScaleStartQuote",
+            "docs": [
+              "Limit for collateral of deposits in native quote",
+              "",
+              "Once the deposits in the bank exceed this quote value, init_asset_weight is scaled",
+              "down to keep the total collateral value constant.",
+              "Set to f64::MAX to disable.",
+              "",
+              "See scaled_init_asset_weight()."
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "reduceOnly",
+            "type": "u8"
+          },
+          {
+            "name": "forceClose",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "feesWithdrawn",
+            "type": "u64"
+          },
+          {
+            "name": "tokenConditionalSwapTakerFeeRate",
+            "docs": [
+              "Fees for the token conditional swap feature"
+            ],
+            "type": "f32"
+          },
+          {
+            "name": "tokenConditionalSwapMakerFeeRate",
+            "type": "f32"
+          },
+          {
+            "name": "flashLoanSwapFeeRate",
+            "type": "f32"
+          },
+          {
+            "name": "interestTargetUtilization",
+            "docs": [
+              "Target utilization: If actual utilization is higher, scale up interest.",
+              "If it's lower, scale down interest (if possible)"
+            ],
+            "type": "f32"
+          },
+          {
+            "name": "interestCurveScaling",
+            "docs": [
+              "Current interest curve scaling, always >= 1.0",
+              "",
+              "Except when first migrating to having this field, then 0.0"
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "potentialSerumTokens",
+            "docs": [
+              "Largest amount of tokens that might be added the the bank based on",
+              "serum open order execution."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maintWeightShiftStart",
+            "docs": [
+              "Start timestamp in seconds at which maint weights should start to change away",
+              "from maint_asset_weight, maint_liab_weight towards _asset_target and _liab_target.",
+              "If _start and _end and _duration_inv are 0, no shift is configured."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maintWeightShiftEnd",
+            "docs": [
+              "End timestamp in seconds until which the maint weights should reach the configured targets."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maintWeightShiftDurationInv",
+            "docs": [
+              "Cache of the inverse of maint_weight_shift_end - maint_weight_shift_start,",
+              "or zero if no shift is configured"
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maintWeightShiftAssetTarget",
+            "docs": [
+              "Maint asset weight to reach at _shift_end."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maintWeightShiftLiabTarget",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "fallbackOracle",
+            "docs": [
+              "Oracle that may be used if the main oracle is stale or not confident enough.",
+              "If this is Pubkey::default(), no fallback is available."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "depositLimit",
+            "docs": [
+              "zero means none, in token native"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "zeroUtilRate",
+            "docs": [
+              "The unscaled borrow interest curve point for zero utilization.",
+              "",
+              "See util0, rate0, util1, rate1, max_rate"
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "platformLiquidationFee",
+            "docs": [
+              "Additional to liquidation_fee, but goes to the group owner instead of the liqor"
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "collectedLiquidationFees",
+            "docs": [
+              "Platform fees that were collected during liquidation (in native tokens)",
+              "",
+              "See also collected_fees_native and fees_withdrawn."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "keeperRewardPerHour",
+            "docs": [
+              "Reward (in native tokens) paid out of collected fees to the keeper of",
+              "token_update_index_and_rate, pro-rated by the time since the last update",
+              "and capped at one hour."
+            ],
+            "type": "f32"
+          },
+          {
+            "name": "fallbackOracleMaxStablePriceDeviation",
+            "docs": [
+              "Max relative deviation of the fallback oracle price from the stable price",
+              "for the fallback to be used. Zero means no check."
+            ],
+            "type": "f32"
+          },
+          {
+            "name": "fallbackOracleMinLiquidity",
+            "docs": [
+              "Min in-range liquidity of a CLMM pool fallback oracle for it to be used.",
+              "Zero means no check."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "haltOnVaultInvariantViolation",
+            "docs": [
+              "If set, a vault invariant violation found by token_check_vault_invariant",
+              "puts the bank into reduce-only mode."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "collateralOnly",
+            "docs": [
+              "Deposits count as collateral, but the token can never be borrowed.",
+              "",
+              "Unlike reduce_only this is a listing property and not a temporary state."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding2",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "interestRateShiftEnd",
+            "docs": [
+              "End timestamp in seconds of a shift of the interest rate curve parameters",
+              "util0, rate0, util1, rate1, max_rate towards the interest_rate_shift_ targets.",
+              "",
+              "The parameters move linearly towards the targets in each token_update_index_and_rate.",
+              "Zero means no shift is configured."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "padding3",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          },
+          {
+            "name": "interestRateShiftUtil0",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftRate0",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftUtil1",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftRate1",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "interestRateShiftMaxRate",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "oracleTwap",
+            "docs": [
+              "Time weighted (exponential) moving average of the oracle price, updated",
+              "in token_update_index_and_rate.",
+              "",
+              "Liquidations and token conditional swap triggers use it to make prices",
+              "harder to manipulate, see oracle_twap_window_seconds."
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "oracleTwapLastUpdate",
+            "type": "u64"
+          },
+          {
+            "name": "oracleTwapWindowSeconds",
+            "docs": [
+              "Averaging window of oracle_twap. Zero disables the twap."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "padding4",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          },
+          {
+            "name": "referralFeesUnclaimed",
+            "docs": [
+              "Loan origination fees (native) in collected_fees_native that were paid by accounts",
+              "with a referrer and that the referrers haven't claimed their share of yet."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "keeperRewardsPaid",
+            "docs": [
+              "Keeper rewards (native) paid out of collected fees, see keeper_reward_per_hour.",
+              "",
+              "Kept apart from fees_withdrawn, which only tracks fees withdrawn from the vault."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referralFeesPaid",
+            "docs": [
+              "Referral fees (native) paid out of collected fees, see account_claim_referral_fees.",
+              "",
+              "Kept apart from fees_withdrawn, the payouts stay in the vault as referrer deposits."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "Layout version of this account, see Bank::LAYOUT_VERSION and token_migrate."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding5",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                1744
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "benchmark",
-      "docs": [
-        "",
-        "benchmark",
-        ""
-      ],
-      "accounts": [
-        {
-          "name": "dummy",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "bank",
+      "name": "group",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "group",
-            "type": "publicKey"
+            "name": "creator",
+            "type": "publicKey"
+          },
+          {
+            "name": "groupNum",
+            "type": "u32"
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "fastListingAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "mngoTokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "insuranceVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "insuranceMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "testing",
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "buybackFees",
+            "type": "u8"
+          },
+          {
+            "name": "buybackFeesMngoBonusFactor",
+            "type": "f32"
+          },
+          {
+            "name": "addressLookupTables",
+            "type": {
+              "array": [
+                "publicKey",
+                20
+              ]
+            }
+          },
+          {
+            "name": "securityAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "depositLimitQuote",
+            "type": "u64"
+          },
+          {
+            "name": "ixGate",
+            "type": "u128"
+          },
+          {
+            "name": "buybackFeesSwapMangoAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "buybackFeesExpiryInterval",
+            "docs": [
+              "Number of seconds after which fees that could be used with the fees buyback feature expire.",
+              "",
+              "The actual expiry is staggered such that the fees users accumulate are always",
+              "available for at least this interval - but may be available for up to twice this time.",
+              "",
+              "When set to 0, there's no expiry of buyback fees."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fastListingIntervalStart",
+            "docs": [
+              "Fast-listings are limited per week, this is the start of the current fast-listing interval",
+              "in seconds since epoch"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fastListingsInInterval",
+            "docs": [
+              "Number of fast listings that happened this interval"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "allowedFastListingsPerInterval",
+            "docs": [
+              "Number of fast listings that are allowed per interval"
+            ],
+            "type": "u16"
           },
           {
-            "name": "name",
+            "name": "allowedSwapPrograms",
+            "docs": [
+              "Programs that margin_trade may cpi into, unused entries are Pubkey::default()"
+            ],
             "type": {
               "array": [
-                "u8",
-                16
+                "publicKey",
+                4
               ]
             }
           },
           {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "vault",
-            "type": "publicKey"
-          },
-          {
-            "name": "oracle",
-            "type": "publicKey"
+            "name": "withdrawOnly",
+            "docs": [
+              "In withdraw-only mode instructions that increase positions are rejected,",
+              "while withdrawals, cancels, settles and liquidations keep working.",
+              "",
+              "See group_toggle_withdraw_only."
+            ],
+            "type": "u8"
           },
           {
-            "name": "oracleConfig",
-            "type": {
-              "defined": "OracleConfig"
-            }
+            "name": "allowCpiDeposit",
+            "docs": [
+              "Whether token deposits may be invoked via CPI, for integrator programs",
+              "that manage MangoAccounts. Other instructions don't check this flag."
+            ],
+            "type": "u8"
           },
           {
-            "name": "stablePriceModel",
+            "name": "padding2",
             "type": {
-              "defined": "StablePriceModel"
+              "array": [
+                "u8",
+                2
+              ]
             }
           },
           {
-            "name": "depositIndex",
+            "name": "referralFeeShare",
             "docs": [
-              "the index used to scale the value of an IndexedPosition",
-              "TODO: should always be >= 0, add checks?"
+              "Share of the fees that a referred MangoAccount generates that is paid out to",
+              "its referrer, see MangoAccount::referral_fees_accrued."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "f32"
           },
           {
-            "name": "borrowIndex",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "insuranceTokenIndex",
+            "docs": [
+              "Token index of the bank for insurance_mint.",
+              "",
+              "Bankruptcy pays out insurance fund tokens through this bank, converting",
+              "at oracle prices to the liability that is being covered."
+            ],
+            "type": "u16"
           },
           {
-            "name": "indexedDeposits",
+            "name": "layoutVersion",
             "docs": [
-              "deposits/borrows for this bank",
-              "",
-              "Note that these may become negative. It's perfectly fine for users to borrow one one bank",
-              "(increasing indexed_borrows there) and paying back on another (possibly decreasing indexed_borrows",
-              "below zero).",
-              "",
-              "The vault amount is not deducable from these values.",
+              "Layout version of this account, see Group::LAYOUT_VERSION and group_migrate.",
               "",
-              "These become meaningful when summed over all banks (like in update_index_and_rate)."
+              "Unrelated to `version`, which gates features."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "u8"
           },
           {
-            "name": "indexedBorrows",
+            "name": "reserved",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                1673
+              ]
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "mangoAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "indexLastUpdated",
-            "type": "u64"
+            "name": "group",
+            "type": "publicKey"
           },
           {
-            "name": "bankRateLastUpdated",
-            "type": "u64"
+            "name": "owner",
+            "type": "publicKey"
           },
           {
-            "name": "avgUtilization",
+            "name": "name",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "adjustmentFactor",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "delegate",
+            "type": "publicKey"
           },
           {
-            "name": "util0",
+            "name": "accountNum",
+            "type": "u32"
+          },
+          {
+            "name": "beingLiquidated",
             "docs": [
-              "The unscaled borrow interest curve is defined as continuous piecewise linear with the points:",
+              "Tracks that this account should be liquidated until init_health >= 0.",
               "",
-              "- 0% util: zero_util_rate",
-              "- util0% util: rate0",
-              "- util1% util: rate1",
-              "- 100% util: max_rate",
+              "Normally accounts can not be liquidated while maint_health >= 0. But when an account",
+              "reaches maint_health < 0, liquidators will call a liquidation instruction and thereby",
+              "set this flag. Now the account may be liquidated until init_health >= 0.",
               "",
-              "The final rate is this unscaled curve multiplied by interest_curve_scaling."
+              "Many actions should be disabled while the account is being liquidated, even if",
+              "its maint health has recovered to positive. Creating new open orders would, for example,",
+              "confuse liquidators."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "u8"
           },
           {
-            "name": "rate0",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "inHealthRegion",
+            "docs": [
+              "The account is currently inside a health region marked by HealthRegionBegin...HealthRegionEnd.",
+              "",
+              "Must never be set after a transaction ends."
+            ],
+            "type": "u8"
           },
           {
-            "name": "util1",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "rate1",
+            "name": "padding",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                1
+              ]
             }
           },
           {
-            "name": "maxRate",
+            "name": "netDeposits",
+            "type": "i64"
+          },
+          {
+            "name": "perpSpotTransfers",
+            "type": "i64"
+          },
+          {
+            "name": "healthRegionBeginInitHealth",
             "docs": [
-              "the 100% utilization rate",
-              "",
-              "This isn't the max_rate, since this still gets scaled by interest_curve_scaling,",
-              "which is >=1."
+              "Init health as calculated during HealthReginBegin, rounded up."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "i64"
           },
           {
-            "name": "collectedFeesNative",
+            "name": "frozenUntil",
+            "type": "u64"
+          },
+          {
+            "name": "buybackFeesAccruedCurrent",
             "docs": [
-              "Fees collected over the lifetime of the bank",
-              "",
-              "See fees_withdrawn for how much of the fees was withdrawn.",
-              "See collected_liquidation_fees for the (included) subtotal for liquidation related fees."
+              "Fees usable with the \"fees buyback\" feature.",
+              "This tracks the ones that accrued in the current expiry interval."
             ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "type": "u64"
           },
           {
-            "name": "loanOriginationFeeRate",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "buybackFeesAccruedPrevious",
+            "docs": [
+              "Fees buyback amount from the previous expiry interval."
+            ],
+            "type": "u64"
           },
           {
-            "name": "loanFeeRate",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "buybackFeesExpiryTimestamp",
+            "docs": [
+              "End timestamp of the current expiry interval of the buyback fees amount."
+            ],
+            "type": "u64"
           },
           {
-            "name": "maintAssetWeight",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "nextTokenConditionalSwapId",
+            "docs": [
+              "Next id to use when adding a token condition swap"
+            ],
+            "type": "u64"
           },
           {
-            "name": "initAssetWeight",
+            "name": "reserved",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                200
+              ]
             }
           },
           {
-            "name": "maintLiabWeight",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "headerVersion",
+            "type": "u8"
           },
           {
-            "name": "initLiabWeight",
+            "name": "padding3",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                7
+              ]
             }
           },
           {
-            "name": "liquidationFee",
-            "docs": [
-              "Liquidation fee that goes to the liqor.",
-              "",
-              "Liquidation always involves two tokens, and the sum of the two configured fees is used.",
-              "",
-              "A fraction of the price, like 0.05 for a 5% fee during liquidation.",
-              "",
-              "See also platform_liquidation_fee."
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "padding4",
+            "type": "u32"
           },
           {
-            "name": "dust",
+            "name": "tokens",
             "type": {
-              "defined": "I80F48"
+              "vec": {
+                "defined": "TokenPosition"
+              }
             }
           },
           {
-            "name": "flashLoanTokenAccountInitial",
-            "type": "u64"
-          },
-          {
-            "name": "flashLoanApprovedAmount",
-            "type": "u64"
+            "name": "padding5",
+            "type": "u32"
           },
           {
-            "name": "tokenIndex",
-            "type": "u16"
+            "name": "serum3",
+            "type": {
+              "vec": {
+                "defined": "Serum3Orders"
+              }
+            }
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "padding6",
+            "type": "u32"
           },
           {
-            "name": "mintDecimals",
-            "type": "u8"
+            "name": "perps",
+            "type": {
+              "vec": {
+                "defined": "PerpPosition"
+              }
+            }
           },
           {
-            "name": "bankNum",
+            "name": "padding7",
             "type": "u32"
           },
           {
-            "name": "minVaultToDepositsRatio",
-            "docs": [
-              "The maximum utilization allowed when borrowing is 1-this value",
-              "WARNING: Outdated name, kept for IDL compatibility"
-            ],
-            "type": "f64"
+            "name": "perpOpenOrders",
+            "type": {
+              "vec": {
+                "defined": "PerpOpenOrder"
+              }
+            }
           },
           {
-            "name": "netBorrowLimitWindowSizeTs",
-            "docs": [
-              "Size in seconds of a net borrows window"
-            ],
-            "type": "u64"
+            "name": "padding8",
+            "type": "u32"
           },
           {
-            "name": "lastNetBorrowsWindowStartTs",
-            "docs": [
-              "Timestamp at which the last net borrows window started"
-            ],
-            "type": "u64"
+            "name": "tokenConditionalSwaps",
+            "type": {
+              "vec": {
+                "defined": "TokenConditionalSwap"
+              }
+            }
           },
           {
-            "name": "netBorrowLimitPerWindowQuote",
-            "docs": [
-              "Net borrow limit per window in quote native; set to -1 to disable."
-            ],
-            "type": "i64"
-          },
+            "name": "reservedDynamic",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "netBorrowsInWindow",
-            "docs": [
-              "Sum of all deposits and borrows in the last window, in native units."
-            ],
-            "type": "i64"
+            "name": "group",
+            "type": "publicKey"
           },
           {
-            "name": "borrowWeightScaleStartQuote",
-            "docs": [
-              "Soft borrow limit in native quote",
-              "",
-              "Once the borrows on the bank exceed this quote value, init_liab_weight is scaled up.",
-              "Set to f64::MAX to disable.",
-              "",
-              "See scaled_init_liab_weight()."
-            ],
-            "type": "f64"
+            "name": "tokenIndex",
+            "type": "u16"
           },
           {
-            "name": "depositWeightScaleStartQuote",
-            "docs": [
-              "Limit for collateral of deposits in native quote",
-              "",
-              "Once the deposits in the bank exceed this quote value, init_asset_weight is scaled",
-              "down to keep the total collateral value constant.",
-              "Set to f64::MAX to disable.",
-              "",
-              "See scaled_init_asset_weight()."
-            ],
-            "type": "f64"
+            "name": "groupInsuranceFund",
+            "type": "u8"
           },
           {
-            "name": "reduceOnly",
-            "type": "u8"
+            "name": "padding1",
+            "type": {
+              "array": [
+                "u8",
+                5
+              ]
+            }
           },
           {
-            "name": "forceClose",
-            "type": "u8"
+            "name": "mint",
+            "type": "publicKey"
           },
           {
-            "name": "padding",
+            "name": "banks",
             "type": {
               "array": [
-                "u8",
+                "publicKey",
                 6
               ]
             }
           },
           {
-            "name": "feesWithdrawn",
-            "type": "u64"
+            "name": "vaults",
+            "type": {
+              "array": [
+                "publicKey",
+                6
+              ]
+            }
           },
           {
-            "name": "tokenConditionalSwapTakerFeeRate",
-            "docs": [
-              "Fees for the token conditional swap feature"
-            ],
-            "type": "f32"
+            "name": "oracle",
+            "type": "publicKey"
           },
           {
-            "name": "tokenConditionalSwapMakerFeeRate",
-            "type": "f32"
+            "name": "registrationTime",
+            "type": "u64"
           },
           {
-            "name": "flashLoanSwapFeeRate",
-            "type": "f32"
+            "name": "fallbackOracle",
+            "type": "publicKey"
           },
           {
-            "name": "interestTargetUtilization",
-            "docs": [
-              "Target utilization: If actual utilization is higher, scale up interest.",
-              "If it's lower, scale down interest (if possible)"
-            ],
-            "type": "f32"
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                2528
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "openbookV2Market",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "type": "publicKey"
           },
           {
-            "name": "interestCurveScaling",
-            "docs": [
-              "Current interest curve scaling, always >= 1.0",
-              "",
-              "Except when first migrating to having this field, then 0.0"
-            ],
-            "type": "f64"
+            "name": "baseTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "potentialSerumTokens",
-            "docs": [
-              "Largest amount of tokens that might be added the the bank based on",
-              "serum open order execution."
-            ],
-            "type": "u64"
+            "name": "quoteTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "maintWeightShiftStart",
-            "docs": [
-              "Start timestamp in seconds at which maint weights should start to change away",
-              "from maint_asset_weight, maint_liab_weight towards _asset_target and _liab_target.",
-              "If _start and _end and _duration_inv are 0, no shift is configured."
-            ],
-            "type": "u64"
+            "name": "reduceOnly",
+            "type": "u8"
           },
           {
-            "name": "maintWeightShiftEnd",
-            "docs": [
-              "End timestamp in seconds until which the maint weights should reach the configured targets."
-            ],
-            "type": "u64"
+            "name": "forceClose",
+            "type": "u8"
           },
           {
-            "name": "maintWeightShiftDurationInv",
-            "docs": [
-              "Cache of the inverse of maint_weight_shift_end - maint_weight_shift_start,",
-              "or zero if no shift is configured"
-            ],
+            "name": "padding1",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                2
+              ]
             }
           },
           {
-            "name": "maintWeightShiftAssetTarget",
-            "docs": [
-              "Maint asset weight to reach at _shift_end."
-            ],
+            "name": "name",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                16
+              ]
             }
           },
           {
-            "name": "maintWeightShiftLiabTarget",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "openbookV2Program",
+            "type": "publicKey"
           },
           {
-            "name": "fallbackOracle",
-            "docs": [
-              "Oracle that may be used if the main oracle is stale or not confident enough.",
-              "If this is Pubkey::default(), no fallback is available."
-            ],
+            "name": "openbookV2MarketExternal",
             "type": "publicKey"
           },
           {
-            "name": "depositLimit",
-            "docs": [
-              "zero means none, in token native"
-            ],
-            "type": "u64"
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
-            "name": "zeroUtilRate",
-            "docs": [
-              "The unscaled borrow interest curve point for zero utilization.",
-              "",
-              "See util0, rate0, util1, rate1, max_rate"
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "platformLiquidationFee",
-            "docs": [
-              "Additional to liquidation_fee, but goes to the group owner instead of the liqor"
-            ],
+            "name": "padding2",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                5
+              ]
             }
           },
           {
-            "name": "collectedLiquidationFees",
-            "docs": [
-              "Platform fees that were collected during liquidation (in native tokens)",
-              "",
-              "See also collected_fees_native and fees_withdrawn."
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "registrationTime",
+            "type": "u64"
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                1920
+                512
               ]
             }
           }
@@ -21560,132 +25315,148 @@ export const IDL: MangoV4 = {
       }
     },
     {
-      "name": "group",
+      "name": "openbookV2MarketIndexReservation",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "creator",
-            "type": "publicKey"
-          },
-          {
-            "name": "groupNum",
-            "type": "u32"
-          },
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "fastListingAdmin",
+            "name": "group",
             "type": "publicKey"
           },
           {
-            "name": "mngoTokenIndex",
+            "name": "marketIndex",
             "type": "u16"
           },
           {
-            "name": "padding",
+            "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                2
+                38
               ]
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "stubOracle",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "insuranceVault",
+            "name": "group",
             "type": "publicKey"
           },
           {
-            "name": "insuranceMint",
+            "name": "mint",
             "type": "publicKey"
           },
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "testing",
-            "type": "u8"
+            "name": "price",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "version",
-            "type": "u8"
+            "name": "lastUpdateTs",
+            "type": "i64"
           },
           {
-            "name": "buybackFees",
-            "type": "u8"
+            "name": "lastUpdateSlot",
+            "type": "u64"
           },
           {
-            "name": "buybackFeesMngoBonusFactor",
-            "type": "f32"
+            "name": "deviation",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "addressLookupTables",
+            "name": "reserved",
             "type": {
               "array": [
-                "publicKey",
-                20
+                "u8",
+                104
               ]
             }
-          },
-          {
-            "name": "securityAdmin",
-            "type": "publicKey"
-          },
-          {
-            "name": "depositLimitQuote",
-            "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "bookSide",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "ixGate",
-            "type": "u128"
+            "name": "roots",
+            "type": {
+              "array": [
+                {
+                  "defined": "OrderTreeRoot"
+                },
+                2
+              ]
+            }
           },
           {
-            "name": "buybackFeesSwapMangoAccount",
-            "type": "publicKey"
+            "name": "reservedRoots",
+            "type": {
+              "array": [
+                {
+                  "defined": "OrderTreeRoot"
+                },
+                4
+              ]
+            }
           },
           {
-            "name": "buybackFeesExpiryInterval",
-            "docs": [
-              "Number of seconds after which fees that could be used with the fees buyback feature expire.",
-              "",
-              "The actual expiry is staggered such that the fees users accumulate are always",
-              "available for at least this interval - but may be available for up to twice this time.",
-              "",
-              "When set to 0, there's no expiry of buyback fees."
-            ],
-            "type": "u64"
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                256
+              ]
+            }
           },
           {
-            "name": "fastListingIntervalStart",
-            "docs": [
-              "Fast-listings are limited per week, this is the start of the current fast-listing interval",
-              "in seconds since epoch"
-            ],
-            "type": "u64"
-          },
+            "name": "nodes",
+            "type": {
+              "defined": "OrderTreeNodes"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "eventQueue",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "fastListingsInInterval",
-            "docs": [
-              "Number of fast listings that happened this interval"
-            ],
-            "type": "u16"
+            "name": "header",
+            "type": {
+              "defined": "EventQueueHeader"
+            }
           },
           {
-            "name": "allowedFastListingsPerInterval",
-            "docs": [
-              "Number of fast listings that are allowed per interval"
-            ],
-            "type": "u16"
+            "name": "buf",
+            "type": {
+              "array": [
+                {
+                  "defined": "AnyEvent"
+                },
+                488
+              ]
+            }
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                1812
+                64
               ]
             }
           }
@@ -21693,7 +25464,7 @@ export const IDL: MangoV4 = {
       }
     },
     {
-      "name": "mangoAccount",
+      "name": "perpMarket",
       "type": {
         "kind": "struct",
         "fields": [
@@ -21702,296 +25473,357 @@ export const IDL: MangoV4 = {
             "type": "publicKey"
           },
           {
-            "name": "owner",
-            "type": "publicKey"
+            "name": "settleTokenIndex",
+            "docs": [
+              "Token index that settlements happen in.",
+              "",
+              "Currently required to be 0, USDC. In the future settlement",
+              "may be allowed to happen in other tokens."
+            ],
+            "type": "u16"
           },
           {
-            "name": "name",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "perpMarketIndex",
+            "docs": [
+              "Index of this perp market. Other data, like the MangoAccount's PerpPosition",
+              "reference this market via this index. Unique for this group's perp markets."
+            ],
+            "type": "u16"
           },
           {
-            "name": "delegate",
-            "type": "publicKey"
+            "name": "blocked1",
+            "docs": [
+              "Field used to contain the trusted_market flag and is now unused."
+            ],
+            "type": "u8"
           },
           {
-            "name": "accountNum",
-            "type": "u32"
+            "name": "groupInsuranceFund",
+            "docs": [
+              "Is this market covered by the group insurance fund?"
+            ],
+            "type": "u8"
           },
           {
-            "name": "beingLiquidated",
+            "name": "bump",
             "docs": [
-              "Tracks that this account should be liquidated until init_health >= 0.",
-              "",
-              "Normally accounts can not be liquidated while maint_health >= 0. But when an account",
-              "reaches maint_health < 0, liquidators will call a liquidation instruction and thereby",
-              "set this flag. Now the account may be liquidated until init_health >= 0.",
-              "",
-              "Many actions should be disabled while the account is being liquidated, even if",
-              "its maint health has recovered to positive. Creating new open orders would, for example,",
-              "confuse liquidators."
+              "PDA bump"
             ],
             "type": "u8"
           },
           {
-            "name": "inHealthRegion",
+            "name": "baseDecimals",
             "docs": [
-              "The account is currently inside a health region marked by HealthRegionBegin...HealthRegionEnd.",
+              "Number of decimals used for the base token.",
               "",
-              "Must never be set after a transaction ends."
+              "Used to convert the oracle's price into a native/native price."
             ],
             "type": "u8"
           },
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "padding",
+            "name": "name",
+            "docs": [
+              "Name. Trailing zero bytes are ignored."
+            ],
             "type": {
               "array": [
                 "u8",
-                1
+                16
               ]
             }
           },
           {
-            "name": "netDeposits",
-            "type": "i64"
+            "name": "bids",
+            "docs": [
+              "Address of the BookSide account for bids"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "perpSpotTransfers",
-            "type": "i64"
+            "name": "asks",
+            "docs": [
+              "Address of the BookSide account for asks"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "healthRegionBeginInitHealth",
+            "name": "eventQueue",
             "docs": [
-              "Init health as calculated during HealthReginBegin, rounded up."
+              "Address of the EventQueue account"
             ],
-            "type": "i64"
+            "type": "publicKey"
           },
           {
-            "name": "frozenUntil",
-            "type": "u64"
+            "name": "oracle",
+            "docs": [
+              "Oracle account address"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "buybackFeesAccruedCurrent",
+            "name": "oracleConfig",
             "docs": [
-              "Fees usable with the \"fees buyback\" feature.",
-              "This tracks the ones that accrued in the current expiry interval."
+              "Oracle configuration"
             ],
-            "type": "u64"
+            "type": {
+              "defined": "OracleConfig"
+            }
           },
           {
-            "name": "buybackFeesAccruedPrevious",
+            "name": "stablePriceModel",
             "docs": [
-              "Fees buyback amount from the previous expiry interval."
+              "Maintains a stable price based on the oracle price that is less volatile."
             ],
-            "type": "u64"
+            "type": {
+              "defined": "StablePriceModel"
+            }
           },
           {
-            "name": "buybackFeesExpiryTimestamp",
+            "name": "quoteLotSize",
             "docs": [
-              "End timestamp of the current expiry interval of the buyback fees amount."
+              "Number of quote native in a quote lot. Must be a power of 10.",
+              "",
+              "Primarily useful for increasing the tick size on the market: A lot price",
+              "of 1 becomes a native price of quote_lot_size/base_lot_size becomes a",
+              "ui price of quote_lot_size*base_decimals/base_lot_size/quote_decimals."
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "nextTokenConditionalSwapId",
+            "name": "baseLotSize",
             "docs": [
-              "Next id to use when adding a token condition swap"
+              "Number of base native in a base lot. Must be a power of 10.",
+              "",
+              "Example: If base decimals for the underlying asset is 6, base lot size",
+              "is 100 and and base position lots is 10_000 then base position native is",
+              "1_000_000 and base position ui is 1."
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "reserved",
+            "name": "maintBaseAssetWeight",
+            "docs": [
+              "These weights apply to the base position. The quote position has",
+              "no explicit weight (but may be covered by the overall pnl asset weight)."
+            ],
             "type": {
-              "array": [
-                "u8",
-                200
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "headerVersion",
-            "type": "u8"
-          },
-          {
-            "name": "padding3",
+            "name": "initBaseAssetWeight",
             "type": {
-              "array": [
-                "u8",
-                7
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "padding4",
-            "type": "u32"
+            "name": "maintBaseLiabWeight",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "tokens",
+            "name": "initBaseLiabWeight",
             "type": {
-              "vec": {
-                "defined": "TokenPosition"
-              }
+              "defined": "I80F48"
             }
           },
           {
-            "name": "padding5",
-            "type": "u32"
+            "name": "openInterest",
+            "docs": [
+              "Number of base lots currently active in the market. Always >= 0.",
+              "",
+              "Since this counts positive base lots and negative base lots, the more relevant",
+              "number of open base lot pairs is half this value."
+            ],
+            "type": "i64"
           },
           {
-            "name": "serum3",
-            "type": {
-              "vec": {
-                "defined": "Serum3Orders"
-              }
-            }
+            "name": "seqNum",
+            "docs": [
+              "Total number of orders seen"
+            ],
+            "type": "u64"
           },
           {
-            "name": "padding6",
-            "type": "u32"
+            "name": "registrationTime",
+            "docs": [
+              "Timestamp in seconds that the market was registered at."
+            ],
+            "type": "u64"
           },
           {
-            "name": "perps",
+            "name": "minFunding",
+            "docs": [
+              "Minimal funding rate per funding period, must be <= 0."
+            ],
             "type": {
-              "vec": {
-                "defined": "PerpPosition"
-              }
+              "defined": "I80F48"
             }
           },
           {
-            "name": "padding7",
-            "type": "u32"
-          },
-          {
-            "name": "perpOpenOrders",
+            "name": "maxFunding",
+            "docs": [
+              "Maximal funding rate per funding period, must be >= 0."
+            ],
             "type": {
-              "vec": {
-                "defined": "PerpOpenOrder"
-              }
+              "defined": "I80F48"
             }
           },
           {
-            "name": "padding8",
-            "type": "u32"
+            "name": "impactQuantity",
+            "docs": [
+              "For funding, get the impact price this many base lots deep into the book."
+            ],
+            "type": "i64"
           },
           {
-            "name": "tokenConditionalSwaps",
+            "name": "longFunding",
+            "docs": [
+              "Current long funding value. Increasing it means that every long base lot",
+              "needs to pay that amount of quote native in funding.",
+              "",
+              "PerpPosition uses and tracks it settle funding. Updated by the perp",
+              "keeper instruction."
+            ],
             "type": {
-              "vec": {
-                "defined": "TokenConditionalSwap"
-              }
+              "defined": "I80F48"
             }
           },
           {
-            "name": "reservedDynamic",
+            "name": "shortFunding",
+            "docs": [
+              "See long_funding."
+            ],
             "type": {
-              "array": [
-                "u8",
-                64
-              ]
+              "defined": "I80F48"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "mintInfo",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "group",
-            "type": "publicKey"
+            "name": "fundingLastUpdated",
+            "docs": [
+              "timestamp that funding was last updated in"
+            ],
+            "type": "u64"
           },
           {
-            "name": "tokenIndex",
-            "type": "u16"
+            "name": "baseLiquidationFee",
+            "docs": [
+              "Fees",
+              "Fee for base position liquidation"
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "groupInsuranceFund",
-            "type": "u8"
+            "name": "makerFee",
+            "docs": [
+              "Fee when matching maker orders. May be negative."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "padding1",
+            "name": "takerFee",
+            "docs": [
+              "Fee for taker orders, may not be negative."
+            ],
             "type": {
-              "array": [
-                "u8",
-                5
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "mint",
-            "type": "publicKey"
+            "name": "feesAccrued",
+            "docs": [
+              "Fees accrued in native quote currency",
+              "these are increased when new fees are paid and decreased when perp_settle_fees is called"
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "banks",
+            "name": "feesSettled",
+            "docs": [
+              "Fees settled in native quote currency",
+              "these are increased when perp_settle_fees is called, and only decreased",
+              "when positive dust perp positions are paid out"
+            ],
             "type": {
-              "array": [
-                "publicKey",
-                6
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "vaults",
-            "type": {
-              "array": [
-                "publicKey",
-                6
-              ]
-            }
+            "name": "feePenalty",
+            "docs": [
+              "Fee (in quote native) to charge for ioc orders"
+            ],
+            "type": "f32"
+          },
+          {
+            "name": "settleFeeFlat",
+            "docs": [
+              "In native units of settlement token, given to each settle call above the",
+              "settle_fee_amount_threshold if settling at least 1% of perp base pos value."
+            ],
+            "type": "f32"
           },
           {
-            "name": "oracle",
-            "type": "publicKey"
+            "name": "settleFeeAmountThreshold",
+            "docs": [
+              "Pnl settlement amount needed to be eligible for the flat fee."
+            ],
+            "type": "f32"
           },
           {
-            "name": "registrationTime",
-            "type": "u64"
+            "name": "settleFeeFractionLowHealth",
+            "docs": [
+              "Fraction of pnl to pay out as fee if +pnl account has low health.",
+              "(limited to 2x settle_fee_flat)"
+            ],
+            "type": "f32"
           },
           {
-            "name": "fallbackOracle",
-            "type": "publicKey"
+            "name": "settlePnlLimitFactor",
+            "docs": [
+              "Controls the strictness of the settle limit.",
+              "Set to a negative value to disable the limit.",
+              "",
+              "This factor applies to the settle limit in two ways",
+              "- for the unrealized pnl settle limit, the factor is multiplied with the stable perp base value",
+              "(i.e. limit_factor * base_native * stable_price)",
+              "- when increasing the realized pnl settle limit (stored per PerpPosition), the factor is",
+              "multiplied with the stable value of the perp pnl being realized",
+              "(i.e. limit_factor * reduced_native * stable_price)",
+              "",
+              "See also PerpPosition::settle_pnl_limit_realized_trade"
+            ],
+            "type": "f32"
           },
           {
-            "name": "reserved",
+            "name": "padding3",
             "type": {
               "array": [
                 "u8",
-                2528
+                4
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "openbookV2Market",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "group",
-            "type": "publicKey"
-          },
-          {
-            "name": "baseTokenIndex",
-            "type": "u16"
           },
           {
-            "name": "quoteTokenIndex",
-            "type": "u16"
+            "name": "settlePnlLimitWindowSizeTs",
+            "docs": [
+              "Window size in seconds for the perp settlement limit"
+            ],
+            "type": "u64"
           },
           {
             "name": "reduceOnly",
+            "docs": [
+              "If true, users may no longer increase their market exposure. Only actions",
+              "that reduce their position are still allowed."
+            ],
             "type": "u8"
           },
           {
@@ -21999,198 +25831,258 @@ export const IDL: MangoV4 = {
             "type": "u8"
           },
           {
-            "name": "padding1",
+            "name": "padding4",
             "type": {
               "array": [
                 "u8",
-                2
+                6
               ]
             }
           },
           {
-            "name": "name",
+            "name": "maintOverallAssetWeight",
+            "docs": [
+              "Weights for full perp market health, if positive"
+            ],
             "type": {
-              "array": [
-                "u8",
-                16
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "openbookV2Program",
-            "type": "publicKey"
-          },
-          {
-            "name": "openbookV2MarketExternal",
-            "type": "publicKey"
-          },
-          {
-            "name": "marketIndex",
-            "type": "u16"
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+            "name": "initOverallAssetWeight",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "padding2",
+            "name": "positivePnlLiquidationFee",
             "type": {
-              "array": [
-                "u8",
-                5
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "registrationTime",
+            "name": "feesWithdrawn",
             "type": "u64"
           },
           {
-            "name": "reserved",
+            "name": "platformLiquidationFee",
+            "docs": [
+              "Additional to liquidation_fee, but goes to the group owner instead of the liqor"
+            ],
             "type": {
-              "array": [
-                "u8",
-                512
-              ]
+              "defined": "I80F48"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "openbookV2MarketIndexReservation",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "group",
-            "type": "publicKey"
           },
           {
-            "name": "marketIndex",
-            "type": "u16"
-          },
-          {
-            "name": "reserved",
+            "name": "accruedLiquidationFees",
+            "docs": [
+              "Platform fees that were accrued during liquidation (in native tokens)",
+              "",
+              "These fees are also added to fees_accrued, this is just for bookkeeping the total",
+              "liquidation fees that happened. So never decreases (different to fees_accrued)."
+            ],
             "type": {
-              "array": [
-                "u8",
-                38
-              ]
+              "defined": "I80F48"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "stubOracle",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "group",
-            "type": "publicKey"
           },
           {
-            "name": "mint",
-            "type": "publicKey"
+            "name": "keeperRewardPerEvent",
+            "docs": [
+              "In native units of settlement token, paid out of fees_accrued to the keeper",
+              "for each event processed by perp_consume_events."
+            ],
+            "type": "f32"
           },
           {
-            "name": "price",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "keeperRewardFundingPerHour",
+            "docs": [
+              "In native units of settlement token, paid out of fees_accrued to the keeper",
+              "of perp_update_funding. Pro-rated by the time since the last funding update,",
+              "capped at one hour."
+            ],
+            "type": "f32"
           },
           {
-            "name": "lastUpdateTs",
-            "type": "i64"
+            "name": "oraclePriceBand",
+            "docs": [
+              "Orders posted to the book must be bids >= price / (1+band) and asks <= price * (1+band),",
+              "where price is the lower (bids) or higher (asks) of the oracle and stable price.",
+              "",
+              "Protects against orders that sit absurdly far from the market. Zero disables the limit.",
+              "",
+              "Funding also clamps the book prices to oracle / (1+band) and oracle * (1+band)."
+            ],
+            "type": "f32"
           },
           {
-            "name": "lastUpdateSlot",
-            "type": "u64"
+            "name": "fundingPeriodSeconds",
+            "docs": [
+              "The funding rate is the rate paid per this many seconds. Zero means one day.",
+              "",
+              "Lower values make funding more responsive to the book/oracle price difference."
+            ],
+            "type": "u32"
           },
           {
-            "name": "deviation",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "dustThreshold",
+            "docs": [
+              "Perp positions without base lots and open orders whose quote position is below this",
+              "many native settle token units get settled into the settle token and deactivated",
+              "when fees are settled. Zero disables."
+            ],
+            "type": "f32"
           },
           {
-            "name": "reserved",
+            "name": "padding5",
             "type": {
               "array": [
                 "u8",
-                104
+                4
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "bookSide",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "roots",
-            "type": {
-              "array": [
-                {
-                  "defined": "OrderTreeRoot"
-                },
-                2
-              ]
-            }
+            "name": "referralFeesUnclaimed",
+            "docs": [
+              "Taker fees (native) in fees_accrued that were paid by accounts with a referrer and",
+              "that the referrers haven't claimed their share of yet."
+            ],
+            "type": "u64"
           },
           {
-            "name": "reservedRoots",
-            "type": {
-              "array": [
-                {
-                  "defined": "OrderTreeRoot"
-                },
-                4
-              ]
-            }
+            "name": "adlCandidateLong",
+            "docs": [
+              "The highest ranked profitable long and short positions, the only possible",
+              "counterparties for auto-deleveraging. See perp_update_adl_candidate."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "adlCandidateShort",
+            "type": "publicKey"
+          },
+          {
+            "name": "adlCandidateLongScore",
+            "docs": [
+              "Auto-deleveraging rank of the candidates when they were last updated: their",
+              "unsettled pnl divided by their equity"
+            ],
+            "type": "f64"
+          },
+          {
+            "name": "adlCandidateShortScore",
+            "type": "f64"
+          },
+          {
+            "name": "referralFeesPaid",
+            "docs": [
+              "Referral fees (native) paid out of settled fees, see account_claim_referral_fees.",
+              "",
+              "Kept apart from fees_withdrawn, the payouts stay in the vault as referrer deposits."
+            ],
+            "type": "u64"
           },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                256
+                1728
               ]
             }
-          },
-          {
-            "name": "nodes",
-            "type": {
-              "defined": "OrderTreeNodes"
-            }
           }
         ]
       }
     },
     {
-      "name": "eventQueue",
+      "name": "perpTriggerOrder",
+      "docs": [
+        "A perp order that anyone can place on behalf of the account once the",
+        "oracle price crosses the trigger price, like a stop-loss or take-profit.",
+        "",
+        "The order is placed as ImmediateOrCancel. Whoever executes it receives",
+        "the lamports of this account and the executor_incentive as incentives."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "header",
-            "type": {
-              "defined": "EventQueueHeader"
-            }
+            "name": "group",
+            "type": "publicKey"
           },
           {
-            "name": "buf",
+            "name": "account",
+            "type": "publicKey"
+          },
+          {
+            "name": "orderId",
+            "docs": [
+              "Chosen by the creator, part of the address seeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiryTimestamp",
+            "docs": [
+              "The order can't be executed at or after this time, u64::MAX for never"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "triggerPriceLots",
+            "docs": [
+              "Oracle price at which the order triggers, in quote lots per base lot"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "priceLots",
+            "docs": [
+              "Worst price the order may fill at, in quote lots per base lot"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxBaseLots",
+            "type": "i64"
+          },
+          {
+            "name": "maxQuoteLots",
+            "type": "i64"
+          },
+          {
+            "name": "executorIncentive",
+            "docs": [
+              "Paid from the account's settle token position to the executor, in native tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "perpMarketIndex",
+            "type": "u16"
+          },
+          {
+            "name": "side",
+            "type": "u8"
+          },
+          {
+            "name": "triggerCondition",
+            "type": "u8"
+          },
+          {
+            "name": "reduceOnly",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
             "type": {
               "array": [
-                {
-                  "defined": "AnyEvent"
-                },
-                488
+                "u8",
+                2
               ]
             }
           },
@@ -22199,7 +26091,7 @@ export const IDL: MangoV4 = {
             "type": {
               "array": [
                 "u8",
-                64
+                56
               ]
             }
           }
@@ -22207,7 +26099,7 @@ export const IDL: MangoV4 = {
       }
     },
     {
-      "name": "perpMarket",
+      "name": "serum3Market",
       "type": {
         "kind": "struct",
         "fields": [
@@ -22216,58 +26108,32 @@ export const IDL: MangoV4 = {
             "type": "publicKey"
           },
           {
-            "name": "settleTokenIndex",
-            "docs": [
-              "Token index that settlements happen in.",
-              "",
-              "Currently required to be 0, USDC. In the future settlement",
-              "may be allowed to happen in other tokens."
-            ],
+            "name": "baseTokenIndex",
             "type": "u16"
           },
           {
-            "name": "perpMarketIndex",
-            "docs": [
-              "Index of this perp market. Other data, like the MangoAccount's PerpPosition",
-              "reference this market via this index. Unique for this group's perp markets."
-            ],
+            "name": "quoteTokenIndex",
             "type": "u16"
           },
           {
-            "name": "blocked1",
-            "docs": [
-              "Field used to contain the trusted_market flag and is now unused."
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "groupInsuranceFund",
-            "docs": [
-              "Is this market covered by the group insurance fund?"
-            ],
+            "name": "reduceOnly",
             "type": "u8"
           },
           {
-            "name": "bump",
-            "docs": [
-              "PDA bump"
-            ],
+            "name": "forceClose",
             "type": "u8"
           },
           {
-            "name": "baseDecimals",
-            "docs": [
-              "Number of decimals used for the base token.",
-              "",
-              "Used to convert the oracle's price into a native/native price."
-            ],
-            "type": "u8"
+            "name": "padding1",
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
           },
           {
             "name": "name",
-            "docs": [
-              "Name. Trailing zero bytes are ignored."
-            ],
             "type": {
               "array": [
                 "u8",
@@ -22276,557 +26142,493 @@ export const IDL: MangoV4 = {
             }
           },
           {
-            "name": "bids",
-            "docs": [
-              "Address of the BookSide account for bids"
-            ],
+            "name": "serumProgram",
             "type": "publicKey"
           },
           {
-            "name": "asks",
-            "docs": [
-              "Address of the BookSide account for asks"
-            ],
+            "name": "serumMarketExternal",
             "type": "publicKey"
           },
           {
-            "name": "eventQueue",
-            "docs": [
-              "Address of the EventQueue account"
-            ],
-            "type": "publicKey"
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
-            "name": "oracle",
-            "docs": [
-              "Oracle account address"
-            ],
-            "type": "publicKey"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "oracleConfig",
-            "docs": [
-              "Oracle configuration"
-            ],
+            "name": "padding2",
             "type": {
-              "defined": "OracleConfig"
+              "array": [
+                "u8",
+                1
+              ]
             }
           },
           {
-            "name": "stablePriceModel",
+            "name": "oraclePriceBand",
             "docs": [
-              "Maintains a stable price based on the oracle price that is less volatile."
+              "Limit orders must be <= oracle * (1+band) and >= oracle / (1+band)",
+              "",
+              "Zero value is the default due to migration and disables the limit,",
+              "same as f32::MAX."
             ],
-            "type": {
-              "defined": "StablePriceModel"
-            }
+            "type": "f32"
           },
           {
-            "name": "quoteLotSize",
+            "name": "registrationTime",
+            "type": "u64"
+          },
+          {
+            "name": "collectedReferrerRebates",
             "docs": [
-              "Number of quote native in a quote lot. Must be a power of 10.",
-              "",
-              "Primarily useful for increasing the tick size on the market: A lot price",
-              "of 1 becomes a native price of quote_lot_size/base_lot_size becomes a",
-              "ui price of quote_lot_size*base_decimals/base_lot_size/quote_decimals."
+              "Referrer rebates claimed into the quote bank's collected fees during settle, in",
+              "native quote tokens. Only counts settles that send the rebates to the DAO."
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "baseLotSize",
+            "name": "crossingPriceBand",
             "docs": [
-              "Number of base native in a base lot. Must be a power of 10.",
+              "Orders must be bids <= oracle * (1+band) and asks >= oracle * (1-band)",
               "",
-              "Example: If base decimals for the underlying asset is 6, base lot size",
-              "is 100 and and base position lots is 10_000 then base position native is",
-              "1_000_000 and base position ui is 1."
+              "Protects against orders that cross the oracle price by a lot, like fat-fingered",
+              "prices or self-trades against a thin book. Zero disables the limit."
             ],
-            "type": "i64"
+            "type": "f32"
           },
           {
-            "name": "maintBaseAssetWeight",
-            "docs": [
-              "These weights apply to the base position. The quote position has",
-              "no explicit weight (but may be covered by the overall pnl asset weight)."
-            ],
+            "name": "reserved",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                116
+              ]
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "serum3MarketIndexReservation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "group",
+            "type": "publicKey"
           },
           {
-            "name": "initBaseAssetWeight",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
-            "name": "maintBaseLiabWeight",
+            "name": "reserved",
             "type": {
-              "defined": "I80F48"
+              "array": [
+                "u8",
+                38
+              ]
             }
-          },
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "PerpPlaceOrderArgs",
+      "docs": [
+        "The parameters of one order in perp_place_orders, see perp_place_order_v2"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "initBaseLiabWeight",
+            "name": "side",
             "type": {
-              "defined": "I80F48"
+              "defined": "Side"
             }
           },
           {
-            "name": "openInterest",
-            "docs": [
-              "Number of base lots currently active in the market. Always >= 0.",
-              "",
-              "Since this counts positive base lots and negative base lots, the more relevant",
-              "number of open base lot pairs is half this value."
-            ],
+            "name": "priceLots",
+            "type": "i64"
+          },
+          {
+            "name": "maxBaseLots",
             "type": "i64"
           },
           {
-            "name": "seqNum",
-            "docs": [
-              "Total number of orders seen"
-            ],
-            "type": "u64"
+            "name": "maxQuoteLots",
+            "type": "i64"
           },
           {
-            "name": "registrationTime",
-            "docs": [
-              "Timestamp in seconds that the market was registered at."
-            ],
+            "name": "clientOrderId",
             "type": "u64"
           },
           {
-            "name": "minFunding",
-            "docs": [
-              "Minimal funding rate per day, must be <= 0."
-            ],
+            "name": "orderType",
             "type": {
-              "defined": "I80F48"
+              "defined": "PlaceOrderType"
             }
           },
           {
-            "name": "maxFunding",
-            "docs": [
-              "Maximal funding rate per day, must be >= 0."
-            ],
+            "name": "selfTradeBehavior",
             "type": {
-              "defined": "I80F48"
+              "defined": "SelfTradeBehavior"
             }
           },
           {
-            "name": "impactQuantity",
-            "docs": [
-              "For funding, get the impact price this many base lots deep into the book."
-            ],
-            "type": "i64"
+            "name": "reduceOnly",
+            "type": "bool"
           },
           {
-            "name": "longFunding",
-            "docs": [
-              "Current long funding value. Increasing it means that every long base lot",
-              "needs to pay that amount of quote native in funding.",
-              "",
-              "PerpPosition uses and tracks it settle funding. Updated by the perp",
-              "keeper instruction."
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
-          },
+            "name": "expiryTimestamp",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Serum3PlaceOrderArgs",
+      "docs": [
+        "The parameters of one order in serum3_place_orders"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "shortFunding",
-            "docs": [
-              "See long_funding."
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "limitPriceLots",
+            "type": "u64"
           },
           {
-            "name": "fundingLastUpdated",
-            "docs": [
-              "timestamp that funding was last updated in"
-            ],
+            "name": "maxBaseQty",
             "type": "u64"
           },
           {
-            "name": "baseLiquidationFee",
-            "docs": [
-              "Fees",
-              "Fee for base position liquidation"
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "maxNativeQuoteQtyIncludingFees",
+            "type": "u64"
           },
           {
-            "name": "makerFee",
-            "docs": [
-              "Fee when matching maker orders. May be negative."
-            ],
+            "name": "selfTradeBehavior",
             "type": {
-              "defined": "I80F48"
+              "defined": "Serum3SelfTradeBehavior"
             }
           },
           {
-            "name": "takerFee",
-            "docs": [
-              "Fee for taker orders, may not be negative."
-            ],
+            "name": "orderType",
             "type": {
-              "defined": "I80F48"
+              "defined": "Serum3OrderType"
             }
           },
           {
-            "name": "feesAccrued",
-            "docs": [
-              "Fees accrued in native quote currency",
-              "these are increased when new fees are paid and decreased when perp_settle_fees is called"
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "clientOrderId",
+            "type": "u64"
           },
           {
-            "name": "feesSettled",
-            "docs": [
-              "Fees settled in native quote currency",
-              "these are increased when perp_settle_fees is called, and never decreased"
-            ],
-            "type": {
-              "defined": "I80F48"
-            }
-          },
+            "name": "limit",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InterestRateParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "feePenalty",
-            "docs": [
-              "Fee (in quote native) to charge for ioc orders"
-            ],
+            "name": "util0",
             "type": "f32"
           },
           {
-            "name": "settleFeeFlat",
-            "docs": [
-              "In native units of settlement token, given to each settle call above the",
-              "settle_fee_amount_threshold if settling at least 1% of perp base pos value."
-            ],
+            "name": "rate0",
             "type": "f32"
           },
           {
-            "name": "settleFeeAmountThreshold",
-            "docs": [
-              "Pnl settlement amount needed to be eligible for the flat fee."
-            ],
+            "name": "util1",
             "type": "f32"
           },
           {
-            "name": "settleFeeFractionLowHealth",
-            "docs": [
-              "Fraction of pnl to pay out as fee if +pnl account has low health.",
-              "(limited to 2x settle_fee_flat)"
-            ],
+            "name": "rate1",
             "type": "f32"
           },
           {
-            "name": "settlePnlLimitFactor",
-            "docs": [
-              "Controls the strictness of the settle limit.",
-              "Set to a negative value to disable the limit.",
-              "",
-              "This factor applies to the settle limit in two ways",
-              "- for the unrealized pnl settle limit, the factor is multiplied with the stable perp base value",
-              "(i.e. limit_factor * base_native * stable_price)",
-              "- when increasing the realized pnl settle limit (stored per PerpPosition), the factor is",
-              "multiplied with the stable value of the perp pnl being realized",
-              "(i.e. limit_factor * reduced_native * stable_price)",
-              "",
-              "See also PerpPosition::settle_pnl_limit_realized_trade"
-            ],
+            "name": "maxRate",
             "type": "f32"
           },
           {
-            "name": "padding3",
-            "type": {
-              "array": [
-                "u8",
-                4
-              ]
-            }
-          },
-          {
-            "name": "settlePnlLimitWindowSizeTs",
-            "docs": [
-              "Window size in seconds for the perp settlement limit"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "reduceOnly",
-            "docs": [
-              "If true, users may no longer increase their market exposure. Only actions",
-              "that reduce their position are still allowed."
-            ],
-            "type": "u8"
-          },
+            "name": "adjustmentFactor",
+            "type": "f32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenPositionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "forceClose",
-            "type": "u8"
+            "name": "tokenIndex",
+            "type": "u16"
           },
           {
-            "name": "padding4",
+            "name": "native",
             "type": {
-              "array": [
-                "u8",
-                6
-              ]
+              "defined": "I80F48"
             }
           },
           {
-            "name": "maintOverallAssetWeight",
-            "docs": [
-              "Weights for full perp market health, if positive"
-            ],
+            "name": "oraclePrice",
             "type": {
               "defined": "I80F48"
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Serum3PositionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "marketIndex",
+            "type": "u16"
           },
           {
-            "name": "initOverallAssetWeight",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "baseTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "positivePnlLiquidationFee",
-            "type": {
-              "defined": "I80F48"
-            }
+            "name": "quoteTokenIndex",
+            "type": "u16"
           },
           {
-            "name": "feesWithdrawn",
+            "name": "baseNativeTotal",
             "type": "u64"
           },
           {
-            "name": "platformLiquidationFee",
-            "docs": [
-              "Additional to liquidation_fee, but goes to the group owner instead of the liqor"
-            ],
+            "name": "quoteNativeTotal",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PerpPositionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "perpMarketIndex",
+            "type": "u16"
+          },
+          {
+            "name": "basePositionNative",
             "type": {
               "defined": "I80F48"
             }
           },
           {
-            "name": "accruedLiquidationFees",
-            "docs": [
-              "Platform fees that were accrued during liquidation (in native tokens)",
-              "",
-              "These fees are also added to fees_accrued, this is just for bookkeeping the total",
-              "liquidation fees that happened. So never decreases (different to fees_accrued)."
-            ],
+            "name": "quotePositionNative",
             "type": {
               "defined": "I80F48"
             }
           },
           {
-            "name": "reserved",
+            "name": "oraclePrice",
             "type": {
-              "array": [
-                "u8",
-                1848
-              ]
+              "defined": "I80F48"
             }
           }
         ]
       }
     },
     {
-      "name": "serum3Market",
+      "name": "Equity",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "group",
-            "type": "publicKey"
-          },
-          {
-            "name": "baseTokenIndex",
-            "type": "u16"
-          },
-          {
-            "name": "quoteTokenIndex",
-            "type": "u16"
-          },
-          {
-            "name": "reduceOnly",
-            "type": "u8"
-          },
-          {
-            "name": "forceClose",
-            "type": "u8"
-          },
-          {
-            "name": "padding1",
+            "name": "tokens",
             "type": {
-              "array": [
-                "u8",
-                2
-              ]
+              "vec": {
+                "defined": "TokenEquity"
+              }
             }
           },
           {
-            "name": "name",
+            "name": "perps",
             "type": {
-              "array": [
-                "u8",
-                16
-              ]
+              "vec": {
+                "defined": "PerpEquity"
+              }
             }
-          },
-          {
-            "name": "serumProgram",
-            "type": "publicKey"
-          },
-          {
-            "name": "serumMarketExternal",
-            "type": "publicKey"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenEquity",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "marketIndex",
+            "name": "tokenIndex",
             "type": "u16"
           },
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "padding2",
+            "name": "value",
             "type": {
-              "array": [
-                "u8",
-                1
-              ]
+              "defined": "I80F48"
             }
-          },
-          {
-            "name": "oraclePriceBand",
-            "docs": [
-              "Limit orders must be <= oracle * (1+band) and >= oracle / (1+band)",
-              "",
-              "Zero value is the default due to migration and disables the limit,",
-              "same as f32::MAX."
-            ],
-            "type": "f32"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "PerpEquity",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "registrationTime",
-            "type": "u64"
+            "name": "perpMarketIndex",
+            "type": "u16"
           },
           {
-            "name": "reserved",
+            "name": "value",
             "type": {
-              "array": [
-                "u8",
-                128
-              ]
+              "defined": "I80F48"
             }
           }
         ]
       }
     },
     {
-      "name": "serum3MarketIndexReservation",
+      "name": "MangoAccountDataPage",
+      "docs": [
+        "Return data of compute_account_data_page"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "group",
-            "type": "publicKey"
+            "name": "initHealth",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "marketIndex",
-            "type": "u16"
+            "name": "maintHealth",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "reserved",
+            "name": "page",
+            "type": "u8"
+          },
+          {
+            "name": "pageCount",
+            "type": "u8"
+          },
+          {
+            "name": "entries",
             "type": {
-              "array": [
-                "u8",
-                38
-              ]
+              "vec": {
+                "defined": "MangoAccountDataEntry"
+              }
             }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "InterestRateParams",
+      "name": "MangoAccountHealthBreakdown",
+      "docs": [
+        "Return data of compute_health_breakdown"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "util0",
-            "type": "f32"
-          },
-          {
-            "name": "rate0",
-            "type": "f32"
+            "name": "initHealth",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "util1",
-            "type": "f32"
+            "name": "maintHealth",
+            "type": {
+              "defined": "I80F48"
+            }
           },
           {
-            "name": "rate1",
-            "type": "f32"
+            "name": "page",
+            "type": "u8"
           },
           {
-            "name": "maxRate",
-            "type": "f32"
+            "name": "pageCount",
+            "type": "u8"
           },
           {
-            "name": "adjustmentFactor",
-            "type": "f32"
+            "name": "contributions",
+            "type": {
+              "vec": {
+                "defined": "HealthContribution"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "Equity",
+      "name": "TokenHealthContribution",
+      "docs": [
+        "Excludes the perp hupnl that's attributed to perp markets settling in the token"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokens",
+            "name": "tokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "init",
             "type": {
-              "vec": {
-                "defined": "TokenEquity"
-              }
+              "defined": "I80F48"
             }
           },
           {
-            "name": "perps",
+            "name": "maint",
             "type": {
-              "vec": {
-                "defined": "PerpEquity"
-              }
+              "defined": "I80F48"
             }
           }
         ]
       }
     },
     {
-      "name": "TokenEquity",
+      "name": "Serum3HealthContribution",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenIndex",
+            "name": "marketIndex",
             "type": "u16"
           },
           {
-            "name": "value",
+            "name": "init",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maint",
             "type": {
               "defined": "I80F48"
             }
@@ -22835,7 +26637,7 @@ export const IDL: MangoV4 = {
       }
     },
     {
-      "name": "PerpEquity",
+      "name": "PerpHealthContribution",
       "type": {
         "kind": "struct",
         "fields": [
@@ -22844,7 +26646,13 @@ export const IDL: MangoV4 = {
             "type": "u16"
           },
           {
-            "name": "value",
+            "name": "init",
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "maint",
             "type": {
               "defined": "I80F48"
             }
@@ -22985,7 +26793,75 @@ export const IDL: MangoV4 = {
           {
             "name": "loanOriginationFee",
             "docs": [
-              "The fee paid on the loan, not included in `loan` or `change_amount`"
+              "The fee paid on the loan, not included in `loan` or `change_amount`"
+            ],
+            "type": "i128"
+          },
+          {
+            "name": "depositIndex",
+            "type": "i128"
+          },
+          {
+            "name": "borrowIndex",
+            "type": "i128"
+          },
+          {
+            "name": "price",
+            "type": "i128"
+          },
+          {
+            "name": "swapFee",
+            "docs": [
+              "Swap fee paid on the in token of a swap.",
+              "",
+              "Not factored into change_amount."
+            ],
+            "type": "i128"
+          },
+          {
+            "name": "approvedAmount",
+            "docs": [
+              "The amount that was transfered out to the user"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MarginTradeTokenDetail",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "changeAmount",
+            "docs": [
+              "The change of the vault balance caused by the swap",
+              "",
+              "Does NOT include the loan_origination_fee or swap_fee, so the true",
+              "change of the token position is `change_amount - loan_origination_fee - swap_fee`."
+            ],
+            "type": "i128"
+          },
+          {
+            "name": "loan",
+            "docs": [
+              "The amount that was a loan, depends on the user's deposits"
+            ],
+            "type": "i128"
+          },
+          {
+            "name": "loanOriginationFee",
+            "type": "i128"
+          },
+          {
+            "name": "swapFee",
+            "docs": [
+              "Swap fee paid on tokens that left the vault"
             ],
             "type": "i128"
           },
@@ -23000,22 +26876,6 @@ export const IDL: MangoV4 = {
           {
             "name": "price",
             "type": "i128"
-          },
-          {
-            "name": "swapFee",
-            "docs": [
-              "Swap fee paid on the in token of a swap.",
-              "",
-              "Not factored into change_amount."
-            ],
-            "type": "i128"
-          },
-          {
-            "name": "approvedAmount",
-            "docs": [
-              "The amount that was transfered out to the user"
-            ],
-            "type": "u64"
           }
         ]
       }
@@ -23424,14 +27284,88 @@ export const IDL: MangoV4 = {
               "defined": "I80F48"
             }
           },
+          {
+            "name": "entryQuoteNative",
+            "docs": [
+              "Quote paid (negative) or received (positive) for entering the current base position.",
+              "",
+              "Reduced proportionally when the base position is reduced and reset when the position",
+              "changes sign or goes to zero. Unlike avg_entry_price_per_base_lot, this is exact."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
+          {
+            "name": "settledPnlForPositionNative",
+            "docs": [
+              "Pnl that was settled over the current position's lifetime.",
+              "",
+              "Reset when the position changes sign or goes to zero."
+            ],
+            "type": {
+              "defined": "I80F48"
+            }
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                88
+                56
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReferralFeesAccrued",
+      "docs": [
+        "Referral fees that a referred account generated in one bank or perp market and that",
+        "its referrer hasn't claimed yet, see MangoAccountFixed::referral_fees_accrued"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "source",
+            "docs": [
+              "ReferralFeeSource, zero for unused slots"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "index",
+            "docs": [
+              "TokenIndex of the bank or PerpMarketIndex of the perp market"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "padding2",
+            "type": {
+              "array": [
+                "u8",
+                4
               ]
             }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Native fees, in the bank's token or the perp market's settle token"
+            ],
+            "type": "u64"
           }
         ]
       }
@@ -23582,12 +27516,72 @@ export const IDL: MangoV4 = {
             "name": "temporaryDelegateExpiry",
             "type": "u64"
           },
+          {
+            "name": "isolated",
+            "docs": [
+              "Isolated accounts may only have positions in the isolated perp market and tokens,",
+              "which also means their health only depends on those."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding2",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "isolatedPerpMarketIndex",
+            "docs": [
+              "The perp market an isolated account may use, PerpMarketIndex::MAX for none"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "isolatedTokenIndexes",
+            "docs": [
+              "The tokens an isolated account may use, TokenIndex::MAX for unused entries"
+            ],
+            "type": {
+              "array": [
+                "u16",
+                6
+              ]
+            }
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "The MangoAccount that referred this account, Pubkey::default() for none.",
+              "",
+              "Can only be set when the account is created."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "referralFeesAccrued",
+            "docs": [
+              "Fees this account generated since the referrer last claimed, per bank or perp",
+              "market that collected them. The referrer receives Group::referral_fee_share of them."
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "ReferralFeesAccrued"
+                },
+                6
+              ]
+            }
+          },
           {
             "name": "reserved",
             "type": {
               "array": [
                 "u8",
-                160
+                16
               ]
             }
           }
@@ -24563,6 +28557,109 @@ export const IDL: MangoV4 = {
         ]
       }
     },
+    {
+      "name": "FastListingTier",
+      "docs": [
+        "Parameter presets that token_register_trustless can list a token with.",
+        "",
+        "The parameters of each tier are hard-coded, so listings that don't go",
+        "through governance can't set weights beyond what the Premium tier allows."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CollateralDisabled"
+          },
+          {
+            "name": "Midwit"
+          },
+          {
+            "name": "Premium"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MangoAccountDataEntry",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "TokenEquity",
+            "fields": [
+              {
+                "defined": "TokenEquity"
+              }
+            ]
+          },
+          {
+            "name": "PerpEquity",
+            "fields": [
+              {
+                "defined": "PerpEquity"
+              }
+            ]
+          },
+          {
+            "name": "TokenPosition",
+            "fields": [
+              {
+                "defined": "TokenPositionData"
+              }
+            ]
+          },
+          {
+            "name": "Serum3Position",
+            "fields": [
+              {
+                "defined": "Serum3PositionData"
+              }
+            ]
+          },
+          {
+            "name": "PerpPosition",
+            "fields": [
+              {
+                "defined": "PerpPositionData"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "HealthContribution",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Token",
+            "fields": [
+              {
+                "defined": "TokenHealthContribution"
+              }
+            ]
+          },
+          {
+            "name": "Serum3",
+            "fields": [
+              {
+                "defined": "Serum3HealthContribution"
+              }
+            ]
+          },
+          {
+            "name": "Perp",
+            "fields": [
+              {
+                "defined": "PerpHealthContribution"
+              }
+            ]
+          }
+        ]
+      }
+    },
     {
       "name": "HealthType",
       "docs": [
@@ -24856,13 +28953,84 @@ export const IDL: MangoV4 = {
             "name": "TokenConditionalSwapStart"
           },
           {
-            "name": "TokenConditionalSwapCreatePremiumAuction"
+            "name": "TokenConditionalSwapCreatePremiumAuction"
+          },
+          {
+            "name": "TokenConditionalSwapCreateLinearAuction"
+          },
+          {
+            "name": "Serum3PlaceOrderV2"
+          },
+          {
+            "name": "PerpLiqAutoDeleverage"
+          },
+          {
+            "name": "GroupDepositInsuranceFund"
+          },
+          {
+            "name": "PerpTriggerOrderCreate"
+          },
+          {
+            "name": "PerpTriggerOrderCancel"
+          },
+          {
+            "name": "PerpTriggerOrderExecute"
+          },
+          {
+            "name": "AltCreate"
+          },
+          {
+            "name": "MarginTrade"
+          },
+          {
+            "name": "PerpSettlePnlBatch"
+          },
+          {
+            "name": "PerpModifyOrder"
+          },
+          {
+            "name": "PerpPlaceOrders"
+          },
+          {
+            "name": "Serum3PlaceOrders"
+          },
+          {
+            "name": "TokenCheckVaultInvariant"
+          },
+          {
+            "name": "AccountClaimReferralFees"
+          },
+          {
+            "name": "PerpUpdateAdlCandidate"
+          },
+          {
+            "name": "PerpLiqCombined"
+          },
+          {
+            "name": "GroupMigrate"
+          },
+          {
+            "name": "TokenMigrate"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReferralFeeSource",
+      "docs": [
+        "The bank or perp market that collected referral fees, see ReferralFeesAccrued"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unused"
           },
           {
-            "name": "TokenConditionalSwapCreateLinearAuction"
+            "name": "Bank"
           },
           {
-            "name": "Serum3PlaceOrderV2"
+            "name": "PerpMarket"
           }
         ]
       }
@@ -24903,6 +29071,12 @@ export const IDL: MangoV4 = {
           },
           {
             "name": "OrcaCLMM"
+          },
+          {
+            "name": "PythV2"
+          },
+          {
+            "name": "SwitchboardOnDemand"
           }
         ]
       }
@@ -25149,6 +29323,20 @@ export const IDL: MangoV4 = {
         ]
       }
     },
+    {
+      "name": "PerpTriggerCondition",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "OraclePriceAbove"
+          },
+          {
+            "name": "OraclePriceBelow"
+          }
+        ]
+      }
+    },
     {
       "name": "TokenConditionalSwapDisplayPriceStyle",
       "type": {
@@ -25225,6 +29413,63 @@ export const IDL: MangoV4 = {
         }
       ]
     },
+    {
+      "name": "MangoAccountPositions",
+      "fields": [
+        {
+          "name": "tokens",
+          "type": {
+            "vec": {
+              "defined": "TokenPositionData"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "serum3",
+          "type": {
+            "vec": {
+              "defined": "Serum3PositionData"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "perps",
+          "type": {
+            "vec": {
+              "defined": "PerpPositionData"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "HealthLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "initHealth",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "maintHealth",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
     {
       "name": "PerpBalanceLog",
       "fields": [
@@ -25743,6 +29988,121 @@ export const IDL: MangoV4 = {
         }
       ]
     },
+    {
+      "name": "FillLogV4",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "takerSide",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerSlot",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "makerOut",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "seqNum",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "makerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "makerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "makerTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "taker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "takerClientOrderId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerFee",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quantity",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "makerClosedPnl",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "takerClosedPnl",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "makerFeePaid",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "takerFeePaid",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "makerBasePosition",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "takerBasePosition",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
     {
       "name": "PerpUpdateFundingLog",
       "fields": [
@@ -26350,23 +30710,103 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "initialLiabNative",
+          "name": "initialLiabNative",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "insuranceTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "insuranceTransfer",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "socializedLoss",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "startingLiabDepositIndex",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "endingLiabDepositIndex",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenLiqBankruptcyLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liabTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "initialLiabNative",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabTransfer",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "insuranceTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "insurancePrice",
           "type": "i128",
           "index": false
         },
         {
-          "name": "liabPrice",
+          "name": "insuranceTransfer",
           "type": "i128",
           "index": false
         },
         {
-          "name": "insuranceTokenIndex",
-          "type": "u16",
+          "name": "liquidationFee",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "insuranceTransfer",
-          "type": "i128",
+          "name": "insuranceFundExhausted",
+          "type": "bool",
           "index": false
         },
         {
@@ -26616,6 +31056,46 @@ export const IDL: MangoV4 = {
         }
       ]
     },
+    {
+      "name": "OpenbookV2RegisterMarketLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "openbookMarket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "baseTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "quoteTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "openbookProgram",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "openbookMarketExternal",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
     {
       "name": "PerpLiqBaseOrPositivePnlLog",
       "fields": [
@@ -27026,6 +31506,51 @@ export const IDL: MangoV4 = {
         }
       ]
     },
+    {
+      "name": "PerpLiqAutoDeleverageLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "perpMarketIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "counterparty",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "baseTransferLiqee",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "quoteTransferLiqee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "bankruptcyPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "oraclePrice",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
     {
       "name": "TokenForceCloseBorrowsWithTokenLog",
       "fields": [
@@ -27090,64 +31615,234 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "liqor",
-          "type": "publicKey",
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "assetTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "liabTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "assetTransferFromLiqee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetTransferToLiqor",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetLiquidationFee",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabTransfer",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "assetPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "liabPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "feeFactor",
+          "type": "i128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenConditionalSwapCreateLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxBuy",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxSell",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceLowerLimit",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "priceUpperLimit",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "pricePremiumRate",
+          "type": "f64",
+          "index": false
+        },
+        {
+          "name": "takerFeeRate",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "makerFeeRate",
+          "type": "f32",
+          "index": false
+        },
+        {
+          "name": "buyTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "sellTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "allowCreatingDeposits",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "allowCreatingBorrows",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenConditionalSwapCreateLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxBuy",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxSell",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "expiryTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "priceLowerLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "liqee",
-          "type": "publicKey",
+          "name": "priceUpperLimit",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "assetTokenIndex",
-          "type": "u16",
+          "name": "pricePremiumRate",
+          "type": "f64",
           "index": false
         },
         {
-          "name": "liabTokenIndex",
-          "type": "u16",
+          "name": "takerFeeRate",
+          "type": "f32",
           "index": false
         },
         {
-          "name": "assetTransferFromLiqee",
-          "type": "i128",
+          "name": "makerFeeRate",
+          "type": "f32",
           "index": false
         },
         {
-          "name": "assetTransferToLiqor",
-          "type": "i128",
+          "name": "buyTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "assetLiquidationFee",
-          "type": "i128",
+          "name": "sellTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "liabTransfer",
-          "type": "i128",
+          "name": "allowCreatingDeposits",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "assetPrice",
-          "type": "i128",
+          "name": "allowCreatingBorrows",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "liabPrice",
-          "type": "i128",
+          "name": "displayPriceStyle",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "feeFactor",
-          "type": "i128",
+          "name": "intention",
+          "type": "u8",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapCreateLog",
+      "name": "TokenConditionalSwapCreateLogV3",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27223,11 +31918,36 @@ export const IDL: MangoV4 = {
           "name": "allowCreatingBorrows",
           "type": "bool",
           "index": false
+        },
+        {
+          "name": "displayPriceStyle",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "intention",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "tcsType",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "startTimestamp",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "durationSeconds",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapCreateLogV2",
+      "name": "TokenConditionalSwapTriggerLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27235,53 +31955,88 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "id",
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenConditionalSwapId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "maxBuy",
+          "name": "buyTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "sellTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "buyAmount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "maxSell",
+          "name": "sellAmount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "expiryTimestamp",
+          "name": "makerFee",
           "type": "u64",
           "index": false
         },
         {
-          "name": "priceLowerLimit",
-          "type": "f64",
+          "name": "takerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64",
+          "name": "buyTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "pricePremiumRate",
-          "type": "f64",
+          "name": "sellTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "takerFeeRate",
-          "type": "f32",
+          "name": "closed",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenConditionalSwapTriggerLogV2",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "makerFeeRate",
-          "type": "f32",
+          "name": "liqee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenConditionalSwapId",
+          "type": "u64",
           "index": false
         },
         {
@@ -27295,12 +32050,37 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool",
+          "name": "buyAmount",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "allowCreatingBorrows",
+          "name": "sellAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "makerFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "takerFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "buyTokenPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "sellTokenPrice",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "closed",
           "type": "bool",
           "index": false
         },
@@ -27317,7 +32097,7 @@ export const IDL: MangoV4 = {
       ]
     },
     {
-      "name": "TokenConditionalSwapCreateLogV3",
+      "name": "TokenConditionalSwapTriggerLogV3",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27325,104 +32105,144 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "mangoAccount",
+          "name": "liqee",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "id",
+          "name": "liqor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenConditionalSwapId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "maxBuy",
+          "name": "buyTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "sellTokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "buyAmount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "maxSell",
+          "name": "sellAmount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "expiryTimestamp",
+          "name": "makerFee",
           "type": "u64",
           "index": false
         },
         {
-          "name": "priceLowerLimit",
-          "type": "f64",
+          "name": "takerFee",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "priceUpperLimit",
-          "type": "f64",
+          "name": "buyTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "pricePremiumRate",
-          "type": "f64",
+          "name": "sellTokenPrice",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "takerFeeRate",
-          "type": "f32",
+          "name": "closed",
+          "type": "bool",
           "index": false
         },
         {
-          "name": "makerFeeRate",
-          "type": "f32",
+          "name": "displayPriceStyle",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
+          "name": "intention",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "sellTokenIndex",
-          "type": "u16",
+          "name": "tcsType",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "allowCreatingDeposits",
-          "type": "bool",
+          "name": "startTimestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenConditionalSwapCancelLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "allowCreatingBorrows",
-          "type": "bool",
+          "name": "mangoAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "id",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenConditionalSwapStartLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "displayPriceStyle",
-          "type": "u8",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "intention",
-          "type": "u8",
+          "name": "caller",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tcsType",
-          "type": "u8",
+          "name": "tokenConditionalSwapId",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "startTimestamp",
-          "type": "u64",
+          "name": "incentiveTokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "durationSeconds",
+          "name": "incentiveAmount",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapTriggerLog",
+      "name": "GroupInsuranceFundLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27430,69 +32250,84 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "insuranceVault",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
-          "type": "publicKey",
+          "name": "amountChange",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "vaultBalance",
           "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
+          "name": "tokenAccount",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TokenVaultInvariantViolationLog",
+      "fields": [
+        {
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sellTokenIndex",
-          "type": "u16",
+          "name": "bank",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "buyAmount",
-          "type": "u64",
+          "name": "tokenIndex",
+          "type": "u16",
           "index": false
         },
         {
-          "name": "sellAmount",
+          "name": "vaultBalance",
           "type": "u64",
           "index": false
         },
         {
-          "name": "makerFee",
-          "type": "u64",
+          "name": "nativeDeposits",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "takerFee",
-          "type": "u64",
+          "name": "nativeBorrows",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "buyTokenPrice",
+          "name": "collectedFees",
           "type": "i128",
           "index": false
         },
         {
-          "name": "sellTokenPrice",
+          "name": "feesWithdrawn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "shortfall",
           "type": "i128",
           "index": false
         },
         {
-          "name": "closed",
+          "name": "halted",
           "type": "bool",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapTriggerLogV2",
+      "name": "PerpTriggerOrderCreateLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27500,79 +32335,74 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "triggerOrder",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "orderId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "sellTokenIndex",
+          "name": "perpMarketIndex",
           "type": "u16",
           "index": false
         },
         {
-          "name": "buyAmount",
-          "type": "u64",
+          "name": "side",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "sellAmount",
-          "type": "u64",
+          "name": "triggerCondition",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "makerFee",
-          "type": "u64",
+          "name": "triggerPriceLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "takerFee",
-          "type": "u64",
+          "name": "priceLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "buyTokenPrice",
-          "type": "i128",
+          "name": "maxBaseLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "sellTokenPrice",
-          "type": "i128",
+          "name": "maxQuoteLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "closed",
+          "name": "reduceOnly",
           "type": "bool",
           "index": false
         },
         {
-          "name": "displayPriceStyle",
-          "type": "u8",
+          "name": "expiryTimestamp",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "intention",
-          "type": "u8",
+          "name": "executorIncentive",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapTriggerLogV3",
+      "name": "PerpTriggerOrderCancelLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27580,89 +32410,93 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "liqee",
+          "name": "mangoAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "liqor",
+          "name": "triggerOrder",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
+          "name": "orderId",
           "type": "u64",
           "index": false
-        },
-        {
-          "name": "buyTokenIndex",
-          "type": "u16",
-          "index": false
-        },
-        {
-          "name": "sellTokenIndex",
-          "type": "u16",
-          "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "PerpTriggerOrderExecuteLog",
+      "fields": [
         {
-          "name": "buyAmount",
-          "type": "u64",
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sellAmount",
-          "type": "u64",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "makerFee",
-          "type": "u64",
+          "name": "triggerOrder",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "takerFee",
+          "name": "orderId",
           "type": "u64",
           "index": false
         },
         {
-          "name": "buyTokenPrice",
-          "type": "i128",
+          "name": "executor",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "sellTokenPrice",
-          "type": "i128",
+          "name": "oraclePriceLots",
+          "type": "i64",
           "index": false
         },
         {
-          "name": "closed",
-          "type": "bool",
+          "name": "executorIncentive",
+          "type": "u64",
           "index": false
-        },
+        }
+      ]
+    },
+    {
+      "name": "MarginTradeLog",
+      "fields": [
         {
-          "name": "displayPriceStyle",
-          "type": "u8",
+          "name": "mangoGroup",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "intention",
-          "type": "u8",
+          "name": "mangoAccount",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "tcsType",
-          "type": "u8",
+          "name": "swapProgram",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "startTimestamp",
-          "type": "u64",
+          "name": "tokenDetails",
+          "type": {
+            "vec": {
+              "defined": "MarginTradeTokenDetail"
+            }
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapCancelLog",
+      "name": "ReferralFeesClaimLog",
       "fields": [
         {
           "name": "mangoGroup",
@@ -27675,44 +32509,78 @@ export const IDL: MangoV4 = {
           "index": false
         },
         {
-          "name": "id",
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenIndex",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "perpMarketIndex",
+          "type": {
+            "option": "u16"
+          },
+          "index": false
+        },
+        {
+          "name": "feesUsed",
           "type": "u64",
           "index": false
+        },
+        {
+          "name": "amount",
+          "type": "i128",
+          "index": false
         }
       ]
     },
     {
-      "name": "TokenConditionalSwapStartLog",
+      "name": "OracleRejectedLog",
       "fields": [
         {
-          "name": "mangoGroup",
+          "name": "oracle",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "mangoAccount",
-          "type": "publicKey",
+          "name": "oracleType",
+          "type": {
+            "defined": "OracleType"
+          },
           "index": false
         },
         {
-          "name": "caller",
-          "type": "publicKey",
+          "name": "price",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "tokenConditionalSwapId",
-          "type": "u64",
+          "name": "deviation",
+          "type": "i128",
           "index": false
         },
         {
-          "name": "incentiveTokenIndex",
-          "type": "u16",
+          "name": "lastUpdateSlot",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "incentiveAmount",
+          "name": "ageSlots",
           "type": "u64",
           "index": false
+        },
+        {
+          "name": "stale",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "fallbackAvailable",
+          "type": "bool",
+          "index": false
         }
       ]
     }
@@ -28062,6 +32930,76 @@ export const IDL: MangoV4 = {
       "code": 6068,
       "name": "MissingFeedForCLMMOracle",
       "msg": "Pyth USDC/USD or SOL/USD feed not found (required by CLMM oracle)"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidPythPriceUpdate",
+      "msg": "the provided pyth price update account is not valid"
+    },
+    {
+      "code": 6070,
+      "name": "InvalidSwitchboardPullFeed",
+      "msg": "the provided switchboard pull feed account is not valid"
+    },
+    {
+      "code": 6071,
+      "name": "VaultBankMismatch",
+      "msg": "the vault does not belong to the bank"
+    },
+    {
+      "code": 6072,
+      "name": "MissingFlashLoanEnd",
+      "msg": "no FlashLoanEnd instruction found in the transaction"
+    },
+    {
+      "code": 6073,
+      "name": "CpiForbidden",
+      "msg": "the instruction may not be invoked via CPI"
+    },
+    {
+      "code": 6074,
+      "name": "HealthAccountBankMissing",
+      "msg": "a vault has no matching bank in the health accounts"
+    },
+    {
+      "code": 6075,
+      "name": "UnexpectedFlashLoanInstruction",
+      "msg": "instruction is not allowed between FlashLoanBegin and FlashLoanEnd"
+    },
+    {
+      "code": 6076,
+      "name": "PerpTriggerOrderExpired",
+      "msg": "the perp trigger order has expired"
+    },
+    {
+      "code": 6077,
+      "name": "PerpTriggerOrderNotTriggered",
+      "msg": "the oracle price has not reached the perp trigger order's trigger price"
+    },
+    {
+      "code": 6078,
+      "name": "SwapProgramNotAllowed",
+      "msg": "the swap program is not allowed"
+    },
+    {
+      "code": 6079,
+      "name": "IsolatedAccountPositionNotAllowed",
+      "msg": "the position is not allowed for this isolated account"
+    },
+    {
+      "code": 6080,
+      "name": "PerpPriceBandExceeded",
+      "msg": "the perp order price is too far from the oracle price"
+    },
+    {
+      "code": 6081,
+      "name": "GroupInWithdrawOnlyMode",
+      "msg": "group is in withdraw only mode"
+    },
+    {
+      "code": 6082,
+      "name": "PerpAdlCounterpartyNotOpposing",
+      "msg": "the auto-deleveraging counterparty's position does not oppose the liqee's"
     }
   ]
 };
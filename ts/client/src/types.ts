@@ -16,6 +16,17 @@ export namespace FlashLoanType {
   export const swap = { swap: {} };
 }
 
+export type FastListingTier =
+  | { collateralDisabled: Record<string, never> }
+  | { midwit: Record<string, never> }
+  | { premium: Record<string, never> };
+// eslint-disable-next-line @typescript-eslint/no-namespace
+export namespace FastListingTier {
+  export const collateralDisabled = { collateralDisabled: {} };
+  export const midwit = { midwit: {} };
+  export const premium = { premium: {} };
+}
+
 export class InterestRateParams {
   util0: number;
   rate0: number;